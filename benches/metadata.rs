@@ -0,0 +1,106 @@
+use std::{fs, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use smufl::{Glyph, Metadata};
+
+/// The reference fonts' metadata files, from the submodules.
+const FONTS: [(&str, &str); 5] = [
+    ("bravura", "submodules/bravura/redist/bravura_metadata.json"),
+    (
+        "petaluma",
+        "submodules/petaluma/redist/petaluma_metadata.json",
+    ),
+    ("leland", "submodules/leland/leland_metadata.json"),
+    ("sebastian", "submodules/sebastian/fonts/Sebastian.json"),
+    (
+        "leipzig",
+        "submodules/verovio/fonts/Leipzig/leipzig_metadata.json",
+    ),
+];
+
+fn fonts() -> impl Iterator<Item = (&'static str, Vec<u8>)> {
+    FONTS.into_iter().map(|(name, path)| {
+        let json = fs::read(path).unwrap_or_else(|error| panic!("reading {path}: {error}"));
+        (name, json)
+    })
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for (name, json) in fonts() {
+        group.throughput(Throughput::Bytes(json.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| Metadata::from_reader(black_box(json.as_slice())).unwrap())
+        });
+        #[cfg(feature = "parallel")]
+        group.bench_function(format!("{name}/parallel"), |b| {
+            b.iter(|| Metadata::from_reader_parallel(black_box(json.as_slice())).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+
+    for (name, json) in fonts() {
+        let metadata = Metadata::from_reader(json.as_slice()).unwrap();
+        let glyphs = metadata
+            .bounding_boxes
+            .iter()
+            .map(|(glyph, _)| glyph)
+            .collect::<Vec<Glyph>>();
+
+        group.throughput(Throughput::Elements(glyphs.len() as u64));
+        group.bench_function(format!("{name}/advance_widths"), |b| {
+            b.iter(|| {
+                for &glyph in &glyphs {
+                    black_box(metadata.advance_widths.get(black_box(glyph)));
+                }
+            })
+        });
+        group.bench_function(format!("{name}/anchors"), |b| {
+            b.iter(|| {
+                for &glyph in &glyphs {
+                    black_box(metadata.anchors.get(black_box(glyph)));
+                }
+            })
+        });
+        group.bench_function(format!("{name}/bounding_boxes"), |b| {
+            b.iter(|| {
+                for &glyph in &glyphs {
+                    black_box(metadata.bounding_boxes.get(black_box(glyph)));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn with_defaults(c: &mut Criterion) {
+    let mut group = c.benchmark_group("with_defaults");
+
+    let fonts = fonts()
+        .map(|(name, json)| (name, Metadata::from_reader(json.as_slice()).unwrap()))
+        .collect::<Vec<_>>();
+    // Bravura covers the most glyphs, so it is the usual fallback.
+    let defaults = &fonts[0].1;
+
+    for (name, metadata) in &fonts[1..] {
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                || (metadata.clone(), defaults.clone()),
+                |(metadata, defaults)| metadata.with_defaults(defaults),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse, lookup, with_defaults);
+criterion_main!(benches);
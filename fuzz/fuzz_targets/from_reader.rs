@@ -0,0 +1,41 @@
+//! Feeds arbitrary bytes to every way of parsing JSON metadata.
+//!
+//! Seed the corpus with real metadata files to start from valid input:
+//!
+//! ```sh
+//! mkdir -p fuzz/corpus/from_reader
+//! cp submodules/bravura/redist/bravura_metadata.json fuzz/corpus/from_reader/
+//! cp submodules/leland/leland_metadata.json fuzz/corpus/from_reader/
+//! cargo +nightly fuzz run from_reader
+//! ```
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smufl::{LazyMetadata, Metadata, ParseOptions};
+
+/// Options which enable every optional check, so that they see malformed
+/// input too.
+const STRICT: ParseOptions = ParseOptions {
+    strict_unknown_glyphs: true,
+    reject_non_finite: true,
+    max_reported_unknown_glyphs: Some(10),
+    detect_duplicate_glyphs: true,
+    report_invalid_bounding_boxes: true,
+    normalize_bounding_boxes: true,
+    lenient: true,
+    validate: true,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Metadata::from_reader(data);
+    let _ = Metadata::from_reader_with(data, &STRICT);
+    let _ = Metadata::from_reader_with_sink(data, &STRICT, |_| {});
+
+    if let Ok(metadata) = LazyMetadata::from_reader(data) {
+        let _ = metadata.advance_widths();
+        let _ = metadata.anchors();
+        let _ = metadata.bounding_boxes();
+        let _ = metadata.into_metadata();
+    }
+});
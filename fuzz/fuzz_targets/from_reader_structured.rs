@@ -0,0 +1,49 @@
+//! Serializes arbitrary metadata, then parses it back, either whole or cut
+//! short, and with or without comments being allowed.
+//!
+//! Whole metadata must parse successfully; truncated metadata must fail
+//! without panicking.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use smufl::{Metadata, ParseOptions, SerializeOptions};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    metadata: Metadata,
+    pretty: bool,
+    lenient: bool,
+    truncate_at: Option<usize>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut json = Vec::new();
+    input
+        .metadata
+        .to_writer(
+            &mut json,
+            &SerializeOptions {
+                pretty: input.pretty,
+                ..Default::default()
+            },
+        )
+        .expect("arbitrary metadata should serialize");
+
+    let options = ParseOptions {
+        lenient: input.lenient,
+        detect_duplicate_glyphs: true,
+        ..Default::default()
+    };
+
+    match input.truncate_at {
+        Some(len) if len < json.len() => {
+            assert!(Metadata::from_reader_with(&json[..len], &options).is_err());
+        }
+        _ => {
+            Metadata::from_reader_with(json.as_slice(), &options)
+                .expect("serialized metadata should parse");
+        }
+    }
+});
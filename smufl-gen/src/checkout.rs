@@ -0,0 +1,628 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    fs::File,
+    io::BufReader,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use convert_case::{Case, Casing};
+use serde::Deserialize;
+
+use crate::{
+    config::{Config, Order},
+    generate::{
+        add_fn, add_gated_fn, add_glyph_set, add_tests, entries, escape, finish, glyph_code,
+        glyph_set_name, glyph_set_words, variant_name, Entry, RangeLink,
+    },
+    glyph_definition::{Codepoint, GlyphDefinition},
+};
+
+const RANGE_ENUM_NAME: &str = "Range";
+const CLASS_ENUM_NAME: &str = "Class";
+
+/// The metadata files in a checkout of the [SMuFL repository](https://github.com/w3c/smufl).
+#[derive(Debug)]
+pub struct Checkout {
+    pub glyphs: HashMap<String, GlyphDefinition>,
+
+    /// Each class's name and the names of the glyphs in it, from
+    /// `classes.json`.
+    pub classes: BTreeMap<String, Vec<String>>,
+
+    /// Each range's name and definition, from `ranges.json`.
+    pub ranges: BTreeMap<String, RangeDefinition>,
+}
+
+/// A range of glyphs, as described in `ranges.json`.
+#[derive(Debug, Deserialize)]
+pub struct RangeDefinition {
+    pub description: String,
+    pub glyphs: Vec<String>,
+    pub range_start: Codepoint,
+    pub range_end: Codepoint,
+}
+
+impl RangeDefinition {
+    /// Loads each range's name and definition from the `ranges.json` at
+    /// `path`.
+    pub fn from_path(path: &Path) -> Result<BTreeMap<String, Self>> {
+        read_json(path)
+    }
+}
+
+impl Checkout {
+    /// Loads the metadata files from the `metadata` directory of the checkout
+    /// at `path`. `path` may also be the `metadata` directory itself.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let metadata = if path.join("metadata").is_dir() {
+            path.join("metadata")
+        } else {
+            path.to_owned()
+        };
+
+        Ok(Self {
+            glyphs: GlyphDefinition::from_path(&metadata.join("glyphnames.json"))?,
+            classes: read_json(&metadata.join("classes.json"))?,
+            ranges: RangeDefinition::from_path(&metadata.join("ranges.json"))?,
+        })
+    }
+
+    /// Generates the `Glyph` enum, along with `Range` and `Class` enums listing
+    /// the glyphs in each range and class, and methods on `Glyph` returning its
+    /// range and classes.
+    ///
+    /// If `config.range_features` is set, each glyph is gated behind a feature
+    /// for its range, and the code starts with a comment listing the features.
+    pub fn generate(mut self, config: &Config) -> String {
+        let mut ranges = self.ranges.into_iter().collect::<Vec<_>>();
+        if config.order == Order::Codepoint {
+            ranges.sort_by_key(|(name, range)| (range.range_start.0, name.clone()));
+        }
+        if config.range_features {
+            gate_by_range(&mut self.glyphs, &ranges);
+        }
+
+        let glyphs = entries(self.glyphs, config);
+        let variants = glyphs
+            .iter()
+            .map(|(variant, name, _)| (name.as_str(), variant.as_str()))
+            .collect::<HashMap<_, _>>();
+        let features = glyphs
+            .iter()
+            .filter_map(|(variant, _, glyph)| Some((variant.as_str(), glyph.feature.as_deref()?)))
+            .collect::<HashMap<_, _>>();
+
+        let ranges = ranges
+            .into_iter()
+            .map(|(name, range)| {
+                let members = members(&range.glyphs, &variants);
+                (variant_name(&name), name, range, members)
+            })
+            .collect::<Vec<_>>();
+        let classes = self
+            .classes
+            .into_iter()
+            .map(|(name, glyph_names)| {
+                let members = members(&glyph_names, &variants);
+                (variant_name(&name), name, members)
+            })
+            .collect::<Vec<_>>();
+
+        let mut links = HashMap::new();
+        for (_, name, range, _) in &ranges {
+            for glyph in &range.glyphs {
+                links.entry(glyph.as_str()).or_insert(RangeLink {
+                    name,
+                    description: &range.description,
+                });
+            }
+        }
+
+        let mut output = glyph_code(&glyphs, &links, config);
+        output.push_str("\n\n");
+        add_range_enum(&ranges, &features, config, &mut output);
+        output.push_str("\n\n");
+        add_class_enum(&classes, &features, config, &mut output);
+        output.push_str("\n\n");
+        add_glyph_lookups(&glyphs, &ranges, &classes, config, &mut output);
+        add_glyph_set(glyphs.len(), config, &mut output);
+        add_glyph_set_constructors(&glyphs, &ranges, &classes, config, &mut output);
+        add_tests(&glyphs, true, config, &mut output);
+
+        let output = finish(output, config);
+        if !config.range_features {
+            return output;
+        }
+
+        let mut header =
+            "// The glyphs in each range are gated behind a Cargo feature:\n//\n// [features]\n"
+                .to_owned();
+        for (_, name, _, _) in &ranges {
+            writeln!(header, "// {} = []", range_feature(name)).unwrap();
+        }
+
+        header + "\n" + &output
+    }
+}
+
+/// Gates each glyph behind the feature for the first of `ranges` containing
+/// it. Glyphs which aren't in any range aren't gated.
+fn gate_by_range(
+    glyphs: &mut HashMap<String, GlyphDefinition>,
+    ranges: &[(String, RangeDefinition)],
+) {
+    for (name, range) in ranges {
+        for glyph in &range.glyphs {
+            if let Some(glyph) = glyphs.get_mut(glyph) {
+                glyph.feature.get_or_insert_with(|| range_feature(name));
+            }
+        }
+    }
+}
+
+/// Returns the feature gating the glyphs in the range named `name` in
+/// `ranges.json`, e.g. `range-beamed-groups-of-notes`.
+fn range_feature(name: &str) -> String {
+    format!("range-{}", name.to_case(Case::Kebab))
+}
+
+/// A range to generate: its variant name, its name in `ranges.json`, its
+/// definition, and the variant names of its glyphs.
+type RangeEntry<'a> = (String, String, RangeDefinition, Vec<&'a str>);
+
+/// A class to generate: its variant name, its name in `classes.json`, and the
+/// variant names of its glyphs.
+type ClassEntry<'a> = (String, String, Vec<&'a str>);
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+    serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Returns the variant names of the glyphs named in `names`, skipping any which
+/// aren't generated.
+fn members<'a>(names: &[String], variants: &HashMap<&str, &'a str>) -> Vec<&'a str> {
+    names
+        .iter()
+        .filter_map(|name| variants.get(name.as_str()).copied())
+        .collect()
+}
+
+/// Returns an array expression of the variants `glyphs`, where each variant in
+/// `features` is gated behind its feature.
+fn glyph_list(config: &Config, features: &HashMap<&str, &str>, glyphs: &[&str]) -> String {
+    let glyphs = glyphs
+        .iter()
+        .map(|glyph| match features.get(glyph) {
+            Some(feature) => format!(
+                r#"#[cfg(feature = "{feature}")] {}::{glyph}"#,
+                config.enum_name
+            ),
+            None => format!("{}::{glyph}", config.enum_name),
+        })
+        .collect::<Vec<_>>();
+
+    format!("&[{}]", glyphs.join(", "))
+}
+
+fn add_enum_header(output: &mut String, doc: &str, name: &str, config: &Config) {
+    writeln!(output, "/// {doc}").unwrap();
+    writeln!(
+        output,
+        "#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]"
+    )
+    .unwrap();
+    writeln!(output, "#[rustfmt::skip]").unwrap();
+    writeln!(output, "{} enum {name} {{", config.visibility).unwrap();
+}
+
+fn add_range_enum(
+    ranges: &[RangeEntry],
+    features: &HashMap<&str, &str>,
+    config: &Config,
+    output: &mut String,
+) {
+    add_enum_header(
+        output,
+        "A range of glyphs, generated from [ranges.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/ranges.json).",
+        RANGE_ENUM_NAME,
+        config,
+    );
+    for (variant, _, range, _) in ranges {
+        writeln!(output, "    /// {}", escape(&range.description)).unwrap();
+        writeln!(output, "    {variant},").unwrap();
+    }
+    writeln!(output, "}}\n").unwrap();
+
+    writeln!(output, "impl {RANGE_ENUM_NAME} {{").unwrap();
+    add_fn(
+        output,
+        &config.visibility,
+        "The range's name in ranges.json",
+        "name",
+        "&'static str",
+        ranges
+            .iter()
+            .map(|(variant, name, _, _)| (variant, format!("{name:?}"))),
+    );
+    output.push('\n');
+    add_fn(
+        output,
+        &config.visibility,
+        "The range's description",
+        "description",
+        "&'static str",
+        ranges
+            .iter()
+            .map(|(variant, _, range, _)| (variant, format!("{:?}", range.description))),
+    );
+    output.push('\n');
+    add_fn(
+        output,
+        &config.visibility,
+        "The first code point in the range",
+        "start",
+        "char",
+        ranges
+            .iter()
+            .map(|(variant, _, range, _)| (variant, range.range_start.to_string())),
+    );
+    output.push('\n');
+    add_fn(
+        output,
+        &config.visibility,
+        "The last code point in the range",
+        "end",
+        "char",
+        ranges
+            .iter()
+            .map(|(variant, _, range, _)| (variant, range.range_end.to_string())),
+    );
+    output.push('\n');
+    add_fn(
+        output,
+        &config.visibility,
+        "The glyphs in the range",
+        "glyphs",
+        &format!("&'static [{}]", config.enum_name),
+        ranges
+            .iter()
+            .map(|(variant, _, _, glyphs)| (variant, glyph_list(config, features, glyphs))),
+    );
+    write!(output, "}}").unwrap();
+}
+
+fn add_class_enum(
+    classes: &[ClassEntry],
+    features: &HashMap<&str, &str>,
+    config: &Config,
+    output: &mut String,
+) {
+    add_enum_header(
+        output,
+        "A class of glyphs, generated from [classes.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/classes.json).",
+        CLASS_ENUM_NAME,
+        config,
+    );
+    for (variant, name, _) in classes {
+        writeln!(output, "    /// `{name}`").unwrap();
+        writeln!(output, "    {variant},").unwrap();
+    }
+    writeln!(output, "}}\n").unwrap();
+
+    writeln!(output, "impl {CLASS_ENUM_NAME} {{").unwrap();
+    add_fn(
+        output,
+        &config.visibility,
+        "The class's name in classes.json",
+        "name",
+        "&'static str",
+        classes
+            .iter()
+            .map(|(variant, name, _)| (variant, format!("{name:?}"))),
+    );
+    output.push('\n');
+    add_fn(
+        output,
+        &config.visibility,
+        "The glyphs in the class",
+        "glyphs",
+        &format!("&'static [{}]", config.enum_name),
+        classes
+            .iter()
+            .map(|(variant, _, glyphs)| (variant, glyph_list(config, features, glyphs))),
+    );
+    write!(output, "}}").unwrap();
+}
+
+fn add_glyph_lookups(
+    glyphs: &[Entry],
+    ranges: &[RangeEntry],
+    classes: &[ClassEntry],
+    config: &Config,
+    output: &mut String,
+) {
+    let mut glyph_ranges = HashMap::new();
+    for (range, _, _, members) in ranges {
+        for glyph in members {
+            glyph_ranges.entry(*glyph).or_insert(range);
+        }
+    }
+
+    let mut glyph_classes = HashMap::<&str, Vec<&str>>::new();
+    for (class, _, members) in classes {
+        for glyph in members {
+            glyph_classes.entry(glyph).or_default().push(class);
+        }
+    }
+
+    writeln!(output, "impl {} {{", config.enum_name).unwrap();
+    add_gated_fn(
+        output,
+        &config.visibility,
+        "The range containing the glyph",
+        "range",
+        &format!("Option<{RANGE_ENUM_NAME}>"),
+        glyphs.iter().map(|(variant, _, glyph)| {
+            let value = match glyph_ranges.get(variant.as_str()) {
+                Some(range) => format!("Some({RANGE_ENUM_NAME}::{range})"),
+                None => "None".to_owned(),
+            };
+            (variant, glyph.feature.as_deref(), value)
+        }),
+    );
+    output.push('\n');
+    add_gated_fn(
+        output,
+        &config.visibility,
+        "The classes containing the glyph",
+        "classes",
+        &format!("&'static [{CLASS_ENUM_NAME}]"),
+        glyphs.iter().map(|(variant, _, glyph)| {
+            let classes = glyph_classes
+                .get(variant.as_str())
+                .map(|classes| {
+                    classes
+                        .iter()
+                        .map(|class| format!("{CLASS_ENUM_NAME}::{class}"))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            (
+                variant,
+                glyph.feature.as_deref(),
+                format!("&[{}]", classes.join(", ")),
+            )
+        }),
+    );
+    write!(output, "}}").unwrap();
+}
+
+/// Adds `range` and `class` constructors to the set type, if
+/// `config.glyph_set` is set, returning sets of the glyphs in each range and
+/// class.
+fn add_glyph_set_constructors(
+    glyphs: &[Entry],
+    ranges: &[RangeEntry],
+    classes: &[ClassEntry],
+    config: &Config,
+    output: &mut String,
+) {
+    if !config.glyph_set {
+        return;
+    }
+
+    // Without features, each variant's index is its position in `glyphs`, so
+    // the sets can be written out. Otherwise, the indices depend on which
+    // features are enabled, so the sets are built when they're evaluated.
+    let gated = glyphs.iter().any(|(_, _, glyph)| glyph.feature.is_some());
+    let features = glyphs
+        .iter()
+        .map(|(variant, _, glyph)| (variant.as_str(), glyph.feature.as_deref()))
+        .collect::<HashMap<_, _>>();
+    let indices = glyphs
+        .iter()
+        .enumerate()
+        .map(|(index, (variant, _, _))| (variant.as_str(), index))
+        .collect::<HashMap<_, _>>();
+    let words = |members: &[&str]| {
+        if !gated {
+            return format!(
+                "Self({})",
+                glyph_set_words(members.iter().map(|member| indices[member]), glyphs.len(),)
+            );
+        }
+
+        // The set isn't modified if none of its glyphs' features are enabled.
+        let mut set =
+            "{\n                #[allow(unused_mut)]\n                let mut set = Self::EMPTY;\n"
+                .to_owned();
+        for member in members {
+            if let Some(feature) = features[member] {
+                writeln!(set, r#"                #[cfg(feature = "{feature}")]"#).unwrap();
+            }
+            writeln!(
+                set,
+                "                set.insert({}::{member});",
+                config.enum_name
+            )
+            .unwrap();
+        }
+        set.push_str("                set\n            }");
+        set
+    };
+
+    let visibility = &config.visibility;
+    writeln!(
+        output,
+        "
+
+impl {} {{",
+        glyph_set_name(config)
+    )
+    .unwrap();
+    writeln!(output, "    /// The glyphs in `range`.").unwrap();
+    writeln!(
+        output,
+        "    {visibility} const fn range(range: {RANGE_ENUM_NAME}) -> Self {{"
+    )
+    .unwrap();
+    writeln!(output, "        match range {{").unwrap();
+    for (variant, _, _, members) in ranges {
+        writeln!(
+            output,
+            "            {RANGE_ENUM_NAME}::{variant} => {},",
+            words(members)
+        )
+        .unwrap();
+    }
+    writeln!(output, "        }}").unwrap();
+    writeln!(
+        output,
+        "    }}
+"
+    )
+    .unwrap();
+    writeln!(output, "    /// The glyphs in `class`.").unwrap();
+    writeln!(
+        output,
+        "    {visibility} const fn class(class: {CLASS_ENUM_NAME}) -> Self {{"
+    )
+    .unwrap();
+    writeln!(output, "        match class {{").unwrap();
+    for (variant, _, members) in classes {
+        writeln!(
+            output,
+            "            {CLASS_ENUM_NAME}::{variant} => {},",
+            words(members)
+        )
+        .unwrap();
+    }
+    writeln!(output, "        }}").unwrap();
+    writeln!(output, "    }}").unwrap();
+    write!(output, "}}").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-gen-checkout-{}", std::process::id()));
+        let metadata = dir.join("metadata");
+        std::fs::create_dir_all(&metadata)?;
+        std::fs::write(
+            metadata.join("glyphnames.json"),
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadWhole": {"codepoint": "U+E0A2", "description": "Whole notehead"}
+            }"#,
+        )?;
+        std::fs::write(
+            metadata.join("classes.json"),
+            r#"{"noteheadSetDefault": ["noteheadWhole", "noteheadBlack", "notAGlyph"]}"#,
+        )?;
+        std::fs::write(
+            metadata.join("ranges.json"),
+            r#"{
+                "noteheads": {
+                    "description": "Noteheads",
+                    "glyphs": ["noteheadWhole", "noteheadBlack"],
+                    "range_end": "U+E0FF",
+                    "range_start": "U+E0A0"
+                }
+            }"#,
+        )?;
+
+        let checkout = Checkout::from_path(&dir);
+        std::fs::remove_dir_all(&dir)?;
+        let config = Config {
+            glyph_set: true,
+            ..Config::default()
+        };
+        let output = checkout?.generate(&config);
+
+        for expected in [
+            "pub enum Range {\n    /// Noteheads\n    Noteheads,\n}",
+            "Self::Noteheads => &[Glyph::NoteheadWhole, Glyph::NoteheadBlack],",
+            "Self::Noteheads => '\\u{e0a0}',",
+            "Self::NoteheadSetDefault => &[Glyph::NoteheadWhole, Glyph::NoteheadBlack],",
+            "Self::NoteheadBlack => Some(Range::Noteheads),",
+            "Self::NoteheadBlack => &[Class::NoteheadSetDefault],",
+            "Range::Noteheads => Self([0x3]),",
+            "Class::NoteheadSetDefault => Self([0x3]),",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn range_features() {
+        let checkout = Checkout {
+            glyphs: HashMap::from([
+                (
+                    "noteheadBlack".to_owned(),
+                    GlyphDefinition {
+                        codepoint: Codepoint('\u{e0a4}'),
+                        alternate_codepoint: None,
+                        description: "Black notehead".to_owned(),
+                        feature: None,
+                    },
+                ),
+                (
+                    "gClef".to_owned(),
+                    GlyphDefinition {
+                        codepoint: Codepoint('\u{e050}'),
+                        alternate_codepoint: None,
+                        description: "G clef".to_owned(),
+                        feature: None,
+                    },
+                ),
+            ]),
+            classes: BTreeMap::from([(
+                "clefs".to_owned(),
+                vec!["gClef".to_owned(), "noteheadBlack".to_owned()],
+            )]),
+            ranges: BTreeMap::from([(
+                "noteheads".to_owned(),
+                RangeDefinition {
+                    description: "Noteheads".to_owned(),
+                    glyphs: vec!["noteheadBlack".to_owned()],
+                    range_start: Codepoint('\u{e0a0}'),
+                    range_end: Codepoint('\u{e0ff}'),
+                },
+            )]),
+        };
+        let config = Config {
+            range_features: true,
+            glyph_set: true,
+            ..Config::default()
+        };
+        let output = checkout.generate(&config);
+
+        assert!(output.starts_with(
+            "// The glyphs in each range are gated behind a Cargo feature:\n//\n// [features]\n// range-noteheads = []\n\n"
+        ));
+        for expected in [
+            "    GClef,\n    /// Black notehead\n    #[cfg(feature = \"range-noteheads\")]\n    NoteheadBlack,\n",
+            "            #[cfg(feature = \"range-noteheads\")]\n            Self::NoteheadBlack => Some(Range::Noteheads),",
+            "Self::Clefs => &[Glyph::GClef, #[cfg(feature = \"range-noteheads\")] Glyph::NoteheadBlack],",
+            "                #[cfg(feature = \"range-noteheads\")]\n                set.insert(Glyph::NoteheadBlack);",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+/// Options controlling the generated code, read from a TOML file.
+///
+/// ```toml
+/// enum_name = "SmuflGlyph"
+/// visibility = "pub(crate)"
+/// module = "glyphs"
+/// derives = ["Clone", "Copy", "Debug", "Deserialize", "Serialize"]
+/// attributes = ["#[non_exhaustive]"]
+/// order = "codepoint"
+/// spec_docs = true
+/// tests = false
+/// glyph_set = true
+/// range_features = true
+/// phf = false
+/// serde_feature = "serde"
+///
+/// [[excluded_ranges]]
+/// start = "U+E800"
+/// end = "U+E82F"
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The name of the generated enum.
+    pub enum_name: String,
+
+    /// The visibility of the generated enum and its methods, e.g. `pub` or
+    /// `pub(crate)`.
+    pub visibility: String,
+
+    /// If set, the generated code is wrapped in a module with this name.
+    pub module: Option<String>,
+
+    /// The traits derived by the generated enum.
+    pub derives: Vec<String>,
+
+    /// Attributes added to the generated enum after the derives.
+    pub attributes: Vec<String>,
+
+    /// Codepoint ranges whose glyphs are left out of the generated enum.
+    pub excluded_ranges: Vec<CodepointRange>,
+
+    /// The order of the generated variants.
+    pub order: Order,
+
+    /// Adds each glyph's name and codepoint to its documentation, along with a
+    /// link to its range's page in the SMuFL specification when generating
+    /// from a checkout.
+    pub spec_docs: bool,
+
+    /// Adds a test module checking that every glyph's name round-trips
+    /// through deserialization, and that every codepoint is unique and in the
+    /// expected range.
+    pub tests: bool,
+
+    /// Adds a set type for the glyphs, named like `GlyphSet`, with one bit per
+    /// glyph. When generating from a checkout, it has constructors for the
+    /// glyphs in each range and class.
+    pub glyph_set: bool,
+
+    /// Gates each glyph behind a Cargo feature for its range, like
+    /// `range-noteheads`, when generating from a checkout, so builds can leave
+    /// out the ranges they don't use. At least one of the features must be
+    /// enabled.
+    pub range_features: bool,
+
+    /// Looks up glyphs by name with a perfect hash map from the `phf` crate,
+    /// which the generated code then depends on, rather than with a binary
+    /// search. Glyphs gated behind features are always found with a binary
+    /// search.
+    pub phf: bool,
+
+    /// A Cargo feature to gate the serde impls behind, for crates where serde
+    /// is optional. Looking glyphs up by name doesn't depend on serde, so it's
+    /// always available.
+    pub serde_feature: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            enum_name: "Glyph".to_owned(),
+            visibility: "pub".to_owned(),
+            module: None,
+            derives: [
+                "Clone",
+                "Copy",
+                "Debug",
+                "Deserialize",
+                "Eq",
+                "Hash",
+                "PartialEq",
+                "Serialize",
+            ]
+            .map(ToOwned::to_owned)
+            .to_vec(),
+            attributes: [
+                r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#,
+                "#[rustfmt::skip]",
+            ]
+            .map(ToOwned::to_owned)
+            .to_vec(),
+            excluded_ranges: Vec::new(),
+            order: Order::default(),
+            spec_docs: false,
+            tests: true,
+            glyph_set: false,
+            range_features: false,
+            phf: true,
+            serde_feature: None,
+        }
+    }
+}
+
+impl Config {
+    /// Reads a config file from `path`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        toml::from_str(&text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Whether the generated enum derives `Copy`.
+    pub fn is_copy(&self) -> bool {
+        self.derives.iter().any(|derive| derive == "Copy")
+    }
+
+    /// Returns the serde traits among the derives, which are implemented using
+    /// a table of glyph names rather than derived.
+    pub fn serde_derives(&self) -> impl Iterator<Item = &str> {
+        self.derives
+            .iter()
+            .map(String::as_str)
+            .filter(|derive| matches!(*derive, "Deserialize" | "Serialize"))
+    }
+
+    /// Returns `true` if `codepoint` is in one of the excluded ranges.
+    pub fn is_excluded(&self, codepoint: char) -> bool {
+        self.excluded_ranges
+            .iter()
+            .any(|range| range.contains(codepoint))
+    }
+}
+
+/// The order of the generated variants. Either way, the output is the same on
+/// every run for the same input.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum Order {
+    /// Alphabetical by variant name.
+    #[default]
+    Name,
+
+    /// By codepoint, which is the order glyphs are listed in the SMuFL
+    /// specification.
+    Codepoint,
+}
+
+/// An inclusive range of codepoints, written as e.g. `U+E000`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CodepointRange {
+    #[serde(deserialize_with = "deserialize_codepoint")]
+    pub start: u32,
+
+    #[serde(deserialize_with = "deserialize_codepoint")]
+    pub end: u32,
+}
+
+impl CodepointRange {
+    pub fn contains(&self, codepoint: char) -> bool {
+        (self.start..=self.end).contains(&u32::from(codepoint))
+    }
+}
+
+fn deserialize_codepoint<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u32, D::Error> {
+    let string = String::deserialize(deserializer)?;
+
+    parse_codepoint(&string).map_err(serde::de::Error::custom)
+}
+
+fn parse_codepoint(string: &str) -> Result<u32> {
+    let Some(hex) = string.strip_prefix("U+") else {
+        bail!("codepoint must start with U+: {string}");
+    };
+
+    u32::from_str_radix(hex, 16).with_context(|| format!("invalid codepoint: {string}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_toml() -> Result<()> {
+        let config: Config = toml::from_str(
+            r#"
+            enum_name = "SmuflGlyph"
+            visibility = "pub(crate)"
+
+            [[excluded_ranges]]
+            start = "U+E800"
+            end = "U+E82F"
+            "#,
+        )?;
+
+        assert_eq!(config.enum_name, "SmuflGlyph");
+        assert_eq!(config.visibility, "pub(crate)");
+        assert_eq!(config.derives, Config::default().derives);
+        assert!(config.is_excluded('\u{e800}'));
+        assert!(config.is_excluded('\u{e82f}'));
+        assert!(!config.is_excluded('\u{e830}'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_toml_invalid_codepoint() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [[excluded_ranges]]
+            start = "E800"
+            end = "U+E82F"
+            "#,
+        );
+
+        assert!(config.is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::glyph_definition::{Codepoint, GlyphDefinition};
+
+/// The differences between two versions of `glyphnames.json`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Diff {
+    /// Glyphs in the new version only, with their codepoints.
+    pub added: Vec<(String, char)>,
+
+    /// Glyphs in the old version only, with their codepoints.
+    pub removed: Vec<(String, char)>,
+
+    /// Glyphs in both versions whose codepoints differ, with their old and new
+    /// codepoints.
+    pub repointed: Vec<(String, char, char)>,
+}
+
+impl Diff {
+    /// Compares the glyphs in `old` and `new`.
+    pub fn new(
+        old: &HashMap<String, GlyphDefinition>,
+        new: &HashMap<String, GlyphDefinition>,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        for (name, glyph) in new {
+            match old.get(name) {
+                None => diff.added.push((name.clone(), glyph.codepoint.0)),
+                Some(old_glyph) if old_glyph.codepoint.0 != glyph.codepoint.0 => diff
+                    .repointed
+                    .push((name.clone(), old_glyph.codepoint.0, glyph.codepoint.0)),
+                Some(_) => {}
+            }
+        }
+        for (name, glyph) in old {
+            if !new.contains_key(name) {
+                diff.removed.push((name.clone(), glyph.codepoint.0));
+            }
+        }
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.repointed.sort();
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.repointed.is_empty()
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no differences");
+        }
+
+        if !self.added.is_empty() {
+            writeln!(f, "added ({}):", self.added.len())?;
+            for (name, codepoint) in &self.added {
+                writeln!(f, "  + {name} {}", Codepoint(*codepoint).to_hex())?;
+            }
+        }
+        if !self.removed.is_empty() {
+            writeln!(f, "removed ({}):", self.removed.len())?;
+            for (name, codepoint) in &self.removed {
+                writeln!(f, "  - {name} {}", Codepoint(*codepoint).to_hex())?;
+            }
+        }
+        if !self.repointed.is_empty() {
+            writeln!(f, "re-pointed ({}):", self.repointed.len())?;
+            for (name, old, new) in &self.repointed {
+                writeln!(
+                    f,
+                    "  ~ {name} {} -> {}",
+                    Codepoint(*old).to_hex(),
+                    Codepoint(*new).to_hex()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyphs(json: &str) -> HashMap<String, GlyphDefinition> {
+        GlyphDefinition::from_reader(json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn diff() {
+        let old = glyphs(
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadWhole": {"codepoint": "U+E0A2", "description": "Whole notehead"},
+                "oldGlyph": {"codepoint": "U+E000", "description": "Old glyph"}
+            }"#,
+        );
+        let new = glyphs(
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead (new description)"},
+                "noteheadWhole": {"codepoint": "U+E0A3", "description": "Whole notehead"},
+                "newGlyph": {"codepoint": "U+E001", "description": "New glyph"}
+            }"#,
+        );
+
+        let diff = Diff::new(&old, &new);
+
+        assert_eq!(
+            diff.to_string(),
+            "added (1):
+  + newGlyph U+E001
+removed (1):
+  - oldGlyph U+E000
+re-pointed (1):
+  ~ noteheadWhole U+E0A2 -> U+E0A3
+"
+        );
+        assert!(Diff::new(&old, &old).is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+//! Downloading `glyphnames.json` from the SMuFL repository.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+use crate::glyph_definition::GlyphDefinition;
+
+/// Downloads `glyphnames.json` for SMuFL `version` from the tagged release in
+/// the [SMuFL repository](https://github.com/w3c/smufl).
+pub fn glyphnames(version: &str) -> Result<HashMap<String, GlyphDefinition>> {
+    let url = glyphnames_url(version);
+    let response = ureq::get(&url)
+        .call()
+        .with_context(|| format!("failed to fetch {url}"))?;
+
+    GlyphDefinition::from_reader(response.into_reader())
+        .with_context(|| format!("failed to read {url}"))
+}
+
+/// Returns the URL of `glyphnames.json` for SMuFL `version`, which may be
+/// given with or without the `v` prefix of the release tags.
+fn glyphnames_url(version: &str) -> String {
+    let version = version.strip_prefix('v').unwrap_or(version);
+
+    format!("https://raw.githubusercontent.com/w3c/smufl/v{version}/metadata/glyphnames.json")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn glyphnames_url() {
+        for version in ["1.40", "v1.40"] {
+            assert_eq!(
+                super::glyphnames_url(version),
+                "https://raw.githubusercontent.com/w3c/smufl/v1.40/metadata/glyphnames.json"
+            );
+        }
+    }
+}
@@ -0,0 +1,1400 @@
+use std::{collections::HashMap, fmt::Write, sync::LazyLock};
+
+use convert_case::{Case, Casing};
+use regex::{Captures, Regex};
+
+use crate::{
+    config::{Config, Order},
+    glyph_definition::GlyphDefinition,
+};
+
+const CODEPOINT_FN_NAME: &str = "codepoint";
+const ALTERNATE_CODEPOINT_FN_NAME: &str = "alternate_codepoint";
+const NAME_FN_NAME: &str = "name";
+const DESCRIPTION_FN_NAME: &str = "description";
+
+/// A glyph to generate: its variant name, its name in `glyphnames.json`, and
+/// its definition.
+pub type Entry = (String, String, GlyphDefinition);
+
+/// The range containing a glyph, which its documentation links to.
+#[derive(Clone, Copy, Debug)]
+pub struct RangeLink<'a> {
+    /// The range's name in `ranges.json`.
+    pub name: &'a str,
+
+    pub description: &'a str,
+}
+
+/// Generates the `Glyph` enum and its `impl` block.
+pub fn generate(glyphs: HashMap<String, GlyphDefinition>, config: &Config) -> String {
+    let glyphs = entries(glyphs, config);
+
+    let mut output = glyph_code(&glyphs, &HashMap::new(), config);
+    add_glyph_set(glyphs.len(), config, &mut output);
+    add_tests(&glyphs, false, config, &mut output);
+
+    finish(output, config)
+}
+
+/// Returns the glyphs which aren't excluded by `config`, in the order given by
+/// `config`.
+pub fn entries(glyphs: HashMap<String, GlyphDefinition>, config: &Config) -> Vec<Entry> {
+    let mut glyphs = glyphs
+        .into_iter()
+        .filter(|(_, glyph)| !config.is_excluded(glyph.codepoint.0))
+        .map(|(name, glyph)| (variant_name(&name), name, glyph))
+        .collect::<Vec<_>>();
+
+    sort(&mut glyphs, config.order, |glyph| glyph.codepoint.0);
+
+    glyphs
+}
+
+/// Sorts glyphs, given as their variant name, their name in the metadata, and
+/// their definition, in `order`.
+pub fn sort<T>(glyphs: &mut [(String, String, T)], order: Order, codepoint: impl Fn(&T) -> char) {
+    // Every key ends with the glyph's name, which is unique, so the order
+    // doesn't depend on the `HashMap`'s iteration order.
+    match order {
+        Order::Name => glyphs.sort_by(|(a_variant, a_name, _), (b_variant, b_name, _)| {
+            (a_variant, a_name).cmp(&(b_variant, b_name))
+        }),
+        Order::Codepoint => glyphs.sort_by(|(_, a_name, a), (_, b_name, b)| {
+            (codepoint(a), a_name).cmp(&(codepoint(b), b_name))
+        }),
+    }
+}
+
+/// Returns the `Glyph` enum and its `impl` block. `ranges` maps glyph names
+/// to the ranges containing them, for linking to the specification.
+pub fn glyph_code(glyphs: &[Entry], ranges: &HashMap<&str, RangeLink>, config: &Config) -> String {
+    let mut output = String::new();
+    add_glyph_enum(glyphs, ranges, config, &mut output);
+    output.push('\n');
+    if config.is_copy() {
+        add_glyph_tables(glyphs, config, &mut output);
+        output.push('\n');
+    }
+    add_glyph_impl(glyphs, config, &mut output);
+    add_serde_impls(
+        &mut output,
+        &config.enum_name,
+        config,
+        glyphs
+            .iter()
+            .map(|(variant, name, glyph)| (variant, name, glyph.feature.as_deref())),
+    );
+
+    output
+}
+
+/// Wraps the generated `code` in a module, if `config` specifies one.
+pub fn finish(code: String, config: &Config) -> String {
+    match &config.module {
+        Some(module) => wrap_in_module(module, config, &code),
+        None => code,
+    }
+}
+
+/// Returns the `use` item for the serde traits which the generated code
+/// derives, if any.
+pub fn serde_import(config: &Config) -> Option<String> {
+    let serde_derives = config.serde_derives().collect::<Vec<_>>();
+    let cfg = serde_cfg(config, "");
+    match serde_derives.as_slice() {
+        [] => None,
+        [derive] => Some(format!("{cfg}use serde::{derive};")),
+        derives => Some(format!("{cfg}use serde::{{{}}};", derives.join(", "))),
+    }
+}
+
+/// Wraps `code` in a module, importing the serde traits which the generated
+/// code derives.
+fn wrap_in_module(module: &str, config: &Config, code: &str) -> String {
+    let mut output = format!("{} mod {module} {{\n", config.visibility);
+
+    if let Some(import) = serde_import(config) {
+        for line in import.lines() {
+            writeln!(output, "    {line}").unwrap();
+        }
+        output.push('\n');
+    }
+
+    for line in code.lines() {
+        if line.is_empty() {
+            output.push('\n');
+        } else {
+            writeln!(output, "    {line}").unwrap();
+        }
+    }
+
+    output.push('}');
+    output
+}
+
+pub fn variant_name(name: &str) -> String {
+    static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)([Th|Nd])").unwrap());
+
+    let name = name.to_case(Case::Pascal);
+
+    // Fix incorrect capitalization for number suffixes
+    // (eg. 8Th -> 8th, 32Nd -> 32nd)
+    let name = REGEX.replace(&name, |captures: &Captures| {
+        format!("{}{}", &captures[1], &captures[2].to_lowercase())
+    });
+
+    let needs_underscore = name.chars().next().is_some_and(|c| !c.is_alphabetic());
+
+    if needs_underscore {
+        format!("_{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn add_glyph_enum(
+    glyphs: &[Entry],
+    ranges: &HashMap<&str, RangeLink>,
+    config: &Config,
+    output: &mut String,
+) {
+    let doc = "`Glyph` is generated from [glyphnames.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/glyphnames.json). See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/glyphnames.html).";
+
+    add_enum(
+        output,
+        doc,
+        &config.enum_name,
+        config,
+        glyphs.iter().map(|(name, original_name, glyph)| {
+            let doc = glyph_doc(
+                original_name,
+                glyph,
+                ranges.get(original_name.as_str()),
+                config,
+            );
+            (name, glyph.feature.as_deref(), doc)
+        }),
+    );
+}
+
+/// Returns the documentation for a glyph: its description, and, if
+/// `config.spec_docs` is set, its name, its codepoint, and a link to its
+/// range's page in the specification.
+fn glyph_doc(
+    name: &str,
+    glyph: &GlyphDefinition,
+    range: Option<&RangeLink>,
+    config: &Config,
+) -> String {
+    let mut doc = escape(&glyph.description);
+    if !config.spec_docs {
+        return doc;
+    }
+
+    write!(doc, "\n\n`{name}` ({})", glyph.codepoint.to_hex()).unwrap();
+    if let Some(range) = range {
+        write!(
+            doc,
+            ", in [{}](https://w3c.github.io/smufl/latest/tables/{}.html)",
+            escape(range.description),
+            range.name.to_case(Case::Kebab)
+        )
+        .unwrap();
+    }
+    doc.push('.');
+
+    doc
+}
+
+/// Escapes square brackets, which appear in some descriptions and which the
+/// Rust documentation generator would otherwise treat as Markdown.
+pub fn escape(description: &str) -> String {
+    description.replace('[', r"\[").replace(']', r"\]")
+}
+
+/// Adds an enum with the derives and attributes from `config`, and a variant
+/// for each of `variants`: its variant name, the feature it's gated behind, if
+/// any, and its documentation, as Markdown.
+///
+/// The serde traits aren't derived here; see [`add_serde_impls`].
+pub fn add_enum<'a>(
+    output: &mut String,
+    doc: &str,
+    name: &str,
+    config: &Config,
+    variants: impl Iterator<Item = (&'a String, Option<&'a str>, String)>,
+) {
+    writeln!(output, "/// {doc}").unwrap();
+    let derives = config
+        .derives
+        .iter()
+        .map(String::as_str)
+        .filter(|derive| !config.serde_derives().any(|serde| serde == *derive))
+        .collect::<Vec<_>>();
+    if !derives.is_empty() {
+        writeln!(output, "#[derive({})]", derives.join(", ")).unwrap();
+    }
+    for attribute in &config.attributes {
+        writeln!(output, "{attribute}").unwrap();
+    }
+    writeln!(output, "{} enum {name} {{", config.visibility).unwrap();
+
+    for (variant, feature, doc) in variants {
+        for line in doc.lines() {
+            if line.is_empty() {
+                writeln!(output, "    ///").unwrap();
+            } else {
+                writeln!(output, "    /// {line}").unwrap();
+            }
+        }
+        if let Some(feature) = feature {
+            writeln!(output, r#"    #[cfg(feature = "{feature}")]"#).unwrap();
+        }
+        writeln!(output, "    {variant},").unwrap();
+    }
+
+    writeln!(output, "}}").unwrap();
+}
+
+/// The names of the static tables generated for a `Copy` enum, prefixed with
+/// the enum's name, e.g. `GLYPH_CODEPOINTS`.
+pub struct TableNames {
+    /// Every variant, e.g. `GLYPHS`.
+    pub variants: String,
+    pub codepoints: String,
+    pub alternate_codepoints: String,
+    pub names: String,
+    pub descriptions: String,
+}
+
+impl TableNames {
+    pub fn new(config: &Config) -> Self {
+        let prefix = config.enum_name.to_case(Case::UpperSnake);
+
+        Self {
+            variants: format!("{prefix}S"),
+            codepoints: format!("{prefix}_CODEPOINTS"),
+            alternate_codepoints: format!("{prefix}_ALTERNATE_CODEPOINTS"),
+            names: format!("{prefix}_NAMES"),
+            descriptions: format!("{prefix}_DESCRIPTIONS"),
+        }
+    }
+}
+
+/// Adds static tables of every variant and its code points, name and
+/// description, indexed by discriminant, for looking glyphs up without any
+/// allocation or parsing. Only `Copy` enums get them, since otherwise the
+/// variant can't be cast to its discriminant.
+pub fn add_glyph_tables(glyphs: &[Entry], config: &Config, output: &mut String) {
+    let enum_name = &config.enum_name;
+    let visibility = &config.visibility;
+    let tables = TableNames::new(config);
+    let features = || glyphs.iter().map(|(_, _, glyph)| glyph.feature.as_deref());
+
+    add_table(
+        output,
+        visibility,
+        &format!("Every [`{enum_name}`], in the order of their discriminants."),
+        &tables.variants,
+        enum_name,
+        glyphs.iter().map(|(variant, _, glyph)| {
+            (glyph.feature.as_deref(), format!("{enum_name}::{variant}"))
+        }),
+    );
+    output.push('\n');
+    add_table(
+        output,
+        visibility,
+        &format!("Each [`{enum_name}`]'s SMuFL code point, indexed by discriminant."),
+        &tables.codepoints,
+        "char",
+        features().zip(
+            glyphs
+                .iter()
+                .map(|(_, _, glyph)| glyph.codepoint.to_string()),
+        ),
+    );
+    output.push('\n');
+    add_table(
+        output,
+        visibility,
+        &format!(
+            "Each [`{enum_name}`]'s Unicode Musical Symbols range code point, indexed by\n/// discriminant."
+        ),
+        &tables.alternate_codepoints,
+        "Option<char>",
+        features().zip(glyphs.iter().map(|(_, _, glyph)| alternate_codepoint(glyph))),
+    );
+    output.push('\n');
+    add_table(
+        output,
+        visibility,
+        &format!("Each [`{enum_name}`]'s name in `glyphnames.json`, indexed by discriminant."),
+        &tables.names,
+        "&str",
+        features().zip(glyphs.iter().map(|(_, name, _)| format!("{name:?}"))),
+    );
+    output.push('\n');
+    add_table(
+        output,
+        visibility,
+        &format!(
+            "Each [`{enum_name}`]'s description in `glyphnames.json`, indexed by\n/// discriminant."
+        ),
+        &tables.descriptions,
+        "&str",
+        features().zip(
+            glyphs
+                .iter()
+                .map(|(_, _, glyph)| format!("{:?}", glyph.description)),
+        ),
+    );
+}
+
+fn alternate_codepoint(glyph: &GlyphDefinition) -> String {
+    match &glyph.alternate_codepoint {
+        Some(codepoint) => format!("Some({codepoint})"),
+        None => "None".to_owned(),
+    }
+}
+
+/// Adds the `Glyph` enum's methods. If the enum is `Copy`, the values are
+/// looked up in the tables from [`add_glyph_tables`], rather than with a
+/// `match` with an arm for every glyph, which is slow to compile. Without
+/// `Copy`, the variant can't be cast to its discriminant.
+pub fn add_glyph_impl(glyphs: &[Entry], config: &Config, output: &mut String) {
+    let visibility = &config.visibility;
+
+    writeln!(output, "impl {} {{", config.enum_name).unwrap();
+    if config.is_copy() {
+        let tables = TableNames::new(config);
+        add_table_fn(
+            output,
+            visibility,
+            "SMuFL code point",
+            CODEPOINT_FN_NAME,
+            "char",
+            &tables.codepoints,
+        );
+        output.push('\n');
+        add_table_fn(
+            output,
+            visibility,
+            "Unicode Musical Symbols range code point",
+            ALTERNATE_CODEPOINT_FN_NAME,
+            "Option<char>",
+            &tables.alternate_codepoints,
+        );
+        output.push('\n');
+        add_table_fn(
+            output,
+            visibility,
+            "Name in `glyphnames.json`",
+            NAME_FN_NAME,
+            "&'static str",
+            &tables.names,
+        );
+        output.push('\n');
+        add_table_fn(
+            output,
+            visibility,
+            "Description in `glyphnames.json`",
+            DESCRIPTION_FN_NAME,
+            "&'static str",
+            &tables.descriptions,
+        );
+    } else {
+        add_gated_fn(
+            output,
+            visibility,
+            "SMuFL code point",
+            CODEPOINT_FN_NAME,
+            "char",
+            glyphs.iter().map(|(variant, _, glyph)| {
+                (
+                    variant,
+                    glyph.feature.as_deref(),
+                    glyph.codepoint.to_string(),
+                )
+            }),
+        );
+        output.push('\n');
+        add_gated_fn(
+            output,
+            visibility,
+            "Unicode Musical Symbols range code point",
+            ALTERNATE_CODEPOINT_FN_NAME,
+            "Option<char>",
+            glyphs.iter().map(|(variant, _, glyph)| {
+                (
+                    variant,
+                    glyph.feature.as_deref(),
+                    alternate_codepoint(glyph),
+                )
+            }),
+        );
+        output.push('\n');
+        add_gated_fn(
+            output,
+            visibility,
+            "Name in `glyphnames.json`",
+            NAME_FN_NAME,
+            "&'static str",
+            glyphs.iter().map(|(variant, name, glyph)| {
+                (variant, glyph.feature.as_deref(), format!("{name:?}"))
+            }),
+        );
+        output.push('\n');
+        add_gated_fn(
+            output,
+            visibility,
+            "Description in `glyphnames.json`",
+            DESCRIPTION_FN_NAME,
+            "&'static str",
+            glyphs.iter().map(|(variant, _, glyph)| {
+                (
+                    variant,
+                    glyph.feature.as_deref(),
+                    format!("{:?}", glyph.description),
+                )
+            }),
+        );
+    }
+    write!(output, "}}").unwrap();
+}
+
+/// Implements the serde traits among `config`'s derives for the enum named
+/// `enum_name`, given each variant's name, its name in the metadata, and the
+/// feature it's gated behind, if any. The enum must have a `name` method
+/// returning the name in the metadata.
+///
+/// Deriving the traits would add a `#[serde(rename)]` attribute to every
+/// variant, and a visitor matching on every name, which is slow to compile.
+/// Instead, glyphs are serialized with `name`, and deserialized by looking up
+/// their names in a perfect hash map, if `config.phf` is set, or otherwise a
+/// sorted table. `from_name` is always generated, but the impls are gated
+/// behind `config.serde_feature`, if any.
+pub fn add_serde_impls<'a>(
+    output: &mut String,
+    enum_name: &str,
+    config: &Config,
+    variants: impl Iterator<Item = (&'a String, &'a String, Option<&'a str>)>,
+) {
+    let visibility = &config.visibility;
+    let serde_derives = config.serde_derives().collect::<Vec<_>>();
+    let cfg = serde_cfg(config, "");
+
+    if serde_derives.contains(&"Deserialize") {
+        let variants = variants.collect::<Vec<_>>();
+        // Entries in a perfect hash map can't be gated behind features, since
+        // its layout is fixed when it's generated.
+        let phf = config.phf && variants.iter().all(|(_, _, feature)| feature.is_none());
+
+        writeln!(output, "\n\nimpl {enum_name} {{").unwrap();
+        if phf {
+            add_phf_names(output, &variants);
+        } else {
+            add_sorted_names(output, variants);
+        }
+        output.push('\n');
+
+        // Without `Copy`, the glyph has to be cloned out of the table.
+        let copy = if config.is_copy() { "" } else { ".clone()" };
+        writeln!(
+            output,
+            "    /// Returns the variant with the given name, if any."
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "    {visibility} fn from_name(name: &str) -> Option<Self> {{"
+        )
+        .unwrap();
+        if phf {
+            let copy = if copy.is_empty() {
+                ".copied()"
+            } else {
+                ".cloned()"
+            };
+            writeln!(output, "        Self::NAMES.get(name){copy}").unwrap();
+        } else {
+            writeln!(output, "        Self::NAMES").unwrap();
+            writeln!(
+                output,
+                "            .binary_search_by_key(&name, |(name, _)| name)"
+            )
+            .unwrap();
+            writeln!(output, "            .ok()").unwrap();
+            writeln!(
+                output,
+                "            .map(|index| Self::NAMES[index].1{copy})"
+            )
+            .unwrap();
+        }
+        writeln!(output, "    }}").unwrap();
+        write!(output, "}}").unwrap();
+
+        write!(
+            output,
+            r#"
+
+{cfg}impl<'de> Deserialize<'de> for {enum_name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        struct NameVisitor;
+
+        impl serde::de::Visitor<'_> for NameVisitor {{
+            type Value = {enum_name};
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{
+                formatter.write_str("a glyph name")
+            }}
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {{
+                {enum_name}::from_name(value)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }}
+        }}
+
+        deserializer.deserialize_str(NameVisitor)
+    }}
+}}"#
+        )
+        .unwrap();
+    }
+
+    if serde_derives.contains(&"Serialize") {
+        write!(
+            output,
+            r#"
+
+{cfg}impl Serialize for {enum_name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        serializer.serialize_str(self.{NAME_FN_NAME}())
+    }}
+}}"#
+        )
+        .unwrap();
+    }
+}
+
+/// Returns a `#[cfg]` attribute and newline, indented by `indent`, gating an
+/// item behind `config.serde_feature`, or nothing if it is unset.
+fn serde_cfg(config: &Config, indent: &str) -> String {
+    config
+        .serde_feature
+        .as_ref()
+        .map(|feature| format!("{indent}#[cfg(feature = \"{feature}\")]\n"))
+        .unwrap_or_default()
+}
+
+/// Adds a set type for the glyphs, named like `GlyphSet`, if `config.glyph_set`
+/// is set. `count` is the number of variants, including any gated behind
+/// features.
+///
+/// The set has one bit per variant, indexed by casting the variant to `usize`,
+/// so membership tests don't depend on the number of glyphs.
+pub fn add_glyph_set(count: usize, config: &Config, output: &mut String) {
+    if !config.glyph_set {
+        return;
+    }
+
+    let enum_name = &config.enum_name;
+    let set_name = glyph_set_name(config);
+    let visibility = &config.visibility;
+    let words = count.div_ceil(64).max(1);
+    write!(
+        output,
+        r#"
+
+/// A set of [`{enum_name}`]s, stored as one bit per glyph.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+{visibility} struct {set_name}([u64; {words}]);
+
+impl {set_name} {{
+    /// The empty set.
+    {visibility} const EMPTY: Self = Self([0; {words}]);
+
+    /// Adds `glyph` to the set, returning whether it wasn't already present.
+    {visibility} const fn insert(&mut self, glyph: {enum_name}) -> bool {{
+        let (word, bit) = Self::position(glyph);
+        let inserted = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+
+        inserted
+    }}
+
+    /// Removes `glyph` from the set, returning whether it was present.
+    {visibility} const fn remove(&mut self, glyph: {enum_name}) -> bool {{
+        let (word, bit) = Self::position(glyph);
+        let removed = self.0[word] & bit != 0;
+        self.0[word] &= !bit;
+
+        removed
+    }}
+
+    /// Whether the set contains `glyph`.
+    {visibility} const fn contains(&self, glyph: {enum_name}) -> bool {{
+        let (word, bit) = Self::position(glyph);
+
+        self.0[word] & bit != 0
+    }}
+
+    /// The number of glyphs in the set.
+    {visibility} const fn len(&self) -> usize {{
+        let mut len = 0;
+        let mut word = 0;
+        while word < self.0.len() {{
+            len += self.0[word].count_ones() as usize;
+            word += 1;
+        }}
+
+        len
+    }}
+
+    /// Whether the set is empty.
+    {visibility} const fn is_empty(&self) -> bool {{
+        self.len() == 0
+    }}
+
+    /// The glyphs in either set.
+    {visibility} const fn union(mut self, other: Self) -> Self {{
+        let mut word = 0;
+        while word < self.0.len() {{
+            self.0[word] |= other.0[word];
+            word += 1;
+        }}
+
+        self
+    }}
+
+    /// The glyphs in both sets.
+    {visibility} const fn intersection(mut self, other: Self) -> Self {{
+        let mut word = 0;
+        while word < self.0.len() {{
+            self.0[word] &= other.0[word];
+            word += 1;
+        }}
+
+        self
+    }}
+
+    const fn position(glyph: {enum_name}) -> (usize, u64) {{
+        let index = glyph as usize;
+
+        (index / 64, 1 << (index % 64))
+    }}
+}}
+
+impl Default for {set_name} {{
+    fn default() -> Self {{
+        Self::EMPTY
+    }}
+}}
+
+impl FromIterator<{enum_name}> for {set_name} {{
+    fn from_iter<I: IntoIterator<Item = {enum_name}>>(glyphs: I) -> Self {{
+        let mut set = Self::EMPTY;
+        for glyph in glyphs {{
+            set.insert(glyph);
+        }}
+
+        set
+    }}
+}}"#
+    )
+    .unwrap();
+}
+
+/// The name of the set type generated for the enum, e.g. `GlyphSet`.
+pub fn glyph_set_name(config: &Config) -> String {
+    format!("{}Set", config.enum_name)
+}
+
+/// Returns the words of a set containing the variants at `indices`, as an
+/// array expression.
+pub fn glyph_set_words(indices: impl Iterator<Item = usize>, count: usize) -> String {
+    let mut words = vec![0u64; count.div_ceil(64).max(1)];
+    for index in indices {
+        words[index / 64] |= 1 << (index % 64);
+    }
+
+    let words = words
+        .iter()
+        .map(|word| match word {
+            0 => "0".to_owned(),
+            word => format!("{word:#x}"),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", words.join(", "))
+}
+
+/// Adds a `NAMES` table of each variant and its name, sorted by name for a
+/// binary search.
+fn add_sorted_names(output: &mut String, mut variants: Vec<(&String, &String, Option<&str>)>) {
+    variants.sort_by_key(|(_, name, _)| *name);
+
+    writeln!(
+        output,
+        "    /// Each variant and its name, sorted by name for\n    /// [`from_name`](Self::from_name)."
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "    const NAMES: &'static [(&'static str, Self)] = &["
+    )
+    .unwrap();
+    for (variant, name, feature) in variants {
+        if let Some(feature) = feature {
+            writeln!(output, r#"        #[cfg(feature = "{feature}")]"#).unwrap();
+        }
+        writeln!(output, "        ({name:?}, Self::{variant}),").unwrap();
+    }
+    writeln!(output, "    ];").unwrap();
+}
+
+/// Adds a `NAMES` perfect hash map from each variant's name to the variant,
+/// using the `phf` crate.
+fn add_phf_names(output: &mut String, variants: &[(&String, &String, Option<&str>)]) {
+    let names = variants
+        .iter()
+        .map(|(_, name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    let state = phf_generator::generate_hash(&names);
+
+    writeln!(
+        output,
+        "    /// Each variant by its name, for [`from_name`](Self::from_name)."
+    )
+    .unwrap();
+    writeln!(output, "    #[rustfmt::skip]").unwrap();
+    writeln!(
+        output,
+        "    const NAMES: phf::Map<&'static str, Self> = phf::Map {{"
+    )
+    .unwrap();
+    writeln!(output, "        key: {},", state.key).unwrap();
+    writeln!(output, "        disps: &[").unwrap();
+    for (d1, d2) in &state.disps {
+        writeln!(output, "            ({d1}, {d2}),").unwrap();
+    }
+    writeln!(output, "        ],").unwrap();
+    writeln!(output, "        entries: &[").unwrap();
+    for index in &state.map {
+        let (variant, name, _) = variants[*index];
+        writeln!(output, "            ({name:?}, Self::{variant}),").unwrap();
+    }
+    writeln!(output, "        ],").unwrap();
+    writeln!(output, "    }};").unwrap();
+}
+
+/// Adds a test module, if `config.tests` is set, checking that every glyph's
+/// name deserializes back to it, and that every codepoint is unique and in the
+/// private use area. If `ranges` is set, it also checks that each glyph's
+/// codepoint is in its `Range`. If `config.glyph_set` is set, it checks that
+/// each glyph has its own bit in the set type, and, with `ranges`, that the
+/// range and class sets hold the right glyphs.
+pub fn add_tests(glyphs: &[Entry], ranges: bool, config: &Config, output: &mut String) {
+    if !config.tests {
+        return;
+    }
+
+    let enum_name = &config.enum_name;
+    write!(
+        output,
+        "\n\n#[cfg(test)]\nmod generated_tests {{\n    use std::collections::HashSet;\n\n    use super::*;\n\n"
+    )
+    .unwrap();
+    if config.is_copy() {
+        let variants = TableNames::new(config).variants;
+        writeln!(
+            output,
+            "    static GLYPHS: &[{enum_name}] = super::{variants};"
+        )
+        .unwrap();
+    } else {
+        writeln!(output, "    const GLYPHS: &[{enum_name}] = &[").unwrap();
+        for (variant, _, glyph) in glyphs {
+            if let Some(feature) = &glyph.feature {
+                writeln!(output, r#"        #[cfg(feature = "{feature}")]"#).unwrap();
+            }
+            writeln!(output, "        {enum_name}::{variant},").unwrap();
+        }
+        writeln!(output, "    ];").unwrap();
+    }
+
+    if config.serde_derives().any(|derive| derive == "Deserialize") {
+        let cfg = serde_cfg(config, "    ");
+        write!(
+            output,
+            r#"
+{cfg}    #[test]
+    fn names_round_trip() {{
+        for glyph in GLYPHS {{
+            let deserializer =
+                serde::de::value::StrDeserializer::<serde::de::value::Error>::new(glyph.name());
+            let deserialized = <{enum_name} as serde::Deserialize>::deserialize(deserializer);
+
+            assert_eq!(deserialized.map(|glyph| glyph.name()), Ok(glyph.name()));
+        }}
+    }}
+"#
+        )
+        .unwrap();
+    }
+
+    write!(
+        output,
+        r#"
+    #[test]
+    fn codepoints_are_unique() {{
+        let mut codepoints = HashSet::new();
+
+        for glyph in GLYPHS {{
+            assert!(
+                codepoints.insert(glyph.codepoint()),
+                "{{}} shares its codepoint",
+                glyph.name()
+            );
+        }}
+    }}
+
+    #[test]
+    fn codepoints_are_in_private_use_area() {{
+        for glyph in GLYPHS {{
+            assert!(
+                ('\u{{e000}}'..='\u{{f8ff}}').contains(&glyph.codepoint()),
+                "{{}} is outside the private use area",
+                glyph.name()
+            );
+        }}
+    }}
+"#
+    )
+    .unwrap();
+
+    if config.glyph_set {
+        let set_name = glyph_set_name(config);
+        write!(
+            output,
+            r#"
+    #[test]
+    fn glyph_set_holds_every_glyph() {{
+        let mut set = {set_name}::EMPTY;
+
+        for (index, glyph) in GLYPHS.iter().cloned().enumerate() {{
+            assert!(set.insert(glyph), "{{}} shares its bit", GLYPHS[index].name());
+            assert_eq!(set.len(), index + 1);
+        }}
+    }}
+"#
+        )
+        .unwrap();
+
+        if ranges {
+            write!(
+                output,
+                r#"
+    #[test]
+    fn range_and_class_sets_hold_their_glyphs() {{
+        for range in GLYPHS.iter().filter_map(|glyph| glyph.range()) {{
+            let glyphs = range.glyphs().iter().cloned().collect::<{set_name}>();
+            assert_eq!({set_name}::range(range), glyphs, "{{}}", range.name());
+        }}
+
+        for class in GLYPHS.iter().flat_map(|glyph| glyph.classes()) {{
+            let glyphs = class.glyphs().iter().cloned().collect::<{set_name}>();
+            assert_eq!({set_name}::class(*class), glyphs, "{{}}", class.name());
+        }}
+    }}
+"#
+            )
+            .unwrap();
+        }
+    }
+
+    if ranges {
+        write!(
+            output,
+            r#"
+    #[test]
+    fn codepoints_are_in_ranges() {{
+        for glyph in GLYPHS {{
+            if let Some(range) = glyph.range() {{
+                assert!(
+                    (range.start()..=range.end()).contains(&glyph.codepoint()),
+                    "{{}} is outside its range",
+                    glyph.name()
+                );
+            }}
+        }}
+    }}
+"#
+        )
+        .unwrap();
+    }
+
+    write!(output, "}}").unwrap();
+}
+
+/// Adds a static table with a value for each variant, in the same order and
+/// gated behind the same features as the variants, so it can be indexed by
+/// discriminant.
+fn add_table<'a>(
+    output: &mut String,
+    visibility: &str,
+    doc: &str,
+    name: &str,
+    ty: &str,
+    values: impl Iterator<Item = (Option<&'a str>, String)>,
+) {
+    writeln!(output, "/// {doc}").unwrap();
+    writeln!(output, "#[rustfmt::skip]").unwrap();
+    writeln!(output, "{visibility} static {name}: &[{ty}] = &[").unwrap();
+    for (feature, value) in values {
+        if let Some(feature) = feature {
+            writeln!(output, r#"    #[cfg(feature = "{feature}")]"#).unwrap();
+        }
+        writeln!(output, "    {value},").unwrap();
+    }
+    writeln!(output, "];").unwrap();
+}
+
+/// Adds a method returning the value for `self` in the table named `table`.
+fn add_table_fn(
+    output: &mut String,
+    visibility: &str,
+    doc: &str,
+    name: &str,
+    ret: &str,
+    table: &str,
+) {
+    writeln!(output, "    /// {doc}").unwrap();
+    writeln!(output, "    {visibility} fn {name}(&self) -> {ret} {{").unwrap();
+    writeln!(output, "        {table}[*self as usize]").unwrap();
+    writeln!(output, "    }}").unwrap();
+}
+
+/// Adds a method which matches on `self` to return a value for each variant.
+pub fn add_fn<'a>(
+    output: &mut String,
+    visibility: &str,
+    doc: &str,
+    name: &str,
+    ret: &str,
+    arms: impl Iterator<Item = (&'a String, String)>,
+) {
+    add_gated_fn(
+        output,
+        visibility,
+        doc,
+        name,
+        ret,
+        arms.map(|(variant, value)| (variant, None, value)),
+    );
+}
+
+/// Adds a method like [`add_fn`], where each arm may be gated behind a feature.
+pub fn add_gated_fn<'a>(
+    output: &mut String,
+    visibility: &str,
+    doc: &str,
+    name: &str,
+    ret: &str,
+    arms: impl Iterator<Item = (&'a String, Option<&'a str>, String)>,
+) {
+    writeln!(output, "    /// {doc}").unwrap();
+    writeln!(output, "    {visibility} fn {name}(&self) -> {ret} {{").unwrap();
+    writeln!(output, "        match self {{").unwrap();
+    for (variant, feature, value) in arms {
+        if let Some(feature) = feature {
+            writeln!(output, r#"            #[cfg(feature = "{feature}")]"#).unwrap();
+        }
+        writeln!(output, "            Self::{variant} => {value},").unwrap();
+    }
+    writeln!(output, "        }}").unwrap();
+    writeln!(output, "    }}").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::CodepointRange, glyph_definition::Codepoint};
+
+    #[test]
+    fn variant_name() {
+        for (name, expected) in [
+            ("noteheadBlack", "NoteheadBlack"),
+            ("flag8thUp", "Flag8thUp"),
+            ("flag32ndUp", "Flag32ndUp"),
+            ("4stringTabClef", "_4StringTabClef"),
+        ] {
+            assert_eq!(super::variant_name(name), expected);
+        }
+    }
+
+    fn glyphs() -> HashMap<String, GlyphDefinition> {
+        HashMap::from([
+            (
+                "noteheadBlack".to_owned(),
+                GlyphDefinition {
+                    codepoint: Codepoint('\u{e0a4}'),
+                    alternate_codepoint: Some(Codepoint('\u{1d158}')),
+                    description: "Black notehead".to_owned(),
+                    feature: None,
+                },
+            ),
+            (
+                "accSagittal11LargeDiesisDown".to_owned(),
+                GlyphDefinition {
+                    codepoint: Codepoint('\u{e30d}'),
+                    alternate_codepoint: None,
+                    description: "3° down [46 EDO]".to_owned(),
+                    feature: None,
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn generate() {
+        let config = Config {
+            tests: false,
+            ..Config::default()
+        };
+
+        assert_eq!(
+            super::generate(glyphs(), &config),
+            r#"/// `Glyph` is generated from [glyphnames.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/glyphnames.json). See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/glyphnames.html).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[rustfmt::skip]
+pub enum Glyph {
+    /// 3° down \[46 EDO\]
+    AccSagittal11LargeDiesisDown,
+    /// Black notehead
+    NoteheadBlack,
+}
+
+/// Every [`Glyph`], in the order of their discriminants.
+#[rustfmt::skip]
+pub static GLYPHS: &[Glyph] = &[
+    Glyph::AccSagittal11LargeDiesisDown,
+    Glyph::NoteheadBlack,
+];
+
+/// Each [`Glyph`]'s SMuFL code point, indexed by discriminant.
+#[rustfmt::skip]
+pub static GLYPH_CODEPOINTS: &[char] = &[
+    '\u{e30d}',
+    '\u{e0a4}',
+];
+
+/// Each [`Glyph`]'s Unicode Musical Symbols range code point, indexed by
+/// discriminant.
+#[rustfmt::skip]
+pub static GLYPH_ALTERNATE_CODEPOINTS: &[Option<char>] = &[
+    None,
+    Some('𝅘'),
+];
+
+/// Each [`Glyph`]'s name in `glyphnames.json`, indexed by discriminant.
+#[rustfmt::skip]
+pub static GLYPH_NAMES: &[&str] = &[
+    "accSagittal11LargeDiesisDown",
+    "noteheadBlack",
+];
+
+/// Each [`Glyph`]'s description in `glyphnames.json`, indexed by
+/// discriminant.
+#[rustfmt::skip]
+pub static GLYPH_DESCRIPTIONS: &[&str] = &[
+    "3° down [46 EDO]",
+    "Black notehead",
+];
+
+impl Glyph {
+    /// SMuFL code point
+    pub fn codepoint(&self) -> char {
+        GLYPH_CODEPOINTS[*self as usize]
+    }
+
+    /// Unicode Musical Symbols range code point
+    pub fn alternate_codepoint(&self) -> Option<char> {
+        GLYPH_ALTERNATE_CODEPOINTS[*self as usize]
+    }
+
+    /// Name in `glyphnames.json`
+    pub fn name(&self) -> &'static str {
+        GLYPH_NAMES[*self as usize]
+    }
+
+    /// Description in `glyphnames.json`
+    pub fn description(&self) -> &'static str {
+        GLYPH_DESCRIPTIONS[*self as usize]
+    }
+}
+
+impl Glyph {
+    /// Each variant by its name, for [`from_name`](Self::from_name).
+    #[rustfmt::skip]
+    const NAMES: phf::Map<&'static str, Self> = phf::Map {
+        key: 12913932095322966823,
+        disps: &[
+            (0, 0),
+        ],
+        entries: &[
+            ("accSagittal11LargeDiesisDown", Self::AccSagittal11LargeDiesisDown),
+            ("noteheadBlack", Self::NoteheadBlack),
+        ],
+    };
+
+    /// Returns the variant with the given name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES.get(name).copied()
+    }
+}
+
+impl<'de> Deserialize<'de> for Glyph {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NameVisitor;
+
+        impl serde::de::Visitor<'_> for NameVisitor {
+            type Value = Glyph;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a glyph name")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Glyph::from_name(value)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+impl Serialize for Glyph {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}"#
+        );
+    }
+
+    #[test]
+    fn generate_with_config() {
+        let config = Config {
+            enum_name: "SmuflGlyph".to_owned(),
+            visibility: "pub(crate)".to_owned(),
+            module: Some("glyphs".to_owned()),
+            derives: vec!["Clone".to_owned()],
+            attributes: Vec::new(),
+            excluded_ranges: vec![CodepointRange {
+                start: 0xe300,
+                end: 0xe3ff,
+            }],
+            order: Order::Name,
+            spec_docs: false,
+            tests: false,
+            glyph_set: false,
+            range_features: false,
+            phf: false,
+            serde_feature: None,
+        };
+
+        assert_eq!(
+            super::generate(glyphs(), &config),
+            r#"pub(crate) mod glyphs {
+    /// `Glyph` is generated from [glyphnames.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/glyphnames.json). See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/glyphnames.html).
+    #[derive(Clone)]
+    pub(crate) enum SmuflGlyph {
+        /// Black notehead
+        NoteheadBlack,
+    }
+
+    impl SmuflGlyph {
+        /// SMuFL code point
+        pub(crate) fn codepoint(&self) -> char {
+            match self {
+                Self::NoteheadBlack => '\u{e0a4}',
+            }
+        }
+
+        /// Unicode Musical Symbols range code point
+        pub(crate) fn alternate_codepoint(&self) -> Option<char> {
+            match self {
+                Self::NoteheadBlack => Some('𝅘'),
+            }
+        }
+
+        /// Name in `glyphnames.json`
+        pub(crate) fn name(&self) -> &'static str {
+            match self {
+                Self::NoteheadBlack => "noteheadBlack",
+            }
+        }
+
+        /// Description in `glyphnames.json`
+        pub(crate) fn description(&self) -> &'static str {
+            match self {
+                Self::NoteheadBlack => "Black notehead",
+            }
+        }
+    }
+}"#
+        );
+    }
+
+    #[test]
+    fn order() {
+        let config = Config {
+            order: Order::Codepoint,
+            ..Config::default()
+        };
+        let variants = |config: &Config| {
+            entries(glyphs(), config)
+                .into_iter()
+                .map(|(variant, _, _)| variant)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            variants(&Config::default()),
+            ["AccSagittal11LargeDiesisDown", "NoteheadBlack"]
+        );
+        assert_eq!(
+            variants(&config),
+            ["NoteheadBlack", "AccSagittal11LargeDiesisDown"]
+        );
+    }
+
+    #[test]
+    fn stable_output() {
+        let output = super::generate(glyphs(), &Config::default());
+
+        // Each `HashMap` has its own random iteration order.
+        for _ in 0..10 {
+            assert_eq!(super::generate(glyphs(), &Config::default()), output);
+        }
+    }
+
+    #[test]
+    fn spec_docs() {
+        let config = Config {
+            spec_docs: true,
+            ..Config::default()
+        };
+        let glyphs = entries(glyphs(), &config);
+        let ranges = HashMap::from([(
+            "noteheadBlack",
+            RangeLink {
+                name: "noteheadSetDefault",
+                description: "Noteheads [default]",
+            },
+        )]);
+        let output = glyph_code(&glyphs, &ranges, &config);
+
+        for expected in [
+            "    /// 3° down \\[46 EDO\\]\n    ///\n    /// `accSagittal11LargeDiesisDown` (U+E30D).\n",
+            "    /// Black notehead\n    ///\n    /// `noteheadBlack` (U+E0A4), in [Noteheads \\[default\\]](https://w3c.github.io/smufl/latest/tables/notehead-set-default.html).\n",
+        ] {
+            assert!(output.contains(expected), "missing {expected:?} in {output}");
+        }
+    }
+
+    #[test]
+    fn tests() {
+        let output = super::generate(glyphs(), &Config::default());
+
+        for expected in [
+            "#[cfg(test)]\nmod generated_tests {",
+            "    static GLYPHS: &[Glyph] = super::GLYPHS;",
+            "fn names_round_trip()",
+            "fn codepoints_are_unique()",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+        assert!(!output.contains("fn codepoints_are_in_ranges()"));
+    }
+
+    #[test]
+    fn table_names() {
+        let config = Config {
+            enum_name: "SmuflGlyph".to_owned(),
+            ..Config::default()
+        };
+        let output = super::generate(glyphs(), &config);
+
+        for expected in [
+            "pub static SMUFL_GLYPHS: &[SmuflGlyph] = &[",
+            "pub static SMUFL_GLYPH_DESCRIPTIONS: &[&str] = &[",
+            "        SMUFL_GLYPH_CODEPOINTS[*self as usize]",
+            "    static GLYPHS: &[SmuflGlyph] = super::SMUFL_GLYPHS;",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+    }
+
+    #[test]
+    fn glyph_set() {
+        let config = Config {
+            glyph_set: true,
+            ..Config::default()
+        };
+        let output = super::generate(glyphs(), &config);
+
+        for expected in [
+            "pub struct GlyphSet([u64; 1]);",
+            "pub const fn insert(&mut self, glyph: Glyph) -> bool {",
+            "impl FromIterator<Glyph> for GlyphSet {",
+            "fn glyph_set_holds_every_glyph()",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+        assert!(!super::generate(glyphs(), &Config::default()).contains("GlyphSet"));
+    }
+
+    #[test]
+    fn serde_feature() {
+        let config = Config {
+            module: Some("glyphs".to_owned()),
+            serde_feature: Some("serde".to_owned()),
+            ..Config::default()
+        };
+        let output = super::generate(glyphs(), &config);
+
+        for expected in [
+            "    #[cfg(feature = \"serde\")]\n    use serde::{Deserialize, Serialize};",
+            "    #[cfg(feature = \"serde\")]\n    impl<'de> Deserialize<'de> for Glyph {",
+            "    #[cfg(feature = \"serde\")]\n    impl Serialize for Glyph {",
+            "        #[cfg(feature = \"serde\")]\n        #[test]\n        fn names_round_trip() {",
+        ] {
+            assert!(
+                output.contains(expected),
+                "missing {expected:?} in {output}"
+            );
+        }
+        assert!(output.contains("    impl Glyph {\n        /// Each variant by its name"));
+    }
+
+    #[test]
+    fn glyph_set_words() {
+        assert_eq!(super::glyph_set_words([].into_iter(), 0), "[0]");
+        assert_eq!(
+            super::glyph_set_words([0, 3, 65].into_iter(), 130),
+            "[0x9, 0x2, 0]"
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// A glyph, as described in `glyphnames.json`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlyphDefinition {
+    pub codepoint: Codepoint,
+    pub alternate_codepoint: Option<Codepoint>,
+    pub description: String,
+
+    /// The feature the glyph is gated behind, when generating for several
+    /// SMuFL versions and the glyph isn't in the earliest.
+    #[serde(skip)]
+    pub feature: Option<String>,
+}
+
+impl GlyphDefinition {
+    /// Loads glyph definitions from `reader`, failing if a glyph name is
+    /// repeated or two glyphs share a codepoint.
+    pub fn from_reader(reader: impl Read) -> Result<HashMap<String, Self>> {
+        let Definitions(glyphs) = serde_json::from_reader(reader)?;
+        check_codepoints(&glyphs, &HashMap::new())?;
+
+        Ok(glyphs)
+    }
+
+    /// Loads glyph definitions from `path`, or from stdin if `path` is `-`.
+    pub fn from_path(path: &Path) -> Result<HashMap<String, Self>> {
+        if path == Path::new("-") {
+            return Self::from_reader(io::stdin().lock()).context("failed to read stdin");
+        }
+
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+        Self::from_reader(BufReader::new(file))
+            .with_context(|| format!("failed to read {}", path.display()))
+    }
+
+    /// Loads and merges glyph definitions from each of `paths`, failing if a
+    /// glyph name or codepoint is used in more than one of them.
+    pub fn from_paths(paths: &[impl AsRef<Path>]) -> Result<HashMap<String, Self>> {
+        let mut glyphs = HashMap::new();
+        let mut sources = HashMap::new();
+
+        for path in paths {
+            let path = path.as_ref();
+
+            for (name, glyph) in Self::from_path(path)? {
+                if let Some(other) = sources.insert(name.clone(), path) {
+                    bail!(
+                        "conflicting glyph definitions: duplicate glyph name `{name}` in {} and {}",
+                        other.display(),
+                        path.display()
+                    );
+                }
+                glyphs.insert(name, glyph);
+            }
+        }
+        check_codepoints(&glyphs, &sources).context("conflicting glyph definitions")?;
+
+        Ok(glyphs)
+    }
+
+    /// Loads glyph definitions for several SMuFL versions, given as pairs of
+    /// versions and paths, oldest first.
+    ///
+    /// Glyphs which aren't in the first version are gated behind the feature
+    /// for the version they first appear in, e.g. `smufl-1-40` for `1.40`.
+    /// When a glyph's definition changes, the latest one is used.
+    pub fn from_versions(versions: &[(String, PathBuf)]) -> Result<HashMap<String, Self>> {
+        let mut glyphs = HashMap::<String, Self>::new();
+        let mut sources = HashMap::new();
+
+        for (index, (version, path)) in versions.iter().enumerate() {
+            for (name, mut glyph) in Self::from_path(path)? {
+                glyph.feature = match glyphs.get(&name) {
+                    Some(existing) => existing.feature.clone(),
+                    None if index == 0 => None,
+                    None => Some(version_feature(version)),
+                };
+                sources.insert(name.clone(), path.as_path());
+                glyphs.insert(name, glyph);
+            }
+        }
+        check_codepoints(&glyphs, &sources).context("conflicting glyph definitions")?;
+
+        Ok(glyphs)
+    }
+}
+
+/// Returns the name of the feature gating glyphs added in SMuFL `version`.
+fn version_feature(version: &str) -> String {
+    format!("smufl-{}", version.replace('.', "-"))
+}
+
+/// Fails if two glyphs in `glyphs` share a codepoint, naming the file each
+/// came from if it's in `sources`.
+fn check_codepoints(
+    glyphs: &HashMap<String, GlyphDefinition>,
+    sources: &HashMap<String, &Path>,
+) -> Result<()> {
+    let mut names = glyphs.keys().collect::<Vec<_>>();
+    names.sort();
+
+    let source = |name: &str| {
+        sources
+            .get(name)
+            .map(|path| format!(" in {}", path.display()))
+            .unwrap_or_default()
+    };
+
+    let mut codepoints = HashMap::new();
+    for name in names {
+        let codepoint = glyphs[name].codepoint.0;
+        if let Some(other) = codepoints.insert(codepoint, name) {
+            bail!(
+                "duplicate codepoint {} for `{other}`{} and `{name}`{}",
+                Codepoint(codepoint).to_hex(),
+                source(other),
+                source(name)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// A map of glyph definitions that rejects repeated glyph names, which
+/// `serde_json` would otherwise silently resolve to the last definition.
+struct Definitions(HashMap<String, GlyphDefinition>);
+
+impl<'de> Deserialize<'de> for Definitions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DefinitionsVisitor;
+
+        impl<'de> Visitor<'de> for DefinitionsVisitor {
+            type Value = Definitions;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a map of glyph names to glyph definitions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut glyphs = HashMap::new();
+
+                while let Some(name) = map.next_key::<String>()? {
+                    match glyphs.entry(name) {
+                        Entry::Occupied(entry) => {
+                            return Err(de::Error::custom(format!(
+                                "duplicate glyph name `{}`",
+                                entry.key()
+                            )))
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(Definitions(glyphs))
+            }
+        }
+
+        deserializer.deserialize_map(DefinitionsVisitor)
+    }
+}
+
+/// A codepoint, written in SMuFL's metadata files as e.g. `U+E0A4`.
+#[derive(Clone, Copy, Debug)]
+pub struct Codepoint(pub char);
+
+impl Codepoint {
+    /// Formats the codepoint as it appears in SMuFL's metadata files, e.g.
+    /// `U+E0A4`.
+    pub fn to_hex(self) -> String {
+        format!("U+{:04X}", u32::from(self.0))
+    }
+}
+
+impl Display for Codepoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        format!("{:?}", self.0).fmt(f)
+    }
+}
+
+impl<'de> Deserialize<'de> for Codepoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Codepoints are serialized as "U+E06", etc.
+        let string: String = Deserialize::deserialize(deserializer)?;
+
+        string
+            .strip_prefix("U+")
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32)
+            .map(Self)
+            .ok_or_else(|| {
+                de::Error::custom(format!(
+                    "invalid codepoint `{string}`, expected a value like `U+E0A4`"
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_paths() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-gen-from-paths-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("glyphnames.json"),
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadWhole": {"codepoint": "U+E0A2", "description": "Whole notehead"}
+            }"#,
+        )?;
+        std::fs::write(
+            dir.join("extension.json"),
+            r#"{
+                "customNotehead": {"codepoint": "U+F400", "description": "Custom notehead"},
+                "customGlyph": {"codepoint": "U+F401", "description": "Custom glyph"}
+            }"#,
+        )?;
+
+        let glyphs =
+            GlyphDefinition::from_paths(&[dir.join("glyphnames.json"), dir.join("extension.json")]);
+        std::fs::remove_dir_all(&dir)?;
+        let glyphs = glyphs?;
+
+        assert_eq!(glyphs.len(), 4);
+        assert_eq!(glyphs["customNotehead"].codepoint.0, '\u{f400}');
+        assert_eq!(glyphs["noteheadWhole"].codepoint.0, '\u{e0a2}');
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_paths_duplicates() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!(
+            "smufl-gen-from-paths-duplicates-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("glyphnames.json"),
+            r#"{"noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"}}"#,
+        )?;
+        std::fs::write(
+            dir.join("name.json"),
+            r#"{"noteheadBlack": {"codepoint": "U+F400", "description": "Custom notehead"}}"#,
+        )?;
+        std::fs::write(
+            dir.join("codepoint.json"),
+            r#"{"customGlyph": {"codepoint": "U+E0A4", "description": "Custom glyph"}}"#,
+        )?;
+
+        let errors = ["name.json", "codepoint.json"].map(|extension| {
+            GlyphDefinition::from_paths(&[dir.join("glyphnames.json"), dir.join(extension)])
+                .map(|_| ())
+                .unwrap_err()
+        });
+        std::fs::remove_dir_all(&dir)?;
+
+        assert_eq!(
+            format!("{:#}", errors[0]),
+            format!(
+                "conflicting glyph definitions: duplicate glyph name `noteheadBlack` in {} and {}",
+                dir.join("glyphnames.json").display(),
+                dir.join("name.json").display()
+            )
+        );
+        assert_eq!(
+            format!("{:#}", errors[1]),
+            format!(
+                "conflicting glyph definitions: duplicate codepoint U+E0A4 for `customGlyph` in {} \
+                 and `noteheadBlack` in {}",
+                dir.join("codepoint.json").display(),
+                dir.join("glyphnames.json").display()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_codepoint() {
+        for codepoint in ["E0A4", "U+", "U+XYZ", "U+D800"] {
+            let json = format!(
+                r#"{{"noteheadBlack": {{"codepoint": "{codepoint}", "description": "Black notehead"}}}}"#
+            );
+            let error = GlyphDefinition::from_reader(json.as_bytes()).unwrap_err();
+
+            assert!(
+                error
+                    .to_string()
+                    .starts_with(&format!("invalid codepoint `{codepoint}`")),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn duplicate_name() {
+        let error = GlyphDefinition::from_reader(
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadBlack": {"codepoint": "U+E0A5", "description": "Black notehead"}
+            }"#
+            .as_bytes(),
+        )
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("duplicate glyph name `noteheadBlack`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn duplicate_codepoint() {
+        let error = GlyphDefinition::from_reader(
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadWhole": {"codepoint": "U+E0A4", "description": "Whole notehead"}
+            }"#
+            .as_bytes(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "duplicate codepoint U+E0A4 for `noteheadBlack` and `noteheadWhole`"
+        );
+    }
+
+    #[test]
+    fn from_versions() -> Result<()> {
+        let dir =
+            std::env::temp_dir().join(format!("smufl-gen-from-versions-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("1.30.json"),
+            r#"{"noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"}}"#,
+        )?;
+        std::fs::write(
+            dir.join("1.40.json"),
+            r#"{
+                "noteheadBlack": {"codepoint": "U+E0A4", "description": "Black notehead"},
+                "noteheadWhole": {"codepoint": "U+E0A2", "description": "Whole notehead"}
+            }"#,
+        )?;
+
+        let glyphs = GlyphDefinition::from_versions(&[
+            ("1.30".to_owned(), dir.join("1.30.json")),
+            ("1.40".to_owned(), dir.join("1.40.json")),
+        ]);
+        std::fs::remove_dir_all(&dir)?;
+        let glyphs = glyphs?;
+
+        assert_eq!(glyphs["noteheadBlack"].feature, None);
+        assert_eq!(
+            glyphs["noteheadWhole"].feature.as_deref(),
+            Some("smufl-1-40")
+        );
+
+        Ok(())
+    }
+}
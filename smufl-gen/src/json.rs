@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{
+    config::Config,
+    generate::entries,
+    glyph_definition::{Codepoint, GlyphDefinition},
+};
+
+/// A glyph as written by [`generate_json`]: the same information the `Glyph`
+/// enum is generated from, normalized for generators in other languages.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct GlyphIr {
+    /// The name of the glyph's variant in the generated enum, e.g.
+    /// `NoteheadBlack`.
+    pub variant: String,
+
+    /// The glyph's name in the metadata, e.g. `noteheadBlack`.
+    pub name: String,
+
+    /// The glyph's codepoint, e.g. `U+E0A4`.
+    pub codepoint: String,
+
+    /// The glyph's codepoint in the Unicode Musical Symbols range, if any.
+    pub alternate_codepoint: Option<String>,
+
+    /// The glyph's description.
+    pub description: String,
+
+    /// The feature the glyph is gated behind, when generating for several
+    /// SMuFL versions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+}
+
+/// Returns the glyphs which aren't excluded by `config`, in the order given by
+/// `config`.
+pub fn glyph_ir(glyphs: HashMap<String, GlyphDefinition>, config: &Config) -> Vec<GlyphIr> {
+    entries(glyphs, config)
+        .into_iter()
+        .map(|(variant, name, glyph)| GlyphIr {
+            variant,
+            name,
+            codepoint: glyph.codepoint.to_hex(),
+            alternate_codepoint: glyph.alternate_codepoint.map(Codepoint::to_hex),
+            description: glyph.description,
+            feature: glyph.feature,
+        })
+        .collect()
+}
+
+/// Generates a JSON array of the glyphs, as described by [`GlyphIr`], instead
+/// of Rust code.
+pub fn generate_json(glyphs: HashMap<String, GlyphDefinition>, config: &Config) -> String {
+    serde_json::to_string_pretty(&glyph_ir(glyphs, config)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_json() {
+        let glyphs = HashMap::from([
+            (
+                "noteheadBlack".to_owned(),
+                GlyphDefinition {
+                    codepoint: Codepoint('\u{e0a4}'),
+                    alternate_codepoint: Some(Codepoint('\u{1d158}')),
+                    description: "Black notehead".to_owned(),
+                    feature: None,
+                },
+            ),
+            (
+                "flag8thUp".to_owned(),
+                GlyphDefinition {
+                    codepoint: Codepoint('\u{e240}'),
+                    alternate_codepoint: None,
+                    description: "Combining flag 1 (8th) above".to_owned(),
+                    feature: Some("smufl-1-40".to_owned()),
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            super::generate_json(glyphs, &Config::default()),
+            r#"[
+  {
+    "variant": "Flag8thUp",
+    "name": "flag8thUp",
+    "codepoint": "U+E240",
+    "alternate_codepoint": null,
+    "description": "Combining flag 1 (8th) above",
+    "feature": "smufl-1-40"
+  },
+  {
+    "variant": "NoteheadBlack",
+    "name": "noteheadBlack",
+    "codepoint": "U+E0A4",
+    "alternate_codepoint": "U+1D158",
+    "description": "Black notehead"
+  }
+]"#
+        );
+    }
+}
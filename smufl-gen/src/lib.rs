@@ -0,0 +1,60 @@
+//! Generates the `Glyph` enum from SMuFL's `glyphnames.json`.
+//!
+//! This is the library behind the `smufl-gen` binary, for regenerating glyph
+//! tables from a build script:
+//!
+//! ```no_run
+//! use std::{env, fs, path::Path};
+//!
+//! use smufl_gen::{generate, Config, GlyphDefinition};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let glyphs = GlyphDefinition::from_path(Path::new("metadata/glyphnames.json"))?;
+//! let config = Config {
+//!     tests: false,
+//!     ..Config::default()
+//! };
+//!
+//! let out_dir = env::var("OUT_DIR")?;
+//! fs::write(
+//!     Path::new(&out_dir).join("glyph.rs"),
+//!     generate(glyphs, &config),
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The generated file can then be included with
+//! `include!(concat!(env!("OUT_DIR"), "/glyph.rs"))`.
+//!
+//! The command line interface is behind the default `cli` feature, which build
+//! scripts can turn off to avoid depending on `clap`.
+
+mod checkout;
+mod config;
+mod diff;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+mod generate;
+mod glyph_definition;
+mod json;
+mod modules;
+mod optional_glyphs;
+pub mod output;
+mod scaffold;
+#[cfg(feature = "template")]
+pub mod template;
+mod verify;
+
+pub use crate::{
+    checkout::{Checkout, RangeDefinition},
+    config::{CodepointRange, Config, Order},
+    diff::Diff,
+    generate::generate,
+    glyph_definition::{Codepoint, GlyphDefinition},
+    json::{generate_json, glyph_ir, GlyphIr},
+    modules::{generate_modules, Modules},
+    optional_glyphs::{FontMetadata, OptionalGlyph},
+    scaffold::write_crate,
+    verify::Drift,
+};
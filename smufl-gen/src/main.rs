@@ -0,0 +1,448 @@
+//! Generates the `Glyph` enum from SMuFL's `glyphnames.json`.
+//!
+//! ```text
+//! smufl-gen submodules/smufl/metadata/glyphnames.json > glyph.rs
+//! smufl-gen glyphnames.json extension.json
+//! cat glyphnames.json | smufl-gen -
+//! smufl-gen glyphnames.json --config smufl-gen.toml
+//! smufl-gen glyphnames.json --order codepoint
+//! smufl-gen glyphnames.json --derive strum::EnumIter --attribute '#[non_exhaustive]'
+//! smufl-gen glyphnames.json --output src/glyph.rs --rustfmt
+//! smufl-gen glyphnames.json --output src/glyph.rs --rustfmt --check
+//! smufl-gen glyphnames.json --output src/glyph.rs --rustfmt --split
+//! smufl-gen glyphnames.json --verify src/glyph.rs
+//! smufl-gen glyphnames.json --format json > glyphs.json
+//! smufl-gen glyphnames.json --template glyphs.rs.jinja
+//! smufl-gen --smufl-dir submodules/smufl
+//! smufl-gen --smufl-dir submodules/smufl --spec-docs
+//! smufl-gen --smufl-dir submodules/smufl --glyph-set
+//! smufl-gen --smufl-dir submodules/smufl --range-features
+//! smufl-gen --smufl-version 1.40
+//! smufl-gen --versioned 1.30=glyphnames-1.30.json --versioned 1.40=glyphnames-1.40.json
+//! smufl-gen diff old/glyphnames.json new/glyphnames.json
+//! smufl-gen optional-glyphs bravura_metadata.json
+//! smufl-gen crate vendor/smufl-glyphs --name smufl-glyphs --spec-version 1.3 glyphnames.json
+//! ```
+//!
+//! `--smufl-version`, which downloads `glyphnames.json` from the SMuFL
+//! repository, requires the `fetch` feature, and `--template` requires the
+//! `template` feature.
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use smufl_gen::{
+    generate, generate_json, generate_modules, output, write_crate, Checkout, Config, Diff, Drift,
+    FontMetadata, GlyphDefinition, Modules, Order,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    source: Source,
+
+    /// Write the generated code to this file instead of stdout.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+
+    /// Whether to generate Rust code, or a JSON description of the glyphs for
+    /// generators in other languages.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Format the generated code with `rustfmt`.
+    #[arg(long)]
+    rustfmt: bool,
+
+    /// Instead of writing the output file, check that it's up to date,
+    /// printing a diff and failing if it isn't.
+    #[arg(long, requires = "output")]
+    check: bool,
+
+    /// Split the generated code into modules, which compile separately: the
+    /// enum and its methods go in the output file, and the static tables,
+    /// name lookups and tests go in a directory named after it, e.g.
+    /// `src/glyph/tables.rs` for `src/glyph.rs`.
+    #[arg(long, requires = "output", conflicts_with = "smufl_dir")]
+    split: bool,
+
+    /// Instead of writing the generated code, compare its variants with those
+    /// in this previously generated file, reporting any which are missing,
+    /// extra or changed, and failing if there are any.
+    #[arg(long, value_name = "EXISTING", conflicts_with_all = ["output", "rustfmt"])]
+    verify: Option<PathBuf>,
+
+    /// A MiniJinja template to render instead of generating the `Glyph` enum.
+    /// It's given the config and the glyphs, with the same fields as in the
+    /// JSON output.
+    #[cfg(feature = "template")]
+    #[arg(long, conflicts_with_all = ["format", "verify", "split"])]
+    template: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Renders the template, if any, or otherwise generates the `Glyph` enum.
+    fn generate(&self, config: &Config) -> Result<String> {
+        #[cfg(feature = "template")]
+        if let Some(path) = &self.template {
+            let template = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            return smufl_gen::template::render(&template, self.source.glyphs()?, config);
+        }
+
+        self.source.generate(config)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum Format {
+    /// The `Glyph` enum, and `Range` and `Class` enums when generating from a
+    /// checkout.
+    #[default]
+    Rust,
+
+    /// An array of the glyphs' variant names, names, codepoints, alternate
+    /// codepoints and descriptions.
+    Json,
+}
+
+/// Where to generate code from, and how.
+#[derive(Debug, Args)]
+struct Source {
+    /// `glyphnames.json`-style files to generate from, or `-` for stdin.
+    /// Project-specific extensions can be listed after the standard file, but
+    /// may not reuse a glyph name or codepoint from another file.
+    #[cfg_attr(
+        feature = "fetch",
+        arg(
+            required_unless_present_any = ["smufl_dir", "smufl_version", "versioned"],
+            conflicts_with_all = ["smufl_dir", "smufl_version", "versioned"]
+        )
+    )]
+    #[cfg_attr(
+        not(feature = "fetch"),
+        arg(
+            required_unless_present_any = ["smufl_dir", "versioned"],
+            conflicts_with_all = ["smufl_dir", "versioned"]
+        )
+    )]
+    inputs: Vec<PathBuf>,
+
+    /// A checkout of the SMuFL repository to generate from. `glyphnames.json`,
+    /// `classes.json` and `ranges.json` are read from its `metadata`
+    /// directory, and `Range` and `Class` enums are generated alongside
+    /// `Glyph`.
+    #[arg(long)]
+    smufl_dir: Option<PathBuf>,
+
+    /// A SMuFL version, e.g. `1.40`, whose `glyphnames.json` is downloaded
+    /// from the tagged release in the SMuFL repository and generated from.
+    #[cfg(feature = "fetch")]
+    #[arg(long, conflicts_with_all = ["smufl_dir", "versioned"])]
+    smufl_version: Option<String>,
+
+    /// A SMuFL version and its `glyphnames.json`, e.g.
+    /// `1.40=glyphnames-1.40.json`. May be repeated, oldest version first, to
+    /// generate one enum for all of them, with glyphs added after the first
+    /// version gated behind features like `smufl-1-40`.
+    #[arg(
+        long,
+        value_name = "VERSION=PATH",
+        value_parser = parse_versioned,
+        conflicts_with = "smufl_dir"
+    )]
+    versioned: Vec<(String, PathBuf)>,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+/// Customizations of the generated code.
+#[derive(Debug, Args)]
+struct ConfigArgs {
+    /// A TOML file customizing the generated code: the enum's name,
+    /// visibility, derives and attributes, a module to wrap it in, and
+    /// codepoint ranges to leave out.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// The order of the generated variants, overriding the config file.
+    #[arg(long, value_enum)]
+    order: Option<Order>,
+
+    /// An extra trait for the generated enum to derive, e.g.
+    /// `strum::EnumIter`, in addition to those in the config file. May be
+    /// repeated.
+    #[arg(long = "derive", value_name = "TRAIT")]
+    derives: Vec<String>,
+
+    /// An extra attribute for the generated enum, e.g. `#[non_exhaustive]`, in
+    /// addition to those in the config file. May be repeated.
+    #[arg(long = "attribute", value_name = "ATTRIBUTE")]
+    attributes: Vec<String>,
+
+    /// Add each glyph's name and codepoint to its documentation, along with a
+    /// link to its range's page in the SMuFL specification when generating
+    /// from a checkout.
+    #[arg(long)]
+    spec_docs: bool,
+
+    /// Leave out the test module checking the generated names and codepoints.
+    #[arg(long)]
+    no_tests: bool,
+
+    /// Generate a set type for the glyphs, with one bit per glyph, and with
+    /// constructors for each range and class when generating from a checkout.
+    #[arg(long)]
+    glyph_set: bool,
+
+    /// Gate each glyph behind a Cargo feature for its range, like
+    /// `range-noteheads`, when generating from a checkout. The features are
+    /// listed in a comment at the top of the generated code, and at least one
+    /// of them must be enabled.
+    #[arg(long)]
+    range_features: bool,
+
+    /// Look up glyphs by name with a binary search rather than a perfect hash
+    /// map, so the generated code doesn't depend on the `phf` crate.
+    #[arg(long)]
+    no_phf: bool,
+
+    /// A Cargo feature to gate the serde impls behind, e.g. `serde`, overriding
+    /// the config file.
+    #[arg(long, value_name = "FEATURE")]
+    serde_feature: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Reports the glyphs added, removed and re-pointed (given a different
+    /// codepoint) between two versions of `glyphnames.json`.
+    Diff {
+        /// The old version.
+        old: PathBuf,
+
+        /// The new version.
+        new: PathBuf,
+    },
+
+    /// Writes a standalone crate (`Cargo.toml` and `src/lib.rs`) containing
+    /// the generated code, for vendoring into projects pinned to a particular
+    /// SMuFL version.
+    Crate {
+        /// The directory to write the crate to.
+        dir: PathBuf,
+
+        /// The crate's name.
+        #[arg(long)]
+        name: String,
+
+        /// The SMuFL version the crate is generated from, used in its
+        /// description.
+        #[arg(long)]
+        spec_version: String,
+
+        #[command(flatten)]
+        source: Source,
+    },
+
+    /// Generates an enum of the optional glyphs in a font's metadata file, with
+    /// their names, codepoints and classes.
+    OptionalGlyphs {
+        /// The font's metadata file.
+        metadata: PathBuf,
+
+        /// The name of the generated enum. Defaults to the font's name followed
+        /// by `Glyph`, e.g. `BravuraGlyph`.
+        #[arg(long)]
+        enum_name: Option<String>,
+
+        /// Customizations of the generated code, as for the `Glyph` enum.
+        /// The config file's enum name and excluded ranges are ignored.
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+}
+
+impl ConfigArgs {
+    /// Loads the config file, if any, and applies the overrides to it.
+    fn load(&self) -> Result<Config> {
+        let mut config = match &self.config {
+            Some(path) => Config::from_path(path)?,
+            None => Config::default(),
+        };
+        if let Some(order) = self.order {
+            config.order = order;
+        }
+        for derive in &self.derives {
+            if !config.derives.contains(derive) {
+                config.derives.push(derive.clone());
+            }
+        }
+        config.attributes.extend(self.attributes.iter().cloned());
+        config.spec_docs |= self.spec_docs;
+        config.tests &= !self.no_tests;
+        config.glyph_set |= self.glyph_set;
+        config.range_features |= self.range_features;
+        config.phf &= !self.no_phf;
+        if let Some(feature) = &self.serde_feature {
+            config.serde_feature = Some(feature.clone());
+        }
+
+        Ok(config)
+    }
+}
+
+impl Source {
+    fn generate(&self, config: &Config) -> Result<String> {
+        match &self.smufl_dir {
+            Some(smufl_dir) => Ok(Checkout::from_path(smufl_dir)?.generate(config)),
+            None => Ok(generate(self.glyphs()?, config)),
+        }
+    }
+
+    fn generate_json(&self, config: &Config) -> Result<String> {
+        Ok(generate_json(self.glyphs()?, config))
+    }
+
+    fn glyphs(&self) -> Result<HashMap<String, GlyphDefinition>> {
+        #[cfg(feature = "fetch")]
+        if let Some(version) = &self.smufl_version {
+            return smufl_gen::fetch::glyphnames(version);
+        }
+
+        if !self.versioned.is_empty() {
+            return GlyphDefinition::from_versions(&self.versioned);
+        }
+
+        match &self.smufl_dir {
+            Some(smufl_dir) => Ok(Checkout::from_path(smufl_dir)?.glyphs),
+            None => GlyphDefinition::from_paths(&self.inputs),
+        }
+    }
+}
+
+fn parse_versioned(value: &str) -> Result<(String, PathBuf), String> {
+    match value.split_once('=') {
+        Some((version, path)) if !version.is_empty() && !path.is_empty() => {
+            Ok((version.to_owned(), PathBuf::from(path)))
+        }
+        _ => Err("expected VERSION=PATH, e.g. 1.40=glyphnames.json".to_owned()),
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match &cli.command {
+        Some(Command::Diff { old, new }) => {
+            let old = GlyphDefinition::from_path(old)?;
+            let new = GlyphDefinition::from_path(new)?;
+            print!("{}", Diff::new(&old, &new));
+        }
+        Some(Command::Crate {
+            dir,
+            name,
+            spec_version,
+            source,
+        }) => {
+            let config = source.config.load()?;
+            let code = source.generate(&config)?;
+            write_crate(dir, name, spec_version, &code, &config)?;
+        }
+        Some(Command::OptionalGlyphs {
+            metadata,
+            enum_name,
+            config,
+        }) => {
+            let config = config.load()?;
+            let metadata = FontMetadata::from_path(metadata)?;
+            let enum_name = enum_name.clone().unwrap_or_else(|| metadata.enum_name());
+            println!("{}", metadata.generate(&enum_name, &config));
+        }
+        None => {
+            let config = cli.source.config.load()?;
+
+            if let Format::Json = cli.format {
+                if cli.rustfmt || cli.verify.is_some() {
+                    bail!("--rustfmt and --verify can only be used with --format rust");
+                }
+
+                if cli.split {
+                    bail!("--split can only be used with --format rust");
+                }
+
+                let json = cli.source.generate_json(&config)? + "\n";
+                match &cli.output {
+                    Some(path) if cli.check => output::check(path, &json)?,
+                    Some(path) => output::write(path, &json)?,
+                    None => print!("{json}"),
+                }
+
+                return Ok(());
+            }
+
+            if let Some(path) = cli.output.as_ref().filter(|_| cli.split) {
+                let mut modules = generate_modules(cli.source.glyphs()?, &config);
+                if cli.rustfmt {
+                    modules = modules.try_map(output::rustfmt)?;
+                }
+
+                for (path, code) in modules.files(path) {
+                    if cli.check {
+                        output::check(&path, code)?;
+                    } else {
+                        if let Some(dir) = path.parent() {
+                            fs::create_dir_all(dir)
+                                .with_context(|| format!("failed to create {}", dir.display()))?;
+                        }
+                        output::write(&path, code)?;
+                    }
+                }
+
+                return Ok(());
+            }
+
+            let mut code = cli.generate(&config)? + "\n";
+
+            if let Some(path) = &cli.verify {
+                let mut existing = fs::read_to_string(path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+                // Code generated with `--split` has its code points in the
+                // tables module.
+                if let Ok(tables) = fs::read_to_string(Modules::tables_path(path)) {
+                    existing.push_str(&tables);
+                }
+                let drift = Drift::new(&existing, &code, &config.enum_name);
+                print!("{drift}");
+                if !drift.is_empty() {
+                    bail!("{} is out of date", path.display());
+                }
+
+                return Ok(());
+            }
+
+            if cli.rustfmt {
+                code = output::rustfmt(&code)?;
+            }
+
+            match &cli.output {
+                Some(path) if cli.check => output::check(path, &code)?,
+                Some(path) => output::write(path, &code)?,
+                None => print!("{code}"),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::Config,
+    generate::{
+        add_glyph_enum, add_glyph_impl, add_glyph_set, add_glyph_tables, add_serde_impls,
+        add_tests, entries, serde_import, TableNames,
+    },
+    glyph_definition::GlyphDefinition,
+};
+
+const TABLES_MODULE: &str = "tables";
+const NAMES_MODULE: &str = "names";
+const TESTS_MODULE: &str = "generated_tests";
+
+/// The generated code, split into a root module and submodules in their own
+/// files, as returned by [`generate_modules`].
+#[derive(Debug)]
+pub struct Modules {
+    /// The enum, its methods and set type, declaring the submodules.
+    pub root: String,
+
+    /// Each submodule's name and code.
+    pub submodules: Vec<(&'static str, String)>,
+}
+
+impl Modules {
+    /// Returns the path and code of each file, given the path of the root
+    /// module's file, e.g. `src/glyph.rs`, whose submodules go in `src/glyph`.
+    pub fn files(&self, root: &Path) -> Vec<(PathBuf, &str)> {
+        let dir = root.with_extension("");
+        let mut files = vec![(root.to_owned(), self.root.as_str())];
+        for (name, code) in &self.submodules {
+            files.push((dir.join(format!("{name}.rs")), code.as_str()));
+        }
+
+        files
+    }
+
+    /// Returns the path of the tables module's file, given the path of the
+    /// root module's file, where `--verify` finds the code points.
+    pub fn tables_path(root: &Path) -> PathBuf {
+        root.with_extension("").join(format!("{TABLES_MODULE}.rs"))
+    }
+
+    /// Applies `f` to the code of every module, e.g. to format it.
+    pub fn try_map<E>(self, mut f: impl FnMut(&str) -> Result<String, E>) -> Result<Self, E> {
+        Ok(Self {
+            root: f(&self.root)?,
+            submodules: self
+                .submodules
+                .into_iter()
+                .map(|(name, code)| Ok((name, f(&code)?)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Generates the same code as [`generate`](crate::generate), split into
+/// modules so that the enum and its methods, the static tables, looking glyphs
+/// up by name, and the tests, are compiled, and recompiled, separately.
+///
+/// The tables are only split out if the enum is `Copy`, since otherwise there
+/// are none. `config.module` is ignored, since the root module's file is
+/// already a module.
+pub fn generate_modules(glyphs: HashMap<String, GlyphDefinition>, config: &Config) -> Modules {
+    let glyphs = entries(glyphs, config);
+    let enum_name = &config.enum_name;
+    let mut submodules = Vec::new();
+
+    let mut names = String::new();
+    add_serde_impls(
+        &mut names,
+        enum_name,
+        config,
+        glyphs
+            .iter()
+            .map(|(variant, name, glyph)| (variant, name, glyph.feature.as_deref())),
+    );
+    if !names.is_empty() {
+        let mut code = String::new();
+        if let Some(import) = serde_import(config) {
+            writeln!(code, "{import}\n").unwrap();
+        }
+        writeln!(code, "use super::{enum_name};\n\n{}", names.trim_start()).unwrap();
+        submodules.push((NAMES_MODULE, code));
+    }
+
+    if config.is_copy() {
+        let mut code = format!("use super::{enum_name};\n\n");
+        add_glyph_tables(&glyphs, config, &mut code);
+        submodules.push((TABLES_MODULE, code));
+    }
+
+    let mut tests = String::new();
+    add_tests(&glyphs, false, config, &mut tests);
+    if let Some(code) = module_body(&tests, TESTS_MODULE) {
+        submodules.push((TESTS_MODULE, code));
+    }
+
+    // In the order `rustfmt` sorts them.
+    submodules.sort_by_key(|(name, _)| *name);
+
+    let mut root = String::new();
+    for (name, _) in &submodules {
+        if *name == TESTS_MODULE {
+            writeln!(root, "#[cfg(test)]").unwrap();
+        }
+        writeln!(root, "mod {name};").unwrap();
+    }
+    if !submodules.is_empty() {
+        root.push('\n');
+    }
+    if config.is_copy() {
+        let tables = TableNames::new(config);
+        writeln!(
+            root,
+            "{} use self::{TABLES_MODULE}::{{\n    {}, {}, {}, {}, {},\n}};\n",
+            config.visibility,
+            tables.variants,
+            tables.codepoints,
+            tables.alternate_codepoints,
+            tables.names,
+            tables.descriptions
+        )
+        .unwrap();
+    }
+    add_glyph_enum(&glyphs, &HashMap::new(), config, &mut root);
+    root.push('\n');
+    add_glyph_impl(&glyphs, config, &mut root);
+    add_glyph_set(glyphs.len(), config, &mut root);
+    root.push('\n');
+
+    Modules { root, submodules }
+}
+
+/// Returns the contents of the inline module named `name` in `code`,
+/// unindented, for writing to its own file.
+fn module_body(code: &str, name: &str) -> Option<String> {
+    let (_, body) = code.split_once(&format!("mod {name} {{\n"))?;
+    let body = body.trim_end().strip_suffix('}')?;
+
+    let mut output = String::new();
+    for line in body.lines() {
+        writeln!(output, "{}", line.strip_prefix("    ").unwrap_or(line)).unwrap();
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph_definition::Codepoint;
+
+    fn glyphs() -> HashMap<String, GlyphDefinition> {
+        HashMap::from([(
+            "noteheadBlack".to_owned(),
+            GlyphDefinition {
+                codepoint: Codepoint('\u{e0a4}'),
+                alternate_codepoint: None,
+                description: "Black notehead".to_owned(),
+                feature: None,
+            },
+        )])
+    }
+
+    #[test]
+    fn generate_modules() {
+        let modules = super::generate_modules(glyphs(), &Config::default());
+        let files = modules.files(Path::new("src/glyph.rs"));
+
+        assert_eq!(
+            files.iter().map(|(path, _)| path).collect::<Vec<_>>(),
+            [
+                Path::new("src/glyph.rs"),
+                Path::new("src/glyph/generated_tests.rs"),
+                Path::new("src/glyph/names.rs"),
+                Path::new("src/glyph/tables.rs"),
+            ]
+        );
+        assert!(modules.root.starts_with(
+            "#[cfg(test)]\nmod generated_tests;\nmod names;\nmod tables;\n\npub use self::tables::{\n"
+        ));
+        assert!(modules
+            .root
+            .contains("        GLYPH_CODEPOINTS[*self as usize]"));
+        assert!(files[1]
+            .1
+            .starts_with("use std::collections::HashSet;\n\nuse super::*;\n"));
+        assert!(files[2].1.starts_with(
+            "use serde::{Deserialize, Serialize};\n\nuse super::Glyph;\n\nimpl Glyph {"
+        ));
+        assert!(files[3]
+            .1
+            .starts_with("use super::Glyph;\n\n/// Every [`Glyph`]"));
+    }
+
+    #[test]
+    fn generate_modules_without_copy() {
+        let config = Config {
+            derives: vec!["Clone".to_owned()],
+            tests: false,
+            ..Config::default()
+        };
+        let modules = super::generate_modules(glyphs(), &config);
+
+        assert!(modules.submodules.is_empty());
+        assert!(modules.root.starts_with("/// `Glyph` is generated"));
+        assert!(modules.root.contains("Self::NoteheadBlack => '\\u{e0a4}',"));
+    }
+}
@@ -0,0 +1,197 @@
+use std::{collections::HashMap, fmt::Write, fs::File, io::BufReader, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::{
+    config::Config,
+    generate::{add_enum, add_fn, add_serde_impls, escape, finish, sort, variant_name},
+    glyph_definition::Codepoint,
+};
+
+/// The parts of a font's metadata file describing its optional glyphs.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontMetadata {
+    pub font_name: String,
+
+    #[serde(default)]
+    pub optional_glyphs: HashMap<String, OptionalGlyph>,
+}
+
+/// A glyph in the font's private use area, outside the SMuFL specification.
+#[derive(Debug, Deserialize)]
+pub struct OptionalGlyph {
+    pub codepoint: Codepoint,
+
+    #[serde(default)]
+    pub classes: Vec<String>,
+
+    pub description: Option<String>,
+}
+
+impl FontMetadata {
+    /// Reads a font's metadata file from `path`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+        serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("failed to read {}", path.display()))
+    }
+
+    /// The default name of the generated enum, e.g. `BravuraGlyph`.
+    pub fn enum_name(&self) -> String {
+        format!("{}Glyph", variant_name(&identifier(&self.font_name)))
+    }
+
+    /// Generates an enum of the font's optional glyphs named `enum_name`, with
+    /// methods returning each glyph's name, codepoint and classes.
+    pub fn generate(self, enum_name: &str, config: &Config) -> String {
+        let mut glyphs = self
+            .optional_glyphs
+            .into_iter()
+            .map(|(name, glyph)| (variant_name(&identifier(&name)), name, glyph))
+            .collect::<Vec<_>>();
+        sort(&mut glyphs, config.order, |glyph| glyph.codepoint.0);
+
+        let mut output = String::new();
+        add_enum(
+            &mut output,
+            &format!(
+                "The optional glyphs in {}, generated from its metadata.",
+                self.font_name
+            ),
+            enum_name,
+            config,
+            glyphs.iter().map(|(variant, name, glyph)| {
+                (
+                    variant,
+                    None,
+                    escape(glyph.description.as_deref().unwrap_or(name.as_str())),
+                )
+            }),
+        );
+        output.push('\n');
+
+        writeln!(output, "impl {enum_name} {{").unwrap();
+        add_fn(
+            &mut output,
+            &config.visibility,
+            "The glyph's name in the font's metadata",
+            "name",
+            "&'static str",
+            glyphs
+                .iter()
+                .map(|(variant, name, _)| (variant, format!("{name:?}"))),
+        );
+        output.push('\n');
+        add_fn(
+            &mut output,
+            &config.visibility,
+            "The glyph's code point in the font",
+            "codepoint",
+            "char",
+            glyphs
+                .iter()
+                .map(|(variant, _, glyph)| (variant, glyph.codepoint.to_string())),
+        );
+        output.push('\n');
+        add_fn(
+            &mut output,
+            &config.visibility,
+            "The classes the glyph belongs to",
+            "classes",
+            "&'static [&'static str]",
+            glyphs
+                .iter()
+                .map(|(variant, _, glyph)| (variant, format!("&{:?}", glyph.classes))),
+        );
+        write!(output, "}}").unwrap();
+        add_serde_impls(
+            &mut output,
+            enum_name,
+            config,
+            glyphs
+                .iter()
+                .map(|(variant, name, _)| (variant, name, None)),
+        );
+
+        finish(output, config)
+    }
+}
+
+/// Replaces characters which can't appear in identifiers, like the `.` in
+/// stylistic alternates' names (e.g. `gClef.ss01`), with underscores.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate() -> Result<()> {
+        let metadata: FontMetadata = serde_json::from_str(
+            r#"{
+                "fontName": "Bravura",
+                "optionalGlyphs": {
+                    "gClef.ss01": {"codepoint": "U+F4A0", "classes": ["clefs"]},
+                    "noteheadBlackSmall": {
+                        "codepoint": "U+F4A1",
+                        "description": "Small black notehead"
+                    }
+                }
+            }"#,
+        )?;
+        let config = Config {
+            derives: vec!["Clone".to_owned()],
+            attributes: Vec::new(),
+            ..Config::default()
+        };
+
+        assert_eq!(metadata.enum_name(), "BravuraGlyph");
+        assert_eq!(
+            metadata.generate("BravuraGlyph", &config),
+            r#"/// The optional glyphs in Bravura, generated from its metadata.
+#[derive(Clone)]
+pub enum BravuraGlyph {
+    /// gClef.ss01
+    GClefSs01,
+    /// Small black notehead
+    NoteheadBlackSmall,
+}
+
+impl BravuraGlyph {
+    /// The glyph's name in the font's metadata
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GClefSs01 => "gClef.ss01",
+            Self::NoteheadBlackSmall => "noteheadBlackSmall",
+        }
+    }
+
+    /// The glyph's code point in the font
+    pub fn codepoint(&self) -> char {
+        match self {
+            Self::GClefSs01 => '\u{f4a0}',
+            Self::NoteheadBlackSmall => '\u{f4a1}',
+        }
+    }
+
+    /// The classes the glyph belongs to
+    pub fn classes(&self) -> &'static [&'static str] {
+        match self {
+            Self::GClefSs01 => &["clefs"],
+            Self::NoteheadBlackSmall => &[],
+        }
+    }
+}"#
+        );
+
+        Ok(())
+    }
+}
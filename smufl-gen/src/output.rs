@@ -0,0 +1,92 @@
+//! Writing generated code, checking that it's up to date, and formatting it.
+
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+};
+
+use anyhow::{bail, Context, Result};
+use similar::TextDiff;
+
+/// Writes `code` to `path`.
+pub fn write(path: &Path, code: &str) -> Result<()> {
+    fs::write(path, code).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Fails if `path` doesn't already contain `code`, printing a diff of the
+/// changes which regenerating it would make.
+pub fn check(path: &Path, code: &str) -> Result<()> {
+    let existing =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+
+    if existing != code {
+        let path = path.display().to_string();
+        eprint!(
+            "{}",
+            TextDiff::from_lines(existing.as_str(), code)
+                .unified_diff()
+                .header(&path, "generated")
+        );
+        bail!("{path} is out of date");
+    }
+
+    Ok(())
+}
+
+/// Formats `code` with `rustfmt`, which must be on the `PATH`.
+pub fn rustfmt(code: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("failed to run rustfmt")?;
+
+    // Write on another thread, so rustfmt can't block on a full stdout pipe
+    // while we're still writing its input.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = code.to_owned();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child.wait_with_output().context("failed to run rustfmt")?;
+    writer
+        .join()
+        .unwrap()
+        .context("failed to write to rustfmt")?;
+
+    if !output.status.success() {
+        bail!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    String::from_utf8(output.stdout).context("rustfmt produced invalid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("smufl-gen-check-{}.rs", std::process::id()));
+        write(&path, "pub enum Glyph {}\n")?;
+
+        let up_to_date = super::check(&path, "pub enum Glyph {}\n");
+        let out_of_date = super::check(&path, "pub enum Glyph {\n    NoteheadBlack,\n}\n");
+        fs::remove_file(&path)?;
+
+        up_to_date?;
+        assert_eq!(
+            out_of_date.unwrap_err().to_string(),
+            format!("{} is out of date", path.display())
+        );
+
+        Ok(())
+    }
+}
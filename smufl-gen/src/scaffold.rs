@@ -0,0 +1,199 @@
+use std::{fmt::Write, fs, path::Path};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::{config::Config, generate::serde_import};
+
+/// Writes a standalone crate to `dir`, containing `code` generated with
+/// `config` for SMuFL `smufl_version`.
+///
+/// The crate has a `Cargo.toml` and a `src/lib.rs`, and depends on serde only
+/// if the generated code derives its traits, optionally if they're gated behind
+/// `config.serde_feature`.
+pub fn write_crate(
+    dir: &Path,
+    name: &str,
+    smufl_version: &str,
+    code: &str,
+    config: &Config,
+) -> Result<()> {
+    let src = dir.join("src");
+    fs::create_dir_all(&src).with_context(|| format!("failed to create {}", src.display()))?;
+
+    for (path, contents) in [
+        (
+            dir.join("Cargo.toml"),
+            cargo_toml(name, smufl_version, code, config),
+        ),
+        (src.join("lib.rs"), lib_rs(smufl_version, code, config)),
+    ] {
+        fs::write(&path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn cargo_toml(name: &str, smufl_version: &str, code: &str, config: &Config) -> String {
+    let mut output = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+description = "SMuFL {smufl_version} glyphs, generated by smufl-gen."
+"#
+    );
+
+    // The default attributes derive `Arbitrary` behind an `arbitrary` feature.
+    let arbitrary = config
+        .attributes
+        .iter()
+        .any(|attribute| attribute.contains(r#"feature = "arbitrary""#));
+    let serde = config.serde_derives().next().is_some();
+    let serde_feature = config.serde_feature.as_deref().filter(|_| serde);
+    let phf = code.contains("phf::Map");
+
+    // Glyphs added in later SMuFL versions are gated behind features like
+    // `smufl-1-40`, and glyphs may be gated behind features for their ranges
+    // like `range-noteheads`.
+    let mut glyph_features = Regex::new(r#"#\[cfg\(feature = "((?:smufl|range)-[^"]+)"\)\]"#)
+        .unwrap()
+        .captures_iter(code)
+        .map(|captures| captures[1].to_owned())
+        .collect::<Vec<_>>();
+    glyph_features.sort();
+    glyph_features.dedup();
+    // Every range, and serde, is included unless default features are turned
+    // off.
+    let default_features = serde_feature
+        .into_iter()
+        .chain(
+            glyph_features
+                .iter()
+                .map(String::as_str)
+                .filter(|feature| feature.starts_with("range-")),
+        )
+        .map(|feature| format!("{feature:?}"))
+        .collect::<Vec<_>>();
+
+    if arbitrary || serde_feature.is_some() || !glyph_features.is_empty() {
+        output.push_str("\n[features]\n");
+    }
+    if !default_features.is_empty() {
+        writeln!(output, "default = [{}]", default_features.join(", ")).unwrap();
+    }
+    if arbitrary {
+        output.push_str("arbitrary = [\"dep:arbitrary\"]\n");
+    }
+    if let Some(feature) = serde_feature {
+        writeln!(output, "{feature} = [\"dep:serde\"]").unwrap();
+    }
+    for feature in &glyph_features {
+        writeln!(output, "{feature} = []").unwrap();
+    }
+    if arbitrary || serde || phf {
+        output.push_str("\n[dependencies]\n");
+    }
+    if arbitrary {
+        output.push_str(
+            "arbitrary = { version = \"1.4\", features = [\"derive\"], optional = true }\n",
+        );
+    }
+    if phf {
+        output.push_str("phf = { version = \"0.11\", default-features = false }\n");
+    }
+    if serde {
+        let optional = if serde_feature.is_some() {
+            ", optional = true"
+        } else {
+            ""
+        };
+        writeln!(
+            output,
+            "serde = {{ version = \"1.0\", features = [\"derive\"]{optional} }}"
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+fn lib_rs(smufl_version: &str, code: &str, config: &Config) -> String {
+    let mut output = format!("//! SMuFL {smufl_version} glyphs, generated by `smufl-gen`.\n\n");
+
+    if config.module.is_none() {
+        if let Some(import) = serde_import(config) {
+            writeln!(output, "{import}\n").unwrap();
+        }
+    }
+
+    writeln!(output, "{code}").unwrap();
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_crate() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-gen-scaffold-{}", std::process::id()));
+
+        let code = "pub enum Glyph {\n    #[cfg(feature = \"smufl-1-40\")]\n    NoteheadBlack,\n}";
+        let result = super::write_crate(&dir, "smufl-glyphs", "1.4", code, &Config::default());
+        let cargo_toml = fs::read_to_string(dir.join("Cargo.toml"));
+        let lib_rs = fs::read_to_string(dir.join("src/lib.rs"));
+        fs::remove_dir_all(&dir)?;
+        result?;
+
+        let cargo_toml = cargo_toml?;
+        assert!(cargo_toml.contains("name = \"smufl-glyphs\""));
+        assert!(cargo_toml.contains("description = \"SMuFL 1.4 glyphs, generated by smufl-gen.\""));
+        assert!(cargo_toml.contains("arbitrary = [\"dep:arbitrary\"]"));
+        assert!(cargo_toml.contains("smufl-1-40 = []"));
+        assert!(cargo_toml.contains("serde = "));
+        assert_eq!(
+            lib_rs?,
+            "//! SMuFL 1.4 glyphs, generated by `smufl-gen`.
+
+use serde::{Deserialize, Serialize};
+
+pub enum Glyph {
+    #[cfg(feature = \"smufl-1-40\")]
+    NoteheadBlack,
+}
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_crate_with_serde_feature() -> Result<()> {
+        let dir =
+            std::env::temp_dir().join(format!("smufl-gen-scaffold-serde-{}", std::process::id()));
+        let config = Config {
+            attributes: Vec::new(),
+            serde_feature: Some("serde".to_owned()),
+            ..Config::default()
+        };
+
+        let code = "pub enum Glyph {\n    NoteheadBlack,\n}";
+        let result = super::write_crate(&dir, "smufl-glyphs", "1.4", code, &config);
+        let cargo_toml = fs::read_to_string(dir.join("Cargo.toml"));
+        let lib_rs = fs::read_to_string(dir.join("src/lib.rs"));
+        fs::remove_dir_all(&dir)?;
+        result?;
+
+        let cargo_toml = cargo_toml?;
+        assert!(cargo_toml.contains("default = [\"serde\"]"));
+        assert!(cargo_toml.contains("serde = [\"dep:serde\"]"));
+        assert!(cargo_toml.contains("features = [\"derive\"], optional = true"));
+        assert!(
+            lib_rs?.contains("#[cfg(feature = \"serde\")]\nuse serde::{Deserialize, Serialize};")
+        );
+
+        Ok(())
+    }
+}
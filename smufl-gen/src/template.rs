@@ -0,0 +1,117 @@
+//! Rendering user-supplied [MiniJinja](https://docs.rs/minijinja) templates
+//! instead of the built-in generator.
+//!
+//! Templates are given:
+//!
+//! - `enum_name`, `visibility`, `derives` and `attributes` from the config
+//! - `glyphs`, a list of glyphs in the configured order, each with `variant`,
+//!   `name`, `codepoint` (e.g. `U+E0A4`), `alternate_codepoint`, `description`
+//!   and `feature` fields, as in the JSON output
+//!
+//! along with these filters:
+//!
+//! - `rust_char`, turning a codepoint like `U+E0A4` into a Rust `char` literal
+//! - `doc`, escaping square brackets for use in documentation comments
+//! - `snake_case`, `pascal_case`, `camel_case` and `screaming_snake_case`
+//!
+//! ```jinja
+//! pub enum {{ enum_name }} {
+//! {%- for glyph in glyphs %}
+//!     /// {{ glyph.description | doc }}
+//!     {{ glyph.name | screaming_snake_case }},
+//! {%- endfor %}
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use convert_case::{Case, Casing};
+use minijinja::{context, Environment, Error, ErrorKind};
+
+use crate::{config::Config, generate::escape, glyph_definition::GlyphDefinition, json::glyph_ir};
+
+/// Renders `template` for `glyphs`, which are filtered and ordered as
+/// configured in `config`.
+pub fn render(
+    template: &str,
+    glyphs: HashMap<String, GlyphDefinition>,
+    config: &Config,
+) -> Result<String> {
+    let mut environment = Environment::new();
+    environment.add_filter("rust_char", rust_char);
+    environment.add_filter("doc", |text: &str| escape(text));
+    environment.add_filter("snake_case", |text: &str| text.to_case(Case::Snake));
+    environment.add_filter("pascal_case", |text: &str| text.to_case(Case::Pascal));
+    environment.add_filter("camel_case", |text: &str| text.to_case(Case::Camel));
+    environment.add_filter("screaming_snake_case", |text: &str| {
+        text.to_case(Case::UpperSnake)
+    });
+    environment
+        .add_template("template", template)
+        .context("failed to parse template")?;
+
+    environment
+        .get_template("template")?
+        .render(context! {
+            enum_name => config.enum_name,
+            visibility => config.visibility,
+            derives => config.derives,
+            attributes => config.attributes,
+            glyphs => glyph_ir(glyphs, config),
+        })
+        .context("failed to render template")
+}
+
+fn rust_char(codepoint: &str) -> Result<String, Error> {
+    codepoint
+        .strip_prefix("U+")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .and_then(char::from_u32)
+        .map(|codepoint| format!("{codepoint:?}"))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("invalid codepoint `{codepoint}`"),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph_definition::Codepoint;
+
+    #[test]
+    fn render() -> Result<()> {
+        let glyphs = HashMap::from([(
+            "noteheadBlack".to_owned(),
+            GlyphDefinition {
+                codepoint: Codepoint('\u{e0a4}'),
+                alternate_codepoint: None,
+                description: "Black notehead [default]".to_owned(),
+                feature: None,
+            },
+        )]);
+        let template = r#"{{ visibility }} enum {{ enum_name }} {
+{%- for glyph in glyphs %}
+    /// {{ glyph.description | doc }}
+    {{ glyph.name | screaming_snake_case }} = {{ glyph.codepoint | rust_char }},
+{%- endfor %}
+}"#;
+
+        assert_eq!(
+            super::render(template, glyphs, &Config::default())?,
+            "pub enum Glyph {\n    /// Black notehead \\[default\\]\n    NOTEHEAD_BLACK = '\\u{e0a4}',\n}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_invalid() {
+        let error = super::render("{{ glyphs", HashMap::new(), &Config::default()).unwrap_err();
+
+        assert_eq!(error.to_string(), "failed to parse template");
+    }
+}
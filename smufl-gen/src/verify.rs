@@ -0,0 +1,241 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use regex::Regex;
+
+/// The differences between the variants of a previously generated enum and
+/// freshly generated code.
+#[derive(Debug, Default, PartialEq)]
+pub struct Drift {
+    /// Variants which would be added by regenerating.
+    pub missing: Vec<String>,
+
+    /// Variants which would be removed by regenerating.
+    pub extra: Vec<String>,
+
+    /// Variants whose codepoint or documentation would change, with a
+    /// description of the change.
+    pub changed: Vec<(String, String)>,
+}
+
+impl Drift {
+    /// Compares the variants of the enum named `enum_name` in `existing` and
+    /// `generated`.
+    pub fn new(existing: &str, generated: &str, enum_name: &str) -> Self {
+        let existing = variants(existing, enum_name);
+        let generated = variants(generated, enum_name);
+        let mut drift = Self::default();
+
+        for (name, variant) in &generated {
+            let Some(old) = existing.get(name) else {
+                drift.missing.push(name.clone());
+                continue;
+            };
+
+            if old.codepoint != variant.codepoint {
+                drift.changed.push((
+                    name.clone(),
+                    format!(
+                        "codepoint {} -> {}",
+                        old.codepoint.as_deref().unwrap_or("none"),
+                        variant.codepoint.as_deref().unwrap_or("none")
+                    ),
+                ));
+            }
+            if old.doc != variant.doc {
+                drift
+                    .changed
+                    .push((name.clone(), "documentation".to_owned()));
+            }
+        }
+        drift.extra = existing
+            .into_keys()
+            .filter(|name| !generated.contains_key(name))
+            .collect();
+
+        drift
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Display for Drift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "up to date");
+        }
+
+        if !self.missing.is_empty() {
+            writeln!(f, "missing ({}):", self.missing.len())?;
+            for name in &self.missing {
+                writeln!(f, "  + {name}")?;
+            }
+        }
+        if !self.extra.is_empty() {
+            writeln!(f, "extra ({}):", self.extra.len())?;
+            for name in &self.extra {
+                writeln!(f, "  - {name}")?;
+            }
+        }
+        if !self.changed.is_empty() {
+            writeln!(f, "changed ({}):", self.changed.len())?;
+            for (name, change) in &self.changed {
+                writeln!(f, "  ~ {name}: {change}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Variant {
+    doc: String,
+    codepoint: Option<String>,
+}
+
+/// Extracts the variants of the enum named `enum_name` from generated `code`,
+/// along with their documentation and their code points, from either the
+/// code point table or the `match` in `codepoint`.
+fn variants(code: &str, enum_name: &str) -> BTreeMap<String, Variant> {
+    let mut variants = BTreeMap::new();
+    let mut order = Vec::new();
+    let mut lines = code.lines().map(str::trim);
+
+    let enum_start = format!("enum {enum_name} {{");
+    if lines.any(|line| line.ends_with(&enum_start)) {
+        let mut doc = String::new();
+        for line in lines.by_ref().take_while(|line| *line != "}") {
+            if let Some(line) = line.strip_prefix("///") {
+                doc.push_str(line.trim());
+                doc.push('\n');
+            } else if let Some(name) = line.strip_suffix(',') {
+                order.push(name.to_owned());
+                variants.insert(
+                    name.to_owned(),
+                    Variant {
+                        doc: std::mem::take(&mut doc),
+                        codepoint: None,
+                    },
+                );
+            }
+        }
+    }
+
+    // Older versions generated a `CODEPOINTS` const in the `impl` block,
+    // rather than a static like `GLYPH_CODEPOINTS`.
+    let table_start = Regex::new(r"(?:\b|_)CODEPOINTS: &(?:'static )?\[char\] = &\[").unwrap();
+    if let Some(start) = table_start.find(code) {
+        let table = &code[start.end()..];
+        // The table may have been reformatted with several values per line.
+        let table = table.split_once("];").map_or(table, |(table, _)| table);
+        let table = Regex::new(r"#\[[^\]]*\]").unwrap().replace_all(table, "");
+        let codepoints = table
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        for (name, codepoint) in order.iter().zip(codepoints) {
+            variants.get_mut(name).unwrap().codepoint = Some(codepoint.to_owned());
+        }
+
+        return variants;
+    }
+
+    if lines.any(|line| line.contains("fn codepoint(&self)")) {
+        for line in lines.take_while(|line| *line != "}") {
+            let arm = line
+                .strip_prefix("Self::")
+                .and_then(|arm| arm.strip_suffix(','))
+                .and_then(|arm| arm.split_once(" => "));
+            if let Some((name, value)) = arm {
+                if let Some(variant) = variants.get_mut(name) {
+                    variant.codepoint = Some(value.to_owned());
+                }
+            }
+        }
+    }
+
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(variants: &[(&str, &str, &str)]) -> String {
+        let mut code = "#[rustfmt::skip]\npub enum Glyph {\n".to_owned();
+        for (name, doc, _) in variants {
+            code.push_str(&format!("    /// {doc}\n    {name},\n"));
+        }
+        code.push_str(
+            "}\n\nimpl Glyph {\n    pub fn codepoint(&self) -> char {\n        match self {\n",
+        );
+        for (name, _, codepoint) in variants {
+            code.push_str(&format!("            Self::{name} => {codepoint},\n"));
+        }
+        code.push_str("        }\n    }\n}\n");
+
+        code
+    }
+
+    #[test]
+    fn drift() {
+        let existing = code(&[
+            ("NoteheadBlack", "Black notehead", r"'\u{e0a4}'"),
+            ("NoteheadWhole", "Whole notehead", r"'\u{e0a2}'"),
+            ("OldGlyph", "Old glyph", r"'\u{e000}'"),
+        ]);
+        let generated = code(&[
+            ("NewGlyph", "New glyph", r"'\u{e001}'"),
+            ("NoteheadBlack", "Black notehead (new)", r"'\u{e0a4}'"),
+            ("NoteheadWhole", "Whole notehead", r"'\u{e0a3}'"),
+        ]);
+
+        assert_eq!(
+            Drift::new(&existing, &generated, "Glyph").to_string(),
+            r"missing (1):
+  + NewGlyph
+extra (1):
+  - OldGlyph
+changed (2):
+  ~ NoteheadBlack: documentation
+  ~ NoteheadWhole: codepoint '\u{e0a2}' -> '\u{e0a3}'
+"
+        );
+        assert!(Drift::new(&existing, &existing, "Glyph").is_empty());
+    }
+
+    #[test]
+    fn drift_with_codepoint_table() {
+        let existing = code(&[
+            ("NoteheadBlack", "Black notehead", r"'\u{e0a4}'"),
+            ("NoteheadWhole", "Whole notehead", r"'\u{e0a2}'"),
+        ]);
+        let generated = r#"#[rustfmt::skip]
+pub enum Glyph {
+    /// Black notehead
+    NoteheadBlack,
+    /// Whole notehead
+    #[cfg(feature = "smufl-1-40")]
+    NoteheadWhole,
+}
+
+pub static GLYPH_CODEPOINTS: &[char] = &[
+    '\u{e0a4}',
+    #[cfg(feature = "smufl-1-40")] '\u{e0a3}',
+];
+"#;
+
+        assert_eq!(
+            Drift::new(&existing, generated, "Glyph").changed,
+            [(
+                "NoteheadWhole".to_owned(),
+                r"codepoint '\u{e0a2}' -> '\u{e0a3}'".to_owned()
+            )]
+        );
+    }
+}
@@ -0,0 +1,549 @@
+//! Inspect SMuFL metadata from the command line.
+//!
+//! ```text
+//! smufl-inspect glyph bravura_metadata.json noteheadBlack
+//! smufl-inspect validate bravura_metadata.json
+//! smufl-inspect diff old_metadata.json new_metadata.json
+//! smufl-inspect cache bravura_metadata.json -o bravura_metadata.bin
+//! smufl-inspect export bravura_metadata.json --format csv
+//! ```
+
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use smufl::{BoundingBox, Change, Glyph, Metadata, SerializeOptions, Severity, StaffSpaces};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the codepoint, advance width, bounding box and anchors of a
+    /// glyph.
+    Glyph {
+        /// The metadata file to query.
+        metadata: PathBuf,
+
+        /// The glyph's name, e.g. `noteheadBlack`.
+        name: String,
+    },
+
+    /// Converts a JSON metadata file to the binary cache format, or a binary
+    /// cache back to JSON if the output file has a `.json` extension.
+    Cache {
+        /// The metadata file to convert.
+        input: PathBuf,
+
+        /// The file to write.
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Prints a table of every glyph in a metadata file, with its codepoint,
+    /// advance width, bounding box and the anchors it has.
+    Export {
+        /// The metadata file to export.
+        metadata: PathBuf,
+
+        /// The format of the table.
+        #[arg(short, long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+    },
+
+    /// Reports the engraving defaults and glyph metrics which differ between
+    /// two metadata files.
+    Diff {
+        /// The original metadata file.
+        old: PathBuf,
+
+        /// The new metadata file.
+        new: PathBuf,
+    },
+
+    /// Checks a metadata file for problems, exiting with a non-zero status if
+    /// any errors are found.
+    Validate {
+        /// The metadata file to check.
+        metadata: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+}
+
+fn main() -> Result<ExitCode> {
+    run(Cli::parse(), &mut std::io::stdout().lock())
+}
+
+/// Runs the command given by `cli`, writing its output to `out`.
+fn run(cli: Cli, out: &mut impl Write) -> Result<ExitCode> {
+    match cli.command {
+        Command::Glyph { metadata, name } => glyph(&load(&metadata)?, &name, out)?,
+        Command::Cache { input, output } => cache(&input, &output)?,
+        Command::Export { metadata, format } => export(&load(&metadata)?, format, out)?,
+        Command::Diff { old, new } => diff(&load(&old)?, &load(&new)?, out)?,
+        Command::Validate { metadata } => return validate(&load(&metadata)?, out),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn load(path: &Path) -> Result<Metadata> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+    Metadata::from_reader(BufReader::new(file))
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+fn glyph(metadata: &Metadata, name: &str, out: &mut impl Write) -> Result<()> {
+    let glyph = Glyph::from_name(name).with_context(|| format!("unknown glyph: {name}"))?;
+
+    writeln!(out, "{name}")?;
+    writeln!(out, "  codepoint: U+{:04X}", u32::from(glyph.codepoint()))?;
+    if let Some(alternate) = glyph.alternate_codepoint() {
+        writeln!(out, "  alternate codepoint: U+{:04X}", u32::from(alternate))?;
+    }
+
+    match metadata.advance_widths.get(glyph) {
+        Some(width) => writeln!(out, "  advance width: {width}")?,
+        None => writeln!(out, "  advance width: none")?,
+    }
+
+    match metadata.bounding_boxes.get(glyph) {
+        Some(bounding_box) => writeln!(out, "  bounding box: {bounding_box}")?,
+        None => writeln!(out, "  bounding box: none")?,
+    }
+
+    let anchors = metadata
+        .anchors
+        .get(glyph)
+        .map(|anchors| anchors.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    if anchors.is_empty() {
+        writeln!(out, "  anchors: none")?;
+    } else {
+        writeln!(out, "  anchors:")?;
+        for (kind, coord) in anchors {
+            writeln!(out, "    {}: {coord}", kind.name())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate(metadata: &Metadata, out: &mut impl Write) -> Result<ExitCode> {
+    let issues = metadata.validate();
+
+    for issue in &issues {
+        writeln!(out, "{issue}")?;
+    }
+
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .count();
+    let warnings = issues.len() - errors;
+    writeln!(out, "{errors} error(s), {warnings} warning(s)")?;
+
+    Ok(if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn diff(old: &Metadata, new: &Metadata, out: &mut impl Write) -> Result<()> {
+    let diff = old.diff(new);
+
+    if diff.is_empty() {
+        writeln!(out, "no differences")?;
+        return Ok(());
+    }
+
+    if let Some(change) = &diff.font_name {
+        writeln!(out, "font name: {}", format_change(change))?;
+    }
+
+    if diff.text_font_family.is_some() || !diff.engraving_defaults.is_empty() {
+        writeln!(out, "engraving defaults:")?;
+    }
+    if let Some(change) = &diff.text_font_family {
+        writeln!(out, "  textFontFamily: {}", format_change(change))?;
+    }
+    for (name, change) in &diff.engraving_defaults {
+        writeln!(out, "  {name}: {}", format_change(change))?;
+    }
+
+    if !diff.glyphs.is_empty() {
+        writeln!(out, "glyphs:")?;
+    }
+    for glyph in &diff.glyphs {
+        writeln!(out, "  {}", glyph.glyph.name())?;
+
+        if let Some(change) = &glyph.advance_width {
+            match (change.old, change.new) {
+                (Some(old), Some(new)) => writeln!(
+                    out,
+                    "    advance width: {old} -> {new} ({:+.3} sp)",
+                    (new - old).0
+                )?,
+                _ => writeln!(out, "    advance width: {}", format_change(change))?,
+            }
+        }
+        if let Some(change) = &glyph.bounding_box {
+            writeln!(out, "    bounding box: {}", format_change(change))?;
+        }
+        for (anchor, change) in &glyph.anchors {
+            writeln!(out, "    {}: {}", anchor.name(), format_change(change))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn format_change<T: Display>(change: &Change<T>) -> String {
+    let format = |value: &Option<T>| match value {
+        Some(value) => value.to_string(),
+        None => "none".to_owned(),
+    };
+
+    format!("{} -> {}", format(&change.old), format(&change.new))
+}
+
+fn cache(input: &Path, output: &Path) -> Result<()> {
+    let to_json = output
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+    // Read the input completely before touching the output, which may be the
+    // same file.
+    let metadata = if to_json {
+        let file =
+            File::open(input).with_context(|| format!("failed to open {}", input.display()))?;
+        Metadata::from_binary(BufReader::new(file))
+            .with_context(|| format!("failed to read {}", input.display()))?
+    } else {
+        load(input)?
+    };
+
+    // Write to a temporary file next to the output and then rename it, so a
+    // failed write never leaves a truncated output behind.
+    let file_name = output
+        .file_name()
+        .with_context(|| format!("invalid output path {}", output.display()))?;
+    let temp = output.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = write_cache(&metadata, &temp, to_json)
+        .and_then(|()| fs::rename(&temp, output).map_err(Into::into));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+
+    result.with_context(|| format!("failed to write {}", output.display()))
+}
+
+fn write_cache(metadata: &Metadata, path: &Path, to_json: bool) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    if to_json {
+        metadata.to_writer(
+            &mut writer,
+            &SerializeOptions {
+                pretty: true,
+                ..SerializeOptions::default()
+            },
+        )?;
+    } else {
+        metadata.to_binary(&mut writer)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// A row of the table printed by `export`.
+struct Row {
+    name: &'static str,
+    codepoint: char,
+    advance_width: Option<StaffSpaces>,
+    bounding_box: Option<BoundingBox>,
+    anchors: Vec<&'static str>,
+}
+
+fn export(metadata: &Metadata, format: Format, out: &mut impl Write) -> Result<()> {
+    let mut glyphs = metadata
+        .advance_widths
+        .iter()
+        .map(|(glyph, _)| glyph)
+        .chain(metadata.anchors.iter().map(|(glyph, _)| glyph))
+        .chain(metadata.bounding_boxes.iter().map(|(glyph, _)| glyph))
+        .map(|glyph| (glyph.name(), glyph))
+        .collect::<Vec<_>>();
+    glyphs.sort_by_key(|&(name, _)| name);
+    glyphs.dedup_by(|(a, _), (b, _)| a == b);
+
+    let rows = glyphs.into_iter().map(|(name, glyph)| Row {
+        name,
+        codepoint: glyph.codepoint(),
+        advance_width: metadata.advance_widths.get(glyph),
+        bounding_box: metadata.bounding_boxes.get(glyph),
+        anchors: metadata
+            .anchors
+            .get(glyph)
+            .map(|anchors| anchors.iter().map(|(kind, _)| kind.name()).collect())
+            .unwrap_or_default(),
+    });
+
+    match format {
+        Format::Csv => {
+            writeln!(
+                out,
+                "glyph,codepoint,advance_width,bbox_sw_x,bbox_sw_y,bbox_ne_x,bbox_ne_y,anchors"
+            )?;
+            for row in rows {
+                let number = |value: Option<StaffSpaces>| value.map(|value| value.0.to_string());
+                let fields = [
+                    Some(row.name.to_owned()),
+                    Some(format!("U+{:04X}", u32::from(row.codepoint))),
+                    number(row.advance_width),
+                    number(row.bounding_box.map(|bounding_box| bounding_box.sw.x())),
+                    number(row.bounding_box.map(|bounding_box| bounding_box.sw.y())),
+                    number(row.bounding_box.map(|bounding_box| bounding_box.ne.x())),
+                    number(row.bounding_box.map(|bounding_box| bounding_box.ne.y())),
+                    Some(row.anchors.join(";")),
+                ];
+                writeln!(out, "{}", fields.map(Option::unwrap_or_default).join(","))?;
+            }
+        }
+        Format::Json => {
+            let rows = rows
+                .into_iter()
+                .map(|row| {
+                    serde_json::json!({
+                        "glyph": row.name,
+                        "codepoint": format!("U+{:04X}", u32::from(row.codepoint)),
+                        "advanceWidth": row.advance_width,
+                        "bBox": row.bounding_box,
+                        "anchors": row.anchors,
+                    })
+                })
+                .collect::<Vec<_>>();
+            serde_json::to_writer_pretty(&mut *out, &rows)?;
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+    use rstest::*;
+
+    use super::*;
+
+    const OLD: &str = r#"{
+        "fontName": "Test",
+        "engravingDefaults": {"stemThickness": 0.12, "textFontFamily": ["serif"]},
+        "glyphAdvanceWidths": {"noteheadBlack": 1.18, "noteheadWhole": 1.836},
+        "glyphsWithAnchors": {
+            "noteheadBlack": {"stemUpSE": [1.18, 0.168], "stemDownNW": [0, -0.168]}
+        },
+        "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]}}
+    }"#;
+
+    const NEW: &str = r#"{
+        "fontName": "Test 2",
+        "engravingDefaults": {"stemThickness": 0.13, "textFontFamily": ["sans-serif"]},
+        "glyphAdvanceWidths": {"noteheadBlack": 1.2},
+        "glyphsWithAnchors": {"noteheadBlack": {"stemUpSE": [1.2, 0.168]}},
+        "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [1.2, 0.5], "bBoxSW": [0, -0.5]}}
+    }"#;
+
+    fn metadata(json: &str) -> Metadata {
+        Metadata::from_reader(json.as_bytes()).unwrap()
+    }
+
+    fn output<T>(command: impl FnOnce(&mut Vec<u8>) -> Result<T>) -> String {
+        let mut out = Vec::new();
+        command(&mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse_glyph_args() {
+        let cli = Cli::try_parse_from(["smufl-inspect", "glyph", "metadata.json", "noteheadBlack"])
+            .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Glyph { metadata, name }
+                if metadata == Path::new("metadata.json") && name == "noteheadBlack"
+        ));
+    }
+
+    #[test]
+    fn parse_export_args() {
+        let cli = Cli::try_parse_from(["smufl-inspect", "export", "metadata.json", "-f", "json"])
+            .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Export {
+                metadata,
+                format: Format::Json,
+            } if metadata == Path::new("metadata.json")
+        ));
+    }
+
+    #[rstest]
+    #[case::missing_output(&["smufl-inspect", "cache", "metadata.json"])]
+    #[case::unknown_format(&["smufl-inspect", "export", "metadata.json", "-f", "xml"])]
+    #[case::missing_new(&["smufl-inspect", "diff", "old.json"])]
+    #[case::missing_glyph_metadata(&["smufl-inspect", "glyph", "noteheadBlack"])]
+    #[case::metadata_before_command(&["smufl-inspect", "metadata.json", "glyph", "noteheadBlack"])]
+    #[case::unknown_command(&["smufl-inspect", "render", "metadata.json"])]
+    fn parse_invalid_args(#[case] args: &[&str]) {
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+
+    #[test]
+    fn glyph() {
+        insta::assert_snapshot!(output(|out| super::glyph(
+            &metadata(OLD),
+            "noteheadBlack",
+            out
+        )));
+    }
+
+    #[test]
+    fn glyph_unknown() {
+        let error = super::glyph(&metadata(OLD), "notAGlyph", &mut Vec::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown glyph: notAGlyph");
+    }
+
+    #[test]
+    fn validate() {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]}}
+        }"#;
+        let mut exit_code = ExitCode::SUCCESS;
+
+        insta::assert_snapshot!(output(|out| {
+            exit_code = super::validate(&metadata(json), out)?;
+            Ok(())
+        }));
+        assert_eq!(exit_code, ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn diff() {
+        insta::assert_snapshot!(output(|out| super::diff(
+            &metadata(OLD),
+            &metadata(NEW),
+            out
+        )));
+    }
+
+    #[test]
+    fn diff_none() {
+        assert_eq!(
+            output(|out| super::diff(&metadata(OLD), &metadata(OLD), out)),
+            "no differences\n"
+        );
+    }
+
+    #[test]
+    fn export_csv() {
+        insta::assert_snapshot!(output(|out| super::export(
+            &metadata(OLD),
+            Format::Csv,
+            out
+        )));
+    }
+
+    #[test]
+    fn export_json() {
+        insta::assert_snapshot!(output(|out| super::export(
+            &metadata(OLD),
+            Format::Json,
+            out
+        )));
+    }
+
+    #[test]
+    fn cache() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-inspect-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("metadata.json"), OLD)?;
+
+        super::cache(&dir.join("metadata.json"), &dir.join("metadata.bin"))?;
+        super::cache(&dir.join("metadata.bin"), &dir.join("roundtrip.json"))?;
+        let roundtrip = load(&dir.join("roundtrip.json"));
+        std::fs::remove_dir_all(&dir)?;
+
+        assert_eq!(
+            output(|out| super::export(&roundtrip?, Format::Csv, out)),
+            output(|out| super::export(&metadata(OLD), Format::Csv, out))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_invalid_input() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!(
+            "smufl-inspect-cache-invalid-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("metadata.json"), OLD)?;
+        std::fs::write(dir.join("invalid.json"), "{")?;
+        std::fs::write(dir.join("metadata.bin"), "existing")?;
+
+        let invalid = super::cache(&dir.join("invalid.json"), &dir.join("metadata.bin"));
+        // Reading JSON as a binary cache fails, and must not truncate the file
+        // being read.
+        let in_place = super::cache(&dir.join("metadata.json"), &dir.join("metadata.json"));
+        let bin = std::fs::read_to_string(dir.join("metadata.bin"));
+        let json = std::fs::read_to_string(dir.join("metadata.json"));
+        let files = std::fs::read_dir(&dir)?.count();
+        std::fs::remove_dir_all(&dir)?;
+
+        assert!(invalid.is_err());
+        assert!(in_place.is_err());
+        assert_eq!(bin?, "existing");
+        assert_eq!(json?, OLD);
+        assert_eq!(files, 3);
+
+        Ok(())
+    }
+}
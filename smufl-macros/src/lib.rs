@@ -0,0 +1,121 @@
+//! Generates the `Glyph` enum, and its static tables, from SMuFL's
+//! `glyphnames.json` at compile time.
+//!
+//! ```ignore
+//! use serde::{Deserialize, Serialize};
+//!
+//! smufl_macros::smufl_glyphs!("metadata/glyphnames.json");
+//!
+//! assert_eq!(Glyph::NoteheadBlack.codepoint(), '\u{E0A4}');
+//! ```
+//!
+//! The generated code is the same as `smufl-gen`'s, without its test module
+//! and attributes, so the serde traits must be in scope where the macro is
+//! used.
+
+use std::{env, path::PathBuf};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use smufl_gen::{generate, Config, GlyphDefinition};
+use syn::{parse::Parser, punctuated::Punctuated, Error, LitStr, Result, Token};
+
+/// Generates the `Glyph` enum from one or more `glyphnames.json`-style files,
+/// given relative to the crate's `Cargo.toml`.
+///
+/// Project-specific extensions can be listed after the standard file, but may
+/// not reuse a glyph name or codepoint from another file. The crate is rebuilt
+/// when any of the files change.
+#[proc_macro]
+pub fn smufl_glyphs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream> {
+    let literals = Punctuated::<LitStr, Token![,]>::parse_terminated.parse2(input)?;
+    if literals.is_empty() {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "expected the path of a `glyphnames.json` file",
+        ));
+    }
+
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let paths = literals
+        .iter()
+        .map(|literal| match &manifest_dir {
+            Some(dir) => dir.join(literal.value()),
+            None => PathBuf::from(literal.value()),
+        })
+        .collect::<Vec<_>>();
+
+    let glyphs = GlyphDefinition::from_paths(&paths)
+        .map_err(|error| Error::new(literals[0].span(), format!("{error:#}")))?;
+    // The default attributes would refer to the `arbitrary` feature of the
+    // crate using the macro, which it probably doesn't have, and the perfect
+    // hash map would need it to depend on `phf`.
+    let config = Config {
+        attributes: Vec::new(),
+        tests: false,
+        phf: false,
+        ..Config::default()
+    };
+    let code = generate(glyphs, &config)
+        .parse::<TokenStream>()
+        .map_err(|error| Error::new(literals[0].span(), error))?;
+
+    // Including the files makes Cargo rebuild the crate when they change.
+    let paths = paths.iter().map(|path| path.to_string_lossy());
+    Ok(quote! {
+        #(const _: &str = include_str!(#paths);)*
+        #code
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn expand() {
+        let path = env::temp_dir().join(format!("smufl-macros-{}.json", std::process::id()));
+        fs::write(
+            &path,
+            r#"{ "noteheadBlack": { "codepoint": "U+E0A4", "description": "Black notehead" } }"#,
+        )
+        .unwrap();
+        let path = path.to_string_lossy();
+
+        let code = super::expand(quote!(#path)).unwrap().to_string();
+        fs::remove_file(&*path).unwrap();
+
+        assert!(code.contains("include_str !"));
+        assert!(code.contains("pub enum Glyph"));
+        assert!(code.contains("NoteheadBlack"));
+        assert!(!code.contains("generated_tests"));
+        assert!(!code.contains("arbitrary"));
+    }
+
+    #[test]
+    fn expand_missing_file() {
+        let error = super::expand(quote!("missing/glyphnames.json")).unwrap_err();
+
+        assert!(error.to_string().starts_with("failed to open "));
+    }
+
+    #[test]
+    fn expand_no_paths() {
+        let error = super::expand(quote!()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "expected the path of a `glyphnames.json` file"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+/// Identifies one of the anchors in [`Anchors`](crate::Anchors), for use with
+/// [`Anchors::get`](crate::Anchors::get) and
+/// [`Anchors::iter`](crate::Anchors::iter).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnchorKind {
+    /// [`Anchors::split_stem_up_se`](crate::Anchors::split_stem_up_se).
+    SplitStemUpSE,
+
+    /// [`Anchors::split_stem_up_sw`](crate::Anchors::split_stem_up_sw).
+    SplitStemUpSW,
+
+    /// [`Anchors::split_stem_down_ne`](crate::Anchors::split_stem_down_ne).
+    SplitStemDownNE,
+
+    /// [`Anchors::split_stem_down_nw`](crate::Anchors::split_stem_down_nw).
+    SplitStemDownNW,
+
+    /// [`Anchors::stem_up_se`](crate::Anchors::stem_up_se).
+    StemUpSE,
+
+    /// [`Anchors::stem_down_nw`](crate::Anchors::stem_down_nw).
+    StemDownNW,
+
+    /// [`Anchors::stem_up_nw`](crate::Anchors::stem_up_nw).
+    StemUpNW,
+
+    /// [`Anchors::stem_down_sw`](crate::Anchors::stem_down_sw).
+    StemDownSW,
+
+    /// [`Anchors::nominal_width`](crate::Anchors::nominal_width).
+    NominalWidth,
+
+    /// [`Anchors::numeral_top`](crate::Anchors::numeral_top).
+    NumeralTop,
+
+    /// [`Anchors::numeral_bottom`](crate::Anchors::numeral_bottom).
+    NumeralBottom,
+
+    /// [`Anchors::cut_out_ne`](crate::Anchors::cut_out_ne).
+    CutOutNE,
+
+    /// [`Anchors::cut_out_se`](crate::Anchors::cut_out_se).
+    CutOutSE,
+
+    /// [`Anchors::cut_out_sw`](crate::Anchors::cut_out_sw).
+    CutOutSW,
+
+    /// [`Anchors::cut_out_nw`](crate::Anchors::cut_out_nw).
+    CutOutNW,
+
+    /// [`Anchors::grace_note_slash_sw`](crate::Anchors::grace_note_slash_sw).
+    GraceNoteSlashSW,
+
+    /// [`Anchors::grace_note_slash_ne`](crate::Anchors::grace_note_slash_ne).
+    GraceNoteSlashNE,
+
+    /// [`Anchors::grace_note_slash_nw`](crate::Anchors::grace_note_slash_nw).
+    GraceNoteSlashNW,
+
+    /// [`Anchors::grace_note_slash_se`](crate::Anchors::grace_note_slash_se).
+    GraceNoteSlashSE,
+
+    /// [`Anchors::repeat_offset`](crate::Anchors::repeat_offset).
+    RepeatOffset,
+
+    /// [`Anchors::notehead_origin`](crate::Anchors::notehead_origin).
+    NoteheadOrigin,
+
+    /// [`Anchors::optical_center`](crate::Anchors::optical_center).
+    OpticalCenter,
+}
+
+impl AnchorKind {
+    /// Every kind of anchor, in the order the fields are declared in
+    /// [`Anchors`](crate::Anchors).
+    pub const ALL: [Self; 22] = [
+        Self::SplitStemUpSE,
+        Self::SplitStemUpSW,
+        Self::SplitStemDownNE,
+        Self::SplitStemDownNW,
+        Self::StemUpSE,
+        Self::StemDownNW,
+        Self::StemUpNW,
+        Self::StemDownSW,
+        Self::NominalWidth,
+        Self::NumeralTop,
+        Self::NumeralBottom,
+        Self::CutOutNE,
+        Self::CutOutSE,
+        Self::CutOutSW,
+        Self::CutOutNW,
+        Self::GraceNoteSlashSW,
+        Self::GraceNoteSlashNE,
+        Self::GraceNoteSlashNW,
+        Self::GraceNoteSlashSE,
+        Self::RepeatOffset,
+        Self::NoteheadOrigin,
+        Self::OpticalCenter,
+    ];
+
+    /// Returns the name of the anchor as it appears in metadata files, e.g.
+    /// `stemUpSE`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SplitStemUpSE => "splitStemUpSE",
+            Self::SplitStemUpSW => "splitStemUpSW",
+            Self::SplitStemDownNE => "splitStemDownNE",
+            Self::SplitStemDownNW => "splitStemDownNW",
+            Self::StemUpSE => "stemUpSE",
+            Self::StemDownNW => "stemDownNW",
+            Self::StemUpNW => "stemUpNW",
+            Self::StemDownSW => "stemDownSW",
+            Self::NominalWidth => "nominalWidth",
+            Self::NumeralTop => "numeralTop",
+            Self::NumeralBottom => "numeralBottom",
+            Self::CutOutNE => "cutOutNE",
+            Self::CutOutSE => "cutOutSE",
+            Self::CutOutSW => "cutOutSW",
+            Self::CutOutNW => "cutOutNW",
+            Self::GraceNoteSlashSW => "graceNoteSlashSW",
+            Self::GraceNoteSlashNE => "graceNoteSlashNE",
+            Self::GraceNoteSlashNW => "graceNoteSlashNW",
+            Self::GraceNoteSlashSE => "graceNoteSlashSE",
+            Self::RepeatOffset => "repeatOffset",
+            Self::NoteheadOrigin => "noteheadOrigin",
+            Self::OpticalCenter => "opticalCenter",
+        }
+    }
+
+    /// Returns the kind of anchor with the given name, as it appears in
+    /// metadata files, if there is one.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_sorted_and_unique() {
+        assert!(AnchorKind::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn name() {
+        let anchors = AnchorKind::ALL
+            .into_iter()
+            .map(|kind| (kind.name().to_owned(), serde_json::json!([0, 0])))
+            .collect::<serde_json::Map<_, _>>();
+        let anchors: crate::Anchors = serde_json::from_value(anchors.into()).unwrap();
+
+        assert_eq!(anchors.iter().count(), AnchorKind::ALL.len());
+    }
+
+    #[test]
+    fn from_name() {
+        for kind in AnchorKind::ALL {
+            assert_eq!(AnchorKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(AnchorKind::from_name("vendorAnchor"), None);
+    }
+}
@@ -1,42 +1,46 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::Coord;
+use crate::{AnchorKind, BoundingBox, Coord, Side, StaffSpaces, StemDirection, Transform};
 
 /// Anchor data for glyphs.
 ///
+/// When serialized, fields use the names from the SMuFL specification (e.g.
+/// `splitStemUpSE`) and anchors which are not present are omitted.
+///
 /// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/glyphswithanchors.html).
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
-#[cfg_attr(test, derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Anchors {
     /// The exact position at which the bottom right-hand (south-east) corner of
     /// an angled upward-pointing stem connecting the right-hand side of a
     /// notehead to a vertical stem to its left should start, relative to the
     /// glyph origin, expressed as Cartesian coordinates in staff spaces.
-    #[serde(rename = "splitStemUpSE")]
+    #[serde(rename = "splitStemUpSE", skip_serializing_if = "Option::is_none")]
     pub split_stem_up_se: Option<Coord>,
     /// The exact position at which the bottom left-hand (south-west) corner of
     /// an angled upward-pointing stem connecting the left-hand side of a
     /// notehead to a vertical stem to its right should start, relative to the
     /// glyph origin, expressed as Cartesian coordinates in staff spaces.
-    #[serde(rename = "splitStemUpSW")]
+    #[serde(rename = "splitStemUpSW", skip_serializing_if = "Option::is_none")]
     pub split_stem_up_sw: Option<Coord>,
     /// The exact position at which the top right-hand (north-east) corner of an
     /// angled downward-pointing stem connecting the right-hand side of a
     /// notehead to a vertical stem to its left should start, relative to the
     /// glyph origin, expressed as Cartesian coordinates in staff spaces.
-    #[serde(rename = "splitStemDownNE")]
+    #[serde(rename = "splitStemDownNE", skip_serializing_if = "Option::is_none")]
     pub split_stem_down_ne: Option<Coord>,
     /// The exact position at which the top left-hand (north-west) corner of an
     /// angled downward-pointing stem connecting the left-hand side of a
     /// notehead to a vertical stem to its right should start, relative to the
     /// glyph origin, expressed as Cartesian coordinates in staff spaces.
-    #[serde(rename = "splitStemDownNW")]
+    #[serde(rename = "splitStemDownNW", skip_serializing_if = "Option::is_none")]
     pub split_stem_down_nw: Option<Coord>,
     /// The exact position at which the bottom right-hand (south-east) corner of
     /// an upward-pointing stem rectangle should start, relative to the glyph
     /// origin, expressed as Cartesian coordinates in staff spaces.
-    #[serde(rename = "stemUpSE")]
+    #[serde(rename = "stemUpSE", skip_serializing_if = "Option::is_none")]
     pub stem_up_se: Option<Coord>,
     /// The exact position at which the top left-hand (north-west) corner of a
     /// downward-pointing stem rectangle should start, relative to the glyph
@@ -60,20 +64,21 @@ pub struct Anchors {
     /// extended is highly dependent on the design of the flag in a particular
     /// font, this value should be specified for each flag glyph in the metadata
     /// JSON file.
-    #[serde(rename = "stemDownNW")]
+    #[serde(rename = "stemDownNW", skip_serializing_if = "Option::is_none")]
     pub stem_down_nw: Option<Coord>,
     /// The amount by which an up-stem should be lengthened from its nominal
     /// unmodified length in order to ensure a good connection with a flag, in
     /// spaces.1
-    #[serde(rename = "stemUpNW")]
+    #[serde(rename = "stemUpNW", skip_serializing_if = "Option::is_none")]
     pub stem_up_nw: Option<Coord>,
     /// The amount by which a down-stem should be lengthened from its nominal
     /// unmodified length in order to ensure a good connection with a flag, in
     /// spaces.
-    #[serde(rename = "stemDownSW")]
+    #[serde(rename = "stemDownSW", skip_serializing_if = "Option::is_none")]
     pub stem_down_sw: Option<Coord>,
     /// The width in staff spaces of a given glyph that should be used for e.g.
     /// positioning leger lines correctly.2
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nominal_width: Option<Coord>,
     /// The position in staff spaces that should be used to position numerals
     /// relative to clefs with ligated numbers where those numbers hang from the
@@ -98,11 +103,13 @@ pub struct Anchors {
     /// extended is highly dependent on the design of the flag in a particular
     /// font, this value should be specified for each flag glyph in the metadata
     /// JSON file.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub numeral_top: Option<Coord>,
     /// The position in staff spaces that should be used to position numerals
     /// relative to clefs with ligatured numbers where those numbers sit on the
     /// baseline or at the north-east corner of the G clef, corresponding
     /// horizontally to the center of the numeral’s bounding box.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub numeral_bottom: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the bottom left corner of a
     /// nominal rectangle that intersects the top right corner of the glyph’s
@@ -110,60 +117,533 @@ pub struct Anchors {
     /// corners of the glyph’s bounding box, can be cut out to produce a more
     /// detailed bounding box (of abutting rectangles), useful for kerning or
     /// interlocking symbols such as accidentals.
-    #[serde(rename = "cutOutNE")]
+    #[serde(rename = "cutOutNE", skip_serializing_if = "Option::is_none")]
     pub cut_out_ne: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the top left corner of a
     /// nominal rectangle that intersects the bottom right corner of the glyph’s
     /// bounding box.
-    #[serde(rename = "cutOutSE")]
+    #[serde(rename = "cutOutSE", skip_serializing_if = "Option::is_none")]
     pub cut_out_se: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the top right corner of a
     /// nominal rectangle that intersects the bottom left corner of the glyph’s
     /// bounding box.
-    #[serde(rename = "cutOutSW")]
+    #[serde(rename = "cutOutSW", skip_serializing_if = "Option::is_none")]
     pub cut_out_sw: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the bottom right corner of
     /// a nominal rectangle that intersects the top left corner of the glyph’s
     /// bounding box.
-    #[serde(rename = "cutOutNW")]
+    #[serde(rename = "cutOutNW", skip_serializing_if = "Option::is_none")]
     pub cut_out_nw: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the position at which the
     /// glyph graceNoteSlashStemUp should be positioned relative to the stem-up
     /// flag of an unbeamed grace note; alternatively, the bottom left corner of
     /// a diagonal line drawn instead of using the above glyph.
-    #[serde(rename = "graceNoteSlashSW")]
+    #[serde(rename = "graceNoteSlashSW", skip_serializing_if = "Option::is_none")]
     pub grace_note_slash_sw: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the top right corner of a
     /// diagonal line drawn instead of using the glyph graceNoteSlashStemUp for
     /// a stem-up flag of an unbeamed grace note.
-    #[serde(rename = "graceNoteSlashNE")]
+    #[serde(rename = "graceNoteSlashNE", skip_serializing_if = "Option::is_none")]
     pub grace_note_slash_ne: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the position at which the
     /// glyph graceNoteSlashStemDown should be positioned relative to the
     /// stem-down flag of an unbeamed grace note; alternatively, the top left
     /// corner of a diagonal line drawn instead of using the above glyph.
-    #[serde(rename = "graceNoteSlashNW")]
+    #[serde(rename = "graceNoteSlashNW", skip_serializing_if = "Option::is_none")]
     pub grace_note_slash_nw: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the bottom right corner of
     /// a diagonal line drawn instead of using the glyph graceNoteSlashStemDown
     /// for a stem-down flag of an unbeamed grace note.
-    #[serde(rename = "graceNoteSlashSE")]
+    #[serde(rename = "graceNoteSlashSE", skip_serializing_if = "Option::is_none")]
     pub grace_note_slash_se: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the horizontal position at
     /// which a glyph repeats, i.e. the position at which the same glyph or
     /// another of the same group should be positioned to ensure correct
     /// tessellation. This is used for e.g. multi-segment lines and the
     /// component glyphs that make up trills and mordents.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub repeat_offset: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the left-hand edge of a
     /// notehead with a non-zero left-hand side bearing (e.g. a double whole, or
     /// breve, notehead with two vertical lines at each side), to assist in the
     /// correct horizontal alignment of these noteheads with other noteheads
     /// with zero-width left-side bearings.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notehead_origin: Option<Coord>,
     /// The Cartesian coordinates in staff spaces of the optical center of the
     /// glyph, to assist in the correct horizontal alignment of the glyph
     /// relative to a notehead or stem. Currently recommended for use with
     /// glyphs in the *Dynamics* range.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub optical_center: Option<Coord>,
 }
+
+impl Anchors {
+    /// Returns the anchor identified by `kind`.
+    pub fn get(&self, kind: AnchorKind) -> Option<Coord> {
+        match kind {
+            AnchorKind::SplitStemUpSE => self.split_stem_up_se,
+            AnchorKind::SplitStemUpSW => self.split_stem_up_sw,
+            AnchorKind::SplitStemDownNE => self.split_stem_down_ne,
+            AnchorKind::SplitStemDownNW => self.split_stem_down_nw,
+            AnchorKind::StemUpSE => self.stem_up_se,
+            AnchorKind::StemDownNW => self.stem_down_nw,
+            AnchorKind::StemUpNW => self.stem_up_nw,
+            AnchorKind::StemDownSW => self.stem_down_sw,
+            AnchorKind::NominalWidth => self.nominal_width,
+            AnchorKind::NumeralTop => self.numeral_top,
+            AnchorKind::NumeralBottom => self.numeral_bottom,
+            AnchorKind::CutOutNE => self.cut_out_ne,
+            AnchorKind::CutOutSE => self.cut_out_se,
+            AnchorKind::CutOutSW => self.cut_out_sw,
+            AnchorKind::CutOutNW => self.cut_out_nw,
+            AnchorKind::GraceNoteSlashSW => self.grace_note_slash_sw,
+            AnchorKind::GraceNoteSlashNE => self.grace_note_slash_ne,
+            AnchorKind::GraceNoteSlashNW => self.grace_note_slash_nw,
+            AnchorKind::GraceNoteSlashSE => self.grace_note_slash_se,
+            AnchorKind::RepeatOffset => self.repeat_offset,
+            AnchorKind::NoteheadOrigin => self.notehead_origin,
+            AnchorKind::OpticalCenter => self.optical_center,
+        }
+    }
+
+    /// Returns an iterator over the anchors which are present, along with
+    /// their kinds, in the order the fields are declared.
+    pub fn iter(&self) -> impl Iterator<Item = (AnchorKind, Coord)> + '_ {
+        AnchorKind::ALL
+            .into_iter()
+            .filter_map(|kind| self.get(kind).map(|coord| (kind, coord)))
+    }
+
+    /// Returns the position at which a stem pointing in `direction` should
+    /// connect to the glyph: [`stem_up_se`](Self::stem_up_se) for up-stems and
+    /// [`stem_down_nw`](Self::stem_down_nw) for down-stems.
+    pub fn stem_attachment(&self, direction: StemDirection) -> Option<Coord> {
+        match direction {
+            StemDirection::Up => self.stem_up_se,
+            StemDirection::Down => self.stem_down_nw,
+        }
+    }
+
+    /// Returns the position at which an angled split stem pointing in
+    /// `direction` should connect to the given `side` of the notehead.
+    ///
+    /// | `direction` | `side`  | Anchor                                              |
+    /// | ----------- | ------- | --------------------------------------------------- |
+    /// | Up          | Right   | [`split_stem_up_se`](Self::split_stem_up_se)        |
+    /// | Up          | Left    | [`split_stem_up_sw`](Self::split_stem_up_sw)        |
+    /// | Down        | Right   | [`split_stem_down_ne`](Self::split_stem_down_ne)    |
+    /// | Down        | Left    | [`split_stem_down_nw`](Self::split_stem_down_nw)    |
+    pub fn split_stem_attachment(&self, direction: StemDirection, side: Side) -> Option<Coord> {
+        match (direction, side) {
+            (StemDirection::Up, Side::Right) => self.split_stem_up_se,
+            (StemDirection::Up, Side::Left) => self.split_stem_up_sw,
+            (StemDirection::Down, Side::Right) => self.split_stem_down_ne,
+            (StemDirection::Down, Side::Left) => self.split_stem_down_nw,
+        }
+    }
+
+    /// Returns the start and end points of a diagonal line which slashes the
+    /// flag of an unbeamed grace note with a stem pointing in `direction`.
+    ///
+    /// For up-stems, the line runs from
+    /// [`grace_note_slash_sw`](Self::grace_note_slash_sw) to
+    /// [`grace_note_slash_ne`](Self::grace_note_slash_ne). For down-stems, it
+    /// runs from [`grace_note_slash_nw`](Self::grace_note_slash_nw) to
+    /// [`grace_note_slash_se`](Self::grace_note_slash_se).
+    ///
+    /// Returns `None` unless both anchors are present.
+    pub fn grace_note_slash(&self, direction: StemDirection) -> Option<(Coord, Coord)> {
+        match direction {
+            StemDirection::Up => self.grace_note_slash_sw.zip(self.grace_note_slash_ne),
+            StemDirection::Down => self.grace_note_slash_nw.zip(self.grace_note_slash_se),
+        }
+    }
+
+    /// Returns the x position of the right-hand edge of a glyph with the given
+    /// `bounding_box` at height `y`, taking the
+    /// [`cut_out_ne`](Self::cut_out_ne) and [`cut_out_se`](Self::cut_out_se)
+    /// anchors into account.
+    pub(crate) fn right_edge_at(&self, bounding_box: &BoundingBox, y: StaffSpaces) -> StaffSpaces {
+        [
+            self.cut_out_ne.filter(|cut_out| y > cut_out.y()),
+            self.cut_out_se.filter(|cut_out| y < cut_out.y()),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|cut_out| cut_out.x())
+        .fold(bounding_box.right(), StaffSpaces::min)
+    }
+
+    /// Returns the x position of the left-hand edge of a glyph with the given
+    /// `bounding_box` at height `y`, taking the
+    /// [`cut_out_nw`](Self::cut_out_nw) and [`cut_out_sw`](Self::cut_out_sw)
+    /// anchors into account.
+    pub(crate) fn left_edge_at(&self, bounding_box: &BoundingBox, y: StaffSpaces) -> StaffSpaces {
+        [
+            self.cut_out_nw.filter(|cut_out| y > cut_out.y()),
+            self.cut_out_sw.filter(|cut_out| y < cut_out.y()),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|cut_out| cut_out.x())
+        .fold(bounding_box.left(), StaffSpaces::max)
+    }
+
+    /// Returns the heights at which the edges returned by
+    /// [`right_edge_at`](Self::right_edge_at) and
+    /// [`left_edge_at`](Self::left_edge_at) may change.
+    pub(crate) fn cut_out_heights(&self) -> impl Iterator<Item = StaffSpaces> {
+        [
+            self.cut_out_ne,
+            self.cut_out_se,
+            self.cut_out_sw,
+            self.cut_out_nw,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|cut_out| cut_out.y())
+    }
+
+    /// Returns the anchors with `transform` applied to each one that is
+    /// present.
+    ///
+    /// [`nominal_width`](Self::nominal_width) and
+    /// [`repeat_offset`](Self::repeat_offset) are distances rather than
+    /// positions, so they are only scaled, not translated.
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        self.map(
+            |coord| transform.apply(coord),
+            |coord| coord * transform.scale,
+        )
+    }
+
+    /// Rounds every anchor to `decimals` decimal places.
+    pub(crate) fn round_decimals(&self, decimals: usize) -> Self {
+        let round = |coord: Coord| coord.round_decimals(decimals);
+
+        self.map(round, round)
+    }
+
+    /// Applies `position` to each anchor which is a position, and `distance`
+    /// to [`nominal_width`](Self::nominal_width) and
+    /// [`repeat_offset`](Self::repeat_offset).
+    fn map(&self, position: impl Fn(Coord) -> Coord, distance: impl Fn(Coord) -> Coord) -> Self {
+        let apply = |anchor: Option<Coord>| anchor.map(&position);
+
+        Self {
+            split_stem_up_se: apply(self.split_stem_up_se),
+            split_stem_up_sw: apply(self.split_stem_up_sw),
+            split_stem_down_ne: apply(self.split_stem_down_ne),
+            split_stem_down_nw: apply(self.split_stem_down_nw),
+            stem_up_se: apply(self.stem_up_se),
+            stem_down_nw: apply(self.stem_down_nw),
+            stem_up_nw: apply(self.stem_up_nw),
+            stem_down_sw: apply(self.stem_down_sw),
+            nominal_width: self.nominal_width.map(&distance),
+            numeral_top: apply(self.numeral_top),
+            numeral_bottom: apply(self.numeral_bottom),
+            cut_out_ne: apply(self.cut_out_ne),
+            cut_out_se: apply(self.cut_out_se),
+            cut_out_sw: apply(self.cut_out_sw),
+            cut_out_nw: apply(self.cut_out_nw),
+            grace_note_slash_sw: apply(self.grace_note_slash_sw),
+            grace_note_slash_ne: apply(self.grace_note_slash_ne),
+            grace_note_slash_nw: apply(self.grace_note_slash_nw),
+            grace_note_slash_se: apply(self.grace_note_slash_se),
+            repeat_offset: self.repeat_offset.map(&distance),
+            notehead_origin: apply(self.notehead_origin),
+            optical_center: apply(self.optical_center),
+        }
+    }
+
+    /// Returns a mutable reference to the anchor identified by `kind`.
+    pub(crate) fn get_mut(&mut self, kind: AnchorKind) -> &mut Option<Coord> {
+        match kind {
+            AnchorKind::SplitStemUpSE => &mut self.split_stem_up_se,
+            AnchorKind::SplitStemUpSW => &mut self.split_stem_up_sw,
+            AnchorKind::SplitStemDownNE => &mut self.split_stem_down_ne,
+            AnchorKind::SplitStemDownNW => &mut self.split_stem_down_nw,
+            AnchorKind::StemUpSE => &mut self.stem_up_se,
+            AnchorKind::StemDownNW => &mut self.stem_down_nw,
+            AnchorKind::StemUpNW => &mut self.stem_up_nw,
+            AnchorKind::StemDownSW => &mut self.stem_down_sw,
+            AnchorKind::NominalWidth => &mut self.nominal_width,
+            AnchorKind::NumeralTop => &mut self.numeral_top,
+            AnchorKind::NumeralBottom => &mut self.numeral_bottom,
+            AnchorKind::CutOutNE => &mut self.cut_out_ne,
+            AnchorKind::CutOutSE => &mut self.cut_out_se,
+            AnchorKind::CutOutSW => &mut self.cut_out_sw,
+            AnchorKind::CutOutNW => &mut self.cut_out_nw,
+            AnchorKind::GraceNoteSlashSW => &mut self.grace_note_slash_sw,
+            AnchorKind::GraceNoteSlashNE => &mut self.grace_note_slash_ne,
+            AnchorKind::GraceNoteSlashNW => &mut self.grace_note_slash_nw,
+            AnchorKind::GraceNoteSlashSE => &mut self.grace_note_slash_se,
+            AnchorKind::RepeatOffset => &mut self.repeat_offset,
+            AnchorKind::NoteheadOrigin => &mut self.notehead_origin,
+            AnchorKind::OpticalCenter => &mut self.optical_center,
+        }
+    }
+}
+
+impl FromIterator<(AnchorKind, Coord)> for Anchors {
+    /// Collects anchors from their kinds and positions. If a kind appears more
+    /// than once, the last position is used.
+    fn from_iter<I: IntoIterator<Item = (AnchorKind, Coord)>>(iter: I) -> Self {
+        let mut anchors = Self::default();
+
+        for (kind, coord) in iter {
+            *anchors.get_mut(kind) = Some(coord);
+        }
+
+        anchors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn anchors() -> Anchors {
+        Anchors {
+            split_stem_up_se: Some(Coord(StaffSpaces(1.0), StaffSpaces(0.0))),
+            split_stem_up_sw: Some(Coord(StaffSpaces(2.0), StaffSpaces(0.0))),
+            split_stem_down_ne: Some(Coord(StaffSpaces(3.0), StaffSpaces(0.0))),
+            split_stem_down_nw: Some(Coord(StaffSpaces(4.0), StaffSpaces(0.0))),
+            stem_up_se: Some(Coord(StaffSpaces(1.18), StaffSpaces(0.168))),
+            stem_down_nw: Some(Coord(StaffSpaces(0.0), StaffSpaces(-0.168))),
+            grace_note_slash_sw: Some(Coord(StaffSpaces(0.0), StaffSpaces(0.0))),
+            grace_note_slash_ne: Some(Coord(StaffSpaces(1.0), StaffSpaces(1.0))),
+            grace_note_slash_nw: Some(Coord(StaffSpaces(0.0), StaffSpaces(1.0))),
+            grace_note_slash_se: Some(Coord(StaffSpaces(1.0), StaffSpaces(0.0))),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(StemDirection::Up, Some(Coord(StaffSpaces(1.18), StaffSpaces(0.168))))]
+    #[case(StemDirection::Down, Some(Coord(StaffSpaces(0.0), StaffSpaces(-0.168))))]
+    fn stem_attachment(#[case] direction: StemDirection, #[case] expected: Option<Coord>) {
+        assert_eq!(anchors().stem_attachment(direction), expected);
+    }
+
+    #[test]
+    fn stem_attachment_missing() {
+        assert_eq!(Anchors::default().stem_attachment(StemDirection::Up), None);
+    }
+
+    #[rstest]
+    #[case(StemDirection::Up, Side::Right, StaffSpaces(1.0))]
+    #[case(StemDirection::Up, Side::Left, StaffSpaces(2.0))]
+    #[case(StemDirection::Down, Side::Right, StaffSpaces(3.0))]
+    #[case(StemDirection::Down, Side::Left, StaffSpaces(4.0))]
+    fn split_stem_attachment(
+        #[case] direction: StemDirection,
+        #[case] side: Side,
+        #[case] expected_x: StaffSpaces,
+    ) {
+        assert_eq!(
+            anchors()
+                .split_stem_attachment(direction, side)
+                .map(|coord| coord.x()),
+            Some(expected_x)
+        );
+    }
+
+    #[rstest]
+    #[case(
+        StemDirection::Up,
+        Some((
+            Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+            Coord(StaffSpaces(1.0), StaffSpaces(1.0))
+        ))
+    )]
+    #[case(
+        StemDirection::Down,
+        Some((
+            Coord(StaffSpaces(0.0), StaffSpaces(1.0)),
+            Coord(StaffSpaces(1.0), StaffSpaces(0.0))
+        ))
+    )]
+    fn grace_note_slash(
+        #[case] direction: StemDirection,
+        #[case] expected: Option<(Coord, Coord)>,
+    ) {
+        assert_eq!(anchors().grace_note_slash(direction), expected);
+    }
+
+    #[test]
+    fn grace_note_slash_incomplete() {
+        let anchors = Anchors {
+            grace_note_slash_sw: Some(Coord(StaffSpaces(0.0), StaffSpaces(0.0))),
+            ..Default::default()
+        };
+
+        assert_eq!(anchors.grace_note_slash(StemDirection::Up), None);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.5), StaffSpaces(1.0))]
+    #[case(StaffSpaces(0.0), StaffSpaces(2.0))]
+    #[case(StaffSpaces(-1.5), StaffSpaces(0.5))]
+    fn right_edge_at(#[case] y: StaffSpaces, #[case] expected: StaffSpaces) {
+        let anchors = Anchors {
+            cut_out_ne: Some(Coord(StaffSpaces(1.0), StaffSpaces(1.0))),
+            cut_out_se: Some(Coord(StaffSpaces(0.5), StaffSpaces(-1.0))),
+            ..Default::default()
+        };
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(2.0), StaffSpaces(2.0)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-2.0)),
+        };
+
+        assert_eq!(anchors.right_edge_at(&bounding_box, y), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.5), StaffSpaces(1.0))]
+    #[case(StaffSpaces(0.0), StaffSpaces(0.0))]
+    #[case(StaffSpaces(-1.5), StaffSpaces(0.5))]
+    fn left_edge_at(#[case] y: StaffSpaces, #[case] expected: StaffSpaces) {
+        let anchors = Anchors {
+            cut_out_nw: Some(Coord(StaffSpaces(1.0), StaffSpaces(1.0))),
+            cut_out_sw: Some(Coord(StaffSpaces(0.5), StaffSpaces(-1.0))),
+            ..Default::default()
+        };
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(2.0), StaffSpaces(2.0)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-2.0)),
+        };
+
+        assert_eq!(anchors.left_edge_at(&bounding_box, y), expected);
+    }
+
+    #[test]
+    fn get() {
+        let anchors = anchors();
+
+        assert_eq!(
+            anchors.get(AnchorKind::StemUpSE),
+            Some(Coord(StaffSpaces(1.18), StaffSpaces(0.168)))
+        );
+        assert_eq!(anchors.get(AnchorKind::OpticalCenter), None);
+    }
+
+    #[test]
+    fn iter() {
+        let anchors = Anchors {
+            optical_center: Some(Coord(StaffSpaces(1.0), StaffSpaces(2.0))),
+            split_stem_up_se: Some(Coord(StaffSpaces(3.0), StaffSpaces(4.0))),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            anchors.iter().collect::<Vec<_>>(),
+            vec![
+                (
+                    AnchorKind::SplitStemUpSE,
+                    Coord(StaffSpaces(3.0), StaffSpaces(4.0))
+                ),
+                (
+                    AnchorKind::OpticalCenter,
+                    Coord(StaffSpaces(1.0), StaffSpaces(2.0))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let anchors = [
+            (
+                AnchorKind::OpticalCenter,
+                Coord(StaffSpaces(1.0), StaffSpaces(2.0)),
+            ),
+            (
+                AnchorKind::StemUpSE,
+                Coord(StaffSpaces(3.0), StaffSpaces(4.0)),
+            ),
+        ]
+        .into_iter()
+        .collect::<Anchors>();
+
+        assert_eq!(
+            anchors,
+            Anchors {
+                optical_center: Some(Coord(StaffSpaces(1.0), StaffSpaces(2.0))),
+                stem_up_se: Some(Coord(StaffSpaces(3.0), StaffSpaces(4.0))),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        let anchors = Anchors {
+            split_stem_up_se: Some(Coord(StaffSpaces(1.0), StaffSpaces(2.0))),
+            cut_out_nw: Some(Coord(StaffSpaces(3.0), StaffSpaces(4.0))),
+            optical_center: Some(Coord(StaffSpaces(5.0), StaffSpaces(6.0))),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&anchors).unwrap(),
+            r#"{"splitStemUpSE":[1.0,2.0],"cutOutNW":[3.0,4.0],"opticalCenter":[5.0,6.0]}"#
+        );
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let anchors = anchors();
+        let json = serde_json::to_string(&anchors).unwrap();
+
+        assert_eq!(serde_json::from_str::<Anchors>(&json).unwrap(), anchors);
+    }
+
+    #[test]
+    fn transformed() {
+        let anchors = Anchors {
+            stem_up_se: Some(Coord(StaffSpaces(1.0), StaffSpaces(2.0))),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            anchors.transformed(&Transform::new(
+                2.0,
+                Coord(StaffSpaces(1.0), StaffSpaces(-1.0))
+            )),
+            Anchors {
+                stem_up_se: Some(Coord(StaffSpaces(3.0), StaffSpaces(3.0))),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(Transform::translate(Coord(StaffSpaces(1.0), StaffSpaces(-1.0))), 1.0)]
+    #[case(Transform::new(2.0, Coord(StaffSpaces(1.0), StaffSpaces(-1.0))), 2.0)]
+    fn transformed_distances(#[case] transform: Transform, #[case] scale: f64) {
+        let nominal_width = Coord(StaffSpaces(1.18), StaffSpaces(0.0));
+        let repeat_offset = Coord(StaffSpaces(0.5), StaffSpaces(0.25));
+        let anchors = Anchors {
+            nominal_width: Some(nominal_width),
+            repeat_offset: Some(repeat_offset),
+            notehead_origin: Some(Coord(StaffSpaces(0.0), StaffSpaces(0.0))),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            anchors.transformed(&transform),
+            Anchors {
+                nominal_width: Some(nominal_width * scale),
+                repeat_offset: Some(repeat_offset * scale),
+                notehead_origin: Some(transform.translate),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! A compact binary encoding of [`Metadata`], for applications which ship
+//! precompiled metrics and want to avoid parsing JSON at startup.
+//!
+//! The encoding is little-endian:
+//!
+//! - The magic bytes `SMuFLbin`, followed by a version byte (currently 1).
+//! - The font name, as a string: a `u32` byte length followed by UTF-8 bytes.
+//! - The engraving defaults, as a string containing their JSON representation.
+//! - The advance widths: a `u32` count, then each glyph's name (a string) and
+//!   width (an `f64`).
+//! - The bounding boxes: a `u32` count, then each glyph's name and the `f64`
+//!   coordinates of its northeast and southwest corners.
+//! - The anchors: a `u32` count, then each glyph's name, a `u32` bitmask of the
+//!   anchors present (bit `n` is [`AnchorKind::ALL`]`[n]`), and the `f64`
+//!   coordinates of each anchor present.
+//!
+//! Glyphs are written in order of name, so encoding the same metadata always
+//! produces the same bytes.
+
+use std::io::{self, Read, Write};
+
+use itertools::Itertools;
+
+use crate::{
+    glyph_or_unknown::GlyphOrUnknown, AnchorKind, Anchors, BoundingBox, Coord, GlyphData, Metadata,
+    StaffSpaces,
+};
+
+const MAGIC: &[u8; 8] = b"SMuFLbin";
+const VERSION: u8 = 1;
+
+impl Metadata {
+    /// Writes the metadata in a compact binary format, which can be read back
+    /// with [`from_binary`](Self::from_binary) much faster than JSON can be
+    /// parsed.
+    ///
+    /// The format may change between versions of this crate, so it should be
+    /// used for caches and precompiled metrics rather than for exchanging
+    /// metadata.
+    pub fn to_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        write_str(&mut writer, &self.font_name)?;
+        write_str(
+            &mut writer,
+            &serde_json::to_string(&self.engraving_defaults)?,
+        )?;
+
+        write_entries(&mut writer, &self.advance_widths, |writer, width| {
+            write_f64(writer, width.0)
+        })?;
+        write_entries(&mut writer, &self.bounding_boxes, |writer, bounding_box| {
+            write_coord(writer, bounding_box.ne)?;
+            write_coord(writer, bounding_box.sw)
+        })?;
+        write_entries(&mut writer, &self.anchors, |writer, anchors| {
+            let mask = AnchorKind::ALL
+                .iter()
+                .enumerate()
+                .filter(|(_, kind)| anchors.get(**kind).is_some())
+                .fold(0u32, |mask, (bit, _)| mask | (1 << bit));
+            writer.write_all(&mask.to_le_bytes())?;
+
+            anchors
+                .iter()
+                .try_for_each(|(_, coord)| write_coord(writer, coord))
+        })
+    }
+
+    /// Reads metadata written by [`to_binary`](Self::to_binary).
+    ///
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) if
+    /// the data is not in the binary format, or was written by an incompatible
+    /// version of this crate.
+    pub fn from_binary(mut reader: impl Read) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not binary SMuFL metadata"));
+        }
+
+        let mut version = [0];
+        reader.read_exact(&mut version)?;
+        if version[0] != VERSION {
+            return Err(invalid_data(format!(
+                "unsupported binary metadata version: {}",
+                version[0]
+            )));
+        }
+
+        let font_name = read_str(&mut reader)?;
+        let engraving_defaults = serde_json::from_str(&read_str(&mut reader)?)?;
+
+        let advance_widths =
+            read_entries(&mut reader, |reader| Ok(StaffSpaces(read_f64(reader)?)))?;
+        let bounding_boxes = read_entries(&mut reader, |reader| {
+            Ok(BoundingBox {
+                ne: read_coord(reader)?,
+                sw: read_coord(reader)?,
+            })
+        })?;
+        let anchors = read_entries(&mut reader, |reader| {
+            let mask = read_u32(reader)?;
+
+            AnchorKind::ALL
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| mask & (1 << bit) != 0)
+                .map(|(_, kind)| Ok((*kind, read_coord(reader)?)))
+                .collect::<io::Result<Anchors>>()
+        })?;
+
+        Ok(Self {
+            font_name,
+            engraving_defaults,
+            advance_widths,
+            anchors,
+            bounding_boxes,
+        })
+    }
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn write_f64(writer: &mut impl Write, value: f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_coord(writer: &mut impl Write, coord: Coord) -> io::Result<()> {
+    write_f64(writer, coord.x().0)?;
+    write_f64(writer, coord.y().0)
+}
+
+fn write_len(writer: &mut impl Write, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len).map_err(|_| invalid_data("too many entries"))?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+fn write_str(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write_len(writer, value.len())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn write_entries<W: Write, T>(
+    writer: &mut W,
+    data: &GlyphData<T>,
+    mut write_value: impl FnMut(&mut W, &T) -> io::Result<()>,
+) -> io::Result<()> {
+    let entries = data
+        .entries()
+        .map(|(glyph, value)| (glyph.name(), value))
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .collect::<Vec<_>>();
+
+    write_len(writer, entries.len())?;
+    for (name, value) in entries {
+        write_str(writer, name)?;
+        write_value(writer, value)?;
+    }
+
+    Ok(())
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_f64(reader: &mut impl Read) -> io::Result<f64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+fn read_coord(reader: &mut impl Read) -> io::Result<Coord> {
+    Ok(Coord(
+        StaffSpaces(read_f64(reader)?),
+        StaffSpaces(read_f64(reader)?),
+    ))
+}
+
+fn read_str(reader: &mut impl Read) -> io::Result<String> {
+    let len = read_u32(reader)?;
+
+    // Read through `take` rather than allocating `len` bytes up front, so a
+    // corrupt length can't cause a huge allocation.
+    let mut bytes = Vec::new();
+    reader.take(len.into()).read_to_end(&mut bytes)?;
+    if bytes.len() != len as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid_data("invalid UTF-8 in string"))
+}
+
+fn read_entries<R: Read, T>(
+    reader: &mut R,
+    mut read_value: impl FnMut(&mut R) -> io::Result<T>,
+) -> io::Result<GlyphData<T>> {
+    let len = read_u32(reader)?;
+
+    let entries = (0..len)
+        .map(|_| {
+            let glyph = GlyphOrUnknown::from_name(&read_str(reader)?);
+            Ok((glyph, read_value(reader)?))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(GlyphData::from_entries(entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::Glyph;
+
+    const JSON: &str = r#"{
+        "fontName": "Test",
+        "engravingDefaults": {"stemThickness": 0.12, "textFontFamily": ["serif"]},
+        "glyphAdvanceWidths": {"noteheadBlack": 1.18, "notAGlyph": 2},
+        "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]}},
+        "glyphsWithAnchors": {"noteheadBlack": {"stemUpSE": [1.18, 0.168], "opticalCenter": [0.59, 0]}}
+    }"#;
+
+    #[test]
+    fn round_trip() -> anyhow::Result<()> {
+        let metadata = Metadata::from_reader(JSON.as_bytes())?;
+
+        let mut binary = Vec::new();
+        metadata.to_binary(&mut binary)?;
+        let decoded = Metadata::from_binary(binary.as_slice())?;
+
+        assert_eq!(
+            serde_json::to_value(&decoded)?,
+            serde_json::to_value(&metadata)?
+        );
+        assert_eq!(
+            decoded.anchors.get(Glyph::NoteheadBlack),
+            metadata.anchors.get(Glyph::NoteheadBlack)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_binary_deterministic() -> anyhow::Result<()> {
+        let encode = || -> anyhow::Result<Vec<u8>> {
+            let mut binary = Vec::new();
+            Metadata::from_reader(JSON.as_bytes())?.to_binary(&mut binary)?;
+            Ok(binary)
+        };
+
+        assert_eq!(encode()?, encode()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_binary_invalid() -> anyhow::Result<()> {
+        let mut binary = Vec::new();
+        Metadata::from_reader(JSON.as_bytes())?.to_binary(&mut binary)?;
+
+        assert_eq!(
+            Metadata::from_binary(JSON.as_bytes()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        for len in 0..binary.len() {
+            assert!(Metadata::from_binary(&binary[..len]).is_err());
+        }
+
+        Ok(())
+    }
+}
@@ -1,18 +1,652 @@
-use serde::Deserialize;
+use core::fmt::{self, Display, Formatter};
 
-use crate::Coord;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Coord, SpaceRange, StaffSpaces, Transform};
 
 /// The smallest rectangle that encloses every part of the glyph’s path.
 ///
 /// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/glyphbboxes.html).
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
-#[cfg_attr(test, derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BoundingBox {
     /// The north-east corner of the bounding box.
-    #[serde(rename = "bBoxNE")]
+    #[cfg_attr(feature = "serde", serde(rename = "bBoxNE"))]
     pub ne: Coord,
 
-    /// The south-east corner of the bounding box.
-    #[serde(rename = "bBoxSW")]
+    /// The south-west corner of the bounding box.
+    #[cfg_attr(feature = "serde", serde(rename = "bBoxSW"))]
     pub sw: Coord,
 }
+
+impl BoundingBox {
+    /// Returns the smallest bounding box which contains all of `points`, or
+    /// `None` if there are no points.
+    ///
+    /// ```
+    /// use smufl::{BoundingBox, Coord};
+    ///
+    /// let bounding_box =
+    ///     BoundingBox::from_points([(0.0, 1.0), (2.0, -1.0), (1.0, 3.0)].map(Coord::from)).unwrap();
+    ///
+    /// assert_eq!(bounding_box.sw, Coord::from((0.0, -1.0)));
+    /// assert_eq!(bounding_box.ne, Coord::from((2.0, 3.0)));
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = Coord>) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|point| Self {
+                ne: point,
+                sw: point,
+            })
+            .reduce(|bounding_box, point| bounding_box.union(&point))
+    }
+
+    /// Returns the horizontal distance between the west and east edges.
+    pub fn width(&self) -> StaffSpaces {
+        self.ne.x() - self.sw.x()
+    }
+
+    /// Returns the vertical distance between the south and north edges.
+    pub fn height(&self) -> StaffSpaces {
+        self.ne.y() - self.sw.y()
+    }
+
+    /// Returns the point half way between the corners.
+    pub fn center(&self) -> Coord {
+        (self.sw + self.ne) / 2.0
+    }
+
+    /// Returns the area of the bounding box, in square staff spaces.
+    pub fn area(&self) -> f64 {
+        (self.width() * self.height()).0
+    }
+
+    /// Returns the north-east corner of the bounding box.
+    pub fn ne(&self) -> Coord {
+        self.ne
+    }
+
+    /// Returns the north-west corner of the bounding box.
+    pub fn nw(&self) -> Coord {
+        Coord(self.sw.x(), self.ne.y())
+    }
+
+    /// Returns the south-east corner of the bounding box.
+    pub fn se(&self) -> Coord {
+        Coord(self.ne.x(), self.sw.y())
+    }
+
+    /// Returns the south-west corner of the bounding box.
+    pub fn sw(&self) -> Coord {
+        self.sw
+    }
+
+    /// Returns `true` if the north-east corner is not below or to the left of
+    /// the south-west corner.
+    ///
+    /// Some fonts include bounding boxes with the corners swapped, which will
+    /// produce negative widths and heights.
+    pub fn is_valid(&self) -> bool {
+        self.ne.x() >= self.sw.x() && self.ne.y() >= self.sw.y()
+    }
+
+    /// Returns a copy of the bounding box with the components of the corners
+    /// swapped where necessary, so that it is valid (see
+    /// [`is_valid`](Self::is_valid)).
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        Self::from_ranges(
+            SpaceRange::new(self.sw.x(), self.ne.x()),
+            SpaceRange::new(self.sw.y(), self.ne.y()),
+        )
+    }
+
+    /// Returns the horizontal extent of the bounding box.
+    pub fn x_range(&self) -> SpaceRange {
+        SpaceRange {
+            min: self.sw.x(),
+            max: self.ne.x(),
+        }
+    }
+
+    /// Returns the vertical extent of the bounding box.
+    pub fn y_range(&self) -> SpaceRange {
+        SpaceRange {
+            min: self.sw.y(),
+            max: self.ne.y(),
+        }
+    }
+
+    /// Returns the smallest bounding box which contains both `self` and
+    /// `other`.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(
+            self.x_range().union(&other.x_range()),
+            self.y_range().union(&other.y_range()),
+        )
+    }
+
+    /// Returns the area covered by both `self` and `other`, or `None` if they
+    /// do not overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self::from_ranges(
+            self.x_range().intersection(&other.x_range())?,
+            self.y_range().intersection(&other.y_range())?,
+        ))
+    }
+
+    /// Returns `true` if `self` and `other` overlap. Bounding boxes which
+    /// only touch at an edge are considered to overlap.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns `true` if `coord` lies within the bounding box, including its
+    /// edges.
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.x_range().contains(coord.x()) && self.y_range().contains(coord.y())
+    }
+
+    /// Returns a copy of the bounding box moved by `offset`.
+    #[must_use]
+    pub fn translated(&self, offset: Coord) -> Self {
+        Self {
+            ne: self.ne + offset,
+            sw: self.sw + offset,
+        }
+    }
+
+    /// Returns a copy of the bounding box scaled by `factor` relative to the
+    /// glyph origin, e.g. `0.6` for a grace note.
+    #[must_use]
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            ne: self.ne * factor,
+            sw: self.sw * factor,
+        }
+    }
+
+    /// Returns a copy of the bounding box with `padding` added to each side.
+    ///
+    /// A negative `padding` shrinks the bounding box.
+    #[must_use]
+    pub fn expanded(&self, padding: StaffSpaces) -> Self {
+        let padding = Coord(padding, padding);
+
+        Self {
+            ne: self.ne + padding,
+            sw: self.sw - padding,
+        }
+    }
+
+    /// Converts the bounding box to a y-down coordinate system (as used by most
+    /// raster graphics APIs), for a glyph whose origin is at `origin` in that
+    /// coordinate system.
+    ///
+    /// The result keeps a positive width and height, so its
+    /// [`sw`](Self::sw) field holds the corner with the smallest coordinates
+    /// (the top-left corner on screen) and its [`ne`](Self::ne) field holds
+    /// the bottom-right corner.
+    #[must_use]
+    pub fn to_y_down(&self, origin: Coord) -> Self {
+        Self {
+            ne: origin + self.se().flip_y(),
+            sw: origin + self.nw().flip_y(),
+        }
+    }
+
+    fn from_ranges(x: SpaceRange, y: SpaceRange) -> Self {
+        Self {
+            ne: Coord(x.max, y.max),
+            sw: Coord(x.min, y.min),
+        }
+    }
+
+    /// Returns the x coordinate of the west edge.
+    pub fn left(&self) -> StaffSpaces {
+        self.sw.x()
+    }
+
+    /// Returns the x coordinate of the east edge.
+    pub fn right(&self) -> StaffSpaces {
+        self.ne.x()
+    }
+
+    /// Returns the y coordinate of the north edge.
+    pub fn top(&self) -> StaffSpaces {
+        self.ne.y()
+    }
+
+    /// Returns the y coordinate of the south edge.
+    pub fn bottom(&self) -> StaffSpaces {
+        self.sw.y()
+    }
+
+    /// Rounds every coordinate to `decimals` decimal places.
+    #[cfg(feature = "serde")]
+    pub(crate) fn round_decimals(&self, decimals: usize) -> Self {
+        Self {
+            ne: self.ne.round_decimals(decimals),
+            sw: self.sw.round_decimals(decimals),
+        }
+    }
+
+    /// Returns the bounding box with `transform` applied.
+    ///
+    /// If the transform has a negative scale, the corners are swapped so that
+    /// the result remains valid.
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let ne = transform.apply(self.ne);
+        let sw = transform.apply(self.sw);
+
+        if transform.scale < 0.0 {
+            Self { ne: sw, sw: ne }
+        } else {
+            Self { ne, sw }
+        }
+    }
+}
+
+impl Display for BoundingBox {
+    /// Formats the bounding box as `sw: (x sp, y sp), ne: (x sp, y sp)`.
+    ///
+    /// The formatter's precision is applied to every component.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "sw: {:.*}, ne: {:.*}",
+                precision, self.sw, precision, self.ne
+            ),
+            None => write!(f, "sw: {}, ne: {}", self.sw, self.ne),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BoundingBox {
+    /// Generates valid bounding boxes (see [`is_valid`](Self::is_valid)).
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            ne: u.arbitrary()?,
+            sw: u.arbitrary()?,
+        }
+        .normalized())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Coord, Coord) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<BoundingBox> for euclid::Rect<f64, crate::StaffSpaceUnit> {
+    /// Converts to a rectangle whose origin is the south-west corner.
+    fn from(BoundingBox { ne, sw }: BoundingBox) -> Self {
+        Self::new(sw.into(), euclid::size2((ne.0 - sw.0).0, (ne.1 - sw.1).0))
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<euclid::Rect<f64, crate::StaffSpaceUnit>> for BoundingBox {
+    /// Converts from a rectangle whose origin is the south-west corner.
+    fn from(rect: euclid::Rect<f64, crate::StaffSpaceUnit>) -> Self {
+        Self {
+            ne: rect.max().into(),
+            sw: rect.min().into(),
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for BoundingBox {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Coord::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.ne.abs_diff_eq(&other.ne, epsilon) && self.sw.abs_diff_eq(&other.sw, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for BoundingBox {
+    fn default_max_relative() -> Self::Epsilon {
+        Coord::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.ne.relative_eq(&other.ne, epsilon, max_relative)
+            && self.sw.relative_eq(&other.sw, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn bounding_box(sw: (f64, f64), ne: (f64, f64)) -> BoundingBox {
+        BoundingBox {
+            ne: ne.into(),
+            sw: sw.into(),
+        }
+    }
+
+    #[test]
+    fn to_y_down() {
+        assert_eq!(
+            bounding_box((-0.5, -1.0), (1.5, 2.0)).to_y_down((10.0, 20.0).into()),
+            bounding_box((9.5, 18.0), (11.5, 21.0))
+        );
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(
+            bounding_box((-0.5, -1.0), (1.5, 2.0)).width(),
+            StaffSpaces(2.0)
+        );
+    }
+
+    #[test]
+    fn height() {
+        assert_eq!(
+            bounding_box((-0.5, -1.0), (1.5, 2.0)).height(),
+            StaffSpaces(3.0)
+        );
+    }
+
+    #[test]
+    fn center() {
+        assert_eq!(
+            bounding_box((-0.5, -1.0), (1.5, 2.0)).center(),
+            Coord(StaffSpaces(0.5), StaffSpaces(0.5))
+        );
+    }
+
+    #[test]
+    fn area() {
+        assert_eq!(bounding_box((-0.5, -1.0), (1.5, 2.0)).area(), 6.0);
+    }
+
+    #[test]
+    fn corners() {
+        let bounding_box = bounding_box((-0.5, -1.0), (1.5, 2.0));
+
+        assert_eq!(bounding_box.ne(), Coord(StaffSpaces(1.5), StaffSpaces(2.0)));
+        assert_eq!(
+            bounding_box.nw(),
+            Coord(StaffSpaces(-0.5), StaffSpaces(2.0))
+        );
+        assert_eq!(
+            bounding_box.se(),
+            Coord(StaffSpaces(1.5), StaffSpaces(-1.0))
+        );
+        assert_eq!(
+            bounding_box.sw(),
+            Coord(StaffSpaces(-0.5), StaffSpaces(-1.0))
+        );
+    }
+
+    #[test]
+    fn edges() {
+        let bounding_box = bounding_box((-0.5, -1.0), (1.5, 2.0));
+
+        assert_eq!(bounding_box.left(), StaffSpaces(-0.5));
+        assert_eq!(bounding_box.right(), StaffSpaces(1.5));
+        assert_eq!(bounding_box.top(), StaffSpaces(2.0));
+        assert_eq!(bounding_box.bottom(), StaffSpaces(-1.0));
+    }
+
+    #[rstest]
+    #[case::empty(&[], None)]
+    #[case::single(&[(1.0, 2.0)], Some(bounding_box((1.0, 2.0), (1.0, 2.0))))]
+    #[case::multiple(
+        &[(0.0, 1.0), (2.0, -1.0), (1.0, 3.0)],
+        Some(bounding_box((0.0, -1.0), (2.0, 3.0)))
+    )]
+    fn from_points(#[case] points: &[(f64, f64)], #[case] expected: Option<BoundingBox>) {
+        assert_eq!(
+            BoundingBox::from_points(points.iter().copied().map(Coord::from)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(bounding_box((0.0, 0.0), (1.0, 1.0)), true)]
+    #[case(bounding_box((0.0, 0.0), (0.0, 0.0)), true)]
+    #[case(bounding_box((1.0, 0.0), (0.0, 1.0)), false)]
+    #[case(bounding_box((0.0, 1.0), (1.0, 0.0)), false)]
+    #[case(bounding_box((1.0, 1.0), (0.0, 0.0)), false)]
+    fn is_valid(#[case] bounding_box: BoundingBox, #[case] expected: bool) {
+        assert_eq!(bounding_box.is_valid(), expected);
+    }
+
+    #[rstest]
+    #[case(bounding_box((0.0, 0.0), (1.0, 1.0)))]
+    #[case(bounding_box((1.0, 0.0), (0.0, 1.0)))]
+    #[case(bounding_box((0.0, 1.0), (1.0, 0.0)))]
+    #[case(bounding_box((1.0, 1.0), (0.0, 0.0)))]
+    fn normalized(#[case] bounding_box: BoundingBox) {
+        assert_eq!(
+            bounding_box.normalized(),
+            self::bounding_box((0.0, 0.0), (1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        let bounding_box = bounding_box((-0.5, -1.0), (1.5, 2.0));
+
+        assert_eq!(
+            bounding_box.x_range(),
+            SpaceRange::new(StaffSpaces(-0.5), StaffSpaces(1.5))
+        );
+        assert_eq!(
+            bounding_box.y_range(),
+            SpaceRange::new(StaffSpaces(-1.0), StaffSpaces(2.0))
+        );
+    }
+
+    #[rstest]
+    #[case::disjoint(
+        bounding_box((0.0, 0.0), (1.0, 1.0)),
+        bounding_box((2.0, 2.0), (3.0, 3.0)),
+        bounding_box((0.0, 0.0), (3.0, 3.0))
+    )]
+    #[case::overlapping(
+        bounding_box((0.0, 0.0), (2.0, 2.0)),
+        bounding_box((1.0, -1.0), (3.0, 1.0)),
+        bounding_box((0.0, -1.0), (3.0, 2.0))
+    )]
+    #[case::contained(
+        bounding_box((0.0, 0.0), (3.0, 3.0)),
+        bounding_box((1.0, 1.0), (2.0, 2.0)),
+        bounding_box((0.0, 0.0), (3.0, 3.0))
+    )]
+    fn union(#[case] a: BoundingBox, #[case] b: BoundingBox, #[case] expected: BoundingBox) {
+        assert_eq!(a.union(&b), expected);
+        assert_eq!(b.union(&a), expected);
+    }
+
+    #[rstest]
+    #[case::disjoint(
+        bounding_box((0.0, 0.0), (1.0, 1.0)),
+        bounding_box((2.0, 2.0), (3.0, 3.0)),
+        None
+    )]
+    #[case::disjoint_vertically(
+        bounding_box((0.0, 0.0), (1.0, 1.0)),
+        bounding_box((0.0, 2.0), (1.0, 3.0)),
+        None
+    )]
+    #[case::touching(
+        bounding_box((0.0, 0.0), (1.0, 1.0)),
+        bounding_box((1.0, 0.0), (2.0, 1.0)),
+        Some(bounding_box((1.0, 0.0), (1.0, 1.0)))
+    )]
+    #[case::overlapping(
+        bounding_box((0.0, 0.0), (2.0, 2.0)),
+        bounding_box((1.0, -1.0), (3.0, 1.0)),
+        Some(bounding_box((1.0, 0.0), (2.0, 1.0)))
+    )]
+    #[case::contained(
+        bounding_box((0.0, 0.0), (3.0, 3.0)),
+        bounding_box((1.0, 1.0), (2.0, 2.0)),
+        Some(bounding_box((1.0, 1.0), (2.0, 2.0)))
+    )]
+    fn intersection(
+        #[case] a: BoundingBox,
+        #[case] b: BoundingBox,
+        #[case] expected: Option<BoundingBox>,
+    ) {
+        assert_eq!(a.intersection(&b), expected);
+        assert_eq!(b.intersection(&a), expected);
+        assert_eq!(a.intersects(&b), expected.is_some());
+    }
+
+    #[rstest]
+    #[case((0.5, 0.5), true)]
+    #[case((0.0, 0.0), true)]
+    #[case((1.0, 1.0), true)]
+    #[case((1.5, 0.5), false)]
+    #[case((0.5, -0.5), false)]
+    fn contains(#[case] coord: (f64, f64), #[case] expected: bool) {
+        assert_eq!(
+            bounding_box((0.0, 0.0), (1.0, 1.0)).contains(coord.into()),
+            expected
+        );
+    }
+
+    #[test]
+    fn translated() {
+        assert_eq!(
+            bounding_box((0.0, -1.0), (1.0, 1.0)).translated((2.0, 0.5).into()),
+            bounding_box((2.0, -0.5), (3.0, 1.5))
+        );
+    }
+
+    #[test]
+    fn scaled() {
+        assert_eq!(
+            bounding_box((0.0, -1.0), (1.0, 1.0)).scaled(0.5),
+            bounding_box((0.0, -0.5), (0.5, 0.5))
+        );
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(0.25), bounding_box((-0.25, -1.25), (1.25, 1.25)))]
+    #[case(StaffSpaces(-0.25), bounding_box((0.25, -0.75), (0.75, 0.75)))]
+    fn expanded(#[case] padding: StaffSpaces, #[case] expected: BoundingBox) {
+        assert_eq!(
+            bounding_box((0.0, -1.0), (1.0, 1.0)).expanded(padding),
+            expected
+        );
+    }
+
+    #[test]
+    fn display() {
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+        };
+
+        assert_eq!(
+            bounding_box.to_string(),
+            "sw: (0 sp, -0.5 sp), ne: (1.18 sp, 0.5 sp)"
+        );
+    }
+
+    #[test]
+    fn display_with_precision() {
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+        };
+
+        assert_eq!(
+            format!("{bounding_box:.2}"),
+            "sw: (0.00 sp, -0.50 sp), ne: (1.18 sp, 0.50 sp)"
+        );
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn into_euclid() {
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(1.5), StaffSpaces(0.5)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+        };
+        let rect: euclid::Rect<f64, crate::StaffSpaceUnit> = bounding_box.into();
+
+        assert_eq!(rect, euclid::rect(0.0, -0.5, 1.5, 1.0));
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn from_euclid() {
+        let rect: euclid::Rect<f64, crate::StaffSpaceUnit> = euclid::rect(0.0, -0.5, 1.5, 1.0);
+
+        assert_eq!(
+            BoundingBox::from(rect),
+            BoundingBox {
+                ne: Coord(StaffSpaces(1.5), StaffSpaces(0.5)),
+                sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+            }
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx() {
+        let bounding_box = BoundingBox {
+            ne: Coord(StaffSpaces(0.1 + 0.2), StaffSpaces(0.5)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+        };
+        let expected = BoundingBox {
+            ne: Coord(StaffSpaces(0.3), StaffSpaces(0.5)),
+            sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+        };
+
+        approx::assert_relative_eq!(bounding_box, expected);
+    }
+
+    #[rstest]
+    #[case(Transform::new(2.0, (1.0, -1.0).into()), ((1.0, -1.0), (3.0, 3.0)))]
+    #[case(Transform::scale(-1.0), ((-1.0, -2.0), (0.0, 0.0)))]
+    fn transformed(#[case] transform: Transform, #[case] (sw, ne): ((f64, f64), (f64, f64))) {
+        assert_eq!(
+            bounding_box((0.0, 0.0), (1.0, 2.0)).transformed(&transform),
+            bounding_box(sw, ne)
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..=255).rev().cycle().take(1024).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            assert!(BoundingBox::arbitrary(&mut u).unwrap().is_valid());
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Loading [`Metadata`] from font bundles: directories or zip archives
+//! containing a font and its metadata file.
+
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{BundleError, Metadata};
+
+/// The file extensions of font files which may have a metadata file with the
+/// same name alongside them.
+pub(crate) const FONT_EXTENSIONS: [&str; 4] = ["otf", "ttf", "woff", "woff2"];
+
+/// The tag of the private font table which [`Metadata::from_font_path`] reads
+/// embedded metadata from.
+#[cfg(feature = "font")]
+const METADATA_TABLE: &[u8; 4] = b"SMuF";
+
+impl Metadata {
+    /// Loads the metadata from a directory containing a font and its metadata
+    /// file, such as an unpacked font distribution.
+    ///
+    /// The metadata file is located by the same conventions as in
+    /// [`from_font_path`](Self::from_font_path), for any font file in the
+    /// directory: a JSON file with the same name as a font (e.g.
+    /// `Sebastian.json` alongside `Sebastian.otf`), then a file named after a
+    /// font with a `_metadata.json` suffix (e.g. `bravura_metadata.json`
+    /// alongside `Bravura.otf`), then `metadata.json`. Failing those, any file
+    /// ending in `_metadata.json` is used. Subdirectories are searched too, and
+    /// if several files match equally well, the first by path is used.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self, BundleError> {
+        let path = path.as_ref();
+        let files = files_in(path, &mut HashSet::new())?;
+        let names = files
+            .iter()
+            .filter_map(|file| file.strip_prefix(path).ok()?.to_str())
+            .map(|name| name.replace(std::path::MAIN_SEPARATOR, "/"))
+            .collect::<Vec<_>>();
+        let name = find_bundle_metadata(names.iter().map(String::as_str))
+            .ok_or(BundleError::MetadataNotFound)?;
+
+        let reader = BufReader::new(File::open(path.join(name))?);
+
+        Ok(Self::from_reader(reader)?)
+    }
+
+    /// Loads the metadata for the font file at `path` (e.g. an OTF or WOFF2
+    /// file) from the metadata embedded in it, or the metadata file
+    /// distributed alongside it.
+    ///
+    /// With the `font` feature, metadata embedded in the font is used if
+    /// present. SMuFL does not define a font table for metadata, so this is a
+    /// convention of this crate: a private table tagged `SMuF` containing the
+    /// metadata as UTF-8 JSON. Fonts which can't be parsed, such as compressed
+    /// WOFF and WOFF2 files, are not checked for it.
+    ///
+    /// Otherwise, the metadata file is looked for in the font's directory, in
+    /// order: a JSON file with the same name as the font (e.g.
+    /// `Sebastian.json` alongside `Sebastian.otf`), a file named after the font
+    /// with a `_metadata.json` suffix (e.g. `bravura_metadata.json` alongside
+    /// `Bravura.otf`), then `metadata.json`. Font names are matched
+    /// case-insensitively.
+    pub fn from_font_path(path: impl AsRef<Path>) -> Result<Self, BundleError> {
+        let path = path.as_ref();
+
+        #[cfg(feature = "font")]
+        if let Some(metadata) = embedded_metadata(path)? {
+            return Ok(metadata);
+        }
+
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or(BundleError::MetadataNotFound)?
+            .to_lowercase();
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+
+        let files = fs::read_dir(directory)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>, io::Error>>()?;
+        let file_names = files.iter().filter_map(|file| file.file_name()?.to_str());
+        let name = find_metadata(file_names, &[stem]).ok_or(BundleError::MetadataNotFound)?;
+
+        let reader = BufReader::new(File::open(directory.join(name))?);
+
+        Ok(Self::from_reader(reader)?)
+    }
+
+    /// Loads the metadata from a zip archive containing a font and its
+    /// metadata file, as distributed by several foundries and as MuseScore
+    /// extensions.
+    ///
+    /// The metadata file is located in the same way as in
+    /// [`from_dir`](Self::from_dir).
+    #[cfg(feature = "zip")]
+    pub fn from_zip(reader: impl std::io::Read + std::io::Seek) -> Result<Self, BundleError> {
+        let mut archive = zip::ZipArchive::new(reader)?;
+        let name = find_bundle_metadata(archive.file_names())
+            .ok_or(BundleError::MetadataNotFound)?
+            .to_owned();
+
+        let file = archive.by_name(&name)?;
+
+        Ok(Self::from_reader(BufReader::new(file))?)
+    }
+}
+
+/// Returns every file in `dir` and its subdirectories, skipping directories in
+/// `visited` (by canonical path) so that symlink loops are only followed once.
+fn files_in(dir: &Path, visited: &mut HashSet<PathBuf>) -> Result<Vec<PathBuf>, BundleError> {
+    let mut files = Vec::new();
+    if !visited.insert(fs::canonicalize(dir)?) {
+        return Ok(files);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            files.extend(files_in(&path, visited)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
+/// Returns the metadata embedded in the font file at `path`, if it can be
+/// parsed and has a [`METADATA_TABLE`].
+#[cfg(feature = "font")]
+fn embedded_metadata(path: &Path) -> Result<Option<Metadata>, BundleError> {
+    let Ok(data) = fs::read(path) else {
+        return Ok(None);
+    };
+    let Ok(face) = owned_ttf_parser::RawFace::parse(&data, 0) else {
+        return Ok(None);
+    };
+
+    let Some(table) = face.table(owned_ttf_parser::Tag::from_bytes(METADATA_TABLE)) else {
+        return Ok(None);
+    };
+
+    Ok(Some(Metadata::from_reader(table)?))
+}
+
+/// Returns the name of the metadata file among `names` (`/`-separated paths
+/// within a bundle) for a font whose file name, lowercased and without its
+/// extension, is one of `fonts`. In order of preference, this is:
+///
+/// 1. A JSON file with the same name as a font.
+/// 2. A file named after a font with a `_metadata.json` suffix.
+/// 3. A file named `metadata.json`.
+///
+/// File names are compared case-insensitively, ignoring their directories. If
+/// several files match equally well, the first by path is used.
+fn find_metadata<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    fonts: &[String],
+) -> Option<&'a str> {
+    let names = names.into_iter().sorted().collect::<Vec<_>>();
+    let candidates = fonts
+        .iter()
+        .map(|font| format!("{font}.json"))
+        .chain(fonts.iter().map(|font| format!("{font}_metadata.json")))
+        .chain(["metadata.json".to_owned()]);
+
+    candidates.into_iter().find_map(|candidate| {
+        names
+            .iter()
+            .copied()
+            .find(|name| file_name(name) == candidate)
+    })
+}
+
+/// Returns the name of the metadata file among `names` (`/`-separated paths
+/// within a bundle), following the conventions described in
+/// [`Metadata::from_dir`].
+fn find_bundle_metadata<'a>(names: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let names = names.into_iter().sorted().collect::<Vec<_>>();
+    let fonts = names
+        .iter()
+        .filter_map(|name| {
+            let file_name = file_name(name);
+            let (stem, extension) = file_name.rsplit_once('.')?;
+            FONT_EXTENSIONS
+                .contains(&extension)
+                .then(|| stem.to_owned())
+        })
+        .collect::<Vec<_>>();
+
+    find_metadata(names.iter().copied(), &fonts).or_else(|| {
+        names
+            .iter()
+            .copied()
+            .find(|name| file_name(name).ends_with("_metadata.json"))
+    })
+}
+
+/// Returns the lowercased file name of `name`, a `/`-separated path.
+fn file_name(name: &str) -> String {
+    name.rsplit('/').next().unwrap_or(name).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    const METADATA: &str = r#"{"fontName": "Test"}"#;
+
+    #[rstest]
+    #[case::named(&["Test.otf", "Test/metadata.json", "other.json"], Some("Test/metadata.json"))]
+    #[case::suffixed(&["test.otf", "test_metadata.json", "other.json"], Some("test_metadata.json"))]
+    #[case::named_before_suffixed(&["a_metadata.json", "b/metadata.json"], Some("b/metadata.json"))]
+    #[case::font(&["fonts/Test.otf", "fonts/Test.json", "other.json"], Some("fonts/Test.json"))]
+    #[case::font_before_named(&["Test.otf", "Test.json", "metadata.json"], Some("Test.json"))]
+    #[case::font_suffixed_before_other(
+        &["Bravura.otf", "a_metadata.json", "redist/bravura_metadata.json"],
+        Some("redist/bravura_metadata.json")
+    )]
+    #[case::first(&["b_metadata.json", "a_metadata.json"], Some("a_metadata.json"))]
+    #[case::none(&["Test.otf", "other.json"], None)]
+    fn find_bundle_metadata(#[case] names: &[&str], #[case] expected: Option<&str>) {
+        assert_eq!(super::find_bundle_metadata(names.iter().copied()), expected);
+    }
+
+    #[test]
+    fn from_dir() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-from-dir-{}", std::process::id()));
+        fs::create_dir_all(dir.join("redist"))?;
+        fs::write(dir.join("redist/test_metadata.json"), METADATA)?;
+        fs::write(dir.join("redist/test.otf"), [])?;
+
+        let metadata = Metadata::from_dir(&dir);
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(metadata?.font_name, "Test");
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn files_in_symlink_loop() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-files-in-{}", std::process::id()));
+        fs::create_dir_all(dir.join("redist"))?;
+        fs::write(dir.join("redist/test_metadata.json"), METADATA)?;
+        std::os::unix::fs::symlink(&dir, dir.join("redist/loop"))?;
+
+        let files = files_in(&dir, &mut HashSet::new());
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(files?, [dir.join("redist/test_metadata.json")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_dir_not_found() -> anyhow::Result<()> {
+        let dir =
+            std::env::temp_dir().join(format!("smufl-from-dir-not-found-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let metadata = Metadata::from_dir(&dir);
+        fs::remove_dir_all(&dir)?;
+
+        assert!(matches!(metadata, Err(BundleError::MetadataNotFound)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::same_name(&["Test.otf", "Test.json", "test_metadata.json"], Some("Test.json"))]
+    #[case::suffixed(&["Test.otf", "test_metadata.json", "metadata.json"], Some("test_metadata.json"))]
+    #[case::named(&["Test.otf", "Other.json", "metadata.json"], Some("metadata.json"))]
+    #[case::none(&["Test.otf", "Other.json"], None)]
+    fn from_font_path(
+        #[case] files: &[&str],
+        #[case] expected: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!(
+            "smufl-from-font-path-{}-{}-{}",
+            files.len(),
+            expected.unwrap_or("none"),
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir)?;
+        for file in files {
+            let font_name = file.trim_end_matches(".json").trim_end_matches(".otf");
+            fs::write(dir.join(file), format!(r#"{{"fontName": "{font_name}"}}"#))?;
+        }
+
+        let metadata = Metadata::from_font_path(dir.join("Test.otf"));
+        fs::remove_dir_all(&dir)?;
+
+        match expected {
+            Some(expected) => assert_eq!(metadata?.font_name, expected.trim_end_matches(".json")),
+            None => assert!(matches!(metadata, Err(BundleError::MetadataNotFound))),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "font")]
+    #[test]
+    fn from_font_path_embedded() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!(
+            "smufl-from-font-path-embedded-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir)?;
+        let embedded = br#"{"fontName": "Embedded"}"#;
+
+        let mut font = Vec::new();
+        font.extend(0x0001_0000u32.to_be_bytes()); // TrueType outlines
+        font.extend(1u16.to_be_bytes()); // number of tables
+        font.extend([0; 6]); // binary search parameters, which are unused
+        font.extend(METADATA_TABLE);
+        font.extend(0u32.to_be_bytes()); // checksum, which is unused
+        font.extend(28u32.to_be_bytes()); // offset
+        font.extend((embedded.len() as u32).to_be_bytes());
+        font.extend(embedded);
+        fs::write(dir.join("Test.otf"), font)?;
+        fs::write(dir.join("Test.json"), METADATA)?;
+
+        let metadata = Metadata::from_font_path(dir.join("Test.otf"));
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(metadata?.font_name, "Embedded");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn from_zip() -> anyhow::Result<()> {
+        use std::io::{Cursor, Write};
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        writer.start_file("Test/Test.otf", options)?;
+        writer.start_file("Test/metadata.json", options)?;
+        writer.write_all(METADATA.as_bytes())?;
+        let archive = writer.finish()?;
+
+        let metadata = Metadata::from_zip(archive)?;
+
+        assert_eq!(metadata.font_name, "Test");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+/// An error encountered while loading [`Metadata`](crate::Metadata) from a
+/// font bundle, such as a directory or a zip archive.
+#[derive(Debug)]
+pub enum BundleError {
+    /// The bundle could not be read.
+    Io(io::Error),
+
+    /// The metadata file could not be parsed.
+    Metadata(crate::Error),
+
+    /// The zip archive could not be read.
+    #[cfg(feature = "zip")]
+    Zip(zip::result::ZipError),
+
+    /// The bundle does not contain a metadata file.
+    MetadataNotFound,
+}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read bundle: {error}"),
+            Self::Metadata(error) => write!(f, "{error}"),
+            #[cfg(feature = "zip")]
+            Self::Zip(error) => write!(f, "failed to read zip archive: {error}"),
+            Self::MetadataNotFound => write!(f, "bundle does not contain a metadata file"),
+        }
+    }
+}
+
+impl Error for BundleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Metadata(error) => Some(error),
+            #[cfg(feature = "zip")]
+            Self::Zip(error) => Some(error),
+            Self::MetadataNotFound => None,
+        }
+    }
+}
+
+impl From<io::Error> for BundleError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<crate::Error> for BundleError {
+    fn from(error: crate::Error) -> Self {
+        Self::Metadata(error)
+    }
+}
+
+#[cfg(feature = "zip")]
+impl From<zip::result::ZipError> for BundleError {
+    fn from(error: zip::result::ZipError) -> Self {
+        Self::Zip(error)
+    }
+}
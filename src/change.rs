@@ -0,0 +1,19 @@
+/// A value which differs between two sets of metadata, as reported by
+/// [`Metadata::diff`](crate::Metadata::diff).
+///
+/// `None` means the value is missing from that side.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    /// The value in the original metadata.
+    pub old: Option<T>,
+
+    /// The value in the new metadata.
+    pub new: Option<T>,
+}
+
+impl<T: PartialEq> Change<T> {
+    /// Returns the change from `old` to `new`, or `None` if they are equal.
+    pub(crate) fn between(old: Option<T>, new: Option<T>) -> Option<Self> {
+        (old != new).then_some(Self { old, new })
+    }
+}
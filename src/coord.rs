@@ -1,31 +1,269 @@
-use serde::Deserialize;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
 
-use crate::StaffSpaces;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{StaffSpaces, Transform};
 
 /// X, Y coordinates in staff spaces.
+///
+/// The components can be accessed with [`x`](Coord::x) and [`y`](Coord::y), or
+/// by destructuring:
+///
+/// ```
+/// use smufl::{Coord, StaffSpaces};
+///
+/// let Coord(x, y) = Coord::new(StaffSpaces(1.0), StaffSpaces(2.0));
+///
+/// assert_eq!(x, StaffSpaces(1.0));
+/// assert_eq!(y, StaffSpaces(2.0));
+/// ```
 #[doc(alias = "coordinate")]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
-#[cfg_attr(test, derive(serde::Serialize))]
-pub struct Coord(pub(crate) StaffSpaces, pub(crate) StaffSpaces);
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Coord(pub StaffSpaces, pub StaffSpaces);
 
 impl Coord {
+    /// Creates a new coordinate from its horizontal and vertical components.
+    pub const fn new(x: StaffSpaces, y: StaffSpaces) -> Self {
+        Self(x, y)
+    }
+
     /// Returns the horizontal component of the coordinate.
-    pub fn x(&self) -> StaffSpaces {
+    pub const fn x(&self) -> StaffSpaces {
         self.0
     }
 
     /// Returns the vertical component of the coordinate.
-    pub fn y(&self) -> StaffSpaces {
+    pub const fn y(&self) -> StaffSpaces {
         self.1
     }
+
+    /// Returns a copy of the coordinate with the horizontal component replaced
+    /// by `x`.
+    #[must_use]
+    pub const fn with_x(&self, x: StaffSpaces) -> Self {
+        Self(x, self.1)
+    }
+
+    /// Returns a copy of the coordinate with the vertical component replaced by
+    /// `y`.
+    #[must_use]
+    pub const fn with_y(&self, y: StaffSpaces) -> Self {
+        Self(self.0, y)
+    }
+
+    /// Returns a copy of the coordinate with the vertical component negated,
+    /// converting between SMuFL's y-up coordinate system and the y-down system
+    /// used by most raster graphics APIs.
+    #[must_use]
+    pub const fn flip_y(&self) -> Self {
+        Self(self.0, StaffSpaces(-self.1 .0))
+    }
+
+    /// Returns the components of the coordinate as a pair of `f64`s.
+    pub const fn to_xy(&self) -> (f64, f64) {
+        (self.0 .0, self.1 .0)
+    }
+
+    /// Sets the horizontal component of the coordinate.
+    pub fn set_x(&mut self, x: StaffSpaces) {
+        self.0 = x;
+    }
+
+    /// Sets the vertical component of the coordinate.
+    pub fn set_y(&mut self, y: StaffSpaces) {
+        self.1 = y;
+    }
+
+    /// Rounds both components to `decimals` decimal places.
+    #[cfg(feature = "serde")]
+    pub(crate) fn round_decimals(self, decimals: usize) -> Self {
+        Self(
+            self.0.round_decimals(decimals),
+            self.1.round_decimals(decimals),
+        )
+    }
+
+    /// Returns the coordinate with `transform` applied.
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        transform.apply(*self)
+    }
+}
+
+impl From<(f64, f64)> for Coord {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self(StaffSpaces(x), StaffSpaces(y))
+    }
+}
+
+impl From<[f64; 2]> for Coord {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self(StaffSpaces(x), StaffSpaces(y))
+    }
+}
+
+impl From<Coord> for (f64, f64) {
+    fn from(value: Coord) -> Self {
+        value.to_xy()
+    }
+}
+
+impl From<Coord> for [f64; 2] {
+    fn from(Coord(x, y): Coord) -> Self {
+        [x.0, y.0]
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<Coord> for euclid::Point2D<f64, crate::StaffSpaceUnit> {
+    fn from(Coord(x, y): Coord) -> Self {
+        Self::new(x.0, y.0)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<euclid::Point2D<f64, crate::StaffSpaceUnit>> for Coord {
+    fn from(point: euclid::Point2D<f64, crate::StaffSpaceUnit>) -> Self {
+        Self(StaffSpaces(point.x), StaffSpaces(point.y))
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Coord> for mint::Point2<f64> {
+    fn from(Coord(x, y): Coord) -> Self {
+        Self { x: x.0, y: y.0 }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Point2<f64>> for Coord {
+    fn from(mint::Point2 { x, y }: mint::Point2<f64>) -> Self {
+        Self(StaffSpaces(x), StaffSpaces(y))
+    }
+}
+
+impl Add for Coord {
+    type Output = Self;
+
+    fn add(self, Coord(x, y): Self) -> Self {
+        Self(self.0 + x, self.1 + y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, Coord(x, y): Self) {
+        self.0 += x;
+        self.1 += y;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Self;
+
+    fn sub(self, Coord(x, y): Self) -> Self {
+        Self(self.0 - x, self.1 - y)
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, Coord(x, y): Self) {
+        self.0 -= x;
+        self.1 -= y;
+    }
+}
+
+impl Mul<f64> for Coord {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Div<f64> for Coord {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self(self.0 / rhs, self.1 / rhs)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl Display for Coord {
+    /// Formats the coordinate as `(x sp, y sp)`.
+    ///
+    /// The formatter's precision is applied to both components.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "({:.*}, {:.*})", precision, self.0, precision, self.1),
+            None => write!(f, "({}, {})", self.0, self.1),
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Coord {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        StaffSpaces::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon) && self.1.abs_diff_eq(&other.1, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Coord {
+    fn default_max_relative() -> Self::Epsilon {
+        StaffSpaces::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && self.1.relative_eq(&other.1, epsilon, max_relative)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::*;
     use similar_asserts::assert_eq;
 
     use super::*;
 
+    fn coord(x: f64, y: f64) -> Coord {
+        Coord(StaffSpaces(x), StaffSpaces(y))
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Coord::new(StaffSpaces(1.0), StaffSpaces(2.0)),
+            Coord(StaffSpaces(1.0), StaffSpaces(2.0))
+        );
+    }
+
     #[test]
     fn x() {
         assert_eq!(
@@ -41,4 +279,202 @@ mod tests {
             StaffSpaces(2.0)
         );
     }
+
+    #[test]
+    fn with_x() {
+        assert_eq!(
+            Coord(StaffSpaces(1.0), StaffSpaces(2.0)).with_x(StaffSpaces(3.0)),
+            Coord(StaffSpaces(3.0), StaffSpaces(2.0))
+        );
+    }
+
+    #[test]
+    fn with_y() {
+        assert_eq!(
+            Coord(StaffSpaces(1.0), StaffSpaces(2.0)).with_y(StaffSpaces(3.0)),
+            Coord(StaffSpaces(1.0), StaffSpaces(3.0))
+        );
+    }
+
+    #[test]
+    fn to_xy() {
+        assert_eq!(coord(1.0, -2.0).to_xy(), (1.0, -2.0));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Coord::from((1.0, -2.0)), coord(1.0, -2.0));
+    }
+
+    #[test]
+    fn from_array() {
+        assert_eq!(Coord::from([1.0, -2.0]), coord(1.0, -2.0));
+    }
+
+    #[test]
+    fn into_tuple() {
+        assert_eq!(<(f64, f64)>::from(coord(1.0, -2.0)), (1.0, -2.0));
+    }
+
+    #[test]
+    fn into_array() {
+        assert_eq!(<[f64; 2]>::from(coord(1.0, -2.0)), [1.0, -2.0]);
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn into_euclid() {
+        let point: euclid::Point2D<f64, crate::StaffSpaceUnit> = coord(1.0, -2.0).into();
+
+        assert_eq!(point, euclid::point2(1.0, -2.0));
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn from_euclid() {
+        let point: euclid::Point2D<f64, crate::StaffSpaceUnit> = euclid::point2(1.0, -2.0);
+
+        assert_eq!(Coord::from(point), coord(1.0, -2.0));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn into_mint() {
+        assert_eq!(
+            mint::Point2::from(coord(1.0, -2.0)),
+            mint::Point2 { x: 1.0, y: -2.0 }
+        );
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn from_mint() {
+        assert_eq!(
+            Coord::from(mint::Point2 { x: 1.0, y: -2.0 }),
+            coord(1.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn set_x() {
+        let mut coord = Coord(StaffSpaces(1.0), StaffSpaces(2.0));
+        coord.set_x(StaffSpaces(3.0));
+
+        assert_eq!(coord, Coord(StaffSpaces(3.0), StaffSpaces(2.0)));
+    }
+
+    #[test]
+    fn set_y() {
+        let mut coord = Coord(StaffSpaces(1.0), StaffSpaces(2.0));
+        coord.set_y(StaffSpaces(3.0));
+
+        assert_eq!(coord, Coord(StaffSpaces(1.0), StaffSpaces(3.0)));
+    }
+
+    #[rstest]
+    #[case(coord(1.0, 2.0), coord(3.0, -1.0), coord(4.0, 1.0))]
+    #[case(coord(0.0, 0.0), coord(3.0, -1.0), coord(3.0, -1.0))]
+    fn add(#[case] left: Coord, #[case] right: Coord, #[case] expected: Coord) {
+        assert_eq!(left + right, expected);
+    }
+
+    #[rstest]
+    #[case(coord(1.0, 2.0), coord(3.0, -1.0), coord(4.0, 1.0))]
+    fn add_assign(#[case] mut left: Coord, #[case] right: Coord, #[case] expected: Coord) {
+        left += right;
+        assert_eq!(left, expected);
+    }
+
+    #[rstest]
+    #[case(coord(1.0, 2.0), coord(3.0, -1.0), coord(-2.0, 3.0))]
+    #[case(coord(3.0, -1.0), coord(0.0, 0.0), coord(3.0, -1.0))]
+    fn sub(#[case] left: Coord, #[case] right: Coord, #[case] expected: Coord) {
+        assert_eq!(left - right, expected);
+    }
+
+    #[rstest]
+    #[case(coord(1.0, 2.0), coord(3.0, -1.0), coord(-2.0, 3.0))]
+    fn sub_assign(#[case] mut left: Coord, #[case] right: Coord, #[case] expected: Coord) {
+        left -= right;
+        assert_eq!(left, expected);
+    }
+
+    #[rstest]
+    #[case(coord(1.0, -2.0), 2.0, coord(2.0, -4.0))]
+    #[case(coord(1.0, -2.0), 0.5, coord(0.5, -1.0))]
+    fn mul_f64(#[case] left: Coord, #[case] right: f64, #[case] expected: Coord) {
+        assert_eq!(left * right, expected);
+    }
+
+    #[rstest]
+    #[case(coord(1.0, -2.0), 2.0, coord(0.5, -1.0))]
+    #[case(coord(1.0, -2.0), 0.5, coord(2.0, -4.0))]
+    fn div_f64(#[case] left: Coord, #[case] right: f64, #[case] expected: Coord) {
+        assert_eq!(left / right, expected);
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-coord(1.0, -2.0), coord(-1.0, 2.0));
+    }
+
+    #[test]
+    fn flip_y() {
+        assert_eq!(coord(1.0, -2.0).flip_y(), coord(1.0, 2.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Coord(StaffSpaces(1.18), StaffSpaces(-0.5))).unwrap(),
+            "[1.18,-0.5]"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Coord>("[1.18, -0.5]").unwrap(),
+            Coord(StaffSpaces(1.18), StaffSpaces(-0.5))
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Coord(StaffSpaces(1.25), StaffSpaces(-0.5)).to_string(),
+            "(1.25 sp, -0.5 sp)"
+        );
+    }
+
+    #[test]
+    fn display_with_precision() {
+        assert_eq!(
+            format!("{:.1}", Coord(StaffSpaces(1.25), StaffSpaces(-0.5))),
+            "(1.2 sp, -0.5 sp)"
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx() {
+        approx::assert_abs_diff_eq!(
+            Coord(StaffSpaces(0.1 + 0.2), StaffSpaces(1.0)),
+            Coord(StaffSpaces(0.3), StaffSpaces(1.0))
+        );
+        approx::assert_relative_ne!(
+            Coord(StaffSpaces(1.0), StaffSpaces(1.0)),
+            Coord(StaffSpaces(1.0), StaffSpaces(1.1))
+        );
+    }
+
+    #[test]
+    fn transformed() {
+        assert_eq!(
+            coord(1.0, 2.0).transformed(&Transform::new(2.0, coord(1.0, -1.0))),
+            coord(3.0, 3.0)
+        );
+    }
 }
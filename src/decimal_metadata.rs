@@ -0,0 +1,365 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+use rust_decimal::Decimal;
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::value::RawValue;
+
+use crate::{Error, Metadata, SerializeOptions};
+
+/// [`Metadata`] along with the exact decimal literal of every number in the
+/// file it was parsed from.
+///
+/// [`StaffSpaces`](crate::StaffSpaces) stores values as `f64`, so writing
+/// parsed metadata back out changes literals such as `1.180` or
+/// `0.12345678901234567890`. [`to_writer`](Self::to_writer) writes the
+/// original literal for every value which hasn't been changed since parsing,
+/// so font-authoring tools can edit metadata files without rewriting every
+/// number in them.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use smufl::{DecimalMetadata, SerializeOptions};
+///
+/// let json = r#"{"fontName":"Test","glyphAdvanceWidths":{"noteheadBlack":1.180}}"#;
+/// let metadata = DecimalMetadata::from_reader(json.as_bytes())?;
+///
+/// let mut output = Vec::new();
+/// metadata.to_writer(&mut output, &SerializeOptions::default())?;
+///
+/// assert!(String::from_utf8(output)?.contains(r#""noteheadBlack":1.180"#));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecimalMetadata {
+    /// The parsed metadata, which can be edited before writing it back out.
+    pub metadata: Metadata,
+
+    literals: Node,
+}
+
+impl DecimalMetadata {
+    /// Deserializes `Metadata` from JSON data, keeping the literal of every
+    /// number in it.
+    ///
+    /// The metadata is parsed as in [`Metadata::from_reader`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, Error> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let raw: &RawValue = serde_json::from_str(&text)?;
+
+        Ok(Self {
+            metadata: Metadata::from_reader(text.as_bytes())?,
+            literals: Node::parse(raw)?,
+        })
+    }
+
+    /// Returns the number at `path` in the parsed file as a [`Decimal`],
+    /// exactly as it was written.
+    ///
+    /// `path` lists the object keys and array indexes leading to the number,
+    /// e.g. `["glyphBBoxes", "noteheadBlack", "bBoxNE", "0"]`. Returns `None`
+    /// if there is no number at `path`, or it is out of the range of
+    /// [`Decimal`].
+    pub fn decimal(&self, path: &[&str]) -> Option<Decimal> {
+        let mut node = &self.literals;
+
+        for segment in path {
+            node = match node {
+                Node::Object(entries) => entries
+                    .iter()
+                    .find_map(|(key, value)| (key == segment).then_some(value))?,
+                Node::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                Node::Value(_) => return None,
+            };
+        }
+
+        match node {
+            Node::Value(raw) if is_number(raw) => Decimal::from_str_exact(raw.get())
+                .or_else(|_| Decimal::from_scientific(raw.get()))
+                .ok(),
+            _ => None,
+        }
+    }
+
+    /// Serializes the metadata as JSON, according to `options`, writing the
+    /// original literal for every number which still has the same value.
+    ///
+    /// Numbers which were edited, or changed by rounding to
+    /// [`SerializeOptions::precision`], are written as in
+    /// [`Metadata::to_writer`].
+    pub fn to_writer(
+        &self,
+        writer: impl Write,
+        options: &SerializeOptions,
+    ) -> Result<(), serde_json::Error> {
+        let mut json = Vec::new();
+        self.metadata.to_writer(
+            &mut json,
+            &SerializeOptions {
+                pretty: false,
+                ..*options
+            },
+        )?;
+
+        let raw: &RawValue = serde_json::from_slice(&json)?;
+        let mut node = Node::parse(raw)?;
+        node.keep_literals(&self.literals);
+
+        if options.pretty {
+            serde_json::to_writer_pretty(writer, &node)
+        } else {
+            serde_json::to_writer(writer, &node)
+        }
+    }
+}
+
+/// A JSON value whose objects keep their key order, and whose numbers,
+/// strings, booleans and nulls are kept as written.
+#[derive(Clone, Debug)]
+enum Node {
+    Object(Vec<(String, Node)>),
+    Array(Vec<Node>),
+    Value(Box<RawValue>),
+}
+
+impl Node {
+    fn parse(raw: &RawValue) -> Result<Self, serde_json::Error> {
+        Ok(match raw.get().as_bytes().first() {
+            Some(b'{') => {
+                let Entries(entries) = serde_json::from_str(raw.get())?;
+
+                Self::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| Ok((key, Self::parse(value)?)))
+                        .collect::<Result<_, serde_json::Error>>()?,
+                )
+            }
+            Some(b'[') => {
+                let items: Vec<&RawValue> = serde_json::from_str(raw.get())?;
+
+                Self::Array(
+                    items
+                        .into_iter()
+                        .map(Self::parse)
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            _ => Self::Value(raw.to_owned()),
+        })
+    }
+
+    /// Replaces each number with the number at the same path in `original`,
+    /// if both have the same value.
+    fn keep_literals(&mut self, original: &Self) {
+        match (self, original) {
+            (Self::Object(entries), Self::Object(original)) => {
+                let original = original
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .collect::<HashMap<_, _>>();
+
+                for (key, value) in entries {
+                    if let Some(original) = original.get(key.as_str()) {
+                        value.keep_literals(original);
+                    }
+                }
+            }
+            (Self::Array(items), Self::Array(original)) => {
+                for (item, original) in items.iter_mut().zip(original) {
+                    item.keep_literals(original);
+                }
+            }
+            (Self::Value(raw), Self::Value(original))
+                if is_number(raw) && is_number(original) && same_number(raw, original) =>
+            {
+                raw.clone_from(original);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Serialize for Node {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Self::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Self::Value(raw) => raw.serialize(serializer),
+        }
+    }
+}
+
+fn is_number(raw: &RawValue) -> bool {
+    matches!(raw.get().as_bytes().first(), Some(b'-' | b'0'..=b'9'))
+}
+
+fn same_number(left: &RawValue, right: &RawValue) -> bool {
+    match (left.get().parse::<f64>(), right.get().parse::<f64>()) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => false,
+    }
+}
+
+/// The entries of a JSON object, in the order they were written.
+struct Entries<'a>(Vec<(String, &'a RawValue)>);
+
+impl<'de> Deserialize<'de> for Entries<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = Entries<'de>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a JSON object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+
+                Ok(Entries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Glyph, KeyOrder, StaffSpaces};
+
+    const JSON: &str = r#"{"fontName":"Test","engravingDefaults":{"textFontFamily":[],"stemThickness":0.12345678901234567890},"glyphAdvanceWidths":{"noteheadBlack":1.180,"noteheadWhole":1e0},"glyphsWithAnchors":{},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.180,0.50],"bBoxSW":[0,-0.500]}}}"#;
+
+    fn to_string(metadata: &DecimalMetadata, options: &SerializeOptions) -> String {
+        let mut output = Vec::new();
+        metadata.to_writer(&mut output, options).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let metadata = DecimalMetadata::from_reader(JSON.as_bytes()).unwrap();
+
+        assert_eq!(to_string(&metadata, &SerializeOptions::default()), JSON);
+    }
+
+    #[test]
+    fn round_trip_edited() {
+        let mut metadata = DecimalMetadata::from_reader(JSON.as_bytes()).unwrap();
+        for (glyph, width) in metadata.metadata.advance_widths.iter_mut() {
+            if glyph == Glyph::NoteheadBlack {
+                *width = StaffSpaces(1.25);
+            }
+        }
+
+        assert_eq!(
+            to_string(&metadata, &SerializeOptions::default()),
+            JSON.replace(r#""noteheadBlack":1.180"#, r#""noteheadBlack":1.25"#)
+        );
+    }
+
+    #[rstest]
+    #[case::alphabetical(
+        SerializeOptions {
+            key_order: KeyOrder::Alphabetical,
+            ..Default::default()
+        },
+        r#"{"engravingDefaults":{"stemThickness":0.12345678901234567890,"textFontFamily":[]},"fontName":"Test","glyphAdvanceWidths":{"noteheadBlack":1.180,"noteheadWhole":1e0},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.180,0.50],"bBoxSW":[0,-0.500]}},"glyphsWithAnchors":{}}"#
+    )]
+    #[case::precision(
+        SerializeOptions {
+            precision: Some(2),
+            ..Default::default()
+        },
+        r#"{"fontName":"Test","engravingDefaults":{"textFontFamily":[],"stemThickness":0.12},"glyphAdvanceWidths":{"noteheadBlack":1.180,"noteheadWhole":1e0},"glyphsWithAnchors":{},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.180,0.50],"bBoxSW":[0,-0.500]}}}"#
+    )]
+    fn to_writer(#[case] options: SerializeOptions, #[case] expected: &str) {
+        let metadata = DecimalMetadata::from_reader(JSON.as_bytes()).unwrap();
+
+        assert_eq!(to_string(&metadata, &options), expected);
+    }
+
+    #[test]
+    fn to_writer_pretty() {
+        let metadata = DecimalMetadata::from_reader(JSON.as_bytes()).unwrap();
+        let output = to_string(
+            &metadata,
+            &SerializeOptions {
+                pretty: true,
+                ..Default::default()
+            },
+        );
+
+        assert!(output.contains(r#""noteheadBlack": 1.180"#), "{output}");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&output).unwrap(),
+            serde_json::from_str::<serde_json::Value>(JSON).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["glyphAdvanceWidths", "noteheadBlack"], Some("1.180"))]
+    #[case(&["glyphAdvanceWidths", "noteheadWhole"], Some("1"))]
+    #[case(&["engravingDefaults", "stemThickness"], Some("0.12345678901234567890"))]
+    #[case(&["glyphBBoxes", "noteheadBlack", "bBoxSW", "1"], Some("-0.500"))]
+    #[case(&["glyphBBoxes", "noteheadBlack", "bBoxSW", "2"], None)]
+    #[case(&["fontName"], None)]
+    #[case(&["glyphAdvanceWidths", "noteheadHalf"], None)]
+    fn decimal(#[case] path: &[&str], #[case] expected: Option<&str>) {
+        let metadata = DecimalMetadata::from_reader(JSON.as_bytes()).unwrap();
+
+        assert_eq!(
+            metadata.decimal(path).map(|decimal| decimal.to_string()),
+            expected.map(str::to_owned)
+        );
+    }
+
+    #[test]
+    fn from_reader_invalid() {
+        assert!(matches!(
+            DecimalMetadata::from_reader(r#"{"fontName": 1.0}"#.as_bytes()),
+            Err(Error::Json { .. })
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::StaffSpaces;
+
+/// A length in ems, relative to the font size.
+///
+/// In SMuFL fonts the em square equals the height of a five-line staff, so one
+/// staff space is 0.25 em.
+///
+/// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/scoring-metrics-glyph-registration.html).
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Ems(pub f64);
+
+impl Ems {
+    /// The number of ems in one staff space.
+    pub(crate) const PER_STAFF_SPACE: f64 = 0.25;
+
+    /// Converts `self` to font design units, given the font's units per em
+    /// (e.g. from the `head` table).
+    pub fn to_font_units(&self, units_per_em: u16) -> f64 {
+        self.0 * f64::from(units_per_em)
+    }
+
+    /// Converts a value in font design units to ems, given the font's units
+    /// per em (e.g. from the `head` table).
+    pub fn from_font_units(value: f64, units_per_em: u16) -> Self {
+        Self(value / f64::from(units_per_em))
+    }
+}
+
+impl From<StaffSpaces> for Ems {
+    fn from(StaffSpaces(value): StaffSpaces) -> Self {
+        Self(value * Self::PER_STAFF_SPACE)
+    }
+}
+
+impl From<Ems> for f64 {
+    fn from(value: Ems) -> Self {
+        value.0
+    }
+}
+
+impl Display for Ems {
+    /// Formats the value followed by the unit, e.g. `0.25 em`.
+    ///
+    /// The formatter's precision is applied to the number.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} em", precision, self.0),
+            None => write!(f, "{} em", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), Ems(0.0))]
+    #[case(StaffSpaces(1.0), Ems(0.25))]
+    #[case(StaffSpaces(4.0), Ems(1.0))]
+    fn from_staff_spaces(#[case] from: StaffSpaces, #[case] expected: Ems) {
+        assert_eq!(Ems::from(from), expected);
+    }
+
+    #[rstest]
+    #[case(Ems(0.25), 1000, 250.0)]
+    #[case(Ems(1.0), 2048, 2048.0)]
+    fn to_font_units(#[case] value: Ems, #[case] units_per_em: u16, #[case] expected: f64) {
+        assert_eq!(value.to_font_units(units_per_em), expected);
+    }
+
+    #[rstest]
+    #[case(250.0, 1000, Ems(0.25))]
+    #[case(2048.0, 2048, Ems(1.0))]
+    fn from_font_units(#[case] value: f64, #[case] units_per_em: u16, #[case] expected: Ems) {
+        assert_eq!(Ems::from_font_units(value, units_per_em), expected);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Ems(0.25).to_string(), "0.25 em");
+        assert_eq!(format!("{:.1}", Ems(0.25)), "0.2 em");
+    }
+}
@@ -1,12 +1,13 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::StaffSpaces;
+use crate::{StaffSpaces, TextFontFamily};
 
 /// Recommended defaults for line widths, etc.
 ///
 /// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/engravingdefaults.html).
-#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
-#[cfg_attr(test, derive(serde::Serialize))]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct EngravingDefaults {
     /// An array containing the text font family (or families, in descending
@@ -15,82 +16,111 @@ pub struct EngravingDefaults {
     /// serif, sans-serif, cursive, fantasy, and monospace. Generic font family
     /// names should be listed after specific font families.
     #[serde(default)]
-    pub text_font_family: Vec<String>,
+    pub text_font_family: TextFontFamily,
     /// The thickness of each staff line
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub staff_line_thickness: Option<StaffSpaces>,
     /// The thickness of a stem
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stem_thickness: Option<StaffSpaces>,
     /// The thickness of a beam
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub beam_thickness: Option<StaffSpaces>,
     /// The distance between the inner edge of the primary and outer edge of
     /// subsequent secondary beams
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub beam_spacing: Option<StaffSpaces>,
     /// The thickness of a leger line (normally somewhat thicker than a staff
     /// line)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub leger_line_thickness: Option<StaffSpaces>,
     /// The amount by which a leger line should extend either side of a
     /// notehead, scaled proportionally with the notehead's size, e.g. when
     /// scaled down as a grace note
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub leger_line_extension: Option<StaffSpaces>,
     /// The thickness of the end of a slur
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slur_endpoint_thickness: Option<StaffSpaces>,
     /// The thickness of the mid-point of a slur (i.e. its thickest point)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slur_midpoint_thickness: Option<StaffSpaces>,
     /// The thickness of the end of a tie
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tie_endpoint_thickness: Option<StaffSpaces>,
     /// The thickness of the mid-point of a tie
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tie_midpoint_thickness: Option<StaffSpaces>,
     /// The thickness of a thin barline, e.g. a normal barline, or each of the
     /// lines of a double barline
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thin_barline_thickness: Option<StaffSpaces>,
     /// The thickness of a thick barline, e.g. in a final barline or a repeat
     /// barline
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thick_barline_thickness: Option<StaffSpaces>,
     /// The thickness of a dashed barline
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dashed_barline_thickness: Option<StaffSpaces>,
     /// The length of the dashes to be used in a dashed barline
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dashed_barline_dash_length: Option<StaffSpaces>,
     /// The length of the gap between dashes in a dashed barline
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dashed_barline_gap_length: Option<StaffSpaces>,
     /// The default distance between multiple thin barlines when locked
     /// together, e.g. between two thin barlines making a double barline,
     /// measured from the right-hand edge of the left barline to the left-hand
     /// edge of the right barline.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub barline_separation: Option<StaffSpaces>,
     /// The default distance between a pair of thin and thick barlines when
     /// locked together, e.g. between the thin and thick barlines making a final
     /// barline, or between the thick and thin barlines making a start repeat
     /// barline.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thin_thick_barline_separation: Option<StaffSpaces>,
     /// The default horizontal distance between the dots and the inner barline
     /// of a repeat barline, measured from the edge of the dots to the edge of
     /// the barline.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub repeat_barline_dot_separation: Option<StaffSpaces>,
     /// The thickness of the vertical line of a bracket grouping staves together
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bracket_thickness: Option<StaffSpaces>,
     /// The thickness of the vertical line of a sub-bracket grouping staves
     /// belonging to the same instrument together
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_bracket_thickness: Option<StaffSpaces>,
     /// The thickness of a crescendo/diminuendo hairpin
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hairpin_thickness: Option<StaffSpaces>,
     /// The thickness of the dashed line used for an octave line
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub octave_line_thickness: Option<StaffSpaces>,
     /// The thickness of the line used for piano pedaling
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pedal_line_thickness: Option<StaffSpaces>,
     /// The thickness of the brackets drawn to indicate repeat endings
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub repeat_ending_line_thickness: Option<StaffSpaces>,
     /// The thickness of the line used for the shaft of an arrow
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub arrow_shaft_thickness: Option<StaffSpaces>,
     /// The thickness of the lyric extension line to indicate a melisma in vocal
     /// music
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lyric_line_thickness: Option<StaffSpaces>,
     /// The thickness of a box drawn around text instructions (e.g. rehearsal
     /// marks)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text_enclosure_thickness: Option<StaffSpaces>,
     /// The thickness of the brackets drawn either side of tuplet numbers
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tuplet_bracket_thickness: Option<StaffSpaces>,
     /// The thickness of the horizontal line drawn between two vertical lines,
     /// known as the H-bar, in a multi-bar rest
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub h_bar_thickness: Option<StaffSpaces>,
 }
 
@@ -167,6 +197,98 @@ impl EngravingDefaults {
             h_bar_thickness: self.h_bar_thickness.or(defaults.h_bar_thickness),
         }
     }
+
+    /// Returns the values which are present, along with their names as they
+    /// appear in the metadata file, in the order the fields are declared.
+    pub(crate) fn values(&self) -> impl Iterator<Item = (&'static str, StaffSpaces)> {
+        self.fields()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+    }
+
+    /// Returns every value other than the text font family, whether or not it
+    /// is present, along with its name as it appears in the metadata file, in
+    /// the order the fields are declared.
+    pub(crate) fn fields(&self) -> impl Iterator<Item = (&'static str, Option<StaffSpaces>)> {
+        [
+            ("staffLineThickness", self.staff_line_thickness),
+            ("stemThickness", self.stem_thickness),
+            ("beamThickness", self.beam_thickness),
+            ("beamSpacing", self.beam_spacing),
+            ("legerLineThickness", self.leger_line_thickness),
+            ("legerLineExtension", self.leger_line_extension),
+            ("slurEndpointThickness", self.slur_endpoint_thickness),
+            ("slurMidpointThickness", self.slur_midpoint_thickness),
+            ("tieEndpointThickness", self.tie_endpoint_thickness),
+            ("tieMidpointThickness", self.tie_midpoint_thickness),
+            ("thinBarlineThickness", self.thin_barline_thickness),
+            ("thickBarlineThickness", self.thick_barline_thickness),
+            ("dashedBarlineThickness", self.dashed_barline_thickness),
+            ("dashedBarlineDashLength", self.dashed_barline_dash_length),
+            ("dashedBarlineGapLength", self.dashed_barline_gap_length),
+            ("barlineSeparation", self.barline_separation),
+            (
+                "thinThickBarlineSeparation",
+                self.thin_thick_barline_separation,
+            ),
+            (
+                "repeatBarlineDotSeparation",
+                self.repeat_barline_dot_separation,
+            ),
+            ("bracketThickness", self.bracket_thickness),
+            ("subBracketThickness", self.sub_bracket_thickness),
+            ("hairpinThickness", self.hairpin_thickness),
+            ("octaveLineThickness", self.octave_line_thickness),
+            ("pedalLineThickness", self.pedal_line_thickness),
+            (
+                "repeatEndingLineThickness",
+                self.repeat_ending_line_thickness,
+            ),
+            ("arrowShaftThickness", self.arrow_shaft_thickness),
+            ("lyricLineThickness", self.lyric_line_thickness),
+            ("textEnclosureThickness", self.text_enclosure_thickness),
+            ("tupletBracketThickness", self.tuplet_bracket_thickness),
+            ("hBarThickness", self.h_bar_thickness),
+        ]
+        .into_iter()
+    }
+
+    /// Rounds every value to `decimals` decimal places.
+    pub(crate) fn round_decimals(&self, decimals: usize) -> Self {
+        let round = |value: Option<StaffSpaces>| value.map(|value| value.round_decimals(decimals));
+
+        Self {
+            text_font_family: self.text_font_family.clone(),
+            staff_line_thickness: round(self.staff_line_thickness),
+            stem_thickness: round(self.stem_thickness),
+            beam_thickness: round(self.beam_thickness),
+            beam_spacing: round(self.beam_spacing),
+            leger_line_thickness: round(self.leger_line_thickness),
+            leger_line_extension: round(self.leger_line_extension),
+            slur_endpoint_thickness: round(self.slur_endpoint_thickness),
+            slur_midpoint_thickness: round(self.slur_midpoint_thickness),
+            tie_endpoint_thickness: round(self.tie_endpoint_thickness),
+            tie_midpoint_thickness: round(self.tie_midpoint_thickness),
+            thin_barline_thickness: round(self.thin_barline_thickness),
+            thick_barline_thickness: round(self.thick_barline_thickness),
+            dashed_barline_thickness: round(self.dashed_barline_thickness),
+            dashed_barline_dash_length: round(self.dashed_barline_dash_length),
+            dashed_barline_gap_length: round(self.dashed_barline_gap_length),
+            barline_separation: round(self.barline_separation),
+            thin_thick_barline_separation: round(self.thin_thick_barline_separation),
+            repeat_barline_dot_separation: round(self.repeat_barline_dot_separation),
+            bracket_thickness: round(self.bracket_thickness),
+            sub_bracket_thickness: round(self.sub_bracket_thickness),
+            hairpin_thickness: round(self.hairpin_thickness),
+            octave_line_thickness: round(self.octave_line_thickness),
+            pedal_line_thickness: round(self.pedal_line_thickness),
+            repeat_ending_line_thickness: round(self.repeat_ending_line_thickness),
+            arrow_shaft_thickness: round(self.arrow_shaft_thickness),
+            lyric_line_thickness: round(self.lyric_line_thickness),
+            text_enclosure_thickness: round(self.text_enclosure_thickness),
+            tuplet_bracket_thickness: round(self.tuplet_bracket_thickness),
+            h_bar_thickness: round(self.h_bar_thickness),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -184,7 +306,7 @@ mod tests {
     #[fixture]
     fn non_empty() -> EngravingDefaults {
         EngravingDefaults {
-            text_font_family: vec!["Non Empty".to_owned()],
+            text_font_family: ["Non Empty"].into_iter().collect(),
             staff_line_thickness: Some(StaffSpaces(1.0)),
             stem_thickness: Some(StaffSpaces(2.0)),
             beam_thickness: Some(StaffSpaces(3.0)),
@@ -220,7 +342,7 @@ mod tests {
     #[fixture]
     fn defaults() -> EngravingDefaults {
         EngravingDefaults {
-            text_font_family: vec!["Defaults".to_owned()],
+            text_font_family: ["Defaults"].into_iter().collect(),
             staff_line_thickness: Some(StaffSpaces(30.0)),
             stem_thickness: Some(StaffSpaces(31.0)),
             beam_thickness: Some(StaffSpaces(32.0)),
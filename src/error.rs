@@ -0,0 +1,167 @@
+use std::{
+    error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+use crate::ValidationIssue;
+
+/// An error encountered while loading [`Metadata`](crate::Metadata).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The metadata could not be read.
+    Io(io::Error),
+
+    /// The metadata is not valid JSON, or does not have the structure of a
+    /// metadata file.
+    Json {
+        /// The underlying error, which includes the line and column at which
+        /// parsing failed.
+        source: serde_json::Error,
+
+        /// The path to the part of the metadata file being parsed when the
+        /// error occurred, e.g. `glyphBBoxes → noteheadBlack → bBoxNE`, if
+        /// known. Array elements are given by their index, e.g. `[1]`.
+        path: Option<String>,
+    },
+
+    /// The metadata was parsed, but failed validation.
+    ///
+    /// Only the issues which caused the failure are included.
+    Validation(Vec<ValidationIssue>),
+
+    /// The metadata refers to glyphs which are not in SMuFL, given by the names
+    /// as they appear in the metadata file.
+    UnknownGlyphs(Vec<String>),
+}
+
+impl Error {
+    /// Wraps a JSON error which occurred while parsing the part of the
+    /// metadata file at `path`.
+    pub(crate) fn json_at(source: serde_json::Error, path: impl Into<String>) -> Self {
+        if source.is_io() {
+            return source.into();
+        }
+
+        Self::Json {
+            source,
+            path: Some(path.into()),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read metadata: {error}"),
+            Self::Json {
+                source,
+                path: Some(path),
+            } => write!(f, "failed to parse metadata at {path}: {source}"),
+            Self::Json { source, path: None } => write!(f, "failed to parse metadata: {source}"),
+            Self::Validation(issues) => {
+                write!(f, "metadata failed validation")?;
+
+                for (index, issue) in issues.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { "; " };
+                    write!(f, "{separator}{issue}")?;
+                }
+
+                Ok(())
+            }
+            Self::UnknownGlyphs(names) => write!(f, "unknown glyphs: {}", names.join(", ")),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json { source, .. } => Some(source),
+            Self::Validation(_) | Self::UnknownGlyphs(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    /// Converts I/O errors from the reader to [`Error::Io`], and all other
+    /// errors to [`Error::Json`] without a path.
+    fn from(error: serde_json::Error) -> Self {
+        if error.is_io() {
+            Self::Io(error.into())
+        } else {
+            Self::Json {
+                source: error,
+                path: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::Severity;
+
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disconnected"))
+        }
+    }
+
+    fn json_error() -> serde_json::Error {
+        serde_json::from_str::<u8>("x").unwrap_err()
+    }
+
+    #[rstest]
+    #[case(
+        Error::Io(io::Error::other("disconnected")),
+        "failed to read metadata: disconnected"
+    )]
+    #[case(
+        Error::from(json_error()),
+        "failed to parse metadata: expected value at line 1 column 1"
+    )]
+    #[case(
+        Error::json_at(json_error(), "glyphBBoxes"),
+        "failed to parse metadata at glyphBBoxes: expected value at line 1 column 1"
+    )]
+    #[case(
+        Error::Validation(vec![
+            ValidationIssue::new(Severity::Error, Some("noteheadBlack".to_owned()), "invalid bounding box"),
+            ValidationIssue::new(Severity::Error, None, "no glyphs"),
+        ]),
+        "metadata failed validation: error: noteheadBlack: invalid bounding box; error: no glyphs"
+    )]
+    #[case(
+        Error::UnknownGlyphs(vec!["foo".to_owned(), "bar".to_owned()]),
+        "unknown glyphs: foo, bar"
+    )]
+    fn display(#[case] error: Error, #[case] expected: &str) {
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn from_io_json_error() {
+        let error = serde_json::from_reader::<_, u8>(FailingReader).unwrap_err();
+
+        assert!(matches!(Error::from(error), Error::Io(_)));
+        let error = serde_json::from_reader::<_, u8>(FailingReader).unwrap_err();
+        assert!(matches!(Error::json_at(error, "glyphBBoxes"), Error::Io(_)));
+    }
+}
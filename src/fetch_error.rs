@@ -0,0 +1,45 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// An error encountered while fetching [`Metadata`](crate::Metadata) over
+/// HTTP.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The request failed.
+    Http(Box<ureq::Error>),
+
+    /// The response could not be parsed as metadata.
+    Metadata(crate::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(error) => write!(f, "failed to fetch metadata: {error}"),
+            Self::Metadata(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Http(error) => Some(error),
+            Self::Metadata(error) => Some(error),
+        }
+    }
+}
+
+impl From<ureq::Error> for FetchError {
+    fn from(error: ureq::Error) -> Self {
+        Self::Http(Box::new(error))
+    }
+}
+
+impl From<crate::Error> for FetchError {
+    fn from(error: crate::Error) -> Self {
+        Self::Metadata(error)
+    }
+}
@@ -0,0 +1,20 @@
+//! The rounding functions for `f64`, which are only in `std`, with `libm`'s
+//! standing in for them without it.
+
+#[cfg(not(feature = "std"))]
+pub(crate) use libm::{ceil, floor, round};
+
+#[cfg(feature = "std")]
+pub(crate) fn ceil(value: f64) -> f64 {
+    value.ceil()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn floor(value: f64) -> f64 {
+    value.floor()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn round(value: f64) -> f64 {
+    value.round()
+}
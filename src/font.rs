@@ -0,0 +1,460 @@
+use std::{fmt, fs, path::Path};
+
+use owned_ttf_parser::{AsFaceRef, Face, GlyphId, OwnedFace, Rect};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    glyph_or_unknown::GlyphOrUnknown, BoundingBox, Coord, FontError, Glyph, Metadata, MetricSource,
+    StaffSpaces, GLYPHS,
+};
+
+/// A SMuFL font: the parsed font file, and its [`Metadata`].
+///
+/// Glyphs are looked up in the font by their SMuFL codepoint, so queries can
+/// take a [`Glyph`] whether they are answered by the font file or by the
+/// metadata.
+///
+/// Metrics which the metadata file omits are filled in from the font file, so
+/// they can be queried in the same way as the rest of the metadata:
+///
+/// - Glyphs in the font but not in `glyphAdvanceWidths` are given their advance
+///   width from the font's `hmtx` table.
+/// - Glyphs with an outline but no entry in `glyphBBoxes` are given the
+///   bounding box of their outline.
+///
+/// Use [`advance_width_source`](Self::advance_width_source) and
+/// [`bounding_box_source`](Self::bounding_box_source) to tell these apart from
+/// the metadata file's own values.
+///
+/// ```no_run
+/// use smufl::{Font, Glyph};
+///
+/// # fn main() -> Result<(), smufl::FontError> {
+/// let font = Font::from_path("submodules/bravura/redist/otf/Bravura.otf")?;
+///
+/// assert_eq!(font.metadata().font_name, "Bravura");
+/// assert!(font.has_outline(Glyph::NoteheadBlack));
+/// # Ok(())
+/// # }
+/// ```
+pub struct Font {
+    face: OwnedFace,
+    metadata: Metadata,
+    derived_advance_widths: FxHashSet<Glyph>,
+    derived_bounding_boxes: FxHashSet<Glyph>,
+}
+
+impl Font {
+    /// Creates a `Font` from the contents of a font file (e.g. an OTF or TTF
+    /// file) and its metadata.
+    pub fn new(data: Vec<u8>, metadata: Metadata) -> Result<Self, FontError> {
+        Self::from_collection(data, 0, metadata)
+    }
+
+    /// Like [`new`](Self::new), but for the font at `index` in a font
+    /// collection (e.g. a TTC file).
+    pub fn from_collection(
+        data: Vec<u8>,
+        index: u32,
+        metadata: Metadata,
+    ) -> Result<Self, FontError> {
+        let mut font = Self {
+            face: OwnedFace::from_vec(data, index)?,
+            metadata,
+            derived_advance_widths: FxHashSet::default(),
+            derived_bounding_boxes: FxHashSet::default(),
+        };
+
+        for &glyph in GLYPHS {
+            if font.metadata.advance_widths.get(glyph).is_none() {
+                if let Some(advance_width) = font.font_advance_width(glyph) {
+                    font.metadata
+                        .advance_widths
+                        .insert(GlyphOrUnknown::Glyph(glyph), advance_width);
+                    font.derived_advance_widths.insert(glyph);
+                }
+            }
+
+            if font.metadata.bounding_boxes.get(glyph).is_none() {
+                if let Some(bounding_box) = font.outline_bounding_box(glyph) {
+                    font.metadata
+                        .bounding_boxes
+                        .insert(GlyphOrUnknown::Glyph(glyph), bounding_box);
+                    font.derived_bounding_boxes.insert(glyph);
+                }
+            }
+        }
+
+        Ok(font)
+    }
+
+    /// Loads the font file at `path`, along with the metadata embedded in it or
+    /// distributed alongside it (see [`Metadata::from_font_path`]).
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FontError> {
+        let path = path.as_ref();
+        let metadata = Metadata::from_font_path(path)?;
+
+        Self::new(fs::read(path)?, metadata)
+    }
+
+    /// Returns the font's metadata, including any metrics filled in from the
+    /// font file.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the parsed font file, for queries not covered by `Font`.
+    pub fn face(&self) -> &Face<'_> {
+        self.face.as_face_ref()
+    }
+
+    /// Returns the number of font design units per em, from the font's `head`
+    /// table.
+    ///
+    /// One staff space is a quarter of an em; see
+    /// [`StaffSpaces::from_font_units`](crate::StaffSpaces::from_font_units).
+    pub fn units_per_em(&self) -> u16 {
+        self.face().units_per_em()
+    }
+
+    /// Returns the font's ID for `glyph`, looked up by its codepoint or, if
+    /// the font doesn't map that, its alternate codepoint.
+    pub fn glyph_id(&self, glyph: Glyph) -> Option<GlyphId> {
+        let face = self.face();
+
+        face.glyph_index(glyph.codepoint()).or_else(|| {
+            glyph
+                .alternate_codepoint()
+                .and_then(|codepoint| face.glyph_index(codepoint))
+        })
+    }
+
+    /// Returns `true` if the font has an outline for `glyph`, i.e. it maps the
+    /// glyph to an ID whose outline is not empty.
+    pub fn has_outline(&self, glyph: Glyph) -> bool {
+        self.glyph_id(glyph)
+            .and_then(|id| self.face().glyph_bounding_box(id))
+            .is_some()
+    }
+
+    /// Returns `glyph`'s advance width from the font's `hmtx` table.
+    pub fn font_advance_width(&self, glyph: Glyph) -> Option<StaffSpaces> {
+        let advance = self.face().glyph_hor_advance(self.glyph_id(glyph)?)?;
+
+        Some(StaffSpaces::from_font_units(
+            f64::from(advance),
+            self.units_per_em(),
+        ))
+    }
+
+    /// Returns the bounding box of `glyph`'s outline in the font file, which
+    /// may differ slightly from the one in the metadata file, e.g. due to
+    /// rounding.
+    pub fn outline_bounding_box(&self, glyph: Glyph) -> Option<BoundingBox> {
+        let Rect {
+            x_min,
+            y_min,
+            x_max,
+            y_max,
+        } = self.face().glyph_bounding_box(self.glyph_id(glyph)?)?;
+        let to_staff_spaces =
+            |value: i16| StaffSpaces::from_font_units(f64::from(value), self.units_per_em());
+
+        Some(BoundingBox {
+            ne: Coord(to_staff_spaces(x_max), to_staff_spaces(y_max)),
+            sw: Coord(to_staff_spaces(x_min), to_staff_spaces(y_min)),
+        })
+    }
+
+    /// Returns where `glyph`'s advance width in [`metadata`](Self::metadata)
+    /// came from, or `None` if it has none.
+    pub fn advance_width_source(&self, glyph: Glyph) -> Option<MetricSource> {
+        if self.derived_advance_widths.contains(&glyph) {
+            Some(MetricSource::Font)
+        } else {
+            self.metadata
+                .advance_widths
+                .get(glyph)
+                .map(|_| MetricSource::Metadata)
+        }
+    }
+
+    /// Returns where `glyph`'s bounding box in [`metadata`](Self::metadata)
+    /// came from, or `None` if it has none.
+    pub fn bounding_box_source(&self, glyph: Glyph) -> Option<MetricSource> {
+        if self.derived_bounding_boxes.contains(&glyph) {
+            Some(MetricSource::Font)
+        } else {
+            self.metadata
+                .bounding_boxes
+                .get(glyph)
+                .map(|_| MetricSource::Metadata)
+        }
+    }
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Font")
+            .field("font_name", &self.metadata.font_name)
+            .field("units_per_em", &self.units_per_em())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    /// The glyphs in the font returned by [`test_font`], sorted by codepoint,
+    /// with their advance widths, and their outlines as
+    /// `(x_min, y_min, x_max, y_max)` in font units, at 1000 units per em.
+    const TEST_GLYPHS: [(Glyph, u16, Option<[i16; 4]>); 2] = [
+        (Glyph::NoteheadWhole, 422, None),
+        (Glyph::NoteheadBlack, 295, Some([0, -125, 295, 125])),
+    ];
+
+    /// Returns a minimal TrueType font with a `.notdef` glyph and the glyphs
+    /// in [`TEST_GLYPHS`], with IDs in the same order, each drawn as a
+    /// rectangle if it has an outline.
+    fn test_font() -> Vec<u8> {
+        let num_glyphs = u16::try_from(TEST_GLYPHS.len() + 1).unwrap();
+
+        let mut cmap = Vec::new();
+        cmap.extend(0u16.to_be_bytes()); // version
+        cmap.extend(1u16.to_be_bytes()); // number of encoding records
+        cmap.extend(3u16.to_be_bytes()); // platform: Windows
+        cmap.extend(10u16.to_be_bytes()); // encoding: Unicode full repertoire
+        cmap.extend(12u32.to_be_bytes()); // subtable offset
+        cmap.extend(12u16.to_be_bytes()); // subtable format
+        cmap.extend(0u16.to_be_bytes()); // reserved
+        cmap.extend((16 + 12 * TEST_GLYPHS.len() as u32).to_be_bytes()); // subtable length
+        cmap.extend(0u32.to_be_bytes()); // language
+        cmap.extend((TEST_GLYPHS.len() as u32).to_be_bytes()); // number of groups
+        for (id, (glyph, _, _)) in (1u32..).zip(TEST_GLYPHS) {
+            let codepoint = u32::from(glyph.codepoint());
+            for value in [codepoint, codepoint, id] {
+                cmap.extend(value.to_be_bytes());
+            }
+        }
+
+        let mut glyf = Vec::new();
+        // `.notdef` is empty, so it starts and ends at offset 0.
+        let mut loca = vec![0u16, 0];
+        let mut hmtx = vec![0u8; 4];
+        for (_, advance, outline) in TEST_GLYPHS {
+            if let Some([x_min, y_min, x_max, y_max]) = outline {
+                glyf.extend(1i16.to_be_bytes()); // number of contours
+                for value in [x_min, y_min, x_max, y_max] {
+                    glyf.extend(value.to_be_bytes());
+                }
+                glyf.extend(3u16.to_be_bytes()); // last point of the contour
+                glyf.extend(0u16.to_be_bytes()); // instruction length
+                glyf.extend([1; 4]); // each point is on the curve
+
+                // Coordinates are relative to the previous point, going
+                // anticlockwise from the bottom left corner.
+                for delta in [x_min, x_max - x_min, 0, x_min - x_max] {
+                    glyf.extend(delta.to_be_bytes());
+                }
+                for delta in [y_min, 0, y_max - y_min, 0] {
+                    glyf.extend(delta.to_be_bytes());
+                }
+            }
+            loca.push(u16::try_from(glyf.len() / 2).unwrap());
+            hmtx.extend(advance.to_be_bytes());
+            hmtx.extend(0i16.to_be_bytes());
+        }
+
+        let mut head = Vec::new();
+        head.extend(0x0001_0000u32.to_be_bytes()); // version
+        head.extend(0x0001_0000u32.to_be_bytes()); // font revision
+        head.extend(0u32.to_be_bytes()); // checksum adjustment
+        head.extend(0x5F0F_3CF5u32.to_be_bytes()); // magic number
+        head.extend(0u16.to_be_bytes()); // flags
+        head.extend(1000u16.to_be_bytes()); // units per em
+        head.extend([0; 16]); // created and modified dates
+        for value in [0i16, -125, 295, 125] {
+            head.extend(value.to_be_bytes()); // bounding box of all glyphs
+        }
+        head.extend([0; 6]); // style, smallest size and direction hint
+        head.extend(0i16.to_be_bytes()); // short `loca` offsets
+        head.extend(0i16.to_be_bytes()); // glyph data format
+
+        let mut hhea = Vec::new();
+        hhea.extend(0x0001_0000u32.to_be_bytes()); // version
+
+        // Ascender, descender and line gap.
+        for value in [500i16, -500, 0] {
+            hhea.extend(value.to_be_bytes());
+        }
+        hhea.extend([0; 24]); // extents, caret, reserved and data format
+        hhea.extend(num_glyphs.to_be_bytes()); // number of horizontal metrics
+
+        let mut maxp = Vec::new();
+        maxp.extend(0x0000_5000u32.to_be_bytes()); // version 0.5
+        maxp.extend(num_glyphs.to_be_bytes());
+
+        let tables: [(&[u8; 4], Vec<u8>); 7] = [
+            (b"cmap", cmap),
+            (b"glyf", glyf),
+            (b"head", head),
+            (b"hhea", hhea),
+            (b"hmtx", hmtx),
+            (
+                b"loca",
+                loca.iter()
+                    .flat_map(|offset| offset.to_be_bytes())
+                    .collect(),
+            ),
+            (b"maxp", maxp),
+        ];
+
+        let mut font = Vec::new();
+        font.extend(0x0001_0000u32.to_be_bytes()); // TrueType outlines
+        font.extend((tables.len() as u16).to_be_bytes());
+        font.extend([0; 6]); // binary search parameters, which are unused
+        let mut offset = 12 + 16 * tables.len();
+        for (tag, data) in &tables {
+            font.extend(*tag);
+            font.extend(0u32.to_be_bytes()); // checksum, which is unused
+            font.extend((offset as u32).to_be_bytes());
+            font.extend((data.len() as u32).to_be_bytes());
+            offset += data.len().next_multiple_of(4);
+        }
+        for (_, mut data) in tables {
+            data.resize(data.len().next_multiple_of(4), 0);
+            font.extend(data);
+        }
+
+        font
+    }
+
+    fn metadata() -> Metadata {
+        Metadata::from_reader(
+            r#"{
+                "fontName": "Test",
+                "glyphAdvanceWidths": {"noteheadBlack": 1.2},
+                "glyphBBoxes": {"noteheadHalf": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]}}
+            }"#
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[fixture]
+    fn font() -> Font {
+        Font::new(test_font(), metadata()).unwrap()
+    }
+
+    #[rstest]
+    fn new(font: Font) {
+        assert_eq!(font.metadata().font_name, "Test");
+        assert_eq!(font.units_per_em(), 1000);
+        assert_eq!(font.face().number_of_glyphs(), 3);
+    }
+
+    #[test]
+    fn new_invalid() {
+        assert!(matches!(
+            Font::new(b"not a font".to_vec(), metadata()),
+            Err(FontError::Face(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadWhole, Some(GlyphId(1)))]
+    #[case(Glyph::NoteheadBlack, Some(GlyphId(2)))]
+    #[case(Glyph::GClef, None)]
+    fn glyph_id(font: Font, #[case] glyph: Glyph, #[case] expected: Option<GlyphId>) {
+        assert_eq!(font.glyph_id(glyph), expected);
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack, true)]
+    #[case(Glyph::NoteheadWhole, false)]
+    #[case(Glyph::GClef, false)]
+    fn has_outline(font: Font, #[case] glyph: Glyph, #[case] expected: bool) {
+        assert_eq!(font.has_outline(glyph), expected);
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack, Some(StaffSpaces(1.18)))]
+    #[case(Glyph::NoteheadWhole, Some(StaffSpaces(1.688)))]
+    #[case(Glyph::GClef, None)]
+    fn font_advance_width(font: Font, #[case] glyph: Glyph, #[case] expected: Option<StaffSpaces>) {
+        assert_eq!(font.font_advance_width(glyph), expected);
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack, Some(MetricSource::Metadata))]
+    #[case(Glyph::NoteheadWhole, Some(MetricSource::Font))]
+    #[case(Glyph::GClef, None)]
+    fn advance_width_source(
+        font: Font,
+        #[case] glyph: Glyph,
+        #[case] expected: Option<MetricSource>,
+    ) {
+        assert_eq!(font.advance_width_source(glyph), expected);
+    }
+
+    #[rstest]
+    fn derived_advance_widths(font: Font) {
+        let advance_widths = &font.metadata().advance_widths;
+
+        assert_eq!(
+            advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.2))
+        );
+        assert_eq!(
+            advance_widths.get(Glyph::NoteheadWhole),
+            Some(StaffSpaces(1.688))
+        );
+        assert_eq!(advance_widths.get(Glyph::GClef), None);
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack, Some(BoundingBox {
+        ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+        sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+    }))]
+    #[case(Glyph::NoteheadWhole, None)]
+    #[case(Glyph::GClef, None)]
+    fn outline_bounding_box(
+        font: Font,
+        #[case] glyph: Glyph,
+        #[case] expected: Option<BoundingBox>,
+    ) {
+        assert_eq!(font.outline_bounding_box(glyph), expected);
+    }
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack, Some(MetricSource::Font))]
+    #[case(Glyph::NoteheadHalf, Some(MetricSource::Metadata))]
+    #[case(Glyph::NoteheadWhole, None)]
+    fn bounding_box_source(
+        font: Font,
+        #[case] glyph: Glyph,
+        #[case] expected: Option<MetricSource>,
+    ) {
+        assert_eq!(font.bounding_box_source(glyph), expected);
+    }
+
+    #[rstest]
+    fn derived_bounding_boxes(font: Font) {
+        let bounding_boxes = &font.metadata().bounding_boxes;
+
+        assert_eq!(
+            bounding_boxes.get(Glyph::NoteheadBlack),
+            font.outline_bounding_box(Glyph::NoteheadBlack)
+        );
+        assert_eq!(
+            bounding_boxes.get(Glyph::NoteheadHalf),
+            metadata().bounding_boxes.get(Glyph::NoteheadHalf)
+        );
+        assert_eq!(bounding_boxes.get(Glyph::NoteheadWhole), None);
+    }
+}
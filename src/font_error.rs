@@ -0,0 +1,60 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+use owned_ttf_parser::FaceParsingError;
+
+use crate::BundleError;
+
+/// An error encountered while loading a [`Font`](crate::Font).
+#[derive(Debug)]
+pub enum FontError {
+    /// The font file could not be read.
+    Io(io::Error),
+
+    /// The font's metadata file could not be found or parsed.
+    Metadata(BundleError),
+
+    /// The font file could not be parsed.
+    Face(FaceParsingError),
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read font: {error}"),
+            Self::Metadata(error) => write!(f, "{error}"),
+            Self::Face(error) => write!(f, "failed to parse font: {error}"),
+        }
+    }
+}
+
+impl Error for FontError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Metadata(error) => Some(error),
+            Self::Face(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for FontError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<BundleError> for FontError {
+    fn from(error: BundleError) -> Self {
+        Self::Metadata(error)
+    }
+}
+
+impl From<FaceParsingError> for FontError {
+    fn from(error: FaceParsingError) -> Self {
+        Self::Face(error)
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use crate::{Anchors, BoundingBox, Glyph, Metadata, StaffSpaces};
+
+/// A collection of named [`Metadata`] with a fallback order.
+///
+/// Lookups for a font check that font first, then each fallback font in
+/// order, returning the first result found. This allows, for example, a text
+/// font which only supplies a few glyphs to fall back to a full music font.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use smufl::{FontRegistry, Glyph, Metadata};
+///
+/// let bravura = Metadata::from_reader(r#"{"fontName": "Bravura"}"#.as_bytes())?;
+/// let leland = Metadata::from_reader(r#"{"fontName": "Leland"}"#.as_bytes())?;
+///
+/// let mut registry = FontRegistry::new();
+/// registry.register("Bravura", bravura);
+/// registry.register("Leland", leland);
+/// registry.set_fallbacks(["Bravura"]);
+///
+/// // Checks Leland, then Bravura.
+/// let width = registry.advance_width("Leland", Glyph::NoteheadBlack);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FontRegistry {
+    fonts: HashMap<String, Metadata>,
+    fallbacks: Vec<String>,
+}
+
+impl FontRegistry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `metadata` under `name`, returning the metadata previously
+    /// registered under that name, if any.
+    pub fn register(&mut self, name: impl Into<String>, metadata: Metadata) -> Option<Metadata> {
+        self.fonts.insert(name.into(), metadata)
+    }
+
+    /// Removes the font registered under `name`, returning its metadata.
+    ///
+    /// The font remains in the fallback order, but is skipped until another
+    /// font is registered under the same name.
+    pub fn unregister(&mut self, name: &str) -> Option<Metadata> {
+        self.fonts.remove(name)
+    }
+
+    /// Returns the metadata registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&Metadata> {
+        self.fonts.get(name)
+    }
+
+    /// Returns an iterator over the registered font names, in arbitrary order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fonts.keys().map(String::as_str)
+    }
+
+    /// Sets the fonts to check, in order, when a lookup doesn't find data in
+    /// the requested font.
+    pub fn set_fallbacks(&mut self, names: impl IntoIterator<Item = impl Into<String>>) {
+        self.fallbacks = names.into_iter().map(Into::into).collect();
+    }
+
+    /// Returns the fallback order.
+    pub fn fallbacks(&self) -> &[String] {
+        &self.fallbacks
+    }
+
+    /// Returns the metadata to check for lookups in `name`: the font itself,
+    /// followed by the registered fallback fonts.
+    pub fn chain<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Metadata> {
+        std::iter::once(name)
+            .chain(
+                self.fallbacks
+                    .iter()
+                    .map(String::as_str)
+                    .filter(move |fallback| *fallback != name),
+            )
+            .filter_map(|name| self.fonts.get(name))
+    }
+
+    /// Returns the first result of `f` for the fonts in [`chain`](Self::chain).
+    pub fn find_map<T>(&self, name: &str, f: impl FnMut(&Metadata) -> Option<T>) -> Option<T> {
+        self.chain(name).find_map(f)
+    }
+
+    /// Returns the advance width of `glyph` in `name` or its fallbacks.
+    pub fn advance_width(&self, name: &str, glyph: Glyph) -> Option<StaffSpaces> {
+        self.find_map(name, |metadata| metadata.advance_widths.get(glyph))
+    }
+
+    /// Returns the anchors of `glyph` in `name` or its fallbacks.
+    pub fn anchors(&self, name: &str, glyph: Glyph) -> Option<Anchors> {
+        self.find_map(name, |metadata| metadata.anchors.get(glyph))
+    }
+
+    /// Returns the bounding box of `glyph` in `name` or its fallbacks.
+    pub fn bounding_box(&self, name: &str, glyph: Glyph) -> Option<BoundingBox> {
+        self.find_map(name, |metadata| metadata.bounding_boxes.get(glyph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::GlyphAdvanceWidths;
+
+    fn metadata(font_name: &str, advance_widths: GlyphAdvanceWidths) -> Metadata {
+        Metadata {
+            font_name: font_name.to_owned(),
+            engraving_defaults: Default::default(),
+            advance_widths,
+            anchors: Default::default(),
+            bounding_boxes: Default::default(),
+        }
+    }
+
+    #[fixture]
+    fn registry() -> FontRegistry {
+        let mut registry = FontRegistry::new();
+        registry.register(
+            "Text",
+            metadata("Text", [(Glyph::AccidentalFlat, StaffSpaces(1.0))].into()),
+        );
+        registry.register(
+            "Music",
+            metadata(
+                "Music",
+                [
+                    (Glyph::AccidentalFlat, StaffSpaces(2.0)),
+                    (Glyph::NoteheadBlack, StaffSpaces(3.0)),
+                ]
+                .into(),
+            ),
+        );
+        registry.set_fallbacks(["Missing", "Music"]);
+
+        registry
+    }
+
+    #[rstest]
+    #[case("Text", Glyph::AccidentalFlat, Some(1.0))]
+    #[case("Text", Glyph::NoteheadBlack, Some(3.0))]
+    #[case("Music", Glyph::AccidentalFlat, Some(2.0))]
+    #[case("Text", Glyph::GClef, None)]
+    #[case("Missing", Glyph::NoteheadBlack, Some(3.0))]
+    fn advance_width(
+        registry: FontRegistry,
+        #[case] name: &str,
+        #[case] glyph: Glyph,
+        #[case] expected: Option<f64>,
+    ) {
+        assert_eq!(
+            registry.advance_width(name, glyph).map(|width| width.0),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn chain(registry: FontRegistry) {
+        let names = registry
+            .chain("Music")
+            .map(|metadata| metadata.font_name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["Music"]);
+    }
+
+    #[rstest]
+    fn unregister(mut registry: FontRegistry) {
+        assert!(registry.unregister("Music").is_some());
+        assert_eq!(registry.advance_width("Text", Glyph::NoteheadBlack), None);
+    }
+}
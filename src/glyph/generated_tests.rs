@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use super::*;
+
+static GLYPHS: &[Glyph] = super::GLYPHS;
+
+#[cfg(feature = "serde")]
+#[test]
+fn names_round_trip() {
+    for glyph in GLYPHS {
+        let deserializer =
+            serde::de::value::StrDeserializer::<serde::de::value::Error>::new(glyph.name());
+        let deserialized = <Glyph as serde::Deserialize>::deserialize(deserializer);
+
+        assert_eq!(deserialized.map(|glyph| glyph.name()), Ok(glyph.name()));
+    }
+}
+
+#[test]
+fn codepoints_are_unique() {
+    let mut codepoints = HashSet::new();
+
+    for glyph in GLYPHS {
+        assert!(
+            codepoints.insert(glyph.codepoint()),
+            "{} shares its codepoint",
+            glyph.name()
+        );
+    }
+}
+
+#[test]
+fn codepoints_are_in_private_use_area() {
+    for glyph in GLYPHS {
+        assert!(
+            ('\u{e000}'..='\u{f8ff}').contains(&glyph.codepoint()),
+            "{} is outside the private use area",
+            glyph.name()
+        );
+    }
+}
@@ -0,0 +1,3569 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Glyph;
+
+impl Glyph {
+    /// Each variant by its name, for [`from_name`](Self::from_name).
+    #[rustfmt::skip]
+    const NAMES: phf::Map<&'static str, Self> = phf::Map {
+        key: 12913932095322966823,
+        disps: &[
+            (0, 22),
+            (0, 300),
+            (0, 1),
+            (0, 34),
+            (0, 32),
+            (0, 101),
+            (0, 30),
+            (0, 12),
+            (0, 28),
+            (0, 5),
+            (0, 165),
+            (0, 1729),
+            (0, 114),
+            (0, 14),
+            (0, 239),
+            (0, 230),
+            (0, 6),
+            (0, 63),
+            (0, 2),
+            (0, 122),
+            (0, 19),
+            (0, 154),
+            (0, 988),
+            (0, 7),
+            (0, 40),
+            (0, 2),
+            (0, 14),
+            (0, 90),
+            (0, 815),
+            (0, 36),
+            (0, 658),
+            (0, 179),
+            (0, 445),
+            (0, 0),
+            (0, 3),
+            (0, 113),
+            (0, 117),
+            (0, 1),
+            (0, 109),
+            (0, 3),
+            (0, 580),
+            (0, 6),
+            (0, 528),
+            (0, 1),
+            (0, 187),
+            (0, 365),
+            (0, 723),
+            (0, 74),
+            (0, 0),
+            (0, 494),
+            (0, 1081),
+            (0, 60),
+            (0, 71),
+            (0, 19),
+            (0, 7),
+            (0, 68),
+            (0, 3),
+            (0, 22),
+            (0, 1),
+            (0, 126),
+            (0, 34),
+            (0, 596),
+            (0, 2),
+            (0, 5),
+            (0, 353),
+            (0, 1121),
+            (0, 0),
+            (0, 15),
+            (0, 288),
+            (0, 17),
+            (0, 564),
+            (0, 66),
+            (0, 3),
+            (0, 1049),
+            (0, 214),
+            (0, 1153),
+            (0, 9),
+            (0, 3),
+            (0, 68),
+            (0, 1362),
+            (0, 82),
+            (0, 39),
+            (0, 240),
+            (0, 55),
+            (0, 1036),
+            (0, 145),
+            (0, 555),
+            (0, 29),
+            (0, 59),
+            (0, 8),
+            (0, 0),
+            (0, 380),
+            (0, 720),
+            (0, 6),
+            (0, 42),
+            (0, 562),
+            (0, 0),
+            (0, 0),
+            (0, 32),
+            (0, 1373),
+            (0, 300),
+            (0, 1),
+            (0, 37),
+            (0, 238),
+            (0, 1),
+            (0, 9),
+            (0, 23),
+            (0, 3),
+            (1, 4),
+            (0, 1),
+            (0, 108),
+            (0, 1742),
+            (0, 364),
+            (0, 194),
+            (0, 68),
+            (0, 1),
+            (0, 2288),
+            (0, 6),
+            (0, 456),
+            (0, 13),
+            (0, 7),
+            (0, 549),
+            (0, 8),
+            (0, 1203),
+            (0, 329),
+            (0, 13),
+            (0, 1820),
+            (0, 9),
+            (0, 0),
+            (0, 25),
+            (0, 56),
+            (0, 1034),
+            (0, 0),
+            (1, 448),
+            (0, 0),
+            (0, 16),
+            (0, 1772),
+            (0, 23),
+            (0, 1),
+            (0, 1023),
+            (0, 29),
+            (0, 35),
+            (0, 25),
+            (0, 0),
+            (0, 0),
+            (0, 40),
+            (0, 38),
+            (0, 15),
+            (0, 172),
+            (0, 48),
+            (0, 36),
+            (0, 49),
+            (0, 76),
+            (0, 254),
+            (0, 1),
+            (0, 13),
+            (0, 3),
+            (0, 528),
+            (0, 1438),
+            (0, 763),
+            (0, 78),
+            (0, 317),
+            (0, 25),
+            (0, 7),
+            (0, 13),
+            (0, 3),
+            (0, 680),
+            (0, 122),
+            (0, 19),
+            (0, 5),
+            (1, 0),
+            (0, 0),
+            (0, 91),
+            (0, 268),
+            (0, 1037),
+            (0, 16),
+            (0, 152),
+            (0, 422),
+            (0, 191),
+            (0, 1),
+            (0, 60),
+            (0, 11),
+            (0, 4),
+            (0, 12),
+            (1, 806),
+            (0, 0),
+            (0, 384),
+            (0, 1907),
+            (0, 368),
+            (0, 2),
+            (0, 37),
+            (0, 6),
+            (0, 1557),
+            (1, 29),
+            (0, 113),
+            (0, 15),
+            (1, 1629),
+            (0, 10),
+            (0, 1044),
+            (0, 1672),
+            (0, 63),
+            (0, 52),
+            (0, 13),
+            (0, 0),
+            (0, 16),
+            (0, 281),
+            (0, 0),
+            (2, 731),
+            (0, 23),
+            (0, 82),
+            (0, 47),
+            (0, 1091),
+            (0, 545),
+            (0, 317),
+            (0, 1765),
+            (0, 0),
+            (0, 3),
+            (0, 3),
+            (0, 8),
+            (0, 342),
+            (0, 290),
+            (0, 9),
+            (0, 1406),
+            (0, 0),
+            (0, 0),
+            (0, 70),
+            (0, 485),
+            (0, 34),
+            (0, 56),
+            (0, 11),
+            (0, 8),
+            (0, 50),
+            (0, 2),
+            (0, 1),
+            (0, 2),
+            (0, 220),
+            (0, 3),
+            (0, 47),
+            (0, 0),
+            (0, 2283),
+            (0, 57),
+            (0, 15),
+            (0, 171),
+            (0, 125),
+            (0, 41),
+            (0, 443),
+            (0, 926),
+            (0, 10),
+            (0, 1030),
+            (0, 0),
+            (0, 6),
+            (0, 584),
+            (0, 2008),
+            (0, 209),
+            (0, 239),
+            (0, 2742),
+            (1, 328),
+            (0, 499),
+            (0, 3),
+            (0, 1837),
+            (0, 7),
+            (0, 1),
+            (0, 6),
+            (0, 20),
+            (0, 34),
+            (0, 81),
+            (0, 78),
+            (0, 254),
+            (0, 36),
+            (0, 112),
+            (0, 119),
+            (0, 2627),
+            (0, 170),
+            (0, 22),
+            (0, 464),
+            (0, 10),
+            (0, 511),
+            (0, 174),
+            (0, 6),
+            (0, 156),
+            (0, 714),
+            (0, 0),
+            (0, 198),
+            (0, 256),
+            (0, 44),
+            (0, 7),
+            (0, 390),
+            (0, 4),
+            (1, 2652),
+            (0, 417),
+            (0, 25),
+            (0, 113),
+            (0, 25),
+            (0, 82),
+            (0, 287),
+            (0, 997),
+            (0, 27),
+            (0, 593),
+            (0, 95),
+            (0, 568),
+            (0, 63),
+            (0, 210),
+            (0, 50),
+            (0, 20),
+            (1, 946),
+            (0, 1446),
+            (0, 262),
+            (0, 52),
+            (0, 2),
+            (0, 33),
+            (0, 13),
+            (0, 0),
+            (0, 14),
+            (0, 619),
+            (0, 31),
+            (0, 29),
+            (0, 3),
+            (0, 1118),
+            (0, 2333),
+            (0, 39),
+            (0, 402),
+            (0, 19),
+            (0, 0),
+            (0, 321),
+            (0, 34),
+            (0, 716),
+            (0, 64),
+            (0, 282),
+            (0, 0),
+            (0, 29),
+            (0, 5),
+            (0, 2),
+            (0, 5),
+            (0, 285),
+            (0, 734),
+            (0, 889),
+            (0, 5),
+            (0, 517),
+            (0, 355),
+            (0, 570),
+            (0, 143),
+            (0, 63),
+            (0, 21),
+            (0, 567),
+            (0, 86),
+            (0, 2),
+            (0, 17),
+            (0, 10),
+            (0, 29),
+            (0, 72),
+            (0, 151),
+            (1, 646),
+            (0, 2030),
+            (0, 2054),
+            (0, 1854),
+            (0, 2011),
+            (0, 363),
+            (0, 3),
+            (0, 61),
+            (0, 1172),
+            (1, 1839),
+            (0, 47),
+            (0, 838),
+            (0, 524),
+            (0, 435),
+            (0, 1395),
+            (0, 0),
+            (0, 2127),
+            (0, 23),
+            (0, 14),
+            (0, 19),
+            (0, 11),
+            (0, 1),
+            (0, 23),
+            (0, 0),
+            (0, 83),
+            (0, 11),
+            (0, 435),
+            (0, 59),
+            (0, 33),
+            (0, 957),
+            (0, 661),
+            (0, 1),
+            (0, 817),
+            (0, 74),
+            (0, 5),
+            (0, 12),
+            (0, 0),
+            (0, 101),
+            (0, 2100),
+            (0, 47),
+            (0, 5),
+            (1, 344),
+            (0, 141),
+            (1, 1456),
+            (0, 521),
+            (0, 7),
+            (0, 44),
+            (0, 81),
+            (0, 181),
+            (0, 1),
+            (0, 6),
+            (1, 2562),
+            (0, 380),
+            (0, 31),
+            (1, 1762),
+            (0, 874),
+            (0, 7),
+            (0, 1),
+            (0, 61),
+            (0, 64),
+            (0, 64),
+            (0, 255),
+            (0, 21),
+            (0, 27),
+            (0, 1),
+            (0, 24),
+            (1, 1383),
+            (2, 1021),
+            (0, 281),
+            (0, 17),
+            (0, 620),
+            (0, 8),
+            (0, 453),
+            (0, 1164),
+            (0, 1),
+            (0, 1994),
+            (0, 451),
+            (0, 648),
+            (1, 580),
+            (0, 6),
+            (0, 162),
+            (0, 88),
+            (0, 213),
+            (1, 2026),
+            (0, 2880),
+            (0, 0),
+            (0, 1003),
+            (0, 122),
+            (1, 685),
+            (0, 43),
+            (2, 411),
+            (0, 236),
+            (0, 46),
+            (0, 2401),
+            (0, 132),
+            (0, 170),
+            (0, 18),
+            (0, 1084),
+            (6, 2641),
+            (2, 2102),
+            (0, 208),
+            (3, 66),
+            (0, 3),
+            (0, 29),
+            (0, 16),
+            (0, 16),
+            (0, 66),
+            (1, 1567),
+            (0, 1095),
+            (0, 0),
+            (0, 638),
+            (0, 0),
+            (0, 0),
+            (2, 43),
+            (1, 284),
+            (0, 9),
+            (0, 1769),
+            (0, 25),
+            (0, 4),
+            (0, 1638),
+            (0, 2),
+            (0, 500),
+            (1, 1434),
+            (0, 1),
+            (1, 794),
+            (0, 309),
+            (0, 0),
+            (0, 1928),
+            (0, 979),
+            (5, 1292),
+            (1, 901),
+            (0, 537),
+            (0, 179),
+            (0, 1350),
+            (0, 1452),
+            (0, 69),
+            (0, 1),
+            (0, 472),
+            (6, 1111),
+            (0, 623),
+            (0, 37),
+            (0, 0),
+            (0, 1),
+            (0, 1068),
+            (2, 626),
+            (0, 129),
+            (0, 474),
+            (0, 3),
+            (1, 57),
+            (0, 7),
+            (0, 104),
+            (0, 1970),
+            (0, 125),
+            (0, 471),
+            (0, 306),
+            (0, 260),
+            (0, 8),
+            (3, 2279),
+            (2, 2549),
+            (0, 2),
+            (0, 228),
+            (0, 1180),
+            (0, 293),
+            (0, 814),
+            (0, 17),
+            (1, 2660),
+            (0, 64),
+            (0, 2587),
+            (0, 1043),
+            (0, 21),
+            (0, 0),
+            (0, 68),
+            (0, 215),
+            (0, 53),
+            (0, 546),
+            (1, 13),
+            (3, 1094),
+            (8, 301),
+            (2, 2613),
+            (0, 525),
+            (3, 778),
+            (0, 404),
+            (0, 125),
+            (5, 2781),
+            (4, 587),
+            (0, 2),
+            (0, 218),
+            (0, 370),
+            (0, 19),
+            (0, 0),
+            (0, 117),
+            (0, 1),
+            (3, 363),
+            (1, 1063),
+            (11, 160),
+            (0, 1052),
+            (0, 1876),
+            (0, 911),
+            (0, 1),
+            (0, 799),
+            (9, 291),
+            (0, 21),
+            (0, 114),
+            (0, 1226),
+            (0, 411),
+            (0, 0),
+            (1, 0),
+            (0, 9),
+            (0, 1757),
+            (0, 124),
+            (0, 90),
+            (0, 44),
+            (0, 165),
+            (0, 206),
+            (0, 534),
+            (0, 2735),
+            (0, 1030),
+            (7, 1543),
+            (7, 614),
+            (0, 1436),
+            (0, 2438),
+            (0, 2397),
+            (10, 2548),
+            (1, 119),
+            (0, 16),
+            (0, 9),
+            (3, 510),
+            (22, 2584),
+            (11, 2260),
+            (0, 281),
+            (0, 100),
+            (0, 1532),
+            (0, 2690),
+            (0, 0),
+            (0, 132),
+            (1, 851),
+        ],
+        entries: &[
+            ("figbassNatural", Self::FigbassNatural),
+            ("dynamicCombinedSeparatorHyphen", Self::DynamicCombinedSeparatorHyphen),
+            ("pictBeaterFist", Self::PictBeaterFist),
+            ("rest64th", Self::Rest64th),
+            ("glissandoUp", Self::GlissandoUp),
+            ("ottavaAlta", Self::OttavaAlta),
+            ("pictBeaterHammerPlasticDown", Self::PictBeaterHammerPlasticDown),
+            ("noteheadLargeArrowDownHalf", Self::NoteheadLargeArrowDownHalf),
+            ("chantDeminutumUpper", Self::ChantDeminutumUpper),
+            ("accidentalNaturalLoweredStockhausen", Self::AccidentalNaturalLoweredStockhausen),
+            ("accdnLH3Ranks8Square", Self::AccdnLh3Ranks8Square),
+            ("staffPosRaise2", Self::StaffPosRaise2),
+            ("accidentalSharpTwoArrowsUp", Self::AccidentalSharpTwoArrowsUp),
+            ("harpSalzedoIsolatedSounds", Self::HarpSalzedoIsolatedSounds),
+            ("noteHalfUp", Self::NoteHalfUp),
+            ("arrowBlackDownRight", Self::ArrowBlackDownRight),
+            ("wiggleRandom4", Self::WiggleRandom4),
+            ("pictHandbell", Self::PictHandbell),
+            ("functionDLower", Self::FunctionDLower),
+            ("keyboardPedalE", Self::KeyboardPedalE),
+            ("mensuralObliqueDesc4thWhite", Self::MensuralObliqueDesc4thWhite),
+            ("mensuralRestLongaImperfecta", Self::MensuralRestLongaImperfecta),
+            ("pictRainstick", Self::PictRainstick),
+            ("pictTurnRightLeftStem", Self::PictTurnRightLeftStem),
+            ("pictBeaterTrianglePlain", Self::PictBeaterTrianglePlain),
+            ("functionPLower", Self::FunctionPLower),
+            ("timeSig4", Self::TimeSig4),
+            ("accidentalJohnstonUp", Self::AccidentalJohnstonUp),
+            ("noteSoWhole", Self::NoteSoWhole),
+            ("pictBeaterSoftTimpaniDown", Self::PictBeaterSoftTimpaniDown),
+            ("tupletColon", Self::TupletColon),
+            ("accSagittalDoubleSharp5v7kDown", Self::AccSagittalDoubleSharp5V7KDown),
+            ("accidentalDoubleFlatOneArrowDown", Self::AccidentalDoubleFlatOneArrowDown),
+            ("accSagittal19CommaUp", Self::AccSagittal19CommaUp),
+            ("noteBSharpWhole", Self::NoteBSharpWhole),
+            ("mensuralWhiteSemibrevis", Self::MensuralWhiteSemibrevis),
+            ("accidentalNaturalTwoArrowsDown", Self::AccidentalNaturalTwoArrowsDown),
+            ("accSagittalDoubleFlat5v7kUp", Self::AccSagittalDoubleFlat5V7KUp),
+            ("tremolo5", Self::Tremolo5),
+            ("timeSigBracketLeft", Self::TimeSigBracketLeft),
+            ("beamAccelRit10", Self::BeamAccelRit10),
+            ("chantCirculusBelow", Self::ChantCirculusBelow),
+            ("arrowheadOpenDownRight", Self::ArrowheadOpenDownRight),
+            ("pictSleighBell", Self::PictSleighBell),
+            ("flag16thUp", Self::Flag16thUp),
+            ("timeSigCommon", Self::TimeSigCommon),
+            ("noteSoHalf", Self::NoteSoHalf),
+            ("stringsOverpressureUpBow", Self::StringsOverpressureUpBow),
+            ("csymParensRightTall", Self::CsymParensRightTall),
+            ("noteShapeMoonLeftBlack", Self::NoteShapeMoonLeftBlack),
+            ("noteDoBlack", Self::NoteDoBlack),
+            ("accidentalFlatLoweredStockhausen", Self::AccidentalFlatLoweredStockhausen),
+            ("accdnCombLH2RanksEmpty", Self::AccdnCombLh2RanksEmpty),
+            ("accSagittalSharp7CUp", Self::AccSagittalSharp7CUp),
+            ("keyboardBebung4DotsAbove", Self::KeyboardBebung4DotsAbove),
+            ("kievanNoteWholeFinal", Self::KievanNoteWholeFinal),
+            ("pictTomTomJapanese", Self::PictTomTomJapanese),
+            ("windStrongAirPressure", Self::WindStrongAirPressure),
+            ("accSagittal25SmallDiesisDown", Self::AccSagittal25SmallDiesisDown),
+            ("accidentalWyschnegradsky2TwelfthsSharp", Self::AccidentalWyschnegradsky2twelfthsSharp),
+            ("note512thDown", Self::Note512thDown),
+            ("beamAccelRit5", Self::BeamAccelRit5),
+            ("timeSigCommonReversed", Self::TimeSigCommonReversed),
+            ("noteheadSlashedWhole1", Self::NoteheadSlashedWhole1),
+            ("staffPosLower5", Self::StaffPosLower5),
+            ("controlEndSlur", Self::ControlEndSlur),
+            ("kahnToeDrop", Self::KahnToeDrop),
+            ("timeSigParensLeftSmall", Self::TimeSigParensLeftSmall),
+            ("harpSalzedoSlideWithSuppleness", Self::HarpSalzedoSlideWithSuppleness),
+            ("ornamentRightFacingHalfCircle", Self::OrnamentRightFacingHalfCircle),
+            ("mensuralTempusImperfectumHoriz", Self::MensuralTempusImperfectumHoriz),
+            ("mensuralRestMaxima", Self::MensuralRestMaxima),
+            ("kahnStompBrush", Self::KahnStompBrush),
+            ("accdnRH3RanksClarinet", Self::AccdnRh3RanksClarinet),
+            ("repeatBarSlash", Self::RepeatBarSlash),
+            ("accidentalSims12Up", Self::AccidentalSims12Up),
+            ("stringsDownBowTowardsBody", Self::StringsDownBowTowardsBody),
+            ("harpSalzedoOboicFlux", Self::HarpSalzedoOboicFlux),
+            ("accSagittalFlat23CDown", Self::AccSagittalFlat23CDown),
+            ("timeSig8Turned", Self::TimeSig8turned),
+            ("keyboardBebung2DotsBelow", Self::KeyboardBebung2DotsBelow),
+            ("mensuralProportion3", Self::MensuralProportion3),
+            ("accSagittal2MinasUp", Self::AccSagittal2MinasUp),
+            ("windVeryTightEmbouchure", Self::WindVeryTightEmbouchure),
+            ("timeSigMinus", Self::TimeSigMinus),
+            ("pictBeaterSuperballDown", Self::PictBeaterSuperballDown),
+            ("mensuralBlackSemibrevisCaudata", Self::MensuralBlackSemibrevisCaudata),
+            ("accidentalHalfSharpArrowUp", Self::AccidentalHalfSharpArrowUp),
+            ("accSagittal7v11KleismaDown", Self::AccSagittal7V11KleismaDown),
+            ("accidentalRaiseOneTridecimalQuartertone", Self::AccidentalRaiseOneTridecimalQuartertone),
+            ("accidentalRaiseOneUndecimalQuartertone", Self::AccidentalRaiseOneUndecimalQuartertone),
+            ("mensuralNoteheadLongaWhite", Self::MensuralNoteheadLongaWhite),
+            ("pictEdgeOfCymbal", Self::PictEdgeOfCymbal),
+            ("mensuralFclefPetrucci", Self::MensuralFclefPetrucci),
+            ("staff3Lines", Self::Staff3Lines),
+            ("accdnLH3RanksDouble8Square", Self::AccdnLh3RanksDouble8Square),
+            ("noteheadClusterDoubleWholeTop", Self::NoteheadClusterDoubleWholeTop),
+            ("chantEntryLineAsc4th", Self::ChantEntryLineAsc4th),
+            ("elecLoudspeaker", Self::ElecLoudspeaker),
+            ("accidentalWyschnegradsky2TwelfthsFlat", Self::AccidentalWyschnegradsky2twelfthsFlat),
+            ("stringsChangeBowDirection", Self::StringsChangeBowDirection),
+            ("stemSulPonticello", Self::StemSulPonticello),
+            ("guitarString8", Self::GuitarString8),
+            ("accidentalQuarterFlatEqualTempered", Self::AccidentalQuarterFlatEqualTempered),
+            ("accdnLH2Ranks16Round", Self::AccdnLh2Ranks16Round),
+            ("harpTuningKeyShank", Self::HarpTuningKeyShank),
+            ("pictBeaterHammer", Self::PictBeaterHammer),
+            ("repeatRight", Self::RepeatRight),
+            ("articSoftAccentStaccatoAbove", Self::ArticSoftAccentStaccatoAbove),
+            ("textBlackNoteFrac8thShortStem", Self::TextBlackNoteFrac8thShortStem),
+            ("accSagittal7v11CommaUp", Self::AccSagittal7V11CommaUp),
+            ("luteGermanELower", Self::LuteGermanELower),
+            ("organGerman3Fusae", Self::OrganGerman3Fusae),
+            ("metricModulationArrowRight", Self::MetricModulationArrowRight),
+            ("kahnLeapFlatFoot", Self::KahnLeapFlatFoot),
+            ("noteGHalf", Self::NoteGHalf),
+            ("analyticsThemeRetrograde", Self::AnalyticsThemeRetrograde),
+            ("pictCrushStem", Self::PictCrushStem),
+            ("mensuralTempusPerfectumHoriz", Self::MensuralTempusPerfectumHoriz),
+            ("accdnRicochet4", Self::AccdnRicochet4),
+            ("noteheadTriangleRightBlack", Self::NoteheadTriangleRightBlack),
+            ("accidentalSims12Down", Self::AccidentalSims12Down),
+            ("augmentationDot", Self::AugmentationDot),
+            ("medRenSharpCroix", Self::MedRenSharpCroix),
+            ("accSagittal6TinasUp", Self::AccSagittal6tinasUp),
+            ("windWeakAirPressure", Self::WindWeakAirPressure),
+            ("pictBeaterMediumYarnUp", Self::PictBeaterMediumYarnUp),
+            ("accSagittal11v19LargeDiesisUp", Self::AccSagittal11V19LargeDiesisUp),
+            ("brassFallRoughMedium", Self::BrassFallRoughMedium),
+            ("stringsBowBehindBridge", Self::StringsBowBehindBridge),
+            ("organGermanFUpper", Self::OrganGermanFUpper),
+            ("accSagittalDoubleSharp17CDown", Self::AccSagittalDoubleSharp17CDown),
+            ("stringsBowBehindBridgeThreeStrings", Self::StringsBowBehindBridgeThreeStrings),
+            ("fingeringRightBracketItalic", Self::FingeringRightBracketItalic),
+            ("noteheadDiamondDoubleWholeOld", Self::NoteheadDiamondDoubleWholeOld),
+            ("pictGumHardDown", Self::PictGumHardDown),
+            ("wiggleArpeggiatoUp", Self::WiggleArpeggiatoUp),
+            ("chantStrophicusLiquescens2nd", Self::ChantStrophicusLiquescens2nd),
+            ("fingering7Italic", Self::Fingering7Italic),
+            ("noteBHalf", Self::NoteBHalf),
+            ("luteGermanHUpper", Self::LuteGermanHUpper),
+            ("organGermanGisUpper", Self::OrganGermanGisUpper),
+            ("wiggleVibratoSmallFasterStill", Self::WiggleVibratoSmallFasterStill),
+            ("chantEntryLineAsc5th", Self::ChantEntryLineAsc5th),
+            ("pictBeaterSnareSticksUp", Self::PictBeaterSnareSticksUp),
+            ("luteGermanCLower", Self::LuteGermanCLower),
+            ("stemBowOnTailpiece", Self::StemBowOnTailpiece),
+            ("accidentalQuarterToneSharpBusotti", Self::AccidentalQuarterToneSharpBusotti),
+            ("timeSig7", Self::TimeSig7),
+            ("fingering3", Self::Fingering3),
+            ("wiggleVibratoSmallFast", Self::WiggleVibratoSmallFast),
+            ("articSoftAccentBelow", Self::ArticSoftAccentBelow),
+            ("accdnRicochet5", Self::AccdnRicochet5),
+            ("accSagittalFlat25SUp", Self::AccSagittalFlat25SUp),
+            ("accdnCombRH3RanksEmpty", Self::AccdnCombRh3RanksEmpty),
+            ("harpPedalDivider", Self::HarpPedalDivider),
+            ("flag64thUp", Self::Flag64thUp),
+            ("noteheadRoundWhiteSlashedLarge", Self::NoteheadRoundWhiteSlashedLarge),
+            ("keyboardPedalP", Self::KeyboardPedalP),
+            ("chantPunctumInclinatumDeminutum", Self::ChantPunctumInclinatumDeminutum),
+            ("accSagittalFlat5v11SUp", Self::AccSagittalFlat5V11SUp),
+            ("analyticsHauptrhythmus", Self::AnalyticsHauptrhythmus),
+            ("gClefTurned", Self::GClefTurned),
+            ("noteShapeIsoscelesTriangleDoubleWhole", Self::NoteShapeIsoscelesTriangleDoubleWhole),
+            ("pictBeaterBrassMalletsUp", Self::PictBeaterBrassMalletsUp),
+            ("tremolo3", Self::Tremolo3),
+            ("caesuraThick", Self::CaesuraThick),
+            ("tuplet0", Self::Tuplet0),
+            ("accidentalBracketLeft", Self::AccidentalBracketLeft),
+            ("elecShuffle", Self::ElecShuffle),
+            ("mensuralProportion1", Self::MensuralProportion1),
+            ("flagInternalDown", Self::FlagInternalDown),
+            ("stringsDownBowBeyondBridge", Self::StringsDownBowBeyondBridge),
+            ("keyboardBebung4DotsBelow", Self::KeyboardBebung4DotsBelow),
+            ("kahnScrape", Self::KahnScrape),
+            ("figbassDoubleFlat", Self::FigbassDoubleFlat),
+            ("mensuralObliqueDesc5thBlackVoid", Self::MensuralObliqueDesc5thBlackVoid),
+            ("noteheadRoundWhiteWithDot", Self::NoteheadRoundWhiteWithDot),
+            ("pictSiren", Self::PictSiren),
+            ("noteShapeDiamondWhite", Self::NoteShapeDiamondWhite),
+            ("accidentalTwoThirdTonesSharpFerneyhough", Self::AccidentalTwoThirdTonesSharpFerneyhough),
+            ("4stringTabClef", Self::_4StringTabClef),
+            ("brassJazzTurn", Self::BrassJazzTurn),
+            ("smnHistoryDoubleFlat", Self::SmnHistoryDoubleFlat),
+            ("ornamentPrecompSlideTrillDAnglebert", Self::OrnamentPrecompSlideTrillDAnglebert),
+            ("pictFingerCymbals", Self::PictFingerCymbals),
+            ("keyboardPedalHalf3", Self::KeyboardPedalHalf3),
+            ("accidentalQuarterToneFlatArabic", Self::AccidentalQuarterToneFlatArabic),
+            ("timeSigCut2", Self::TimeSigCut2),
+            ("accSagittal5v23SmallDiesisUp", Self::AccSagittal5V23SmallDiesisUp),
+            ("arrowheadWhiteDownLeft", Self::ArrowheadWhiteDownLeft),
+            ("luteGermanILower", Self::LuteGermanILower),
+            ("timeSigFractionQuarter", Self::TimeSigFractionQuarter),
+            ("textHeadlessBlackNoteFrac8thShortStem", Self::TextHeadlessBlackNoteFrac8thShortStem),
+            ("luteGermanAUpper", Self::LuteGermanAUpper),
+            ("arrowBlackUpRight", Self::ArrowBlackUpRight),
+            ("luteFrenchFretM", Self::LuteFrenchFretM),
+            ("accSagittal4TinasUp", Self::AccSagittal4tinasUp),
+            ("accdnRH3RanksBandoneon", Self::AccdnRh3RanksBandoneon),
+            ("pictBeaterWoodXylophoneUp", Self::PictBeaterWoodXylophoneUp),
+            ("arrowWhiteLeft", Self::ArrowWhiteLeft),
+            ("timeSig7Turned", Self::TimeSig7turned),
+            ("accdnLH2RanksMasterPlus16Round", Self::AccdnLh2RanksMasterPlus16Round),
+            ("octaveLoco", Self::OctaveLoco),
+            ("pictBeaterMediumYarnRight", Self::PictBeaterMediumYarnRight),
+            ("organGermanFusaRest", Self::OrganGermanFusaRest),
+            ("noteBSharpBlack", Self::NoteBSharpBlack),
+            ("accSagittalSharp19sUp", Self::AccSagittalSharp19SUp),
+            ("accdnRH3RanksOboe", Self::AccdnRh3RanksOboe),
+            ("elecAudioChannelsFive", Self::ElecAudioChannelsFive),
+            ("functionDD", Self::FunctionDd),
+            ("mensuralObliqueAsc3rdVoid", Self::MensuralObliqueAsc3RdVoid),
+            ("noteEmptyHalf", Self::NoteEmptyHalf),
+            ("metNote1024thDown", Self::MetNote1024thDown),
+            ("pictGongWithButton", Self::PictGongWithButton),
+            ("windReedPositionOut", Self::WindReedPositionOut),
+            ("fingeringCLower", Self::FingeringCLower),
+            ("accSagittal7TinasUp", Self::AccSagittal7tinasUp),
+            ("buzzRoll", Self::BuzzRoll),
+            ("metNote32ndUp", Self::MetNote32ndUp),
+            ("accSagittalSharp11v49CDown", Self::AccSagittalSharp11V49CDown),
+            ("arrowheadWhiteRight", Self::ArrowheadWhiteRight),
+            ("arrowOpenUp", Self::ArrowOpenUp),
+            ("reversedBrace", Self::ReversedBrace),
+            ("flag128thDown", Self::Flag128thDown),
+            ("daseianGraves2", Self::DaseianGraves2),
+            ("noteheadTriangleRightWhite", Self::NoteheadTriangleRightWhite),
+            ("accidentalCommaSlashDown", Self::AccidentalCommaSlashDown),
+            ("accidentalWyschnegradsky11TwelfthsFlat", Self::AccidentalWyschnegradsky11twelfthsFlat),
+            ("breathMarkTick", Self::BreathMarkTick),
+            ("noteFaBlack", Self::NoteFaBlack),
+            ("tremoloFingered3", Self::TremoloFingered3),
+            ("elecAudioChannelsThreeFrontal", Self::ElecAudioChannelsThreeFrontal),
+            ("accidentalReversedFlatAndFlatArrowUp", Self::AccidentalReversedFlatAndFlatArrowUp),
+            ("noteheadClusterQuarter2nd", Self::NoteheadClusterQuarter2nd),
+            ("accdnRH3RanksHarmonium", Self::AccdnRh3RanksHarmonium),
+            ("timeSig1Turned", Self::TimeSig1turned),
+            ("luteFingeringRHSecond", Self::LuteFingeringRhSecond),
+            ("metNoteDoubleWholeSquare", Self::MetNoteDoubleWholeSquare),
+            ("noteheadTriangleDownHalf", Self::NoteheadTriangleDownHalf),
+            ("mensuralProlationCombiningTwoDots", Self::MensuralProlationCombiningTwoDots),
+            ("mensuralBlackSemiminima", Self::MensuralBlackSemiminima),
+            ("articTenutoStaccatoBelow", Self::ArticTenutoStaccatoBelow),
+            ("pictCencerro", Self::PictCencerro),
+            ("noteEBlack", Self::NoteEBlack),
+            ("accidentalDoubleFlatThreeArrowsUp", Self::AccidentalDoubleFlatThreeArrowsUp),
+            ("clefChangeCombining", Self::ClefChangeCombining),
+            ("gClef8vbParens", Self::GClef8VbParens),
+            ("accSagittalFractionalTinaDown", Self::AccSagittalFractionalTinaDown),
+            ("guitarString4", Self::GuitarString4),
+            ("wiggleArpeggiatoDown", Self::WiggleArpeggiatoDown),
+            ("accidental3CommaSharp", Self::Accidental3CommaSharp),
+            ("noteESharpWhole", Self::NoteESharpWhole),
+            ("brassFallSmoothMedium", Self::BrassFallSmoothMedium),
+            ("kahnFlapStep", Self::KahnFlapStep),
+            ("accidentalSharpSharp", Self::AccidentalSharpSharp),
+            ("beamAccelRit14", Self::BeamAccelRit14),
+            ("fretboardO", Self::FretboardO),
+            ("ornamentHighLeftConvexStroke", Self::OrnamentHighLeftConvexStroke),
+            ("pictTimbales", Self::PictTimbales),
+            ("luteGermanVLower", Self::LuteGermanVLower),
+            ("mensuralProlationCombiningDotVoid", Self::MensuralProlationCombiningDotVoid),
+            ("chantPunctumInclinatum", Self::ChantPunctumInclinatum),
+            ("wiggleSawtoothWide", Self::WiggleSawtoothWide),
+            ("ornamentPrecompTrillWithMordent", Self::OrnamentPrecompTrillWithMordent),
+            ("noteheadTriangleUpRightBlack", Self::NoteheadTriangleUpRightBlack),
+            ("brassFallLipMedium", Self::BrassFallLipMedium),
+            ("noteheadTriangleDownWhole", Self::NoteheadTriangleDownWhole),
+            ("noteSoBlack", Self::NoteSoBlack),
+            ("metNote32ndDown", Self::MetNote32ndDown),
+            ("barlineDashed", Self::BarlineDashed),
+            ("ornamentHaydn", Self::OrnamentHaydn),
+            ("organGermanGisLower", Self::OrganGermanGisLower),
+            ("fingering1", Self::Fingering1),
+            ("mensuralCustosDown", Self::MensuralCustosDown),
+            ("elecHeadphones", Self::ElecHeadphones),
+            ("noteDSharpHalf", Self::NoteDSharpHalf),
+            ("ornamentPinceCouperin", Self::OrnamentPinceCouperin),
+            ("conductorWeakBeat", Self::ConductorWeakBeat),
+            ("arrowBlackDown", Self::ArrowBlackDown),
+            ("ornamentLeftFacingHook", Self::OrnamentLeftFacingHook),
+            ("fretboardFilledCircle", Self::FretboardFilledCircle),
+            ("dynamicSforzando1", Self::DynamicSforzando1),
+            ("accidentalFlatTwoArrowsUp", Self::AccidentalFlatTwoArrowsUp),
+            ("accdnCombLH3RanksEmptySquare", Self::AccdnCombLh3RanksEmptySquare),
+            ("ornamentSchleifer", Self::OrnamentSchleifer),
+            ("medRenStrophicusCMN", Self::MedRenStrophicusCmn),
+            ("quindicesimaAlta", Self::QuindicesimaAlta),
+            ("accidentalNaturalSharp", Self::AccidentalNaturalSharp),
+            ("keyboardBebung2DotsAbove", Self::KeyboardBebung2DotsAbove),
+            ("noteMeWhole", Self::NoteMeWhole),
+            ("arrowheadWhiteUpLeft", Self::ArrowheadWhiteUpLeft),
+            ("noteheadRectangularClusterBlackBottom", Self::NoteheadRectangularClusterBlackBottom),
+            ("ornamentBottomRightConcaveStroke", Self::OrnamentBottomRightConcaveStroke),
+            ("accSagittalAcute", Self::AccSagittalAcute),
+            ("noteheadRectangularClusterWhiteTop", Self::NoteheadRectangularClusterWhiteTop),
+            ("noteheadClusterQuarterBottom", Self::NoteheadClusterQuarterBottom),
+            ("luteGermanBUpper", Self::LuteGermanBUpper),
+            ("stringsFouette", Self::StringsFouette),
+            ("accidentalFilledReversedFlatAndFlat", Self::AccidentalFilledReversedFlatAndFlat),
+            ("luteItalianTempoVerySlow", Self::LuteItalianTempoVerySlow),
+            ("figbass6Raised", Self::Figbass6Raised),
+            ("pictBeaterBrassMalletsRight", Self::PictBeaterBrassMalletsRight),
+            ("accidentalDoubleSharpArabic", Self::AccidentalDoubleSharpArabic),
+            ("noteShapeMoonWhite", Self::NoteShapeMoonWhite),
+            ("cClefArrowDown", Self::CClefArrowDown),
+            ("accSagittalSharp5v7kUp", Self::AccSagittalSharp5V7KUp),
+            ("noteEmptyWhole", Self::NoteEmptyWhole),
+            ("wiggleVibratoSmallSlow", Self::WiggleVibratoSmallSlow),
+            ("arrowWhiteRight", Self::ArrowWhiteRight),
+            ("pictRecoReco", Self::PictRecoReco),
+            ("accSagittal49MediumDiesisUp", Self::AccSagittal49MediumDiesisUp),
+            ("luteStaff6LinesNarrow", Self::LuteStaff6LinesNarrow),
+            ("stockhausenTremolo", Self::StockhausenTremolo),
+            ("tremolo4", Self::Tremolo4),
+            ("doubleLateralRollStevens", Self::DoubleLateralRollStevens),
+            ("mensuralWhiteMaxima", Self::MensuralWhiteMaxima),
+            ("ventiduesimaBassaMb", Self::VentiduesimaBassaMb),
+            ("timeSig8", Self::TimeSig8),
+            ("pluckedDamp", Self::PluckedDamp),
+            ("accSagittal5v49MediumDiesisUp", Self::AccSagittal5V49MediumDiesisUp),
+            ("accSagittal7v11KleismaUp", Self::AccSagittal7V11KleismaUp),
+            ("accSagittalGrave", Self::AccSagittalGrave),
+            ("organGerman3Minimae", Self::OrganGerman3Minimae),
+            ("luteItalianFret4", Self::LuteItalianFret4),
+            ("noteheadHeavyXHat", Self::NoteheadHeavyXHat),
+            ("ornamentTopRightConvexStroke", Self::OrnamentTopRightConvexStroke),
+            ("fingering9Italic", Self::Fingering9Italic),
+            ("kahnScuffle", Self::KahnScuffle),
+            ("metNote16thDown", Self::MetNote16thDown),
+            ("keyboardPedalUpSpecial", Self::KeyboardPedalUpSpecial),
+            ("noteheadParenthesisRight", Self::NoteheadParenthesisRight),
+            ("pictSizzleCymbal", Self::PictSizzleCymbal),
+            ("noteLaHalf", Self::NoteLaHalf),
+            ("elecLoop", Self::ElecLoop),
+            ("mensuralSignumUp", Self::MensuralSignumUp),
+            ("articStaccatissimoWedgeBelow", Self::ArticStaccatissimoWedgeBelow),
+            ("elecProjector", Self::ElecProjector),
+            ("organGerman6Fusae", Self::OrganGerman6Fusae),
+            ("conductorStrongBeat", Self::ConductorStrongBeat),
+            ("accdnRicochetStem4", Self::AccdnRicochetStem4),
+            ("fingeringLeftBracket", Self::FingeringLeftBracket),
+            ("noteShapeTriangleRoundLeftDoubleWhole", Self::NoteShapeTriangleRoundLeftDoubleWhole),
+            ("timeSig4Reversed", Self::TimeSig4Reversed),
+            ("pictBeaterSoftXylophoneLeft", Self::PictBeaterSoftXylophoneLeft),
+            ("legerLineNarrow", Self::LegerLineNarrow),
+            ("pictGumMediumDown", Self::PictGumMediumDown),
+            ("articStaccatissimoBelow", Self::ArticStaccatissimoBelow),
+            ("mensuralCombStemDownFlagFusa", Self::MensuralCombStemDownFlagFusa),
+            ("organGermanHLower", Self::OrganGermanHLower),
+            ("functionGreaterThan", Self::FunctionGreaterThan),
+            ("keyboardPedalDot", Self::KeyboardPedalDot),
+            ("windReedPositionNormal", Self::WindReedPositionNormal),
+            ("pictDamp4", Self::PictDamp4),
+            ("fingeringMLower", Self::FingeringMLower),
+            ("pictBeaterSoftXylophoneRight", Self::PictBeaterSoftXylophoneRight),
+            ("pictRimShotOnStem", Self::PictRimShotOnStem),
+            ("graceNoteAcciaccaturaStemUp", Self::GraceNoteAcciaccaturaStemUp),
+            ("mensuralProlation4", Self::MensuralProlation4),
+            ("dynamicForte", Self::DynamicForte),
+            ("elecVolumeFaderThumb", Self::ElecVolumeFaderThumb),
+            ("ornamentBottomLeftConcaveStroke", Self::OrnamentBottomLeftConcaveStroke),
+            ("wiggleCircularSmall", Self::WiggleCircularSmall),
+            ("dynamicPPPPP", Self::DynamicPpppp),
+            ("accSagittalSharp5v23SUp", Self::AccSagittalSharp5V23SUp),
+            ("pictSistrum", Self::PictSistrum),
+            ("noteQuarterUp", Self::NoteQuarterUp),
+            ("windRimOnly", Self::WindRimOnly),
+            ("vocalMouthPursed", Self::VocalMouthPursed),
+            ("staffDivideArrowDown", Self::StaffDivideArrowDown),
+            ("accSagittalUnused3", Self::AccSagittalUnused3),
+            ("staff5LinesNarrow", Self::Staff5LinesNarrow),
+            ("flag1024thUp", Self::Flag1024thUp),
+            ("keyboardPedalParensRight", Self::KeyboardPedalParensRight),
+            ("accidentalTwoThirdTonesFlatFerneyhough", Self::AccidentalTwoThirdTonesFlatFerneyhough),
+            ("chantDivisioMinima", Self::ChantDivisioMinima),
+            ("accSagittal2TinasUp", Self::AccSagittal2tinasUp),
+            ("mensuralObliqueDesc4thBlackVoid", Self::MensuralObliqueDesc4thBlackVoid),
+            ("pictBeaterMetalDown", Self::PictBeaterMetalDown),
+            ("pictGlassPlateChimes", Self::PictGlassPlateChimes),
+            ("stemPendereckiTremolo", Self::StemPendereckiTremolo),
+            ("timeSigBracketLeftSmall", Self::TimeSigBracketLeftSmall),
+            ("accidentalBakiyeSharp", Self::AccidentalBakiyeSharp),
+            ("ottava", Self::Ottava),
+            ("pictBeaterCombiningDashedCircle", Self::PictBeaterCombiningDashedCircle),
+            ("functionSlashedDD", Self::FunctionSlashedDd),
+            ("figbassFlat", Self::FigbassFlat),
+            ("ornamentObliqueLineHorizBeforeNote", Self::OrnamentObliqueLineHorizBeforeNote),
+            ("mensuralCclefPetrucciPosHighest", Self::MensuralCclefPetrucciPosHighest),
+            ("stringsUpBowTowardsBody", Self::StringsUpBowTowardsBody),
+            ("noteShapeQuarterMoonDoubleWhole", Self::NoteShapeQuarterMoonDoubleWhole),
+            ("stringsVibratoPulse", Self::StringsVibratoPulse),
+            ("dynamicNiente", Self::DynamicNiente),
+            ("chantConnectingLineAsc2nd", Self::ChantConnectingLineAsc2nd),
+            ("mensuralCombStemDownFlagRight", Self::MensuralCombStemDownFlagRight),
+            ("organGermanSemifusaRest", Self::OrganGermanSemifusaRest),
+            ("pictBeaterMediumTimpaniUp", Self::PictBeaterMediumTimpaniUp),
+            ("organGermanBUpper", Self::OrganGermanBUpper),
+            ("noteFSharpHalf", Self::NoteFSharpHalf),
+            ("brassLiftSmoothMedium", Self::BrassLiftSmoothMedium),
+            ("accSagittal5v49MediumDiesisDown", Self::AccSagittal5V49MediumDiesisDown),
+            ("dynamicPF", Self::DynamicPf),
+            ("wiggleVibratoSmallestSlowest", Self::WiggleVibratoSmallestSlowest),
+            ("elecUnmute", Self::ElecUnmute),
+            ("accSagittalDoubleSharp5CDown", Self::AccSagittalDoubleSharp5CDown),
+            ("legerLine", Self::LegerLine),
+            ("pictBeaterMetalUp", Self::PictBeaterMetalUp),
+            ("csymParensLeftVeryTall", Self::CsymParensLeftVeryTall),
+            ("accidentalOneQuarterToneSharpFerneyhough", Self::AccidentalOneQuarterToneSharpFerneyhough),
+            ("chantLigaturaDesc5th", Self::ChantLigaturaDesc5th),
+            ("fingering6Italic", Self::Fingering6Italic),
+            ("pictBeaterMetalLeft", Self::PictBeaterMetalLeft),
+            ("kahnRip", Self::KahnRip),
+            ("tuplet9", Self::Tuplet9),
+            ("metNote128thDown", Self::MetNote128thDown),
+            ("kahnHeelDrop", Self::KahnHeelDrop),
+            ("flag16thDown", Self::Flag16thDown),
+            ("mensuralCclef", Self::MensuralCclef),
+            ("articSoftAccentTenutoStaccatoAbove", Self::ArticSoftAccentTenutoStaccatoAbove),
+            ("accSagittalShaftUp", Self::AccSagittalShaftUp),
+            ("accidentalReversedFlatAndFlatArrowDown", Self::AccidentalReversedFlatAndFlatArrowDown),
+            ("accSagittalFlat7v11kUp", Self::AccSagittalFlat7V11KUp),
+            ("chantConnectingLineAsc6th", Self::ChantConnectingLineAsc6th),
+            ("noteheadRoundBlackDoubleSlashed", Self::NoteheadRoundBlackDoubleSlashed),
+            ("graceNoteAppoggiaturaStemUp", Self::GraceNoteAppoggiaturaStemUp),
+            ("fermataShortHenzeAbove", Self::FermataShortHenzeAbove),
+            ("luteBarlineStartRepeat", Self::LuteBarlineStartRepeat),
+            ("noteEFlatWhole", Self::NoteEFlatWhole),
+            ("wiggleSawtooth", Self::WiggleSawtooth),
+            ("noteShapeIsoscelesTriangleWhite", Self::NoteShapeIsoscelesTriangleWhite),
+            ("analyticsHauptstimme", Self::AnalyticsHauptstimme),
+            ("pictBeaterHardBassDrumDown", Self::PictBeaterHardBassDrumDown),
+            ("noteheadClusterHalf3rd", Self::NoteheadClusterHalf3Rd),
+            ("accdnRH3RanksViolin", Self::AccdnRh3RanksViolin),
+            ("keyboardPedalHeelToToe", Self::KeyboardPedalHeelToToe),
+            ("schaefferPreviousClef", Self::SchaefferPreviousClef),
+            ("luteItalianReleaseFinger", Self::LuteItalianReleaseFinger),
+            ("pictBeaterHardYarnDown", Self::PictBeaterHardYarnDown),
+            ("figbass2", Self::Figbass2),
+            ("accidentalJohnston31", Self::AccidentalJohnston31),
+            ("handbellsMartellatoLift", Self::HandbellsMartellatoLift),
+            ("pictRim2", Self::PictRim2),
+            ("stringsThumbPosition", Self::StringsThumbPosition),
+            ("elecCamera", Self::ElecCamera),
+            ("breathMarkComma", Self::BreathMarkComma),
+            ("staffPosLower7", Self::StaffPosLower7),
+            ("kahnGraceTapHop", Self::KahnGraceTapHop),
+            ("pictCoins", Self::PictCoins),
+            ("chantCustosStemUpPosMiddle", Self::ChantCustosStemUpPosMiddle),
+            ("noteheadSlashVerticalEndsSmall", Self::NoteheadSlashVerticalEndsSmall),
+            ("organGerman3Semiminimae", Self::OrganGerman3Semiminimae),
+            ("csymAugmented", Self::CsymAugmented),
+            ("accidentalFlatThreeArrowsDown", Self::AccidentalFlatThreeArrowsDown),
+            ("pictGumSoftDown", Self::PictGumSoftDown),
+            ("accSagittalFlat5v7kUp", Self::AccSagittalFlat5V7KUp),
+            ("note128thDown", Self::Note128thDown),
+            ("noteCSharpBlack", Self::NoteCSharpBlack),
+            ("accidentalWyschnegradsky6TwelfthsFlat", Self::AccidentalWyschnegradsky6twelfthsFlat),
+            ("note128thUp", Self::Note128thUp),
+            ("noteheadSlashVerticalEndsMuted", Self::NoteheadSlashVerticalEndsMuted),
+            ("luteItalianTempoSlow", Self::LuteItalianTempoSlow),
+            ("figbass6Raised2", Self::Figbass6Raised2),
+            ("textTupletBracketEndShortStem", Self::TextTupletBracketEndShortStem),
+            ("noteBSharpHalf", Self::NoteBSharpHalf),
+            ("elecHeadset", Self::ElecHeadset),
+            ("mensuralObliqueAsc2ndVoid", Self::MensuralObliqueAsc2ndVoid),
+            ("dalSegno", Self::DalSegno),
+            ("clef15", Self::Clef15),
+            ("pictBeaterHardGlockenspielUp", Self::PictBeaterHardGlockenspielUp),
+            ("articStaccatissimoWedgeAbove", Self::ArticStaccatissimoWedgeAbove),
+            ("kahnSlideStep", Self::KahnSlideStep),
+            ("pictSwishStem", Self::PictSwishStem),
+            ("fClef15ma", Self::FClef15Ma),
+            ("accSagittal6TinasDown", Self::AccSagittal6tinasDown),
+            ("bracketTop", Self::BracketTop),
+            ("dynamicPiano", Self::DynamicPiano),
+            ("repeat4Bars", Self::Repeat4Bars),
+            ("textBlackNoteLongStem", Self::TextBlackNoteLongStem),
+            ("lyricsHyphenBaselineNonBreaking", Self::LyricsHyphenBaselineNonBreaking),
+            ("accidentalThreeQuarterTonesFlatZimmermann", Self::AccidentalThreeQuarterTonesFlatZimmermann),
+            ("pictGumHardRight", Self::PictGumHardRight),
+            ("noteShapeRoundBlack", Self::NoteShapeRoundBlack),
+            ("luteGermanNLower", Self::LuteGermanNLower),
+            ("articMarcatoTenutoAbove", Self::ArticMarcatoTenutoAbove),
+            ("tremolo1", Self::Tremolo1),
+            ("accSagittal23CommaDown", Self::AccSagittal23CommaDown),
+            ("mensuralColorationStartSquare", Self::MensuralColorationStartSquare),
+            ("harpSalzedoTimpanicSounds", Self::HarpSalzedoTimpanicSounds),
+            ("fingeringRightParenthesis", Self::FingeringRightParenthesis),
+            ("mensuralAlterationSign", Self::MensuralAlterationSign),
+            ("luteFrench7thCourse", Self::LuteFrench7thCourse),
+            ("pictBeaterSuperballLeft", Self::PictBeaterSuperballLeft),
+            ("ornamentDownCurve", Self::OrnamentDownCurve),
+            ("textBlackNoteFrac8thLongStem", Self::TextBlackNoteFrac8thLongStem),
+            ("accSagittalSharp143CDown", Self::AccSagittalSharp143CDown),
+            ("elecVolumeLevel40", Self::ElecVolumeLevel40),
+            ("accidentalCombiningRaise29LimitComma", Self::AccidentalCombiningRaise29LimitComma),
+            ("accidentalDoubleFlatTwoArrowsUp", Self::AccidentalDoubleFlatTwoArrowsUp),
+            ("pendereckiTremolo", Self::PendereckiTremolo),
+            ("pluckedSnapPizzicatoBelow", Self::PluckedSnapPizzicatoBelow),
+            ("dynamicNienteForHairpin", Self::DynamicNienteForHairpin),
+            ("figbassPlus", Self::FigbassPlus),
+            ("mensuralProlation5", Self::MensuralProlation5),
+            ("stringsMuteOff", Self::StringsMuteOff),
+            ("staffPosRaise7", Self::StaffPosRaise7),
+            ("accidentalJohnston13", Self::AccidentalJohnston13),
+            ("pictOpen", Self::PictOpen),
+            ("medRenOriscusCMN", Self::MedRenOriscusCmn),
+            ("mensuralObliqueDesc4thVoid", Self::MensuralObliqueDesc4thVoid),
+            ("kahnToeStep", Self::KahnToeStep),
+            ("accSagittalFlat5v19CUp", Self::AccSagittalFlat5V19CUp),
+            ("pictRatchet", Self::PictRatchet),
+            ("fermataLongBelow", Self::FermataLongBelow),
+            ("accidental1CommaFlat", Self::Accidental1CommaFlat),
+            ("organGermanBuxheimerSemibrevis", Self::OrganGermanBuxheimerSemibrevis),
+            ("kahnHeelChange", Self::KahnHeelChange),
+            ("handbellsHandMartellato", Self::HandbellsHandMartellato),
+            ("articSoftAccentStaccatoBelow", Self::ArticSoftAccentStaccatoBelow),
+            ("mensuralNoteheadSemibrevisBlack", Self::MensuralNoteheadSemibrevisBlack),
+            ("brassHarmonMuteStemHalfRight", Self::BrassHarmonMuteStemHalfRight),
+            ("elecStop", Self::ElecStop),
+            ("timeSigParensLeft", Self::TimeSigParensLeft),
+            ("accidentalDoubleSharpThreeArrowsUp", Self::AccidentalDoubleSharpThreeArrowsUp),
+            ("luteFrenchFretA", Self::LuteFrenchFretA),
+            ("wiggleVibratoLargeSlower", Self::WiggleVibratoLargeSlower),
+            ("functionMinus", Self::FunctionMinus),
+            ("pictOpenRimShot", Self::PictOpenRimShot),
+            ("fingering0Italic", Self::Fingering0Italic),
+            ("elecReplay", Self::ElecReplay),
+            ("noteheadClusterWhole3rd", Self::NoteheadClusterWhole3Rd),
+            ("arrowheadOpenDown", Self::ArrowheadOpenDown),
+            ("beamAccelRit4", Self::BeamAccelRit4),
+            ("keyboardPedalToeToHeel", Self::KeyboardPedalToeToHeel),
+            ("tremoloDivisiDots4", Self::TremoloDivisiDots4),
+            ("elecMIDIController40", Self::ElecMidiController40),
+            ("luteGermanBLower", Self::LuteGermanBLower),
+            ("pictBeaterMetalHammer", Self::PictBeaterMetalHammer),
+            ("pictBassDrum", Self::PictBassDrum),
+            ("noteFFlatBlack", Self::NoteFFlatBlack),
+            ("functionVUpper", Self::FunctionVUpper),
+            ("mensuralCombStemUpFlagRight", Self::MensuralCombStemUpFlagRight),
+            ("accidentalSharpThreeArrowsDown", Self::AccidentalSharpThreeArrowsDown),
+            ("accSagittalFlat11v19LDown", Self::AccSagittalFlat11V19LDown),
+            ("articSoftAccentTenutoAbove", Self::ArticSoftAccentTenutoAbove),
+            ("accidentalHabaQuarterToneLower", Self::AccidentalHabaQuarterToneLower),
+            ("figbass4Raised", Self::Figbass4Raised),
+            ("accSagittal25SmallDiesisUp", Self::AccSagittal25SmallDiesisUp),
+            ("dynamicRinforzando2", Self::DynamicRinforzando2),
+            ("accidentalQuarterToneFlatArrowUp", Self::AccidentalQuarterToneFlatArrowUp),
+            ("flag64thDown", Self::Flag64thDown),
+            ("smnSharpWhite", Self::SmnSharpWhite),
+            ("kievanAccidentalSharp", Self::KievanAccidentalSharp),
+            ("arrowheadBlackRight", Self::ArrowheadBlackRight),
+            ("guitarLeftHandTapping", Self::GuitarLeftHandTapping),
+            ("accSagittalSharp143CUp", Self::AccSagittalSharp143CUp),
+            ("ornamentTopLeftConvexStroke", Self::OrnamentTopLeftConvexStroke),
+            ("textCont16thBeamShortStem", Self::TextCont16thBeamShortStem),
+            ("timeSig6", Self::TimeSig6),
+            ("daseianSuperiores4", Self::DaseianSuperiores4),
+            ("organGerman4Minimae", Self::OrganGerman4Minimae),
+            ("accidentalCombiningLower23Limit29LimitComma", Self::AccidentalCombiningLower23Limit29LimitComma),
+            ("accSagittalSharp25SUp", Self::AccSagittalSharp25SUp),
+            ("note64thUp", Self::Note64thUp),
+            ("functionPlus", Self::FunctionPlus),
+            ("elecMIDIController100", Self::ElecMidiController100),
+            ("accdnLH3RanksTuttiSquare", Self::AccdnLh3RanksTuttiSquare),
+            ("guitarFadeIn", Self::GuitarFadeIn),
+            ("pictShellBells", Self::PictShellBells),
+            ("restWhole", Self::RestWhole),
+            ("csymDiagonalArrangementSlash", Self::CsymDiagonalArrangementSlash),
+            ("ornamentObliqueLineAfterNote", Self::OrnamentObliqueLineAfterNote),
+            ("accSagittalDoubleSharp7v19CDown", Self::AccSagittalDoubleSharp7V19CDown),
+            ("chantAccentusBelow", Self::ChantAccentusBelow),
+            ("vocalMouthClosed", Self::VocalMouthClosed),
+            ("wiggleVibratoLargestFaster", Self::WiggleVibratoLargestFaster),
+            ("elecMIDIController60", Self::ElecMidiController60),
+            ("noteASharpWhole", Self::NoteASharpWhole),
+            ("elecUpload", Self::ElecUpload),
+            ("accdnRH3RanksPiccolo", Self::AccdnRh3RanksPiccolo),
+            ("accidentalThreeQuarterTonesFlatCouper", Self::AccidentalThreeQuarterTonesFlatCouper),
+            ("fermataVeryLongBelow", Self::FermataVeryLongBelow),
+            ("stringsBowBehindBridgeFourStrings", Self::StringsBowBehindBridgeFourStrings),
+            ("accidentalHabaSharpThreeQuarterTonesHigher", Self::AccidentalHabaSharpThreeQuarterTonesHigher),
+            ("noteSeBlack", Self::NoteSeBlack),
+            ("noteSeWhole", Self::NoteSeWhole),
+            ("accidental2CommaSharp", Self::Accidental2CommaSharp),
+            ("arrowWhiteUpLeft", Self::ArrowWhiteUpLeft),
+            ("pictBeaterWoodTimpaniDown", Self::PictBeaterWoodTimpaniDown),
+            ("textAugmentationDot", Self::TextAugmentationDot),
+            ("mensuralWhiteFusa", Self::MensuralWhiteFusa),
+            ("noteRaWhole", Self::NoteRaWhole),
+            ("pictLionsRoar", Self::PictLionsRoar),
+            ("stemMultiphonicsBlackWhite", Self::StemMultiphonicsBlackWhite),
+            ("noteDFlatHalf", Self::NoteDFlatHalf),
+            ("noteheadTriangleUpWhite", Self::NoteheadTriangleUpWhite),
+            ("accSagittalDoubleSharp5v23SDown", Self::AccSagittalDoubleSharp5V23SDown),
+            ("accidentalCombiningRaise47Quartertone", Self::AccidentalCombiningRaise47Quartertone),
+            ("ornamentPrecompCadenceUpperPrefixTurn", Self::OrnamentPrecompCadenceUpperPrefixTurn),
+            ("noteheadCowellThirdNoteSeriesHalf", Self::NoteheadCowellThirdNoteSeriesHalf),
+            ("accidentalJohnstonEl", Self::AccidentalJohnstonEl),
+            ("accidentalSharpArabic", Self::AccidentalSharpArabic),
+            ("accidentalHabaFlatQuarterToneHigher", Self::AccidentalHabaFlatQuarterToneHigher),
+            ("scaleDegree7", Self::ScaleDegree7),
+            ("figbass2Raised", Self::Figbass2Raised),
+            ("arrowOpenUpLeft", Self::ArrowOpenUpLeft),
+            ("accSagittal49SmallDiesisDown", Self::AccSagittal49SmallDiesisDown),
+            ("accSagittalDoubleFlat19CUp", Self::AccSagittalDoubleFlat19CUp),
+            ("ornamentPrecompTurnTrillDAnglebert", Self::OrnamentPrecompTurnTrillDAnglebert),
+            ("vocalMouthWideOpen", Self::VocalMouthWideOpen),
+            ("chantAuctumAsc", Self::ChantAuctumAsc),
+            ("dynamicPP", Self::DynamicPp),
+            ("fretboard3StringNut", Self::Fretboard3StringNut),
+            ("fingering6", Self::Fingering6),
+            ("csymAccidentalDoubleFlat", Self::CsymAccidentalDoubleFlat),
+            ("articTenutoAccentBelow", Self::ArticTenutoAccentBelow),
+            ("mensuralBlackBrevisVoid", Self::MensuralBlackBrevisVoid),
+            ("functionGLower", Self::FunctionGLower),
+            ("timeSigX", Self::TimeSigX),
+            ("fingering1Italic", Self::Fingering1Italic),
+            ("accdnDiatonicClef", Self::AccdnDiatonicClef),
+            ("organGerman2Semiminimae", Self::OrganGerman2Semiminimae),
+            ("kahnOverTheTop", Self::KahnOverTheTop),
+            ("pictBeaterWireBrushesUp", Self::PictBeaterWireBrushesUp),
+            ("segno", Self::Segno),
+            ("scaleDegree8", Self::ScaleDegree8),
+            ("chantCustosStemDownPosHighest", Self::ChantCustosStemDownPosHighest),
+            ("accidentalSharpOneArrowUp", Self::AccidentalSharpOneArrowUp),
+            ("noteheadDiamondHalf", Self::NoteheadDiamondHalf),
+            ("quindicesimaBassa", Self::QuindicesimaBassa),
+            ("chantCustosStemDownPosMiddle", Self::ChantCustosStemDownPosMiddle),
+            ("noteAFlatWhole", Self::NoteAFlatWhole),
+            ("accSagittalSharp11LUp", Self::AccSagittalSharp11LUp),
+            ("harpSalzedoDampBelow", Self::HarpSalzedoDampBelow),
+            ("staff5Lines", Self::Staff5Lines),
+            ("pictBeaterSoftGlockenspielRight", Self::PictBeaterSoftGlockenspielRight),
+            ("kahnScuff", Self::KahnScuff),
+            ("repeatBarUpperDot", Self::RepeatBarUpperDot),
+            ("accidentalEnharmonicEquals", Self::AccidentalEnharmonicEquals),
+            ("unpitchedPercussionClef1", Self::UnpitchedPercussionClef1),
+            ("keyboardPedalHalf2", Self::KeyboardPedalHalf2),
+            ("mensuralProlation11", Self::MensuralProlation11),
+            ("ornamentTremblementCouperin", Self::OrnamentTremblementCouperin),
+            ("accSagittalDoubleFlat7v19CUp", Self::AccSagittalDoubleFlat7V19CUp),
+            ("dynamicForzando", Self::DynamicForzando),
+            ("chantStrophicusLiquescens5th", Self::ChantStrophicusLiquescens5th),
+            ("timeSig0Turned", Self::TimeSig0turned),
+            ("windTrillKey", Self::WindTrillKey),
+            ("brace", Self::Brace),
+            ("pictCannon", Self::PictCannon),
+            ("mensuralGclefPetrucci", Self::MensuralGclefPetrucci),
+            ("accidentalFlatEqualTempered", Self::AccidentalFlatEqualTempered),
+            ("noteCSharpHalf", Self::NoteCSharpHalf),
+            ("keyboardBebung3DotsAbove", Self::KeyboardBebung3DotsAbove),
+            ("dynamicFFFFF", Self::DynamicFffff),
+            ("accidentalWyschnegradsky8TwelfthsSharp", Self::AccidentalWyschnegradsky8twelfthsSharp),
+            ("pictRim1", Self::PictRim1),
+            ("luteItalianTempoSomewhatFast", Self::LuteItalianTempoSomewhatFast),
+            ("note8thDown", Self::Note8thDown),
+            ("gClefArrowUp", Self::GClefArrowUp),
+            ("arrowOpenDownRight", Self::ArrowOpenDownRight),
+            ("pictGobletDrum", Self::PictGobletDrum),
+            ("tremoloFingered1", Self::TremoloFingered1),
+            ("elecAudioChannelsFour", Self::ElecAudioChannelsFour),
+            ("wiggleTrillSlowest", Self::WiggleTrillSlowest),
+            ("ornamentLowRightConcaveStroke", Self::OrnamentLowRightConcaveStroke),
+            ("fermataLongHenzeAbove", Self::FermataLongHenzeAbove),
+            ("chantPunctumCavum", Self::ChantPunctumCavum),
+            ("noteheadDoubleWholeSquare", Self::NoteheadDoubleWholeSquare),
+            ("medRenFlatSoftB", Self::MedRenFlatSoftB),
+            ("windHalfClosedHole3", Self::WindHalfClosedHole3),
+            ("octaveBaselineV", Self::OctaveBaselineV),
+            ("accidentalOneAndAHalfSharpsArrowUp", Self::AccidentalOneAndAHalfSharpsArrowUp),
+            ("fermataVeryShortBelow", Self::FermataVeryShortBelow),
+            ("leftRepeatSmall", Self::LeftRepeatSmall),
+            ("accidentalRaisedStockhausen", Self::AccidentalRaisedStockhausen),
+            ("luteDuration16th", Self::LuteDuration16th),
+            ("noteGSharpHalf", Self::NoteGSharpHalf),
+            ("fingering4Italic", Self::Fingering4Italic),
+            ("conductorBeat4Simple", Self::ConductorBeat4Simple),
+            ("staff6Lines", Self::Staff6Lines),
+            ("noteheadClusterHalfBottom", Self::NoteheadClusterHalfBottom),
+            ("dynamicCombinedSeparatorColon", Self::DynamicCombinedSeparatorColon),
+            ("keyboardPedalPed", Self::KeyboardPedalPed),
+            ("csymParensRightVeryTall", Self::CsymParensRightVeryTall),
+            ("accSagittal49SmallDiesisUp", Self::AccSagittal49SmallDiesisUp),
+            ("functionRepetition2", Self::FunctionRepetition2),
+            ("ornamentPrecompSlideTrillMuffat", Self::OrnamentPrecompSlideTrillMuffat),
+            ("accSagittalSharp7CDown", Self::AccSagittalSharp7CDown),
+            ("accSagittalSharp7v11kDown", Self::AccSagittalSharp7V11KDown),
+            ("timeSigEquals", Self::TimeSigEquals),
+            ("wiggleVibratoLargeFaster", Self::WiggleVibratoLargeFaster),
+            ("metNoteDoubleWhole", Self::MetNoteDoubleWhole),
+            ("arrowheadOpenLeft", Self::ArrowheadOpenLeft),
+            ("dynamicSforzato", Self::DynamicSforzato),
+            ("noteheadCowellEleventhNoteSeriesWhole", Self::NoteheadCowellEleventhNoteSeriesWhole),
+            ("pictLeftHandCircle", Self::PictLeftHandCircle),
+            ("elecPlay", Self::ElecPlay),
+            ("pictHiHatOnStand", Self::PictHiHatOnStand),
+            ("pictWindWhistle", Self::PictWindWhistle),
+            ("tuplet4", Self::Tuplet4),
+            ("accSagittalFlat7v11CUp", Self::AccSagittalFlat7V11CUp),
+            ("stringsDownBowTurned", Self::StringsDownBowTurned),
+            ("dynamicFF", Self::DynamicFf),
+            ("accSagittalDoubleFlat5CUp", Self::AccSagittalDoubleFlat5CUp),
+            ("mensuralObliqueAsc3rdBlackVoid", Self::MensuralObliqueAsc3RdBlackVoid),
+            ("windMultiphonicsBlackWhiteStem", Self::WindMultiphonicsBlackWhiteStem),
+            ("fermataVeryLongAbove", Self::FermataVeryLongAbove),
+            ("timeSig6Turned", Self::TimeSig6turned),
+            ("ornamentUpCurve", Self::OrnamentUpCurve),
+            ("arrowBlackRight", Self::ArrowBlackRight),
+            ("accSagittalSharp49SUp", Self::AccSagittalSharp49SUp),
+            ("brassFallSmoothLong", Self::BrassFallSmoothLong),
+            ("accSagittalFlat7v11CDown", Self::AccSagittalFlat7V11CDown),
+            ("brassHarmonMuteClosed", Self::BrassHarmonMuteClosed),
+            ("conductorRightBeat", Self::ConductorRightBeat),
+            ("accidentalDoubleSharp", Self::AccidentalDoubleSharp),
+            ("noteheadCowellFifthNoteSeriesHalf", Self::NoteheadCowellFifthNoteSeriesHalf),
+            ("wiggleArpeggiatoUpSwash", Self::WiggleArpeggiatoUpSwash),
+            ("fingeringSLower", Self::FingeringSLower),
+            ("brassLiftMedium", Self::BrassLiftMedium),
+            ("luteGermanLUpper", Self::LuteGermanLUpper),
+            ("brassScoop", Self::BrassScoop),
+            ("noteFSharpWhole", Self::NoteFSharpWhole),
+            ("fingeringSubstitutionAbove", Self::FingeringSubstitutionAbove),
+            ("accSagittalSharp7v19CDown", Self::AccSagittalSharp7V19CDown),
+            ("functionLLower", Self::FunctionLLower),
+            ("csymAccidentalNatural", Self::CsymAccidentalNatural),
+            ("pictBeaterSoftYarnDown", Self::PictBeaterSoftYarnDown),
+            ("keyboardPedalHyphen", Self::KeyboardPedalHyphen),
+            ("chantPunctumDeminutum", Self::ChantPunctumDeminutum),
+            ("ornamentPrecompCadenceWithTurn", Self::OrnamentPrecompCadenceWithTurn),
+            ("ornamentTurnSlash", Self::OrnamentTurnSlash),
+            ("stemSussurando", Self::StemSussurando),
+            ("guitarStrumUp", Self::GuitarStrumUp),
+            ("noteShapeTriangleUpBlack", Self::NoteShapeTriangleUpBlack),
+            ("daseianGraves4", Self::DaseianGraves4),
+            ("noteSiWhole", Self::NoteSiWhole),
+            ("organGermanALower", Self::OrganGermanALower),
+            ("accSagittal7CommaUp", Self::AccSagittal7CommaUp),
+            ("kahnPush", Self::KahnPush),
+            ("wiggleRandom3", Self::WiggleRandom3),
+            ("organGermanCUpper", Self::OrganGermanCUpper),
+            ("accdnRH3RanksDoubleTremoloUpper8ve", Self::AccdnRh3RanksDoubleTremoloUpper8Ve),
+            ("noteheadTriangleUpDoubleWhole", Self::NoteheadTriangleUpDoubleWhole),
+            ("pictTubularBells", Self::PictTubularBells),
+            ("articSoftAccentTenutoBelow", Self::ArticSoftAccentTenutoBelow),
+            ("mensuralProportion4", Self::MensuralProportion4),
+            ("noteheadClusterWhole2nd", Self::NoteheadClusterWhole2nd),
+            ("noteheadCircleXHalf", Self::NoteheadCircleXHalf),
+            ("arrowWhiteUp", Self::ArrowWhiteUp),
+            ("noteShapeRoundDoubleWhole", Self::NoteShapeRoundDoubleWhole),
+            ("pictBeaterHand", Self::PictBeaterHand),
+            ("accSagittalSharp7v11CUp", Self::AccSagittalSharp7V11CUp),
+            ("harpSalzedoSnareDrum", Self::HarpSalzedoSnareDrum),
+            ("pictBeaterHardYarnRight", Self::PictBeaterHardYarnRight),
+            ("analyticsEndStimme", Self::AnalyticsEndStimme),
+            ("fingeringALower", Self::FingeringALower),
+            ("kahnGraceTapStamp", Self::KahnGraceTapStamp),
+            ("reversedBracketBottom", Self::ReversedBracketBottom),
+            ("restHBarMiddle", Self::RestHBarMiddle),
+            ("arpeggiato", Self::Arpeggiato),
+            ("mensuralCombStemUpFlagLeft", Self::MensuralCombStemUpFlagLeft),
+            ("functionAngleRight", Self::FunctionAngleRight),
+            ("guitarString6", Self::GuitarString6),
+            ("accidentalKoron", Self::AccidentalKoron),
+            ("luteItalianHoldNote", Self::LuteItalianHoldNote),
+            ("noteCFlatBlack", Self::NoteCFlatBlack),
+            ("kievanNoteQuarterStemUp", Self::KievanNoteQuarterStemUp),
+            ("daseianFinales1", Self::DaseianFinales1),
+            ("wiggleVibratoLargeSlow", Self::WiggleVibratoLargeSlow),
+            ("chantLigaturaDesc2nd", Self::ChantLigaturaDesc2nd),
+            ("chantEpisema", Self::ChantEpisema),
+            ("pictBeaterFingernails", Self::PictBeaterFingernails),
+            ("chantPunctumVirgaReversed", Self::ChantPunctumVirgaReversed),
+            ("gClef8vbOld", Self::GClef8VbOld),
+            ("systemDividerExtraLong", Self::SystemDividerExtraLong),
+            ("kahnBackRiff", Self::KahnBackRiff),
+            ("mensuralObliqueAsc2ndBlackVoid", Self::MensuralObliqueAsc2ndBlackVoid),
+            ("noteShapeTriangleLeftWhite", Self::NoteShapeTriangleLeftWhite),
+            ("organGerman5Fusae", Self::OrganGerman5Fusae),
+            ("gClefReversed", Self::GClefReversed),
+            ("luteFingeringRHFirst", Self::LuteFingeringRhFirst),
+            ("pictBeaterHardBassDrumUp", Self::PictBeaterHardBassDrumUp),
+            ("restQuarter", Self::RestQuarter),
+            ("harpSalzedoAeolianAscending", Self::HarpSalzedoAeolianAscending),
+            ("wiggleVibratoSmallFaster", Self::WiggleVibratoSmallFaster),
+            ("pictBeaterWoodTimpaniLeft", Self::PictBeaterWoodTimpaniLeft),
+            ("pictSnareDrumMilitary", Self::PictSnareDrumMilitary),
+            ("pictVibMotorOff", Self::PictVibMotorOff),
+            ("ornamentBottomLeftConvexStroke", Self::OrnamentBottomLeftConvexStroke),
+            ("graceNoteAcciaccaturaStemDown", Self::GraceNoteAcciaccaturaStemDown),
+            ("pictGumMediumRight", Self::PictGumMediumRight),
+            ("noteheadSlashVerticalEnds", Self::NoteheadSlashVerticalEnds),
+            ("accidentalFlat", Self::AccidentalFlat),
+            ("functionAngleLeft", Self::FunctionAngleLeft),
+            ("kahnJumpApart", Self::KahnJumpApart),
+            ("accSagittalSharp5v13LUp", Self::AccSagittalSharp5V13LUp),
+            ("accSagittalDoubleFlat5v11SUp", Self::AccSagittalDoubleFlat5V11SUp),
+            ("repeatDots", Self::RepeatDots),
+            ("noteShapeIsoscelesTriangleBlack", Self::NoteShapeIsoscelesTriangleBlack),
+            ("noteheadClusterDoubleWhole3rd", Self::NoteheadClusterDoubleWhole3Rd),
+            ("fretboard5StringNut", Self::Fretboard5StringNut),
+            ("accidentalJohnstonDown", Self::AccidentalJohnstonDown),
+            ("kahnStepStamp", Self::KahnStepStamp),
+            ("stringsBowOnBridge", Self::StringsBowOnBridge),
+            ("noteheadCowellFifteenthNoteSeriesWhole", Self::NoteheadCowellFifteenthNoteSeriesWhole),
+            ("pictCowBell", Self::PictCowBell),
+            ("pictDamp2", Self::PictDamp2),
+            ("caesuraSingleStroke", Self::CaesuraSingleStroke),
+            ("metNoteWhole", Self::MetNoteWhole),
+            ("mensuralWhiteSemiminima", Self::MensuralWhiteSemiminima),
+            ("flag128thUp", Self::Flag128thUp),
+            ("mensuralObliqueDesc5thWhite", Self::MensuralObliqueDesc5thWhite),
+            ("elecMicrophoneUnmute", Self::ElecMicrophoneUnmute),
+            ("pictChineseCymbal", Self::PictChineseCymbal),
+            ("tuplet7", Self::Tuplet7),
+            ("accidentalArrowDown", Self::AccidentalArrowDown),
+            ("pictCastanets", Self::PictCastanets),
+            ("vocalTongueFingerClickStockhausen", Self::VocalTongueFingerClickStockhausen),
+            ("functionOne", Self::FunctionOne),
+            ("figbass7", Self::Figbass7),
+            ("beamAccelRit2", Self::BeamAccelRit2),
+            ("kahnBallDig", Self::KahnBallDig),
+            ("stemSprechgesang", Self::StemSprechgesang),
+            ("mensuralProlation9", Self::MensuralProlation9),
+            ("daseianExcellentes1", Self::DaseianExcellentes1),
+            ("accidentalWyschnegradsky9TwelfthsSharp", Self::AccidentalWyschnegradsky9twelfthsSharp),
+            ("noteheadCowellNinthNoteSeriesBlack", Self::NoteheadCowellNinthNoteSeriesBlack),
+            ("mensuralProportionMajor", Self::MensuralProportionMajor),
+            ("kahnFlam", Self::KahnFlam),
+            ("accSagittalSharp7v11CDown", Self::AccSagittalSharp7V11CDown),
+            ("accdnRH3RanksLowerTremolo8", Self::AccdnRh3RanksLowerTremolo8),
+            ("flag512thDown", Self::Flag512thDown),
+            ("pictHalfOpen2", Self::PictHalfOpen2),
+            ("accSagittalFlat5v23SUp", Self::AccSagittalFlat5V23SUp),
+            ("mensuralNoteheadLongaBlackVoid", Self::MensuralNoteheadLongaBlackVoid),
+            ("ornamentLeftShakeT", Self::OrnamentLeftShakeT),
+            ("mensuralCclefPetrucciPosHigh", Self::MensuralCclefPetrucciPosHigh),
+            ("noteDFlatWhole", Self::NoteDFlatWhole),
+            ("octaveSuperscriptB", Self::OctaveSuperscriptB),
+            ("functionGUpper", Self::FunctionGUpper),
+            ("mensuralCustosCheckmark", Self::MensuralCustosCheckmark),
+            ("tremoloFingered2", Self::TremoloFingered2),
+            ("metNote8thUp", Self::MetNote8thUp),
+            ("noteReHalf", Self::NoteReHalf),
+            ("accSagittalSharp5CUp", Self::AccSagittalSharp5CUp),
+            ("accSagittalFlat19CUp", Self::AccSagittalFlat19CUp),
+            ("accidentalWilsonPlus", Self::AccidentalWilsonPlus),
+            ("elecVolumeLevel0", Self::ElecVolumeLevel0),
+            ("noteShapeKeystoneBlack", Self::NoteShapeKeystoneBlack),
+            ("articMarcatoBelow", Self::ArticMarcatoBelow),
+            ("chantCirculusAbove", Self::ChantCirculusAbove),
+            ("functionZero", Self::FunctionZero),
+            ("stem", Self::Stem),
+            ("figbass9", Self::Figbass9),
+            ("accSagittalSharp11MUp", Self::AccSagittalSharp11MUp),
+            ("handbellsMalletBellOnTable", Self::HandbellsMalletBellOnTable),
+            ("textHeadlessBlackNoteShortStem", Self::TextHeadlessBlackNoteShortStem),
+            ("chantConnectingLineAsc5th", Self::ChantConnectingLineAsc5th),
+            ("elecLineIn", Self::ElecLineIn),
+            ("accidentalCombiningLower17Schisma", Self::AccidentalCombiningLower17Schisma),
+            ("kodalyHandRe", Self::KodalyHandRe),
+            ("accSagittalFlat49SUp", Self::AccSagittalFlat49SUp),
+            ("timeSigOpenPenderecki", Self::TimeSigOpenPenderecki),
+            ("noteHWhole", Self::NoteHWhole),
+            ("fermataLongHenzeBelow", Self::FermataLongHenzeBelow),
+            ("accidentalArrowUp", Self::AccidentalArrowUp),
+            ("guitarClosePedal", Self::GuitarClosePedal),
+            ("ornamentPrecompTurnTrillBach", Self::OrnamentPrecompTurnTrillBach),
+            ("accSagittal35LargeDiesisUp", Self::AccSagittal35LargeDiesisUp),
+            ("accidentalOneQuarterToneFlatStockhausen", Self::AccidentalOneQuarterToneFlatStockhausen),
+            ("accidentalDoubleFlatOneArrowUp", Self::AccidentalDoubleFlatOneArrowUp),
+            ("accSagittalFlat5v13LDown", Self::AccSagittalFlat5V13LDown),
+            ("barlineSingle", Self::BarlineSingle),
+            ("wiggleVibratoLargestFasterStill", Self::WiggleVibratoLargestFasterStill),
+            ("caesuraShort", Self::CaesuraShort),
+            ("keyboardPlayWithRH", Self::KeyboardPlayWithRh),
+            ("kahnDrawStep", Self::KahnDrawStep),
+            ("chantIctusAbove", Self::ChantIctusAbove),
+            ("noteheadParenthesis", Self::NoteheadParenthesis),
+            ("noteheadCowellSeventhNoteSeriesHalf", Self::NoteheadCowellSeventhNoteSeriesHalf),
+            ("figbassTripleSharp", Self::FigbassTripleSharp),
+            ("fClefArrowUp", Self::FClefArrowUp),
+            ("luteBarlineFinal", Self::LuteBarlineFinal),
+            ("keyboardPedalParensLeft", Self::KeyboardPedalParensLeft),
+            ("elecFastForward", Self::ElecFastForward),
+            ("controlBeginPhrase", Self::ControlBeginPhrase),
+            ("pictVietnameseHat", Self::PictVietnameseHat),
+            ("noteShapeDiamondDoubleWhole", Self::NoteShapeDiamondDoubleWhole),
+            ("staffPosRaise4", Self::StaffPosRaise4),
+            ("elecVideoOut", Self::ElecVideoOut),
+            ("organGermanCisLower", Self::OrganGermanCisLower),
+            ("noteCFlatWhole", Self::NoteCFlatWhole),
+            ("fingeringQLower", Self::FingeringQLower),
+            ("kahnRightCross", Self::KahnRightCross),
+            ("accSagittal143CommaUp", Self::AccSagittal143CommaUp),
+            ("functionMLower", Self::FunctionMLower),
+            ("fingering4", Self::Fingering4),
+            ("accidentalNaturalReversed", Self::AccidentalNaturalReversed),
+            ("dynamicRinforzando1", Self::DynamicRinforzando1),
+            ("accSagittalUnused4", Self::AccSagittalUnused4),
+            ("organGermanDisUpper", Self::OrganGermanDisUpper),
+            ("pictBongos", Self::PictBongos),
+            ("accidentalThreeQuarterTonesFlatArabic", Self::AccidentalThreeQuarterTonesFlatArabic),
+            ("daseianGraves3", Self::DaseianGraves3),
+            ("pictBeaterHammerWoodUp", Self::PictBeaterHammerWoodUp),
+            ("articSoftAccentAbove", Self::ArticSoftAccentAbove),
+            ("noteDiBlack", Self::NoteDiBlack),
+            ("accSagittalSharp5v23SDown", Self::AccSagittalSharp5V23SDown),
+            ("noteheadSquareWhite", Self::NoteheadSquareWhite),
+            ("noteHSharpWhole", Self::NoteHSharpWhole),
+            ("elecSkipForwards", Self::ElecSkipForwards),
+            ("organGermanOctaveUp", Self::OrganGermanOctaveUp),
+            ("noteheadWhole", Self::NoteheadWhole),
+            ("tremoloFingered5", Self::TremoloFingered5),
+            ("brassFallLipLong", Self::BrassFallLipLong),
+            ("noteShapeKeystoneWhite", Self::NoteShapeKeystoneWhite),
+            ("arrowOpenDown", Self::ArrowOpenDown),
+            ("dynamicPPPPPP", Self::DynamicPppppp),
+            ("figbass3", Self::Figbass3),
+            ("accSagittalSharp5v49MUp", Self::AccSagittalSharp5V49MUp),
+            ("accidentalWyschnegradsky5TwelfthsSharp", Self::AccidentalWyschnegradsky5twelfthsSharp),
+            ("accidentalWyschnegradsky6TwelfthsSharp", Self::AccidentalWyschnegradsky6twelfthsSharp),
+            ("cClefReversed", Self::CClefReversed),
+            ("flag256thUp", Self::Flag256thUp),
+            ("accSagittalFlat5v7kDown", Self::AccSagittalFlat5V7KDown),
+            ("unmeasuredTremoloSimple", Self::UnmeasuredTremoloSimple),
+            ("accidentalWyschnegradsky4TwelfthsSharp", Self::AccidentalWyschnegradsky4twelfthsSharp),
+            ("functionFour", Self::FunctionFour),
+            ("pictSlideBrushOnGong", Self::PictSlideBrushOnGong),
+            ("luteFrenchFretF", Self::LuteFrenchFretF),
+            ("accSagittalDoubleSharp55CDown", Self::AccSagittalDoubleSharp55CDown),
+            ("mensuralProportion9", Self::MensuralProportion9),
+            ("luteFrenchMordentInverted", Self::LuteFrenchMordentInverted),
+            ("accidentalCombiningLower31Schisma", Self::AccidentalCombiningLower31Schisma),
+            ("accidentalSharpRepeatedSpaceStockhausen", Self::AccidentalSharpRepeatedSpaceStockhausen),
+            ("windOpenHole", Self::WindOpenHole),
+            ("luteItalianClefCSolFaUt", Self::LuteItalianClefCSolFaUt),
+            ("keyboardPedalSost", Self::KeyboardPedalSost),
+            ("scaleDegree3", Self::ScaleDegree3),
+            ("functionSeven", Self::FunctionSeven),
+            ("noteheadCowellFifteenthNoteSeriesHalf", Self::NoteheadCowellFifteenthNoteSeriesHalf),
+            ("staff1LineWide", Self::Staff1LineWide),
+            ("accidentalSims4Up", Self::AccidentalSims4Up),
+            ("accidentalXenakisTwoThirdTonesSharp", Self::AccidentalXenakisTwoThirdTonesSharp),
+            ("analyticsChoralmelodie", Self::AnalyticsChoralmelodie),
+            ("fermataBelow", Self::FermataBelow),
+            ("guitarStrumDown", Self::GuitarStrumDown),
+            ("reversedBracketTop", Self::ReversedBracketTop),
+            ("wiggleVibratoMediumFaster", Self::WiggleVibratoMediumFaster),
+            ("accSagittalDoubleFlat19sUp", Self::AccSagittalDoubleFlat19SUp),
+            ("accidentalCombiningRaise41Comma", Self::AccidentalCombiningRaise41Comma),
+            ("arrowheadBlackUpRight", Self::ArrowheadBlackUpRight),
+            ("kahnLeftFoot", Self::KahnLeftFoot),
+            ("kahnZink", Self::KahnZink),
+            ("smnSharp", Self::SmnSharp),
+            ("pictAnvil", Self::PictAnvil),
+            ("functionSSLower", Self::FunctionSsLower),
+            ("wiggleWavyNarrow", Self::WiggleWavyNarrow),
+            ("noteheadCircledHalfLarge", Self::NoteheadCircledHalfLarge),
+            ("noteheadCircledWholeLarge", Self::NoteheadCircledWholeLarge),
+            ("accidentalDoubleFlatArabic", Self::AccidentalDoubleFlatArabic),
+            ("fClef8va", Self::FClef8Va),
+            ("tremoloFingered4", Self::TremoloFingered4),
+            ("accidentalFiveQuarterTonesSharpArrowUp", Self::AccidentalFiveQuarterTonesSharpArrowUp),
+            ("functionBracketRight", Self::FunctionBracketRight),
+            ("noteheadClusterRoundBlack", Self::NoteheadClusterRoundBlack),
+            ("accidentalQuarterSharpEqualTempered", Self::AccidentalQuarterSharpEqualTempered),
+            ("kahnBackChug", Self::KahnBackChug),
+            ("accidentalWyschnegradsky7TwelfthsFlat", Self::AccidentalWyschnegradsky7twelfthsFlat),
+            ("organGermanEUpper", Self::OrganGermanEUpper),
+            ("pictGlassTubeChimes", Self::PictGlassTubeChimes),
+            ("luteGermanEUpper", Self::LuteGermanEUpper),
+            ("accdnRH3RanksUpperTremolo8", Self::AccdnRh3RanksUpperTremolo8),
+            ("organGerman2Semifusae", Self::OrganGerman2Semifusae),
+            ("figbass5Raised1", Self::Figbass5Raised1),
+            ("beamAccelRitFinal", Self::BeamAccelRitFinal),
+            ("vocalHalbGesungen", Self::VocalHalbGesungen),
+            ("pictGlspSmithBrindle", Self::PictGlspSmithBrindle),
+            ("noteheadRoundWhiteWithDotLarge", Self::NoteheadRoundWhiteWithDotLarge),
+            ("noteRaBlack", Self::NoteRaBlack),
+            ("pictJingleBells", Self::PictJingleBells),
+            ("daseianExcellentes3", Self::DaseianExcellentes3),
+            ("pictFootballRatchet", Self::PictFootballRatchet),
+            ("semipitchedPercussionClef2", Self::SemipitchedPercussionClef2),
+            ("noteFaHalf", Self::NoteFaHalf),
+            ("organGermanTie", Self::OrganGermanTie),
+            ("medRenGClefCMN", Self::MedRenGClefCmn),
+            ("luteFrenchFretK", Self::LuteFrenchFretK),
+            ("noteheadNancarrowSine", Self::NoteheadNancarrowSine),
+            ("noteheadDiamondWhole", Self::NoteheadDiamondWhole),
+            ("guitarString10", Self::GuitarString10),
+            ("arrowWhiteDownRight", Self::ArrowWhiteDownRight),
+            ("luteGermanRLower", Self::LuteGermanRLower),
+            ("accidentalWyschnegradsky10TwelfthsFlat", Self::AccidentalWyschnegradsky10twelfthsFlat),
+            ("chantStrophicusAuctus", Self::ChantStrophicusAuctus),
+            ("note256thDown", Self::Note256thDown),
+            ("timeSigCutCommonReversed", Self::TimeSigCutCommonReversed),
+            ("functionNUpper", Self::FunctionNUpper),
+            ("functionSLower", Self::FunctionSLower),
+            ("wiggleCircularLargerStill", Self::WiggleCircularLargerStill),
+            ("pictBeaterCombiningParentheses", Self::PictBeaterCombiningParentheses),
+            ("miscEyeglasses", Self::MiscEyeglasses),
+            ("ornamentPrecompDoubleCadenceUpperPrefixTurn", Self::OrnamentPrecompDoubleCadenceUpperPrefixTurn),
+            ("functionParensLeft", Self::FunctionParensLeft),
+            ("noteBFlatHalf", Self::NoteBFlatHalf),
+            ("pictCenter2", Self::PictCenter2),
+            ("kahnFleaHop", Self::KahnFleaHop),
+            ("windReedPositionIn", Self::WindReedPositionIn),
+            ("doubleTongueBelow", Self::DoubleTongueBelow),
+            ("noteheadCowellEleventhSeriesBlack", Self::NoteheadCowellEleventhSeriesBlack),
+            ("figbass7Raised1", Self::Figbass7Raised1),
+            ("pictBeaterHardYarnUp", Self::PictBeaterHardYarnUp),
+            ("luteFrenchFretH", Self::LuteFrenchFretH),
+            ("metAugmentationDot", Self::MetAugmentationDot),
+            ("accSagittalFlat143CUp", Self::AccSagittalFlat143CUp),
+            ("restHalfLegerLine", Self::RestHalfLegerLine),
+            ("timeSig2Reversed", Self::TimeSig2Reversed),
+            ("ornamentLowRightConvexStroke", Self::OrnamentLowRightConvexStroke),
+            ("accdnRH3RanksAuthenticMusette", Self::AccdnRh3RanksAuthenticMusette),
+            ("accdnRH3RanksBassoon", Self::AccdnRh3RanksBassoon),
+            ("accidentalThreeQuarterTonesFlatGrisey", Self::AccidentalThreeQuarterTonesFlatGrisey),
+            ("accSagittalFlat", Self::AccSagittalFlat),
+            ("mensuralBlackLonga", Self::MensuralBlackLonga),
+            ("chantOriscusLiquescens", Self::ChantOriscusLiquescens),
+            ("ornamentComma", Self::OrnamentComma),
+            ("medRenLiquescentAscCMN", Self::MedRenLiquescentAscCmn),
+            ("pictBeaterSoftYarnUp", Self::PictBeaterSoftYarnUp),
+            ("stringsUpBow", Self::StringsUpBow),
+            ("accidentalLoweredStockhausen", Self::AccidentalLoweredStockhausen),
+            ("wiggleTrillFasterStill", Self::WiggleTrillFasterStill),
+            ("luteFrenchMordentUpper", Self::LuteFrenchMordentUpper),
+            ("accSagittalDoubleSharp25SDown", Self::AccSagittalDoubleSharp25SDown),
+            ("stringsMuteOn", Self::StringsMuteOn),
+            ("noteheadDoubleWholeWithX", Self::NoteheadDoubleWholeWithX),
+            ("noteheadLargeArrowUpDoubleWhole", Self::NoteheadLargeArrowUpDoubleWhole),
+            ("staff5LinesWide", Self::Staff5LinesWide),
+            ("accdnLH2Ranks8Plus16Round", Self::AccdnLh2Ranks8Plus16Round),
+            ("vocalNasalVoice", Self::VocalNasalVoice),
+            ("noteESharpHalf", Self::NoteESharpHalf),
+            ("schaefferFClefToGClef", Self::SchaefferFClefToGClef),
+            ("accSagittal19SchismaUp", Self::AccSagittal19SchismaUp),
+            ("functionBracketLeft", Self::FunctionBracketLeft),
+            ("pictXyl", Self::PictXyl),
+            ("wiggleArpeggiatoDownSwash", Self::WiggleArpeggiatoDownSwash),
+            ("accSagittalDoubleFlat17CUp", Self::AccSagittalDoubleFlat17CUp),
+            ("fingering7", Self::Fingering7),
+            ("accidentalSharpThreeArrowsUp", Self::AccidentalSharpThreeArrowsUp),
+            ("noteheadBlack", Self::NoteheadBlack),
+            ("functionSix", Self::FunctionSix),
+            ("conductorBeat4Compound", Self::ConductorBeat4Compound),
+            ("fingeringTLower", Self::FingeringTLower),
+            ("wiggleTrillSlow", Self::WiggleTrillSlow),
+            ("figbass5Raised2", Self::Figbass5Raised2),
+            ("accidentalCombiningRaise19Schisma", Self::AccidentalCombiningRaise19Schisma),
+            ("fingering8Italic", Self::Fingering8Italic),
+            ("accSagittal23SmallDiesisUp", Self::AccSagittal23SmallDiesisUp),
+            ("windSharpEmbouchure", Self::WindSharpEmbouchure),
+            ("accSagittal7v11CommaDown", Self::AccSagittal7V11CommaDown),
+            ("systemDivider", Self::SystemDivider),
+            ("pictTempleBlocks", Self::PictTempleBlocks),
+            ("mensuralBlackDragma", Self::MensuralBlackDragma),
+            ("metNoteQuarterUp", Self::MetNoteQuarterUp),
+            ("dynamicSforzando", Self::DynamicSforzando),
+            ("noteheadSlashX", Self::NoteheadSlashX),
+            ("staff2LinesWide", Self::Staff2LinesWide),
+            ("noteHSharpBlack", Self::NoteHSharpBlack),
+            ("timeSigSlash", Self::TimeSigSlash),
+            ("organGermanFLower", Self::OrganGermanFLower),
+            ("accSagittal35LargeDiesisDown", Self::AccSagittal35LargeDiesisDown),
+            ("pictTomTom", Self::PictTomTom),
+            ("elecAudioChannelsSix", Self::ElecAudioChannelsSix),
+            ("kahnLeftTurn", Self::KahnLeftTurn),
+            ("accSagittalFlat17CUp", Self::AccSagittalFlat17CUp),
+            ("gClef15mb", Self::GClef15Mb),
+            ("fermataLongAbove", Self::FermataLongAbove),
+            ("pictBassDrumOnSide", Self::PictBassDrumOnSide),
+            ("harpSalzedoFluidicSoundsRight", Self::HarpSalzedoFluidicSoundsRight),
+            ("articSoftAccentTenutoStaccatoBelow", Self::ArticSoftAccentTenutoStaccatoBelow),
+            ("mensuralObliqueDesc2ndBlackVoid", Self::MensuralObliqueDesc2ndBlackVoid),
+            ("brassFallSmoothShort", Self::BrassFallSmoothShort),
+            ("mensuralCombStemDownFlagSemiminima", Self::MensuralCombStemDownFlagSemiminima),
+            ("luteGermanOLower", Self::LuteGermanOLower),
+            ("accSagittalFlat11v49CUp", Self::AccSagittalFlat11V49CUp),
+            ("pictQuijada", Self::PictQuijada),
+            ("pictCuica", Self::PictCuica),
+            ("wiggleVibratoSmallestFastest", Self::WiggleVibratoSmallestFastest),
+            ("luteFrenchFretI", Self::LuteFrenchFretI),
+            ("accSagittalFlat5v23SDown", Self::AccSagittalFlat5V23SDown),
+            ("accSagittal49MediumDiesisDown", Self::AccSagittal49MediumDiesisDown),
+            ("accidentalRaiseTwoSeptimalCommas", Self::AccidentalRaiseTwoSeptimalCommas),
+            ("organGermanDUpper", Self::OrganGermanDUpper),
+            ("accdnCombDot", Self::AccdnCombDot),
+            ("accidentalQuarterToneSharp4", Self::AccidentalQuarterToneSharp4),
+            ("staff1LineNarrow", Self::Staff1LineNarrow),
+            ("noteDiHalf", Self::NoteDiHalf),
+            ("restHBarRight", Self::RestHBarRight),
+            ("noteheadCowellThirteenthNoteSeriesHalf", Self::NoteheadCowellThirteenthNoteSeriesHalf),
+            ("noteheadCowellEleventhNoteSeriesHalf", Self::NoteheadCowellEleventhNoteSeriesHalf),
+            ("accSagittalFlat5CUp", Self::AccSagittalFlat5CUp),
+            ("controlBeginBeam", Self::ControlBeginBeam),
+            ("timeSig4Turned", Self::TimeSig4turned),
+            ("elecTape", Self::ElecTape),
+            ("pictBeaterSoftYarnLeft", Self::PictBeaterSoftYarnLeft),
+            ("pictCenter1", Self::PictCenter1),
+            ("harpSalzedoTamTamSounds", Self::HarpSalzedoTamTamSounds),
+            ("fClef15mb", Self::FClef15Mb),
+            ("accidental5CommaSharp", Self::Accidental5CommaSharp),
+            ("functionFive", Self::FunctionFive),
+            ("flag256thDown", Self::Flag256thDown),
+            ("accdnRH3RanksFullFactory", Self::AccdnRh3RanksFullFactory),
+            ("noteShapeDiamondBlack", Self::NoteShapeDiamondBlack),
+            ("luteItalianFret7", Self::LuteItalianFret7),
+            ("accSagittalSharp5v19CDown", Self::AccSagittalSharp5V19CDown),
+            ("pictTabla", Self::PictTabla),
+            ("noteheadTriangleLeftWhite", Self::NoteheadTriangleLeftWhite),
+            ("graceNoteAppoggiaturaStemDown", Self::GraceNoteAppoggiaturaStemDown),
+            ("noteheadCircledHalf", Self::NoteheadCircledHalf),
+            ("functionNine", Self::FunctionNine),
+            ("wiggleVibratoLargeFastest", Self::WiggleVibratoLargeFastest),
+            ("wiggleSquareWave", Self::WiggleSquareWave),
+            ("pictBeaterHammerPlasticUp", Self::PictBeaterHammerPlasticUp),
+            ("controlEndPhrase", Self::ControlEndPhrase),
+            ("unpitchedPercussionClef2", Self::UnpitchedPercussionClef2),
+            ("octaveBaselineA", Self::OctaveBaselineA),
+            ("daseianResidua2", Self::DaseianResidua2),
+            ("keyboardPedalHeel1", Self::KeyboardPedalHeel1),
+            ("noteheadDiamondClusterBlackMiddle", Self::NoteheadDiamondClusterBlackMiddle),
+            ("accSagittalDoubleSharp49SDown", Self::AccSagittalDoubleSharp49SDown),
+            ("functionThree", Self::FunctionThree),
+            ("pictBeaterDoubleBassDrumDown", Self::PictBeaterDoubleBassDrumDown),
+            ("accSagittal2MinasDown", Self::AccSagittal2MinasDown),
+            ("accSagittal49LargeDiesisUp", Self::AccSagittal49LargeDiesisUp),
+            ("breathMarkSalzedo", Self::BreathMarkSalzedo),
+            ("accSagittal7TinasDown", Self::AccSagittal7tinasDown),
+            ("accSagittalDoubleFlat55CUp", Self::AccSagittalDoubleFlat55CUp),
+            ("pictScrapeAroundRimClockwise", Self::PictScrapeAroundRimClockwise),
+            ("luteItalianTempoFast", Self::LuteItalianTempoFast),
+            ("noteFHalf", Self::NoteFHalf),
+            ("note32ndDown", Self::Note32ndDown),
+            ("wiggleWavyWide", Self::WiggleWavyWide),
+            ("luteGermanIUpper", Self::LuteGermanIUpper),
+            ("noteFSharpBlack", Self::NoteFSharpBlack),
+            ("pictCelesta", Self::PictCelesta),
+            ("accidental1CommaSharp", Self::Accidental1CommaSharp),
+            ("kahnLeftCross", Self::KahnLeftCross),
+            ("noteWhole", Self::NoteWhole),
+            ("chantLigaturaDesc3rd", Self::ChantLigaturaDesc3Rd),
+            ("accSagittalFlat5v49MDown", Self::AccSagittalFlat5V49MDown),
+            ("pictBeaterSoftXylophone", Self::PictBeaterSoftXylophone),
+            ("fingeringRightBracket", Self::FingeringRightBracket),
+            ("pictPoliceWhistle", Self::PictPoliceWhistle),
+            ("smnFlatWhite", Self::SmnFlatWhite),
+            ("mensuralObliqueDesc5thBlack", Self::MensuralObliqueDesc5thBlack),
+            ("kahnTrench", Self::KahnTrench),
+            ("accSagittal7v19CommaDown", Self::AccSagittal7V19CommaDown),
+            ("pictBeaterDoubleBassDrumUp", Self::PictBeaterDoubleBassDrumUp),
+            ("accidentalJohnstonSeven", Self::AccidentalJohnstonSeven),
+            ("wiggleCircularLarge", Self::WiggleCircularLarge),
+            ("accidentalLowerTwoSeptimalCommas", Self::AccidentalLowerTwoSeptimalCommas),
+            ("functionTLower", Self::FunctionTLower),
+            ("fermataShortBelow", Self::FermataShortBelow),
+            ("fingeringSeparatorMiddleDot", Self::FingeringSeparatorMiddleDot),
+            ("accSagittal23CommaUp", Self::AccSagittal23CommaUp),
+            ("accdnRH4RanksAlto", Self::AccdnRh4RanksAlto),
+            ("handbellsMutedMartellato", Self::HandbellsMutedMartellato),
+            ("accidentalHalfSharpArrowDown", Self::AccidentalHalfSharpArrowDown),
+            ("tuplet5", Self::Tuplet5),
+            ("wiggleCircularConstantFlipped", Self::WiggleCircularConstantFlipped),
+            ("noteheadDiamondClusterWhiteMiddle", Self::NoteheadDiamondClusterWhiteMiddle),
+            ("pictGumMediumLeft", Self::PictGumMediumLeft),
+            ("harpStringNoiseStem", Self::HarpStringNoiseStem),
+            ("pictScrapeAroundRim", Self::PictScrapeAroundRim),
+            ("stringsHalfHarmonic", Self::StringsHalfHarmonic),
+            ("doubleTongueAbove", Self::DoubleTongueAbove),
+            ("handbellsGyro", Self::HandbellsGyro),
+            ("accidentalThreeQuarterTonesFlatArrowUp", Self::AccidentalThreeQuarterTonesFlatArrowUp),
+            ("rest512th", Self::Rest512th),
+            ("ventiduesima", Self::Ventiduesima),
+            ("accSagittalDoubleFlat7CUp", Self::AccSagittalDoubleFlat7CUp),
+            ("staffPosLower8", Self::StaffPosLower8),
+            ("functionRepetition1", Self::FunctionRepetition1),
+            ("restQuarterZ", Self::RestQuarterZ),
+            ("accSagittalDoubleSharp7v11CDown", Self::AccSagittalDoubleSharp7V11CDown),
+            ("accidentalNaturalOneArrowDown", Self::AccidentalNaturalOneArrowDown),
+            ("accSagittalDoubleFlat5v19CUp", Self::AccSagittalDoubleFlat5V19CUp),
+            ("noteShapeTriangleLeftDoubleWhole", Self::NoteShapeTriangleLeftDoubleWhole),
+            ("keyboardPlayWithLH", Self::KeyboardPlayWithLh),
+            ("barlineDotted", Self::BarlineDotted),
+            ("elecAudioMono", Self::ElecAudioMono),
+            ("timeSig3Turned", Self::TimeSig3turned),
+            ("pictLithophone", Self::PictLithophone),
+            ("pictBeaterTriangleDown", Self::PictBeaterTriangleDown),
+            ("textHeadlessBlackNoteFrac16thShortStem", Self::TextHeadlessBlackNoteFrac16thShortStem),
+            ("figbass7Raised2", Self::Figbass7Raised2),
+            ("accSagittal5CommaUp", Self::AccSagittal5CommaUp),
+            ("elecVolumeLevel100", Self::ElecVolumeLevel100),
+            ("accSagittalFlat17kDown", Self::AccSagittalFlat17KDown),
+            ("fingeringSeparatorSlash", Self::FingeringSeparatorSlash),
+            ("noteShapeTriangleRoundBlack", Self::NoteShapeTriangleRoundBlack),
+            ("figbassCombiningRaising", Self::FigbassCombiningRaising),
+            ("arpeggiatoDown", Self::ArpeggiatoDown),
+            ("accidentalQuarterToneSharpStein", Self::AccidentalQuarterToneSharpStein),
+            ("accidentalHabaSharpQuarterToneLower", Self::AccidentalHabaSharpQuarterToneLower),
+            ("tripleTongueAbove", Self::TripleTongueAbove),
+            ("noteheadCowellSeventhNoteSeriesBlack", Self::NoteheadCowellSeventhNoteSeriesBlack),
+            ("organGerman5Semifusae", Self::OrganGerman5Semifusae),
+            ("pictSuspendedCymbal", Self::PictSuspendedCymbal),
+            ("luteGermanXLower", Self::LuteGermanXLower),
+            ("pictJawHarp", Self::PictJawHarp),
+            ("textCont8thBeamLongStem", Self::TextCont8thBeamLongStem),
+            ("mensuralWhiteMinima", Self::MensuralWhiteMinima),
+            ("organGermanSemibrevis", Self::OrganGermanSemibrevis),
+            ("pictSuperball", Self::PictSuperball),
+            ("accSagittalSharp17CDown", Self::AccSagittalSharp17CDown),
+            ("accSagittal11v19MediumDiesisUp", Self::AccSagittal11V19MediumDiesisUp),
+            ("pluckedLeftHandPizzicato", Self::PluckedLeftHandPizzicato),
+            ("accidentalQuarterToneFlatFilledReversed", Self::AccidentalQuarterToneFlatFilledReversed),
+            ("pictBrakeDrum", Self::PictBrakeDrum),
+            ("curlewSign", Self::CurlewSign),
+            ("elecPowerOnOff", Self::ElecPowerOnOff),
+            ("functionEight", Self::FunctionEight),
+            ("gClef15ma", Self::GClef15Ma),
+            ("kahnLeftToeStrike", Self::KahnLeftToeStrike),
+            ("accidentalCombiningOpenCurlyBrace", Self::AccidentalCombiningOpenCurlyBrace),
+            ("accidentalFlatOneArrowDown", Self::AccidentalFlatOneArrowDown),
+            ("fretboardX", Self::FretboardX),
+            ("accidentalCommaSlashUp", Self::AccidentalCommaSlashUp),
+            ("kahnHeel", Self::KahnHeel),
+            ("noteheadRoundBlackLarge", Self::NoteheadRoundBlackLarge),
+            ("wiggleCircularLarger", Self::WiggleCircularLarger),
+            ("csymParensLeftTall", Self::CsymParensLeftTall),
+            ("accSagittalDoubleFlat11v49CUp", Self::AccSagittalDoubleFlat11V49CUp),
+            ("noteheadClusterHalf2nd", Self::NoteheadClusterHalf2nd),
+            ("noteheadCowellThirdNoteSeriesBlack", Self::NoteheadCowellThirdNoteSeriesBlack),
+            ("fingering5Italic", Self::Fingering5Italic),
+            ("luteItalianFret3", Self::LuteItalianFret3),
+            ("chantDivisioMaxima", Self::ChantDivisioMaxima),
+            ("pluckedSnapPizzicatoAbove", Self::PluckedSnapPizzicatoAbove),
+            ("pictGlsp", Self::PictGlsp),
+            ("note32ndUp", Self::Note32ndUp),
+            ("chantIctusBelow", Self::ChantIctusBelow),
+            ("noteEFlatHalf", Self::NoteEFlatHalf),
+            ("chantDeminutumLower", Self::ChantDeminutumLower),
+            ("figbass7Diminished", Self::Figbass7Diminished),
+            ("csymMajorSeventh", Self::CsymMajorSeventh),
+            ("wiggleGlissandoGroup1", Self::WiggleGlissandoGroup1),
+            ("brassLiftLong", Self::BrassLiftLong),
+            ("conductorBeat3Compound", Self::ConductorBeat3Compound),
+            ("pictBeaterHardXylophoneUp", Self::PictBeaterHardXylophoneUp),
+            ("coda", Self::Coda),
+            ("timeSigBracketRight", Self::TimeSigBracketRight),
+            ("elecMIDIController80", Self::ElecMidiController80),
+            ("noteFFlatWhole", Self::NoteFFlatWhole),
+            ("textTupletBracketStartLongStem", Self::TextTupletBracketStartLongStem),
+            ("accSagittalSharp55CDown", Self::AccSagittalSharp55CDown),
+            ("kahnLeftCatch", Self::KahnLeftCatch),
+            ("luteBarlineEndRepeat", Self::LuteBarlineEndRepeat),
+            ("pictWhip", Self::PictWhip),
+            ("ornamentVerticalLine", Self::OrnamentVerticalLine),
+            ("pluckedDampOnStem", Self::PluckedDampOnStem),
+            ("kahnHeelTap", Self::KahnHeelTap),
+            ("brassDoitMedium", Self::BrassDoitMedium),
+            ("brassLiftShort", Self::BrassLiftShort),
+            ("ornamentTurnUp", Self::OrnamentTurnUp),
+            ("wiggleVibratoSmallestSlow", Self::WiggleVibratoSmallestSlow),
+            ("accidentalQuarterToneFlatStein", Self::AccidentalQuarterToneFlatStein),
+            ("accidentalDoubleFlatTurned", Self::AccidentalDoubleFlatTurned),
+            ("arrowheadWhiteUp", Self::ArrowheadWhiteUp),
+            ("handbellsTablePairBells", Self::HandbellsTablePairBells),
+            ("figbass6", Self::Figbass6),
+            ("mensuralProlation2", Self::MensuralProlation2),
+            ("accSagittal11LargeDiesisUp", Self::AccSagittal11LargeDiesisUp),
+            ("kahnStampStamp", Self::KahnStampStamp),
+            ("handbellsEcho2", Self::HandbellsEcho2),
+            ("pictBeaterBrassMalletsDown", Self::PictBeaterBrassMalletsDown),
+            ("mensuralProlation7", Self::MensuralProlation7),
+            ("pictBeaterKnittingNeedle", Self::PictBeaterKnittingNeedle),
+            ("accidentalDoubleFlatTwoArrowsDown", Self::AccidentalDoubleFlatTwoArrowsDown),
+            ("ottavaBassaBa", Self::OttavaBassaBa),
+            ("accidentalHabaFlatThreeQuarterTonesLower", Self::AccidentalHabaFlatThreeQuarterTonesLower),
+            ("organGermanSemiminimaRest", Self::OrganGermanSemiminimaRest),
+            ("fingering5", Self::Fingering5),
+            ("figbassBracketRight", Self::FigbassBracketRight),
+            ("brassSmear", Self::BrassSmear),
+            ("ornamentZigZagLineNoRightEnd", Self::OrnamentZigZagLineNoRightEnd),
+            ("pictGong", Self::PictGong),
+            ("mensuralCustosTurn", Self::MensuralCustosTurn),
+            ("ornamentPrecompPortDeVoixMordent", Self::OrnamentPrecompPortDeVoixMordent),
+            ("pictBeaterSoftTimpaniLeft", Self::PictBeaterSoftTimpaniLeft),
+            ("accSagittalFlat35LDown", Self::AccSagittalFlat35LDown),
+            ("accSagittalSharp5v13MUp", Self::AccSagittalSharp5V13MUp),
+            ("pictBeaterHammerMetalUp", Self::PictBeaterHammerMetalUp),
+            ("accSagittal1TinaDown", Self::AccSagittal1tinaDown),
+            ("mensuralCombStemUpFlagSemiminima", Self::MensuralCombStemUpFlagSemiminima),
+            ("noteAFlatHalf", Self::NoteAFlatHalf),
+            ("mensuralCclefPetrucciPosLow", Self::MensuralCclefPetrucciPosLow),
+            ("ornamentLeftFacingHalfCircle", Self::OrnamentLeftFacingHalfCircle),
+            ("noteheadClusterHalfMiddle", Self::NoteheadClusterHalfMiddle),
+            ("harpTuningKeyGlissando", Self::HarpTuningKeyGlissando),
+            ("figbassParensRight", Self::FigbassParensRight),
+            ("accSagittalDoubleSharp19sDown", Self::AccSagittalDoubleSharp19SDown),
+            ("pictBeaterSoftGlockenspielDown", Self::PictBeaterSoftGlockenspielDown),
+            ("accidentalKomaFlat", Self::AccidentalKomaFlat),
+            ("luteGermanQLower", Self::LuteGermanQLower),
+            ("ornamentLeftPlus", Self::OrnamentLeftPlus),
+            ("noteheadRoundBlackSlashedLarge", Self::NoteheadRoundBlackSlashedLarge),
+            ("accidentalQuarterToneFlatPenderecki", Self::AccidentalQuarterToneFlatPenderecki),
+            ("kahnPull", Self::KahnPull),
+            ("chantConnectingLineAsc4th", Self::ChantConnectingLineAsc4th),
+            ("chantDivisioFinalis", Self::ChantDivisioFinalis),
+            ("stemHarpStringNoise", Self::StemHarpStringNoise),
+            ("staffPosRaise8", Self::StaffPosRaise8),
+            ("accSagittalSharp23CUp", Self::AccSagittalSharp23CUp),
+            ("dynamicMP", Self::DynamicMp),
+            ("noteheadDiamondBlackOld", Self::NoteheadDiamondBlackOld),
+            ("handbellsTableSingleBell", Self::HandbellsTableSingleBell),
+            ("wiggleVibratoLargeSlowest", Self::WiggleVibratoLargeSlowest),
+            ("accidentalQuarterToneFlatNaturalArrowDown", Self::AccidentalQuarterToneFlatNaturalArrowDown),
+            ("wiggleCircularConstantLarge", Self::WiggleCircularConstantLarge),
+            ("accidentalBuyukMucennebSharp", Self::AccidentalBuyukMucennebSharp),
+            ("csymAccidentalTripleFlat", Self::CsymAccidentalTripleFlat),
+            ("kahnSlap", Self::KahnSlap),
+            ("noteBBlack", Self::NoteBBlack),
+            ("accdnRH4RanksSoftBass", Self::AccdnRh4RanksSoftBass),
+            ("beamAccelRit13", Self::BeamAccelRit13),
+            ("kahnBrushForward", Self::KahnBrushForward),
+            ("accidental2CommaFlat", Self::Accidental2CommaFlat),
+            ("staffDivideArrowUp", Self::StaffDivideArrowUp),
+            ("wiggleCircularLargest", Self::WiggleCircularLargest),
+            ("noteFiBlack", Self::NoteFiBlack),
+            ("rest1024th", Self::Rest1024th),
+            ("stringsUpBowTurned", Self::StringsUpBowTurned),
+            ("accSagittalDoubleFlat49SUp", Self::AccSagittalDoubleFlat49SUp),
+            ("pictTurnRightStem", Self::PictTurnRightStem),
+            ("mensuralProlation6", Self::MensuralProlation6),
+            ("stringsScrapeCircularClockwise", Self::StringsScrapeCircularClockwise),
+            ("mensuralObliqueAsc5thBlackVoid", Self::MensuralObliqueAsc5thBlackVoid),
+            ("noteShapeTriangleRoundLeftBlack", Self::NoteShapeTriangleRoundLeftBlack),
+            ("octaveBaselineB", Self::OctaveBaselineB),
+            ("accidentalTavenerFlat", Self::AccidentalTavenerFlat),
+            ("organGermanFisLower", Self::OrganGermanFisLower),
+            ("elecAudioChannelsEight", Self::ElecAudioChannelsEight),
+            ("accSagittalSharp49SDown", Self::AccSagittalSharp49SDown),
+            ("pictBeaterMediumBassDrumUp", Self::PictBeaterMediumBassDrumUp),
+            ("ornamentPrecompDoubleCadenceLowerPrefix", Self::OrnamentPrecompDoubleCadenceLowerPrefix),
+            ("noteheadCowellNinthNoteSeriesHalf", Self::NoteheadCowellNinthNoteSeriesHalf),
+            ("cClef", Self::CClef),
+            ("noteEmptyBlack", Self::NoteEmptyBlack),
+            ("pictBell", Self::PictBell),
+            ("mensuralNoteheadSemibrevisVoid", Self::MensuralNoteheadSemibrevisVoid),
+            ("harpSalzedoThunderEffect", Self::HarpSalzedoThunderEffect),
+            ("pictWoundHardRight", Self::PictWoundHardRight),
+            ("accidentalFlatArabic", Self::AccidentalFlatArabic),
+            ("ornamentPrecompSlideTrillMarpurg", Self::OrnamentPrecompSlideTrillMarpurg),
+            ("noteheadTriangleRoundDownBlack", Self::NoteheadTriangleRoundDownBlack),
+            ("swissRudimentsNoteheadHalfDouble", Self::SwissRudimentsNoteheadHalfDouble),
+            ("pictChokeCymbal", Self::PictChokeCymbal),
+            ("quindicesima", Self::Quindicesima),
+            ("kahnSnap", Self::KahnSnap),
+            ("pictBeaterFinger", Self::PictBeaterFinger),
+            ("harpPedalLowered", Self::HarpPedalLowered),
+            ("luteFrenchAppoggiaturaAbove", Self::LuteFrenchAppoggiaturaAbove),
+            ("accdnRH3RanksAccordion", Self::AccdnRh3RanksAccordion),
+            ("mensuralObliqueAsc3rdBlack", Self::MensuralObliqueAsc3RdBlack),
+            ("guitarHalfOpenPedal", Self::GuitarHalfOpenPedal),
+            ("textBlackNoteShortStem", Self::TextBlackNoteShortStem),
+            ("accSagittal5v13MediumDiesisDown", Self::AccSagittal5V13MediumDiesisDown),
+            ("caesuraCurved", Self::CaesuraCurved),
+            ("guitarString0", Self::GuitarString0),
+            ("noteCSharpWhole", Self::NoteCSharpWhole),
+            ("guitarWideVibratoStroke", Self::GuitarWideVibratoStroke),
+            ("accidentalCombiningCloseCurlyBrace", Self::AccidentalCombiningCloseCurlyBrace),
+            ("pictBeaterMediumXylophoneLeft", Self::PictBeaterMediumXylophoneLeft),
+            ("beamAccelRit9", Self::BeamAccelRit9),
+            ("mensuralRestSemibrevis", Self::MensuralRestSemibrevis),
+            ("stringsScrapeParallelOutward", Self::StringsScrapeParallelOutward),
+            ("chantPunctumVirga", Self::ChantPunctumVirga),
+            ("noteShapeArrowheadLeftDoubleWhole", Self::NoteShapeArrowheadLeftDoubleWhole),
+            ("tripleTongueBelow", Self::TripleTongueBelow),
+            ("bracketBottom", Self::BracketBottom),
+            ("tuplet1", Self::Tuplet1),
+            ("kahnHeelClick", Self::KahnHeelClick),
+            ("cClef8vb", Self::CClef8Vb),
+            ("kahnStomp", Self::KahnStomp),
+            ("fClef", Self::FClef),
+            ("elecVolumeLevel20", Self::ElecVolumeLevel20),
+            ("accSagittalUnused2", Self::AccSagittalUnused2),
+            ("wiggleVibratoMediumFast", Self::WiggleVibratoMediumFast),
+            ("noteheadDiamondClusterWhite2nd", Self::NoteheadDiamondClusterWhite2nd),
+            ("noteheadCircleXDoubleWhole", Self::NoteheadCircleXDoubleWhole),
+            ("functionFUpper", Self::FunctionFUpper),
+            ("elecAudioChannelsSeven", Self::ElecAudioChannelsSeven),
+            ("note64thDown", Self::Note64thDown),
+            ("noteheadCircledDoubleWhole", Self::NoteheadCircledDoubleWhole),
+            ("keyboardPedalHookStart", Self::KeyboardPedalHookStart),
+            ("accidentalNaturalTwoArrowsUp", Self::AccidentalNaturalTwoArrowsUp),
+            ("chantCustosStemUpPosLowest", Self::ChantCustosStemUpPosLowest),
+            ("kahnRightCatch", Self::KahnRightCatch),
+            ("kahnBackFlap", Self::KahnBackFlap),
+            ("pluckedWithFingernails", Self::PluckedWithFingernails),
+            ("unmeasuredTremolo", Self::UnmeasuredTremolo),
+            ("kodalyHandLa", Self::KodalyHandLa),
+            ("accSagittal17CommaUp", Self::AccSagittal17CommaUp),
+            ("pictAlmglocken", Self::PictAlmglocken),
+            ("accidentalThreeQuarterTonesFlatArrowDown", Self::AccidentalThreeQuarterTonesFlatArrowDown),
+            ("kahnOverTheTopTap", Self::KahnOverTheTopTap),
+            ("articLaissezVibrerBelow", Self::ArticLaissezVibrerBelow),
+            ("wiggleVibratoLargestSlow", Self::WiggleVibratoLargestSlow),
+            ("pictScrapeEdgeToCenter", Self::PictScrapeEdgeToCenter),
+            ("octaveParensRight", Self::OctaveParensRight),
+            ("noteASharpBlack", Self::NoteASharpBlack),
+            ("chantLigaturaDesc4th", Self::ChantLigaturaDesc4th),
+            ("pictBeaterHardTimpaniLeft", Self::PictBeaterHardTimpaniLeft),
+            ("pictBeaterJazzSticksUp", Self::PictBeaterJazzSticksUp),
+            ("noteCBlack", Self::NoteCBlack),
+            ("keyboardPedalToe2", Self::KeyboardPedalToe2),
+            ("mensuralProportion6", Self::MensuralProportion6),
+            ("accSagittal11MediumDiesisDown", Self::AccSagittal11MediumDiesisDown),
+            ("textTupletBracketStartShortStem", Self::TextTupletBracketStartShortStem),
+            ("wiggleSquareWaveWide", Self::WiggleSquareWaveWide),
+            ("organGermanBuxheimerSemibrevisRest", Self::OrganGermanBuxheimerSemibrevisRest),
+            ("accidentalNaturalThreeArrowsDown", Self::AccidentalNaturalThreeArrowsDown),
+            ("noteGSharpWhole", Self::NoteGSharpWhole),
+            ("ornamentShake3", Self::OrnamentShake3),
+            ("wiggleVibratoSmallSlower", Self::WiggleVibratoSmallSlower),
+            ("elecVolumeLevel60", Self::ElecVolumeLevel60),
+            ("mensuralObliqueAsc4thWhite", Self::MensuralObliqueAsc4thWhite),
+            ("harpSalzedoFluidicSoundsLeft", Self::HarpSalzedoFluidicSoundsLeft),
+            ("chantPunctum", Self::ChantPunctum),
+            ("timeSigParensRight", Self::TimeSigParensRight),
+            ("accSagittalDoubleSharp5v11SDown", Self::AccSagittalDoubleSharp5V11SDown),
+            ("accSagittal11LargeDiesisDown", Self::AccSagittal11LargeDiesisDown),
+            ("wiggleCircular", Self::WiggleCircular),
+            ("mensuralObliqueAsc4thBlackVoid", Self::MensuralObliqueAsc4thBlackVoid),
+            ("staffPosLower6", Self::StaffPosLower6),
+            ("harpSalzedoWhistlingSounds", Self::HarpSalzedoWhistlingSounds),
+            ("repeatRightLeft", Self::RepeatRightLeft),
+            ("figbass8", Self::Figbass8),
+            ("staff4Lines", Self::Staff4Lines),
+            ("kahnJumpTogether", Self::KahnJumpTogether),
+            ("controlBeginTie", Self::ControlBeginTie),
+            ("wiggleVibratoSmallestFast", Self::WiggleVibratoSmallestFast),
+            ("ornamentObliqueLineBeforeNote", Self::OrnamentObliqueLineBeforeNote),
+            ("accSagittal5v19CommaUp", Self::AccSagittal5V19CommaUp),
+            ("wiggleTrill", Self::WiggleTrill),
+            ("noteEFlatBlack", Self::NoteEFlatBlack),
+            ("noteheadCircledXLarge", Self::NoteheadCircledXLarge),
+            ("ornamentPrecompInvertedMordentUpperPrefix", Self::OrnamentPrecompInvertedMordentUpperPrefix),
+            ("pictMusicalSaw", Self::PictMusicalSaw),
+            ("noteShapeMoonLeftDoubleWhole", Self::NoteShapeMoonLeftDoubleWhole),
+            ("pictXylBass", Self::PictXylBass),
+            ("staffDivideArrowUpDown", Self::StaffDivideArrowUpDown),
+            ("pictBeaterMediumTimpaniRight", Self::PictBeaterMediumTimpaniRight),
+            ("noteTeHalf", Self::NoteTeHalf),
+            ("quindicesimaBassaMb", Self::QuindicesimaBassaMb),
+            ("accSagittalSharp23CDown", Self::AccSagittalSharp23CDown),
+            ("stringsTripleChopInward", Self::StringsTripleChopInward),
+            ("controlBeginSlur", Self::ControlBeginSlur),
+            ("luteGermanSLower", Self::LuteGermanSLower),
+            ("luteGermanFLower", Self::LuteGermanFLower),
+            ("functionKUpper", Self::FunctionKUpper),
+            ("accidentalCombiningRaise53LimitComma", Self::AccidentalCombiningRaise53LimitComma),
+            ("arrowBlackUp", Self::ArrowBlackUp),
+            ("accidentalNarrowReversedFlat", Self::AccidentalNarrowReversedFlat),
+            ("chantPodatusLower", Self::ChantPodatusLower),
+            ("restLonga", Self::RestLonga),
+            ("ornamentDoubleObliqueLinesAfterNote", Self::OrnamentDoubleObliqueLinesAfterNote),
+            ("noteMiWhole", Self::NoteMiWhole),
+            ("brassMuteClosed", Self::BrassMuteClosed),
+            ("organGerman4Fusae", Self::OrganGerman4Fusae),
+            ("fretboard4String", Self::Fretboard4String),
+            ("ornamentPrecompSlideTrillBach", Self::OrnamentPrecompSlideTrillBach),
+            ("pictBeaterJazzSticksDown", Self::PictBeaterJazzSticksDown),
+            ("wiggleVibratoMediumSlow", Self::WiggleVibratoMediumSlow),
+            ("ornamentPrecompTrillLowerSuffix", Self::OrnamentPrecompTrillLowerSuffix),
+            ("luteFrench9thCourse", Self::LuteFrench9thCourse),
+            ("staff3LinesNarrow", Self::Staff3LinesNarrow),
+            ("dynamicSforzandoPiano", Self::DynamicSforzandoPiano),
+            ("accSagittalFlat7v19CDown", Self::AccSagittalFlat7V19CDown),
+            ("accSagittalDoubleSharp19CDown", Self::AccSagittalDoubleSharp19CDown),
+            ("bracket", Self::Bracket),
+            ("guitarVibratoStroke", Self::GuitarVibratoStroke),
+            ("pictGumHardLeft", Self::PictGumHardLeft),
+            ("kahnWingChange", Self::KahnWingChange),
+            ("stringsDownBowAwayFromBody", Self::StringsDownBowAwayFromBody),
+            ("luteGermanDUpper", Self::LuteGermanDUpper),
+            ("pictBeaterHardTimpaniUp", Self::PictBeaterHardTimpaniUp),
+            ("timeSigCombDenominator", Self::TimeSigCombDenominator),
+            ("pictTomTomIndoAmerican", Self::PictTomTomIndoAmerican),
+            ("ornamentTurn", Self::OrnamentTurn),
+            ("kahnGraceTapChange", Self::KahnGraceTapChange),
+            ("noteheadDiamondClusterWhiteTop", Self::NoteheadDiamondClusterWhiteTop),
+            ("mensuralObliqueAsc2ndBlack", Self::MensuralObliqueAsc2ndBlack),
+            ("kahnRightFoot", Self::KahnRightFoot),
+            ("noteheadDoubleWhole", Self::NoteheadDoubleWhole),
+            ("chantFclef", Self::ChantFclef),
+            ("noteheadXBlack", Self::NoteheadXBlack),
+            ("handbellsEcho1", Self::HandbellsEcho1),
+            ("staffPosRaise6", Self::StaffPosRaise6),
+            ("fClef8vb", Self::FClef8Vb),
+            ("noteheadLargeArrowUpWhole", Self::NoteheadLargeArrowUpWhole),
+            ("noteheadSlashedBlack1", Self::NoteheadSlashedBlack1),
+            ("accidentalSharpReversed", Self::AccidentalSharpReversed),
+            ("noteShapeArrowheadLeftBlack", Self::NoteShapeArrowheadLeftBlack),
+            ("mensuralRestBrevis", Self::MensuralRestBrevis),
+            ("noteheadCircledBlack", Self::NoteheadCircledBlack),
+            ("pictBeaterHardGlockenspielRight", Self::PictBeaterHardGlockenspielRight),
+            ("noteheadSlashHorizontalEndsMuted", Self::NoteheadSlashHorizontalEndsMuted),
+            ("kahnRipple", Self::KahnRipple),
+            ("mensuralRestLongaPerfecta", Self::MensuralRestLongaPerfecta),
+            ("elecMIDIOut", Self::ElecMidiOut),
+            ("accSagittal5v13LargeDiesisUp", Self::AccSagittal5V13LargeDiesisUp),
+            ("beamAccelRit11", Self::BeamAccelRit11),
+            ("pictDuckCall", Self::PictDuckCall),
+            ("note256thUp", Self::Note256thUp),
+            ("accidentalSharpOneHorizontalStroke", Self::AccidentalSharpOneHorizontalStroke),
+            ("noteheadTriangleUpHalf", Self::NoteheadTriangleUpHalf),
+            ("luteGermanZLower", Self::LuteGermanZLower),
+            ("arrowheadBlackLeft", Self::ArrowheadBlackLeft),
+            ("articStaccatoAbove", Self::ArticStaccatoAbove),
+            ("luteFrenchMordentLower", Self::LuteFrenchMordentLower),
+            ("noteheadTriangleDownDoubleWhole", Self::NoteheadTriangleDownDoubleWhole),
+            ("stemVibratoPulse", Self::StemVibratoPulse),
+            ("mensuralColorationEndRound", Self::MensuralColorationEndRound),
+            ("arrowOpenDownLeft", Self::ArrowOpenDownLeft),
+            ("noteheadCircledWhole", Self::NoteheadCircledWhole),
+            ("noteheadDiamondHalfFilled", Self::NoteheadDiamondHalfFilled),
+            ("accSagittal11v19LargeDiesisDown", Self::AccSagittal11V19LargeDiesisDown),
+            ("pictMegaphone", Self::PictMegaphone),
+            ("luteGermanKUpper", Self::LuteGermanKUpper),
+            ("textHeadlessBlackNoteLongStem", Self::TextHeadlessBlackNoteLongStem),
+            ("harpMetalRod", Self::HarpMetalRod),
+            ("articTenutoAccentAbove", Self::ArticTenutoAccentAbove),
+            ("flag8thDown", Self::Flag8thDown),
+            ("noteLeWhole", Self::NoteLeWhole),
+            ("timeSigMultiply", Self::TimeSigMultiply),
+            ("chantConnectingLineAsc3rd", Self::ChantConnectingLineAsc3Rd),
+            ("accidentalFilledReversedFlatAndFlatArrowUp", Self::AccidentalFilledReversedFlatAndFlatArrowUp),
+            ("noteheadDiamondClusterBlackBottom", Self::NoteheadDiamondClusterBlackBottom),
+            ("noteheadRoundBlackSlashed", Self::NoteheadRoundBlackSlashed),
+            ("pictBeaterSoftYarnRight", Self::PictBeaterSoftYarnRight),
+            ("noteSiBlack", Self::NoteSiBlack),
+            ("staffPosLower2", Self::StaffPosLower2),
+            ("csymBracketLeftTall", Self::CsymBracketLeftTall),
+            ("elecVolumeLevel80", Self::ElecVolumeLevel80),
+            ("noteDiWhole", Self::NoteDiWhole),
+            ("wiggleArpeggiatoDownArrow", Self::WiggleArpeggiatoDownArrow),
+            ("noteheadSlashDiamondWhite", Self::NoteheadSlashDiamondWhite),
+            ("ottavaBassa", Self::OttavaBassa),
+            ("beamAccelRit7", Self::BeamAccelRit7),
+            ("accSagittal11v49CommaUp", Self::AccSagittal11V49CommaUp),
+            ("pictBeaterMediumTimpaniLeft", Self::PictBeaterMediumTimpaniLeft),
+            ("accidentalThreeQuarterTonesSharpStein", Self::AccidentalThreeQuarterTonesSharpStein),
+            ("staff6LinesWide", Self::Staff6LinesWide),
+            ("pictMaraca", Self::PictMaraca),
+            ("luteStaff6Lines", Self::LuteStaff6Lines),
+            ("stringsBowBehindBridgeTwoStrings", Self::StringsBowBehindBridgeTwoStrings),
+            ("timeSig0", Self::TimeSig0),
+            ("kahnBackRip", Self::KahnBackRip),
+            ("beamAccelRit15", Self::BeamAccelRit15),
+            ("barlineDouble", Self::BarlineDouble),
+            ("figbassParensLeft", Self::FigbassParensLeft),
+            ("fingeringLeftParenthesis", Self::FingeringLeftParenthesis),
+            ("stemRimShot", Self::StemRimShot),
+            ("elecMIDIController20", Self::ElecMidiController20),
+            ("rest32nd", Self::Rest32nd),
+            ("accidentalEnharmonicAlmostEqualTo", Self::AccidentalEnharmonicAlmostEqualTo),
+            ("noteBFlatWhole", Self::NoteBFlatWhole),
+            ("mensuralNoteheadLongaBlack", Self::MensuralNoteheadLongaBlack),
+            ("mensuralGclef", Self::MensuralGclef),
+            ("accidentalWyschnegradsky5TwelfthsFlat", Self::AccidentalWyschnegradsky5twelfthsFlat),
+            ("luteFrenchFretN", Self::LuteFrenchFretN),
+            ("windHalfClosedHole1", Self::WindHalfClosedHole1),
+            ("restMaxima", Self::RestMaxima),
+            ("pictBoardClapper", Self::PictBoardClapper),
+            ("luteGermanGLower", Self::LuteGermanGLower),
+            ("mensuralObliqueAsc4thVoid", Self::MensuralObliqueAsc4thVoid),
+            ("organGermanFusa", Self::OrganGermanFusa),
+            ("noteheadSlashedHalf1", Self::NoteheadSlashedHalf1),
+            ("accSagittalFlat55CUp", Self::AccSagittalFlat55CUp),
+            ("csymAlteredBassSlash", Self::CsymAlteredBassSlash),
+            ("timeSigBracketRightSmall", Self::TimeSigBracketRightSmall),
+            ("stemSwished", Self::StemSwished),
+            ("articUnstressBelow", Self::ArticUnstressBelow),
+            ("chantStrophicusLiquescens4th", Self::ChantStrophicusLiquescens4th),
+            ("swissRudimentsNoteheadBlackFlam", Self::SwissRudimentsNoteheadBlackFlam),
+            ("pictTambourine", Self::PictTambourine),
+            ("accSagittalDoubleFlat23CUp", Self::AccSagittalDoubleFlat23CUp),
+            ("accSagittalDoubleFlat5v23SUp", Self::AccSagittalDoubleFlat5V23SUp),
+            ("pictGumSoftRight", Self::PictGumSoftRight),
+            ("elecDownload", Self::ElecDownload),
+            ("timeSig5Turned", Self::TimeSig5turned),
+            ("accidentalCombiningRaise23Limit29LimitComma", Self::AccidentalCombiningRaise23Limit29LimitComma),
+            ("accidentalReversedFlatArrowUp", Self::AccidentalReversedFlatArrowUp),
+            ("elecRewind", Self::ElecRewind),
+            ("luteItalianHoldFinger", Self::LuteItalianHoldFinger),
+            ("timeSigFractionalSlash", Self::TimeSigFractionalSlash),
+            ("accidentalCombiningRaise37Quartertone", Self::AccidentalCombiningRaise37Quartertone),
+            ("mensuralCombStemDown", Self::MensuralCombStemDown),
+            ("brassMuteOpen", Self::BrassMuteOpen),
+            ("scaleDegree5", Self::ScaleDegree5),
+            ("accdnPush", Self::AccdnPush),
+            ("mensuralCclefPetrucciPosMiddle", Self::MensuralCclefPetrucciPosMiddle),
+            ("noteABlack", Self::NoteABlack),
+            ("accidentalQuarterToneSharpArrowDown", Self::AccidentalQuarterToneSharpArrowDown),
+            ("stringsThumbPositionTurned", Self::StringsThumbPositionTurned),
+            ("functionLessThan", Self::FunctionLessThan),
+            ("accSagittal7CommaDown", Self::AccSagittal7CommaDown),
+            ("accidentalWyschnegradsky1TwelfthsFlat", Self::AccidentalWyschnegradsky1twelfthsFlat),
+            ("mensuralNoteheadMinimaWhite", Self::MensuralNoteheadMinimaWhite),
+            ("elecAudioChannelsOne", Self::ElecAudioChannelsOne),
+            ("arrowheadWhiteLeft", Self::ArrowheadWhiteLeft),
+            ("keyboardPedalS", Self::KeyboardPedalS),
+            ("pictBeaterMetalRight", Self::PictBeaterMetalRight),
+            ("noteSeHalf", Self::NoteSeHalf),
+            ("mensuralProlation3", Self::MensuralProlation3),
+            ("organGerman6Minimae", Self::OrganGerman6Minimae),
+            ("accSagittalSharp19sDown", Self::AccSagittalSharp19SDown),
+            ("mensuralNoteheadSemibrevisBlackVoid", Self::MensuralNoteheadSemibrevisBlackVoid),
+            ("organGerman2Fusae", Self::OrganGerman2Fusae),
+            ("accidentalQuarterToneSharpNaturalArrowUp", Self::AccidentalQuarterToneSharpNaturalArrowUp),
+            ("noteheadRectangularClusterBlackMiddle", Self::NoteheadRectangularClusterBlackMiddle),
+            ("wiggleCircularEnd", Self::WiggleCircularEnd),
+            ("pictPistolShot", Self::PictPistolShot),
+            ("miscDoNotPhotocopy", Self::MiscDoNotPhotocopy),
+            ("articMarcatoStaccatoBelow", Self::ArticMarcatoStaccatoBelow),
+            ("kievanAugmentationDot", Self::KievanAugmentationDot),
+            ("wiggleVibratoLargestFast", Self::WiggleVibratoLargestFast),
+            ("vocalTongueClickStockhausen", Self::VocalTongueClickStockhausen),
+            ("pictWoundHardDown", Self::PictWoundHardDown),
+            ("pictBeaterWoodXylophoneRight", Self::PictBeaterWoodXylophoneRight),
+            ("metNoteHalfDown", Self::MetNoteHalfDown),
+            ("timeSig0Reversed", Self::TimeSig0Reversed),
+            ("metNoteHalfUp", Self::MetNoteHalfUp),
+            ("wiggleVibratoLargeFast", Self::WiggleVibratoLargeFast),
+            ("figbass5Raised3", Self::Figbass5Raised3),
+            ("daseianFinales4", Self::DaseianFinales4),
+            ("noteShapeSquareWhite", Self::NoteShapeSquareWhite),
+            ("wiggleVibratoMediumSlowest", Self::WiggleVibratoMediumSlowest),
+            ("pictGumSoftUp", Self::PictGumSoftUp),
+            ("guitarString5", Self::GuitarString5),
+            ("pictBeaterSoftBassDrumUp", Self::PictBeaterSoftBassDrumUp),
+            ("pictBellTree", Self::PictBellTree),
+            ("luteItalianClefFFaUt", Self::LuteItalianClefFFaUt),
+            ("pictOnRim", Self::PictOnRim),
+            ("accidentalFlatTurned", Self::AccidentalFlatTurned),
+            ("mensuralBlackSemibrevisVoid", Self::MensuralBlackSemibrevisVoid),
+            ("mensuralNoteheadSemibrevisBlackVoidTurned", Self::MensuralNoteheadSemibrevisBlackVoidTurned),
+            ("handbellsMalletLft", Self::HandbellsMalletLft),
+            ("luteDurationHalf", Self::LuteDurationHalf),
+            ("accidentalOneAndAHalfSharpsArrowDown", Self::AccidentalOneAndAHalfSharpsArrowDown),
+            ("noteheadRoundWhiteSlashed", Self::NoteheadRoundWhiteSlashed),
+            ("functionSUpper", Self::FunctionSUpper),
+            ("scaleDegree4", Self::ScaleDegree4),
+            ("guitarRightHandTapping", Self::GuitarRightHandTapping),
+            ("graceNoteSlashStemDown", Self::GraceNoteSlashStemDown),
+            ("accidentalFlatOneArrowUp", Self::AccidentalFlatOneArrowUp),
+            ("luteFrench10thCourse", Self::LuteFrench10thCourse),
+            ("pictBirdWhistle", Self::PictBirdWhistle),
+            ("noteheadXHalf", Self::NoteheadXHalf),
+            ("noteheadSlashedBlack2", Self::NoteheadSlashedBlack2),
+            ("mensuralProportionProportioDupla2", Self::MensuralProportionProportioDupla2),
+            ("textBlackNoteFrac16thLongStem", Self::TextBlackNoteFrac16thLongStem),
+            ("timeSig9", Self::TimeSig9),
+            ("noteheadDiamondWholeOld", Self::NoteheadDiamondWholeOld),
+            ("daseianFinales3", Self::DaseianFinales3),
+            ("mensuralModusImperfectumVert", Self::MensuralModusImperfectumVert),
+            ("handbellsBelltree", Self::HandbellsBelltree),
+            ("arrowBlackLeft", Self::ArrowBlackLeft),
+            ("pictBambooChimes", Self::PictBambooChimes),
+            ("textTuplet3LongStem", Self::TextTuplet3LongStem),
+            ("cClefChange", Self::CClefChange),
+            ("accSagittalFlat11v19MDown", Self::AccSagittalFlat11V19MDown),
+            ("pictBeaterMediumXylophoneDown", Self::PictBeaterMediumXylophoneDown),
+            ("accSagittalFlat11LDown", Self::AccSagittalFlat11LDown),
+            ("kahnSlideTap", Self::KahnSlideTap),
+            ("harpSalzedoMuffleTotally", Self::HarpSalzedoMuffleTotally),
+            ("accSagittalSharp", Self::AccSagittalSharp),
+            ("chantStrophicus", Self::ChantStrophicus),
+            ("articTenutoStaccatoAbove", Self::ArticTenutoStaccatoAbove),
+            ("pictTomTomChinese", Self::PictTomTomChinese),
+            ("pictBeaterSoftTimpaniRight", Self::PictBeaterSoftTimpaniRight),
+            ("accSagittalFlat19CDown", Self::AccSagittalFlat19CDown),
+            ("ornamentBottomLeftConcaveStrokeLarge", Self::OrnamentBottomLeftConcaveStrokeLarge),
+            ("pictBeaterSuperballUp", Self::PictBeaterSuperballUp),
+            ("mensuralCombStemUpFlagFusa", Self::MensuralCombStemUpFlagFusa),
+            ("accSagittalFlat11v49CDown", Self::AccSagittalFlat11V49CDown),
+            ("ornamentRightVerticalStroke", Self::OrnamentRightVerticalStroke),
+            ("accidentalBuyukMucennebFlat", Self::AccidentalBuyukMucennebFlat),
+            ("organGerman5Semiminimae", Self::OrganGerman5Semiminimae),
+            ("kievanNoteWhole", Self::KievanNoteWhole),
+            ("arrowheadBlackDownRight", Self::ArrowheadBlackDownRight),
+            ("metNote1024thUp", Self::MetNote1024thUp),
+            ("luteGermanCUpper", Self::LuteGermanCUpper),
+            ("noteAFlatBlack", Self::NoteAFlatBlack),
+            ("kahnGraceTap", Self::KahnGraceTap),
+            ("chantCustosStemDownPosHigh", Self::ChantCustosStemDownPosHigh),
+            ("tremoloDivisiDots6", Self::TremoloDivisiDots6),
+            ("accidentalCombiningLower41Comma", Self::AccidentalCombiningLower41Comma),
+            ("accSagittalFlat23SDown", Self::AccSagittalFlat23SDown),
+            ("analyticsStartStimme", Self::AnalyticsStartStimme),
+            ("accSagittalDoubleFlat23SUp", Self::AccSagittalDoubleFlat23SUp),
+            ("pictCabasa", Self::PictCabasa),
+            ("timeSig3Reversed", Self::TimeSig3Reversed),
+            ("accidentalJohnstonPlus", Self::AccidentalJohnstonPlus),
+            ("noteheadTriangleLeftBlack", Self::NoteheadTriangleLeftBlack),
+            ("mensuralProportionProportioQuadrupla", Self::MensuralProportionProportioQuadrupla),
+            ("organGerman6Semiminimae", Self::OrganGerman6Semiminimae),
+            ("ornamentPrecompMordentRelease", Self::OrnamentPrecompMordentRelease),
+            ("elecUSB", Self::ElecUsb),
+            ("csymDiminished", Self::CsymDiminished),
+            ("noteheadSlashedDoubleWhole2", Self::NoteheadSlashedDoubleWhole2),
+            ("kahnLeap", Self::KahnLeap),
+            ("accSagittal143CommaDown", Self::AccSagittal143CommaDown),
+            ("staff2LinesNarrow", Self::Staff2LinesNarrow),
+            ("accidentalLowerOneTridecimalQuartertone", Self::AccidentalLowerOneTridecimalQuartertone),
+            ("pictTamTam", Self::PictTamTam),
+            ("kahnWing", Self::KahnWing),
+            ("fingeringLeftBracketItalic", Self::FingeringLeftBracketItalic),
+            ("mensuralObliqueAsc2ndWhite", Self::MensuralObliqueAsc2ndWhite),
+            ("luteItalianFret6", Self::LuteItalianFret6),
+            ("accdnRH4RanksBassAlto", Self::AccdnRh4RanksBassAlto),
+            ("mensuralProportion5", Self::MensuralProportion5),
+            ("medRenNatural", Self::MedRenNatural),
+            ("pictCarHorn", Self::PictCarHorn),
+            ("brassBend", Self::BrassBend),
+            ("accSagittalDoubleSharp17kDown", Self::AccSagittalDoubleSharp17KDown),
+            ("chantAccentusAbove", Self::ChantAccentusAbove),
+            ("mensuralObliqueAsc3rdWhite", Self::MensuralObliqueAsc3RdWhite),
+            ("accSagittalFlat7v11kDown", Self::AccSagittalFlat7V11KDown),
+            ("noteheadPlusHalf", Self::NoteheadPlusHalf),
+            ("accSagittal17KleismaUp", Self::AccSagittal17KleismaUp),
+            ("mensuralCclefPetrucciPosLowest", Self::MensuralCclefPetrucciPosLowest),
+            ("noteShapeMoonLeftWhite", Self::NoteShapeMoonLeftWhite),
+            ("ornamentTrill", Self::OrnamentTrill),
+            ("noteheadCowellThirteenthNoteSeriesWhole", Self::NoteheadCowellThirteenthNoteSeriesWhole),
+            ("luteGermanALower", Self::LuteGermanALower),
+            ("accidentalHabaQuarterToneHigher", Self::AccidentalHabaQuarterToneHigher),
+            ("accSagittal5TinasUp", Self::AccSagittal5tinasUp),
+            ("accSagittalFlat23SUp", Self::AccSagittalFlat23SUp),
+            ("accSagittalSharp7v11kUp", Self::AccSagittalSharp7V11KUp),
+            ("pictFlexatone", Self::PictFlexatone),
+            ("noteheadSlashWhiteWhole", Self::NoteheadSlashWhiteWhole),
+            ("organGermanSemibrevisRest", Self::OrganGermanSemibrevisRest),
+            ("textCont32ndBeamLongStem", Self::TextCont32ndBeamLongStem),
+            ("figbass5", Self::Figbass5),
+            ("mensuralProlationCombiningThreeDotsTri", Self::MensuralProlationCombiningThreeDotsTri),
+            ("windMultiphonicsWhiteStem", Self::WindMultiphonicsWhiteStem),
+            ("pictMarSmithBrindle", Self::PictMarSmithBrindle),
+            ("pictHalfOpen1", Self::PictHalfOpen1),
+            ("timeSigCut3", Self::TimeSigCut3),
+            ("metricModulationArrowLeft", Self::MetricModulationArrowLeft),
+            ("pictCastanetsWithHandle", Self::PictCastanetsWithHandle),
+            ("accSagittal5v11SmallDiesisDown", Self::AccSagittal5V11SmallDiesisDown),
+            ("accdnCombRH4RanksEmpty", Self::AccdnCombRh4RanksEmpty),
+            ("kahnHeelStep", Self::KahnHeelStep),
+            ("wiggleTrillFast", Self::WiggleTrillFast),
+            ("wiggleGlissandoGroup2", Self::WiggleGlissandoGroup2),
+            ("noteShapeTriangleRoundLeftWhite", Self::NoteShapeTriangleRoundLeftWhite),
+            ("noteheadSlashedWhole2", Self::NoteheadSlashedWhole2),
+            ("vocalMouthSlightlyOpen", Self::VocalMouthSlightlyOpen),
+            ("elecVideoCamera", Self::ElecVideoCamera),
+            ("noteDoWhole", Self::NoteDoWhole),
+            ("accdnRicochet6", Self::AccdnRicochet6),
+            ("noteHalfDown", Self::NoteHalfDown),
+            ("mensuralBlackMinima", Self::MensuralBlackMinima),
+            ("noteHBlack", Self::NoteHBlack),
+            ("lyricsElision", Self::LyricsElision),
+            ("accidentalBracketRight", Self::AccidentalBracketRight),
+            ("pictBeaterMediumYarnLeft", Self::PictBeaterMediumYarnLeft),
+            ("harpTuningKey", Self::HarpTuningKey),
+            ("rest256th", Self::Rest256th),
+            ("schaefferGClefToFClef", Self::SchaefferGClefToFClef),
+            ("kievanNoteReciting", Self::KievanNoteReciting),
+            ("accidentalLargeDoubleSharp", Self::AccidentalLargeDoubleSharp),
+            ("noteFaWhole", Self::NoteFaWhole),
+            ("accdnRH3RanksOrgan", Self::AccdnRh3RanksOrgan),
+            ("accidentalDoubleSharpOneArrowDown", Self::AccidentalDoubleSharpOneArrowDown),
+            ("kievanEndingSymbol", Self::KievanEndingSymbol),
+            ("timeSig5", Self::TimeSig5),
+            ("accSagittalDoubleSharp11v49CDown", Self::AccSagittalDoubleSharp11V49CDown),
+            ("guitarString7", Self::GuitarString7),
+            ("mensuralCombStemUpFlagFlared", Self::MensuralCombStemUpFlagFlared),
+            ("mensuralObliqueDesc2ndWhite", Self::MensuralObliqueDesc2ndWhite),
+            ("mensuralModusPerfectumVert", Self::MensuralModusPerfectumVert),
+            ("articUnstressAbove", Self::ArticUnstressAbove),
+            ("textTuplet3ShortStem", Self::TextTuplet3ShortStem),
+            ("cClefCombining", Self::CClefCombining),
+            ("elecAudioChannelsTwo", Self::ElecAudioChannelsTwo),
+            ("pictSnareDrum", Self::PictSnareDrum),
+            ("accSagittal55CommaDown", Self::AccSagittal55CommaDown),
+            ("windHalfClosedHole2", Self::WindHalfClosedHole2),
+            ("noteheadClusterHalfTop", Self::NoteheadClusterHalfTop),
+            ("mensuralBlackBrevis", Self::MensuralBlackBrevis),
+            ("fingering2", Self::Fingering2),
+            ("noteShapeArrowheadLeftWhite", Self::NoteShapeArrowheadLeftWhite),
+            ("accSagittal5v11SmallDiesisUp", Self::AccSagittal5V11SmallDiesisUp),
+            ("accidentalSims6Down", Self::AccidentalSims6Down),
+            ("metNote64thUp", Self::MetNote64thUp),
+            ("luteItalianFret1", Self::LuteItalianFret1),
+            ("accSagittalDoubleFlat", Self::AccSagittalDoubleFlat),
+            ("noteheadWholeWithX", Self::NoteheadWholeWithX),
+            ("wiggleVibratoSmallestSlower", Self::WiggleVibratoSmallestSlower),
+            ("accidentalSharpRepeatedLineStockhausen", Self::AccidentalSharpRepeatedLineStockhausen),
+            ("accSagittalFlat5v19CDown", Self::AccSagittalFlat5V19CDown),
+            ("semipitchedPercussionClef1", Self::SemipitchedPercussionClef1),
+            ("daseianResidua1", Self::DaseianResidua1),
+            ("mensuralRestSemifusa", Self::MensuralRestSemifusa),
+            ("wiggleCircularStart", Self::WiggleCircularStart),
+            ("accSagittalFlat49MDown", Self::AccSagittalFlat49MDown),
+            ("mensuralObliqueDesc2ndVoid", Self::MensuralObliqueDesc2ndVoid),
+            ("accidentalFlatThreeArrowsUp", Self::AccidentalFlatThreeArrowsUp),
+            ("pluckedDampAll", Self::PluckedDampAll),
+            ("timeSigFractionTwoThirds", Self::TimeSigFractionTwoThirds),
+            ("functionKLower", Self::FunctionKLower),
+            ("mensuralProportion2", Self::MensuralProportion2),
+            ("arrowBlackDownLeft", Self::ArrowBlackDownLeft),
+            ("accidentalWyschnegradsky9TwelfthsFlat", Self::AccidentalWyschnegradsky9twelfthsFlat),
+            ("arrowWhiteUpRight", Self::ArrowWhiteUpRight),
+            ("flag8thUp", Self::Flag8thUp),
+            ("accdnPull", Self::AccdnPull),
+            ("timeSigFractionThreeQuarters", Self::TimeSigFractionThreeQuarters),
+            ("accSagittalFlat17CDown", Self::AccSagittalFlat17CDown),
+            ("noteGFlatHalf", Self::NoteGFlatHalf),
+            ("guitarString3", Self::GuitarString3),
+            ("fClefTurned", Self::FClefTurned),
+            ("accidentalTavenerSharp", Self::AccidentalTavenerSharp),
+            ("brassDoitShort", Self::BrassDoitShort),
+            ("accidentalSharpTwoArrowsDown", Self::AccidentalSharpTwoArrowsDown),
+            ("accSagittalSharp7v19CUp", Self::AccSagittalSharp7V19CUp),
+            ("accSagittal49LargeDiesisDown", Self::AccSagittal49LargeDiesisDown),
+            ("octaveBaselineM", Self::OctaveBaselineM),
+            ("noteShapeTriangleRightWhite", Self::NoteShapeTriangleRightWhite),
+            ("kievanNoteHalfStaffLine", Self::KievanNoteHalfStaffLine),
+            ("ornamentPrecompAppoggTrillSuffix", Self::OrnamentPrecompAppoggTrillSuffix),
+            ("arrowheadOpenDownLeft", Self::ArrowheadOpenDownLeft),
+            ("pictCymbalTongs", Self::PictCymbalTongs),
+            ("mensuralRestMinima", Self::MensuralRestMinima),
+            ("articAccentStaccatoBelow", Self::ArticAccentStaccatoBelow),
+            ("pictSlideWhistle", Self::PictSlideWhistle),
+            ("windRelaxedEmbouchure", Self::WindRelaxedEmbouchure),
+            ("accidentalDoubleSharpTwoArrowsDown", Self::AccidentalDoubleSharpTwoArrowsDown),
+            ("noteheadPlusWhole", Self::NoteheadPlusWhole),
+            ("pictBeaterHardXylophoneLeft", Self::PictBeaterHardXylophoneLeft),
+            ("chantAuctumDesc", Self::ChantAuctumDesc),
+            ("articStressBelow", Self::ArticStressBelow),
+            ("ornamentLeftVerticalStroke", Self::OrnamentLeftVerticalStroke),
+            ("accdnRH4RanksSoftTenor", Self::AccdnRh4RanksSoftTenor),
+            ("pictMetalTubeChimes", Self::PictMetalTubeChimes),
+            ("staffPosLower1", Self::StaffPosLower1),
+            ("fingeringOLower", Self::FingeringOLower),
+            ("chantOriscusDescending", Self::ChantOriscusDescending),
+            ("textBlackNoteFrac32ndLongStem", Self::TextBlackNoteFrac32ndLongStem),
+            ("wiggleVibratoLargestFastest", Self::WiggleVibratoLargestFastest),
+            ("pictBeaterSpoonWoodenMallet", Self::PictBeaterSpoonWoodenMallet),
+            ("organGermanAUpper", Self::OrganGermanAUpper),
+            ("accidentalOneQuarterToneSharpStockhausen", Self::AccidentalOneQuarterToneSharpStockhausen),
+            ("ornamentPortDeVoixV", Self::OrnamentPortDeVoixV),
+            ("accdnRH3RanksMaster", Self::AccdnRh3RanksMaster),
+            ("pictBeaterHardXylophoneDown", Self::PictBeaterHardXylophoneDown),
+            ("pictTamTamWithBeater", Self::PictTamTamWithBeater),
+            ("noteheadHalfWithX", Self::NoteheadHalfWithX),
+            ("functionMUpper", Self::FunctionMUpper),
+            ("accSagittalSharp23SUp", Self::AccSagittalSharp23SUp),
+            ("note1024thDown", Self::Note1024thDown),
+            ("kahnRiff", Self::KahnRiff),
+            ("brassValveTrill", Self::BrassValveTrill),
+            ("noteheadSlashWhiteMuted", Self::NoteheadSlashWhiteMuted),
+            ("noteheadTriangleDownWhite", Self::NoteheadTriangleDownWhite),
+            ("organGermanGLower", Self::OrganGermanGLower),
+            ("accSagittalFlat7CUp", Self::AccSagittalFlat7CUp),
+            ("accidentalNaturalArabic", Self::AccidentalNaturalArabic),
+            ("accidentalFlatRepeatedLineStockhausen", Self::AccidentalFlatRepeatedLineStockhausen),
+            ("keyboardMiddlePedalPictogram", Self::KeyboardMiddlePedalPictogram),
+            ("harpPedalCentered", Self::HarpPedalCentered),
+            ("mensuralProportion8", Self::MensuralProportion8),
+            ("ornamentShortTrill", Self::OrnamentShortTrill),
+            ("fretboard5String", Self::Fretboard5String),
+            ("brassFallLipShort", Self::BrassFallLipShort),
+            ("ornamentTremblement", Self::OrnamentTremblement),
+            ("luteGermanPLower", Self::LuteGermanPLower),
+            ("accidentalSims6Up", Self::AccidentalSims6Up),
+            ("accSagittal4TinasDown", Self::AccSagittal4tinasDown),
+            ("swissRudimentsNoteheadHalfFlam", Self::SwissRudimentsNoteheadHalfFlam),
+            ("pictBeaterSoftXylophoneDown", Self::PictBeaterSoftXylophoneDown),
+            ("metNote16thUp", Self::MetNote16thUp),
+            ("accdnLH2RanksMasterRound", Self::AccdnLh2RanksMasterRound),
+            ("restWholeLegerLine", Self::RestWholeLegerLine),
+            ("octaveSuperscriptM", Self::OctaveSuperscriptM),
+            ("noteheadHalfFilled", Self::NoteheadHalfFilled),
+            ("keyboardPlayWithLHEnd", Self::KeyboardPlayWithLhEnd),
+            ("staff1Line", Self::Staff1Line),
+            ("accidentalFilledReversedFlatArrowUp", Self::AccidentalFilledReversedFlatArrowUp),
+            ("noteCHalf", Self::NoteCHalf),
+            ("rest128th", Self::Rest128th),
+            ("dynamicPPPP", Self::DynamicPppp),
+            ("kievanNote8thStemUp", Self::KievanNote8thStemUp),
+            ("accSagittalFlat7CDown", Self::AccSagittalFlat7CDown),
+            ("accidentalFlatTwoArrowsDown", Self::AccidentalFlatTwoArrowsDown),
+            ("accdnRicochetStem3", Self::AccdnRicochetStem3),
+            ("miscDoNotCopy", Self::MiscDoNotCopy),
+            ("mensuralNoteheadMaximaBlack", Self::MensuralNoteheadMaximaBlack),
+            ("tuplet3", Self::Tuplet3),
+            ("noteShapeSquareDoubleWhole", Self::NoteShapeSquareDoubleWhole),
+            ("pictBeaterSoftGlockenspielLeft", Self::PictBeaterSoftGlockenspielLeft),
+            ("noteGFlatBlack", Self::NoteGFlatBlack),
+            ("luteFingeringRHThumb", Self::LuteFingeringRhThumb),
+            ("noteheadXWhole", Self::NoteheadXWhole),
+            ("noteheadHalf", Self::NoteheadHalf),
+            ("accidentalUpsAndDownsLess", Self::AccidentalUpsAndDownsLess),
+            ("fingeringPLower", Self::FingeringPLower),
+            ("luteFrenchAppoggiaturaBelow", Self::LuteFrenchAppoggiaturaBelow),
+            ("ornamentShortObliqueLineBeforeNote", Self::OrnamentShortObliqueLineBeforeNote),
+            ("indianDrumClef", Self::IndianDrumClef),
+            ("beamAccelRit8", Self::BeamAccelRit8),
+            ("arrowWhiteDownLeft", Self::ArrowWhiteDownLeft),
+            ("accidentalKomaSharp", Self::AccidentalKomaSharp),
+            ("accidentalFiveQuarterTonesFlatArrowDown", Self::AccidentalFiveQuarterTonesFlatArrowDown),
+            ("mensuralNoteheadSemiminimaWhite", Self::MensuralNoteheadSemiminimaWhite),
+            ("handbellsSwingUp", Self::HandbellsSwingUp),
+            ("ornamentShakeMuffat1", Self::OrnamentShakeMuffat1),
+            ("pictWoodBlock", Self::PictWoodBlock),
+            ("ornamentPrecompCadence", Self::OrnamentPrecompCadence),
+            ("barlineFinal", Self::BarlineFinal),
+            ("metNote512thDown", Self::MetNote512thDown),
+            ("guitarShake", Self::GuitarShake),
+            ("textCont16thBeamLongStem", Self::TextCont16thBeamLongStem),
+            ("pictWoundHardLeft", Self::PictWoundHardLeft),
+            ("fermataShortAbove", Self::FermataShortAbove),
+            ("kahnShuffle", Self::KahnShuffle),
+            ("noteLaBlack", Self::NoteLaBlack),
+            ("harpPedalRaised", Self::HarpPedalRaised),
+            ("conductorLeftBeat", Self::ConductorLeftBeat),
+            ("metNote8thDown", Self::MetNote8thDown),
+            ("guitarString1", Self::GuitarString1),
+            ("mensuralCombStemUpFlagExtended", Self::MensuralCombStemUpFlagExtended),
+            ("accSagittalSharp55CUp", Self::AccSagittalSharp55CUp),
+            ("handbellsMartellato", Self::HandbellsMartellato),
+            ("noteASharpHalf", Self::NoteASharpHalf),
+            ("gClef8vbCClef", Self::GClef8VbCClef),
+            ("dynamicHairpinBracketRight", Self::DynamicHairpinBracketRight),
+            ("figbass4", Self::Figbass4),
+            ("noteheadClusterQuarterMiddle", Self::NoteheadClusterQuarterMiddle),
+            ("luteDuration32nd", Self::LuteDuration32nd),
+            ("ornamentPrecompMordentUpperPrefix", Self::OrnamentPrecompMordentUpperPrefix),
+            ("accidentalCombiningLower29LimitComma", Self::AccidentalCombiningLower29LimitComma),
+            ("chantOriscusAscending", Self::ChantOriscusAscending),
+            ("noteBFlatBlack", Self::NoteBFlatBlack),
+            ("controlEndBeam", Self::ControlEndBeam),
+            ("dynamicCombinedSeparatorSlash", Self::DynamicCombinedSeparatorSlash),
+            ("accSagittalSharp23SDown", Self::AccSagittalSharp23SDown),
+            ("wiggleSquareWaveNarrow", Self::WiggleSquareWaveNarrow),
+            ("accSagittalSharp49MUp", Self::AccSagittalSharp49MUp),
+            ("organGermanFisUpper", Self::OrganGermanFisUpper),
+            ("noteheadRectangularClusterWhiteBottom", Self::NoteheadRectangularClusterWhiteBottom),
+            ("octaveParensLeft", Self::OctaveParensLeft),
+            ("pictBeaterMediumBassDrumDown", Self::PictBeaterMediumBassDrumDown),
+            ("lyricsElisionNarrow", Self::LyricsElisionNarrow),
+            ("noteheadDiamondClusterBlackTop", Self::NoteheadDiamondClusterBlackTop),
+            ("pictWindChimesGlass", Self::PictWindChimesGlass),
+            ("staff3LinesWide", Self::Staff3LinesWide),
+            ("scaleDegree9", Self::ScaleDegree9),
+            ("fClefArrowDown", Self::FClefArrowDown),
+            ("noteAHalf", Self::NoteAHalf),
+            ("medRenNaturalWithCross", Self::MedRenNaturalWithCross),
+            ("mensuralObliqueAsc4thBlack", Self::MensuralObliqueAsc4thBlack),
+            ("luteFrench8thCourse", Self::LuteFrench8thCourse),
+            ("accidentalTripleFlat", Self::AccidentalTripleFlat),
+            ("noteheadClusterRoundWhite", Self::NoteheadClusterRoundWhite),
+            ("stringsJeteAbove", Self::StringsJeteAbove),
+            ("analyticsInversion1", Self::AnalyticsInversion1),
+            ("fingering9", Self::Fingering9),
+            ("noteheadDiamondOpen", Self::NoteheadDiamondOpen),
+            ("organGerman2OctaveUp", Self::OrganGerman2OctaveUp),
+            ("functionTUpper", Self::FunctionTUpper),
+            ("pictClaves", Self::PictClaves),
+            ("noteEHalf", Self::NoteEHalf),
+            ("noteFWhole", Self::NoteFWhole),
+            ("luteDurationDoubleWhole", Self::LuteDurationDoubleWhole),
+            ("conductorUnconducted", Self::ConductorUnconducted),
+            ("timeSig7Reversed", Self::TimeSig7Reversed),
+            ("fingeringXLower", Self::FingeringXLower),
+            ("elecAudioStereo", Self::ElecAudioStereo),
+            ("csymAccidentalTripleSharp", Self::CsymAccidentalTripleSharp),
+            ("accdnRH4RanksTenor", Self::AccdnRh4RanksTenor),
+            ("textTie", Self::TextTie),
+            ("noteShapeTriangleLeftBlack", Self::NoteShapeTriangleLeftBlack),
+            ("keyboardLeftPedalPictogram", Self::KeyboardLeftPedalPictogram),
+            ("noteHHalf", Self::NoteHHalf),
+            ("accdnRH3RanksImitationMusette", Self::AccdnRh3RanksImitationMusette),
+            ("fingeringSubstitutionDash", Self::FingeringSubstitutionDash),
+            ("smnHistorySharp", Self::SmnHistorySharp),
+            ("arrowheadBlackUp", Self::ArrowheadBlackUp),
+            ("dynamicFFFFFF", Self::DynamicFfffff),
+            ("accSagittalFlat5CDown", Self::AccSagittalFlat5CDown),
+            ("restHBar", Self::RestHBar),
+            ("pictWoundSoftDown", Self::PictWoundSoftDown),
+            ("guitarVolumeSwell", Self::GuitarVolumeSwell),
+            ("smnHistoryDoubleSharp", Self::SmnHistoryDoubleSharp),
+            ("accSagittal11v49CommaDown", Self::AccSagittal11V49CommaDown),
+            ("arrowheadBlackUpLeft", Self::ArrowheadBlackUpLeft),
+            ("wiggleVibratoSmallestFasterStill", Self::WiggleVibratoSmallestFasterStill),
+            ("accidentalWyschnegradsky3TwelfthsFlat", Self::AccidentalWyschnegradsky3twelfthsFlat),
+            ("pictVib", Self::PictVib),
+            ("guitarString11", Self::GuitarString11),
+            ("functionDUpper", Self::FunctionDUpper),
+            ("noteTiWhole", Self::NoteTiWhole),
+            ("accidentalNaturalRaisedStockhausen", Self::AccidentalNaturalRaisedStockhausen),
+            ("elecDataIn", Self::ElecDataIn),
+            ("flagInternalUp", Self::FlagInternalUp),
+            ("luteFrenchFretE", Self::LuteFrenchFretE),
+            ("luteItalianFret9", Self::LuteItalianFret9),
+            ("pictXylTrough", Self::PictXylTrough),
+            ("arrowheadOpenUp", Self::ArrowheadOpenUp),
+            ("harpTuningKeyHandle", Self::HarpTuningKeyHandle),
+            ("pictBeaterWoodTimpaniUp", Self::PictBeaterWoodTimpaniUp),
+            ("accidentalLowerOneUndecimalQuartertone", Self::AccidentalLowerOneUndecimalQuartertone),
+            ("organGermanDisLower", Self::OrganGermanDisLower),
+            ("luteFrenchFretD", Self::LuteFrenchFretD),
+            ("keyboardPedalUpNotch", Self::KeyboardPedalUpNotch),
+            ("barlineHeavyHeavy", Self::BarlineHeavyHeavy),
+            ("gClefLigatedNumberAbove", Self::GClefLigatedNumberAbove),
+            ("accidentalQuarterToneFlat4", Self::AccidentalQuarterToneFlat4),
+            ("kodalyHandDo", Self::KodalyHandDo),
+            ("pictBeaterMetalBassDrumDown", Self::PictBeaterMetalBassDrumDown),
+            ("wiggleGlissandoGroup3", Self::WiggleGlissandoGroup3),
+            ("mensuralProportion7", Self::MensuralProportion7),
+            ("staff6LinesNarrow", Self::Staff6LinesNarrow),
+            ("stringsScrapeCircularCounterclockwise", Self::StringsScrapeCircularCounterclockwise),
+            ("pictNormalPosition", Self::PictNormalPosition),
+            ("accidentalDoubleSharpThreeArrowsDown", Self::AccidentalDoubleSharpThreeArrowsDown),
+            ("accidentalUpsAndDownsMore", Self::AccidentalUpsAndDownsMore),
+            ("arrowBlackUpLeft", Self::ArrowBlackUpLeft),
+            ("flag32ndDown", Self::Flag32ndDown),
+            ("accidentalBakiyeFlat", Self::AccidentalBakiyeFlat),
+            ("organGermanCLower", Self::OrganGermanCLower),
+            ("noteGFlatWhole", Self::NoteGFlatWhole),
+            ("wiggleArpeggiatoUpArrow", Self::WiggleArpeggiatoUpArrow),
+            ("pictBeaterBow", Self::PictBeaterBow),
+            ("accidentalJohnstonMinus", Self::AccidentalJohnstonMinus),
+            ("organGerman2Minimae", Self::OrganGerman2Minimae),
+            ("wiggleTrillFaster", Self::WiggleTrillFaster),
+            ("staffPosRaise5", Self::StaffPosRaise5),
+            ("restHBarLeft", Self::RestHBarLeft),
+            ("accdnRH3RanksTremoloLower8ve", Self::AccdnRh3RanksTremoloLower8Ve),
+            ("accidentalKucukMucennebFlat", Self::AccidentalKucukMucennebFlat),
+            ("accdnLH2Ranks8Round", Self::AccdnLh2Ranks8Round),
+            ("accidentalDoubleFlatEqualTempered", Self::AccidentalDoubleFlatEqualTempered),
+            ("csymAccidentalDoubleSharp", Self::CsymAccidentalDoubleSharp),
+            ("noteheadLargeArrowDownBlack", Self::NoteheadLargeArrowDownBlack),
+            ("kievanCClef", Self::KievanCClef),
+            ("scaleDegree1", Self::ScaleDegree1),
+            ("figbass9Raised", Self::Figbass9Raised),
+            ("pictRightHandSquare", Self::PictRightHandSquare),
+            ("pictLogDrum", Self::PictLogDrum),
+            ("csymAccidentalFlat", Self::CsymAccidentalFlat),
+            ("mensuralRestFusa", Self::MensuralRestFusa),
+            ("handbellsDamp3", Self::HandbellsDamp3),
+            ("ornamentBottomRightConvexStroke", Self::OrnamentBottomRightConvexStroke),
+            ("csymAccidentalSharp", Self::CsymAccidentalSharp),
+            ("wiggleVibratoStart", Self::WiggleVibratoStart),
+            ("accSagittalFlat25SDown", Self::AccSagittalFlat25SDown),
+            ("chantEntryLineAsc6th", Self::ChantEntryLineAsc6th),
+            ("noteLaWhole", Self::NoteLaWhole),
+            ("accidentalFlatRepeatedSpaceStockhausen", Self::AccidentalFlatRepeatedSpaceStockhausen),
+            ("kodalyHandMi", Self::KodalyHandMi),
+            ("pictVibraslap", Self::PictVibraslap),
+            ("codaSquare", Self::CodaSquare),
+            ("timeSigCommonTurned", Self::TimeSigCommonTurned),
+            ("accdnRicochetStem5", Self::AccdnRicochetStem5),
+            ("pictSteelDrums", Self::PictSteelDrums),
+            ("accSagittal9TinasDown", Self::AccSagittal9tinasDown),
+            ("noteheadClusterQuarterTop", Self::NoteheadClusterQuarterTop),
+            ("pictBeaterHardTimpaniDown", Self::PictBeaterHardTimpaniDown),
+            ("mensuralBlackMinimaVoid", Self::MensuralBlackMinimaVoid),
+            ("keyboardPedalUp", Self::KeyboardPedalUp),
+            ("arrowWhiteDown", Self::ArrowWhiteDown),
+            ("functionNUpperSuperscript", Self::FunctionNUpperSuperscript),
+            ("pictBeaterGuiroScraper", Self::PictBeaterGuiroScraper),
+            ("dynamicSforzatoFF", Self::DynamicSforzatoFf),
+            ("guitarVibratoBarDip", Self::GuitarVibratoBarDip),
+            ("arrowOpenRight", Self::ArrowOpenRight),
+            ("keyboardPedalToe1", Self::KeyboardPedalToe1),
+            ("pictBeaterHardGlockenspielDown", Self::PictBeaterHardGlockenspielDown),
+            ("staffPosRaise1", Self::StaffPosRaise1),
+            ("accidentalOneQuarterToneFlatFerneyhough", Self::AccidentalOneQuarterToneFlatFerneyhough),
+            ("ornamentQuilisma", Self::OrnamentQuilisma),
+            ("metNote512thUp", Self::MetNote512thUp),
+            ("noteTeWhole", Self::NoteTeWhole),
+            ("stringsHarmonic", Self::StringsHarmonic),
+            ("noteShapeTriangleRightBlack", Self::NoteShapeTriangleRightBlack),
+            ("noteMiBlack", Self::NoteMiBlack),
+            ("noteheadDiamondBlackWide", Self::NoteheadDiamondBlackWide),
+            ("noteheadTriangleUpWhole", Self::NoteheadTriangleUpWhole),
+            ("textCont8thBeamShortStem", Self::TextCont8thBeamShortStem),
+            ("noteFBlack", Self::NoteFBlack),
+            ("noteheadRectangularClusterWhiteMiddle", Self::NoteheadRectangularClusterWhiteMiddle),
+            ("wiggleVibratoSmallestFaster", Self::WiggleVibratoSmallestFaster),
+            ("pictCrashCymbals", Self::PictCrashCymbals),
+            ("daseianExcellentes2", Self::DaseianExcellentes2),
+            ("noteTiHalf", Self::NoteTiHalf),
+            ("wiggleVibratoMediumFastest", Self::WiggleVibratoMediumFastest),
+            ("windClosedHole", Self::WindClosedHole),
+            ("pictBeaterHardGlockenspielLeft", Self::PictBeaterHardGlockenspielLeft),
+            ("functionPUpper", Self::FunctionPUpper),
+            ("stringsOverpressureDownBow", Self::StringsOverpressureDownBow),
+            ("kievanNoteHalfStaffSpace", Self::KievanNoteHalfStaffSpace),
+            ("kahnHop", Self::KahnHop),
+            ("brassPlop", Self::BrassPlop),
+            ("dynamicFFFF", Self::DynamicFfff),
+            ("chantEntryLineAsc3rd", Self::ChantEntryLineAsc3Rd),
+            ("articStressAbove", Self::ArticStressAbove),
+            ("accSagittalFlat143CDown", Self::AccSagittalFlat143CDown),
+            ("noteReBlack", Self::NoteReBlack),
+            ("pictGumSoftLeft", Self::PictGumSoftLeft),
+            ("guitarBarreHalf", Self::GuitarBarreHalf),
+            ("noteheadDiamondClusterWhite3rd", Self::NoteheadDiamondClusterWhite3Rd),
+            ("noteShapeKeystoneDoubleWhole", Self::NoteShapeKeystoneDoubleWhole),
+            ("luteGermanYLower", Self::LuteGermanYLower),
+            ("accidentalDoubleSharpOneArrowUp", Self::AccidentalDoubleSharpOneArrowUp),
+            ("windThreeQuartersClosedHole", Self::WindThreeQuartersClosedHole),
+            ("timeSigFractionHalf", Self::TimeSigFractionHalf),
+            ("organGermanMinima", Self::OrganGermanMinima),
+            ("pictTriangle", Self::PictTriangle),
+            ("ornamentMordent", Self::OrnamentMordent),
+            ("noteheadCowellFifthNoteSeriesBlack", Self::NoteheadCowellFifthNoteSeriesBlack),
+            ("noteMiHalf", Self::NoteMiHalf),
+            ("pictThundersheet", Self::PictThundersheet),
+            ("mensuralObliqueDesc3rdBlack", Self::MensuralObliqueDesc3RdBlack),
+            ("chantPodatusUpper", Self::ChantPodatusUpper),
+            ("beamAccelRit6", Self::BeamAccelRit6),
+            ("vocalSprechgesang", Self::VocalSprechgesang),
+            ("accdnLH3Ranks2Plus8Square", Self::AccdnLh3Ranks2Plus8Square),
+            ("noteFiWhole", Self::NoteFiWhole),
+            ("rightRepeatSmall", Self::RightRepeatSmall),
+            ("accdnRicochet3", Self::AccdnRicochet3),
+            ("noteheadCircleX", Self::NoteheadCircleX),
+            ("noteShapeTriangleRightDoubleWhole", Self::NoteShapeTriangleRightDoubleWhole),
+            ("medRenLiquescentDescCMN", Self::MedRenLiquescentDescCmn),
+            ("noteheadXOrnateEllipse", Self::NoteheadXOrnateEllipse),
+            ("ottavaBassaVb", Self::OttavaBassaVb),
+            ("accidentalKucukMucennebSharp", Self::AccidentalKucukMucennebSharp),
+            ("functionParensRight", Self::FunctionParensRight),
+            ("noteheadSquareBlackLarge", Self::NoteheadSquareBlackLarge),
+            ("noteheadParenthesisLeft", Self::NoteheadParenthesisLeft),
+            ("accdnRH4RanksSoprano", Self::AccdnRh4RanksSoprano),
+            ("noteGWhole", Self::NoteGWhole),
+            ("ornamentHighRightConcaveStroke", Self::OrnamentHighRightConcaveStroke),
+            ("ornamentLowLeftConcaveStroke", Self::OrnamentLowLeftConcaveStroke),
+            ("noteheadSlashWhiteHalf", Self::NoteheadSlashWhiteHalf),
+            ("wiggleRandom2", Self::WiggleRandom2),
+            ("medRenLiquescenceCMN", Self::MedRenLiquescenceCmn),
+            ("dynamicRinforzando", Self::DynamicRinforzando),
+            ("caesura", Self::Caesura),
+            ("stemBowOnBridge", Self::StemBowOnBridge),
+            ("guitarOpenPedal", Self::GuitarOpenPedal),
+            ("wiggleCircularConstant", Self::WiggleCircularConstant),
+            ("luteGermanFUpper", Self::LuteGermanFUpper),
+            ("mensuralObliqueAsc5thVoid", Self::MensuralObliqueAsc5thVoid),
+            ("accidentalParensRight", Self::AccidentalParensRight),
+            ("smnFlat", Self::SmnFlat),
+            ("luteGermanKLower", Self::LuteGermanKLower),
+            ("fClefChange", Self::FClefChange),
+            ("pictBeaterSuperballRight", Self::PictBeaterSuperballRight),
+            ("noteheadLargeArrowUpHalf", Self::NoteheadLargeArrowUpHalf),
+            ("timeSig3", Self::TimeSig3),
+            ("figbassSharp", Self::FigbassSharp),
+            ("wiggleVibratoLargeFasterStill", Self::WiggleVibratoLargeFasterStill),
+            ("staff4LinesNarrow", Self::Staff4LinesNarrow),
+            ("noteShapeRoundWhite", Self::NoteShapeRoundWhite),
+            ("accSagittalShaftDown", Self::AccSagittalShaftDown),
+            ("pictTurnLeftStem", Self::PictTurnLeftStem),
+            ("repeat1Bar", Self::Repeat1Bar),
+            ("accdnRicochet2", Self::AccdnRicochet2),
+            ("pictWoundSoftLeft", Self::PictWoundSoftLeft),
+            ("pluckedBuzzPizzicato", Self::PluckedBuzzPizzicato),
+            ("articAccentAbove", Self::ArticAccentAbove),
+            ("accidentalNaturalOneArrowUp", Self::AccidentalNaturalOneArrowUp),
+            ("dynamicFortePiano", Self::DynamicFortePiano),
+            ("noteESharpBlack", Self::NoteESharpBlack),
+            ("noteDoubleWholeSquare", Self::NoteDoubleWholeSquare),
+            ("elecPause", Self::ElecPause),
+            ("accidentalSharpRaisedStockhausen", Self::AccidentalSharpRaisedStockhausen),
+            ("noteheadCowellFifthNoteSeriesWhole", Self::NoteheadCowellFifthNoteSeriesWhole),
+            ("stringsUpBowAwayFromBody", Self::StringsUpBowAwayFromBody),
+            ("noteheadPlusDoubleWhole", Self::NoteheadPlusDoubleWhole),
+            ("staff4LinesWide", Self::Staff4LinesWide),
+            ("chantCaesura", Self::ChantCaesura),
+            ("metNote256thUp", Self::MetNote256thUp),
+            ("elecVideoIn", Self::ElecVideoIn),
+            ("noteheadRoundWhite", Self::NoteheadRoundWhite),
+            ("flag512thUp", Self::Flag512thUp),
+            ("timeSigComma", Self::TimeSigComma),
+            ("pictBeaterBrassMalletsLeft", Self::PictBeaterBrassMalletsLeft),
+            ("wiggleWavy", Self::WiggleWavy),
+            ("keyboardPedalD", Self::KeyboardPedalD),
+            ("accSagittalDoubleSharp7CDown", Self::AccSagittalDoubleSharp7CDown),
+            ("keyboardRightPedalPictogram", Self::KeyboardRightPedalPictogram),
+            ("pictBeaterBox", Self::PictBeaterBox),
+            ("pictDamp1", Self::PictDamp1),
+            ("pictBeaterWoodTimpaniRight", Self::PictBeaterWoodTimpaniRight),
+            ("ornamentHookAfterNote", Self::OrnamentHookAfterNote),
+            ("articStaccatissimoStrokeAbove", Self::ArticStaccatissimoStrokeAbove),
+            ("accSagittalSharp49LUp", Self::AccSagittalSharp49LUp),
+            ("dynamicDiminuendoHairpin", Self::DynamicDiminuendoHairpin),
+            ("accidentalDoubleSharpTwoArrowsUp", Self::AccidentalDoubleSharpTwoArrowsUp),
+            ("accidentalDoubleFlat", Self::AccidentalDoubleFlat),
+            ("accSagittalSharp19CUp", Self::AccSagittalSharp19CUp),
+            ("wiggleVibratoSmallSlowest", Self::WiggleVibratoSmallSlowest),
+            ("guitarString2", Self::GuitarString2),
+            ("note1024thUp", Self::Note1024thUp),
+            ("luteFrenchFretG", Self::LuteFrenchFretG),
+            ("mensuralProlation8", Self::MensuralProlation8),
+            ("daCapo", Self::DaCapo),
+            ("pictDamp3", Self::PictDamp3),
+            ("kahnDoubleWing", Self::KahnDoubleWing),
+            ("elecSkipBackwards", Self::ElecSkipBackwards),
+            ("noteShapeMoonBlack", Self::NoteShapeMoonBlack),
+            ("accidentalSharpEqualTempered", Self::AccidentalSharpEqualTempered),
+            ("clef8", Self::Clef8),
+            ("accSagittalDoubleFlat143CUp", Self::AccSagittalDoubleFlat143CUp),
+            ("pictBeaterSnareSticksDown", Self::PictBeaterSnareSticksDown),
+            ("noteRiHalf", Self::NoteRiHalf),
+            ("accSagittalSharp5CDown", Self::AccSagittalSharp5CDown),
+            ("timeSig9Turned", Self::TimeSig9turned),
+            ("lyricsHyphenBaseline", Self::LyricsHyphenBaseline),
+            ("keyboardPedalHeelToe", Self::KeyboardPedalHeelToe),
+            ("stemDamp", Self::StemDamp),
+            ("ornamentRightFacingHook", Self::OrnamentRightFacingHook),
+            ("mensuralNoteheadLongaVoid", Self::MensuralNoteheadLongaVoid),
+            ("accSagittalFlat49LDown", Self::AccSagittalFlat49LDown),
+            ("noteheadRoundWhiteLarge", Self::NoteheadRoundWhiteLarge),
+            ("accidentalCombiningLower19Schisma", Self::AccidentalCombiningLower19Schisma),
+            ("noteheadSquareBlack", Self::NoteheadSquareBlack),
+            ("wiggleRandom1", Self::WiggleRandom1),
+            ("luteItalianTimeTriple", Self::LuteItalianTimeTriple),
+            ("luteItalianTempoNeitherFastNorSlow", Self::LuteItalianTempoNeitherFastNorSlow),
+            ("accSagittal7v19CommaUp", Self::AccSagittal7V19CommaUp),
+            ("pictMar", Self::PictMar),
+            ("textHeadlessBlackNoteFrac16thLongStem", Self::TextHeadlessBlackNoteFrac16thLongStem),
+            ("pictBeaterTriangleUp", Self::PictBeaterTriangleUp),
+            ("noteheadMoonWhite", Self::NoteheadMoonWhite),
+            ("mensuralProlationCombiningThreeDots", Self::MensuralProlationCombiningThreeDots),
+            ("analyticsNebenstimme", Self::AnalyticsNebenstimme),
+            ("barlineReverseFinal", Self::BarlineReverseFinal),
+            ("mensuralCombStemDownFlagFlared", Self::MensuralCombStemDownFlagFlared),
+            ("fingeringILower", Self::FingeringILower),
+            ("kahnKneeOutward", Self::KahnKneeOutward),
+            ("dynamicPPP", Self::DynamicPpp),
+            ("wiggleVibrato", Self::WiggleVibrato),
+            ("medRenFlatHardB", Self::MedRenFlatHardB),
+            ("accidentalFilledReversedFlatAndFlatArrowDown", Self::AccidentalFilledReversedFlatAndFlatArrowDown),
+            ("figbass0", Self::Figbass0),
+            ("accSagittalFlat7v19CUp", Self::AccSagittalFlat7V19CUp),
+            ("beamAccelRit1", Self::BeamAccelRit1),
+            ("mensuralObliqueDesc3rdBlackVoid", Self::MensuralObliqueDesc3RdBlackVoid),
+            ("noteheadDiamondClusterBlack2nd", Self::NoteheadDiamondClusterBlack2nd),
+            ("pictWoundHardUp", Self::PictWoundHardUp),
+            ("stringsBowBehindBridgeOneString", Self::StringsBowBehindBridgeOneString),
+            ("brassHarmonMuteStemHalfLeft", Self::BrassHarmonMuteStemHalfLeft),
+            ("noteQuarterDown", Self::NoteQuarterDown),
+            ("accSagittal1TinaUp", Self::AccSagittal1tinaUp),
+            ("luteGermanGUpper", Self::LuteGermanGUpper),
+            ("csymHalfDiminished", Self::CsymHalfDiminished),
+            ("fretboard3String", Self::Fretboard3String),
+            ("elecMIDIIn", Self::ElecMidiIn),
+            ("pictTimpani", Self::PictTimpani),
+            ("accidentalFilledReversedFlatArrowDown", Self::AccidentalFilledReversedFlatArrowDown),
+            ("repeatLeft", Self::RepeatLeft),
+            ("brassFlip", Self::BrassFlip),
+            ("fingeringSubstitutionBelow", Self::FingeringSubstitutionBelow),
+            ("mensuralObliqueDesc5thVoid", Self::MensuralObliqueDesc5thVoid),
+            ("accSagittal3TinasDown", Self::AccSagittal3tinasDown),
+            ("accSagittalSharp11v19MUp", Self::AccSagittalSharp11V19MUp),
+            ("noteTiBlack", Self::NoteTiBlack),
+            ("barlineShort", Self::BarlineShort),
+            ("analyticsTheme", Self::AnalyticsTheme),
+            ("accSagittal5v13MediumDiesisUp", Self::AccSagittal5V13MediumDiesisUp),
+            ("timeSig2Turned", Self::TimeSig2turned),
+            ("keyboardPluckInside", Self::KeyboardPluckInside),
+            ("figbassCombiningLowering", Self::FigbassCombiningLowering),
+            ("luteFrenchFretL", Self::LuteFrenchFretL),
+            ("accSagittal8TinasDown", Self::AccSagittal8tinasDown),
+            ("organGerman3Semifusae", Self::OrganGerman3Semifusae),
+            ("accidentalNaturalEqualTempered", Self::AccidentalNaturalEqualTempered),
+            ("pictBeaterHammerWoodDown", Self::PictBeaterHammerWoodDown),
+            ("tuplet2", Self::Tuplet2),
+            ("pictGlassHarp", Self::PictGlassHarp),
+            ("noteheadRoundWhiteDoubleSlashed", Self::NoteheadRoundWhiteDoubleSlashed),
+            ("accidentalQuarterToneSharpArabic", Self::AccidentalQuarterToneSharpArabic),
+            ("accSagittalDoubleSharp", Self::AccSagittalDoubleSharp),
+            ("elecEject", Self::ElecEject),
+            ("mensuralObliqueAsc5thWhite", Self::MensuralObliqueAsc5thWhite),
+            ("tremolo2", Self::Tremolo2),
+            ("pluckedFingernailFlick", Self::PluckedFingernailFlick),
+            ("pictTenorDrum", Self::PictTenorDrum),
+            ("luteGermanTLower", Self::LuteGermanTLower),
+            ("ornamentTurnUpS", Self::OrnamentTurnUpS),
+            ("organGermanAugmentationDot", Self::OrganGermanAugmentationDot),
+            ("accidental4CommaFlat", Self::Accidental4CommaFlat),
+            ("functionTwo", Self::FunctionTwo),
+            ("dynamicCrescendoHairpin", Self::DynamicCrescendoHairpin),
+            ("dynamicHairpinParenthesisRight", Self::DynamicHairpinParenthesisRight),
+            ("csymMinor", Self::CsymMinor),
+            ("accSagittalSharp17CUp", Self::AccSagittalSharp17CUp),
+            ("dynamicHairpinBracketLeft", Self::DynamicHairpinBracketLeft),
+            ("accidentalEnharmonicTilde", Self::AccidentalEnharmonicTilde),
+            ("kievanAccidentalFlat", Self::KievanAccidentalFlat),
+            ("pictGumMediumUp", Self::PictGumMediumUp),
+            ("noteFFlatHalf", Self::NoteFFlatHalf),
+            ("smnSharpWhiteDown", Self::SmnSharpWhiteDown),
+            ("articStaccatissimoStrokeBelow", Self::ArticStaccatissimoStrokeBelow),
+            ("guitarVibratoBarScoop", Self::GuitarVibratoBarScoop),
+            ("pictDrumStick", Self::PictDrumStick),
+            ("ornamentHighLeftConcaveStroke", Self::OrnamentHighLeftConcaveStroke),
+            ("articTenutoAbove", Self::ArticTenutoAbove),
+            ("elecMonitor", Self::ElecMonitor),
+            ("wiggleVibratoLargestSlowest", Self::WiggleVibratoLargestSlowest),
+            ("restDoubleWhole", Self::RestDoubleWhole),
+            ("kahnDoubleSnap", Self::KahnDoubleSnap),
+            ("noteHSharpHalf", Self::NoteHSharpHalf),
+            ("accSagittalDoubleFlat25SUp", Self::AccSagittalDoubleFlat25SUp),
+            ("accidentalQuarterToneSharpWiggle", Self::AccidentalQuarterToneSharpWiggle),
+            ("keyboardPedalHalf", Self::KeyboardPedalHalf),
+            ("noteheadDiamondWhite", Self::NoteheadDiamondWhite),
+            ("textHeadlessBlackNoteFrac8thLongStem", Self::TextHeadlessBlackNoteFrac8thLongStem),
+            ("keyboardPedalHeel2", Self::KeyboardPedalHeel2),
+            ("ventiduesimaBassa", Self::VentiduesimaBassa),
+            ("accidentalWyschnegradsky10TwelfthsSharp", Self::AccidentalWyschnegradsky10twelfthsSharp),
+            ("medRenPunctumCMN", Self::MedRenPunctumCmn),
+            ("wiggleVibratoMediumFasterStill", Self::WiggleVibratoMediumFasterStill),
+            ("kahnRightToeStrike", Self::KahnRightToeStrike),
+            ("elecAudioOut", Self::ElecAudioOut),
+            ("pictWoundSoftRight", Self::PictWoundSoftRight),
+            ("gClef8vb", Self::GClef8Vb),
+            ("accidentalDoubleFlatThreeArrowsDown", Self::AccidentalDoubleFlatThreeArrowsDown),
+            ("windMultiphonicsBlackStem", Self::WindMultiphonicsBlackStem),
+            ("ornamentTopLeftConcaveStroke", Self::OrnamentTopLeftConcaveStroke),
+            ("ornamentPrecompTrillSuffixDandrieu", Self::OrnamentPrecompTrillSuffixDandrieu),
+            ("chantDivisioMaior", Self::ChantDivisioMaior),
+            ("noteTeBlack", Self::NoteTeBlack),
+            ("organGermanSemifusa", Self::OrganGermanSemifusa),
+            ("mensuralRestSemiminima", Self::MensuralRestSemiminima),
+            ("mensuralWhiteLonga", Self::MensuralWhiteLonga),
+            ("handbellsSwingDown", Self::HandbellsSwingDown),
+            ("noteShapeTriangleUpWhite", Self::NoteShapeTriangleUpWhite),
+            ("elecAudioChannelsThreeSurround", Self::ElecAudioChannelsThreeSurround),
+            ("noteDFlatBlack", Self::NoteDFlatBlack),
+            ("mensuralCombStemDownFlagExtended", Self::MensuralCombStemDownFlagExtended),
+            ("accidentalDoubleFlatReversed", Self::AccidentalDoubleFlatReversed),
+            ("accidentalThreeQuarterTonesSharpArrowDown", Self::AccidentalThreeQuarterTonesSharpArrowDown),
+            ("note512thUp", Self::Note512thUp),
+            ("timeSig9Reversed", Self::TimeSig9Reversed),
+            ("noteAWhole", Self::NoteAWhole),
+            ("daseianSuperiores1", Self::DaseianSuperiores1),
+            ("pictRim3", Self::PictRim3),
+            ("fingering3Italic", Self::Fingering3Italic),
+            ("ornamentPrecompCadenceUpperPrefix", Self::OrnamentPrecompCadenceUpperPrefix),
+            ("wiggleSawtoothNarrow", Self::WiggleSawtoothNarrow),
+            ("accSagittalFlat19sUp", Self::AccSagittalFlat19SUp),
+            ("mensuralProportionProportioDupla1", Self::MensuralProportionProportioDupla1),
+            ("chantEntryLineAsc2nd", Self::ChantEntryLineAsc2nd),
+            ("staffPosLower4", Self::StaffPosLower4),
+            ("stringsOverpressurePossibileUpBow", Self::StringsOverpressurePossibileUpBow),
+            ("timeSigCombNumerator", Self::TimeSigCombNumerator),
+            ("noteFiHalf", Self::NoteFiHalf),
+            ("functionVLower", Self::FunctionVLower),
+            ("arrowheadWhiteUpRight", Self::ArrowheadWhiteUpRight),
+            ("accSagittal5v19CommaDown", Self::AccSagittal5V19CommaDown),
+            ("noteheadCowellSeventhNoteSeriesWhole", Self::NoteheadCowellSeventhNoteSeriesWhole),
+            ("fingeringMultipleNotes", Self::FingeringMultipleNotes),
+            ("kahnStamp", Self::KahnStamp),
+            ("wiggleVIbratoMediumSlower", Self::WiggleVIbratoMediumSlower),
+            ("pictDeadNoteStem", Self::PictDeadNoteStem),
+            ("noteheadCircleSlash", Self::NoteheadCircleSlash),
+            ("noteheadSlashWhiteDoubleWhole", Self::NoteheadSlashWhiteDoubleWhole),
+            ("tremoloDivisiDots2", Self::TremoloDivisiDots2),
+            ("accSagittalSharp35MUp", Self::AccSagittalSharp35MUp),
+            ("legerLineWide", Self::LegerLineWide),
+            ("stringsBowOnTailpiece", Self::StringsBowOnTailpiece),
+            ("pictBeaterMalletDown", Self::PictBeaterMalletDown),
+            ("accidentalSims4Down", Self::AccidentalSims4Down),
+            ("smnSharpDown", Self::SmnSharpDown),
+            ("luteItalianVibrato", Self::LuteItalianVibrato),
+            ("fingeringLeftParenthesisItalic", Self::FingeringLeftParenthesisItalic),
+            ("accidentalNaturalThreeArrowsUp", Self::AccidentalNaturalThreeArrowsUp),
+            ("handbellsSwing", Self::HandbellsSwing),
+            ("pictBeaterMediumXylophoneUp", Self::PictBeaterMediumXylophoneUp),
+            ("chantPunctumLineaCavum", Self::ChantPunctumLineaCavum),
+            ("timeSigParensRightSmall", Self::TimeSigParensRightSmall),
+            ("pictBeaterWoodXylophoneDown", Self::PictBeaterWoodXylophoneDown),
+            ("accSagittalDoubleSharp143CDown", Self::AccSagittalDoubleSharp143CDown),
+            ("arpeggiatoUp", Self::ArpeggiatoUp),
+            ("functionSSUpper", Self::FunctionSsUpper),
+            ("accidentalThreeQuarterTonesFlatTartini", Self::AccidentalThreeQuarterTonesFlatTartini),
+            ("organGermanBuxheimerMinimaRest", Self::OrganGermanBuxheimerMinimaRest),
+            ("mensuralBlackSemibrevis", Self::MensuralBlackSemibrevis),
+            ("organGermanCisUpper", Self::OrganGermanCisUpper),
+            ("accidentalWilsonMinus", Self::AccidentalWilsonMinus),
+            ("pictBeaterHardYarnLeft", Self::PictBeaterHardYarnLeft),
+            ("pictXylTenor", Self::PictXylTenor),
+            ("noteheadClusterWholeMiddle", Self::NoteheadClusterWholeMiddle),
+            ("mensuralColorationEndSquare", Self::MensuralColorationEndSquare),
+            ("functionIUpper", Self::FunctionIUpper),
+            ("accidentalThreeQuarterTonesSharpBusotti", Self::AccidentalThreeQuarterTonesSharpBusotti),
+            ("noteheadDiamondDoubleWhole", Self::NoteheadDiamondDoubleWhole),
+            ("conductorBeat2Simple", Self::ConductorBeat2Simple),
+            ("accidentalQuarterToneFlatVanBlankenburg", Self::AccidentalQuarterToneFlatVanBlankenburg),
+            ("note16thUp", Self::Note16thUp),
+            ("noteDSharpBlack", Self::NoteDSharpBlack),
+            ("accidentalThreeQuarterTonesSharpArrowUp", Self::AccidentalThreeQuarterTonesSharpArrowUp),
+            ("kahnClap", Self::KahnClap),
+            ("octaveSuperscriptA", Self::OctaveSuperscriptA),
+            ("noteheadClusterWholeTop", Self::NoteheadClusterWholeTop),
+            ("medRenQuilismaCMN", Self::MedRenQuilismaCmn),
+            ("arrowheadWhiteDownRight", Self::ArrowheadWhiteDownRight),
+            ("timeSig2", Self::TimeSig2),
+            ("staffPosLower3", Self::StaffPosLower3),
+            ("organGermanBuxheimerBrevis3", Self::OrganGermanBuxheimerBrevis3),
+            ("schaefferClef", Self::SchaefferClef),
+            ("elecDataOut", Self::ElecDataOut),
+            ("ornamentDoubleObliqueLinesBeforeNote", Self::OrnamentDoubleObliqueLinesBeforeNote),
+            ("figbassTripleFlat", Self::FigbassTripleFlat),
+            ("ornamentZigZagLineWithRightEnd", Self::OrnamentZigZagLineWithRightEnd),
+            ("gClefArrowDown", Self::GClefArrowDown),
+            ("kahnSlam", Self::KahnSlam),
+            ("organGerman4Semifusae", Self::OrganGerman4Semifusae),
+            ("timeSig8Reversed", Self::TimeSig8Reversed),
+            ("mensuralWhiteBrevis", Self::MensuralWhiteBrevis),
+            ("accSagittalDoubleSharp5v19CDown", Self::AccSagittalDoubleSharp5V19CDown),
+            ("ornamentShortObliqueLineAfterNote", Self::OrnamentShortObliqueLineAfterNote),
+            ("chantStaffNarrow", Self::ChantStaffNarrow),
+            ("pictBeaterMediumTimpaniDown", Self::PictBeaterMediumTimpaniDown),
+            ("accidentalWyschnegradsky4TwelfthsFlat", Self::AccidentalWyschnegradsky4twelfthsFlat),
+            ("dynamicHairpinParenthesisLeft", Self::DynamicHairpinParenthesisLeft),
+            ("kahnTap", Self::KahnTap),
+            ("accidentalSharp", Self::AccidentalSharp),
+            ("noteheadSquareBlackWhite", Self::NoteheadSquareBlackWhite),
+            ("note8thUp", Self::Note8thUp),
+            ("noteShapeTriangleRoundDoubleWhole", Self::NoteShapeTriangleRoundDoubleWhole),
+            ("ornamentTurnInverted", Self::OrnamentTurnInverted),
+            ("keyboardPlayWithRHEnd", Self::KeyboardPlayWithRhEnd),
+            ("mensuralProportionMinor", Self::MensuralProportionMinor),
+            ("noteDWhole", Self::NoteDWhole),
+            ("noteheadCircleXWhole", Self::NoteheadCircleXWhole),
+            ("pictBeaterWireBrushesDown", Self::PictBeaterWireBrushesDown),
+            ("pictBeaterMallet", Self::PictBeaterMallet),
+            ("fingeringRightParenthesisItalic", Self::FingeringRightParenthesisItalic),
+            ("vocalsSussurando", Self::VocalsSussurando),
+            ("rest16th", Self::Rest16th),
+            ("pictMetalPlateChimes", Self::PictMetalPlateChimes),
+            ("fingeringSeparatorMiddleDotWhite", Self::FingeringSeparatorMiddleDotWhite),
+            ("accSagittal5v7KleismaDown", Self::AccSagittal5V7KleismaDown),
+            ("luteItalianTremolo", Self::LuteItalianTremolo),
+            ("elecMIDIController0", Self::ElecMidiController0),
+            ("functionRLower", Self::FunctionRLower),
+            ("conductorBeat3Simple", Self::ConductorBeat3Simple),
+            ("articMarcatoStaccatoAbove", Self::ArticMarcatoStaccatoAbove),
+            ("ornamentPrecompSlideTrillSuffixMuffat", Self::OrnamentPrecompSlideTrillSuffixMuffat),
+            ("noteheadDiamondHalfOld", Self::NoteheadDiamondHalfOld),
+            ("pictBeaterSoftBassDrumDown", Self::PictBeaterSoftBassDrumDown),
+            ("textTupletBracketEndLongStem", Self::TextTupletBracketEndLongStem),
+            ("noteLeHalf", Self::NoteLeHalf),
+            ("noteheadTriangleRoundDownWhite", Self::NoteheadTriangleRoundDownWhite),
+            ("gClefChange", Self::GClefChange),
+            ("arrowheadOpenUpRight", Self::ArrowheadOpenUpRight),
+            ("flag1024thDown", Self::Flag1024thDown),
+            ("noteDBlack", Self::NoteDBlack),
+            ("kahnLeapHeelClick", Self::KahnLeapHeelClick),
+            ("stemMultiphonicsWhite", Self::StemMultiphonicsWhite),
+            ("harpSalzedoAeolianDescending", Self::HarpSalzedoAeolianDescending),
+            ("ornamentOriscus", Self::OrnamentOriscus),
+            ("organGerman5Minimae", Self::OrganGerman5Minimae),
+            ("textHeadlessBlackNoteFrac32ndLongStem", Self::TextHeadlessBlackNoteFrac32ndLongStem),
+            ("accSagittal5TinasDown", Self::AccSagittal5tinasDown),
+            ("mensuralFclef", Self::MensuralFclef),
+            ("repeatDot", Self::RepeatDot),
+            ("accidentalNatural", Self::AccidentalNatural),
+            ("arrowheadBlackDownLeft", Self::ArrowheadBlackDownLeft),
+            ("accSagittal55CommaUp", Self::AccSagittal55CommaUp),
+            ("pictBeaterHardTimpaniRight", Self::PictBeaterHardTimpaniRight),
+            ("systemDividerLong", Self::SystemDividerLong),
+            ("accidentalNaturalFlat", Self::AccidentalNaturalFlat),
+            ("accidentalOneThirdToneSharpFerneyhough", Self::AccidentalOneThirdToneSharpFerneyhough),
+            ("elecVolumeFader", Self::ElecVolumeFader),
+            ("pictBeaterSoftXylophoneUp", Self::PictBeaterSoftXylophoneUp),
+            ("pictSandpaperBlocks", Self::PictSandpaperBlocks),
+            ("luteGermanMUpper", Self::LuteGermanMUpper),
+            ("articAccentStaccatoAbove", Self::ArticAccentStaccatoAbove),
+            ("accidentalTripleSharp", Self::AccidentalTripleSharp),
+            ("accidentalWyschnegradsky7TwelfthsSharp", Self::AccidentalWyschnegradsky7twelfthsSharp),
+            ("organGermanBLower", Self::OrganGermanBLower),
+            ("octaveSuperscriptV", Self::OctaveSuperscriptV),
+            ("pictBeaterHammerMetalDown", Self::PictBeaterHammerMetalDown),
+            ("accSagittalSharp17kUp", Self::AccSagittalSharp17KUp),
+            ("pictGlassHarmonica", Self::PictGlassHarmonica),
+            ("noteShapeSquareBlack", Self::NoteShapeSquareBlack),
+            ("vocalFingerClickStockhausen", Self::VocalFingerClickStockhausen),
+            ("noteheadClusterSquareBlack", Self::NoteheadClusterSquareBlack),
+            ("textBlackNoteFrac16thShortStem", Self::TextBlackNoteFrac16thShortStem),
+            ("noteheadPlusBlack", Self::NoteheadPlusBlack),
+            ("elecAudioIn", Self::ElecAudioIn),
+            ("stemBuzzRoll", Self::StemBuzzRoll),
+            ("wiggleVIbratoLargestSlower", Self::WiggleVIbratoLargestSlower),
+            ("accSagittal3TinasUp", Self::AccSagittal3tinasUp),
+            ("note16thDown", Self::Note16thDown),
+            ("noteDSharpWhole", Self::NoteDSharpWhole),
+            ("noteheadLargeArrowDownWhole", Self::NoteheadLargeArrowDownWhole),
+            ("cClefSquare", Self::CClefSquare),
+            ("figbass1", Self::Figbass1),
+            ("mensuralProlationCombiningDot", Self::MensuralProlationCombiningDot),
+            ("pictChimes", Self::PictChimes),
+            ("mensuralObliqueDesc3rdVoid", Self::MensuralObliqueDesc3RdVoid),
+            ("accidentalRaiseOneSeptimalComma", Self::AccidentalRaiseOneSeptimalComma),
+            ("vocalMouthOpen", Self::VocalMouthOpen),
+            ("accSagittalSharp11v19LUp", Self::AccSagittalSharp11V19LUp),
+            ("chantStaff", Self::ChantStaff),
+            ("noteShapeMoonDoubleWhole", Self::NoteShapeMoonDoubleWhole),
+            ("brassDoitLong", Self::BrassDoitLong),
+            ("mensuralNoteheadMaximaVoid", Self::MensuralNoteheadMaximaVoid),
+            ("keyboardPedalHookEnd", Self::KeyboardPedalHookEnd),
+            ("timeSig6Reversed", Self::TimeSig6Reversed),
+            ("accSagittal35MediumDiesisUp", Self::AccSagittal35MediumDiesisUp),
+            ("ornamentLeftVerticalStrokeWithCross", Self::OrnamentLeftVerticalStrokeWithCross),
+            ("barlineHeavy", Self::BarlineHeavy),
+            ("accSagittal1MinaUp", Self::AccSagittal1MinaUp),
+            ("articTenutoBelow", Self::ArticTenutoBelow),
+            ("wiggleTrillSlowerStill", Self::WiggleTrillSlowerStill),
+            ("harpSalzedoDampBothHands", Self::HarpSalzedoDampBothHands),
+            ("noteRiBlack", Self::NoteRiBlack),
+            ("pictAgogo", Self::PictAgogo),
+            ("repeat2Bars", Self::Repeat2Bars),
+            ("barlineTick", Self::BarlineTick),
+            ("organGermanSemiminima", Self::OrganGermanSemiminima),
+            ("accSagittalDoubleFlat17kUp", Self::AccSagittalDoubleFlat17KUp),
+            ("harpSalzedoMetallicSounds", Self::HarpSalzedoMetallicSounds),
+            ("accdnLH2RanksFullMasterRound", Self::AccdnLh2RanksFullMasterRound),
+            ("metNoteQuarterDown", Self::MetNoteQuarterDown),
+            ("noteheadTriangleDownBlack", Self::NoteheadTriangleDownBlack),
+            ("accidentalWyschnegradsky3TwelfthsSharp", Self::AccidentalWyschnegradsky3twelfthsSharp),
+            ("tremoloDivisiDots3", Self::TremoloDivisiDots3),
+            ("mensuralColorationStartRound", Self::MensuralColorationStartRound),
+            ("organGermanOctaveDown", Self::OrganGermanOctaveDown),
+            ("kahnFlap", Self::KahnFlap),
+            ("splitBarDivider", Self::SplitBarDivider),
+            ("luteGermanDLower", Self::LuteGermanDLower),
+            ("accSagittalFlat5v11SDown", Self::AccSagittalFlat5V11SDown),
+            ("accSagittalDoubleSharp7v11kDown", Self::AccSagittalDoubleSharp7V11KDown),
+            ("pictCrotales", Self::PictCrotales),
+            ("accSagittalSharp5v7kDown", Self::AccSagittalSharp5V7KDown),
+            ("chantCustosStemUpPosLow", Self::ChantCustosStemUpPosLow),
+            ("daseianGraves1", Self::DaseianGraves1),
+            ("figbassDoubleSharp", Self::FigbassDoubleSharp),
+            ("gClef", Self::GClef),
+            ("brassLiftSmoothLong", Self::BrassLiftSmoothLong),
+            ("noteCWhole", Self::NoteCWhole),
+            ("noteLiHalf", Self::NoteLiHalf),
+            ("pictChainRattle", Self::PictChainRattle),
+            ("graceNoteSlashStemUp", Self::GraceNoteSlashStemUp),
+            ("metNote128thUp", Self::MetNote128thUp),
+            ("lyricsTextRepeat", Self::LyricsTextRepeat),
+            ("functionLUpper", Self::FunctionLUpper),
+            ("kahnFleaTap", Self::KahnFleaTap),
+            ("functionRing", Self::FunctionRing),
+            ("accSagittalSharp5v11SUp", Self::AccSagittalSharp5V11SUp),
+            ("gClef8va", Self::GClef8Va),
+            ("brassFallRoughLong", Self::BrassFallRoughLong),
+            ("mensuralCustosUp", Self::MensuralCustosUp),
+            ("luteFrenchFretB", Self::LuteFrenchFretB),
+            ("accidentalThreeQuarterTonesSharpArabic", Self::AccidentalThreeQuarterTonesSharpArabic),
+            ("stemMultiphonicsBlack", Self::StemMultiphonicsBlack),
+            ("accSagittal1MinaDown", Self::AccSagittal1MinaDown),
+            ("fermataShortHenzeBelow", Self::FermataShortHenzeBelow),
+            ("arrowheadOpenUpLeft", Self::ArrowheadOpenUpLeft),
+            ("noteSiHalf", Self::NoteSiHalf),
+            ("accSagittal5v13LargeDiesisDown", Self::AccSagittal5V13LargeDiesisDown),
+            ("harpSalzedoMetallicSoundsOneString", Self::HarpSalzedoMetallicSoundsOneString),
+            ("accidental3CommaFlat", Self::Accidental3CommaFlat),
+            ("accSagittal17CommaDown", Self::AccSagittal17CommaDown),
+            ("arrowOpenLeft", Self::ArrowOpenLeft),
+            ("timeSig5Reversed", Self::TimeSig5Reversed),
+            ("analyticsThemeRetrogradeInversion", Self::AnalyticsThemeRetrogradeInversion),
+            ("keyboardPedalHeel3", Self::KeyboardPedalHeel3),
+            ("luteFrenchFretC", Self::LuteFrenchFretC),
+            ("accidentalCombiningRaise31Schisma", Self::AccidentalCombiningRaise31Schisma),
+            ("restDoubleWholeLegerLine", Self::RestDoubleWholeLegerLine),
+            ("lyricsElisionWide", Self::LyricsElisionWide),
+            ("elecDisc", Self::ElecDisc),
+            ("accSagittal11v19MediumDiesisDown", Self::AccSagittal11V19MediumDiesisDown),
+            ("guitarFadeOut", Self::GuitarFadeOut),
+            ("accSagittal17KleismaDown", Self::AccSagittal17KleismaDown),
+            ("noteheadRectangularClusterBlackTop", Self::NoteheadRectangularClusterBlackTop),
+            ("pictSlitDrum", Self::PictSlitDrum),
+            ("mensuralProportionProportioTripla", Self::MensuralProportionProportioTripla),
+            ("octaveBassa", Self::OctaveBassa),
+            ("dynamicMessaDiVoce", Self::DynamicMessaDiVoce),
+            ("luteFingeringRHThird", Self::LuteFingeringRhThird),
+            ("accidentalOneThirdToneFlatFerneyhough", Self::AccidentalOneThirdToneFlatFerneyhough),
+            ("noteGSharpBlack", Self::NoteGSharpBlack),
+            ("noteheadDiamondBlack", Self::NoteheadDiamondBlack),
+            ("noteBWhole", Self::NoteBWhole),
+            ("noteheadXDoubleWhole", Self::NoteheadXDoubleWhole),
+            ("pictBeaterWoodXylophoneLeft", Self::PictBeaterWoodXylophoneLeft),
+            ("ventiduesimaAlta", Self::VentiduesimaAlta),
+            ("pictStickShot", Self::PictStickShot),
+            ("pictScrapeCenterToEdge", Self::PictScrapeCenterToEdge),
+            ("elecLineOut", Self::ElecLineOut),
+            ("kodalyHandSo", Self::KodalyHandSo),
+            ("accdnRicochetStem6", Self::AccdnRicochetStem6),
+            ("accSagittalFlat19sDown", Self::AccSagittalFlat19SDown),
+            ("organGermanGUpper", Self::OrganGermanGUpper),
+            ("noteheadSlashedHalf2", Self::NoteheadSlashedHalf2),
+            ("noteShapeQuarterMoonBlack", Self::NoteShapeQuarterMoonBlack),
+            ("fretboard6String", Self::Fretboard6String),
+            ("luteItalianFret2", Self::LuteItalianFret2),
+            ("mensuralProportionTempusPerfectum", Self::MensuralProportionTempusPerfectum),
+            ("fingering2Italic", Self::Fingering2Italic),
+            ("metNote64thDown", Self::MetNote64thDown),
+            ("noteheadDiamondClusterWhiteBottom", Self::NoteheadDiamondClusterWhiteBottom),
+            ("beamAccelRit3", Self::BeamAccelRit3),
+            ("mensuralObliqueAsc5thBlack", Self::MensuralObliqueAsc5thBlack),
+            ("pictEmptyTrap", Self::PictEmptyTrap),
+            ("noteheadMoonBlack", Self::NoteheadMoonBlack),
+            ("noteheadCowellThirteenthNoteSeriesBlack", Self::NoteheadCowellThirteenthNoteSeriesBlack),
+            ("chantPunctumInclinatumAuctum", Self::ChantPunctumInclinatumAuctum),
+            ("kahnDrawTap", Self::KahnDrawTap),
+            ("noteRaHalf", Self::NoteRaHalf),
+            ("accidentalSharpLoweredStockhausen", Self::AccidentalSharpLoweredStockhausen),
+            ("mensuralObliqueDesc3rdWhite", Self::MensuralObliqueDesc3RdWhite),
+            ("articMarcatoTenutoBelow", Self::ArticMarcatoTenutoBelow),
+            ("pictCenter3", Self::PictCenter3),
+            ("windLessRelaxedEmbouchure", Self::WindLessRelaxedEmbouchure),
+            ("mensuralSignumDown", Self::MensuralSignumDown),
+            ("guitarBarreFull", Self::GuitarBarreFull),
+            ("noteEWhole", Self::NoteEWhole),
+            ("accSagittal35MediumDiesisDown", Self::AccSagittal35MediumDiesisDown),
+            ("ornamentLowLeftConvexStroke", Self::OrnamentLowLeftConvexStroke),
+            ("mensuralCombStemDiagonal", Self::MensuralCombStemDiagonal),
+            ("noteheadClusterSquareWhite", Self::NoteheadClusterSquareWhite),
+            ("pictWindMachine", Self::PictWindMachine),
+            ("organGermanHUpper", Self::OrganGermanHUpper),
+            ("organGermanBuxheimerBrevis2", Self::OrganGermanBuxheimerBrevis2),
+            ("fClefReversed", Self::FClefReversed),
+            ("windFlatEmbouchure", Self::WindFlatEmbouchure),
+            ("ornamentPrecompDescendingSlide", Self::OrnamentPrecompDescendingSlide),
+            ("analyticsTheme1", Self::AnalyticsTheme1),
+            ("windLessTightEmbouchure", Self::WindLessTightEmbouchure),
+            ("mensuralObliqueDesc2ndBlack", Self::MensuralObliqueDesc2ndBlack),
+            ("noteheadClusterQuarter3rd", Self::NoteheadClusterQuarter3Rd),
+            ("articStaccatoBelow", Self::ArticStaccatoBelow),
+            ("fingering8", Self::Fingering8),
+            ("pictShellChimes", Self::PictShellChimes),
+            ("repeatBarLowerDot", Self::RepeatBarLowerDot),
+            ("smnHistoryFlat", Self::SmnHistoryFlat),
+            ("accSagittalFlat49SDown", Self::AccSagittalFlat49SDown),
+            ("arrowheadWhiteDown", Self::ArrowheadWhiteDown),
+            ("kahnToeClick", Self::KahnToeClick),
+            ("accSagittalSharp25SDown", Self::AccSagittalSharp25SDown),
+            ("organGermanDLower", Self::OrganGermanDLower),
+            ("daseianSuperiores3", Self::DaseianSuperiores3),
+            ("accdnRicochetStem2", Self::AccdnRicochetStem2),
+            ("noteheadLargeArrowDownDoubleWhole", Self::NoteheadLargeArrowDownDoubleWhole),
+            ("ornamentHookBeforeNote", Self::OrnamentHookBeforeNote),
+            ("mensuralProlation10", Self::MensuralProlation10),
+            ("fretboard6StringNut", Self::Fretboard6StringNut),
+            ("articStaccatissimoAbove", Self::ArticStaccatissimoAbove),
+            ("keyboardBebung3DotsBelow", Self::KeyboardBebung3DotsBelow),
+            ("noteheadDiamondClusterBlack3rd", Self::NoteheadDiamondClusterBlack3Rd),
+            ("stringsTripleChopOutward", Self::StringsTripleChopOutward),
+            ("noteheadSlashHorizontalEnds", Self::NoteheadSlashHorizontalEnds),
+            ("restHalf", Self::RestHalf),
+            ("tuplet6", Self::Tuplet6),
+            ("noteheadCowellNinthNoteSeriesWhole", Self::NoteheadCowellNinthNoteSeriesWhole),
+            ("tuplet8", Self::Tuplet8),
+            ("luteStaff6LinesWide", Self::LuteStaff6LinesWide),
+            ("guitarString9", Self::GuitarString9),
+            ("brassHarmonMuteStemOpen", Self::BrassHarmonMuteStemOpen),
+            ("accidentalUpsAndDownsDown", Self::AccidentalUpsAndDownsDown),
+            ("chantPunctumLinea", Self::ChantPunctumLinea),
+            ("articLaissezVibrerAbove", Self::ArticLaissezVibrerAbove),
+            ("accSagittalFlat23CUp", Self::AccSagittalFlat23CUp),
+            ("medRenFlatWithDot", Self::MedRenFlatWithDot),
+            ("accidentalXenakisOneThirdToneSharp", Self::AccidentalXenakisOneThirdToneSharp),
+            ("chantSemicirculusAbove", Self::ChantSemicirculusAbove),
+            ("accSagittalUnused1", Self::AccSagittalUnused1),
+            ("timeSigFractionOneThird", Self::TimeSigFractionOneThird),
+            ("accidentalWyschnegradsky11TwelfthsSharp", Self::AccidentalWyschnegradsky11twelfthsSharp),
+            ("noteheadCowellThirdNoteSeriesWhole", Self::NoteheadCowellThirdNoteSeriesWhole),
+            ("noteShapeQuarterMoonWhite", Self::NoteShapeQuarterMoonWhite),
+            ("stringsScrapeParallelInward", Self::StringsScrapeParallelInward),
+            ("accSagittalSharp35LUp", Self::AccSagittalSharp35LUp),
+            ("accSagittalFlat17kUp", Self::AccSagittalFlat17KUp),
+            ("segnoSerpent1", Self::SegnoSerpent1),
+            ("dynamicSforzandoPianissimo", Self::DynamicSforzandoPianissimo),
+            ("windTightEmbouchure", Self::WindTightEmbouchure),
+            ("gClefLigatedNumberBelow", Self::GClefLigatedNumberBelow),
+            ("noteReWhole", Self::NoteReWhole),
+            ("accSagittalSharp17kDown", Self::AccSagittalSharp17KDown),
+            ("kahnBrushBackward", Self::KahnBrushBackward),
+            ("chantStaffWide", Self::ChantStaffWide),
+            ("luteDurationWhole", Self::LuteDurationWhole),
+            ("kahnRightTurn", Self::KahnRightTurn),
+            ("noteheadDiamondHalfWide", Self::NoteheadDiamondHalfWide),
+            ("pictWoundSoftUp", Self::PictWoundSoftUp),
+            ("pictBambooScraper", Self::PictBambooScraper),
+            ("mensuralProlationCombiningStroke", Self::MensuralProlationCombiningStroke),
+            ("kievanNote8thStemDown", Self::KievanNote8thStemDown),
+            ("wiggleTrillSlower", Self::WiggleTrillSlower),
+            ("rest8th", Self::Rest8th),
+            ("pictBellPlate", Self::PictBellPlate),
+            ("accSagittal11MediumDiesisUp", Self::AccSagittal11MediumDiesisUp),
+            ("harpSalzedoPlayUpperEnd", Self::HarpSalzedoPlayUpperEnd),
+            ("articAccentBelow", Self::ArticAccentBelow),
+            ("noteheadDiamondWhiteWide", Self::NoteheadDiamondWhiteWide),
+            ("controlEndTie", Self::ControlEndTie),
+            ("dynamicCombinedSeparatorSpace", Self::DynamicCombinedSeparatorSpace),
+            ("stringsJeteBelow", Self::StringsJeteBelow),
+            ("accidentalSharpOneArrowDown", Self::AccidentalSharpOneArrowDown),
+            ("dynamicSforzatoPiano", Self::DynamicSforzatoPiano),
+            ("stringsOverpressurePossibileDownBow", Self::StringsOverpressurePossibileDownBow),
+            ("timeSig1Reversed", Self::TimeSig1Reversed),
+            ("accdnRH3RanksDoubleTremoloLower8ve", Self::AccdnRh3RanksDoubleTremoloLower8Ve),
+            ("pictXylTenorTrough", Self::PictXylTenorTrough),
+            ("dynamicZ", Self::DynamicZ),
+            ("guitarString12", Self::GuitarString12),
+            ("accSagittalFlat5v13MDown", Self::AccSagittalFlat5V13MDown),
+            ("luteGermanNUpper", Self::LuteGermanNUpper),
+            ("pluckedPlectrum", Self::PluckedPlectrum),
+            ("smnNatural", Self::SmnNatural),
+            ("harpSalzedoDampLowStrings", Self::HarpSalzedoDampLowStrings),
+            ("arrowheadOpenRight", Self::ArrowheadOpenRight),
+            ("csymBracketRightTall", Self::CsymBracketRightTall),
+            ("luteGermanMLower", Self::LuteGermanMLower),
+            ("kievanNoteQuarterStemDown", Self::KievanNoteQuarterStemDown),
+            ("accSagittalDoubleSharp23SDown", Self::AccSagittalDoubleSharp23SDown),
+            ("luteGermanHLower", Self::LuteGermanHLower),
+            ("accidentalCombiningRaise17Schisma", Self::AccidentalCombiningRaise17Schisma),
+            ("pictGumHardUp", Self::PictGumHardUp),
+            ("accSagittalFractionalTinaUp", Self::AccSagittalFractionalTinaUp),
+            ("brassFallRoughShort", Self::BrassFallRoughShort),
+            ("mensuralBlackMaxima", Self::MensuralBlackMaxima),
+            ("noteheadCowellFifteenthNoteSeriesBlack", Self::NoteheadCowellFifteenthNoteSeriesBlack),
+            ("analyticsThemeInversion", Self::AnalyticsThemeInversion),
+            ("noteDoubleWhole", Self::NoteDoubleWhole),
+            ("accSagittal2TinasDown", Self::AccSagittal2tinasDown),
+            ("accidentalCombiningLower43Comma", Self::AccidentalCombiningLower43Comma),
+            ("fingering0", Self::Fingering0),
+            ("stringsOverpressureNoDirection", Self::StringsOverpressureNoDirection),
+            ("fretboard4StringNut", Self::Fretboard4StringNut),
+            ("pictVibSmithBrindle", Self::PictVibSmithBrindle),
+            ("accidentalReversedFlatArrowDown", Self::AccidentalReversedFlatArrowDown),
+            ("pictHiHat", Self::PictHiHat),
+            ("fermataAbove", Self::FermataAbove),
+            ("wiggleVibratoSmallFastest", Self::WiggleVibratoSmallFastest),
+            ("organGermanMinimaRest", Self::OrganGermanMinimaRest),
+            ("fingeringELower", Self::FingeringELower),
+            ("chantStrophicusLiquescens3rd", Self::ChantStrophicusLiquescens3Rd),
+            ("noteheadVoidWithX", Self::NoteheadVoidWithX),
+            ("elecMute", Self::ElecMute),
+            ("accSagittal23SmallDiesisDown", Self::AccSagittal23SmallDiesisDown),
+            ("elecMicrophone", Self::ElecMicrophone),
+            ("arrowOpenUpRight", Self::ArrowOpenUpRight),
+            ("pictTubaphone", Self::PictTubaphone),
+            ("wiggleCircularConstantFlippedLarge", Self::WiggleCircularConstantFlippedLarge),
+            ("accidentalFlatRaisedStockhausen", Self::AccidentalFlatRaisedStockhausen),
+            ("chantAugmentum", Self::ChantAugmentum),
+            ("6stringTabClef", Self::_6StringTabClef),
+            ("articMarcatoAbove", Self::ArticMarcatoAbove),
+            ("noteShapeTriangleUpDoubleWhole", Self::NoteShapeTriangleUpDoubleWhole),
+            ("pictGuiro", Self::PictGuiro),
+            ("restQuarterOld", Self::RestQuarterOld),
+            ("swissRudimentsNoteheadBlackDouble", Self::SwissRudimentsNoteheadBlackDouble),
+            ("accSagittalDoubleSharp23CDown", Self::AccSagittalDoubleSharp23CDown),
+            ("wiggleVibratoWide", Self::WiggleVibratoWide),
+            ("accSagittal8TinasUp", Self::AccSagittal8tinasUp),
+            ("pictBeaterSoftTimpaniUp", Self::PictBeaterSoftTimpaniUp),
+            ("noteheadXOrnate", Self::NoteheadXOrnate),
+            ("accidentalCombiningRaise43Comma", Self::AccidentalCombiningRaise43Comma),
+            ("accSagittal5v7KleismaUp", Self::AccSagittal5V7KleismaUp),
+            ("accSagittalDoubleFlat7v11CUp", Self::AccSagittalDoubleFlat7V11CUp),
+            ("windMouthpiecePop", Self::WindMouthpiecePop),
+            ("noteLiWhole", Self::NoteLiWhole),
+            ("pictBeaterSoftGlockenspielUp", Self::PictBeaterSoftGlockenspielUp),
+            ("mensuralNoteheadMaximaBlackVoid", Self::MensuralNoteheadMaximaBlackVoid),
+            ("kievanNoteBeam", Self::KievanNoteBeam),
+            ("noteheadRoundBlack", Self::NoteheadRoundBlack),
+            ("accSagittal19SchismaDown", Self::AccSagittal19SchismaDown),
+            ("chantQuilisma", Self::ChantQuilisma),
+            ("accSagittal5CommaDown", Self::AccSagittal5CommaDown),
+            ("kodalyHandTi", Self::KodalyHandTi),
+            ("accidentalDoubleSharpEqualTempered", Self::AccidentalDoubleSharpEqualTempered),
+            ("conductorBeat2Compound", Self::ConductorBeat2Compound),
+            ("accSagittalSharp5v19CUp", Self::AccSagittalSharp5V19CUp),
+            ("ornamentTopRightConcaveStroke", Self::OrnamentTopRightConcaveStroke),
+            ("noteheadClusterDoubleWhole2nd", Self::NoteheadClusterDoubleWhole2nd),
+            ("medRenPlicaCMN", Self::MedRenPlicaCmn),
+            ("accSagittalFlat55CDown", Self::AccSagittalFlat55CDown),
+            ("dynamicMezzo", Self::DynamicMezzo),
+            ("ornamentMiddleVerticalStroke", Self::OrnamentMiddleVerticalStroke),
+            ("accSagittal5v23SmallDiesisDown", Self::AccSagittal5V23SmallDiesisDown),
+            ("accSagittalSharp11v49CUp", Self::AccSagittalSharp11V49CUp),
+            ("accdnRH4RanksMaster", Self::AccdnRh4RanksMaster),
+            ("noteheadTriangleUpRightWhite", Self::NoteheadTriangleUpRightWhite),
+            ("mensuralNoteheadMaximaWhite", Self::MensuralNoteheadMaximaWhite),
+            ("noteheadClusterDoubleWholeMiddle", Self::NoteheadClusterDoubleWholeMiddle),
+            ("accdnLH3Ranks2Square", Self::AccdnLh3Ranks2Square),
+            ("pictBeaterMetalBassDrumUp", Self::PictBeaterMetalBassDrumUp),
+            ("oneHandedRollStevens", Self::OneHandedRollStevens),
+            ("ornamentPrecompAppoggTrill", Self::OrnamentPrecompAppoggTrill),
+            ("accSagittalSharp5v11SDown", Self::AccSagittalSharp5V11SDown),
+            ("beamAccelRit12", Self::BeamAccelRit12),
+            ("timeSigCutCommonTurned", Self::TimeSigCutCommonTurned),
+            ("ornamentObliqueLineHorizAfterNote", Self::OrnamentObliqueLineHorizAfterNote),
+            ("kodalyHandFa", Self::KodalyHandFa),
+            ("segnoSerpent2", Self::SegnoSerpent2),
+            ("noteMeHalf", Self::NoteMeHalf),
+            ("noteheadCircledDoubleWholeLarge", Self::NoteheadCircledDoubleWholeLarge),
+            ("pictBeaterHardXylophoneRight", Self::PictBeaterHardXylophoneRight),
+            ("luteItalianFret8", Self::LuteItalianFret8),
+            ("bridgeClef", Self::BridgeClef),
+            ("accSagittal19CommaDown", Self::AccSagittal19CommaDown),
+            ("dynamicMF", Self::DynamicMf),
+            ("accidentalWyschnegradsky1TwelfthsSharp", Self::AccidentalWyschnegradsky1twelfthsSharp),
+            ("noteheadClusterDoubleWholeBottom", Self::NoteheadClusterDoubleWholeBottom),
+            ("wiggleGlissando", Self::WiggleGlissando),
+            ("noteLeBlack", Self::NoteLeBlack),
+            ("pictKlaxonHorn", Self::PictKlaxonHorn),
+            ("noteCFlatHalf", Self::NoteCFlatHalf),
+            ("daseianExcellentes4", Self::DaseianExcellentes4),
+            ("kahnRiffle", Self::KahnRiffle),
+            ("accSagittalFlat35MDown", Self::AccSagittalFlat35MDown),
+            ("guitarString13", Self::GuitarString13),
+            ("accidentalSori", Self::AccidentalSori),
+            ("kahnFlat", Self::KahnFlat),
+            ("breathMarkUpbow", Self::BreathMarkUpbow),
+            ("accidentalThreeQuarterTonesSharpStockhausen", Self::AccidentalThreeQuarterTonesSharpStockhausen),
+            ("pictBeaterMediumYarnDown", Self::PictBeaterMediumYarnDown),
+            ("fingeringTUpper", Self::FingeringTUpper),
+            ("noteheadHeavyX", Self::NoteheadHeavyX),
+            ("luteItalianFret0", Self::LuteItalianFret0),
+            ("noteShapeTriangleRoundWhite", Self::NoteShapeTriangleRoundWhite),
+            ("noteLiBlack", Self::NoteLiBlack),
+            ("dynamicFFF", Self::DynamicFff),
+            ("noteheadWholeFilled", Self::NoteheadWholeFilled),
+            ("staff2Lines", Self::Staff2Lines),
+            ("mensuralCombStemDownFlagLeft", Self::MensuralCombStemDownFlagLeft),
+            ("scaleDegree2", Self::ScaleDegree2),
+            ("pictLotusFlute", Self::PictLotusFlute),
+            ("staffPosRaise3", Self::StaffPosRaise3),
+            ("accSagittalSharp19CDown", Self::AccSagittalSharp19CDown),
+            ("noteheadSlashedDoubleWhole1", Self::NoteheadSlashedDoubleWhole1),
+            ("brassLiftSmoothShort", Self::BrassLiftSmoothShort),
+            ("figbassBracketLeft", Self::FigbassBracketLeft),
+            ("accidentalNarrowReversedFlatAndFlat", Self::AccidentalNarrowReversedFlatAndFlat),
+            ("mensuralCombStemUp", Self::MensuralCombStemUp),
+            ("mensuralProlation1", Self::MensuralProlation1),
+            ("noteGBlack", Self::NoteGBlack),
+            ("noteheadClusterWholeBottom", Self::NoteheadClusterWholeBottom),
+            ("functionNLower", Self::FunctionNLower),
+            ("pictMaracas", Self::PictMaracas),
+            ("timeSigPlus", Self::TimeSigPlus),
+            ("pictBeaterMediumXylophoneRight", Self::PictBeaterMediumXylophoneRight),
+            ("kahnStep", Self::KahnStep),
+            ("elecMixingConsole", Self::ElecMixingConsole),
+            ("elecMicrophoneMute", Self::ElecMicrophoneMute),
+            ("accidentalCombiningLower47Quartertone", Self::AccidentalCombiningLower47Quartertone),
+            ("luteDuration8th", Self::LuteDuration8th),
+            ("chantSemicirculusBelow", Self::ChantSemicirculusBelow),
+            ("accdnRH3RanksTremoloUpper8ve", Self::AccdnRh3RanksTremoloUpper8Ve),
+            ("mensuralBlackSemibrevisOblique", Self::MensuralBlackSemibrevisOblique),
+            ("accSagittal9TinasUp", Self::AccSagittal9tinasUp),
+            ("luteDurationQuarter", Self::LuteDurationQuarter),
+            ("noteDHalf", Self::NoteDHalf),
+            ("ornamentPrecompDoubleCadenceUpperPrefix", Self::OrnamentPrecompDoubleCadenceUpperPrefix),
+            ("noteMeBlack", Self::NoteMeBlack),
+            ("noteDoHalf", Self::NoteDoHalf),
+            ("kahnKneeInward", Self::KahnKneeInward),
+            ("pictXylSmithBrindle", Self::PictXylSmithBrindle),
+            ("pictBellOfCymbal", Self::PictBellOfCymbal),
+            ("functionILower", Self::FunctionILower),
+            ("accidentalLowerOneSeptimalComma", Self::AccidentalLowerOneSeptimalComma),
+            ("stringsUpBowBeyondBridge", Self::StringsUpBowBeyondBridge),
+            ("pictSnareDrumSnaresOff", Self::PictSnareDrumSnaresOff),
+            ("accSagittalFlat11MDown", Self::AccSagittalFlat11MDown),
+            ("cClefArrowUp", Self::CClefArrowUp),
+            ("accdnRH3RanksTwoChoirs", Self::AccdnRh3RanksTwoChoirs),
+            ("handbellsMalletBellSuspended", Self::HandbellsMalletBellSuspended),
+            ("guitarGolpe", Self::GuitarGolpe),
+            ("timeSigPlusSmall", Self::TimeSigPlusSmall),
+            ("handbellsPluckLift", Self::HandbellsPluckLift),
+            ("scaleDegree6", Self::ScaleDegree6),
+            ("kahnBallChange", Self::KahnBallChange),
+            ("noteheadCircledBlackLarge", Self::NoteheadCircledBlackLarge),
+            ("fermataVeryShortAbove", Self::FermataVeryShortAbove),
+            ("timeSig1", Self::TimeSig1),
+            ("arrowheadBlackDown", Self::ArrowheadBlackDown),
+            ("wiggleTrillFastest", Self::WiggleTrillFastest),
+            ("luteGermanLLower", Self::LuteGermanLLower),
+            ("noteRiWhole", Self::NoteRiWhole),
+            ("brassMuteHalfClosed", Self::BrassMuteHalfClosed),
+            ("harpSalzedoDampAbove", Self::HarpSalzedoDampAbove),
+            ("luteItalianFret5", Self::LuteItalianFret5),
+            ("metNote256thDown", Self::MetNote256thDown),
+            ("organGerman4Semiminimae", Self::OrganGerman4Semiminimae),
+            ("timeSigCutCommon", Self::TimeSigCutCommon),
+            ("accidentalWyschnegradsky8TwelfthsFlat", Self::AccidentalWyschnegradsky8twelfthsFlat),
+            ("noteheadLargeArrowUpBlack", Self::NoteheadLargeArrowUpBlack),
+            ("pictConga", Self::PictConga),
+            ("chantCclef", Self::ChantCclef),
+            ("mensuralObliqueDesc4thBlack", Self::MensuralObliqueDesc4thBlack),
+            ("stringsDownBow", Self::StringsDownBow),
+            ("ornamentPrecompSlide", Self::OrnamentPrecompSlide),
+            ("organGermanELower", Self::OrganGermanELower),
+            ("kahnToe", Self::KahnToe),
+            ("accidentalUpsAndDownsUp", Self::AccidentalUpsAndDownsUp),
+            ("daseianSuperiores2", Self::DaseianSuperiores2),
+            ("flag32ndUp", Self::Flag32ndUp),
+            ("accidentalCombiningLower53LimitComma", Self::AccidentalCombiningLower53LimitComma),
+            ("accidentalParensLeft", Self::AccidentalParensLeft),
+            ("noteheadNull", Self::NoteheadNull),
+            ("glissandoDown", Self::GlissandoDown),
+            ("noteheadTriangleUpBlack", Self::NoteheadTriangleUpBlack),
+            ("ornamentHighRightConvexStroke", Self::OrnamentHighRightConvexStroke),
+            ("kahnChug", Self::KahnChug),
+            ("daseianFinales2", Self::DaseianFinales2),
+            ("accSagittalDoubleFlat7v11kUp", Self::AccSagittalDoubleFlat7V11KUp),
+            ("chantVirgula", Self::ChantVirgula),
+            ("kahnZank", Self::KahnZank),
+            ("organGerman6Semifusae", Self::OrganGerman6Semifusae),
+            ("kahnToeTap", Self::KahnToeTap),
+            ("accidentalCombiningLower37Quartertone", Self::AccidentalCombiningLower37Quartertone),
+        ],
+    };
+
+    /// Returns the variant with the given name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES.get(name).copied()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Glyph {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NameVisitor;
+
+        impl serde::de::Visitor<'_> for NameVisitor {
+            type Value = Glyph;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a glyph name")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Glyph::from_name(value)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Glyph {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
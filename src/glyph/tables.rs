@@ -0,0 +1,14688 @@
+use super::Glyph;
+
+/// Every [`Glyph`], in the order of their discriminants.
+#[rustfmt::skip]
+pub static GLYPHS: &[Glyph] = &[
+    Glyph::AccSagittal11LargeDiesisDown,
+    Glyph::AccSagittal11LargeDiesisUp,
+    Glyph::AccSagittal11MediumDiesisDown,
+    Glyph::AccSagittal11MediumDiesisUp,
+    Glyph::AccSagittal11V19LargeDiesisDown,
+    Glyph::AccSagittal11V19LargeDiesisUp,
+    Glyph::AccSagittal11V19MediumDiesisDown,
+    Glyph::AccSagittal11V19MediumDiesisUp,
+    Glyph::AccSagittal11V49CommaDown,
+    Glyph::AccSagittal11V49CommaUp,
+    Glyph::AccSagittal143CommaDown,
+    Glyph::AccSagittal143CommaUp,
+    Glyph::AccSagittal17CommaDown,
+    Glyph::AccSagittal17CommaUp,
+    Glyph::AccSagittal17KleismaDown,
+    Glyph::AccSagittal17KleismaUp,
+    Glyph::AccSagittal19CommaDown,
+    Glyph::AccSagittal19CommaUp,
+    Glyph::AccSagittal19SchismaDown,
+    Glyph::AccSagittal19SchismaUp,
+    Glyph::AccSagittal1MinaDown,
+    Glyph::AccSagittal1MinaUp,
+    Glyph::AccSagittal1tinaDown,
+    Glyph::AccSagittal1tinaUp,
+    Glyph::AccSagittal23CommaDown,
+    Glyph::AccSagittal23CommaUp,
+    Glyph::AccSagittal23SmallDiesisDown,
+    Glyph::AccSagittal23SmallDiesisUp,
+    Glyph::AccSagittal25SmallDiesisDown,
+    Glyph::AccSagittal25SmallDiesisUp,
+    Glyph::AccSagittal2MinasDown,
+    Glyph::AccSagittal2MinasUp,
+    Glyph::AccSagittal2tinasDown,
+    Glyph::AccSagittal2tinasUp,
+    Glyph::AccSagittal35LargeDiesisDown,
+    Glyph::AccSagittal35LargeDiesisUp,
+    Glyph::AccSagittal35MediumDiesisDown,
+    Glyph::AccSagittal35MediumDiesisUp,
+    Glyph::AccSagittal3tinasDown,
+    Glyph::AccSagittal3tinasUp,
+    Glyph::AccSagittal49LargeDiesisDown,
+    Glyph::AccSagittal49LargeDiesisUp,
+    Glyph::AccSagittal49MediumDiesisDown,
+    Glyph::AccSagittal49MediumDiesisUp,
+    Glyph::AccSagittal49SmallDiesisDown,
+    Glyph::AccSagittal49SmallDiesisUp,
+    Glyph::AccSagittal4tinasDown,
+    Glyph::AccSagittal4tinasUp,
+    Glyph::AccSagittal55CommaDown,
+    Glyph::AccSagittal55CommaUp,
+    Glyph::AccSagittal5CommaDown,
+    Glyph::AccSagittal5CommaUp,
+    Glyph::AccSagittal5V11SmallDiesisDown,
+    Glyph::AccSagittal5V11SmallDiesisUp,
+    Glyph::AccSagittal5V13LargeDiesisDown,
+    Glyph::AccSagittal5V13LargeDiesisUp,
+    Glyph::AccSagittal5V13MediumDiesisDown,
+    Glyph::AccSagittal5V13MediumDiesisUp,
+    Glyph::AccSagittal5V19CommaDown,
+    Glyph::AccSagittal5V19CommaUp,
+    Glyph::AccSagittal5V23SmallDiesisDown,
+    Glyph::AccSagittal5V23SmallDiesisUp,
+    Glyph::AccSagittal5V49MediumDiesisDown,
+    Glyph::AccSagittal5V49MediumDiesisUp,
+    Glyph::AccSagittal5V7KleismaDown,
+    Glyph::AccSagittal5V7KleismaUp,
+    Glyph::AccSagittal5tinasDown,
+    Glyph::AccSagittal5tinasUp,
+    Glyph::AccSagittal6tinasDown,
+    Glyph::AccSagittal6tinasUp,
+    Glyph::AccSagittal7CommaDown,
+    Glyph::AccSagittal7CommaUp,
+    Glyph::AccSagittal7V11CommaDown,
+    Glyph::AccSagittal7V11CommaUp,
+    Glyph::AccSagittal7V11KleismaDown,
+    Glyph::AccSagittal7V11KleismaUp,
+    Glyph::AccSagittal7V19CommaDown,
+    Glyph::AccSagittal7V19CommaUp,
+    Glyph::AccSagittal7tinasDown,
+    Glyph::AccSagittal7tinasUp,
+    Glyph::AccSagittal8tinasDown,
+    Glyph::AccSagittal8tinasUp,
+    Glyph::AccSagittal9tinasDown,
+    Glyph::AccSagittal9tinasUp,
+    Glyph::AccSagittalAcute,
+    Glyph::AccSagittalDoubleFlat,
+    Glyph::AccSagittalDoubleFlat11V49CUp,
+    Glyph::AccSagittalDoubleFlat143CUp,
+    Glyph::AccSagittalDoubleFlat17CUp,
+    Glyph::AccSagittalDoubleFlat17KUp,
+    Glyph::AccSagittalDoubleFlat19CUp,
+    Glyph::AccSagittalDoubleFlat19SUp,
+    Glyph::AccSagittalDoubleFlat23CUp,
+    Glyph::AccSagittalDoubleFlat23SUp,
+    Glyph::AccSagittalDoubleFlat25SUp,
+    Glyph::AccSagittalDoubleFlat49SUp,
+    Glyph::AccSagittalDoubleFlat55CUp,
+    Glyph::AccSagittalDoubleFlat5CUp,
+    Glyph::AccSagittalDoubleFlat5V11SUp,
+    Glyph::AccSagittalDoubleFlat5V19CUp,
+    Glyph::AccSagittalDoubleFlat5V23SUp,
+    Glyph::AccSagittalDoubleFlat5V7KUp,
+    Glyph::AccSagittalDoubleFlat7CUp,
+    Glyph::AccSagittalDoubleFlat7V11CUp,
+    Glyph::AccSagittalDoubleFlat7V11KUp,
+    Glyph::AccSagittalDoubleFlat7V19CUp,
+    Glyph::AccSagittalDoubleSharp,
+    Glyph::AccSagittalDoubleSharp11V49CDown,
+    Glyph::AccSagittalDoubleSharp143CDown,
+    Glyph::AccSagittalDoubleSharp17CDown,
+    Glyph::AccSagittalDoubleSharp17KDown,
+    Glyph::AccSagittalDoubleSharp19CDown,
+    Glyph::AccSagittalDoubleSharp19SDown,
+    Glyph::AccSagittalDoubleSharp23CDown,
+    Glyph::AccSagittalDoubleSharp23SDown,
+    Glyph::AccSagittalDoubleSharp25SDown,
+    Glyph::AccSagittalDoubleSharp49SDown,
+    Glyph::AccSagittalDoubleSharp55CDown,
+    Glyph::AccSagittalDoubleSharp5CDown,
+    Glyph::AccSagittalDoubleSharp5V11SDown,
+    Glyph::AccSagittalDoubleSharp5V19CDown,
+    Glyph::AccSagittalDoubleSharp5V23SDown,
+    Glyph::AccSagittalDoubleSharp5V7KDown,
+    Glyph::AccSagittalDoubleSharp7CDown,
+    Glyph::AccSagittalDoubleSharp7V11CDown,
+    Glyph::AccSagittalDoubleSharp7V11KDown,
+    Glyph::AccSagittalDoubleSharp7V19CDown,
+    Glyph::AccSagittalFlat,
+    Glyph::AccSagittalFlat11LDown,
+    Glyph::AccSagittalFlat11MDown,
+    Glyph::AccSagittalFlat11V19LDown,
+    Glyph::AccSagittalFlat11V19MDown,
+    Glyph::AccSagittalFlat11V49CDown,
+    Glyph::AccSagittalFlat11V49CUp,
+    Glyph::AccSagittalFlat143CDown,
+    Glyph::AccSagittalFlat143CUp,
+    Glyph::AccSagittalFlat17CDown,
+    Glyph::AccSagittalFlat17CUp,
+    Glyph::AccSagittalFlat17KDown,
+    Glyph::AccSagittalFlat17KUp,
+    Glyph::AccSagittalFlat19CDown,
+    Glyph::AccSagittalFlat19CUp,
+    Glyph::AccSagittalFlat19SDown,
+    Glyph::AccSagittalFlat19SUp,
+    Glyph::AccSagittalFlat23CDown,
+    Glyph::AccSagittalFlat23CUp,
+    Glyph::AccSagittalFlat23SDown,
+    Glyph::AccSagittalFlat23SUp,
+    Glyph::AccSagittalFlat25SDown,
+    Glyph::AccSagittalFlat25SUp,
+    Glyph::AccSagittalFlat35LDown,
+    Glyph::AccSagittalFlat35MDown,
+    Glyph::AccSagittalFlat49LDown,
+    Glyph::AccSagittalFlat49MDown,
+    Glyph::AccSagittalFlat49SDown,
+    Glyph::AccSagittalFlat49SUp,
+    Glyph::AccSagittalFlat55CDown,
+    Glyph::AccSagittalFlat55CUp,
+    Glyph::AccSagittalFlat5CDown,
+    Glyph::AccSagittalFlat5CUp,
+    Glyph::AccSagittalFlat5V11SDown,
+    Glyph::AccSagittalFlat5V11SUp,
+    Glyph::AccSagittalFlat5V13LDown,
+    Glyph::AccSagittalFlat5V13MDown,
+    Glyph::AccSagittalFlat5V19CDown,
+    Glyph::AccSagittalFlat5V19CUp,
+    Glyph::AccSagittalFlat5V23SDown,
+    Glyph::AccSagittalFlat5V23SUp,
+    Glyph::AccSagittalFlat5V49MDown,
+    Glyph::AccSagittalFlat5V7KDown,
+    Glyph::AccSagittalFlat5V7KUp,
+    Glyph::AccSagittalFlat7CDown,
+    Glyph::AccSagittalFlat7CUp,
+    Glyph::AccSagittalFlat7V11CDown,
+    Glyph::AccSagittalFlat7V11CUp,
+    Glyph::AccSagittalFlat7V11KDown,
+    Glyph::AccSagittalFlat7V11KUp,
+    Glyph::AccSagittalFlat7V19CDown,
+    Glyph::AccSagittalFlat7V19CUp,
+    Glyph::AccSagittalFractionalTinaDown,
+    Glyph::AccSagittalFractionalTinaUp,
+    Glyph::AccSagittalGrave,
+    Glyph::AccSagittalShaftDown,
+    Glyph::AccSagittalShaftUp,
+    Glyph::AccSagittalSharp,
+    Glyph::AccSagittalSharp11LUp,
+    Glyph::AccSagittalSharp11MUp,
+    Glyph::AccSagittalSharp11V19LUp,
+    Glyph::AccSagittalSharp11V19MUp,
+    Glyph::AccSagittalSharp11V49CDown,
+    Glyph::AccSagittalSharp11V49CUp,
+    Glyph::AccSagittalSharp143CDown,
+    Glyph::AccSagittalSharp143CUp,
+    Glyph::AccSagittalSharp17CDown,
+    Glyph::AccSagittalSharp17CUp,
+    Glyph::AccSagittalSharp17KDown,
+    Glyph::AccSagittalSharp17KUp,
+    Glyph::AccSagittalSharp19CDown,
+    Glyph::AccSagittalSharp19CUp,
+    Glyph::AccSagittalSharp19SDown,
+    Glyph::AccSagittalSharp19SUp,
+    Glyph::AccSagittalSharp23CDown,
+    Glyph::AccSagittalSharp23CUp,
+    Glyph::AccSagittalSharp23SDown,
+    Glyph::AccSagittalSharp23SUp,
+    Glyph::AccSagittalSharp25SDown,
+    Glyph::AccSagittalSharp25SUp,
+    Glyph::AccSagittalSharp35LUp,
+    Glyph::AccSagittalSharp35MUp,
+    Glyph::AccSagittalSharp49LUp,
+    Glyph::AccSagittalSharp49MUp,
+    Glyph::AccSagittalSharp49SDown,
+    Glyph::AccSagittalSharp49SUp,
+    Glyph::AccSagittalSharp55CDown,
+    Glyph::AccSagittalSharp55CUp,
+    Glyph::AccSagittalSharp5CDown,
+    Glyph::AccSagittalSharp5CUp,
+    Glyph::AccSagittalSharp5V11SDown,
+    Glyph::AccSagittalSharp5V11SUp,
+    Glyph::AccSagittalSharp5V13LUp,
+    Glyph::AccSagittalSharp5V13MUp,
+    Glyph::AccSagittalSharp5V19CDown,
+    Glyph::AccSagittalSharp5V19CUp,
+    Glyph::AccSagittalSharp5V23SDown,
+    Glyph::AccSagittalSharp5V23SUp,
+    Glyph::AccSagittalSharp5V49MUp,
+    Glyph::AccSagittalSharp5V7KDown,
+    Glyph::AccSagittalSharp5V7KUp,
+    Glyph::AccSagittalSharp7CDown,
+    Glyph::AccSagittalSharp7CUp,
+    Glyph::AccSagittalSharp7V11CDown,
+    Glyph::AccSagittalSharp7V11CUp,
+    Glyph::AccSagittalSharp7V11KDown,
+    Glyph::AccSagittalSharp7V11KUp,
+    Glyph::AccSagittalSharp7V19CDown,
+    Glyph::AccSagittalSharp7V19CUp,
+    Glyph::AccSagittalUnused1,
+    Glyph::AccSagittalUnused2,
+    Glyph::AccSagittalUnused3,
+    Glyph::AccSagittalUnused4,
+    Glyph::AccdnCombDot,
+    Glyph::AccdnCombLh2RanksEmpty,
+    Glyph::AccdnCombLh3RanksEmptySquare,
+    Glyph::AccdnCombRh3RanksEmpty,
+    Glyph::AccdnCombRh4RanksEmpty,
+    Glyph::AccdnDiatonicClef,
+    Glyph::AccdnLh2Ranks16Round,
+    Glyph::AccdnLh2Ranks8Plus16Round,
+    Glyph::AccdnLh2Ranks8Round,
+    Glyph::AccdnLh2RanksFullMasterRound,
+    Glyph::AccdnLh2RanksMasterPlus16Round,
+    Glyph::AccdnLh2RanksMasterRound,
+    Glyph::AccdnLh3Ranks2Plus8Square,
+    Glyph::AccdnLh3Ranks2Square,
+    Glyph::AccdnLh3Ranks8Square,
+    Glyph::AccdnLh3RanksDouble8Square,
+    Glyph::AccdnLh3RanksTuttiSquare,
+    Glyph::AccdnPull,
+    Glyph::AccdnPush,
+    Glyph::AccdnRh3RanksAccordion,
+    Glyph::AccdnRh3RanksAuthenticMusette,
+    Glyph::AccdnRh3RanksBandoneon,
+    Glyph::AccdnRh3RanksBassoon,
+    Glyph::AccdnRh3RanksClarinet,
+    Glyph::AccdnRh3RanksDoubleTremoloLower8Ve,
+    Glyph::AccdnRh3RanksDoubleTremoloUpper8Ve,
+    Glyph::AccdnRh3RanksFullFactory,
+    Glyph::AccdnRh3RanksHarmonium,
+    Glyph::AccdnRh3RanksImitationMusette,
+    Glyph::AccdnRh3RanksLowerTremolo8,
+    Glyph::AccdnRh3RanksMaster,
+    Glyph::AccdnRh3RanksOboe,
+    Glyph::AccdnRh3RanksOrgan,
+    Glyph::AccdnRh3RanksPiccolo,
+    Glyph::AccdnRh3RanksTremoloLower8Ve,
+    Glyph::AccdnRh3RanksTremoloUpper8Ve,
+    Glyph::AccdnRh3RanksTwoChoirs,
+    Glyph::AccdnRh3RanksUpperTremolo8,
+    Glyph::AccdnRh3RanksViolin,
+    Glyph::AccdnRh4RanksAlto,
+    Glyph::AccdnRh4RanksBassAlto,
+    Glyph::AccdnRh4RanksMaster,
+    Glyph::AccdnRh4RanksSoftBass,
+    Glyph::AccdnRh4RanksSoftTenor,
+    Glyph::AccdnRh4RanksSoprano,
+    Glyph::AccdnRh4RanksTenor,
+    Glyph::AccdnRicochet2,
+    Glyph::AccdnRicochet3,
+    Glyph::AccdnRicochet4,
+    Glyph::AccdnRicochet5,
+    Glyph::AccdnRicochet6,
+    Glyph::AccdnRicochetStem2,
+    Glyph::AccdnRicochetStem3,
+    Glyph::AccdnRicochetStem4,
+    Glyph::AccdnRicochetStem5,
+    Glyph::AccdnRicochetStem6,
+    Glyph::Accidental1CommaFlat,
+    Glyph::Accidental1CommaSharp,
+    Glyph::Accidental2CommaFlat,
+    Glyph::Accidental2CommaSharp,
+    Glyph::Accidental3CommaFlat,
+    Glyph::Accidental3CommaSharp,
+    Glyph::Accidental4CommaFlat,
+    Glyph::Accidental5CommaSharp,
+    Glyph::AccidentalArrowDown,
+    Glyph::AccidentalArrowUp,
+    Glyph::AccidentalBakiyeFlat,
+    Glyph::AccidentalBakiyeSharp,
+    Glyph::AccidentalBracketLeft,
+    Glyph::AccidentalBracketRight,
+    Glyph::AccidentalBuyukMucennebFlat,
+    Glyph::AccidentalBuyukMucennebSharp,
+    Glyph::AccidentalCombiningCloseCurlyBrace,
+    Glyph::AccidentalCombiningLower17Schisma,
+    Glyph::AccidentalCombiningLower19Schisma,
+    Glyph::AccidentalCombiningLower23Limit29LimitComma,
+    Glyph::AccidentalCombiningLower29LimitComma,
+    Glyph::AccidentalCombiningLower31Schisma,
+    Glyph::AccidentalCombiningLower37Quartertone,
+    Glyph::AccidentalCombiningLower41Comma,
+    Glyph::AccidentalCombiningLower43Comma,
+    Glyph::AccidentalCombiningLower47Quartertone,
+    Glyph::AccidentalCombiningLower53LimitComma,
+    Glyph::AccidentalCombiningOpenCurlyBrace,
+    Glyph::AccidentalCombiningRaise17Schisma,
+    Glyph::AccidentalCombiningRaise19Schisma,
+    Glyph::AccidentalCombiningRaise23Limit29LimitComma,
+    Glyph::AccidentalCombiningRaise29LimitComma,
+    Glyph::AccidentalCombiningRaise31Schisma,
+    Glyph::AccidentalCombiningRaise37Quartertone,
+    Glyph::AccidentalCombiningRaise41Comma,
+    Glyph::AccidentalCombiningRaise43Comma,
+    Glyph::AccidentalCombiningRaise47Quartertone,
+    Glyph::AccidentalCombiningRaise53LimitComma,
+    Glyph::AccidentalCommaSlashDown,
+    Glyph::AccidentalCommaSlashUp,
+    Glyph::AccidentalDoubleFlat,
+    Glyph::AccidentalDoubleFlatArabic,
+    Glyph::AccidentalDoubleFlatEqualTempered,
+    Glyph::AccidentalDoubleFlatOneArrowDown,
+    Glyph::AccidentalDoubleFlatOneArrowUp,
+    Glyph::AccidentalDoubleFlatReversed,
+    Glyph::AccidentalDoubleFlatThreeArrowsDown,
+    Glyph::AccidentalDoubleFlatThreeArrowsUp,
+    Glyph::AccidentalDoubleFlatTurned,
+    Glyph::AccidentalDoubleFlatTwoArrowsDown,
+    Glyph::AccidentalDoubleFlatTwoArrowsUp,
+    Glyph::AccidentalDoubleSharp,
+    Glyph::AccidentalDoubleSharpArabic,
+    Glyph::AccidentalDoubleSharpEqualTempered,
+    Glyph::AccidentalDoubleSharpOneArrowDown,
+    Glyph::AccidentalDoubleSharpOneArrowUp,
+    Glyph::AccidentalDoubleSharpThreeArrowsDown,
+    Glyph::AccidentalDoubleSharpThreeArrowsUp,
+    Glyph::AccidentalDoubleSharpTwoArrowsDown,
+    Glyph::AccidentalDoubleSharpTwoArrowsUp,
+    Glyph::AccidentalEnharmonicAlmostEqualTo,
+    Glyph::AccidentalEnharmonicEquals,
+    Glyph::AccidentalEnharmonicTilde,
+    Glyph::AccidentalFilledReversedFlatAndFlat,
+    Glyph::AccidentalFilledReversedFlatAndFlatArrowDown,
+    Glyph::AccidentalFilledReversedFlatAndFlatArrowUp,
+    Glyph::AccidentalFilledReversedFlatArrowDown,
+    Glyph::AccidentalFilledReversedFlatArrowUp,
+    Glyph::AccidentalFiveQuarterTonesFlatArrowDown,
+    Glyph::AccidentalFiveQuarterTonesSharpArrowUp,
+    Glyph::AccidentalFlat,
+    Glyph::AccidentalFlatArabic,
+    Glyph::AccidentalFlatEqualTempered,
+    Glyph::AccidentalFlatLoweredStockhausen,
+    Glyph::AccidentalFlatOneArrowDown,
+    Glyph::AccidentalFlatOneArrowUp,
+    Glyph::AccidentalFlatRaisedStockhausen,
+    Glyph::AccidentalFlatRepeatedLineStockhausen,
+    Glyph::AccidentalFlatRepeatedSpaceStockhausen,
+    Glyph::AccidentalFlatThreeArrowsDown,
+    Glyph::AccidentalFlatThreeArrowsUp,
+    Glyph::AccidentalFlatTurned,
+    Glyph::AccidentalFlatTwoArrowsDown,
+    Glyph::AccidentalFlatTwoArrowsUp,
+    Glyph::AccidentalHabaFlatQuarterToneHigher,
+    Glyph::AccidentalHabaFlatThreeQuarterTonesLower,
+    Glyph::AccidentalHabaQuarterToneHigher,
+    Glyph::AccidentalHabaQuarterToneLower,
+    Glyph::AccidentalHabaSharpQuarterToneLower,
+    Glyph::AccidentalHabaSharpThreeQuarterTonesHigher,
+    Glyph::AccidentalHalfSharpArrowDown,
+    Glyph::AccidentalHalfSharpArrowUp,
+    Glyph::AccidentalJohnston13,
+    Glyph::AccidentalJohnston31,
+    Glyph::AccidentalJohnstonDown,
+    Glyph::AccidentalJohnstonEl,
+    Glyph::AccidentalJohnstonMinus,
+    Glyph::AccidentalJohnstonPlus,
+    Glyph::AccidentalJohnstonSeven,
+    Glyph::AccidentalJohnstonUp,
+    Glyph::AccidentalKomaFlat,
+    Glyph::AccidentalKomaSharp,
+    Glyph::AccidentalKoron,
+    Glyph::AccidentalKucukMucennebFlat,
+    Glyph::AccidentalKucukMucennebSharp,
+    Glyph::AccidentalLargeDoubleSharp,
+    Glyph::AccidentalLowerOneSeptimalComma,
+    Glyph::AccidentalLowerOneTridecimalQuartertone,
+    Glyph::AccidentalLowerOneUndecimalQuartertone,
+    Glyph::AccidentalLowerTwoSeptimalCommas,
+    Glyph::AccidentalLoweredStockhausen,
+    Glyph::AccidentalNarrowReversedFlat,
+    Glyph::AccidentalNarrowReversedFlatAndFlat,
+    Glyph::AccidentalNatural,
+    Glyph::AccidentalNaturalArabic,
+    Glyph::AccidentalNaturalEqualTempered,
+    Glyph::AccidentalNaturalFlat,
+    Glyph::AccidentalNaturalLoweredStockhausen,
+    Glyph::AccidentalNaturalOneArrowDown,
+    Glyph::AccidentalNaturalOneArrowUp,
+    Glyph::AccidentalNaturalRaisedStockhausen,
+    Glyph::AccidentalNaturalReversed,
+    Glyph::AccidentalNaturalSharp,
+    Glyph::AccidentalNaturalThreeArrowsDown,
+    Glyph::AccidentalNaturalThreeArrowsUp,
+    Glyph::AccidentalNaturalTwoArrowsDown,
+    Glyph::AccidentalNaturalTwoArrowsUp,
+    Glyph::AccidentalOneAndAHalfSharpsArrowDown,
+    Glyph::AccidentalOneAndAHalfSharpsArrowUp,
+    Glyph::AccidentalOneQuarterToneFlatFerneyhough,
+    Glyph::AccidentalOneQuarterToneFlatStockhausen,
+    Glyph::AccidentalOneQuarterToneSharpFerneyhough,
+    Glyph::AccidentalOneQuarterToneSharpStockhausen,
+    Glyph::AccidentalOneThirdToneFlatFerneyhough,
+    Glyph::AccidentalOneThirdToneSharpFerneyhough,
+    Glyph::AccidentalParensLeft,
+    Glyph::AccidentalParensRight,
+    Glyph::AccidentalQuarterFlatEqualTempered,
+    Glyph::AccidentalQuarterSharpEqualTempered,
+    Glyph::AccidentalQuarterToneFlat4,
+    Glyph::AccidentalQuarterToneFlatArabic,
+    Glyph::AccidentalQuarterToneFlatArrowUp,
+    Glyph::AccidentalQuarterToneFlatFilledReversed,
+    Glyph::AccidentalQuarterToneFlatNaturalArrowDown,
+    Glyph::AccidentalQuarterToneFlatPenderecki,
+    Glyph::AccidentalQuarterToneFlatStein,
+    Glyph::AccidentalQuarterToneFlatVanBlankenburg,
+    Glyph::AccidentalQuarterToneSharp4,
+    Glyph::AccidentalQuarterToneSharpArabic,
+    Glyph::AccidentalQuarterToneSharpArrowDown,
+    Glyph::AccidentalQuarterToneSharpBusotti,
+    Glyph::AccidentalQuarterToneSharpNaturalArrowUp,
+    Glyph::AccidentalQuarterToneSharpStein,
+    Glyph::AccidentalQuarterToneSharpWiggle,
+    Glyph::AccidentalRaiseOneSeptimalComma,
+    Glyph::AccidentalRaiseOneTridecimalQuartertone,
+    Glyph::AccidentalRaiseOneUndecimalQuartertone,
+    Glyph::AccidentalRaiseTwoSeptimalCommas,
+    Glyph::AccidentalRaisedStockhausen,
+    Glyph::AccidentalReversedFlatAndFlatArrowDown,
+    Glyph::AccidentalReversedFlatAndFlatArrowUp,
+    Glyph::AccidentalReversedFlatArrowDown,
+    Glyph::AccidentalReversedFlatArrowUp,
+    Glyph::AccidentalSharp,
+    Glyph::AccidentalSharpArabic,
+    Glyph::AccidentalSharpEqualTempered,
+    Glyph::AccidentalSharpLoweredStockhausen,
+    Glyph::AccidentalSharpOneArrowDown,
+    Glyph::AccidentalSharpOneArrowUp,
+    Glyph::AccidentalSharpOneHorizontalStroke,
+    Glyph::AccidentalSharpRaisedStockhausen,
+    Glyph::AccidentalSharpRepeatedLineStockhausen,
+    Glyph::AccidentalSharpRepeatedSpaceStockhausen,
+    Glyph::AccidentalSharpReversed,
+    Glyph::AccidentalSharpSharp,
+    Glyph::AccidentalSharpThreeArrowsDown,
+    Glyph::AccidentalSharpThreeArrowsUp,
+    Glyph::AccidentalSharpTwoArrowsDown,
+    Glyph::AccidentalSharpTwoArrowsUp,
+    Glyph::AccidentalSims12Down,
+    Glyph::AccidentalSims12Up,
+    Glyph::AccidentalSims4Down,
+    Glyph::AccidentalSims4Up,
+    Glyph::AccidentalSims6Down,
+    Glyph::AccidentalSims6Up,
+    Glyph::AccidentalSori,
+    Glyph::AccidentalTavenerFlat,
+    Glyph::AccidentalTavenerSharp,
+    Glyph::AccidentalThreeQuarterTonesFlatArabic,
+    Glyph::AccidentalThreeQuarterTonesFlatArrowDown,
+    Glyph::AccidentalThreeQuarterTonesFlatArrowUp,
+    Glyph::AccidentalThreeQuarterTonesFlatCouper,
+    Glyph::AccidentalThreeQuarterTonesFlatGrisey,
+    Glyph::AccidentalThreeQuarterTonesFlatTartini,
+    Glyph::AccidentalThreeQuarterTonesFlatZimmermann,
+    Glyph::AccidentalThreeQuarterTonesSharpArabic,
+    Glyph::AccidentalThreeQuarterTonesSharpArrowDown,
+    Glyph::AccidentalThreeQuarterTonesSharpArrowUp,
+    Glyph::AccidentalThreeQuarterTonesSharpBusotti,
+    Glyph::AccidentalThreeQuarterTonesSharpStein,
+    Glyph::AccidentalThreeQuarterTonesSharpStockhausen,
+    Glyph::AccidentalTripleFlat,
+    Glyph::AccidentalTripleSharp,
+    Glyph::AccidentalTwoThirdTonesFlatFerneyhough,
+    Glyph::AccidentalTwoThirdTonesSharpFerneyhough,
+    Glyph::AccidentalUpsAndDownsDown,
+    Glyph::AccidentalUpsAndDownsLess,
+    Glyph::AccidentalUpsAndDownsMore,
+    Glyph::AccidentalUpsAndDownsUp,
+    Glyph::AccidentalWilsonMinus,
+    Glyph::AccidentalWilsonPlus,
+    Glyph::AccidentalWyschnegradsky10twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky10twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky11twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky11twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky1twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky1twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky2twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky2twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky3twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky3twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky4twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky4twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky5twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky5twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky6twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky6twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky7twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky7twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky8twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky8twelfthsSharp,
+    Glyph::AccidentalWyschnegradsky9twelfthsFlat,
+    Glyph::AccidentalWyschnegradsky9twelfthsSharp,
+    Glyph::AccidentalXenakisOneThirdToneSharp,
+    Glyph::AccidentalXenakisTwoThirdTonesSharp,
+    Glyph::AnalyticsChoralmelodie,
+    Glyph::AnalyticsEndStimme,
+    Glyph::AnalyticsHauptrhythmus,
+    Glyph::AnalyticsHauptstimme,
+    Glyph::AnalyticsInversion1,
+    Glyph::AnalyticsNebenstimme,
+    Glyph::AnalyticsStartStimme,
+    Glyph::AnalyticsTheme,
+    Glyph::AnalyticsTheme1,
+    Glyph::AnalyticsThemeInversion,
+    Glyph::AnalyticsThemeRetrograde,
+    Glyph::AnalyticsThemeRetrogradeInversion,
+    Glyph::Arpeggiato,
+    Glyph::ArpeggiatoDown,
+    Glyph::ArpeggiatoUp,
+    Glyph::ArrowBlackDown,
+    Glyph::ArrowBlackDownLeft,
+    Glyph::ArrowBlackDownRight,
+    Glyph::ArrowBlackLeft,
+    Glyph::ArrowBlackRight,
+    Glyph::ArrowBlackUp,
+    Glyph::ArrowBlackUpLeft,
+    Glyph::ArrowBlackUpRight,
+    Glyph::ArrowOpenDown,
+    Glyph::ArrowOpenDownLeft,
+    Glyph::ArrowOpenDownRight,
+    Glyph::ArrowOpenLeft,
+    Glyph::ArrowOpenRight,
+    Glyph::ArrowOpenUp,
+    Glyph::ArrowOpenUpLeft,
+    Glyph::ArrowOpenUpRight,
+    Glyph::ArrowWhiteDown,
+    Glyph::ArrowWhiteDownLeft,
+    Glyph::ArrowWhiteDownRight,
+    Glyph::ArrowWhiteLeft,
+    Glyph::ArrowWhiteRight,
+    Glyph::ArrowWhiteUp,
+    Glyph::ArrowWhiteUpLeft,
+    Glyph::ArrowWhiteUpRight,
+    Glyph::ArrowheadBlackDown,
+    Glyph::ArrowheadBlackDownLeft,
+    Glyph::ArrowheadBlackDownRight,
+    Glyph::ArrowheadBlackLeft,
+    Glyph::ArrowheadBlackRight,
+    Glyph::ArrowheadBlackUp,
+    Glyph::ArrowheadBlackUpLeft,
+    Glyph::ArrowheadBlackUpRight,
+    Glyph::ArrowheadOpenDown,
+    Glyph::ArrowheadOpenDownLeft,
+    Glyph::ArrowheadOpenDownRight,
+    Glyph::ArrowheadOpenLeft,
+    Glyph::ArrowheadOpenRight,
+    Glyph::ArrowheadOpenUp,
+    Glyph::ArrowheadOpenUpLeft,
+    Glyph::ArrowheadOpenUpRight,
+    Glyph::ArrowheadWhiteDown,
+    Glyph::ArrowheadWhiteDownLeft,
+    Glyph::ArrowheadWhiteDownRight,
+    Glyph::ArrowheadWhiteLeft,
+    Glyph::ArrowheadWhiteRight,
+    Glyph::ArrowheadWhiteUp,
+    Glyph::ArrowheadWhiteUpLeft,
+    Glyph::ArrowheadWhiteUpRight,
+    Glyph::ArticAccentAbove,
+    Glyph::ArticAccentBelow,
+    Glyph::ArticAccentStaccatoAbove,
+    Glyph::ArticAccentStaccatoBelow,
+    Glyph::ArticLaissezVibrerAbove,
+    Glyph::ArticLaissezVibrerBelow,
+    Glyph::ArticMarcatoAbove,
+    Glyph::ArticMarcatoBelow,
+    Glyph::ArticMarcatoStaccatoAbove,
+    Glyph::ArticMarcatoStaccatoBelow,
+    Glyph::ArticMarcatoTenutoAbove,
+    Glyph::ArticMarcatoTenutoBelow,
+    Glyph::ArticSoftAccentAbove,
+    Glyph::ArticSoftAccentBelow,
+    Glyph::ArticSoftAccentStaccatoAbove,
+    Glyph::ArticSoftAccentStaccatoBelow,
+    Glyph::ArticSoftAccentTenutoAbove,
+    Glyph::ArticSoftAccentTenutoBelow,
+    Glyph::ArticSoftAccentTenutoStaccatoAbove,
+    Glyph::ArticSoftAccentTenutoStaccatoBelow,
+    Glyph::ArticStaccatissimoAbove,
+    Glyph::ArticStaccatissimoBelow,
+    Glyph::ArticStaccatissimoStrokeAbove,
+    Glyph::ArticStaccatissimoStrokeBelow,
+    Glyph::ArticStaccatissimoWedgeAbove,
+    Glyph::ArticStaccatissimoWedgeBelow,
+    Glyph::ArticStaccatoAbove,
+    Glyph::ArticStaccatoBelow,
+    Glyph::ArticStressAbove,
+    Glyph::ArticStressBelow,
+    Glyph::ArticTenutoAbove,
+    Glyph::ArticTenutoAccentAbove,
+    Glyph::ArticTenutoAccentBelow,
+    Glyph::ArticTenutoBelow,
+    Glyph::ArticTenutoStaccatoAbove,
+    Glyph::ArticTenutoStaccatoBelow,
+    Glyph::ArticUnstressAbove,
+    Glyph::ArticUnstressBelow,
+    Glyph::AugmentationDot,
+    Glyph::BarlineDashed,
+    Glyph::BarlineDotted,
+    Glyph::BarlineDouble,
+    Glyph::BarlineFinal,
+    Glyph::BarlineHeavy,
+    Glyph::BarlineHeavyHeavy,
+    Glyph::BarlineReverseFinal,
+    Glyph::BarlineShort,
+    Glyph::BarlineSingle,
+    Glyph::BarlineTick,
+    Glyph::BeamAccelRit1,
+    Glyph::BeamAccelRit10,
+    Glyph::BeamAccelRit11,
+    Glyph::BeamAccelRit12,
+    Glyph::BeamAccelRit13,
+    Glyph::BeamAccelRit14,
+    Glyph::BeamAccelRit15,
+    Glyph::BeamAccelRit2,
+    Glyph::BeamAccelRit3,
+    Glyph::BeamAccelRit4,
+    Glyph::BeamAccelRit5,
+    Glyph::BeamAccelRit6,
+    Glyph::BeamAccelRit7,
+    Glyph::BeamAccelRit8,
+    Glyph::BeamAccelRit9,
+    Glyph::BeamAccelRitFinal,
+    Glyph::Brace,
+    Glyph::Bracket,
+    Glyph::BracketBottom,
+    Glyph::BracketTop,
+    Glyph::BrassBend,
+    Glyph::BrassDoitLong,
+    Glyph::BrassDoitMedium,
+    Glyph::BrassDoitShort,
+    Glyph::BrassFallLipLong,
+    Glyph::BrassFallLipMedium,
+    Glyph::BrassFallLipShort,
+    Glyph::BrassFallRoughLong,
+    Glyph::BrassFallRoughMedium,
+    Glyph::BrassFallRoughShort,
+    Glyph::BrassFallSmoothLong,
+    Glyph::BrassFallSmoothMedium,
+    Glyph::BrassFallSmoothShort,
+    Glyph::BrassFlip,
+    Glyph::BrassHarmonMuteClosed,
+    Glyph::BrassHarmonMuteStemHalfLeft,
+    Glyph::BrassHarmonMuteStemHalfRight,
+    Glyph::BrassHarmonMuteStemOpen,
+    Glyph::BrassJazzTurn,
+    Glyph::BrassLiftLong,
+    Glyph::BrassLiftMedium,
+    Glyph::BrassLiftShort,
+    Glyph::BrassLiftSmoothLong,
+    Glyph::BrassLiftSmoothMedium,
+    Glyph::BrassLiftSmoothShort,
+    Glyph::BrassMuteClosed,
+    Glyph::BrassMuteHalfClosed,
+    Glyph::BrassMuteOpen,
+    Glyph::BrassPlop,
+    Glyph::BrassScoop,
+    Glyph::BrassSmear,
+    Glyph::BrassValveTrill,
+    Glyph::BreathMarkComma,
+    Glyph::BreathMarkSalzedo,
+    Glyph::BreathMarkTick,
+    Glyph::BreathMarkUpbow,
+    Glyph::BridgeClef,
+    Glyph::BuzzRoll,
+    Glyph::CClef,
+    Glyph::CClef8Vb,
+    Glyph::CClefArrowDown,
+    Glyph::CClefArrowUp,
+    Glyph::CClefChange,
+    Glyph::CClefCombining,
+    Glyph::CClefReversed,
+    Glyph::CClefSquare,
+    Glyph::Caesura,
+    Glyph::CaesuraCurved,
+    Glyph::CaesuraShort,
+    Glyph::CaesuraSingleStroke,
+    Glyph::CaesuraThick,
+    Glyph::ChantAccentusAbove,
+    Glyph::ChantAccentusBelow,
+    Glyph::ChantAuctumAsc,
+    Glyph::ChantAuctumDesc,
+    Glyph::ChantAugmentum,
+    Glyph::ChantCaesura,
+    Glyph::ChantCclef,
+    Glyph::ChantCirculusAbove,
+    Glyph::ChantCirculusBelow,
+    Glyph::ChantConnectingLineAsc2nd,
+    Glyph::ChantConnectingLineAsc3Rd,
+    Glyph::ChantConnectingLineAsc4th,
+    Glyph::ChantConnectingLineAsc5th,
+    Glyph::ChantConnectingLineAsc6th,
+    Glyph::ChantCustosStemDownPosHigh,
+    Glyph::ChantCustosStemDownPosHighest,
+    Glyph::ChantCustosStemDownPosMiddle,
+    Glyph::ChantCustosStemUpPosLow,
+    Glyph::ChantCustosStemUpPosLowest,
+    Glyph::ChantCustosStemUpPosMiddle,
+    Glyph::ChantDeminutumLower,
+    Glyph::ChantDeminutumUpper,
+    Glyph::ChantDivisioFinalis,
+    Glyph::ChantDivisioMaior,
+    Glyph::ChantDivisioMaxima,
+    Glyph::ChantDivisioMinima,
+    Glyph::ChantEntryLineAsc2nd,
+    Glyph::ChantEntryLineAsc3Rd,
+    Glyph::ChantEntryLineAsc4th,
+    Glyph::ChantEntryLineAsc5th,
+    Glyph::ChantEntryLineAsc6th,
+    Glyph::ChantEpisema,
+    Glyph::ChantFclef,
+    Glyph::ChantIctusAbove,
+    Glyph::ChantIctusBelow,
+    Glyph::ChantLigaturaDesc2nd,
+    Glyph::ChantLigaturaDesc3Rd,
+    Glyph::ChantLigaturaDesc4th,
+    Glyph::ChantLigaturaDesc5th,
+    Glyph::ChantOriscusAscending,
+    Glyph::ChantOriscusDescending,
+    Glyph::ChantOriscusLiquescens,
+    Glyph::ChantPodatusLower,
+    Glyph::ChantPodatusUpper,
+    Glyph::ChantPunctum,
+    Glyph::ChantPunctumCavum,
+    Glyph::ChantPunctumDeminutum,
+    Glyph::ChantPunctumInclinatum,
+    Glyph::ChantPunctumInclinatumAuctum,
+    Glyph::ChantPunctumInclinatumDeminutum,
+    Glyph::ChantPunctumLinea,
+    Glyph::ChantPunctumLineaCavum,
+    Glyph::ChantPunctumVirga,
+    Glyph::ChantPunctumVirgaReversed,
+    Glyph::ChantQuilisma,
+    Glyph::ChantSemicirculusAbove,
+    Glyph::ChantSemicirculusBelow,
+    Glyph::ChantStaff,
+    Glyph::ChantStaffNarrow,
+    Glyph::ChantStaffWide,
+    Glyph::ChantStrophicus,
+    Glyph::ChantStrophicusAuctus,
+    Glyph::ChantStrophicusLiquescens2nd,
+    Glyph::ChantStrophicusLiquescens3Rd,
+    Glyph::ChantStrophicusLiquescens4th,
+    Glyph::ChantStrophicusLiquescens5th,
+    Glyph::ChantVirgula,
+    Glyph::Clef15,
+    Glyph::Clef8,
+    Glyph::ClefChangeCombining,
+    Glyph::Coda,
+    Glyph::CodaSquare,
+    Glyph::ConductorBeat2Compound,
+    Glyph::ConductorBeat2Simple,
+    Glyph::ConductorBeat3Compound,
+    Glyph::ConductorBeat3Simple,
+    Glyph::ConductorBeat4Compound,
+    Glyph::ConductorBeat4Simple,
+    Glyph::ConductorLeftBeat,
+    Glyph::ConductorRightBeat,
+    Glyph::ConductorStrongBeat,
+    Glyph::ConductorUnconducted,
+    Glyph::ConductorWeakBeat,
+    Glyph::ControlBeginBeam,
+    Glyph::ControlBeginPhrase,
+    Glyph::ControlBeginSlur,
+    Glyph::ControlBeginTie,
+    Glyph::ControlEndBeam,
+    Glyph::ControlEndPhrase,
+    Glyph::ControlEndSlur,
+    Glyph::ControlEndTie,
+    Glyph::CsymAccidentalDoubleFlat,
+    Glyph::CsymAccidentalDoubleSharp,
+    Glyph::CsymAccidentalFlat,
+    Glyph::CsymAccidentalNatural,
+    Glyph::CsymAccidentalSharp,
+    Glyph::CsymAccidentalTripleFlat,
+    Glyph::CsymAccidentalTripleSharp,
+    Glyph::CsymAlteredBassSlash,
+    Glyph::CsymAugmented,
+    Glyph::CsymBracketLeftTall,
+    Glyph::CsymBracketRightTall,
+    Glyph::CsymDiagonalArrangementSlash,
+    Glyph::CsymDiminished,
+    Glyph::CsymHalfDiminished,
+    Glyph::CsymMajorSeventh,
+    Glyph::CsymMinor,
+    Glyph::CsymParensLeftTall,
+    Glyph::CsymParensLeftVeryTall,
+    Glyph::CsymParensRightTall,
+    Glyph::CsymParensRightVeryTall,
+    Glyph::CurlewSign,
+    Glyph::DaCapo,
+    Glyph::DalSegno,
+    Glyph::DaseianExcellentes1,
+    Glyph::DaseianExcellentes2,
+    Glyph::DaseianExcellentes3,
+    Glyph::DaseianExcellentes4,
+    Glyph::DaseianFinales1,
+    Glyph::DaseianFinales2,
+    Glyph::DaseianFinales3,
+    Glyph::DaseianFinales4,
+    Glyph::DaseianGraves1,
+    Glyph::DaseianGraves2,
+    Glyph::DaseianGraves3,
+    Glyph::DaseianGraves4,
+    Glyph::DaseianResidua1,
+    Glyph::DaseianResidua2,
+    Glyph::DaseianSuperiores1,
+    Glyph::DaseianSuperiores2,
+    Glyph::DaseianSuperiores3,
+    Glyph::DaseianSuperiores4,
+    Glyph::DoubleLateralRollStevens,
+    Glyph::DoubleTongueAbove,
+    Glyph::DoubleTongueBelow,
+    Glyph::DynamicCombinedSeparatorColon,
+    Glyph::DynamicCombinedSeparatorHyphen,
+    Glyph::DynamicCombinedSeparatorSlash,
+    Glyph::DynamicCombinedSeparatorSpace,
+    Glyph::DynamicCrescendoHairpin,
+    Glyph::DynamicDiminuendoHairpin,
+    Glyph::DynamicFf,
+    Glyph::DynamicFff,
+    Glyph::DynamicFfff,
+    Glyph::DynamicFffff,
+    Glyph::DynamicFfffff,
+    Glyph::DynamicForte,
+    Glyph::DynamicFortePiano,
+    Glyph::DynamicForzando,
+    Glyph::DynamicHairpinBracketLeft,
+    Glyph::DynamicHairpinBracketRight,
+    Glyph::DynamicHairpinParenthesisLeft,
+    Glyph::DynamicHairpinParenthesisRight,
+    Glyph::DynamicMessaDiVoce,
+    Glyph::DynamicMezzo,
+    Glyph::DynamicMf,
+    Glyph::DynamicMp,
+    Glyph::DynamicNiente,
+    Glyph::DynamicNienteForHairpin,
+    Glyph::DynamicPf,
+    Glyph::DynamicPiano,
+    Glyph::DynamicPp,
+    Glyph::DynamicPpp,
+    Glyph::DynamicPppp,
+    Glyph::DynamicPpppp,
+    Glyph::DynamicPppppp,
+    Glyph::DynamicRinforzando,
+    Glyph::DynamicRinforzando1,
+    Glyph::DynamicRinforzando2,
+    Glyph::DynamicSforzando,
+    Glyph::DynamicSforzando1,
+    Glyph::DynamicSforzandoPianissimo,
+    Glyph::DynamicSforzandoPiano,
+    Glyph::DynamicSforzato,
+    Glyph::DynamicSforzatoFf,
+    Glyph::DynamicSforzatoPiano,
+    Glyph::DynamicZ,
+    Glyph::ElecAudioChannelsEight,
+    Glyph::ElecAudioChannelsFive,
+    Glyph::ElecAudioChannelsFour,
+    Glyph::ElecAudioChannelsOne,
+    Glyph::ElecAudioChannelsSeven,
+    Glyph::ElecAudioChannelsSix,
+    Glyph::ElecAudioChannelsThreeFrontal,
+    Glyph::ElecAudioChannelsThreeSurround,
+    Glyph::ElecAudioChannelsTwo,
+    Glyph::ElecAudioIn,
+    Glyph::ElecAudioMono,
+    Glyph::ElecAudioOut,
+    Glyph::ElecAudioStereo,
+    Glyph::ElecCamera,
+    Glyph::ElecDataIn,
+    Glyph::ElecDataOut,
+    Glyph::ElecDisc,
+    Glyph::ElecDownload,
+    Glyph::ElecEject,
+    Glyph::ElecFastForward,
+    Glyph::ElecHeadphones,
+    Glyph::ElecHeadset,
+    Glyph::ElecLineIn,
+    Glyph::ElecLineOut,
+    Glyph::ElecLoop,
+    Glyph::ElecLoudspeaker,
+    Glyph::ElecMicrophone,
+    Glyph::ElecMicrophoneMute,
+    Glyph::ElecMicrophoneUnmute,
+    Glyph::ElecMidiController0,
+    Glyph::ElecMidiController100,
+    Glyph::ElecMidiController20,
+    Glyph::ElecMidiController40,
+    Glyph::ElecMidiController60,
+    Glyph::ElecMidiController80,
+    Glyph::ElecMidiIn,
+    Glyph::ElecMidiOut,
+    Glyph::ElecMixingConsole,
+    Glyph::ElecMonitor,
+    Glyph::ElecMute,
+    Glyph::ElecPause,
+    Glyph::ElecPlay,
+    Glyph::ElecPowerOnOff,
+    Glyph::ElecProjector,
+    Glyph::ElecReplay,
+    Glyph::ElecRewind,
+    Glyph::ElecShuffle,
+    Glyph::ElecSkipBackwards,
+    Glyph::ElecSkipForwards,
+    Glyph::ElecStop,
+    Glyph::ElecTape,
+    Glyph::ElecUnmute,
+    Glyph::ElecUpload,
+    Glyph::ElecUsb,
+    Glyph::ElecVideoCamera,
+    Glyph::ElecVideoIn,
+    Glyph::ElecVideoOut,
+    Glyph::ElecVolumeFader,
+    Glyph::ElecVolumeFaderThumb,
+    Glyph::ElecVolumeLevel0,
+    Glyph::ElecVolumeLevel100,
+    Glyph::ElecVolumeLevel20,
+    Glyph::ElecVolumeLevel40,
+    Glyph::ElecVolumeLevel60,
+    Glyph::ElecVolumeLevel80,
+    Glyph::FClef,
+    Glyph::FClef15Ma,
+    Glyph::FClef15Mb,
+    Glyph::FClef8Va,
+    Glyph::FClef8Vb,
+    Glyph::FClefArrowDown,
+    Glyph::FClefArrowUp,
+    Glyph::FClefChange,
+    Glyph::FClefReversed,
+    Glyph::FClefTurned,
+    Glyph::FermataAbove,
+    Glyph::FermataBelow,
+    Glyph::FermataLongAbove,
+    Glyph::FermataLongBelow,
+    Glyph::FermataLongHenzeAbove,
+    Glyph::FermataLongHenzeBelow,
+    Glyph::FermataShortAbove,
+    Glyph::FermataShortBelow,
+    Glyph::FermataShortHenzeAbove,
+    Glyph::FermataShortHenzeBelow,
+    Glyph::FermataVeryLongAbove,
+    Glyph::FermataVeryLongBelow,
+    Glyph::FermataVeryShortAbove,
+    Glyph::FermataVeryShortBelow,
+    Glyph::Figbass0,
+    Glyph::Figbass1,
+    Glyph::Figbass2,
+    Glyph::Figbass2Raised,
+    Glyph::Figbass3,
+    Glyph::Figbass4,
+    Glyph::Figbass4Raised,
+    Glyph::Figbass5,
+    Glyph::Figbass5Raised1,
+    Glyph::Figbass5Raised2,
+    Glyph::Figbass5Raised3,
+    Glyph::Figbass6,
+    Glyph::Figbass6Raised,
+    Glyph::Figbass6Raised2,
+    Glyph::Figbass7,
+    Glyph::Figbass7Diminished,
+    Glyph::Figbass7Raised1,
+    Glyph::Figbass7Raised2,
+    Glyph::Figbass8,
+    Glyph::Figbass9,
+    Glyph::Figbass9Raised,
+    Glyph::FigbassBracketLeft,
+    Glyph::FigbassBracketRight,
+    Glyph::FigbassCombiningLowering,
+    Glyph::FigbassCombiningRaising,
+    Glyph::FigbassDoubleFlat,
+    Glyph::FigbassDoubleSharp,
+    Glyph::FigbassFlat,
+    Glyph::FigbassNatural,
+    Glyph::FigbassParensLeft,
+    Glyph::FigbassParensRight,
+    Glyph::FigbassPlus,
+    Glyph::FigbassSharp,
+    Glyph::FigbassTripleFlat,
+    Glyph::FigbassTripleSharp,
+    Glyph::Fingering0,
+    Glyph::Fingering0Italic,
+    Glyph::Fingering1,
+    Glyph::Fingering1Italic,
+    Glyph::Fingering2,
+    Glyph::Fingering2Italic,
+    Glyph::Fingering3,
+    Glyph::Fingering3Italic,
+    Glyph::Fingering4,
+    Glyph::Fingering4Italic,
+    Glyph::Fingering5,
+    Glyph::Fingering5Italic,
+    Glyph::Fingering6,
+    Glyph::Fingering6Italic,
+    Glyph::Fingering7,
+    Glyph::Fingering7Italic,
+    Glyph::Fingering8,
+    Glyph::Fingering8Italic,
+    Glyph::Fingering9,
+    Glyph::Fingering9Italic,
+    Glyph::FingeringALower,
+    Glyph::FingeringCLower,
+    Glyph::FingeringELower,
+    Glyph::FingeringILower,
+    Glyph::FingeringLeftBracket,
+    Glyph::FingeringLeftBracketItalic,
+    Glyph::FingeringLeftParenthesis,
+    Glyph::FingeringLeftParenthesisItalic,
+    Glyph::FingeringMLower,
+    Glyph::FingeringMultipleNotes,
+    Glyph::FingeringOLower,
+    Glyph::FingeringPLower,
+    Glyph::FingeringQLower,
+    Glyph::FingeringRightBracket,
+    Glyph::FingeringRightBracketItalic,
+    Glyph::FingeringRightParenthesis,
+    Glyph::FingeringRightParenthesisItalic,
+    Glyph::FingeringSLower,
+    Glyph::FingeringSeparatorMiddleDot,
+    Glyph::FingeringSeparatorMiddleDotWhite,
+    Glyph::FingeringSeparatorSlash,
+    Glyph::FingeringSubstitutionAbove,
+    Glyph::FingeringSubstitutionBelow,
+    Glyph::FingeringSubstitutionDash,
+    Glyph::FingeringTLower,
+    Glyph::FingeringTUpper,
+    Glyph::FingeringXLower,
+    Glyph::Flag1024thDown,
+    Glyph::Flag1024thUp,
+    Glyph::Flag128thDown,
+    Glyph::Flag128thUp,
+    Glyph::Flag16thDown,
+    Glyph::Flag16thUp,
+    Glyph::Flag256thDown,
+    Glyph::Flag256thUp,
+    Glyph::Flag32ndDown,
+    Glyph::Flag32ndUp,
+    Glyph::Flag512thDown,
+    Glyph::Flag512thUp,
+    Glyph::Flag64thDown,
+    Glyph::Flag64thUp,
+    Glyph::Flag8thDown,
+    Glyph::Flag8thUp,
+    Glyph::FlagInternalDown,
+    Glyph::FlagInternalUp,
+    Glyph::Fretboard3String,
+    Glyph::Fretboard3StringNut,
+    Glyph::Fretboard4String,
+    Glyph::Fretboard4StringNut,
+    Glyph::Fretboard5String,
+    Glyph::Fretboard5StringNut,
+    Glyph::Fretboard6String,
+    Glyph::Fretboard6StringNut,
+    Glyph::FretboardFilledCircle,
+    Glyph::FretboardO,
+    Glyph::FretboardX,
+    Glyph::FunctionAngleLeft,
+    Glyph::FunctionAngleRight,
+    Glyph::FunctionBracketLeft,
+    Glyph::FunctionBracketRight,
+    Glyph::FunctionDLower,
+    Glyph::FunctionDUpper,
+    Glyph::FunctionDd,
+    Glyph::FunctionEight,
+    Glyph::FunctionFUpper,
+    Glyph::FunctionFive,
+    Glyph::FunctionFour,
+    Glyph::FunctionGLower,
+    Glyph::FunctionGUpper,
+    Glyph::FunctionGreaterThan,
+    Glyph::FunctionILower,
+    Glyph::FunctionIUpper,
+    Glyph::FunctionKLower,
+    Glyph::FunctionKUpper,
+    Glyph::FunctionLLower,
+    Glyph::FunctionLUpper,
+    Glyph::FunctionLessThan,
+    Glyph::FunctionMLower,
+    Glyph::FunctionMUpper,
+    Glyph::FunctionMinus,
+    Glyph::FunctionNLower,
+    Glyph::FunctionNUpper,
+    Glyph::FunctionNUpperSuperscript,
+    Glyph::FunctionNine,
+    Glyph::FunctionOne,
+    Glyph::FunctionPLower,
+    Glyph::FunctionPUpper,
+    Glyph::FunctionParensLeft,
+    Glyph::FunctionParensRight,
+    Glyph::FunctionPlus,
+    Glyph::FunctionRLower,
+    Glyph::FunctionRepetition1,
+    Glyph::FunctionRepetition2,
+    Glyph::FunctionRing,
+    Glyph::FunctionSLower,
+    Glyph::FunctionSUpper,
+    Glyph::FunctionSeven,
+    Glyph::FunctionSix,
+    Glyph::FunctionSlashedDd,
+    Glyph::FunctionSsLower,
+    Glyph::FunctionSsUpper,
+    Glyph::FunctionTLower,
+    Glyph::FunctionTUpper,
+    Glyph::FunctionThree,
+    Glyph::FunctionTwo,
+    Glyph::FunctionVLower,
+    Glyph::FunctionVUpper,
+    Glyph::FunctionZero,
+    Glyph::GClef,
+    Glyph::GClef15Ma,
+    Glyph::GClef15Mb,
+    Glyph::GClef8Va,
+    Glyph::GClef8Vb,
+    Glyph::GClef8VbCClef,
+    Glyph::GClef8VbOld,
+    Glyph::GClef8VbParens,
+    Glyph::GClefArrowDown,
+    Glyph::GClefArrowUp,
+    Glyph::GClefChange,
+    Glyph::GClefLigatedNumberAbove,
+    Glyph::GClefLigatedNumberBelow,
+    Glyph::GClefReversed,
+    Glyph::GClefTurned,
+    Glyph::GlissandoDown,
+    Glyph::GlissandoUp,
+    Glyph::GraceNoteAcciaccaturaStemDown,
+    Glyph::GraceNoteAcciaccaturaStemUp,
+    Glyph::GraceNoteAppoggiaturaStemDown,
+    Glyph::GraceNoteAppoggiaturaStemUp,
+    Glyph::GraceNoteSlashStemDown,
+    Glyph::GraceNoteSlashStemUp,
+    Glyph::GuitarBarreFull,
+    Glyph::GuitarBarreHalf,
+    Glyph::GuitarClosePedal,
+    Glyph::GuitarFadeIn,
+    Glyph::GuitarFadeOut,
+    Glyph::GuitarGolpe,
+    Glyph::GuitarHalfOpenPedal,
+    Glyph::GuitarLeftHandTapping,
+    Glyph::GuitarOpenPedal,
+    Glyph::GuitarRightHandTapping,
+    Glyph::GuitarShake,
+    Glyph::GuitarString0,
+    Glyph::GuitarString1,
+    Glyph::GuitarString10,
+    Glyph::GuitarString11,
+    Glyph::GuitarString12,
+    Glyph::GuitarString13,
+    Glyph::GuitarString2,
+    Glyph::GuitarString3,
+    Glyph::GuitarString4,
+    Glyph::GuitarString5,
+    Glyph::GuitarString6,
+    Glyph::GuitarString7,
+    Glyph::GuitarString8,
+    Glyph::GuitarString9,
+    Glyph::GuitarStrumDown,
+    Glyph::GuitarStrumUp,
+    Glyph::GuitarVibratoBarDip,
+    Glyph::GuitarVibratoBarScoop,
+    Glyph::GuitarVibratoStroke,
+    Glyph::GuitarVolumeSwell,
+    Glyph::GuitarWideVibratoStroke,
+    Glyph::HandbellsBelltree,
+    Glyph::HandbellsDamp3,
+    Glyph::HandbellsEcho1,
+    Glyph::HandbellsEcho2,
+    Glyph::HandbellsGyro,
+    Glyph::HandbellsHandMartellato,
+    Glyph::HandbellsMalletBellOnTable,
+    Glyph::HandbellsMalletBellSuspended,
+    Glyph::HandbellsMalletLft,
+    Glyph::HandbellsMartellato,
+    Glyph::HandbellsMartellatoLift,
+    Glyph::HandbellsMutedMartellato,
+    Glyph::HandbellsPluckLift,
+    Glyph::HandbellsSwing,
+    Glyph::HandbellsSwingDown,
+    Glyph::HandbellsSwingUp,
+    Glyph::HandbellsTablePairBells,
+    Glyph::HandbellsTableSingleBell,
+    Glyph::HarpMetalRod,
+    Glyph::HarpPedalCentered,
+    Glyph::HarpPedalDivider,
+    Glyph::HarpPedalLowered,
+    Glyph::HarpPedalRaised,
+    Glyph::HarpSalzedoAeolianAscending,
+    Glyph::HarpSalzedoAeolianDescending,
+    Glyph::HarpSalzedoDampAbove,
+    Glyph::HarpSalzedoDampBelow,
+    Glyph::HarpSalzedoDampBothHands,
+    Glyph::HarpSalzedoDampLowStrings,
+    Glyph::HarpSalzedoFluidicSoundsLeft,
+    Glyph::HarpSalzedoFluidicSoundsRight,
+    Glyph::HarpSalzedoIsolatedSounds,
+    Glyph::HarpSalzedoMetallicSounds,
+    Glyph::HarpSalzedoMetallicSoundsOneString,
+    Glyph::HarpSalzedoMuffleTotally,
+    Glyph::HarpSalzedoOboicFlux,
+    Glyph::HarpSalzedoPlayUpperEnd,
+    Glyph::HarpSalzedoSlideWithSuppleness,
+    Glyph::HarpSalzedoSnareDrum,
+    Glyph::HarpSalzedoTamTamSounds,
+    Glyph::HarpSalzedoThunderEffect,
+    Glyph::HarpSalzedoTimpanicSounds,
+    Glyph::HarpSalzedoWhistlingSounds,
+    Glyph::HarpStringNoiseStem,
+    Glyph::HarpTuningKey,
+    Glyph::HarpTuningKeyGlissando,
+    Glyph::HarpTuningKeyHandle,
+    Glyph::HarpTuningKeyShank,
+    Glyph::IndianDrumClef,
+    Glyph::KahnBackChug,
+    Glyph::KahnBackFlap,
+    Glyph::KahnBackRiff,
+    Glyph::KahnBackRip,
+    Glyph::KahnBallChange,
+    Glyph::KahnBallDig,
+    Glyph::KahnBrushBackward,
+    Glyph::KahnBrushForward,
+    Glyph::KahnChug,
+    Glyph::KahnClap,
+    Glyph::KahnDoubleSnap,
+    Glyph::KahnDoubleWing,
+    Glyph::KahnDrawStep,
+    Glyph::KahnDrawTap,
+    Glyph::KahnFlam,
+    Glyph::KahnFlap,
+    Glyph::KahnFlapStep,
+    Glyph::KahnFlat,
+    Glyph::KahnFleaHop,
+    Glyph::KahnFleaTap,
+    Glyph::KahnGraceTap,
+    Glyph::KahnGraceTapChange,
+    Glyph::KahnGraceTapHop,
+    Glyph::KahnGraceTapStamp,
+    Glyph::KahnHeel,
+    Glyph::KahnHeelChange,
+    Glyph::KahnHeelClick,
+    Glyph::KahnHeelDrop,
+    Glyph::KahnHeelStep,
+    Glyph::KahnHeelTap,
+    Glyph::KahnHop,
+    Glyph::KahnJumpApart,
+    Glyph::KahnJumpTogether,
+    Glyph::KahnKneeInward,
+    Glyph::KahnKneeOutward,
+    Glyph::KahnLeap,
+    Glyph::KahnLeapFlatFoot,
+    Glyph::KahnLeapHeelClick,
+    Glyph::KahnLeftCatch,
+    Glyph::KahnLeftCross,
+    Glyph::KahnLeftFoot,
+    Glyph::KahnLeftToeStrike,
+    Glyph::KahnLeftTurn,
+    Glyph::KahnOverTheTop,
+    Glyph::KahnOverTheTopTap,
+    Glyph::KahnPull,
+    Glyph::KahnPush,
+    Glyph::KahnRiff,
+    Glyph::KahnRiffle,
+    Glyph::KahnRightCatch,
+    Glyph::KahnRightCross,
+    Glyph::KahnRightFoot,
+    Glyph::KahnRightToeStrike,
+    Glyph::KahnRightTurn,
+    Glyph::KahnRip,
+    Glyph::KahnRipple,
+    Glyph::KahnScrape,
+    Glyph::KahnScuff,
+    Glyph::KahnScuffle,
+    Glyph::KahnShuffle,
+    Glyph::KahnSlam,
+    Glyph::KahnSlap,
+    Glyph::KahnSlideStep,
+    Glyph::KahnSlideTap,
+    Glyph::KahnSnap,
+    Glyph::KahnStamp,
+    Glyph::KahnStampStamp,
+    Glyph::KahnStep,
+    Glyph::KahnStepStamp,
+    Glyph::KahnStomp,
+    Glyph::KahnStompBrush,
+    Glyph::KahnTap,
+    Glyph::KahnToe,
+    Glyph::KahnToeClick,
+    Glyph::KahnToeDrop,
+    Glyph::KahnToeStep,
+    Glyph::KahnToeTap,
+    Glyph::KahnTrench,
+    Glyph::KahnWing,
+    Glyph::KahnWingChange,
+    Glyph::KahnZank,
+    Glyph::KahnZink,
+    Glyph::KeyboardBebung2DotsAbove,
+    Glyph::KeyboardBebung2DotsBelow,
+    Glyph::KeyboardBebung3DotsAbove,
+    Glyph::KeyboardBebung3DotsBelow,
+    Glyph::KeyboardBebung4DotsAbove,
+    Glyph::KeyboardBebung4DotsBelow,
+    Glyph::KeyboardLeftPedalPictogram,
+    Glyph::KeyboardMiddlePedalPictogram,
+    Glyph::KeyboardPedalD,
+    Glyph::KeyboardPedalDot,
+    Glyph::KeyboardPedalE,
+    Glyph::KeyboardPedalHalf,
+    Glyph::KeyboardPedalHalf2,
+    Glyph::KeyboardPedalHalf3,
+    Glyph::KeyboardPedalHeel1,
+    Glyph::KeyboardPedalHeel2,
+    Glyph::KeyboardPedalHeel3,
+    Glyph::KeyboardPedalHeelToToe,
+    Glyph::KeyboardPedalHeelToe,
+    Glyph::KeyboardPedalHookEnd,
+    Glyph::KeyboardPedalHookStart,
+    Glyph::KeyboardPedalHyphen,
+    Glyph::KeyboardPedalP,
+    Glyph::KeyboardPedalParensLeft,
+    Glyph::KeyboardPedalParensRight,
+    Glyph::KeyboardPedalPed,
+    Glyph::KeyboardPedalS,
+    Glyph::KeyboardPedalSost,
+    Glyph::KeyboardPedalToe1,
+    Glyph::KeyboardPedalToe2,
+    Glyph::KeyboardPedalToeToHeel,
+    Glyph::KeyboardPedalUp,
+    Glyph::KeyboardPedalUpNotch,
+    Glyph::KeyboardPedalUpSpecial,
+    Glyph::KeyboardPlayWithLh,
+    Glyph::KeyboardPlayWithLhEnd,
+    Glyph::KeyboardPlayWithRh,
+    Glyph::KeyboardPlayWithRhEnd,
+    Glyph::KeyboardPluckInside,
+    Glyph::KeyboardRightPedalPictogram,
+    Glyph::KievanAccidentalFlat,
+    Glyph::KievanAccidentalSharp,
+    Glyph::KievanAugmentationDot,
+    Glyph::KievanCClef,
+    Glyph::KievanEndingSymbol,
+    Glyph::KievanNote8thStemDown,
+    Glyph::KievanNote8thStemUp,
+    Glyph::KievanNoteBeam,
+    Glyph::KievanNoteHalfStaffLine,
+    Glyph::KievanNoteHalfStaffSpace,
+    Glyph::KievanNoteQuarterStemDown,
+    Glyph::KievanNoteQuarterStemUp,
+    Glyph::KievanNoteReciting,
+    Glyph::KievanNoteWhole,
+    Glyph::KievanNoteWholeFinal,
+    Glyph::KodalyHandDo,
+    Glyph::KodalyHandFa,
+    Glyph::KodalyHandLa,
+    Glyph::KodalyHandMi,
+    Glyph::KodalyHandRe,
+    Glyph::KodalyHandSo,
+    Glyph::KodalyHandTi,
+    Glyph::LeftRepeatSmall,
+    Glyph::LegerLine,
+    Glyph::LegerLineNarrow,
+    Glyph::LegerLineWide,
+    Glyph::LuteBarlineEndRepeat,
+    Glyph::LuteBarlineFinal,
+    Glyph::LuteBarlineStartRepeat,
+    Glyph::LuteDuration16th,
+    Glyph::LuteDuration32nd,
+    Glyph::LuteDuration8th,
+    Glyph::LuteDurationDoubleWhole,
+    Glyph::LuteDurationHalf,
+    Glyph::LuteDurationQuarter,
+    Glyph::LuteDurationWhole,
+    Glyph::LuteFingeringRhFirst,
+    Glyph::LuteFingeringRhSecond,
+    Glyph::LuteFingeringRhThird,
+    Glyph::LuteFingeringRhThumb,
+    Glyph::LuteFrench10thCourse,
+    Glyph::LuteFrench7thCourse,
+    Glyph::LuteFrench8thCourse,
+    Glyph::LuteFrench9thCourse,
+    Glyph::LuteFrenchAppoggiaturaAbove,
+    Glyph::LuteFrenchAppoggiaturaBelow,
+    Glyph::LuteFrenchFretA,
+    Glyph::LuteFrenchFretB,
+    Glyph::LuteFrenchFretC,
+    Glyph::LuteFrenchFretD,
+    Glyph::LuteFrenchFretE,
+    Glyph::LuteFrenchFretF,
+    Glyph::LuteFrenchFretG,
+    Glyph::LuteFrenchFretH,
+    Glyph::LuteFrenchFretI,
+    Glyph::LuteFrenchFretK,
+    Glyph::LuteFrenchFretL,
+    Glyph::LuteFrenchFretM,
+    Glyph::LuteFrenchFretN,
+    Glyph::LuteFrenchMordentInverted,
+    Glyph::LuteFrenchMordentLower,
+    Glyph::LuteFrenchMordentUpper,
+    Glyph::LuteGermanALower,
+    Glyph::LuteGermanAUpper,
+    Glyph::LuteGermanBLower,
+    Glyph::LuteGermanBUpper,
+    Glyph::LuteGermanCLower,
+    Glyph::LuteGermanCUpper,
+    Glyph::LuteGermanDLower,
+    Glyph::LuteGermanDUpper,
+    Glyph::LuteGermanELower,
+    Glyph::LuteGermanEUpper,
+    Glyph::LuteGermanFLower,
+    Glyph::LuteGermanFUpper,
+    Glyph::LuteGermanGLower,
+    Glyph::LuteGermanGUpper,
+    Glyph::LuteGermanHLower,
+    Glyph::LuteGermanHUpper,
+    Glyph::LuteGermanILower,
+    Glyph::LuteGermanIUpper,
+    Glyph::LuteGermanKLower,
+    Glyph::LuteGermanKUpper,
+    Glyph::LuteGermanLLower,
+    Glyph::LuteGermanLUpper,
+    Glyph::LuteGermanMLower,
+    Glyph::LuteGermanMUpper,
+    Glyph::LuteGermanNLower,
+    Glyph::LuteGermanNUpper,
+    Glyph::LuteGermanOLower,
+    Glyph::LuteGermanPLower,
+    Glyph::LuteGermanQLower,
+    Glyph::LuteGermanRLower,
+    Glyph::LuteGermanSLower,
+    Glyph::LuteGermanTLower,
+    Glyph::LuteGermanVLower,
+    Glyph::LuteGermanXLower,
+    Glyph::LuteGermanYLower,
+    Glyph::LuteGermanZLower,
+    Glyph::LuteItalianClefCSolFaUt,
+    Glyph::LuteItalianClefFFaUt,
+    Glyph::LuteItalianFret0,
+    Glyph::LuteItalianFret1,
+    Glyph::LuteItalianFret2,
+    Glyph::LuteItalianFret3,
+    Glyph::LuteItalianFret4,
+    Glyph::LuteItalianFret5,
+    Glyph::LuteItalianFret6,
+    Glyph::LuteItalianFret7,
+    Glyph::LuteItalianFret8,
+    Glyph::LuteItalianFret9,
+    Glyph::LuteItalianHoldFinger,
+    Glyph::LuteItalianHoldNote,
+    Glyph::LuteItalianReleaseFinger,
+    Glyph::LuteItalianTempoFast,
+    Glyph::LuteItalianTempoNeitherFastNorSlow,
+    Glyph::LuteItalianTempoSlow,
+    Glyph::LuteItalianTempoSomewhatFast,
+    Glyph::LuteItalianTempoVerySlow,
+    Glyph::LuteItalianTimeTriple,
+    Glyph::LuteItalianTremolo,
+    Glyph::LuteItalianVibrato,
+    Glyph::LuteStaff6Lines,
+    Glyph::LuteStaff6LinesNarrow,
+    Glyph::LuteStaff6LinesWide,
+    Glyph::LyricsElision,
+    Glyph::LyricsElisionNarrow,
+    Glyph::LyricsElisionWide,
+    Glyph::LyricsHyphenBaseline,
+    Glyph::LyricsHyphenBaselineNonBreaking,
+    Glyph::LyricsTextRepeat,
+    Glyph::MedRenFlatHardB,
+    Glyph::MedRenFlatSoftB,
+    Glyph::MedRenFlatWithDot,
+    Glyph::MedRenGClefCmn,
+    Glyph::MedRenLiquescenceCmn,
+    Glyph::MedRenLiquescentAscCmn,
+    Glyph::MedRenLiquescentDescCmn,
+    Glyph::MedRenNatural,
+    Glyph::MedRenNaturalWithCross,
+    Glyph::MedRenOriscusCmn,
+    Glyph::MedRenPlicaCmn,
+    Glyph::MedRenPunctumCmn,
+    Glyph::MedRenQuilismaCmn,
+    Glyph::MedRenSharpCroix,
+    Glyph::MedRenStrophicusCmn,
+    Glyph::MensuralAlterationSign,
+    Glyph::MensuralBlackBrevis,
+    Glyph::MensuralBlackBrevisVoid,
+    Glyph::MensuralBlackDragma,
+    Glyph::MensuralBlackLonga,
+    Glyph::MensuralBlackMaxima,
+    Glyph::MensuralBlackMinima,
+    Glyph::MensuralBlackMinimaVoid,
+    Glyph::MensuralBlackSemibrevis,
+    Glyph::MensuralBlackSemibrevisCaudata,
+    Glyph::MensuralBlackSemibrevisOblique,
+    Glyph::MensuralBlackSemibrevisVoid,
+    Glyph::MensuralBlackSemiminima,
+    Glyph::MensuralCclef,
+    Glyph::MensuralCclefPetrucciPosHigh,
+    Glyph::MensuralCclefPetrucciPosHighest,
+    Glyph::MensuralCclefPetrucciPosLow,
+    Glyph::MensuralCclefPetrucciPosLowest,
+    Glyph::MensuralCclefPetrucciPosMiddle,
+    Glyph::MensuralColorationEndRound,
+    Glyph::MensuralColorationEndSquare,
+    Glyph::MensuralColorationStartRound,
+    Glyph::MensuralColorationStartSquare,
+    Glyph::MensuralCombStemDiagonal,
+    Glyph::MensuralCombStemDown,
+    Glyph::MensuralCombStemDownFlagExtended,
+    Glyph::MensuralCombStemDownFlagFlared,
+    Glyph::MensuralCombStemDownFlagFusa,
+    Glyph::MensuralCombStemDownFlagLeft,
+    Glyph::MensuralCombStemDownFlagRight,
+    Glyph::MensuralCombStemDownFlagSemiminima,
+    Glyph::MensuralCombStemUp,
+    Glyph::MensuralCombStemUpFlagExtended,
+    Glyph::MensuralCombStemUpFlagFlared,
+    Glyph::MensuralCombStemUpFlagFusa,
+    Glyph::MensuralCombStemUpFlagLeft,
+    Glyph::MensuralCombStemUpFlagRight,
+    Glyph::MensuralCombStemUpFlagSemiminima,
+    Glyph::MensuralCustosCheckmark,
+    Glyph::MensuralCustosDown,
+    Glyph::MensuralCustosTurn,
+    Glyph::MensuralCustosUp,
+    Glyph::MensuralFclef,
+    Glyph::MensuralFclefPetrucci,
+    Glyph::MensuralGclef,
+    Glyph::MensuralGclefPetrucci,
+    Glyph::MensuralModusImperfectumVert,
+    Glyph::MensuralModusPerfectumVert,
+    Glyph::MensuralNoteheadLongaBlack,
+    Glyph::MensuralNoteheadLongaBlackVoid,
+    Glyph::MensuralNoteheadLongaVoid,
+    Glyph::MensuralNoteheadLongaWhite,
+    Glyph::MensuralNoteheadMaximaBlack,
+    Glyph::MensuralNoteheadMaximaBlackVoid,
+    Glyph::MensuralNoteheadMaximaVoid,
+    Glyph::MensuralNoteheadMaximaWhite,
+    Glyph::MensuralNoteheadMinimaWhite,
+    Glyph::MensuralNoteheadSemibrevisBlack,
+    Glyph::MensuralNoteheadSemibrevisBlackVoid,
+    Glyph::MensuralNoteheadSemibrevisBlackVoidTurned,
+    Glyph::MensuralNoteheadSemibrevisVoid,
+    Glyph::MensuralNoteheadSemiminimaWhite,
+    Glyph::MensuralObliqueAsc2ndBlack,
+    Glyph::MensuralObliqueAsc2ndBlackVoid,
+    Glyph::MensuralObliqueAsc2ndVoid,
+    Glyph::MensuralObliqueAsc2ndWhite,
+    Glyph::MensuralObliqueAsc3RdBlack,
+    Glyph::MensuralObliqueAsc3RdBlackVoid,
+    Glyph::MensuralObliqueAsc3RdVoid,
+    Glyph::MensuralObliqueAsc3RdWhite,
+    Glyph::MensuralObliqueAsc4thBlack,
+    Glyph::MensuralObliqueAsc4thBlackVoid,
+    Glyph::MensuralObliqueAsc4thVoid,
+    Glyph::MensuralObliqueAsc4thWhite,
+    Glyph::MensuralObliqueAsc5thBlack,
+    Glyph::MensuralObliqueAsc5thBlackVoid,
+    Glyph::MensuralObliqueAsc5thVoid,
+    Glyph::MensuralObliqueAsc5thWhite,
+    Glyph::MensuralObliqueDesc2ndBlack,
+    Glyph::MensuralObliqueDesc2ndBlackVoid,
+    Glyph::MensuralObliqueDesc2ndVoid,
+    Glyph::MensuralObliqueDesc2ndWhite,
+    Glyph::MensuralObliqueDesc3RdBlack,
+    Glyph::MensuralObliqueDesc3RdBlackVoid,
+    Glyph::MensuralObliqueDesc3RdVoid,
+    Glyph::MensuralObliqueDesc3RdWhite,
+    Glyph::MensuralObliqueDesc4thBlack,
+    Glyph::MensuralObliqueDesc4thBlackVoid,
+    Glyph::MensuralObliqueDesc4thVoid,
+    Glyph::MensuralObliqueDesc4thWhite,
+    Glyph::MensuralObliqueDesc5thBlack,
+    Glyph::MensuralObliqueDesc5thBlackVoid,
+    Glyph::MensuralObliqueDesc5thVoid,
+    Glyph::MensuralObliqueDesc5thWhite,
+    Glyph::MensuralProlation1,
+    Glyph::MensuralProlation10,
+    Glyph::MensuralProlation11,
+    Glyph::MensuralProlation2,
+    Glyph::MensuralProlation3,
+    Glyph::MensuralProlation4,
+    Glyph::MensuralProlation5,
+    Glyph::MensuralProlation6,
+    Glyph::MensuralProlation7,
+    Glyph::MensuralProlation8,
+    Glyph::MensuralProlation9,
+    Glyph::MensuralProlationCombiningDot,
+    Glyph::MensuralProlationCombiningDotVoid,
+    Glyph::MensuralProlationCombiningStroke,
+    Glyph::MensuralProlationCombiningThreeDots,
+    Glyph::MensuralProlationCombiningThreeDotsTri,
+    Glyph::MensuralProlationCombiningTwoDots,
+    Glyph::MensuralProportion1,
+    Glyph::MensuralProportion2,
+    Glyph::MensuralProportion3,
+    Glyph::MensuralProportion4,
+    Glyph::MensuralProportion5,
+    Glyph::MensuralProportion6,
+    Glyph::MensuralProportion7,
+    Glyph::MensuralProportion8,
+    Glyph::MensuralProportion9,
+    Glyph::MensuralProportionMajor,
+    Glyph::MensuralProportionMinor,
+    Glyph::MensuralProportionProportioDupla1,
+    Glyph::MensuralProportionProportioDupla2,
+    Glyph::MensuralProportionProportioQuadrupla,
+    Glyph::MensuralProportionProportioTripla,
+    Glyph::MensuralProportionTempusPerfectum,
+    Glyph::MensuralRestBrevis,
+    Glyph::MensuralRestFusa,
+    Glyph::MensuralRestLongaImperfecta,
+    Glyph::MensuralRestLongaPerfecta,
+    Glyph::MensuralRestMaxima,
+    Glyph::MensuralRestMinima,
+    Glyph::MensuralRestSemibrevis,
+    Glyph::MensuralRestSemifusa,
+    Glyph::MensuralRestSemiminima,
+    Glyph::MensuralSignumDown,
+    Glyph::MensuralSignumUp,
+    Glyph::MensuralTempusImperfectumHoriz,
+    Glyph::MensuralTempusPerfectumHoriz,
+    Glyph::MensuralWhiteBrevis,
+    Glyph::MensuralWhiteFusa,
+    Glyph::MensuralWhiteLonga,
+    Glyph::MensuralWhiteMaxima,
+    Glyph::MensuralWhiteMinima,
+    Glyph::MensuralWhiteSemibrevis,
+    Glyph::MensuralWhiteSemiminima,
+    Glyph::MetAugmentationDot,
+    Glyph::MetNote1024thDown,
+    Glyph::MetNote1024thUp,
+    Glyph::MetNote128thDown,
+    Glyph::MetNote128thUp,
+    Glyph::MetNote16thDown,
+    Glyph::MetNote16thUp,
+    Glyph::MetNote256thDown,
+    Glyph::MetNote256thUp,
+    Glyph::MetNote32ndDown,
+    Glyph::MetNote32ndUp,
+    Glyph::MetNote512thDown,
+    Glyph::MetNote512thUp,
+    Glyph::MetNote64thDown,
+    Glyph::MetNote64thUp,
+    Glyph::MetNote8thDown,
+    Glyph::MetNote8thUp,
+    Glyph::MetNoteDoubleWhole,
+    Glyph::MetNoteDoubleWholeSquare,
+    Glyph::MetNoteHalfDown,
+    Glyph::MetNoteHalfUp,
+    Glyph::MetNoteQuarterDown,
+    Glyph::MetNoteQuarterUp,
+    Glyph::MetNoteWhole,
+    Glyph::MetricModulationArrowLeft,
+    Glyph::MetricModulationArrowRight,
+    Glyph::MiscDoNotCopy,
+    Glyph::MiscDoNotPhotocopy,
+    Glyph::MiscEyeglasses,
+    Glyph::Note1024thDown,
+    Glyph::Note1024thUp,
+    Glyph::Note128thDown,
+    Glyph::Note128thUp,
+    Glyph::Note16thDown,
+    Glyph::Note16thUp,
+    Glyph::Note256thDown,
+    Glyph::Note256thUp,
+    Glyph::Note32ndDown,
+    Glyph::Note32ndUp,
+    Glyph::Note512thDown,
+    Glyph::Note512thUp,
+    Glyph::Note64thDown,
+    Glyph::Note64thUp,
+    Glyph::Note8thDown,
+    Glyph::Note8thUp,
+    Glyph::NoteABlack,
+    Glyph::NoteAFlatBlack,
+    Glyph::NoteAFlatHalf,
+    Glyph::NoteAFlatWhole,
+    Glyph::NoteAHalf,
+    Glyph::NoteASharpBlack,
+    Glyph::NoteASharpHalf,
+    Glyph::NoteASharpWhole,
+    Glyph::NoteAWhole,
+    Glyph::NoteBBlack,
+    Glyph::NoteBFlatBlack,
+    Glyph::NoteBFlatHalf,
+    Glyph::NoteBFlatWhole,
+    Glyph::NoteBHalf,
+    Glyph::NoteBSharpBlack,
+    Glyph::NoteBSharpHalf,
+    Glyph::NoteBSharpWhole,
+    Glyph::NoteBWhole,
+    Glyph::NoteCBlack,
+    Glyph::NoteCFlatBlack,
+    Glyph::NoteCFlatHalf,
+    Glyph::NoteCFlatWhole,
+    Glyph::NoteCHalf,
+    Glyph::NoteCSharpBlack,
+    Glyph::NoteCSharpHalf,
+    Glyph::NoteCSharpWhole,
+    Glyph::NoteCWhole,
+    Glyph::NoteDBlack,
+    Glyph::NoteDFlatBlack,
+    Glyph::NoteDFlatHalf,
+    Glyph::NoteDFlatWhole,
+    Glyph::NoteDHalf,
+    Glyph::NoteDSharpBlack,
+    Glyph::NoteDSharpHalf,
+    Glyph::NoteDSharpWhole,
+    Glyph::NoteDWhole,
+    Glyph::NoteDiBlack,
+    Glyph::NoteDiHalf,
+    Glyph::NoteDiWhole,
+    Glyph::NoteDoBlack,
+    Glyph::NoteDoHalf,
+    Glyph::NoteDoWhole,
+    Glyph::NoteDoubleWhole,
+    Glyph::NoteDoubleWholeSquare,
+    Glyph::NoteEBlack,
+    Glyph::NoteEFlatBlack,
+    Glyph::NoteEFlatHalf,
+    Glyph::NoteEFlatWhole,
+    Glyph::NoteEHalf,
+    Glyph::NoteESharpBlack,
+    Glyph::NoteESharpHalf,
+    Glyph::NoteESharpWhole,
+    Glyph::NoteEWhole,
+    Glyph::NoteEmptyBlack,
+    Glyph::NoteEmptyHalf,
+    Glyph::NoteEmptyWhole,
+    Glyph::NoteFBlack,
+    Glyph::NoteFFlatBlack,
+    Glyph::NoteFFlatHalf,
+    Glyph::NoteFFlatWhole,
+    Glyph::NoteFHalf,
+    Glyph::NoteFSharpBlack,
+    Glyph::NoteFSharpHalf,
+    Glyph::NoteFSharpWhole,
+    Glyph::NoteFWhole,
+    Glyph::NoteFaBlack,
+    Glyph::NoteFaHalf,
+    Glyph::NoteFaWhole,
+    Glyph::NoteFiBlack,
+    Glyph::NoteFiHalf,
+    Glyph::NoteFiWhole,
+    Glyph::NoteGBlack,
+    Glyph::NoteGFlatBlack,
+    Glyph::NoteGFlatHalf,
+    Glyph::NoteGFlatWhole,
+    Glyph::NoteGHalf,
+    Glyph::NoteGSharpBlack,
+    Glyph::NoteGSharpHalf,
+    Glyph::NoteGSharpWhole,
+    Glyph::NoteGWhole,
+    Glyph::NoteHBlack,
+    Glyph::NoteHHalf,
+    Glyph::NoteHSharpBlack,
+    Glyph::NoteHSharpHalf,
+    Glyph::NoteHSharpWhole,
+    Glyph::NoteHWhole,
+    Glyph::NoteHalfDown,
+    Glyph::NoteHalfUp,
+    Glyph::NoteLaBlack,
+    Glyph::NoteLaHalf,
+    Glyph::NoteLaWhole,
+    Glyph::NoteLeBlack,
+    Glyph::NoteLeHalf,
+    Glyph::NoteLeWhole,
+    Glyph::NoteLiBlack,
+    Glyph::NoteLiHalf,
+    Glyph::NoteLiWhole,
+    Glyph::NoteMeBlack,
+    Glyph::NoteMeHalf,
+    Glyph::NoteMeWhole,
+    Glyph::NoteMiBlack,
+    Glyph::NoteMiHalf,
+    Glyph::NoteMiWhole,
+    Glyph::NoteQuarterDown,
+    Glyph::NoteQuarterUp,
+    Glyph::NoteRaBlack,
+    Glyph::NoteRaHalf,
+    Glyph::NoteRaWhole,
+    Glyph::NoteReBlack,
+    Glyph::NoteReHalf,
+    Glyph::NoteReWhole,
+    Glyph::NoteRiBlack,
+    Glyph::NoteRiHalf,
+    Glyph::NoteRiWhole,
+    Glyph::NoteSeBlack,
+    Glyph::NoteSeHalf,
+    Glyph::NoteSeWhole,
+    Glyph::NoteShapeArrowheadLeftBlack,
+    Glyph::NoteShapeArrowheadLeftDoubleWhole,
+    Glyph::NoteShapeArrowheadLeftWhite,
+    Glyph::NoteShapeDiamondBlack,
+    Glyph::NoteShapeDiamondDoubleWhole,
+    Glyph::NoteShapeDiamondWhite,
+    Glyph::NoteShapeIsoscelesTriangleBlack,
+    Glyph::NoteShapeIsoscelesTriangleDoubleWhole,
+    Glyph::NoteShapeIsoscelesTriangleWhite,
+    Glyph::NoteShapeKeystoneBlack,
+    Glyph::NoteShapeKeystoneDoubleWhole,
+    Glyph::NoteShapeKeystoneWhite,
+    Glyph::NoteShapeMoonBlack,
+    Glyph::NoteShapeMoonDoubleWhole,
+    Glyph::NoteShapeMoonLeftBlack,
+    Glyph::NoteShapeMoonLeftDoubleWhole,
+    Glyph::NoteShapeMoonLeftWhite,
+    Glyph::NoteShapeMoonWhite,
+    Glyph::NoteShapeQuarterMoonBlack,
+    Glyph::NoteShapeQuarterMoonDoubleWhole,
+    Glyph::NoteShapeQuarterMoonWhite,
+    Glyph::NoteShapeRoundBlack,
+    Glyph::NoteShapeRoundDoubleWhole,
+    Glyph::NoteShapeRoundWhite,
+    Glyph::NoteShapeSquareBlack,
+    Glyph::NoteShapeSquareDoubleWhole,
+    Glyph::NoteShapeSquareWhite,
+    Glyph::NoteShapeTriangleLeftBlack,
+    Glyph::NoteShapeTriangleLeftDoubleWhole,
+    Glyph::NoteShapeTriangleLeftWhite,
+    Glyph::NoteShapeTriangleRightBlack,
+    Glyph::NoteShapeTriangleRightDoubleWhole,
+    Glyph::NoteShapeTriangleRightWhite,
+    Glyph::NoteShapeTriangleRoundBlack,
+    Glyph::NoteShapeTriangleRoundDoubleWhole,
+    Glyph::NoteShapeTriangleRoundLeftBlack,
+    Glyph::NoteShapeTriangleRoundLeftDoubleWhole,
+    Glyph::NoteShapeTriangleRoundLeftWhite,
+    Glyph::NoteShapeTriangleRoundWhite,
+    Glyph::NoteShapeTriangleUpBlack,
+    Glyph::NoteShapeTriangleUpDoubleWhole,
+    Glyph::NoteShapeTriangleUpWhite,
+    Glyph::NoteSiBlack,
+    Glyph::NoteSiHalf,
+    Glyph::NoteSiWhole,
+    Glyph::NoteSoBlack,
+    Glyph::NoteSoHalf,
+    Glyph::NoteSoWhole,
+    Glyph::NoteTeBlack,
+    Glyph::NoteTeHalf,
+    Glyph::NoteTeWhole,
+    Glyph::NoteTiBlack,
+    Glyph::NoteTiHalf,
+    Glyph::NoteTiWhole,
+    Glyph::NoteWhole,
+    Glyph::NoteheadBlack,
+    Glyph::NoteheadCircleSlash,
+    Glyph::NoteheadCircleX,
+    Glyph::NoteheadCircleXDoubleWhole,
+    Glyph::NoteheadCircleXHalf,
+    Glyph::NoteheadCircleXWhole,
+    Glyph::NoteheadCircledBlack,
+    Glyph::NoteheadCircledBlackLarge,
+    Glyph::NoteheadCircledDoubleWhole,
+    Glyph::NoteheadCircledDoubleWholeLarge,
+    Glyph::NoteheadCircledHalf,
+    Glyph::NoteheadCircledHalfLarge,
+    Glyph::NoteheadCircledWhole,
+    Glyph::NoteheadCircledWholeLarge,
+    Glyph::NoteheadCircledXLarge,
+    Glyph::NoteheadClusterDoubleWhole2nd,
+    Glyph::NoteheadClusterDoubleWhole3Rd,
+    Glyph::NoteheadClusterDoubleWholeBottom,
+    Glyph::NoteheadClusterDoubleWholeMiddle,
+    Glyph::NoteheadClusterDoubleWholeTop,
+    Glyph::NoteheadClusterHalf2nd,
+    Glyph::NoteheadClusterHalf3Rd,
+    Glyph::NoteheadClusterHalfBottom,
+    Glyph::NoteheadClusterHalfMiddle,
+    Glyph::NoteheadClusterHalfTop,
+    Glyph::NoteheadClusterQuarter2nd,
+    Glyph::NoteheadClusterQuarter3Rd,
+    Glyph::NoteheadClusterQuarterBottom,
+    Glyph::NoteheadClusterQuarterMiddle,
+    Glyph::NoteheadClusterQuarterTop,
+    Glyph::NoteheadClusterRoundBlack,
+    Glyph::NoteheadClusterRoundWhite,
+    Glyph::NoteheadClusterSquareBlack,
+    Glyph::NoteheadClusterSquareWhite,
+    Glyph::NoteheadClusterWhole2nd,
+    Glyph::NoteheadClusterWhole3Rd,
+    Glyph::NoteheadClusterWholeBottom,
+    Glyph::NoteheadClusterWholeMiddle,
+    Glyph::NoteheadClusterWholeTop,
+    Glyph::NoteheadCowellEleventhNoteSeriesHalf,
+    Glyph::NoteheadCowellEleventhNoteSeriesWhole,
+    Glyph::NoteheadCowellEleventhSeriesBlack,
+    Glyph::NoteheadCowellFifteenthNoteSeriesBlack,
+    Glyph::NoteheadCowellFifteenthNoteSeriesHalf,
+    Glyph::NoteheadCowellFifteenthNoteSeriesWhole,
+    Glyph::NoteheadCowellFifthNoteSeriesBlack,
+    Glyph::NoteheadCowellFifthNoteSeriesHalf,
+    Glyph::NoteheadCowellFifthNoteSeriesWhole,
+    Glyph::NoteheadCowellNinthNoteSeriesBlack,
+    Glyph::NoteheadCowellNinthNoteSeriesHalf,
+    Glyph::NoteheadCowellNinthNoteSeriesWhole,
+    Glyph::NoteheadCowellSeventhNoteSeriesBlack,
+    Glyph::NoteheadCowellSeventhNoteSeriesHalf,
+    Glyph::NoteheadCowellSeventhNoteSeriesWhole,
+    Glyph::NoteheadCowellThirdNoteSeriesBlack,
+    Glyph::NoteheadCowellThirdNoteSeriesHalf,
+    Glyph::NoteheadCowellThirdNoteSeriesWhole,
+    Glyph::NoteheadCowellThirteenthNoteSeriesBlack,
+    Glyph::NoteheadCowellThirteenthNoteSeriesHalf,
+    Glyph::NoteheadCowellThirteenthNoteSeriesWhole,
+    Glyph::NoteheadDiamondBlack,
+    Glyph::NoteheadDiamondBlackOld,
+    Glyph::NoteheadDiamondBlackWide,
+    Glyph::NoteheadDiamondClusterBlack2nd,
+    Glyph::NoteheadDiamondClusterBlack3Rd,
+    Glyph::NoteheadDiamondClusterBlackBottom,
+    Glyph::NoteheadDiamondClusterBlackMiddle,
+    Glyph::NoteheadDiamondClusterBlackTop,
+    Glyph::NoteheadDiamondClusterWhite2nd,
+    Glyph::NoteheadDiamondClusterWhite3Rd,
+    Glyph::NoteheadDiamondClusterWhiteBottom,
+    Glyph::NoteheadDiamondClusterWhiteMiddle,
+    Glyph::NoteheadDiamondClusterWhiteTop,
+    Glyph::NoteheadDiamondDoubleWhole,
+    Glyph::NoteheadDiamondDoubleWholeOld,
+    Glyph::NoteheadDiamondHalf,
+    Glyph::NoteheadDiamondHalfFilled,
+    Glyph::NoteheadDiamondHalfOld,
+    Glyph::NoteheadDiamondHalfWide,
+    Glyph::NoteheadDiamondOpen,
+    Glyph::NoteheadDiamondWhite,
+    Glyph::NoteheadDiamondWhiteWide,
+    Glyph::NoteheadDiamondWhole,
+    Glyph::NoteheadDiamondWholeOld,
+    Glyph::NoteheadDoubleWhole,
+    Glyph::NoteheadDoubleWholeSquare,
+    Glyph::NoteheadDoubleWholeWithX,
+    Glyph::NoteheadHalf,
+    Glyph::NoteheadHalfFilled,
+    Glyph::NoteheadHalfWithX,
+    Glyph::NoteheadHeavyX,
+    Glyph::NoteheadHeavyXHat,
+    Glyph::NoteheadLargeArrowDownBlack,
+    Glyph::NoteheadLargeArrowDownDoubleWhole,
+    Glyph::NoteheadLargeArrowDownHalf,
+    Glyph::NoteheadLargeArrowDownWhole,
+    Glyph::NoteheadLargeArrowUpBlack,
+    Glyph::NoteheadLargeArrowUpDoubleWhole,
+    Glyph::NoteheadLargeArrowUpHalf,
+    Glyph::NoteheadLargeArrowUpWhole,
+    Glyph::NoteheadMoonBlack,
+    Glyph::NoteheadMoonWhite,
+    Glyph::NoteheadNancarrowSine,
+    Glyph::NoteheadNull,
+    Glyph::NoteheadParenthesis,
+    Glyph::NoteheadParenthesisLeft,
+    Glyph::NoteheadParenthesisRight,
+    Glyph::NoteheadPlusBlack,
+    Glyph::NoteheadPlusDoubleWhole,
+    Glyph::NoteheadPlusHalf,
+    Glyph::NoteheadPlusWhole,
+    Glyph::NoteheadRectangularClusterBlackBottom,
+    Glyph::NoteheadRectangularClusterBlackMiddle,
+    Glyph::NoteheadRectangularClusterBlackTop,
+    Glyph::NoteheadRectangularClusterWhiteBottom,
+    Glyph::NoteheadRectangularClusterWhiteMiddle,
+    Glyph::NoteheadRectangularClusterWhiteTop,
+    Glyph::NoteheadRoundBlack,
+    Glyph::NoteheadRoundBlackDoubleSlashed,
+    Glyph::NoteheadRoundBlackLarge,
+    Glyph::NoteheadRoundBlackSlashed,
+    Glyph::NoteheadRoundBlackSlashedLarge,
+    Glyph::NoteheadRoundWhite,
+    Glyph::NoteheadRoundWhiteDoubleSlashed,
+    Glyph::NoteheadRoundWhiteLarge,
+    Glyph::NoteheadRoundWhiteSlashed,
+    Glyph::NoteheadRoundWhiteSlashedLarge,
+    Glyph::NoteheadRoundWhiteWithDot,
+    Glyph::NoteheadRoundWhiteWithDotLarge,
+    Glyph::NoteheadSlashDiamondWhite,
+    Glyph::NoteheadSlashHorizontalEnds,
+    Glyph::NoteheadSlashHorizontalEndsMuted,
+    Glyph::NoteheadSlashVerticalEnds,
+    Glyph::NoteheadSlashVerticalEndsMuted,
+    Glyph::NoteheadSlashVerticalEndsSmall,
+    Glyph::NoteheadSlashWhiteDoubleWhole,
+    Glyph::NoteheadSlashWhiteHalf,
+    Glyph::NoteheadSlashWhiteMuted,
+    Glyph::NoteheadSlashWhiteWhole,
+    Glyph::NoteheadSlashX,
+    Glyph::NoteheadSlashedBlack1,
+    Glyph::NoteheadSlashedBlack2,
+    Glyph::NoteheadSlashedDoubleWhole1,
+    Glyph::NoteheadSlashedDoubleWhole2,
+    Glyph::NoteheadSlashedHalf1,
+    Glyph::NoteheadSlashedHalf2,
+    Glyph::NoteheadSlashedWhole1,
+    Glyph::NoteheadSlashedWhole2,
+    Glyph::NoteheadSquareBlack,
+    Glyph::NoteheadSquareBlackLarge,
+    Glyph::NoteheadSquareBlackWhite,
+    Glyph::NoteheadSquareWhite,
+    Glyph::NoteheadTriangleDownBlack,
+    Glyph::NoteheadTriangleDownDoubleWhole,
+    Glyph::NoteheadTriangleDownHalf,
+    Glyph::NoteheadTriangleDownWhite,
+    Glyph::NoteheadTriangleDownWhole,
+    Glyph::NoteheadTriangleLeftBlack,
+    Glyph::NoteheadTriangleLeftWhite,
+    Glyph::NoteheadTriangleRightBlack,
+    Glyph::NoteheadTriangleRightWhite,
+    Glyph::NoteheadTriangleRoundDownBlack,
+    Glyph::NoteheadTriangleRoundDownWhite,
+    Glyph::NoteheadTriangleUpBlack,
+    Glyph::NoteheadTriangleUpDoubleWhole,
+    Glyph::NoteheadTriangleUpHalf,
+    Glyph::NoteheadTriangleUpRightBlack,
+    Glyph::NoteheadTriangleUpRightWhite,
+    Glyph::NoteheadTriangleUpWhite,
+    Glyph::NoteheadTriangleUpWhole,
+    Glyph::NoteheadVoidWithX,
+    Glyph::NoteheadWhole,
+    Glyph::NoteheadWholeFilled,
+    Glyph::NoteheadWholeWithX,
+    Glyph::NoteheadXBlack,
+    Glyph::NoteheadXDoubleWhole,
+    Glyph::NoteheadXHalf,
+    Glyph::NoteheadXOrnate,
+    Glyph::NoteheadXOrnateEllipse,
+    Glyph::NoteheadXWhole,
+    Glyph::OctaveBaselineA,
+    Glyph::OctaveBaselineB,
+    Glyph::OctaveBaselineM,
+    Glyph::OctaveBaselineV,
+    Glyph::OctaveBassa,
+    Glyph::OctaveLoco,
+    Glyph::OctaveParensLeft,
+    Glyph::OctaveParensRight,
+    Glyph::OctaveSuperscriptA,
+    Glyph::OctaveSuperscriptB,
+    Glyph::OctaveSuperscriptM,
+    Glyph::OctaveSuperscriptV,
+    Glyph::OneHandedRollStevens,
+    Glyph::OrganGerman2Fusae,
+    Glyph::OrganGerman2Minimae,
+    Glyph::OrganGerman2OctaveUp,
+    Glyph::OrganGerman2Semifusae,
+    Glyph::OrganGerman2Semiminimae,
+    Glyph::OrganGerman3Fusae,
+    Glyph::OrganGerman3Minimae,
+    Glyph::OrganGerman3Semifusae,
+    Glyph::OrganGerman3Semiminimae,
+    Glyph::OrganGerman4Fusae,
+    Glyph::OrganGerman4Minimae,
+    Glyph::OrganGerman4Semifusae,
+    Glyph::OrganGerman4Semiminimae,
+    Glyph::OrganGerman5Fusae,
+    Glyph::OrganGerman5Minimae,
+    Glyph::OrganGerman5Semifusae,
+    Glyph::OrganGerman5Semiminimae,
+    Glyph::OrganGerman6Fusae,
+    Glyph::OrganGerman6Minimae,
+    Glyph::OrganGerman6Semifusae,
+    Glyph::OrganGerman6Semiminimae,
+    Glyph::OrganGermanALower,
+    Glyph::OrganGermanAUpper,
+    Glyph::OrganGermanAugmentationDot,
+    Glyph::OrganGermanBLower,
+    Glyph::OrganGermanBUpper,
+    Glyph::OrganGermanBuxheimerBrevis2,
+    Glyph::OrganGermanBuxheimerBrevis3,
+    Glyph::OrganGermanBuxheimerMinimaRest,
+    Glyph::OrganGermanBuxheimerSemibrevis,
+    Glyph::OrganGermanBuxheimerSemibrevisRest,
+    Glyph::OrganGermanCLower,
+    Glyph::OrganGermanCUpper,
+    Glyph::OrganGermanCisLower,
+    Glyph::OrganGermanCisUpper,
+    Glyph::OrganGermanDLower,
+    Glyph::OrganGermanDUpper,
+    Glyph::OrganGermanDisLower,
+    Glyph::OrganGermanDisUpper,
+    Glyph::OrganGermanELower,
+    Glyph::OrganGermanEUpper,
+    Glyph::OrganGermanFLower,
+    Glyph::OrganGermanFUpper,
+    Glyph::OrganGermanFisLower,
+    Glyph::OrganGermanFisUpper,
+    Glyph::OrganGermanFusa,
+    Glyph::OrganGermanFusaRest,
+    Glyph::OrganGermanGLower,
+    Glyph::OrganGermanGUpper,
+    Glyph::OrganGermanGisLower,
+    Glyph::OrganGermanGisUpper,
+    Glyph::OrganGermanHLower,
+    Glyph::OrganGermanHUpper,
+    Glyph::OrganGermanMinima,
+    Glyph::OrganGermanMinimaRest,
+    Glyph::OrganGermanOctaveDown,
+    Glyph::OrganGermanOctaveUp,
+    Glyph::OrganGermanSemibrevis,
+    Glyph::OrganGermanSemibrevisRest,
+    Glyph::OrganGermanSemifusa,
+    Glyph::OrganGermanSemifusaRest,
+    Glyph::OrganGermanSemiminima,
+    Glyph::OrganGermanSemiminimaRest,
+    Glyph::OrganGermanTie,
+    Glyph::OrnamentBottomLeftConcaveStroke,
+    Glyph::OrnamentBottomLeftConcaveStrokeLarge,
+    Glyph::OrnamentBottomLeftConvexStroke,
+    Glyph::OrnamentBottomRightConcaveStroke,
+    Glyph::OrnamentBottomRightConvexStroke,
+    Glyph::OrnamentComma,
+    Glyph::OrnamentDoubleObliqueLinesAfterNote,
+    Glyph::OrnamentDoubleObliqueLinesBeforeNote,
+    Glyph::OrnamentDownCurve,
+    Glyph::OrnamentHaydn,
+    Glyph::OrnamentHighLeftConcaveStroke,
+    Glyph::OrnamentHighLeftConvexStroke,
+    Glyph::OrnamentHighRightConcaveStroke,
+    Glyph::OrnamentHighRightConvexStroke,
+    Glyph::OrnamentHookAfterNote,
+    Glyph::OrnamentHookBeforeNote,
+    Glyph::OrnamentLeftFacingHalfCircle,
+    Glyph::OrnamentLeftFacingHook,
+    Glyph::OrnamentLeftPlus,
+    Glyph::OrnamentLeftShakeT,
+    Glyph::OrnamentLeftVerticalStroke,
+    Glyph::OrnamentLeftVerticalStrokeWithCross,
+    Glyph::OrnamentLowLeftConcaveStroke,
+    Glyph::OrnamentLowLeftConvexStroke,
+    Glyph::OrnamentLowRightConcaveStroke,
+    Glyph::OrnamentLowRightConvexStroke,
+    Glyph::OrnamentMiddleVerticalStroke,
+    Glyph::OrnamentMordent,
+    Glyph::OrnamentObliqueLineAfterNote,
+    Glyph::OrnamentObliqueLineBeforeNote,
+    Glyph::OrnamentObliqueLineHorizAfterNote,
+    Glyph::OrnamentObliqueLineHorizBeforeNote,
+    Glyph::OrnamentOriscus,
+    Glyph::OrnamentPinceCouperin,
+    Glyph::OrnamentPortDeVoixV,
+    Glyph::OrnamentPrecompAppoggTrill,
+    Glyph::OrnamentPrecompAppoggTrillSuffix,
+    Glyph::OrnamentPrecompCadence,
+    Glyph::OrnamentPrecompCadenceUpperPrefix,
+    Glyph::OrnamentPrecompCadenceUpperPrefixTurn,
+    Glyph::OrnamentPrecompCadenceWithTurn,
+    Glyph::OrnamentPrecompDescendingSlide,
+    Glyph::OrnamentPrecompDoubleCadenceLowerPrefix,
+    Glyph::OrnamentPrecompDoubleCadenceUpperPrefix,
+    Glyph::OrnamentPrecompDoubleCadenceUpperPrefixTurn,
+    Glyph::OrnamentPrecompInvertedMordentUpperPrefix,
+    Glyph::OrnamentPrecompMordentRelease,
+    Glyph::OrnamentPrecompMordentUpperPrefix,
+    Glyph::OrnamentPrecompPortDeVoixMordent,
+    Glyph::OrnamentPrecompSlide,
+    Glyph::OrnamentPrecompSlideTrillBach,
+    Glyph::OrnamentPrecompSlideTrillDAnglebert,
+    Glyph::OrnamentPrecompSlideTrillMarpurg,
+    Glyph::OrnamentPrecompSlideTrillMuffat,
+    Glyph::OrnamentPrecompSlideTrillSuffixMuffat,
+    Glyph::OrnamentPrecompTrillLowerSuffix,
+    Glyph::OrnamentPrecompTrillSuffixDandrieu,
+    Glyph::OrnamentPrecompTrillWithMordent,
+    Glyph::OrnamentPrecompTurnTrillBach,
+    Glyph::OrnamentPrecompTurnTrillDAnglebert,
+    Glyph::OrnamentQuilisma,
+    Glyph::OrnamentRightFacingHalfCircle,
+    Glyph::OrnamentRightFacingHook,
+    Glyph::OrnamentRightVerticalStroke,
+    Glyph::OrnamentSchleifer,
+    Glyph::OrnamentShake3,
+    Glyph::OrnamentShakeMuffat1,
+    Glyph::OrnamentShortObliqueLineAfterNote,
+    Glyph::OrnamentShortObliqueLineBeforeNote,
+    Glyph::OrnamentShortTrill,
+    Glyph::OrnamentTopLeftConcaveStroke,
+    Glyph::OrnamentTopLeftConvexStroke,
+    Glyph::OrnamentTopRightConcaveStroke,
+    Glyph::OrnamentTopRightConvexStroke,
+    Glyph::OrnamentTremblement,
+    Glyph::OrnamentTremblementCouperin,
+    Glyph::OrnamentTrill,
+    Glyph::OrnamentTurn,
+    Glyph::OrnamentTurnInverted,
+    Glyph::OrnamentTurnSlash,
+    Glyph::OrnamentTurnUp,
+    Glyph::OrnamentTurnUpS,
+    Glyph::OrnamentUpCurve,
+    Glyph::OrnamentVerticalLine,
+    Glyph::OrnamentZigZagLineNoRightEnd,
+    Glyph::OrnamentZigZagLineWithRightEnd,
+    Glyph::Ottava,
+    Glyph::OttavaAlta,
+    Glyph::OttavaBassa,
+    Glyph::OttavaBassaBa,
+    Glyph::OttavaBassaVb,
+    Glyph::PendereckiTremolo,
+    Glyph::PictAgogo,
+    Glyph::PictAlmglocken,
+    Glyph::PictAnvil,
+    Glyph::PictBambooChimes,
+    Glyph::PictBambooScraper,
+    Glyph::PictBassDrum,
+    Glyph::PictBassDrumOnSide,
+    Glyph::PictBeaterBow,
+    Glyph::PictBeaterBox,
+    Glyph::PictBeaterBrassMalletsDown,
+    Glyph::PictBeaterBrassMalletsLeft,
+    Glyph::PictBeaterBrassMalletsRight,
+    Glyph::PictBeaterBrassMalletsUp,
+    Glyph::PictBeaterCombiningDashedCircle,
+    Glyph::PictBeaterCombiningParentheses,
+    Glyph::PictBeaterDoubleBassDrumDown,
+    Glyph::PictBeaterDoubleBassDrumUp,
+    Glyph::PictBeaterFinger,
+    Glyph::PictBeaterFingernails,
+    Glyph::PictBeaterFist,
+    Glyph::PictBeaterGuiroScraper,
+    Glyph::PictBeaterHammer,
+    Glyph::PictBeaterHammerMetalDown,
+    Glyph::PictBeaterHammerMetalUp,
+    Glyph::PictBeaterHammerPlasticDown,
+    Glyph::PictBeaterHammerPlasticUp,
+    Glyph::PictBeaterHammerWoodDown,
+    Glyph::PictBeaterHammerWoodUp,
+    Glyph::PictBeaterHand,
+    Glyph::PictBeaterHardBassDrumDown,
+    Glyph::PictBeaterHardBassDrumUp,
+    Glyph::PictBeaterHardGlockenspielDown,
+    Glyph::PictBeaterHardGlockenspielLeft,
+    Glyph::PictBeaterHardGlockenspielRight,
+    Glyph::PictBeaterHardGlockenspielUp,
+    Glyph::PictBeaterHardTimpaniDown,
+    Glyph::PictBeaterHardTimpaniLeft,
+    Glyph::PictBeaterHardTimpaniRight,
+    Glyph::PictBeaterHardTimpaniUp,
+    Glyph::PictBeaterHardXylophoneDown,
+    Glyph::PictBeaterHardXylophoneLeft,
+    Glyph::PictBeaterHardXylophoneRight,
+    Glyph::PictBeaterHardXylophoneUp,
+    Glyph::PictBeaterHardYarnDown,
+    Glyph::PictBeaterHardYarnLeft,
+    Glyph::PictBeaterHardYarnRight,
+    Glyph::PictBeaterHardYarnUp,
+    Glyph::PictBeaterJazzSticksDown,
+    Glyph::PictBeaterJazzSticksUp,
+    Glyph::PictBeaterKnittingNeedle,
+    Glyph::PictBeaterMallet,
+    Glyph::PictBeaterMalletDown,
+    Glyph::PictBeaterMediumBassDrumDown,
+    Glyph::PictBeaterMediumBassDrumUp,
+    Glyph::PictBeaterMediumTimpaniDown,
+    Glyph::PictBeaterMediumTimpaniLeft,
+    Glyph::PictBeaterMediumTimpaniRight,
+    Glyph::PictBeaterMediumTimpaniUp,
+    Glyph::PictBeaterMediumXylophoneDown,
+    Glyph::PictBeaterMediumXylophoneLeft,
+    Glyph::PictBeaterMediumXylophoneRight,
+    Glyph::PictBeaterMediumXylophoneUp,
+    Glyph::PictBeaterMediumYarnDown,
+    Glyph::PictBeaterMediumYarnLeft,
+    Glyph::PictBeaterMediumYarnRight,
+    Glyph::PictBeaterMediumYarnUp,
+    Glyph::PictBeaterMetalBassDrumDown,
+    Glyph::PictBeaterMetalBassDrumUp,
+    Glyph::PictBeaterMetalDown,
+    Glyph::PictBeaterMetalHammer,
+    Glyph::PictBeaterMetalLeft,
+    Glyph::PictBeaterMetalRight,
+    Glyph::PictBeaterMetalUp,
+    Glyph::PictBeaterSnareSticksDown,
+    Glyph::PictBeaterSnareSticksUp,
+    Glyph::PictBeaterSoftBassDrumDown,
+    Glyph::PictBeaterSoftBassDrumUp,
+    Glyph::PictBeaterSoftGlockenspielDown,
+    Glyph::PictBeaterSoftGlockenspielLeft,
+    Glyph::PictBeaterSoftGlockenspielRight,
+    Glyph::PictBeaterSoftGlockenspielUp,
+    Glyph::PictBeaterSoftTimpaniDown,
+    Glyph::PictBeaterSoftTimpaniLeft,
+    Glyph::PictBeaterSoftTimpaniRight,
+    Glyph::PictBeaterSoftTimpaniUp,
+    Glyph::PictBeaterSoftXylophone,
+    Glyph::PictBeaterSoftXylophoneDown,
+    Glyph::PictBeaterSoftXylophoneLeft,
+    Glyph::PictBeaterSoftXylophoneRight,
+    Glyph::PictBeaterSoftXylophoneUp,
+    Glyph::PictBeaterSoftYarnDown,
+    Glyph::PictBeaterSoftYarnLeft,
+    Glyph::PictBeaterSoftYarnRight,
+    Glyph::PictBeaterSoftYarnUp,
+    Glyph::PictBeaterSpoonWoodenMallet,
+    Glyph::PictBeaterSuperballDown,
+    Glyph::PictBeaterSuperballLeft,
+    Glyph::PictBeaterSuperballRight,
+    Glyph::PictBeaterSuperballUp,
+    Glyph::PictBeaterTriangleDown,
+    Glyph::PictBeaterTrianglePlain,
+    Glyph::PictBeaterTriangleUp,
+    Glyph::PictBeaterWireBrushesDown,
+    Glyph::PictBeaterWireBrushesUp,
+    Glyph::PictBeaterWoodTimpaniDown,
+    Glyph::PictBeaterWoodTimpaniLeft,
+    Glyph::PictBeaterWoodTimpaniRight,
+    Glyph::PictBeaterWoodTimpaniUp,
+    Glyph::PictBeaterWoodXylophoneDown,
+    Glyph::PictBeaterWoodXylophoneLeft,
+    Glyph::PictBeaterWoodXylophoneRight,
+    Glyph::PictBeaterWoodXylophoneUp,
+    Glyph::PictBell,
+    Glyph::PictBellOfCymbal,
+    Glyph::PictBellPlate,
+    Glyph::PictBellTree,
+    Glyph::PictBirdWhistle,
+    Glyph::PictBoardClapper,
+    Glyph::PictBongos,
+    Glyph::PictBrakeDrum,
+    Glyph::PictCabasa,
+    Glyph::PictCannon,
+    Glyph::PictCarHorn,
+    Glyph::PictCastanets,
+    Glyph::PictCastanetsWithHandle,
+    Glyph::PictCelesta,
+    Glyph::PictCencerro,
+    Glyph::PictCenter1,
+    Glyph::PictCenter2,
+    Glyph::PictCenter3,
+    Glyph::PictChainRattle,
+    Glyph::PictChimes,
+    Glyph::PictChineseCymbal,
+    Glyph::PictChokeCymbal,
+    Glyph::PictClaves,
+    Glyph::PictCoins,
+    Glyph::PictConga,
+    Glyph::PictCowBell,
+    Glyph::PictCrashCymbals,
+    Glyph::PictCrotales,
+    Glyph::PictCrushStem,
+    Glyph::PictCuica,
+    Glyph::PictCymbalTongs,
+    Glyph::PictDamp1,
+    Glyph::PictDamp2,
+    Glyph::PictDamp3,
+    Glyph::PictDamp4,
+    Glyph::PictDeadNoteStem,
+    Glyph::PictDrumStick,
+    Glyph::PictDuckCall,
+    Glyph::PictEdgeOfCymbal,
+    Glyph::PictEmptyTrap,
+    Glyph::PictFingerCymbals,
+    Glyph::PictFlexatone,
+    Glyph::PictFootballRatchet,
+    Glyph::PictGlassHarmonica,
+    Glyph::PictGlassHarp,
+    Glyph::PictGlassPlateChimes,
+    Glyph::PictGlassTubeChimes,
+    Glyph::PictGlsp,
+    Glyph::PictGlspSmithBrindle,
+    Glyph::PictGobletDrum,
+    Glyph::PictGong,
+    Glyph::PictGongWithButton,
+    Glyph::PictGuiro,
+    Glyph::PictGumHardDown,
+    Glyph::PictGumHardLeft,
+    Glyph::PictGumHardRight,
+    Glyph::PictGumHardUp,
+    Glyph::PictGumMediumDown,
+    Glyph::PictGumMediumLeft,
+    Glyph::PictGumMediumRight,
+    Glyph::PictGumMediumUp,
+    Glyph::PictGumSoftDown,
+    Glyph::PictGumSoftLeft,
+    Glyph::PictGumSoftRight,
+    Glyph::PictGumSoftUp,
+    Glyph::PictHalfOpen1,
+    Glyph::PictHalfOpen2,
+    Glyph::PictHandbell,
+    Glyph::PictHiHat,
+    Glyph::PictHiHatOnStand,
+    Glyph::PictJawHarp,
+    Glyph::PictJingleBells,
+    Glyph::PictKlaxonHorn,
+    Glyph::PictLeftHandCircle,
+    Glyph::PictLionsRoar,
+    Glyph::PictLithophone,
+    Glyph::PictLogDrum,
+    Glyph::PictLotusFlute,
+    Glyph::PictMar,
+    Glyph::PictMarSmithBrindle,
+    Glyph::PictMaraca,
+    Glyph::PictMaracas,
+    Glyph::PictMegaphone,
+    Glyph::PictMetalPlateChimes,
+    Glyph::PictMetalTubeChimes,
+    Glyph::PictMusicalSaw,
+    Glyph::PictNormalPosition,
+    Glyph::PictOnRim,
+    Glyph::PictOpen,
+    Glyph::PictOpenRimShot,
+    Glyph::PictPistolShot,
+    Glyph::PictPoliceWhistle,
+    Glyph::PictQuijada,
+    Glyph::PictRainstick,
+    Glyph::PictRatchet,
+    Glyph::PictRecoReco,
+    Glyph::PictRightHandSquare,
+    Glyph::PictRim1,
+    Glyph::PictRim2,
+    Glyph::PictRim3,
+    Glyph::PictRimShotOnStem,
+    Glyph::PictSandpaperBlocks,
+    Glyph::PictScrapeAroundRim,
+    Glyph::PictScrapeAroundRimClockwise,
+    Glyph::PictScrapeCenterToEdge,
+    Glyph::PictScrapeEdgeToCenter,
+    Glyph::PictShellBells,
+    Glyph::PictShellChimes,
+    Glyph::PictSiren,
+    Glyph::PictSistrum,
+    Glyph::PictSizzleCymbal,
+    Glyph::PictSleighBell,
+    Glyph::PictSlideBrushOnGong,
+    Glyph::PictSlideWhistle,
+    Glyph::PictSlitDrum,
+    Glyph::PictSnareDrum,
+    Glyph::PictSnareDrumMilitary,
+    Glyph::PictSnareDrumSnaresOff,
+    Glyph::PictSteelDrums,
+    Glyph::PictStickShot,
+    Glyph::PictSuperball,
+    Glyph::PictSuspendedCymbal,
+    Glyph::PictSwishStem,
+    Glyph::PictTabla,
+    Glyph::PictTamTam,
+    Glyph::PictTamTamWithBeater,
+    Glyph::PictTambourine,
+    Glyph::PictTempleBlocks,
+    Glyph::PictTenorDrum,
+    Glyph::PictThundersheet,
+    Glyph::PictTimbales,
+    Glyph::PictTimpani,
+    Glyph::PictTomTom,
+    Glyph::PictTomTomChinese,
+    Glyph::PictTomTomIndoAmerican,
+    Glyph::PictTomTomJapanese,
+    Glyph::PictTriangle,
+    Glyph::PictTubaphone,
+    Glyph::PictTubularBells,
+    Glyph::PictTurnLeftStem,
+    Glyph::PictTurnRightLeftStem,
+    Glyph::PictTurnRightStem,
+    Glyph::PictVib,
+    Glyph::PictVibMotorOff,
+    Glyph::PictVibSmithBrindle,
+    Glyph::PictVibraslap,
+    Glyph::PictVietnameseHat,
+    Glyph::PictWhip,
+    Glyph::PictWindChimesGlass,
+    Glyph::PictWindMachine,
+    Glyph::PictWindWhistle,
+    Glyph::PictWoodBlock,
+    Glyph::PictWoundHardDown,
+    Glyph::PictWoundHardLeft,
+    Glyph::PictWoundHardRight,
+    Glyph::PictWoundHardUp,
+    Glyph::PictWoundSoftDown,
+    Glyph::PictWoundSoftLeft,
+    Glyph::PictWoundSoftRight,
+    Glyph::PictWoundSoftUp,
+    Glyph::PictXyl,
+    Glyph::PictXylBass,
+    Glyph::PictXylSmithBrindle,
+    Glyph::PictXylTenor,
+    Glyph::PictXylTenorTrough,
+    Glyph::PictXylTrough,
+    Glyph::PluckedBuzzPizzicato,
+    Glyph::PluckedDamp,
+    Glyph::PluckedDampAll,
+    Glyph::PluckedDampOnStem,
+    Glyph::PluckedFingernailFlick,
+    Glyph::PluckedLeftHandPizzicato,
+    Glyph::PluckedPlectrum,
+    Glyph::PluckedSnapPizzicatoAbove,
+    Glyph::PluckedSnapPizzicatoBelow,
+    Glyph::PluckedWithFingernails,
+    Glyph::Quindicesima,
+    Glyph::QuindicesimaAlta,
+    Glyph::QuindicesimaBassa,
+    Glyph::QuindicesimaBassaMb,
+    Glyph::Repeat1Bar,
+    Glyph::Repeat2Bars,
+    Glyph::Repeat4Bars,
+    Glyph::RepeatBarLowerDot,
+    Glyph::RepeatBarSlash,
+    Glyph::RepeatBarUpperDot,
+    Glyph::RepeatDot,
+    Glyph::RepeatDots,
+    Glyph::RepeatLeft,
+    Glyph::RepeatRight,
+    Glyph::RepeatRightLeft,
+    Glyph::Rest1024th,
+    Glyph::Rest128th,
+    Glyph::Rest16th,
+    Glyph::Rest256th,
+    Glyph::Rest32nd,
+    Glyph::Rest512th,
+    Glyph::Rest64th,
+    Glyph::Rest8th,
+    Glyph::RestDoubleWhole,
+    Glyph::RestDoubleWholeLegerLine,
+    Glyph::RestHBar,
+    Glyph::RestHBarLeft,
+    Glyph::RestHBarMiddle,
+    Glyph::RestHBarRight,
+    Glyph::RestHalf,
+    Glyph::RestHalfLegerLine,
+    Glyph::RestLonga,
+    Glyph::RestMaxima,
+    Glyph::RestQuarter,
+    Glyph::RestQuarterOld,
+    Glyph::RestQuarterZ,
+    Glyph::RestWhole,
+    Glyph::RestWholeLegerLine,
+    Glyph::ReversedBrace,
+    Glyph::ReversedBracketBottom,
+    Glyph::ReversedBracketTop,
+    Glyph::RightRepeatSmall,
+    Glyph::ScaleDegree1,
+    Glyph::ScaleDegree2,
+    Glyph::ScaleDegree3,
+    Glyph::ScaleDegree4,
+    Glyph::ScaleDegree5,
+    Glyph::ScaleDegree6,
+    Glyph::ScaleDegree7,
+    Glyph::ScaleDegree8,
+    Glyph::ScaleDegree9,
+    Glyph::SchaefferClef,
+    Glyph::SchaefferFClefToGClef,
+    Glyph::SchaefferGClefToFClef,
+    Glyph::SchaefferPreviousClef,
+    Glyph::Segno,
+    Glyph::SegnoSerpent1,
+    Glyph::SegnoSerpent2,
+    Glyph::SemipitchedPercussionClef1,
+    Glyph::SemipitchedPercussionClef2,
+    Glyph::SmnFlat,
+    Glyph::SmnFlatWhite,
+    Glyph::SmnHistoryDoubleFlat,
+    Glyph::SmnHistoryDoubleSharp,
+    Glyph::SmnHistoryFlat,
+    Glyph::SmnHistorySharp,
+    Glyph::SmnNatural,
+    Glyph::SmnSharp,
+    Glyph::SmnSharpDown,
+    Glyph::SmnSharpWhite,
+    Glyph::SmnSharpWhiteDown,
+    Glyph::SplitBarDivider,
+    Glyph::Staff1Line,
+    Glyph::Staff1LineNarrow,
+    Glyph::Staff1LineWide,
+    Glyph::Staff2Lines,
+    Glyph::Staff2LinesNarrow,
+    Glyph::Staff2LinesWide,
+    Glyph::Staff3Lines,
+    Glyph::Staff3LinesNarrow,
+    Glyph::Staff3LinesWide,
+    Glyph::Staff4Lines,
+    Glyph::Staff4LinesNarrow,
+    Glyph::Staff4LinesWide,
+    Glyph::Staff5Lines,
+    Glyph::Staff5LinesNarrow,
+    Glyph::Staff5LinesWide,
+    Glyph::Staff6Lines,
+    Glyph::Staff6LinesNarrow,
+    Glyph::Staff6LinesWide,
+    Glyph::StaffDivideArrowDown,
+    Glyph::StaffDivideArrowUp,
+    Glyph::StaffDivideArrowUpDown,
+    Glyph::StaffPosLower1,
+    Glyph::StaffPosLower2,
+    Glyph::StaffPosLower3,
+    Glyph::StaffPosLower4,
+    Glyph::StaffPosLower5,
+    Glyph::StaffPosLower6,
+    Glyph::StaffPosLower7,
+    Glyph::StaffPosLower8,
+    Glyph::StaffPosRaise1,
+    Glyph::StaffPosRaise2,
+    Glyph::StaffPosRaise3,
+    Glyph::StaffPosRaise4,
+    Glyph::StaffPosRaise5,
+    Glyph::StaffPosRaise6,
+    Glyph::StaffPosRaise7,
+    Glyph::StaffPosRaise8,
+    Glyph::Stem,
+    Glyph::StemBowOnBridge,
+    Glyph::StemBowOnTailpiece,
+    Glyph::StemBuzzRoll,
+    Glyph::StemDamp,
+    Glyph::StemHarpStringNoise,
+    Glyph::StemMultiphonicsBlack,
+    Glyph::StemMultiphonicsBlackWhite,
+    Glyph::StemMultiphonicsWhite,
+    Glyph::StemPendereckiTremolo,
+    Glyph::StemRimShot,
+    Glyph::StemSprechgesang,
+    Glyph::StemSulPonticello,
+    Glyph::StemSussurando,
+    Glyph::StemSwished,
+    Glyph::StemVibratoPulse,
+    Glyph::StockhausenTremolo,
+    Glyph::StringsBowBehindBridge,
+    Glyph::StringsBowBehindBridgeFourStrings,
+    Glyph::StringsBowBehindBridgeOneString,
+    Glyph::StringsBowBehindBridgeThreeStrings,
+    Glyph::StringsBowBehindBridgeTwoStrings,
+    Glyph::StringsBowOnBridge,
+    Glyph::StringsBowOnTailpiece,
+    Glyph::StringsChangeBowDirection,
+    Glyph::StringsDownBow,
+    Glyph::StringsDownBowAwayFromBody,
+    Glyph::StringsDownBowBeyondBridge,
+    Glyph::StringsDownBowTowardsBody,
+    Glyph::StringsDownBowTurned,
+    Glyph::StringsFouette,
+    Glyph::StringsHalfHarmonic,
+    Glyph::StringsHarmonic,
+    Glyph::StringsJeteAbove,
+    Glyph::StringsJeteBelow,
+    Glyph::StringsMuteOff,
+    Glyph::StringsMuteOn,
+    Glyph::StringsOverpressureDownBow,
+    Glyph::StringsOverpressureNoDirection,
+    Glyph::StringsOverpressurePossibileDownBow,
+    Glyph::StringsOverpressurePossibileUpBow,
+    Glyph::StringsOverpressureUpBow,
+    Glyph::StringsScrapeCircularClockwise,
+    Glyph::StringsScrapeCircularCounterclockwise,
+    Glyph::StringsScrapeParallelInward,
+    Glyph::StringsScrapeParallelOutward,
+    Glyph::StringsThumbPosition,
+    Glyph::StringsThumbPositionTurned,
+    Glyph::StringsTripleChopInward,
+    Glyph::StringsTripleChopOutward,
+    Glyph::StringsUpBow,
+    Glyph::StringsUpBowAwayFromBody,
+    Glyph::StringsUpBowBeyondBridge,
+    Glyph::StringsUpBowTowardsBody,
+    Glyph::StringsUpBowTurned,
+    Glyph::StringsVibratoPulse,
+    Glyph::SwissRudimentsNoteheadBlackDouble,
+    Glyph::SwissRudimentsNoteheadBlackFlam,
+    Glyph::SwissRudimentsNoteheadHalfDouble,
+    Glyph::SwissRudimentsNoteheadHalfFlam,
+    Glyph::SystemDivider,
+    Glyph::SystemDividerExtraLong,
+    Glyph::SystemDividerLong,
+    Glyph::TextAugmentationDot,
+    Glyph::TextBlackNoteFrac16thLongStem,
+    Glyph::TextBlackNoteFrac16thShortStem,
+    Glyph::TextBlackNoteFrac32ndLongStem,
+    Glyph::TextBlackNoteFrac8thLongStem,
+    Glyph::TextBlackNoteFrac8thShortStem,
+    Glyph::TextBlackNoteLongStem,
+    Glyph::TextBlackNoteShortStem,
+    Glyph::TextCont16thBeamLongStem,
+    Glyph::TextCont16thBeamShortStem,
+    Glyph::TextCont32ndBeamLongStem,
+    Glyph::TextCont8thBeamLongStem,
+    Glyph::TextCont8thBeamShortStem,
+    Glyph::TextHeadlessBlackNoteFrac16thLongStem,
+    Glyph::TextHeadlessBlackNoteFrac16thShortStem,
+    Glyph::TextHeadlessBlackNoteFrac32ndLongStem,
+    Glyph::TextHeadlessBlackNoteFrac8thLongStem,
+    Glyph::TextHeadlessBlackNoteFrac8thShortStem,
+    Glyph::TextHeadlessBlackNoteLongStem,
+    Glyph::TextHeadlessBlackNoteShortStem,
+    Glyph::TextTie,
+    Glyph::TextTuplet3LongStem,
+    Glyph::TextTuplet3ShortStem,
+    Glyph::TextTupletBracketEndLongStem,
+    Glyph::TextTupletBracketEndShortStem,
+    Glyph::TextTupletBracketStartLongStem,
+    Glyph::TextTupletBracketStartShortStem,
+    Glyph::TimeSig0,
+    Glyph::TimeSig0Reversed,
+    Glyph::TimeSig0turned,
+    Glyph::TimeSig1,
+    Glyph::TimeSig1Reversed,
+    Glyph::TimeSig1turned,
+    Glyph::TimeSig2,
+    Glyph::TimeSig2Reversed,
+    Glyph::TimeSig2turned,
+    Glyph::TimeSig3,
+    Glyph::TimeSig3Reversed,
+    Glyph::TimeSig3turned,
+    Glyph::TimeSig4,
+    Glyph::TimeSig4Reversed,
+    Glyph::TimeSig4turned,
+    Glyph::TimeSig5,
+    Glyph::TimeSig5Reversed,
+    Glyph::TimeSig5turned,
+    Glyph::TimeSig6,
+    Glyph::TimeSig6Reversed,
+    Glyph::TimeSig6turned,
+    Glyph::TimeSig7,
+    Glyph::TimeSig7Reversed,
+    Glyph::TimeSig7turned,
+    Glyph::TimeSig8,
+    Glyph::TimeSig8Reversed,
+    Glyph::TimeSig8turned,
+    Glyph::TimeSig9,
+    Glyph::TimeSig9Reversed,
+    Glyph::TimeSig9turned,
+    Glyph::TimeSigBracketLeft,
+    Glyph::TimeSigBracketLeftSmall,
+    Glyph::TimeSigBracketRight,
+    Glyph::TimeSigBracketRightSmall,
+    Glyph::TimeSigCombDenominator,
+    Glyph::TimeSigCombNumerator,
+    Glyph::TimeSigComma,
+    Glyph::TimeSigCommon,
+    Glyph::TimeSigCommonReversed,
+    Glyph::TimeSigCommonTurned,
+    Glyph::TimeSigCut2,
+    Glyph::TimeSigCut3,
+    Glyph::TimeSigCutCommon,
+    Glyph::TimeSigCutCommonReversed,
+    Glyph::TimeSigCutCommonTurned,
+    Glyph::TimeSigEquals,
+    Glyph::TimeSigFractionHalf,
+    Glyph::TimeSigFractionOneThird,
+    Glyph::TimeSigFractionQuarter,
+    Glyph::TimeSigFractionThreeQuarters,
+    Glyph::TimeSigFractionTwoThirds,
+    Glyph::TimeSigFractionalSlash,
+    Glyph::TimeSigMinus,
+    Glyph::TimeSigMultiply,
+    Glyph::TimeSigOpenPenderecki,
+    Glyph::TimeSigParensLeft,
+    Glyph::TimeSigParensLeftSmall,
+    Glyph::TimeSigParensRight,
+    Glyph::TimeSigParensRightSmall,
+    Glyph::TimeSigPlus,
+    Glyph::TimeSigPlusSmall,
+    Glyph::TimeSigSlash,
+    Glyph::TimeSigX,
+    Glyph::Tremolo1,
+    Glyph::Tremolo2,
+    Glyph::Tremolo3,
+    Glyph::Tremolo4,
+    Glyph::Tremolo5,
+    Glyph::TremoloDivisiDots2,
+    Glyph::TremoloDivisiDots3,
+    Glyph::TremoloDivisiDots4,
+    Glyph::TremoloDivisiDots6,
+    Glyph::TremoloFingered1,
+    Glyph::TremoloFingered2,
+    Glyph::TremoloFingered3,
+    Glyph::TremoloFingered4,
+    Glyph::TremoloFingered5,
+    Glyph::TripleTongueAbove,
+    Glyph::TripleTongueBelow,
+    Glyph::Tuplet0,
+    Glyph::Tuplet1,
+    Glyph::Tuplet2,
+    Glyph::Tuplet3,
+    Glyph::Tuplet4,
+    Glyph::Tuplet5,
+    Glyph::Tuplet6,
+    Glyph::Tuplet7,
+    Glyph::Tuplet8,
+    Glyph::Tuplet9,
+    Glyph::TupletColon,
+    Glyph::UnmeasuredTremolo,
+    Glyph::UnmeasuredTremoloSimple,
+    Glyph::UnpitchedPercussionClef1,
+    Glyph::UnpitchedPercussionClef2,
+    Glyph::Ventiduesima,
+    Glyph::VentiduesimaAlta,
+    Glyph::VentiduesimaBassa,
+    Glyph::VentiduesimaBassaMb,
+    Glyph::VocalFingerClickStockhausen,
+    Glyph::VocalHalbGesungen,
+    Glyph::VocalMouthClosed,
+    Glyph::VocalMouthOpen,
+    Glyph::VocalMouthPursed,
+    Glyph::VocalMouthSlightlyOpen,
+    Glyph::VocalMouthWideOpen,
+    Glyph::VocalNasalVoice,
+    Glyph::VocalSprechgesang,
+    Glyph::VocalTongueClickStockhausen,
+    Glyph::VocalTongueFingerClickStockhausen,
+    Glyph::VocalsSussurando,
+    Glyph::WiggleArpeggiatoDown,
+    Glyph::WiggleArpeggiatoDownArrow,
+    Glyph::WiggleArpeggiatoDownSwash,
+    Glyph::WiggleArpeggiatoUp,
+    Glyph::WiggleArpeggiatoUpArrow,
+    Glyph::WiggleArpeggiatoUpSwash,
+    Glyph::WiggleCircular,
+    Glyph::WiggleCircularConstant,
+    Glyph::WiggleCircularConstantFlipped,
+    Glyph::WiggleCircularConstantFlippedLarge,
+    Glyph::WiggleCircularConstantLarge,
+    Glyph::WiggleCircularEnd,
+    Glyph::WiggleCircularLarge,
+    Glyph::WiggleCircularLarger,
+    Glyph::WiggleCircularLargerStill,
+    Glyph::WiggleCircularLargest,
+    Glyph::WiggleCircularSmall,
+    Glyph::WiggleCircularStart,
+    Glyph::WiggleGlissando,
+    Glyph::WiggleGlissandoGroup1,
+    Glyph::WiggleGlissandoGroup2,
+    Glyph::WiggleGlissandoGroup3,
+    Glyph::WiggleRandom1,
+    Glyph::WiggleRandom2,
+    Glyph::WiggleRandom3,
+    Glyph::WiggleRandom4,
+    Glyph::WiggleSawtooth,
+    Glyph::WiggleSawtoothNarrow,
+    Glyph::WiggleSawtoothWide,
+    Glyph::WiggleSquareWave,
+    Glyph::WiggleSquareWaveNarrow,
+    Glyph::WiggleSquareWaveWide,
+    Glyph::WiggleTrill,
+    Glyph::WiggleTrillFast,
+    Glyph::WiggleTrillFaster,
+    Glyph::WiggleTrillFasterStill,
+    Glyph::WiggleTrillFastest,
+    Glyph::WiggleTrillSlow,
+    Glyph::WiggleTrillSlower,
+    Glyph::WiggleTrillSlowerStill,
+    Glyph::WiggleTrillSlowest,
+    Glyph::WiggleVIbratoLargestSlower,
+    Glyph::WiggleVIbratoMediumSlower,
+    Glyph::WiggleVibrato,
+    Glyph::WiggleVibratoLargeFast,
+    Glyph::WiggleVibratoLargeFaster,
+    Glyph::WiggleVibratoLargeFasterStill,
+    Glyph::WiggleVibratoLargeFastest,
+    Glyph::WiggleVibratoLargeSlow,
+    Glyph::WiggleVibratoLargeSlower,
+    Glyph::WiggleVibratoLargeSlowest,
+    Glyph::WiggleVibratoLargestFast,
+    Glyph::WiggleVibratoLargestFaster,
+    Glyph::WiggleVibratoLargestFasterStill,
+    Glyph::WiggleVibratoLargestFastest,
+    Glyph::WiggleVibratoLargestSlow,
+    Glyph::WiggleVibratoLargestSlowest,
+    Glyph::WiggleVibratoMediumFast,
+    Glyph::WiggleVibratoMediumFaster,
+    Glyph::WiggleVibratoMediumFasterStill,
+    Glyph::WiggleVibratoMediumFastest,
+    Glyph::WiggleVibratoMediumSlow,
+    Glyph::WiggleVibratoMediumSlowest,
+    Glyph::WiggleVibratoSmallFast,
+    Glyph::WiggleVibratoSmallFaster,
+    Glyph::WiggleVibratoSmallFasterStill,
+    Glyph::WiggleVibratoSmallFastest,
+    Glyph::WiggleVibratoSmallSlow,
+    Glyph::WiggleVibratoSmallSlower,
+    Glyph::WiggleVibratoSmallSlowest,
+    Glyph::WiggleVibratoSmallestFast,
+    Glyph::WiggleVibratoSmallestFaster,
+    Glyph::WiggleVibratoSmallestFasterStill,
+    Glyph::WiggleVibratoSmallestFastest,
+    Glyph::WiggleVibratoSmallestSlow,
+    Glyph::WiggleVibratoSmallestSlower,
+    Glyph::WiggleVibratoSmallestSlowest,
+    Glyph::WiggleVibratoStart,
+    Glyph::WiggleVibratoWide,
+    Glyph::WiggleWavy,
+    Glyph::WiggleWavyNarrow,
+    Glyph::WiggleWavyWide,
+    Glyph::WindClosedHole,
+    Glyph::WindFlatEmbouchure,
+    Glyph::WindHalfClosedHole1,
+    Glyph::WindHalfClosedHole2,
+    Glyph::WindHalfClosedHole3,
+    Glyph::WindLessRelaxedEmbouchure,
+    Glyph::WindLessTightEmbouchure,
+    Glyph::WindMouthpiecePop,
+    Glyph::WindMultiphonicsBlackStem,
+    Glyph::WindMultiphonicsBlackWhiteStem,
+    Glyph::WindMultiphonicsWhiteStem,
+    Glyph::WindOpenHole,
+    Glyph::WindReedPositionIn,
+    Glyph::WindReedPositionNormal,
+    Glyph::WindReedPositionOut,
+    Glyph::WindRelaxedEmbouchure,
+    Glyph::WindRimOnly,
+    Glyph::WindSharpEmbouchure,
+    Glyph::WindStrongAirPressure,
+    Glyph::WindThreeQuartersClosedHole,
+    Glyph::WindTightEmbouchure,
+    Glyph::WindTrillKey,
+    Glyph::WindVeryTightEmbouchure,
+    Glyph::WindWeakAirPressure,
+    Glyph::_4StringTabClef,
+    Glyph::_6StringTabClef,
+];
+
+/// Each [`Glyph`]'s SMuFL code point, indexed by discriminant.
+#[rustfmt::skip]
+pub static GLYPH_CODEPOINTS: &[char] = &[
+    '\u{e30d}',
+    '\u{e30c}',
+    '\u{e30b}',
+    '\u{e30a}',
+    '\u{e3ab}',
+    '\u{e3aa}',
+    '\u{e3a3}',
+    '\u{e3a2}',
+    '\u{e397}',
+    '\u{e396}',
+    '\u{e395}',
+    '\u{e394}',
+    '\u{e343}',
+    '\u{e342}',
+    '\u{e393}',
+    '\u{e392}',
+    '\u{e399}',
+    '\u{e398}',
+    '\u{e391}',
+    '\u{e390}',
+    '\u{e3f5}',
+    '\u{e3f4}',
+    '\u{e3f9}',
+    '\u{e3f8}',
+    '\u{e371}',
+    '\u{e370}',
+    '\u{e39f}',
+    '\u{e39e}',
+    '\u{e307}',
+    '\u{e306}',
+    '\u{e3f7}',
+    '\u{e3f6}',
+    '\u{e3fb}',
+    '\u{e3fa}',
+    '\u{e30f}',
+    '\u{e30e}',
+    '\u{e309}',
+    '\u{e308}',
+    '\u{e3fd}',
+    '\u{e3fc}',
+    '\u{e3a9}',
+    '\u{e3a8}',
+    '\u{e3a5}',
+    '\u{e3a4}',
+    '\u{e39d}',
+    '\u{e39c}',
+    '\u{e3ff}',
+    '\u{e3fe}',
+    '\u{e345}',
+    '\u{e344}',
+    '\u{e303}',
+    '\u{e302}',
+    '\u{e349}',
+    '\u{e348}',
+    '\u{e3ad}',
+    '\u{e3ac}',
+    '\u{e3a1}',
+    '\u{e3a0}',
+    '\u{e373}',
+    '\u{e372}',
+    '\u{e375}',
+    '\u{e374}',
+    '\u{e3a7}',
+    '\u{e3a6}',
+    '\u{e301}',
+    '\u{e300}',
+    '\u{e401}',
+    '\u{e400}',
+    '\u{e403}',
+    '\u{e402}',
+    '\u{e305}',
+    '\u{e304}',
+    '\u{e347}',
+    '\u{e346}',
+    '\u{e341}',
+    '\u{e340}',
+    '\u{e39b}',
+    '\u{e39a}',
+    '\u{e405}',
+    '\u{e404}',
+    '\u{e407}',
+    '\u{e406}',
+    '\u{e409}',
+    '\u{e408}',
+    '\u{e3f2}',
+    '\u{e335}',
+    '\u{e3e9}',
+    '\u{e3eb}',
+    '\u{e365}',
+    '\u{e3ed}',
+    '\u{e3e7}',
+    '\u{e3ef}',
+    '\u{e387}',
+    '\u{e3e1}',
+    '\u{e32d}',
+    '\u{e3e3}',
+    '\u{e363}',
+    '\u{e331}',
+    '\u{e35f}',
+    '\u{e385}',
+    '\u{e383}',
+    '\u{e333}',
+    '\u{e32f}',
+    '\u{e361}',
+    '\u{e367}',
+    '\u{e3e5}',
+    '\u{e334}',
+    '\u{e3e8}',
+    '\u{e3ea}',
+    '\u{e364}',
+    '\u{e3ec}',
+    '\u{e3e6}',
+    '\u{e3ee}',
+    '\u{e386}',
+    '\u{e3e0}',
+    '\u{e32c}',
+    '\u{e3e2}',
+    '\u{e362}',
+    '\u{e330}',
+    '\u{e35e}',
+    '\u{e384}',
+    '\u{e382}',
+    '\u{e332}',
+    '\u{e32e}',
+    '\u{e360}',
+    '\u{e366}',
+    '\u{e3e4}',
+    '\u{e319}',
+    '\u{e329}',
+    '\u{e327}',
+    '\u{e3db}',
+    '\u{e3d3}',
+    '\u{e3c7}',
+    '\u{e3b9}',
+    '\u{e3c5}',
+    '\u{e3bb}',
+    '\u{e357}',
+    '\u{e351}',
+    '\u{e3c3}',
+    '\u{e3bd}',
+    '\u{e3c9}',
+    '\u{e3b7}',
+    '\u{e3c1}',
+    '\u{e3bf}',
+    '\u{e37d}',
+    '\u{e37b}',
+    '\u{e3cf}',
+    '\u{e3b1}',
+    '\u{e323}',
+    '\u{e311}',
+    '\u{e32b}',
+    '\u{e325}',
+    '\u{e3d9}',
+    '\u{e3d5}',
+    '\u{e3cd}',
+    '\u{e3b3}',
+    '\u{e359}',
+    '\u{e34f}',
+    '\u{e31f}',
+    '\u{e315}',
+    '\u{e35d}',
+    '\u{e34b}',
+    '\u{e3dd}',
+    '\u{e3d1}',
+    '\u{e37f}',
+    '\u{e379}',
+    '\u{e381}',
+    '\u{e377}',
+    '\u{e3d7}',
+    '\u{e31d}',
+    '\u{e317}',
+    '\u{e321}',
+    '\u{e313}',
+    '\u{e35b}',
+    '\u{e34d}',
+    '\u{e355}',
+    '\u{e353}',
+    '\u{e3cb}',
+    '\u{e3b5}',
+    '\u{e40b}',
+    '\u{e40a}',
+    '\u{e3f3}',
+    '\u{e3f1}',
+    '\u{e3f0}',
+    '\u{e318}',
+    '\u{e328}',
+    '\u{e326}',
+    '\u{e3da}',
+    '\u{e3d2}',
+    '\u{e3b8}',
+    '\u{e3c6}',
+    '\u{e3ba}',
+    '\u{e3c4}',
+    '\u{e350}',
+    '\u{e356}',
+    '\u{e3bc}',
+    '\u{e3c2}',
+    '\u{e3b6}',
+    '\u{e3c8}',
+    '\u{e3be}',
+    '\u{e3c0}',
+    '\u{e37a}',
+    '\u{e37c}',
+    '\u{e3b0}',
+    '\u{e3ce}',
+    '\u{e310}',
+    '\u{e322}',
+    '\u{e32a}',
+    '\u{e324}',
+    '\u{e3d8}',
+    '\u{e3d4}',
+    '\u{e3b2}',
+    '\u{e3cc}',
+    '\u{e34e}',
+    '\u{e358}',
+    '\u{e314}',
+    '\u{e31e}',
+    '\u{e34a}',
+    '\u{e35c}',
+    '\u{e3dc}',
+    '\u{e3d0}',
+    '\u{e378}',
+    '\u{e37e}',
+    '\u{e376}',
+    '\u{e380}',
+    '\u{e3d6}',
+    '\u{e316}',
+    '\u{e31c}',
+    '\u{e312}',
+    '\u{e320}',
+    '\u{e34c}',
+    '\u{e35a}',
+    '\u{e352}',
+    '\u{e354}',
+    '\u{e3b4}',
+    '\u{e3ca}',
+    '\u{e31a}',
+    '\u{e31b}',
+    '\u{e3de}',
+    '\u{e3df}',
+    '\u{e8ca}',
+    '\u{e8c8}',
+    '\u{e8c9}',
+    '\u{e8c6}',
+    '\u{e8c7}',
+    '\u{e079}',
+    '\u{e8bc}',
+    '\u{e8bd}',
+    '\u{e8bb}',
+    '\u{e8c0}',
+    '\u{e8bf}',
+    '\u{e8be}',
+    '\u{e8c4}',
+    '\u{e8c2}',
+    '\u{e8c1}',
+    '\u{e8c3}',
+    '\u{e8c5}',
+    '\u{e8cc}',
+    '\u{e8cb}',
+    '\u{e8ac}',
+    '\u{e8a8}',
+    '\u{e8ab}',
+    '\u{e8a4}',
+    '\u{e8a1}',
+    '\u{e8b1}',
+    '\u{e8b2}',
+    '\u{e8b3}',
+    '\u{e8aa}',
+    '\u{e8a7}',
+    '\u{e8a3}',
+    '\u{e8ad}',
+    '\u{e8a5}',
+    '\u{e8a9}',
+    '\u{e8a0}',
+    '\u{e8af}',
+    '\u{e8b0}',
+    '\u{e8ae}',
+    '\u{e8a2}',
+    '\u{e8a6}',
+    '\u{e8b5}',
+    '\u{e8ba}',
+    '\u{e8b7}',
+    '\u{e8b8}',
+    '\u{e8b9}',
+    '\u{e8b4}',
+    '\u{e8b6}',
+    '\u{e8cd}',
+    '\u{e8ce}',
+    '\u{e8cf}',
+    '\u{e8d0}',
+    '\u{e8d1}',
+    '\u{e8d2}',
+    '\u{e8d3}',
+    '\u{e8d4}',
+    '\u{e8d5}',
+    '\u{e8d6}',
+    '\u{e454}',
+    '\u{e450}',
+    '\u{e455}',
+    '\u{e451}',
+    '\u{e456}',
+    '\u{e452}',
+    '\u{e457}',
+    '\u{e453}',
+    '\u{e27b}',
+    '\u{e27a}',
+    '\u{e442}',
+    '\u{e445}',
+    '\u{e26c}',
+    '\u{e26d}',
+    '\u{e440}',
+    '\u{e447}',
+    '\u{e2ef}',
+    '\u{e2e6}',
+    '\u{e2e8}',
+    '\u{e2ea}',
+    '\u{ee50}',
+    '\u{e2ec}',
+    '\u{ee52}',
+    '\u{ee54}',
+    '\u{ee56}',
+    '\u{ee58}',
+    '\u{e2f7}',
+    '\u{e2ee}',
+    '\u{e2e7}',
+    '\u{e2e9}',
+    '\u{e2eb}',
+    '\u{ee51}',
+    '\u{e2ed}',
+    '\u{ee53}',
+    '\u{ee55}',
+    '\u{ee57}',
+    '\u{ee59}',
+    '\u{e2f8}',
+    '\u{e47a}',
+    '\u{e479}',
+    '\u{e264}',
+    '\u{ed30}',
+    '\u{e2f0}',
+    '\u{e2c0}',
+    '\u{e2c5}',
+    '\u{e483}',
+    '\u{e2d4}',
+    '\u{e2d9}',
+    '\u{e485}',
+    '\u{e2ca}',
+    '\u{e2cf}',
+    '\u{e263}',
+    '\u{ed38}',
+    '\u{e2f4}',
+    '\u{e2c4}',
+    '\u{e2c9}',
+    '\u{e2d8}',
+    '\u{e2dd}',
+    '\u{e2ce}',
+    '\u{e2d3}',
+    '\u{e2fa}',
+    '\u{e2fb}',
+    '\u{e2f9}',
+    '\u{e296}',
+    '\u{e298}',
+    '\u{e297}',
+    '\u{e293}',
+    '\u{e292}',
+    '\u{e279}',
+    '\u{e276}',
+    '\u{e260}',
+    '\u{ed32}',
+    '\u{e2f1}',
+    '\u{ed53}',
+    '\u{e2c1}',
+    '\u{e2c6}',
+    '\u{ed52}',
+    '\u{ed5c}',
+    '\u{ed5b}',
+    '\u{e2d5}',
+    '\u{e2da}',
+    '\u{e484}',
+    '\u{e2cb}',
+    '\u{e2d0}',
+    '\u{ee65}',
+    '\u{ee69}',
+    '\u{ee64}',
+    '\u{ee67}',
+    '\u{ee68}',
+    '\u{ee66}',
+    '\u{e29a}',
+    '\u{e299}',
+    '\u{e2b6}',
+    '\u{e2b7}',
+    '\u{e2b5}',
+    '\u{e2b2}',
+    '\u{e2b1}',
+    '\u{e2b0}',
+    '\u{e2b3}',
+    '\u{e2b4}',
+    '\u{e443}',
+    '\u{e444}',
+    '\u{e460}',
+    '\u{e441}',
+    '\u{e446}',
+    '\u{e47d}',
+    '\u{e2de}',
+    '\u{e2e4}',
+    '\u{e2e2}',
+    '\u{e2e0}',
+    '\u{ed51}',
+    '\u{e284}',
+    '\u{e285}',
+    '\u{e261}',
+    '\u{ed34}',
+    '\u{e2f2}',
+    '\u{e267}',
+    '\u{ed55}',
+    '\u{e2c2}',
+    '\u{e2c7}',
+    '\u{ed54}',
+    '\u{e482}',
+    '\u{e268}',
+    '\u{e2d6}',
+    '\u{e2db}',
+    '\u{e2cc}',
+    '\u{e2d1}',
+    '\u{e29c}',
+    '\u{e29b}',
+    '\u{e48f}',
+    '\u{ed59}',
+    '\u{e48e}',
+    '\u{ed58}',
+    '\u{e48b}',
+    '\u{e48a}',
+    '\u{e26a}',
+    '\u{e26b}',
+    '\u{e2f5}',
+    '\u{e2f6}',
+    '\u{e47f}',
+    '\u{ed33}',
+    '\u{e270}',
+    '\u{e480}',
+    '\u{e273}',
+    '\u{e478}',
+    '\u{e280}',
+    '\u{e488}',
+    '\u{e47e}',
+    '\u{ed35}',
+    '\u{e275}',
+    '\u{e472}',
+    '\u{e272}',
+    '\u{e282}',
+    '\u{e475}',
+    '\u{e2df}',
+    '\u{e2e5}',
+    '\u{e2e3}',
+    '\u{e2e1}',
+    '\u{ed50}',
+    '\u{e295}',
+    '\u{e294}',
+    '\u{e291}',
+    '\u{e290}',
+    '\u{e262}',
+    '\u{ed36}',
+    '\u{e2f3}',
+    '\u{ed57}',
+    '\u{e2c3}',
+    '\u{e2c8}',
+    '\u{e473}',
+    '\u{ed56}',
+    '\u{ed5e}',
+    '\u{ed5d}',
+    '\u{e481}',
+    '\u{e269}',
+    '\u{e2d7}',
+    '\u{e2dc}',
+    '\u{e2cd}',
+    '\u{e2d2}',
+    '\u{e2a0}',
+    '\u{e2a3}',
+    '\u{e2a2}',
+    '\u{e2a5}',
+    '\u{e2a1}',
+    '\u{e2a4}',
+    '\u{e461}',
+    '\u{e477}',
+    '\u{e476}',
+    '\u{ed31}',
+    '\u{e271}',
+    '\u{e278}',
+    '\u{e489}',
+    '\u{e486}',
+    '\u{e487}',
+    '\u{e281}',
+    '\u{ed37}',
+    '\u{e277}',
+    '\u{e274}',
+    '\u{e474}',
+    '\u{e283}',
+    '\u{ed5a}',
+    '\u{e266}',
+    '\u{e265}',
+    '\u{e48d}',
+    '\u{e48c}',
+    '\u{ee61}',
+    '\u{ee63}',
+    '\u{ee62}',
+    '\u{ee60}',
+    '\u{e47c}',
+    '\u{e47b}',
+    '\u{e434}',
+    '\u{e429}',
+    '\u{e435}',
+    '\u{e42a}',
+    '\u{e42b}',
+    '\u{e420}',
+    '\u{e42c}',
+    '\u{e421}',
+    '\u{e42d}',
+    '\u{e422}',
+    '\u{e42e}',
+    '\u{e423}',
+    '\u{e42f}',
+    '\u{e424}',
+    '\u{e430}',
+    '\u{e425}',
+    '\u{e431}',
+    '\u{e426}',
+    '\u{e432}',
+    '\u{e427}',
+    '\u{e433}',
+    '\u{e428}',
+    '\u{e470}',
+    '\u{e471}',
+    '\u{e86a}',
+    '\u{e863}',
+    '\u{e86b}',
+    '\u{e860}',
+    '\u{e869}',
+    '\u{e861}',
+    '\u{e862}',
+    '\u{e864}',
+    '\u{e868}',
+    '\u{e867}',
+    '\u{e865}',
+    '\u{e866}',
+    '\u{e63c}',
+    '\u{e635}',
+    '\u{e634}',
+    '\u{eb64}',
+    '\u{eb65}',
+    '\u{eb63}',
+    '\u{eb66}',
+    '\u{eb62}',
+    '\u{eb60}',
+    '\u{eb67}',
+    '\u{eb61}',
+    '\u{eb74}',
+    '\u{eb75}',
+    '\u{eb73}',
+    '\u{eb76}',
+    '\u{eb72}',
+    '\u{eb70}',
+    '\u{eb77}',
+    '\u{eb71}',
+    '\u{eb6c}',
+    '\u{eb6d}',
+    '\u{eb6b}',
+    '\u{eb6e}',
+    '\u{eb6a}',
+    '\u{eb68}',
+    '\u{eb6f}',
+    '\u{eb69}',
+    '\u{eb7c}',
+    '\u{eb7d}',
+    '\u{eb7b}',
+    '\u{eb7e}',
+    '\u{eb7a}',
+    '\u{eb78}',
+    '\u{eb7f}',
+    '\u{eb79}',
+    '\u{eb8c}',
+    '\u{eb8d}',
+    '\u{eb8b}',
+    '\u{eb8e}',
+    '\u{eb8a}',
+    '\u{eb88}',
+    '\u{eb8f}',
+    '\u{eb89}',
+    '\u{eb84}',
+    '\u{eb85}',
+    '\u{eb83}',
+    '\u{eb86}',
+    '\u{eb82}',
+    '\u{eb80}',
+    '\u{eb87}',
+    '\u{eb81}',
+    '\u{e4a0}',
+    '\u{e4a1}',
+    '\u{e4b0}',
+    '\u{e4b1}',
+    '\u{e4ba}',
+    '\u{e4bb}',
+    '\u{e4ac}',
+    '\u{e4ad}',
+    '\u{e4ae}',
+    '\u{e4af}',
+    '\u{e4bc}',
+    '\u{e4bd}',
+    '\u{ed40}',
+    '\u{ed41}',
+    '\u{ed42}',
+    '\u{ed43}',
+    '\u{ed44}',
+    '\u{ed45}',
+    '\u{ed46}',
+    '\u{ed47}',
+    '\u{e4a6}',
+    '\u{e4a7}',
+    '\u{e4aa}',
+    '\u{e4ab}',
+    '\u{e4a8}',
+    '\u{e4a9}',
+    '\u{e4a2}',
+    '\u{e4a3}',
+    '\u{e4b6}',
+    '\u{e4b7}',
+    '\u{e4a4}',
+    '\u{e4b4}',
+    '\u{e4b5}',
+    '\u{e4a5}',
+    '\u{e4b2}',
+    '\u{e4b3}',
+    '\u{e4b8}',
+    '\u{e4b9}',
+    '\u{e1e7}',
+    '\u{e036}',
+    '\u{e037}',
+    '\u{e031}',
+    '\u{e032}',
+    '\u{e034}',
+    '\u{e035}',
+    '\u{e033}',
+    '\u{e038}',
+    '\u{e030}',
+    '\u{e039}',
+    '\u{eaf4}',
+    '\u{eafd}',
+    '\u{eafe}',
+    '\u{eaff}',
+    '\u{eb00}',
+    '\u{eb01}',
+    '\u{eb02}',
+    '\u{eaf5}',
+    '\u{eaf6}',
+    '\u{eaf7}',
+    '\u{eaf8}',
+    '\u{eaf9}',
+    '\u{eafa}',
+    '\u{eafb}',
+    '\u{eafc}',
+    '\u{eb03}',
+    '\u{e000}',
+    '\u{e002}',
+    '\u{e004}',
+    '\u{e003}',
+    '\u{e5e3}',
+    '\u{e5d6}',
+    '\u{e5d5}',
+    '\u{e5d4}',
+    '\u{e5d9}',
+    '\u{e5d8}',
+    '\u{e5d7}',
+    '\u{e5df}',
+    '\u{e5de}',
+    '\u{e5dd}',
+    '\u{e5dc}',
+    '\u{e5db}',
+    '\u{e5da}',
+    '\u{e5e1}',
+    '\u{e5e8}',
+    '\u{e5e9}',
+    '\u{e5ea}',
+    '\u{e5eb}',
+    '\u{e5e4}',
+    '\u{e5d3}',
+    '\u{e5d2}',
+    '\u{e5d1}',
+    '\u{e5ee}',
+    '\u{e5ed}',
+    '\u{e5ec}',
+    '\u{e5e5}',
+    '\u{e5e6}',
+    '\u{e5e7}',
+    '\u{e5e0}',
+    '\u{e5d0}',
+    '\u{e5e2}',
+    '\u{e5ef}',
+    '\u{e4ce}',
+    '\u{e4d5}',
+    '\u{e4cf}',
+    '\u{e4d0}',
+    '\u{e078}',
+    '\u{e22a}',
+    '\u{e05c}',
+    '\u{e05d}',
+    '\u{e05f}',
+    '\u{e05e}',
+    '\u{e07b}',
+    '\u{e061}',
+    '\u{e075}',
+    '\u{e060}',
+    '\u{e4d1}',
+    '\u{e4d4}',
+    '\u{e4d3}',
+    '\u{e4d7}',
+    '\u{e4d2}',
+    '\u{e9d6}',
+    '\u{e9d7}',
+    '\u{e994}',
+    '\u{e995}',
+    '\u{e9d9}',
+    '\u{e8f8}',
+    '\u{e906}',
+    '\u{e9d2}',
+    '\u{e9d3}',
+    '\u{e9bd}',
+    '\u{e9be}',
+    '\u{e9bf}',
+    '\u{e9c0}',
+    '\u{e9c1}',
+    '\u{ea08}',
+    '\u{ea09}',
+    '\u{ea07}',
+    '\u{ea05}',
+    '\u{ea04}',
+    '\u{ea06}',
+    '\u{e9b3}',
+    '\u{e9b2}',
+    '\u{e8f6}',
+    '\u{e8f4}',
+    '\u{e8f5}',
+    '\u{e8f3}',
+    '\u{e9b4}',
+    '\u{e9b5}',
+    '\u{e9b6}',
+    '\u{e9b7}',
+    '\u{e9b8}',
+    '\u{e9d8}',
+    '\u{e902}',
+    '\u{e9d0}',
+    '\u{e9d1}',
+    '\u{e9b9}',
+    '\u{e9ba}',
+    '\u{e9bb}',
+    '\u{e9bc}',
+    '\u{e99c}',
+    '\u{e99d}',
+    '\u{e99e}',
+    '\u{e9b0}',
+    '\u{e9b1}',
+    '\u{e990}',
+    '\u{e998}',
+    '\u{e9a1}',
+    '\u{e991}',
+    '\u{e992}',
+    '\u{e993}',
+    '\u{e999}',
+    '\u{e99a}',
+    '\u{e996}',
+    '\u{e997}',
+    '\u{e99b}',
+    '\u{e9d4}',
+    '\u{e9d5}',
+    '\u{e8f0}',
+    '\u{e8f2}',
+    '\u{e8f1}',
+    '\u{e99f}',
+    '\u{e9a0}',
+    '\u{e9c2}',
+    '\u{e9c3}',
+    '\u{e9c4}',
+    '\u{e9c5}',
+    '\u{e8f7}',
+    '\u{e07e}',
+    '\u{e07d}',
+    '\u{e07f}',
+    '\u{e048}',
+    '\u{e049}',
+    '\u{e897}',
+    '\u{e894}',
+    '\u{e898}',
+    '\u{e895}',
+    '\u{e899}',
+    '\u{e896}',
+    '\u{e891}',
+    '\u{e892}',
+    '\u{e890}',
+    '\u{e89a}',
+    '\u{e893}',
+    '\u{e8e0}',
+    '\u{e8e6}',
+    '\u{e8e4}',
+    '\u{e8e2}',
+    '\u{e8e1}',
+    '\u{e8e7}',
+    '\u{e8e5}',
+    '\u{e8e3}',
+    '\u{ed64}',
+    '\u{ed63}',
+    '\u{ed60}',
+    '\u{ed61}',
+    '\u{ed62}',
+    '\u{ed66}',
+    '\u{ed65}',
+    '\u{e87b}',
+    '\u{e872}',
+    '\u{e877}',
+    '\u{e878}',
+    '\u{e87c}',
+    '\u{e870}',
+    '\u{e871}',
+    '\u{e873}',
+    '\u{e874}',
+    '\u{e875}',
+    '\u{e879}',
+    '\u{e876}',
+    '\u{e87a}',
+    '\u{e4d6}',
+    '\u{e046}',
+    '\u{e045}',
+    '\u{ea3c}',
+    '\u{ea3d}',
+    '\u{ea3e}',
+    '\u{ea3f}',
+    '\u{ea34}',
+    '\u{ea35}',
+    '\u{ea36}',
+    '\u{ea37}',
+    '\u{ea30}',
+    '\u{ea31}',
+    '\u{ea32}',
+    '\u{ea33}',
+    '\u{ea40}',
+    '\u{ea41}',
+    '\u{ea38}',
+    '\u{ea39}',
+    '\u{ea3a}',
+    '\u{ea3b}',
+    '\u{e234}',
+    '\u{e5f0}',
+    '\u{e5f1}',
+    '\u{e546}',
+    '\u{e547}',
+    '\u{e549}',
+    '\u{e548}',
+    '\u{e53e}',
+    '\u{e53f}',
+    '\u{e52f}',
+    '\u{e530}',
+    '\u{e531}',
+    '\u{e532}',
+    '\u{e533}',
+    '\u{e522}',
+    '\u{e534}',
+    '\u{e535}',
+    '\u{e544}',
+    '\u{e545}',
+    '\u{e542}',
+    '\u{e543}',
+    '\u{e540}',
+    '\u{e521}',
+    '\u{e52d}',
+    '\u{e52c}',
+    '\u{e526}',
+    '\u{e541}',
+    '\u{e52e}',
+    '\u{e520}',
+    '\u{e52b}',
+    '\u{e52a}',
+    '\u{e529}',
+    '\u{e528}',
+    '\u{e527}',
+    '\u{e523}',
+    '\u{e53c}',
+    '\u{e53d}',
+    '\u{e524}',
+    '\u{e536}',
+    '\u{e538}',
+    '\u{e537}',
+    '\u{e539}',
+    '\u{e53b}',
+    '\u{e53a}',
+    '\u{e525}',
+    '\u{eb46}',
+    '\u{eb43}',
+    '\u{eb42}',
+    '\u{eb3e}',
+    '\u{eb45}',
+    '\u{eb44}',
+    '\u{eb40}',
+    '\u{eb41}',
+    '\u{eb3f}',
+    '\u{eb49}',
+    '\u{eb3c}',
+    '\u{eb4a}',
+    '\u{eb3d}',
+    '\u{eb1b}',
+    '\u{eb4d}',
+    '\u{eb4e}',
+    '\u{eb13}',
+    '\u{eb4f}',
+    '\u{eb2b}',
+    '\u{eb1f}',
+    '\u{eb11}',
+    '\u{eb12}',
+    '\u{eb47}',
+    '\u{eb48}',
+    '\u{eb23}',
+    '\u{eb1a}',
+    '\u{eb10}',
+    '\u{eb28}',
+    '\u{eb29}',
+    '\u{eb36}',
+    '\u{eb3b}',
+    '\u{eb37}',
+    '\u{eb38}',
+    '\u{eb39}',
+    '\u{eb3a}',
+    '\u{eb34}',
+    '\u{eb35}',
+    '\u{eb15}',
+    '\u{eb18}',
+    '\u{eb26}',
+    '\u{eb1e}',
+    '\u{eb1c}',
+    '\u{eb2a}',
+    '\u{eb19}',
+    '\u{eb24}',
+    '\u{eb20}',
+    '\u{eb25}',
+    '\u{eb22}',
+    '\u{eb21}',
+    '\u{eb1d}',
+    '\u{eb14}',
+    '\u{eb27}',
+    '\u{eb50}',
+    '\u{eb16}',
+    '\u{eb17}',
+    '\u{eb4b}',
+    '\u{eb4c}',
+    '\u{eb2c}',
+    '\u{eb2d}',
+    '\u{eb2e}',
+    '\u{eb33}',
+    '\u{eb2f}',
+    '\u{eb30}',
+    '\u{eb31}',
+    '\u{eb32}',
+    '\u{e062}',
+    '\u{e066}',
+    '\u{e063}',
+    '\u{e065}',
+    '\u{e064}',
+    '\u{e068}',
+    '\u{e067}',
+    '\u{e07c}',
+    '\u{e076}',
+    '\u{e077}',
+    '\u{e4c0}',
+    '\u{e4c1}',
+    '\u{e4c6}',
+    '\u{e4c7}',
+    '\u{e4ca}',
+    '\u{e4cb}',
+    '\u{e4c4}',
+    '\u{e4c5}',
+    '\u{e4cc}',
+    '\u{e4cd}',
+    '\u{e4c8}',
+    '\u{e4c9}',
+    '\u{e4c2}',
+    '\u{e4c3}',
+    '\u{ea50}',
+    '\u{ea51}',
+    '\u{ea52}',
+    '\u{ea53}',
+    '\u{ea54}',
+    '\u{ea55}',
+    '\u{ea56}',
+    '\u{ea57}',
+    '\u{ea58}',
+    '\u{ea59}',
+    '\u{ea5a}',
+    '\u{ea5b}',
+    '\u{ea5c}',
+    '\u{ea6f}',
+    '\u{ea5d}',
+    '\u{ecc0}',
+    '\u{ea5e}',
+    '\u{ea5f}',
+    '\u{ea60}',
+    '\u{ea61}',
+    '\u{ea62}',
+    '\u{ea68}',
+    '\u{ea69}',
+    '\u{ea6e}',
+    '\u{ea6d}',
+    '\u{ea63}',
+    '\u{ea67}',
+    '\u{ea64}',
+    '\u{ea65}',
+    '\u{ea6a}',
+    '\u{ea6b}',
+    '\u{ea6c}',
+    '\u{ea66}',
+    '\u{ecc1}',
+    '\u{ecc2}',
+    '\u{ed10}',
+    '\u{ed80}',
+    '\u{ed11}',
+    '\u{ed81}',
+    '\u{ed12}',
+    '\u{ed82}',
+    '\u{ed13}',
+    '\u{ed83}',
+    '\u{ed14}',
+    '\u{ed84}',
+    '\u{ed15}',
+    '\u{ed85}',
+    '\u{ed24}',
+    '\u{ed86}',
+    '\u{ed25}',
+    '\u{ed87}',
+    '\u{ed26}',
+    '\u{ed88}',
+    '\u{ed27}',
+    '\u{ed89}',
+    '\u{ed1b}',
+    '\u{ed1c}',
+    '\u{ed1e}',
+    '\u{ed19}',
+    '\u{ed2a}',
+    '\u{ed8c}',
+    '\u{ed28}',
+    '\u{ed8a}',
+    '\u{ed1a}',
+    '\u{ed23}',
+    '\u{ed1f}',
+    '\u{ed17}',
+    '\u{ed8e}',
+    '\u{ed2b}',
+    '\u{ed8d}',
+    '\u{ed29}',
+    '\u{ed8b}',
+    '\u{ed8f}',
+    '\u{ed2c}',
+    '\u{ed2d}',
+    '\u{ed2e}',
+    '\u{ed20}',
+    '\u{ed21}',
+    '\u{ed22}',
+    '\u{ed18}',
+    '\u{ed16}',
+    '\u{ed1d}',
+    '\u{e24f}',
+    '\u{e24e}',
+    '\u{e249}',
+    '\u{e248}',
+    '\u{e243}',
+    '\u{e242}',
+    '\u{e24b}',
+    '\u{e24a}',
+    '\u{e245}',
+    '\u{e244}',
+    '\u{e24d}',
+    '\u{e24c}',
+    '\u{e247}',
+    '\u{e246}',
+    '\u{e241}',
+    '\u{e240}',
+    '\u{e251}',
+    '\u{e250}',
+    '\u{e850}',
+    '\u{e851}',
+    '\u{e852}',
+    '\u{e853}',
+    '\u{e854}',
+    '\u{e855}',
+    '\u{e856}',
+    '\u{e857}',
+    '\u{e858}',
+    '\u{e85a}',
+    '\u{e859}',
+    '\u{ea93}',
+    '\u{ea94}',
+    '\u{ea8f}',
+    '\u{ea90}',
+    '\u{ea80}',
+    '\u{ea7f}',
+    '\u{ea81}',
+    '\u{ea78}',
+    '\u{ea99}',
+    '\u{ea75}',
+    '\u{ea74}',
+    '\u{ea84}',
+    '\u{ea83}',
+    '\u{ea7c}',
+    '\u{ea9b}',
+    '\u{ea9a}',
+    '\u{ea9d}',
+    '\u{ea9c}',
+    '\u{ea9f}',
+    '\u{ea9e}',
+    '\u{ea7a}',
+    '\u{ed01}',
+    '\u{ed00}',
+    '\u{ea7b}',
+    '\u{ea86}',
+    '\u{ea85}',
+    '\u{ed02}',
+    '\u{ea79}',
+    '\u{ea71}',
+    '\u{ea88}',
+    '\u{ea87}',
+    '\u{ea91}',
+    '\u{ea92}',
+    '\u{ea98}',
+    '\u{ed03}',
+    '\u{ea95}',
+    '\u{ea96}',
+    '\u{ea97}',
+    '\u{ea8a}',
+    '\u{ea89}',
+    '\u{ea77}',
+    '\u{ea76}',
+    '\u{ea82}',
+    '\u{ea7e}',
+    '\u{ea7d}',
+    '\u{ea8c}',
+    '\u{ea8b}',
+    '\u{ea73}',
+    '\u{ea72}',
+    '\u{ea8e}',
+    '\u{ea8d}',
+    '\u{ea70}',
+    '\u{e050}',
+    '\u{e054}',
+    '\u{e051}',
+    '\u{e053}',
+    '\u{e052}',
+    '\u{e056}',
+    '\u{e055}',
+    '\u{e057}',
+    '\u{e05b}',
+    '\u{e05a}',
+    '\u{e07a}',
+    '\u{e059}',
+    '\u{e058}',
+    '\u{e073}',
+    '\u{e074}',
+    '\u{e586}',
+    '\u{e585}',
+    '\u{e561}',
+    '\u{e560}',
+    '\u{e563}',
+    '\u{e562}',
+    '\u{e565}',
+    '\u{e564}',
+    '\u{e848}',
+    '\u{e849}',
+    '\u{e83f}',
+    '\u{e843}',
+    '\u{e844}',
+    '\u{e842}',
+    '\u{e83e}',
+    '\u{e840}',
+    '\u{e83d}',
+    '\u{e841}',
+    '\u{e832}',
+    '\u{e833}',
+    '\u{e834}',
+    '\u{e84a}',
+    '\u{e84b}',
+    '\u{e84c}',
+    '\u{e84d}',
+    '\u{e835}',
+    '\u{e836}',
+    '\u{e837}',
+    '\u{e838}',
+    '\u{e839}',
+    '\u{e83a}',
+    '\u{e83b}',
+    '\u{e83c}',
+    '\u{e847}',
+    '\u{e846}',
+    '\u{e831}',
+    '\u{e830}',
+    '\u{eab2}',
+    '\u{e845}',
+    '\u{eab3}',
+    '\u{e81f}',
+    '\u{e81e}',
+    '\u{e81b}',
+    '\u{e81c}',
+    '\u{e81d}',
+    '\u{e812}',
+    '\u{e815}',
+    '\u{e814}',
+    '\u{e816}',
+    '\u{e810}',
+    '\u{e811}',
+    '\u{e813}',
+    '\u{e817}',
+    '\u{e81a}',
+    '\u{e819}',
+    '\u{e818}',
+    '\u{e821}',
+    '\u{e820}',
+    '\u{e68f}',
+    '\u{e681}',
+    '\u{e683}',
+    '\u{e682}',
+    '\u{e680}',
+    '\u{e695}',
+    '\u{e696}',
+    '\u{e69a}',
+    '\u{e699}',
+    '\u{e698}',
+    '\u{e697}',
+    '\u{e68d}',
+    '\u{e68e}',
+    '\u{e69c}',
+    '\u{e688}',
+    '\u{e69b}',
+    '\u{e68c}',
+    '\u{e685}',
+    '\u{e68a}',
+    '\u{e684}',
+    '\u{e69d}',
+    '\u{e689}',
+    '\u{e686}',
+    '\u{e68b}',
+    '\u{e687}',
+    '\u{e694}',
+    '\u{e690}',
+    '\u{e693}',
+    '\u{e691}',
+    '\u{e692}',
+    '\u{ed70}',
+    '\u{ede2}',
+    '\u{edd8}',
+    '\u{ede1}',
+    '\u{edda}',
+    '\u{edc6}',
+    '\u{edcd}',
+    '\u{eda7}',
+    '\u{eda6}',
+    '\u{eddd}',
+    '\u{edb8}',
+    '\u{edba}',
+    '\u{edeb}',
+    '\u{edb2}',
+    '\u{edb3}',
+    '\u{edcf}',
+    '\u{edd5}',
+    '\u{edd7}',
+    '\u{eda9}',
+    '\u{edb0}',
+    '\u{edb1}',
+    '\u{eda8}',
+    '\u{edd1}',
+    '\u{edd0}',
+    '\u{edd3}',
+    '\u{edaa}',
+    '\u{edc9}',
+    '\u{edbb}',
+    '\u{edb6}',
+    '\u{edc4}',
+    '\u{edcb}',
+    '\u{eda2}',
+    '\u{eda5}',
+    '\u{eda4}',
+    '\u{edad}',
+    '\u{edac}',
+    '\u{eda3}',
+    '\u{edd2}',
+    '\u{edd4}',
+    '\u{edbf}',
+    '\u{edbd}',
+    '\u{edee}',
+    '\u{edc1}',
+    '\u{edf0}',
+    '\u{edec}',
+    '\u{eded}',
+    '\u{ede3}',
+    '\u{edde}',
+    '\u{ede0}',
+    '\u{ede7}',
+    '\u{edc0}',
+    '\u{edbe}',
+    '\u{edef}',
+    '\u{edc2}',
+    '\u{edf1}',
+    '\u{edd6}',
+    '\u{ede8}',
+    '\u{edae}',
+    '\u{eddc}',
+    '\u{ede6}',
+    '\u{ede5}',
+    '\u{edce}',
+    '\u{edd9}',
+    '\u{edb4}',
+    '\u{edb5}',
+    '\u{edb9}',
+    '\u{edc3}',
+    '\u{edc8}',
+    '\u{eda0}',
+    '\u{edc7}',
+    '\u{edca}',
+    '\u{eddb}',
+    '\u{eda1}',
+    '\u{edab}',
+    '\u{edbc}',
+    '\u{edb7}',
+    '\u{edc5}',
+    '\u{edcc}',
+    '\u{edaf}',
+    '\u{ede9}',
+    '\u{edea}',
+    '\u{ede4}',
+    '\u{eddf}',
+    '\u{e668}',
+    '\u{e669}',
+    '\u{e66a}',
+    '\u{e66b}',
+    '\u{e66c}',
+    '\u{e66d}',
+    '\u{e65e}',
+    '\u{e65f}',
+    '\u{e653}',
+    '\u{e654}',
+    '\u{e652}',
+    '\u{e656}',
+    '\u{e65b}',
+    '\u{e65c}',
+    '\u{e661}',
+    '\u{e662}',
+    '\u{e663}',
+    '\u{e674}',
+    '\u{e666}',
+    '\u{e673}',
+    '\u{e672}',
+    '\u{e658}',
+    '\u{e651}',
+    '\u{e676}',
+    '\u{e677}',
+    '\u{e650}',
+    '\u{e65a}',
+    '\u{e659}',
+    '\u{e664}',
+    '\u{e665}',
+    '\u{e675}',
+    '\u{e655}',
+    '\u{e657}',
+    '\u{e65d}',
+    '\u{e670}',
+    '\u{e671}',
+    '\u{e66e}',
+    '\u{e66f}',
+    '\u{e667}',
+    '\u{e660}',
+    '\u{ec3e}',
+    '\u{ec3d}',
+    '\u{ec3c}',
+    '\u{ec30}',
+    '\u{ec31}',
+    '\u{ec3a}',
+    '\u{ec39}',
+    '\u{ec3b}',
+    '\u{ec35}',
+    '\u{ec36}',
+    '\u{ec38}',
+    '\u{ec37}',
+    '\u{ec32}',
+    '\u{ec33}',
+    '\u{ec34}',
+    '\u{ec40}',
+    '\u{ec43}',
+    '\u{ec45}',
+    '\u{ec42}',
+    '\u{ec41}',
+    '\u{ec44}',
+    '\u{ec46}',
+    '\u{e04c}',
+    '\u{e022}',
+    '\u{e024}',
+    '\u{e023}',
+    '\u{eba4}',
+    '\u{eba5}',
+    '\u{eba3}',
+    '\u{ebab}',
+    '\u{ebac}',
+    '\u{ebaa}',
+    '\u{eba6}',
+    '\u{eba8}',
+    '\u{eba9}',
+    '\u{eba7}',
+    '\u{ebae}',
+    '\u{ebaf}',
+    '\u{ebb0}',
+    '\u{ebad}',
+    '\u{ebd0}',
+    '\u{ebcd}',
+    '\u{ebce}',
+    '\u{ebcf}',
+    '\u{ebd5}',
+    '\u{ebd4}',
+    '\u{ebc0}',
+    '\u{ebc1}',
+    '\u{ebc2}',
+    '\u{ebc3}',
+    '\u{ebc4}',
+    '\u{ebc5}',
+    '\u{ebc6}',
+    '\u{ebc7}',
+    '\u{ebc8}',
+    '\u{ebc9}',
+    '\u{ebca}',
+    '\u{ebcb}',
+    '\u{ebcc}',
+    '\u{ebd3}',
+    '\u{ebd2}',
+    '\u{ebd1}',
+    '\u{ec00}',
+    '\u{ec17}',
+    '\u{ec01}',
+    '\u{ec18}',
+    '\u{ec02}',
+    '\u{ec19}',
+    '\u{ec03}',
+    '\u{ec1a}',
+    '\u{ec04}',
+    '\u{ec1b}',
+    '\u{ec05}',
+    '\u{ec1c}',
+    '\u{ec06}',
+    '\u{ec1d}',
+    '\u{ec07}',
+    '\u{ec1e}',
+    '\u{ec08}',
+    '\u{ec1f}',
+    '\u{ec09}',
+    '\u{ec20}',
+    '\u{ec0a}',
+    '\u{ec21}',
+    '\u{ec0b}',
+    '\u{ec22}',
+    '\u{ec0c}',
+    '\u{ec23}',
+    '\u{ec0d}',
+    '\u{ec0e}',
+    '\u{ec0f}',
+    '\u{ec10}',
+    '\u{ec11}',
+    '\u{ec12}',
+    '\u{ec13}',
+    '\u{ec14}',
+    '\u{ec15}',
+    '\u{ec16}',
+    '\u{ebf1}',
+    '\u{ebf0}',
+    '\u{ebe0}',
+    '\u{ebe1}',
+    '\u{ebe2}',
+    '\u{ebe3}',
+    '\u{ebe4}',
+    '\u{ebe5}',
+    '\u{ebe6}',
+    '\u{ebe7}',
+    '\u{ebe8}',
+    '\u{ebe9}',
+    '\u{ebf4}',
+    '\u{ebf3}',
+    '\u{ebf5}',
+    '\u{ebea}',
+    '\u{ebec}',
+    '\u{ebed}',
+    '\u{ebeb}',
+    '\u{ebee}',
+    '\u{ebef}',
+    '\u{ebf2}',
+    '\u{ebf6}',
+    '\u{eba0}',
+    '\u{eba2}',
+    '\u{eba1}',
+    '\u{e551}',
+    '\u{e550}',
+    '\u{e552}',
+    '\u{e553}',
+    '\u{e554}',
+    '\u{e555}',
+    '\u{e9e1}',
+    '\u{e9e0}',
+    '\u{e9e4}',
+    '\u{ea24}',
+    '\u{ea22}',
+    '\u{ea26}',
+    '\u{ea27}',
+    '\u{e9e2}',
+    '\u{e9e5}',
+    '\u{ea2a}',
+    '\u{ea23}',
+    '\u{ea25}',
+    '\u{ea28}',
+    '\u{e9e3}',
+    '\u{ea29}',
+    '\u{ea10}',
+    '\u{e952}',
+    '\u{e956}',
+    '\u{e95a}',
+    '\u{e951}',
+    '\u{e950}',
+    '\u{e954}',
+    '\u{e958}',
+    '\u{e953}',
+    '\u{e959}',
+    '\u{e95b}',
+    '\u{e957}',
+    '\u{e955}',
+    '\u{e905}',
+    '\u{e90a}',
+    '\u{e90b}',
+    '\u{e908}',
+    '\u{e907}',
+    '\u{e909}',
+    '\u{ea0f}',
+    '\u{ea0d}',
+    '\u{ea0e}',
+    '\u{ea0c}',
+    '\u{e940}',
+    '\u{e93f}',
+    '\u{e948}',
+    '\u{e946}',
+    '\u{e94c}',
+    '\u{e944}',
+    '\u{e942}',
+    '\u{e94a}',
+    '\u{e93e}',
+    '\u{e947}',
+    '\u{e945}',
+    '\u{e94b}',
+    '\u{e943}',
+    '\u{e941}',
+    '\u{e949}',
+    '\u{ea0a}',
+    '\u{ea03}',
+    '\u{ea0b}',
+    '\u{ea02}',
+    '\u{e903}',
+    '\u{e904}',
+    '\u{e900}',
+    '\u{e901}',
+    '\u{e92d}',
+    '\u{e92c}',
+    '\u{e934}',
+    '\u{e936}',
+    '\u{e935}',
+    '\u{e937}',
+    '\u{e930}',
+    '\u{e932}',
+    '\u{e931}',
+    '\u{e933}',
+    '\u{e93c}',
+    '\u{e938}',
+    '\u{e93a}',
+    '\u{e93b}',
+    '\u{e939}',
+    '\u{e93d}',
+    '\u{e970}',
+    '\u{e972}',
+    '\u{e971}',
+    '\u{e973}',
+    '\u{e974}',
+    '\u{e976}',
+    '\u{e975}',
+    '\u{e977}',
+    '\u{e978}',
+    '\u{e97a}',
+    '\u{e979}',
+    '\u{e97b}',
+    '\u{e97c}',
+    '\u{e97e}',
+    '\u{e97d}',
+    '\u{e97f}',
+    '\u{e980}',
+    '\u{e982}',
+    '\u{e981}',
+    '\u{e983}',
+    '\u{e984}',
+    '\u{e986}',
+    '\u{e985}',
+    '\u{e987}',
+    '\u{e988}',
+    '\u{e98a}',
+    '\u{e989}',
+    '\u{e98b}',
+    '\u{e98c}',
+    '\u{e98e}',
+    '\u{e98d}',
+    '\u{e98f}',
+    '\u{e910}',
+    '\u{e919}',
+    '\u{e91a}',
+    '\u{e911}',
+    '\u{e912}',
+    '\u{e913}',
+    '\u{e914}',
+    '\u{e915}',
+    '\u{e916}',
+    '\u{e917}',
+    '\u{e918}',
+    '\u{e920}',
+    '\u{e924}',
+    '\u{e925}',
+    '\u{e922}',
+    '\u{e923}',
+    '\u{e921}',
+    '\u{e926}',
+    '\u{e927}',
+    '\u{e928}',
+    '\u{e929}',
+    '\u{ee90}',
+    '\u{ee91}',
+    '\u{ee92}',
+    '\u{ee93}',
+    '\u{ee94}',
+    '\u{e92b}',
+    '\u{e92a}',
+    '\u{e91c}',
+    '\u{e91d}',
+    '\u{e91f}',
+    '\u{e91e}',
+    '\u{e91b}',
+    '\u{e9f3}',
+    '\u{e9f7}',
+    '\u{e9f2}',
+    '\u{e9f1}',
+    '\u{e9f0}',
+    '\u{e9f5}',
+    '\u{e9f4}',
+    '\u{e9f8}',
+    '\u{e9f6}',
+    '\u{ea01}',
+    '\u{ea00}',
+    '\u{e92f}',
+    '\u{e92e}',
+    '\u{e95e}',
+    '\u{e961}',
+    '\u{e95d}',
+    '\u{e95c}',
+    '\u{e95f}',
+    '\u{e962}',
+    '\u{e960}',
+    '\u{ecb7}',
+    '\u{ecb6}',
+    '\u{ecb5}',
+    '\u{ecb0}',
+    '\u{ecaf}',
+    '\u{ecaa}',
+    '\u{eca9}',
+    '\u{ecb2}',
+    '\u{ecb1}',
+    '\u{ecac}',
+    '\u{ecab}',
+    '\u{ecb4}',
+    '\u{ecb3}',
+    '\u{ecae}',
+    '\u{ecad}',
+    '\u{eca8}',
+    '\u{eca7}',
+    '\u{eca0}',
+    '\u{eca1}',
+    '\u{eca4}',
+    '\u{eca3}',
+    '\u{eca6}',
+    '\u{eca5}',
+    '\u{eca2}',
+    '\u{ec63}',
+    '\u{ec64}',
+    '\u{ec61}',
+    '\u{ec60}',
+    '\u{ec62}',
+    '\u{e1e6}',
+    '\u{e1e5}',
+    '\u{e1e0}',
+    '\u{e1df}',
+    '\u{e1da}',
+    '\u{e1d9}',
+    '\u{e1e2}',
+    '\u{e1e1}',
+    '\u{e1dc}',
+    '\u{e1db}',
+    '\u{e1e4}',
+    '\u{e1e3}',
+    '\u{e1de}',
+    '\u{e1dd}',
+    '\u{e1d8}',
+    '\u{e1d7}',
+    '\u{e197}',
+    '\u{e196}',
+    '\u{e17f}',
+    '\u{e168}',
+    '\u{e180}',
+    '\u{e198}',
+    '\u{e181}',
+    '\u{e16a}',
+    '\u{e169}',
+    '\u{e19a}',
+    '\u{e199}',
+    '\u{e182}',
+    '\u{e16b}',
+    '\u{e183}',
+    '\u{e19b}',
+    '\u{e184}',
+    '\u{e16d}',
+    '\u{e16c}',
+    '\u{e19d}',
+    '\u{e19c}',
+    '\u{e185}',
+    '\u{e16e}',
+    '\u{e186}',
+    '\u{e19e}',
+    '\u{e187}',
+    '\u{e170}',
+    '\u{e16f}',
+    '\u{e1a0}',
+    '\u{e19f}',
+    '\u{e188}',
+    '\u{e171}',
+    '\u{e189}',
+    '\u{e1a1}',
+    '\u{e18a}',
+    '\u{e173}',
+    '\u{e172}',
+    '\u{eef2}',
+    '\u{eee9}',
+    '\u{eee0}',
+    '\u{e160}',
+    '\u{e158}',
+    '\u{e150}',
+    '\u{e1d0}',
+    '\u{e1d1}',
+    '\u{e1a3}',
+    '\u{e1a2}',
+    '\u{e18b}',
+    '\u{e174}',
+    '\u{e18c}',
+    '\u{e1a4}',
+    '\u{e18d}',
+    '\u{e176}',
+    '\u{e175}',
+    '\u{e1af}',
+    '\u{e1ae}',
+    '\u{e1ad}',
+    '\u{e1a6}',
+    '\u{e1a5}',
+    '\u{e18e}',
+    '\u{e177}',
+    '\u{e18f}',
+    '\u{e1a7}',
+    '\u{e190}',
+    '\u{e179}',
+    '\u{e178}',
+    '\u{e163}',
+    '\u{e15b}',
+    '\u{e153}',
+    '\u{eef6}',
+    '\u{eeed}',
+    '\u{eee4}',
+    '\u{e1a9}',
+    '\u{e1a8}',
+    '\u{e191}',
+    '\u{e17a}',
+    '\u{e192}',
+    '\u{e1aa}',
+    '\u{e193}',
+    '\u{e17c}',
+    '\u{e17b}',
+    '\u{e1ab}',
+    '\u{e194}',
+    '\u{e1ac}',
+    '\u{e195}',
+    '\u{e17e}',
+    '\u{e17d}',
+    '\u{e1d4}',
+    '\u{e1d3}',
+    '\u{e165}',
+    '\u{e15d}',
+    '\u{e155}',
+    '\u{eef9}',
+    '\u{eef0}',
+    '\u{eee7}',
+    '\u{eef8}',
+    '\u{eeef}',
+    '\u{eee6}',
+    '\u{eef5}',
+    '\u{eeec}',
+    '\u{eee3}',
+    '\u{e162}',
+    '\u{e15a}',
+    '\u{e152}',
+    '\u{e1d6}',
+    '\u{e1d5}',
+    '\u{eef4}',
+    '\u{eeeb}',
+    '\u{eee2}',
+    '\u{e161}',
+    '\u{e159}',
+    '\u{e151}',
+    '\u{eef3}',
+    '\u{eeea}',
+    '\u{eee1}',
+    '\u{eef7}',
+    '\u{eeee}',
+    '\u{eee5}',
+    '\u{e1c9}',
+    '\u{ecdc}',
+    '\u{e1c8}',
+    '\u{e1b9}',
+    '\u{ecd4}',
+    '\u{e1b8}',
+    '\u{e1c5}',
+    '\u{ecda}',
+    '\u{e1c4}',
+    '\u{e1c1}',
+    '\u{ecd8}',
+    '\u{e1c0}',
+    '\u{e1bd}',
+    '\u{ecd6}',
+    '\u{e1c7}',
+    '\u{ecdb}',
+    '\u{e1c6}',
+    '\u{e1bc}',
+    '\u{e1c3}',
+    '\u{ecd9}',
+    '\u{e1c2}',
+    '\u{e1b1}',
+    '\u{ecd0}',
+    '\u{e1b0}',
+    '\u{e1b3}',
+    '\u{ecd1}',
+    '\u{e1b2}',
+    '\u{e1b7}',
+    '\u{ecd3}',
+    '\u{e1b6}',
+    '\u{e1b5}',
+    '\u{ecd2}',
+    '\u{e1b4}',
+    '\u{e1bf}',
+    '\u{ecd7}',
+    '\u{e1cb}',
+    '\u{ecdd}',
+    '\u{e1ca}',
+    '\u{e1be}',
+    '\u{e1bb}',
+    '\u{ecd5}',
+    '\u{e1ba}',
+    '\u{e167}',
+    '\u{e15f}',
+    '\u{e157}',
+    '\u{e164}',
+    '\u{e15c}',
+    '\u{e154}',
+    '\u{eefa}',
+    '\u{eef1}',
+    '\u{eee8}',
+    '\u{e166}',
+    '\u{e15e}',
+    '\u{e156}',
+    '\u{e1d2}',
+    '\u{e0a4}',
+    '\u{e0f7}',
+    '\u{e0b3}',
+    '\u{e0b0}',
+    '\u{e0b2}',
+    '\u{e0b1}',
+    '\u{e0e4}',
+    '\u{e0e8}',
+    '\u{e0e7}',
+    '\u{e0eb}',
+    '\u{e0e5}',
+    '\u{e0e9}',
+    '\u{e0e6}',
+    '\u{e0ea}',
+    '\u{e0ec}',
+    '\u{e124}',
+    '\u{e128}',
+    '\u{e12e}',
+    '\u{e12d}',
+    '\u{e12c}',
+    '\u{e126}',
+    '\u{e12a}',
+    '\u{e134}',
+    '\u{e133}',
+    '\u{e132}',
+    '\u{e127}',
+    '\u{e12b}',
+    '\u{e137}',
+    '\u{e136}',
+    '\u{e135}',
+    '\u{e123}',
+    '\u{e122}',
+    '\u{e121}',
+    '\u{e120}',
+    '\u{e125}',
+    '\u{e129}',
+    '\u{e131}',
+    '\u{e130}',
+    '\u{e12f}',
+    '\u{eeae}',
+    '\u{eead}',
+    '\u{eeaf}',
+    '\u{eeb5}',
+    '\u{eeb4}',
+    '\u{eeb3}',
+    '\u{eea6}',
+    '\u{eea5}',
+    '\u{eea4}',
+    '\u{eeac}',
+    '\u{eeab}',
+    '\u{eeaa}',
+    '\u{eea9}',
+    '\u{eea8}',
+    '\u{eea7}',
+    '\u{eea3}',
+    '\u{eea2}',
+    '\u{eea1}',
+    '\u{eeb2}',
+    '\u{eeb1}',
+    '\u{eeb0}',
+    '\u{e0db}',
+    '\u{e0e2}',
+    '\u{e0dc}',
+    '\u{e139}',
+    '\u{e13b}',
+    '\u{e141}',
+    '\u{e140}',
+    '\u{e13f}',
+    '\u{e138}',
+    '\u{e13a}',
+    '\u{e13e}',
+    '\u{e13d}',
+    '\u{e13c}',
+    '\u{e0d7}',
+    '\u{e0df}',
+    '\u{e0d9}',
+    '\u{e0e3}',
+    '\u{e0e1}',
+    '\u{e0da}',
+    '\u{e0fc}',
+    '\u{e0dd}',
+    '\u{e0de}',
+    '\u{e0d8}',
+    '\u{e0e0}',
+    '\u{e0a0}',
+    '\u{e0a1}',
+    '\u{e0b4}',
+    '\u{e0a3}',
+    '\u{e0fb}',
+    '\u{e0b6}',
+    '\u{e0f8}',
+    '\u{e0f9}',
+    '\u{e0f4}',
+    '\u{e0f1}',
+    '\u{e0f3}',
+    '\u{e0f2}',
+    '\u{e0f0}',
+    '\u{e0ed}',
+    '\u{e0ef}',
+    '\u{e0ee}',
+    '\u{e0cb}',
+    '\u{e0ca}',
+    '\u{eea0}',
+    '\u{e0a5}',
+    '\u{e0ce}',
+    '\u{e0f5}',
+    '\u{e0f6}',
+    '\u{e0af}',
+    '\u{e0ac}',
+    '\u{e0ae}',
+    '\u{e0ad}',
+    '\u{e144}',
+    '\u{e143}',
+    '\u{e142}',
+    '\u{e147}',
+    '\u{e146}',
+    '\u{e145}',
+    '\u{e113}',
+    '\u{e11c}',
+    '\u{e110}',
+    '\u{e118}',
+    '\u{e116}',
+    '\u{e114}',
+    '\u{e11d}',
+    '\u{e111}',
+    '\u{e119}',
+    '\u{e117}',
+    '\u{e115}',
+    '\u{e112}',
+    '\u{e104}',
+    '\u{e101}',
+    '\u{e108}',
+    '\u{e100}',
+    '\u{e107}',
+    '\u{e105}',
+    '\u{e10a}',
+    '\u{e103}',
+    '\u{e109}',
+    '\u{e102}',
+    '\u{e106}',
+    '\u{e0cf}',
+    '\u{e0d0}',
+    '\u{e0d5}',
+    '\u{e0d6}',
+    '\u{e0d1}',
+    '\u{e0d2}',
+    '\u{e0d3}',
+    '\u{e0d4}',
+    '\u{e0b9}',
+    '\u{e11a}',
+    '\u{e11b}',
+    '\u{e0b8}',
+    '\u{e0c7}',
+    '\u{e0c3}',
+    '\u{e0c5}',
+    '\u{e0c6}',
+    '\u{e0c4}',
+    '\u{e0c0}',
+    '\u{e0bf}',
+    '\u{e0c2}',
+    '\u{e0c1}',
+    '\u{e0cd}',
+    '\u{e0cc}',
+    '\u{e0be}',
+    '\u{e0ba}',
+    '\u{e0bc}',
+    '\u{e0c9}',
+    '\u{e0c8}',
+    '\u{e0bd}',
+    '\u{e0bb}',
+    '\u{e0b7}',
+    '\u{e0a2}',
+    '\u{e0fa}',
+    '\u{e0b5}',
+    '\u{e0a9}',
+    '\u{e0a6}',
+    '\u{e0a8}',
+    '\u{e0aa}',
+    '\u{e0ab}',
+    '\u{e0a7}',
+    '\u{ec91}',
+    '\u{ec93}',
+    '\u{ec95}',
+    '\u{ec97}',
+    '\u{e51f}',
+    '\u{ec90}',
+    '\u{e51a}',
+    '\u{e51b}',
+    '\u{ec92}',
+    '\u{ec94}',
+    '\u{ec96}',
+    '\u{ec98}',
+    '\u{e233}',
+    '\u{ee2e}',
+    '\u{ee2c}',
+    '\u{ee19}',
+    '\u{ee2f}',
+    '\u{ee2d}',
+    '\u{ee32}',
+    '\u{ee30}',
+    '\u{ee33}',
+    '\u{ee31}',
+    '\u{ee36}',
+    '\u{ee34}',
+    '\u{ee37}',
+    '\u{ee35}',
+    '\u{ee3a}',
+    '\u{ee38}',
+    '\u{ee3b}',
+    '\u{ee39}',
+    '\u{ee3e}',
+    '\u{ee3c}',
+    '\u{ee3f}',
+    '\u{ee3d}',
+    '\u{ee15}',
+    '\u{ee09}',
+    '\u{ee1c}',
+    '\u{ee16}',
+    '\u{ee0a}',
+    '\u{ee25}',
+    '\u{ee24}',
+    '\u{ee1e}',
+    '\u{ee26}',
+    '\u{ee1d}',
+    '\u{ee0c}',
+    '\u{ee00}',
+    '\u{ee0d}',
+    '\u{ee01}',
+    '\u{ee0e}',
+    '\u{ee02}',
+    '\u{ee0f}',
+    '\u{ee03}',
+    '\u{ee10}',
+    '\u{ee04}',
+    '\u{ee11}',
+    '\u{ee05}',
+    '\u{ee12}',
+    '\u{ee06}',
+    '\u{ee2a}',
+    '\u{ee22}',
+    '\u{ee13}',
+    '\u{ee07}',
+    '\u{ee14}',
+    '\u{ee08}',
+    '\u{ee17}',
+    '\u{ee0b}',
+    '\u{ee28}',
+    '\u{ee20}',
+    '\u{ee1a}',
+    '\u{ee18}',
+    '\u{ee27}',
+    '\u{ee1f}',
+    '\u{ee2b}',
+    '\u{ee23}',
+    '\u{ee29}',
+    '\u{ee21}',
+    '\u{ee1b}',
+    '\u{e59a}',
+    '\u{e59b}',
+    '\u{e59c}',
+    '\u{e5a7}',
+    '\u{e5a8}',
+    '\u{e581}',
+    '\u{e57e}',
+    '\u{e57d}',
+    '\u{e578}',
+    '\u{e56f}',
+    '\u{e592}',
+    '\u{e593}',
+    '\u{e5a2}',
+    '\u{e5a3}',
+    '\u{e576}',
+    '\u{e575}',
+    '\u{e572}',
+    '\u{e574}',
+    '\u{e597}',
+    '\u{e596}',
+    '\u{e594}',
+    '\u{e595}',
+    '\u{e598}',
+    '\u{e599}',
+    '\u{e5a5}',
+    '\u{e5a6}',
+    '\u{e59f}',
+    '\u{e56d}',
+    '\u{e57c}',
+    '\u{e57b}',
+    '\u{e580}',
+    '\u{e57f}',
+    '\u{ea21}',
+    '\u{e588}',
+    '\u{e570}',
+    '\u{e5b2}',
+    '\u{e5b3}',
+    '\u{e5be}',
+    '\u{e5c1}',
+    '\u{e5c2}',
+    '\u{e5bf}',
+    '\u{e5b1}',
+    '\u{e5c0}',
+    '\u{e5c3}',
+    '\u{e5c4}',
+    '\u{e5c7}',
+    '\u{e5c5}',
+    '\u{e5c6}',
+    '\u{e5bc}',
+    '\u{e5b0}',
+    '\u{e5b8}',
+    '\u{e5b5}',
+    '\u{e5b6}',
+    '\u{e5b9}',
+    '\u{e5ba}',
+    '\u{e5c8}',
+    '\u{e5bb}',
+    '\u{e5bd}',
+    '\u{e5b7}',
+    '\u{e5b4}',
+    '\u{ea20}',
+    '\u{e571}',
+    '\u{e573}',
+    '\u{e5a4}',
+    '\u{e587}',
+    '\u{e582}',
+    '\u{e584}',
+    '\u{e57a}',
+    '\u{e579}',
+    '\u{e56c}',
+    '\u{e590}',
+    '\u{e591}',
+    '\u{e5a0}',
+    '\u{e5a1}',
+    '\u{e56e}',
+    '\u{e589}',
+    '\u{e566}',
+    '\u{e567}',
+    '\u{e568}',
+    '\u{e569}',
+    '\u{e56a}',
+    '\u{e56b}',
+    '\u{e577}',
+    '\u{e583}',
+    '\u{e59d}',
+    '\u{e59e}',
+    '\u{e510}',
+    '\u{e511}',
+    '\u{e512}',
+    '\u{e513}',
+    '\u{e51c}',
+    '\u{e22b}',
+    '\u{e717}',
+    '\u{e712}',
+    '\u{e701}',
+    '\u{e6c3}',
+    '\u{e6fb}',
+    '\u{e6d4}',
+    '\u{e6d5}',
+    '\u{e7de}',
+    '\u{e7eb}',
+    '\u{e7da}',
+    '\u{e7ee}',
+    '\u{e7ed}',
+    '\u{e7d9}',
+    '\u{e7ea}',
+    '\u{e7e9}',
+    '\u{e7a1}',
+    '\u{e7a0}',
+    '\u{e7e4}',
+    '\u{e7e6}',
+    '\u{e7e5}',
+    '\u{e7dd}',
+    '\u{e7e1}',
+    '\u{e7d0}',
+    '\u{e7cf}',
+    '\u{e7ce}',
+    '\u{e7cd}',
+    '\u{e7cc}',
+    '\u{e7cb}',
+    '\u{e7e3}',
+    '\u{e79d}',
+    '\u{e79c}',
+    '\u{e785}',
+    '\u{e787}',
+    '\u{e786}',
+    '\u{e784}',
+    '\u{e791}',
+    '\u{e793}',
+    '\u{e792}',
+    '\u{e790}',
+    '\u{e779}',
+    '\u{e77b}',
+    '\u{e77a}',
+    '\u{e778}',
+    '\u{e7ab}',
+    '\u{e7ad}',
+    '\u{e7ac}',
+    '\u{e7aa}',
+    '\u{e7d4}',
+    '\u{e7d3}',
+    '\u{e7e2}',
+    '\u{e7df}',
+    '\u{e7ec}',
+    '\u{e79b}',
+    '\u{e79a}',
+    '\u{e78d}',
+    '\u{e78f}',
+    '\u{e78e}',
+    '\u{e78c}',
+    '\u{e775}',
+    '\u{e777}',
+    '\u{e776}',
+    '\u{e774}',
+    '\u{e7a7}',
+    '\u{e7a9}',
+    '\u{e7a8}',
+    '\u{e7a6}',
+    '\u{e79f}',
+    '\u{e79e}',
+    '\u{e7c8}',
+    '\u{e7e0}',
+    '\u{e7ca}',
+    '\u{e7c9}',
+    '\u{e7c7}',
+    '\u{e7d2}',
+    '\u{e7d1}',
+    '\u{e799}',
+    '\u{e798}',
+    '\u{e781}',
+    '\u{e783}',
+    '\u{e782}',
+    '\u{e780}',
+    '\u{e789}',
+    '\u{e78b}',
+    '\u{e78a}',
+    '\u{e788}',
+    '\u{e7db}',
+    '\u{e771}',
+    '\u{e773}',
+    '\u{e772}',
+    '\u{e770}',
+    '\u{e7a3}',
+    '\u{e7a5}',
+    '\u{e7a4}',
+    '\u{e7a2}',
+    '\u{e7dc}',
+    '\u{e7af}',
+    '\u{e7b1}',
+    '\u{e7b0}',
+    '\u{e7ae}',
+    '\u{e7d6}',
+    '\u{e7ef}',
+    '\u{e7d5}',
+    '\u{e7d8}',
+    '\u{e7d7}',
+    '\u{e795}',
+    '\u{e797}',
+    '\u{e796}',
+    '\u{e794}',
+    '\u{e77d}',
+    '\u{e77f}',
+    '\u{e77e}',
+    '\u{e77c}',
+    '\u{e714}',
+    '\u{e72a}',
+    '\u{e713}',
+    '\u{e71a}',
+    '\u{e751}',
+    '\u{e6f7}',
+    '\u{e6dd}',
+    '\u{e6e1}',
+    '\u{e743}',
+    '\u{e761}',
+    '\u{e755}',
+    '\u{e6f8}',
+    '\u{e6f9}',
+    '\u{e6b0}',
+    '\u{e716}',
+    '\u{e7fe}',
+    '\u{e7ff}',
+    '\u{e800}',
+    '\u{e748}',
+    '\u{e6c2}',
+    '\u{e726}',
+    '\u{e805}',
+    '\u{e6f2}',
+    '\u{e7e7}',
+    '\u{e6de}',
+    '\u{e711}',
+    '\u{e720}',
+    '\u{e6ae}',
+    '\u{e80c}',
+    '\u{e6e4}',
+    '\u{e728}',
+    '\u{e7f9}',
+    '\u{e7fa}',
+    '\u{e7fb}',
+    '\u{e7fc}',
+    '\u{e80d}',
+    '\u{e7e8}',
+    '\u{e757}',
+    '\u{e729}',
+    '\u{e6a9}',
+    '\u{e727}',
+    '\u{e740}',
+    '\u{e6f5}',
+    '\u{e765}',
+    '\u{e764}',
+    '\u{e6c6}',
+    '\u{e6c5}',
+    '\u{e6a0}',
+    '\u{e6aa}',
+    '\u{e6e2}',
+    '\u{e732}',
+    '\u{e733}',
+    '\u{e6f3}',
+    '\u{e7c4}',
+    '\u{e7c6}',
+    '\u{e7c5}',
+    '\u{e7c3}',
+    '\u{e7c0}',
+    '\u{e7c2}',
+    '\u{e7c1}',
+    '\u{e7bf}',
+    '\u{e7bc}',
+    '\u{e7be}',
+    '\u{e7bd}',
+    '\u{e7bb}',
+    '\u{e7f6}',
+    '\u{e7f7}',
+    '\u{e715}',
+    '\u{e722}',
+    '\u{e723}',
+    '\u{e767}',
+    '\u{e719}',
+    '\u{e756}',
+    '\u{e807}',
+    '\u{e763}',
+    '\u{e6b1}',
+    '\u{e6df}',
+    '\u{e75a}',
+    '\u{e6a6}',
+    '\u{e6ac}',
+    '\u{e741}',
+    '\u{e742}',
+    '\u{e759}',
+    '\u{e6c8}',
+    '\u{e6c7}',
+    '\u{e766}',
+    '\u{e804}',
+    '\u{e7f4}',
+    '\u{e7f8}',
+    '\u{e7f5}',
+    '\u{e760}',
+    '\u{e752}',
+    '\u{e6fa}',
+    '\u{e747}',
+    '\u{e6f4}',
+    '\u{e6fc}',
+    '\u{e806}',
+    '\u{e801}',
+    '\u{e802}',
+    '\u{e803}',
+    '\u{e7fd}',
+    '\u{e762}',
+    '\u{e7f3}',
+    '\u{e80e}',
+    '\u{e7f1}',
+    '\u{e7f2}',
+    '\u{e718}',
+    '\u{e6c4}',
+    '\u{e753}',
+    '\u{e746}',
+    '\u{e724}',
+    '\u{e710}',
+    '\u{e734}',
+    '\u{e750}',
+    '\u{e6e0}',
+    '\u{e6d1}',
+    '\u{e6d3}',
+    '\u{e6d2}',
+    '\u{e6af}',
+    '\u{e7f0}',
+    '\u{e7b2}',
+    '\u{e721}',
+    '\u{e808}',
+    '\u{e6e3}',
+    '\u{e730}',
+    '\u{e731}',
+    '\u{e6db}',
+    '\u{e6f1}',
+    '\u{e6d6}',
+    '\u{e744}',
+    '\u{e6dc}',
+    '\u{e6d0}',
+    '\u{e6d7}',
+    '\u{e6d8}',
+    '\u{e6da}',
+    '\u{e6d9}',
+    '\u{e700}',
+    '\u{e6b2}',
+    '\u{e6c0}',
+    '\u{e80a}',
+    '\u{e80b}',
+    '\u{e809}',
+    '\u{e6a7}',
+    '\u{e6a8}',
+    '\u{e6ad}',
+    '\u{e745}',
+    '\u{e725}',
+    '\u{e6f6}',
+    '\u{e6c1}',
+    '\u{e754}',
+    '\u{e758}',
+    '\u{e6f0}',
+    '\u{e7b4}',
+    '\u{e7b6}',
+    '\u{e7b5}',
+    '\u{e7b3}',
+    '\u{e7b8}',
+    '\u{e7ba}',
+    '\u{e7b9}',
+    '\u{e7b7}',
+    '\u{e6a1}',
+    '\u{e6a3}',
+    '\u{e6ab}',
+    '\u{e6a2}',
+    '\u{e6a5}',
+    '\u{e6a4}',
+    '\u{e632}',
+    '\u{e638}',
+    '\u{e639}',
+    '\u{e63b}',
+    '\u{e637}',
+    '\u{e633}',
+    '\u{e63a}',
+    '\u{e631}',
+    '\u{e630}',
+    '\u{e636}',
+    '\u{e514}',
+    '\u{e515}',
+    '\u{e516}',
+    '\u{e51d}',
+    '\u{e500}',
+    '\u{e501}',
+    '\u{e502}',
+    '\u{e505}',
+    '\u{e504}',
+    '\u{e503}',
+    '\u{e044}',
+    '\u{e043}',
+    '\u{e040}',
+    '\u{e041}',
+    '\u{e042}',
+    '\u{e4ed}',
+    '\u{e4ea}',
+    '\u{e4e7}',
+    '\u{e4eb}',
+    '\u{e4e8}',
+    '\u{e4ec}',
+    '\u{e4e9}',
+    '\u{e4e6}',
+    '\u{e4e2}',
+    '\u{e4f3}',
+    '\u{e4ee}',
+    '\u{e4ef}',
+    '\u{e4f0}',
+    '\u{e4f1}',
+    '\u{e4e4}',
+    '\u{e4f5}',
+    '\u{e4e1}',
+    '\u{e4e0}',
+    '\u{e4e5}',
+    '\u{e4f2}',
+    '\u{e4f6}',
+    '\u{e4e3}',
+    '\u{e4f4}',
+    '\u{e001}',
+    '\u{e006}',
+    '\u{e005}',
+    '\u{e04d}',
+    '\u{ef00}',
+    '\u{ef01}',
+    '\u{ef02}',
+    '\u{ef03}',
+    '\u{ef04}',
+    '\u{ef05}',
+    '\u{ef06}',
+    '\u{ef07}',
+    '\u{ef08}',
+    '\u{e06f}',
+    '\u{e072}',
+    '\u{e071}',
+    '\u{e070}',
+    '\u{e047}',
+    '\u{e04a}',
+    '\u{e04b}',
+    '\u{e06b}',
+    '\u{e06c}',
+    '\u{ec52}',
+    '\u{ec53}',
+    '\u{ec57}',
+    '\u{ec55}',
+    '\u{ec56}',
+    '\u{ec54}',
+    '\u{ec58}',
+    '\u{ec50}',
+    '\u{ec59}',
+    '\u{ec51}',
+    '\u{ec5a}',
+    '\u{e00a}',
+    '\u{e010}',
+    '\u{e01c}',
+    '\u{e016}',
+    '\u{e011}',
+    '\u{e01d}',
+    '\u{e017}',
+    '\u{e012}',
+    '\u{e01e}',
+    '\u{e018}',
+    '\u{e013}',
+    '\u{e01f}',
+    '\u{e019}',
+    '\u{e014}',
+    '\u{e020}',
+    '\u{e01a}',
+    '\u{e015}',
+    '\u{e021}',
+    '\u{e01b}',
+    '\u{e00b}',
+    '\u{e00c}',
+    '\u{e00d}',
+    '\u{eb98}',
+    '\u{eb99}',
+    '\u{eb9a}',
+    '\u{eb9b}',
+    '\u{eb9c}',
+    '\u{eb9d}',
+    '\u{eb9e}',
+    '\u{eb9f}',
+    '\u{eb90}',
+    '\u{eb91}',
+    '\u{eb92}',
+    '\u{eb93}',
+    '\u{eb94}',
+    '\u{eb95}',
+    '\u{eb96}',
+    '\u{eb97}',
+    '\u{e210}',
+    '\u{e215}',
+    '\u{e216}',
+    '\u{e217}',
+    '\u{e218}',
+    '\u{e21f}',
+    '\u{e21a}',
+    '\u{e21c}',
+    '\u{e21b}',
+    '\u{e213}',
+    '\u{e21e}',
+    '\u{e211}',
+    '\u{e214}',
+    '\u{e21d}',
+    '\u{e212}',
+    '\u{e219}',
+    '\u{e232}',
+    '\u{e618}',
+    '\u{e62a}',
+    '\u{e627}',
+    '\u{e629}',
+    '\u{e628}',
+    '\u{e619}',
+    '\u{e61a}',
+    '\u{e626}',
+    '\u{e610}',
+    '\u{ee82}',
+    '\u{ee84}',
+    '\u{ee80}',
+    '\u{e611}',
+    '\u{e622}',
+    '\u{e615}',
+    '\u{e614}',
+    '\u{e620}',
+    '\u{e621}',
+    '\u{e617}',
+    '\u{e616}',
+    '\u{e61b}',
+    '\u{e61f}',
+    '\u{e61d}',
+    '\u{e61e}',
+    '\u{e61c}',
+    '\u{ee88}',
+    '\u{ee89}',
+    '\u{ee86}',
+    '\u{ee87}',
+    '\u{e624}',
+    '\u{e625}',
+    '\u{ee8a}',
+    '\u{ee8b}',
+    '\u{e612}',
+    '\u{ee83}',
+    '\u{ee85}',
+    '\u{ee81}',
+    '\u{e613}',
+    '\u{e623}',
+    '\u{ee72}',
+    '\u{ee70}',
+    '\u{ee73}',
+    '\u{ee71}',
+    '\u{e007}',
+    '\u{e009}',
+    '\u{e008}',
+    '\u{e1fc}',
+    '\u{e1f5}',
+    '\u{e1f4}',
+    '\u{e1f6}',
+    '\u{e1f3}',
+    '\u{e1f2}',
+    '\u{e1f1}',
+    '\u{e1f0}',
+    '\u{e1fa}',
+    '\u{e1f9}',
+    '\u{e1fb}',
+    '\u{e1f8}',
+    '\u{e1f7}',
+    '\u{e209}',
+    '\u{e208}',
+    '\u{e20a}',
+    '\u{e207}',
+    '\u{e206}',
+    '\u{e205}',
+    '\u{e204}',
+    '\u{e1fd}',
+    '\u{e202}',
+    '\u{e1ff}',
+    '\u{e203}',
+    '\u{e200}',
+    '\u{e201}',
+    '\u{e1fe}',
+    '\u{e080}',
+    '\u{ecf0}',
+    '\u{ece0}',
+    '\u{e081}',
+    '\u{ecf1}',
+    '\u{ece1}',
+    '\u{e082}',
+    '\u{ecf2}',
+    '\u{ece2}',
+    '\u{e083}',
+    '\u{ecf3}',
+    '\u{ece3}',
+    '\u{e084}',
+    '\u{ecf4}',
+    '\u{ece4}',
+    '\u{e085}',
+    '\u{ecf5}',
+    '\u{ece5}',
+    '\u{e086}',
+    '\u{ecf6}',
+    '\u{ece6}',
+    '\u{e087}',
+    '\u{ecf7}',
+    '\u{ece7}',
+    '\u{e088}',
+    '\u{ecf8}',
+    '\u{ece8}',
+    '\u{e089}',
+    '\u{ecf9}',
+    '\u{ece9}',
+    '\u{ec80}',
+    '\u{ec82}',
+    '\u{ec81}',
+    '\u{ec83}',
+    '\u{e09f}',
+    '\u{e09e}',
+    '\u{e096}',
+    '\u{e08a}',
+    '\u{ecfa}',
+    '\u{ecea}',
+    '\u{ec85}',
+    '\u{ec86}',
+    '\u{e08b}',
+    '\u{ecfb}',
+    '\u{eceb}',
+    '\u{e08f}',
+    '\u{e098}',
+    '\u{e09a}',
+    '\u{e097}',
+    '\u{e099}',
+    '\u{e09b}',
+    '\u{e08e}',
+    '\u{e090}',
+    '\u{e091}',
+    '\u{e09d}',
+    '\u{e094}',
+    '\u{e092}',
+    '\u{e095}',
+    '\u{e093}',
+    '\u{e08c}',
+    '\u{e08d}',
+    '\u{ec84}',
+    '\u{e09c}',
+    '\u{e220}',
+    '\u{e221}',
+    '\u{e222}',
+    '\u{e223}',
+    '\u{e224}',
+    '\u{e22e}',
+    '\u{e22f}',
+    '\u{e230}',
+    '\u{e231}',
+    '\u{e225}',
+    '\u{e226}',
+    '\u{e227}',
+    '\u{e228}',
+    '\u{e229}',
+    '\u{e5f2}',
+    '\u{e5f3}',
+    '\u{e880}',
+    '\u{e881}',
+    '\u{e882}',
+    '\u{e883}',
+    '\u{e884}',
+    '\u{e885}',
+    '\u{e886}',
+    '\u{e887}',
+    '\u{e888}',
+    '\u{e889}',
+    '\u{e88a}',
+    '\u{e22c}',
+    '\u{e22d}',
+    '\u{e069}',
+    '\u{e06a}',
+    '\u{e517}',
+    '\u{e518}',
+    '\u{e519}',
+    '\u{e51e}',
+    '\u{e649}',
+    '\u{e64b}',
+    '\u{e640}',
+    '\u{e642}',
+    '\u{e644}',
+    '\u{e641}',
+    '\u{e643}',
+    '\u{e647}',
+    '\u{e645}',
+    '\u{e648}',
+    '\u{e64a}',
+    '\u{e646}',
+    '\u{eaaa}',
+    '\u{eaae}',
+    '\u{eaac}',
+    '\u{eaa9}',
+    '\u{eaad}',
+    '\u{eaab}',
+    '\u{eac9}',
+    '\u{eac0}',
+    '\u{eac1}',
+    '\u{eac3}',
+    '\u{eac2}',
+    '\u{eacb}',
+    '\u{eac8}',
+    '\u{eac7}',
+    '\u{eac6}',
+    '\u{eac5}',
+    '\u{eaca}',
+    '\u{eac4}',
+    '\u{eaaf}',
+    '\u{eabd}',
+    '\u{eabe}',
+    '\u{eabf}',
+    '\u{eaf0}',
+    '\u{eaf1}',
+    '\u{eaf2}',
+    '\u{eaf3}',
+    '\u{eabb}',
+    '\u{eaba}',
+    '\u{eabc}',
+    '\u{eab8}',
+    '\u{eab7}',
+    '\u{eab9}',
+    '\u{eaa4}',
+    '\u{eaa3}',
+    '\u{eaa2}',
+    '\u{eaa1}',
+    '\u{eaa0}',
+    '\u{eaa5}',
+    '\u{eaa6}',
+    '\u{eaa7}',
+    '\u{eaa8}',
+    '\u{eaee}',
+    '\u{eae0}',
+    '\u{eab0}',
+    '\u{eae5}',
+    '\u{eae4}',
+    '\u{eae3}',
+    '\u{eae2}',
+    '\u{eae6}',
+    '\u{eae7}',
+    '\u{eae8}',
+    '\u{eaec}',
+    '\u{eaeb}',
+    '\u{eaea}',
+    '\u{eae9}',
+    '\u{eaed}',
+    '\u{eaef}',
+    '\u{eade}',
+    '\u{eadd}',
+    '\u{eadc}',
+    '\u{eadb}',
+    '\u{eadf}',
+    '\u{eae1}',
+    '\u{ead7}',
+    '\u{ead6}',
+    '\u{ead5}',
+    '\u{ead4}',
+    '\u{ead8}',
+    '\u{ead9}',
+    '\u{eada}',
+    '\u{ead0}',
+    '\u{eacf}',
+    '\u{eace}',
+    '\u{eacd}',
+    '\u{ead1}',
+    '\u{ead2}',
+    '\u{ead3}',
+    '\u{eacc}',
+    '\u{eab1}',
+    '\u{eab5}',
+    '\u{eab4}',
+    '\u{eab6}',
+    '\u{e5f4}',
+    '\u{e5fb}',
+    '\u{e5f6}',
+    '\u{e5f7}',
+    '\u{e5f8}',
+    '\u{e5fe}',
+    '\u{e600}',
+    '\u{e60a}',
+    '\u{e607}',
+    '\u{e609}',
+    '\u{e608}',
+    '\u{e5f9}',
+    '\u{e606}',
+    '\u{e604}',
+    '\u{e605}',
+    '\u{e5fd}',
+    '\u{e60b}',
+    '\u{e5fc}',
+    '\u{e603}',
+    '\u{e5f5}',
+    '\u{e5ff}',
+    '\u{e5fa}',
+    '\u{e601}',
+    '\u{e602}',
+    '\u{e06e}',
+    '\u{e06d}',
+];
+
+/// Each [`Glyph`]'s Unicode Musical Symbols range code point, indexed by
+/// discriminant.
+#[rustfmt::skip]
+pub static GLYPH_ALTERNATE_CODEPOINTS: &[Option<char>] = &[
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄫'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄪'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('♭'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('♮'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄳'),
+    None,
+    Some('𝄬'),
+    None,
+    Some('𝄯'),
+    None,
+    None,
+    None,
+    Some('𝄲'),
+    None,
+    Some('𝄱'),
+    None,
+    Some('𝄮'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('♯'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄭'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄰'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆨'),
+    None,
+    Some('𝆦'),
+    None,
+    Some('𝆧'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆄'),
+    Some('𝆃'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d17b}'),
+    None,
+    Some('\u{1d181}'),
+    None,
+    None,
+    None,
+    Some('\u{1d17f}'),
+    None,
+    Some('\u{1d180}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d17e}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d17c}'),
+    None,
+    None,
+    None,
+    Some('\u{1d17d}'),
+    None,
+    None,
+    None,
+    Some('\u{1d182}'),
+    None,
+    None,
+    None,
+    Some('\u{1d16d}'),
+    Some('𝄄'),
+    None,
+    Some('𝄁'),
+    Some('𝄂'),
+    None,
+    None,
+    Some('𝄃'),
+    Some('𝄅'),
+    Some('𝄀'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄔'),
+    Some('𝄕'),
+    None,
+    None,
+    Some('\u{1d189}'),
+    None,
+    None,
+    Some('\u{1d185}'),
+    None,
+    None,
+    Some('\u{1d186}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d187}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d188}'),
+    None,
+    Some('𝄒'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄡'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄓'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇐'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇑'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇔'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇓'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄌'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d173}'),
+    Some('\u{1d179}'),
+    Some('\u{1d177}'),
+    Some('\u{1d175}'),
+    Some('\u{1d174}'),
+    Some('\u{1d17a}'),
+    Some('\u{1d178}'),
+    Some('\u{1d176}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆩'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄊'),
+    Some('𝄉'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d18a}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆒'),
+    Some('𝆓'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆑'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆐'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆏'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆌'),
+    None,
+    None,
+    Some('𝆍'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆎'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄢'),
+    None,
+    None,
+    Some('𝄣'),
+    Some('𝄤'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄐'),
+    Some('𝄑'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d172}'),
+    None,
+    Some('\u{1d16f}'),
+    None,
+    None,
+    None,
+    Some('\u{1d170}'),
+    None,
+    None,
+    None,
+    Some('\u{1d171}'),
+    None,
+    Some('\u{1d16e}'),
+    None,
+    None,
+    None,
+    None,
+    Some('𝄝'),
+    None,
+    None,
+    None,
+    Some('𝄜'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄞'),
+    None,
+    None,
+    Some('𝄟'),
+    Some('𝄠'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆲'),
+    Some('𝆱'),
+    None,
+    Some('𝆔'),
+    None,
+    Some('𝆕'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆰'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆮'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆯'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇨'),
+    None,
+    None,
+    Some('𝇞'),
+    Some('𝇟'),
+    Some('𝇦'),
+    Some('𝇧'),
+    None,
+    Some('𝇣'),
+    None,
+    Some('𝇤'),
+    Some('𝇥'),
+    Some('𝇡'),
+    Some('𝇢'),
+    Some('𝇠'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇒'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇏'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆺𝅥'),
+    Some('𝆹𝅥'),
+    Some('𝆺'),
+    None,
+    None,
+    Some('𝆹'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇇'),
+    Some('𝇎'),
+    None,
+    Some('𝇈'),
+    Some('𝇉'),
+    None,
+    Some('𝇊'),
+    Some('𝇋'),
+    Some('𝇌'),
+    None,
+    Some('𝇍'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝇃'),
+    None,
+    Some('𝇂'),
+    Some('𝇁'),
+    None,
+    Some('𝇅'),
+    Some('𝇄'),
+    None,
+    Some('𝇆'),
+    None,
+    None,
+    None,
+    None,
+    Some('𝆸'),
+    Some('𝆺𝅥𝅮'),
+    Some('𝆷'),
+    Some('𝆶'),
+    None,
+    Some('𝆹'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅘𝅥𝅲'),
+    None,
+    Some('𝅘𝅥𝅯'),
+    None,
+    None,
+    None,
+    Some('𝅘𝅥𝅰'),
+    None,
+    None,
+    None,
+    Some('𝅘𝅥𝅱'),
+    None,
+    Some('𝅘𝅥𝅮'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅜'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅗𝅥'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅘𝅥'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅝'),
+    Some('𝅘'),
+    None,
+    Some('𝅅'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅛'),
+    Some('𝅚'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅗'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅓'),
+    Some('𝅒'),
+    None,
+    Some('𝅙'),
+    Some('𝅖'),
+    None,
+    None,
+    Some('𝅄'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄍'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅇'),
+    None,
+    None,
+    Some('𝅆'),
+    Some('𝅏'),
+    None,
+    None,
+    Some('𝅎'),
+    None,
+    Some('𝅋'),
+    Some('𝅊'),
+    Some('𝅍'),
+    Some('𝅌'),
+    Some('𝅕'),
+    Some('𝅔'),
+    Some('𝅉'),
+    None,
+    None,
+    Some('𝅑'),
+    Some('𝅐'),
+    Some('𝅈'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅃'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆡'),
+    None,
+    Some('𝆟'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆢'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆛'),
+    None,
+    None,
+    Some('𝆤'),
+    Some('𝆣'),
+    None,
+    Some('𝆠'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆥'),
+    None,
+    Some('𝆞'),
+    None,
+    None,
+    Some('𝆖'),
+    Some('𝆗'),
+    Some('𝆘'),
+    Some('𝆙'),
+    Some('𝆚'),
+    None,
+    None,
+    None,
+    Some('𝆜'),
+    Some('𝆝'),
+    None,
+    Some('𝄶'),
+    Some('𝄷'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝆴'),
+    Some('𝆵'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d1ad}'),
+    Some('𝆳'),
+    None,
+    None,
+    Some('𝄹'),
+    None,
+    Some('𝄎'),
+    Some('𝄏'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄈'),
+    Some('𝄆'),
+    Some('𝄇'),
+    None,
+    None,
+    Some('𝅂'),
+    Some('𝄿'),
+    None,
+    Some('𝅀'),
+    None,
+    Some('𝅁'),
+    Some('𝄾'),
+    Some('𝄺'),
+    None,
+    Some('𝄩'),
+    None,
+    None,
+    None,
+    Some('𝄼'),
+    None,
+    None,
+    None,
+    Some('𝄽'),
+    None,
+    None,
+    Some('𝄻'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄋'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄖'),
+    None,
+    None,
+    Some('𝄗'),
+    None,
+    None,
+    Some('𝄘'),
+    None,
+    None,
+    Some('𝄙'),
+    None,
+    None,
+    Some('𝄚'),
+    None,
+    None,
+    Some('𝄛'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d165}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d166}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d1aa}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d1ac}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d1ab}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄴'),
+    None,
+    None,
+    None,
+    None,
+    Some('𝄵'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('\u{1d167}'),
+    Some('\u{1d168}'),
+    Some('\u{1d169}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝅪'),
+    Some('𝅫'),
+    Some('𝅬'),
+    None,
+    None,
+    Some('\u{1d18b}'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('𝄥'),
+    Some('𝄦'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+];
+
+/// Each [`Glyph`]'s name in `glyphnames.json`, indexed by discriminant.
+#[rustfmt::skip]
+pub static GLYPH_NAMES: &[&str] = &[
+    "accSagittal11LargeDiesisDown",
+    "accSagittal11LargeDiesisUp",
+    "accSagittal11MediumDiesisDown",
+    "accSagittal11MediumDiesisUp",
+    "accSagittal11v19LargeDiesisDown",
+    "accSagittal11v19LargeDiesisUp",
+    "accSagittal11v19MediumDiesisDown",
+    "accSagittal11v19MediumDiesisUp",
+    "accSagittal11v49CommaDown",
+    "accSagittal11v49CommaUp",
+    "accSagittal143CommaDown",
+    "accSagittal143CommaUp",
+    "accSagittal17CommaDown",
+    "accSagittal17CommaUp",
+    "accSagittal17KleismaDown",
+    "accSagittal17KleismaUp",
+    "accSagittal19CommaDown",
+    "accSagittal19CommaUp",
+    "accSagittal19SchismaDown",
+    "accSagittal19SchismaUp",
+    "accSagittal1MinaDown",
+    "accSagittal1MinaUp",
+    "accSagittal1TinaDown",
+    "accSagittal1TinaUp",
+    "accSagittal23CommaDown",
+    "accSagittal23CommaUp",
+    "accSagittal23SmallDiesisDown",
+    "accSagittal23SmallDiesisUp",
+    "accSagittal25SmallDiesisDown",
+    "accSagittal25SmallDiesisUp",
+    "accSagittal2MinasDown",
+    "accSagittal2MinasUp",
+    "accSagittal2TinasDown",
+    "accSagittal2TinasUp",
+    "accSagittal35LargeDiesisDown",
+    "accSagittal35LargeDiesisUp",
+    "accSagittal35MediumDiesisDown",
+    "accSagittal35MediumDiesisUp",
+    "accSagittal3TinasDown",
+    "accSagittal3TinasUp",
+    "accSagittal49LargeDiesisDown",
+    "accSagittal49LargeDiesisUp",
+    "accSagittal49MediumDiesisDown",
+    "accSagittal49MediumDiesisUp",
+    "accSagittal49SmallDiesisDown",
+    "accSagittal49SmallDiesisUp",
+    "accSagittal4TinasDown",
+    "accSagittal4TinasUp",
+    "accSagittal55CommaDown",
+    "accSagittal55CommaUp",
+    "accSagittal5CommaDown",
+    "accSagittal5CommaUp",
+    "accSagittal5v11SmallDiesisDown",
+    "accSagittal5v11SmallDiesisUp",
+    "accSagittal5v13LargeDiesisDown",
+    "accSagittal5v13LargeDiesisUp",
+    "accSagittal5v13MediumDiesisDown",
+    "accSagittal5v13MediumDiesisUp",
+    "accSagittal5v19CommaDown",
+    "accSagittal5v19CommaUp",
+    "accSagittal5v23SmallDiesisDown",
+    "accSagittal5v23SmallDiesisUp",
+    "accSagittal5v49MediumDiesisDown",
+    "accSagittal5v49MediumDiesisUp",
+    "accSagittal5v7KleismaDown",
+    "accSagittal5v7KleismaUp",
+    "accSagittal5TinasDown",
+    "accSagittal5TinasUp",
+    "accSagittal6TinasDown",
+    "accSagittal6TinasUp",
+    "accSagittal7CommaDown",
+    "accSagittal7CommaUp",
+    "accSagittal7v11CommaDown",
+    "accSagittal7v11CommaUp",
+    "accSagittal7v11KleismaDown",
+    "accSagittal7v11KleismaUp",
+    "accSagittal7v19CommaDown",
+    "accSagittal7v19CommaUp",
+    "accSagittal7TinasDown",
+    "accSagittal7TinasUp",
+    "accSagittal8TinasDown",
+    "accSagittal8TinasUp",
+    "accSagittal9TinasDown",
+    "accSagittal9TinasUp",
+    "accSagittalAcute",
+    "accSagittalDoubleFlat",
+    "accSagittalDoubleFlat11v49CUp",
+    "accSagittalDoubleFlat143CUp",
+    "accSagittalDoubleFlat17CUp",
+    "accSagittalDoubleFlat17kUp",
+    "accSagittalDoubleFlat19CUp",
+    "accSagittalDoubleFlat19sUp",
+    "accSagittalDoubleFlat23CUp",
+    "accSagittalDoubleFlat23SUp",
+    "accSagittalDoubleFlat25SUp",
+    "accSagittalDoubleFlat49SUp",
+    "accSagittalDoubleFlat55CUp",
+    "accSagittalDoubleFlat5CUp",
+    "accSagittalDoubleFlat5v11SUp",
+    "accSagittalDoubleFlat5v19CUp",
+    "accSagittalDoubleFlat5v23SUp",
+    "accSagittalDoubleFlat5v7kUp",
+    "accSagittalDoubleFlat7CUp",
+    "accSagittalDoubleFlat7v11CUp",
+    "accSagittalDoubleFlat7v11kUp",
+    "accSagittalDoubleFlat7v19CUp",
+    "accSagittalDoubleSharp",
+    "accSagittalDoubleSharp11v49CDown",
+    "accSagittalDoubleSharp143CDown",
+    "accSagittalDoubleSharp17CDown",
+    "accSagittalDoubleSharp17kDown",
+    "accSagittalDoubleSharp19CDown",
+    "accSagittalDoubleSharp19sDown",
+    "accSagittalDoubleSharp23CDown",
+    "accSagittalDoubleSharp23SDown",
+    "accSagittalDoubleSharp25SDown",
+    "accSagittalDoubleSharp49SDown",
+    "accSagittalDoubleSharp55CDown",
+    "accSagittalDoubleSharp5CDown",
+    "accSagittalDoubleSharp5v11SDown",
+    "accSagittalDoubleSharp5v19CDown",
+    "accSagittalDoubleSharp5v23SDown",
+    "accSagittalDoubleSharp5v7kDown",
+    "accSagittalDoubleSharp7CDown",
+    "accSagittalDoubleSharp7v11CDown",
+    "accSagittalDoubleSharp7v11kDown",
+    "accSagittalDoubleSharp7v19CDown",
+    "accSagittalFlat",
+    "accSagittalFlat11LDown",
+    "accSagittalFlat11MDown",
+    "accSagittalFlat11v19LDown",
+    "accSagittalFlat11v19MDown",
+    "accSagittalFlat11v49CDown",
+    "accSagittalFlat11v49CUp",
+    "accSagittalFlat143CDown",
+    "accSagittalFlat143CUp",
+    "accSagittalFlat17CDown",
+    "accSagittalFlat17CUp",
+    "accSagittalFlat17kDown",
+    "accSagittalFlat17kUp",
+    "accSagittalFlat19CDown",
+    "accSagittalFlat19CUp",
+    "accSagittalFlat19sDown",
+    "accSagittalFlat19sUp",
+    "accSagittalFlat23CDown",
+    "accSagittalFlat23CUp",
+    "accSagittalFlat23SDown",
+    "accSagittalFlat23SUp",
+    "accSagittalFlat25SDown",
+    "accSagittalFlat25SUp",
+    "accSagittalFlat35LDown",
+    "accSagittalFlat35MDown",
+    "accSagittalFlat49LDown",
+    "accSagittalFlat49MDown",
+    "accSagittalFlat49SDown",
+    "accSagittalFlat49SUp",
+    "accSagittalFlat55CDown",
+    "accSagittalFlat55CUp",
+    "accSagittalFlat5CDown",
+    "accSagittalFlat5CUp",
+    "accSagittalFlat5v11SDown",
+    "accSagittalFlat5v11SUp",
+    "accSagittalFlat5v13LDown",
+    "accSagittalFlat5v13MDown",
+    "accSagittalFlat5v19CDown",
+    "accSagittalFlat5v19CUp",
+    "accSagittalFlat5v23SDown",
+    "accSagittalFlat5v23SUp",
+    "accSagittalFlat5v49MDown",
+    "accSagittalFlat5v7kDown",
+    "accSagittalFlat5v7kUp",
+    "accSagittalFlat7CDown",
+    "accSagittalFlat7CUp",
+    "accSagittalFlat7v11CDown",
+    "accSagittalFlat7v11CUp",
+    "accSagittalFlat7v11kDown",
+    "accSagittalFlat7v11kUp",
+    "accSagittalFlat7v19CDown",
+    "accSagittalFlat7v19CUp",
+    "accSagittalFractionalTinaDown",
+    "accSagittalFractionalTinaUp",
+    "accSagittalGrave",
+    "accSagittalShaftDown",
+    "accSagittalShaftUp",
+    "accSagittalSharp",
+    "accSagittalSharp11LUp",
+    "accSagittalSharp11MUp",
+    "accSagittalSharp11v19LUp",
+    "accSagittalSharp11v19MUp",
+    "accSagittalSharp11v49CDown",
+    "accSagittalSharp11v49CUp",
+    "accSagittalSharp143CDown",
+    "accSagittalSharp143CUp",
+    "accSagittalSharp17CDown",
+    "accSagittalSharp17CUp",
+    "accSagittalSharp17kDown",
+    "accSagittalSharp17kUp",
+    "accSagittalSharp19CDown",
+    "accSagittalSharp19CUp",
+    "accSagittalSharp19sDown",
+    "accSagittalSharp19sUp",
+    "accSagittalSharp23CDown",
+    "accSagittalSharp23CUp",
+    "accSagittalSharp23SDown",
+    "accSagittalSharp23SUp",
+    "accSagittalSharp25SDown",
+    "accSagittalSharp25SUp",
+    "accSagittalSharp35LUp",
+    "accSagittalSharp35MUp",
+    "accSagittalSharp49LUp",
+    "accSagittalSharp49MUp",
+    "accSagittalSharp49SDown",
+    "accSagittalSharp49SUp",
+    "accSagittalSharp55CDown",
+    "accSagittalSharp55CUp",
+    "accSagittalSharp5CDown",
+    "accSagittalSharp5CUp",
+    "accSagittalSharp5v11SDown",
+    "accSagittalSharp5v11SUp",
+    "accSagittalSharp5v13LUp",
+    "accSagittalSharp5v13MUp",
+    "accSagittalSharp5v19CDown",
+    "accSagittalSharp5v19CUp",
+    "accSagittalSharp5v23SDown",
+    "accSagittalSharp5v23SUp",
+    "accSagittalSharp5v49MUp",
+    "accSagittalSharp5v7kDown",
+    "accSagittalSharp5v7kUp",
+    "accSagittalSharp7CDown",
+    "accSagittalSharp7CUp",
+    "accSagittalSharp7v11CDown",
+    "accSagittalSharp7v11CUp",
+    "accSagittalSharp7v11kDown",
+    "accSagittalSharp7v11kUp",
+    "accSagittalSharp7v19CDown",
+    "accSagittalSharp7v19CUp",
+    "accSagittalUnused1",
+    "accSagittalUnused2",
+    "accSagittalUnused3",
+    "accSagittalUnused4",
+    "accdnCombDot",
+    "accdnCombLH2RanksEmpty",
+    "accdnCombLH3RanksEmptySquare",
+    "accdnCombRH3RanksEmpty",
+    "accdnCombRH4RanksEmpty",
+    "accdnDiatonicClef",
+    "accdnLH2Ranks16Round",
+    "accdnLH2Ranks8Plus16Round",
+    "accdnLH2Ranks8Round",
+    "accdnLH2RanksFullMasterRound",
+    "accdnLH2RanksMasterPlus16Round",
+    "accdnLH2RanksMasterRound",
+    "accdnLH3Ranks2Plus8Square",
+    "accdnLH3Ranks2Square",
+    "accdnLH3Ranks8Square",
+    "accdnLH3RanksDouble8Square",
+    "accdnLH3RanksTuttiSquare",
+    "accdnPull",
+    "accdnPush",
+    "accdnRH3RanksAccordion",
+    "accdnRH3RanksAuthenticMusette",
+    "accdnRH3RanksBandoneon",
+    "accdnRH3RanksBassoon",
+    "accdnRH3RanksClarinet",
+    "accdnRH3RanksDoubleTremoloLower8ve",
+    "accdnRH3RanksDoubleTremoloUpper8ve",
+    "accdnRH3RanksFullFactory",
+    "accdnRH3RanksHarmonium",
+    "accdnRH3RanksImitationMusette",
+    "accdnRH3RanksLowerTremolo8",
+    "accdnRH3RanksMaster",
+    "accdnRH3RanksOboe",
+    "accdnRH3RanksOrgan",
+    "accdnRH3RanksPiccolo",
+    "accdnRH3RanksTremoloLower8ve",
+    "accdnRH3RanksTremoloUpper8ve",
+    "accdnRH3RanksTwoChoirs",
+    "accdnRH3RanksUpperTremolo8",
+    "accdnRH3RanksViolin",
+    "accdnRH4RanksAlto",
+    "accdnRH4RanksBassAlto",
+    "accdnRH4RanksMaster",
+    "accdnRH4RanksSoftBass",
+    "accdnRH4RanksSoftTenor",
+    "accdnRH4RanksSoprano",
+    "accdnRH4RanksTenor",
+    "accdnRicochet2",
+    "accdnRicochet3",
+    "accdnRicochet4",
+    "accdnRicochet5",
+    "accdnRicochet6",
+    "accdnRicochetStem2",
+    "accdnRicochetStem3",
+    "accdnRicochetStem4",
+    "accdnRicochetStem5",
+    "accdnRicochetStem6",
+    "accidental1CommaFlat",
+    "accidental1CommaSharp",
+    "accidental2CommaFlat",
+    "accidental2CommaSharp",
+    "accidental3CommaFlat",
+    "accidental3CommaSharp",
+    "accidental4CommaFlat",
+    "accidental5CommaSharp",
+    "accidentalArrowDown",
+    "accidentalArrowUp",
+    "accidentalBakiyeFlat",
+    "accidentalBakiyeSharp",
+    "accidentalBracketLeft",
+    "accidentalBracketRight",
+    "accidentalBuyukMucennebFlat",
+    "accidentalBuyukMucennebSharp",
+    "accidentalCombiningCloseCurlyBrace",
+    "accidentalCombiningLower17Schisma",
+    "accidentalCombiningLower19Schisma",
+    "accidentalCombiningLower23Limit29LimitComma",
+    "accidentalCombiningLower29LimitComma",
+    "accidentalCombiningLower31Schisma",
+    "accidentalCombiningLower37Quartertone",
+    "accidentalCombiningLower41Comma",
+    "accidentalCombiningLower43Comma",
+    "accidentalCombiningLower47Quartertone",
+    "accidentalCombiningLower53LimitComma",
+    "accidentalCombiningOpenCurlyBrace",
+    "accidentalCombiningRaise17Schisma",
+    "accidentalCombiningRaise19Schisma",
+    "accidentalCombiningRaise23Limit29LimitComma",
+    "accidentalCombiningRaise29LimitComma",
+    "accidentalCombiningRaise31Schisma",
+    "accidentalCombiningRaise37Quartertone",
+    "accidentalCombiningRaise41Comma",
+    "accidentalCombiningRaise43Comma",
+    "accidentalCombiningRaise47Quartertone",
+    "accidentalCombiningRaise53LimitComma",
+    "accidentalCommaSlashDown",
+    "accidentalCommaSlashUp",
+    "accidentalDoubleFlat",
+    "accidentalDoubleFlatArabic",
+    "accidentalDoubleFlatEqualTempered",
+    "accidentalDoubleFlatOneArrowDown",
+    "accidentalDoubleFlatOneArrowUp",
+    "accidentalDoubleFlatReversed",
+    "accidentalDoubleFlatThreeArrowsDown",
+    "accidentalDoubleFlatThreeArrowsUp",
+    "accidentalDoubleFlatTurned",
+    "accidentalDoubleFlatTwoArrowsDown",
+    "accidentalDoubleFlatTwoArrowsUp",
+    "accidentalDoubleSharp",
+    "accidentalDoubleSharpArabic",
+    "accidentalDoubleSharpEqualTempered",
+    "accidentalDoubleSharpOneArrowDown",
+    "accidentalDoubleSharpOneArrowUp",
+    "accidentalDoubleSharpThreeArrowsDown",
+    "accidentalDoubleSharpThreeArrowsUp",
+    "accidentalDoubleSharpTwoArrowsDown",
+    "accidentalDoubleSharpTwoArrowsUp",
+    "accidentalEnharmonicAlmostEqualTo",
+    "accidentalEnharmonicEquals",
+    "accidentalEnharmonicTilde",
+    "accidentalFilledReversedFlatAndFlat",
+    "accidentalFilledReversedFlatAndFlatArrowDown",
+    "accidentalFilledReversedFlatAndFlatArrowUp",
+    "accidentalFilledReversedFlatArrowDown",
+    "accidentalFilledReversedFlatArrowUp",
+    "accidentalFiveQuarterTonesFlatArrowDown",
+    "accidentalFiveQuarterTonesSharpArrowUp",
+    "accidentalFlat",
+    "accidentalFlatArabic",
+    "accidentalFlatEqualTempered",
+    "accidentalFlatLoweredStockhausen",
+    "accidentalFlatOneArrowDown",
+    "accidentalFlatOneArrowUp",
+    "accidentalFlatRaisedStockhausen",
+    "accidentalFlatRepeatedLineStockhausen",
+    "accidentalFlatRepeatedSpaceStockhausen",
+    "accidentalFlatThreeArrowsDown",
+    "accidentalFlatThreeArrowsUp",
+    "accidentalFlatTurned",
+    "accidentalFlatTwoArrowsDown",
+    "accidentalFlatTwoArrowsUp",
+    "accidentalHabaFlatQuarterToneHigher",
+    "accidentalHabaFlatThreeQuarterTonesLower",
+    "accidentalHabaQuarterToneHigher",
+    "accidentalHabaQuarterToneLower",
+    "accidentalHabaSharpQuarterToneLower",
+    "accidentalHabaSharpThreeQuarterTonesHigher",
+    "accidentalHalfSharpArrowDown",
+    "accidentalHalfSharpArrowUp",
+    "accidentalJohnston13",
+    "accidentalJohnston31",
+    "accidentalJohnstonDown",
+    "accidentalJohnstonEl",
+    "accidentalJohnstonMinus",
+    "accidentalJohnstonPlus",
+    "accidentalJohnstonSeven",
+    "accidentalJohnstonUp",
+    "accidentalKomaFlat",
+    "accidentalKomaSharp",
+    "accidentalKoron",
+    "accidentalKucukMucennebFlat",
+    "accidentalKucukMucennebSharp",
+    "accidentalLargeDoubleSharp",
+    "accidentalLowerOneSeptimalComma",
+    "accidentalLowerOneTridecimalQuartertone",
+    "accidentalLowerOneUndecimalQuartertone",
+    "accidentalLowerTwoSeptimalCommas",
+    "accidentalLoweredStockhausen",
+    "accidentalNarrowReversedFlat",
+    "accidentalNarrowReversedFlatAndFlat",
+    "accidentalNatural",
+    "accidentalNaturalArabic",
+    "accidentalNaturalEqualTempered",
+    "accidentalNaturalFlat",
+    "accidentalNaturalLoweredStockhausen",
+    "accidentalNaturalOneArrowDown",
+    "accidentalNaturalOneArrowUp",
+    "accidentalNaturalRaisedStockhausen",
+    "accidentalNaturalReversed",
+    "accidentalNaturalSharp",
+    "accidentalNaturalThreeArrowsDown",
+    "accidentalNaturalThreeArrowsUp",
+    "accidentalNaturalTwoArrowsDown",
+    "accidentalNaturalTwoArrowsUp",
+    "accidentalOneAndAHalfSharpsArrowDown",
+    "accidentalOneAndAHalfSharpsArrowUp",
+    "accidentalOneQuarterToneFlatFerneyhough",
+    "accidentalOneQuarterToneFlatStockhausen",
+    "accidentalOneQuarterToneSharpFerneyhough",
+    "accidentalOneQuarterToneSharpStockhausen",
+    "accidentalOneThirdToneFlatFerneyhough",
+    "accidentalOneThirdToneSharpFerneyhough",
+    "accidentalParensLeft",
+    "accidentalParensRight",
+    "accidentalQuarterFlatEqualTempered",
+    "accidentalQuarterSharpEqualTempered",
+    "accidentalQuarterToneFlat4",
+    "accidentalQuarterToneFlatArabic",
+    "accidentalQuarterToneFlatArrowUp",
+    "accidentalQuarterToneFlatFilledReversed",
+    "accidentalQuarterToneFlatNaturalArrowDown",
+    "accidentalQuarterToneFlatPenderecki",
+    "accidentalQuarterToneFlatStein",
+    "accidentalQuarterToneFlatVanBlankenburg",
+    "accidentalQuarterToneSharp4",
+    "accidentalQuarterToneSharpArabic",
+    "accidentalQuarterToneSharpArrowDown",
+    "accidentalQuarterToneSharpBusotti",
+    "accidentalQuarterToneSharpNaturalArrowUp",
+    "accidentalQuarterToneSharpStein",
+    "accidentalQuarterToneSharpWiggle",
+    "accidentalRaiseOneSeptimalComma",
+    "accidentalRaiseOneTridecimalQuartertone",
+    "accidentalRaiseOneUndecimalQuartertone",
+    "accidentalRaiseTwoSeptimalCommas",
+    "accidentalRaisedStockhausen",
+    "accidentalReversedFlatAndFlatArrowDown",
+    "accidentalReversedFlatAndFlatArrowUp",
+    "accidentalReversedFlatArrowDown",
+    "accidentalReversedFlatArrowUp",
+    "accidentalSharp",
+    "accidentalSharpArabic",
+    "accidentalSharpEqualTempered",
+    "accidentalSharpLoweredStockhausen",
+    "accidentalSharpOneArrowDown",
+    "accidentalSharpOneArrowUp",
+    "accidentalSharpOneHorizontalStroke",
+    "accidentalSharpRaisedStockhausen",
+    "accidentalSharpRepeatedLineStockhausen",
+    "accidentalSharpRepeatedSpaceStockhausen",
+    "accidentalSharpReversed",
+    "accidentalSharpSharp",
+    "accidentalSharpThreeArrowsDown",
+    "accidentalSharpThreeArrowsUp",
+    "accidentalSharpTwoArrowsDown",
+    "accidentalSharpTwoArrowsUp",
+    "accidentalSims12Down",
+    "accidentalSims12Up",
+    "accidentalSims4Down",
+    "accidentalSims4Up",
+    "accidentalSims6Down",
+    "accidentalSims6Up",
+    "accidentalSori",
+    "accidentalTavenerFlat",
+    "accidentalTavenerSharp",
+    "accidentalThreeQuarterTonesFlatArabic",
+    "accidentalThreeQuarterTonesFlatArrowDown",
+    "accidentalThreeQuarterTonesFlatArrowUp",
+    "accidentalThreeQuarterTonesFlatCouper",
+    "accidentalThreeQuarterTonesFlatGrisey",
+    "accidentalThreeQuarterTonesFlatTartini",
+    "accidentalThreeQuarterTonesFlatZimmermann",
+    "accidentalThreeQuarterTonesSharpArabic",
+    "accidentalThreeQuarterTonesSharpArrowDown",
+    "accidentalThreeQuarterTonesSharpArrowUp",
+    "accidentalThreeQuarterTonesSharpBusotti",
+    "accidentalThreeQuarterTonesSharpStein",
+    "accidentalThreeQuarterTonesSharpStockhausen",
+    "accidentalTripleFlat",
+    "accidentalTripleSharp",
+    "accidentalTwoThirdTonesFlatFerneyhough",
+    "accidentalTwoThirdTonesSharpFerneyhough",
+    "accidentalUpsAndDownsDown",
+    "accidentalUpsAndDownsLess",
+    "accidentalUpsAndDownsMore",
+    "accidentalUpsAndDownsUp",
+    "accidentalWilsonMinus",
+    "accidentalWilsonPlus",
+    "accidentalWyschnegradsky10TwelfthsFlat",
+    "accidentalWyschnegradsky10TwelfthsSharp",
+    "accidentalWyschnegradsky11TwelfthsFlat",
+    "accidentalWyschnegradsky11TwelfthsSharp",
+    "accidentalWyschnegradsky1TwelfthsFlat",
+    "accidentalWyschnegradsky1TwelfthsSharp",
+    "accidentalWyschnegradsky2TwelfthsFlat",
+    "accidentalWyschnegradsky2TwelfthsSharp",
+    "accidentalWyschnegradsky3TwelfthsFlat",
+    "accidentalWyschnegradsky3TwelfthsSharp",
+    "accidentalWyschnegradsky4TwelfthsFlat",
+    "accidentalWyschnegradsky4TwelfthsSharp",
+    "accidentalWyschnegradsky5TwelfthsFlat",
+    "accidentalWyschnegradsky5TwelfthsSharp",
+    "accidentalWyschnegradsky6TwelfthsFlat",
+    "accidentalWyschnegradsky6TwelfthsSharp",
+    "accidentalWyschnegradsky7TwelfthsFlat",
+    "accidentalWyschnegradsky7TwelfthsSharp",
+    "accidentalWyschnegradsky8TwelfthsFlat",
+    "accidentalWyschnegradsky8TwelfthsSharp",
+    "accidentalWyschnegradsky9TwelfthsFlat",
+    "accidentalWyschnegradsky9TwelfthsSharp",
+    "accidentalXenakisOneThirdToneSharp",
+    "accidentalXenakisTwoThirdTonesSharp",
+    "analyticsChoralmelodie",
+    "analyticsEndStimme",
+    "analyticsHauptrhythmus",
+    "analyticsHauptstimme",
+    "analyticsInversion1",
+    "analyticsNebenstimme",
+    "analyticsStartStimme",
+    "analyticsTheme",
+    "analyticsTheme1",
+    "analyticsThemeInversion",
+    "analyticsThemeRetrograde",
+    "analyticsThemeRetrogradeInversion",
+    "arpeggiato",
+    "arpeggiatoDown",
+    "arpeggiatoUp",
+    "arrowBlackDown",
+    "arrowBlackDownLeft",
+    "arrowBlackDownRight",
+    "arrowBlackLeft",
+    "arrowBlackRight",
+    "arrowBlackUp",
+    "arrowBlackUpLeft",
+    "arrowBlackUpRight",
+    "arrowOpenDown",
+    "arrowOpenDownLeft",
+    "arrowOpenDownRight",
+    "arrowOpenLeft",
+    "arrowOpenRight",
+    "arrowOpenUp",
+    "arrowOpenUpLeft",
+    "arrowOpenUpRight",
+    "arrowWhiteDown",
+    "arrowWhiteDownLeft",
+    "arrowWhiteDownRight",
+    "arrowWhiteLeft",
+    "arrowWhiteRight",
+    "arrowWhiteUp",
+    "arrowWhiteUpLeft",
+    "arrowWhiteUpRight",
+    "arrowheadBlackDown",
+    "arrowheadBlackDownLeft",
+    "arrowheadBlackDownRight",
+    "arrowheadBlackLeft",
+    "arrowheadBlackRight",
+    "arrowheadBlackUp",
+    "arrowheadBlackUpLeft",
+    "arrowheadBlackUpRight",
+    "arrowheadOpenDown",
+    "arrowheadOpenDownLeft",
+    "arrowheadOpenDownRight",
+    "arrowheadOpenLeft",
+    "arrowheadOpenRight",
+    "arrowheadOpenUp",
+    "arrowheadOpenUpLeft",
+    "arrowheadOpenUpRight",
+    "arrowheadWhiteDown",
+    "arrowheadWhiteDownLeft",
+    "arrowheadWhiteDownRight",
+    "arrowheadWhiteLeft",
+    "arrowheadWhiteRight",
+    "arrowheadWhiteUp",
+    "arrowheadWhiteUpLeft",
+    "arrowheadWhiteUpRight",
+    "articAccentAbove",
+    "articAccentBelow",
+    "articAccentStaccatoAbove",
+    "articAccentStaccatoBelow",
+    "articLaissezVibrerAbove",
+    "articLaissezVibrerBelow",
+    "articMarcatoAbove",
+    "articMarcatoBelow",
+    "articMarcatoStaccatoAbove",
+    "articMarcatoStaccatoBelow",
+    "articMarcatoTenutoAbove",
+    "articMarcatoTenutoBelow",
+    "articSoftAccentAbove",
+    "articSoftAccentBelow",
+    "articSoftAccentStaccatoAbove",
+    "articSoftAccentStaccatoBelow",
+    "articSoftAccentTenutoAbove",
+    "articSoftAccentTenutoBelow",
+    "articSoftAccentTenutoStaccatoAbove",
+    "articSoftAccentTenutoStaccatoBelow",
+    "articStaccatissimoAbove",
+    "articStaccatissimoBelow",
+    "articStaccatissimoStrokeAbove",
+    "articStaccatissimoStrokeBelow",
+    "articStaccatissimoWedgeAbove",
+    "articStaccatissimoWedgeBelow",
+    "articStaccatoAbove",
+    "articStaccatoBelow",
+    "articStressAbove",
+    "articStressBelow",
+    "articTenutoAbove",
+    "articTenutoAccentAbove",
+    "articTenutoAccentBelow",
+    "articTenutoBelow",
+    "articTenutoStaccatoAbove",
+    "articTenutoStaccatoBelow",
+    "articUnstressAbove",
+    "articUnstressBelow",
+    "augmentationDot",
+    "barlineDashed",
+    "barlineDotted",
+    "barlineDouble",
+    "barlineFinal",
+    "barlineHeavy",
+    "barlineHeavyHeavy",
+    "barlineReverseFinal",
+    "barlineShort",
+    "barlineSingle",
+    "barlineTick",
+    "beamAccelRit1",
+    "beamAccelRit10",
+    "beamAccelRit11",
+    "beamAccelRit12",
+    "beamAccelRit13",
+    "beamAccelRit14",
+    "beamAccelRit15",
+    "beamAccelRit2",
+    "beamAccelRit3",
+    "beamAccelRit4",
+    "beamAccelRit5",
+    "beamAccelRit6",
+    "beamAccelRit7",
+    "beamAccelRit8",
+    "beamAccelRit9",
+    "beamAccelRitFinal",
+    "brace",
+    "bracket",
+    "bracketBottom",
+    "bracketTop",
+    "brassBend",
+    "brassDoitLong",
+    "brassDoitMedium",
+    "brassDoitShort",
+    "brassFallLipLong",
+    "brassFallLipMedium",
+    "brassFallLipShort",
+    "brassFallRoughLong",
+    "brassFallRoughMedium",
+    "brassFallRoughShort",
+    "brassFallSmoothLong",
+    "brassFallSmoothMedium",
+    "brassFallSmoothShort",
+    "brassFlip",
+    "brassHarmonMuteClosed",
+    "brassHarmonMuteStemHalfLeft",
+    "brassHarmonMuteStemHalfRight",
+    "brassHarmonMuteStemOpen",
+    "brassJazzTurn",
+    "brassLiftLong",
+    "brassLiftMedium",
+    "brassLiftShort",
+    "brassLiftSmoothLong",
+    "brassLiftSmoothMedium",
+    "brassLiftSmoothShort",
+    "brassMuteClosed",
+    "brassMuteHalfClosed",
+    "brassMuteOpen",
+    "brassPlop",
+    "brassScoop",
+    "brassSmear",
+    "brassValveTrill",
+    "breathMarkComma",
+    "breathMarkSalzedo",
+    "breathMarkTick",
+    "breathMarkUpbow",
+    "bridgeClef",
+    "buzzRoll",
+    "cClef",
+    "cClef8vb",
+    "cClefArrowDown",
+    "cClefArrowUp",
+    "cClefChange",
+    "cClefCombining",
+    "cClefReversed",
+    "cClefSquare",
+    "caesura",
+    "caesuraCurved",
+    "caesuraShort",
+    "caesuraSingleStroke",
+    "caesuraThick",
+    "chantAccentusAbove",
+    "chantAccentusBelow",
+    "chantAuctumAsc",
+    "chantAuctumDesc",
+    "chantAugmentum",
+    "chantCaesura",
+    "chantCclef",
+    "chantCirculusAbove",
+    "chantCirculusBelow",
+    "chantConnectingLineAsc2nd",
+    "chantConnectingLineAsc3rd",
+    "chantConnectingLineAsc4th",
+    "chantConnectingLineAsc5th",
+    "chantConnectingLineAsc6th",
+    "chantCustosStemDownPosHigh",
+    "chantCustosStemDownPosHighest",
+    "chantCustosStemDownPosMiddle",
+    "chantCustosStemUpPosLow",
+    "chantCustosStemUpPosLowest",
+    "chantCustosStemUpPosMiddle",
+    "chantDeminutumLower",
+    "chantDeminutumUpper",
+    "chantDivisioFinalis",
+    "chantDivisioMaior",
+    "chantDivisioMaxima",
+    "chantDivisioMinima",
+    "chantEntryLineAsc2nd",
+    "chantEntryLineAsc3rd",
+    "chantEntryLineAsc4th",
+    "chantEntryLineAsc5th",
+    "chantEntryLineAsc6th",
+    "chantEpisema",
+    "chantFclef",
+    "chantIctusAbove",
+    "chantIctusBelow",
+    "chantLigaturaDesc2nd",
+    "chantLigaturaDesc3rd",
+    "chantLigaturaDesc4th",
+    "chantLigaturaDesc5th",
+    "chantOriscusAscending",
+    "chantOriscusDescending",
+    "chantOriscusLiquescens",
+    "chantPodatusLower",
+    "chantPodatusUpper",
+    "chantPunctum",
+    "chantPunctumCavum",
+    "chantPunctumDeminutum",
+    "chantPunctumInclinatum",
+    "chantPunctumInclinatumAuctum",
+    "chantPunctumInclinatumDeminutum",
+    "chantPunctumLinea",
+    "chantPunctumLineaCavum",
+    "chantPunctumVirga",
+    "chantPunctumVirgaReversed",
+    "chantQuilisma",
+    "chantSemicirculusAbove",
+    "chantSemicirculusBelow",
+    "chantStaff",
+    "chantStaffNarrow",
+    "chantStaffWide",
+    "chantStrophicus",
+    "chantStrophicusAuctus",
+    "chantStrophicusLiquescens2nd",
+    "chantStrophicusLiquescens3rd",
+    "chantStrophicusLiquescens4th",
+    "chantStrophicusLiquescens5th",
+    "chantVirgula",
+    "clef15",
+    "clef8",
+    "clefChangeCombining",
+    "coda",
+    "codaSquare",
+    "conductorBeat2Compound",
+    "conductorBeat2Simple",
+    "conductorBeat3Compound",
+    "conductorBeat3Simple",
+    "conductorBeat4Compound",
+    "conductorBeat4Simple",
+    "conductorLeftBeat",
+    "conductorRightBeat",
+    "conductorStrongBeat",
+    "conductorUnconducted",
+    "conductorWeakBeat",
+    "controlBeginBeam",
+    "controlBeginPhrase",
+    "controlBeginSlur",
+    "controlBeginTie",
+    "controlEndBeam",
+    "controlEndPhrase",
+    "controlEndSlur",
+    "controlEndTie",
+    "csymAccidentalDoubleFlat",
+    "csymAccidentalDoubleSharp",
+    "csymAccidentalFlat",
+    "csymAccidentalNatural",
+    "csymAccidentalSharp",
+    "csymAccidentalTripleFlat",
+    "csymAccidentalTripleSharp",
+    "csymAlteredBassSlash",
+    "csymAugmented",
+    "csymBracketLeftTall",
+    "csymBracketRightTall",
+    "csymDiagonalArrangementSlash",
+    "csymDiminished",
+    "csymHalfDiminished",
+    "csymMajorSeventh",
+    "csymMinor",
+    "csymParensLeftTall",
+    "csymParensLeftVeryTall",
+    "csymParensRightTall",
+    "csymParensRightVeryTall",
+    "curlewSign",
+    "daCapo",
+    "dalSegno",
+    "daseianExcellentes1",
+    "daseianExcellentes2",
+    "daseianExcellentes3",
+    "daseianExcellentes4",
+    "daseianFinales1",
+    "daseianFinales2",
+    "daseianFinales3",
+    "daseianFinales4",
+    "daseianGraves1",
+    "daseianGraves2",
+    "daseianGraves3",
+    "daseianGraves4",
+    "daseianResidua1",
+    "daseianResidua2",
+    "daseianSuperiores1",
+    "daseianSuperiores2",
+    "daseianSuperiores3",
+    "daseianSuperiores4",
+    "doubleLateralRollStevens",
+    "doubleTongueAbove",
+    "doubleTongueBelow",
+    "dynamicCombinedSeparatorColon",
+    "dynamicCombinedSeparatorHyphen",
+    "dynamicCombinedSeparatorSlash",
+    "dynamicCombinedSeparatorSpace",
+    "dynamicCrescendoHairpin",
+    "dynamicDiminuendoHairpin",
+    "dynamicFF",
+    "dynamicFFF",
+    "dynamicFFFF",
+    "dynamicFFFFF",
+    "dynamicFFFFFF",
+    "dynamicForte",
+    "dynamicFortePiano",
+    "dynamicForzando",
+    "dynamicHairpinBracketLeft",
+    "dynamicHairpinBracketRight",
+    "dynamicHairpinParenthesisLeft",
+    "dynamicHairpinParenthesisRight",
+    "dynamicMessaDiVoce",
+    "dynamicMezzo",
+    "dynamicMF",
+    "dynamicMP",
+    "dynamicNiente",
+    "dynamicNienteForHairpin",
+    "dynamicPF",
+    "dynamicPiano",
+    "dynamicPP",
+    "dynamicPPP",
+    "dynamicPPPP",
+    "dynamicPPPPP",
+    "dynamicPPPPPP",
+    "dynamicRinforzando",
+    "dynamicRinforzando1",
+    "dynamicRinforzando2",
+    "dynamicSforzando",
+    "dynamicSforzando1",
+    "dynamicSforzandoPianissimo",
+    "dynamicSforzandoPiano",
+    "dynamicSforzato",
+    "dynamicSforzatoFF",
+    "dynamicSforzatoPiano",
+    "dynamicZ",
+    "elecAudioChannelsEight",
+    "elecAudioChannelsFive",
+    "elecAudioChannelsFour",
+    "elecAudioChannelsOne",
+    "elecAudioChannelsSeven",
+    "elecAudioChannelsSix",
+    "elecAudioChannelsThreeFrontal",
+    "elecAudioChannelsThreeSurround",
+    "elecAudioChannelsTwo",
+    "elecAudioIn",
+    "elecAudioMono",
+    "elecAudioOut",
+    "elecAudioStereo",
+    "elecCamera",
+    "elecDataIn",
+    "elecDataOut",
+    "elecDisc",
+    "elecDownload",
+    "elecEject",
+    "elecFastForward",
+    "elecHeadphones",
+    "elecHeadset",
+    "elecLineIn",
+    "elecLineOut",
+    "elecLoop",
+    "elecLoudspeaker",
+    "elecMicrophone",
+    "elecMicrophoneMute",
+    "elecMicrophoneUnmute",
+    "elecMIDIController0",
+    "elecMIDIController100",
+    "elecMIDIController20",
+    "elecMIDIController40",
+    "elecMIDIController60",
+    "elecMIDIController80",
+    "elecMIDIIn",
+    "elecMIDIOut",
+    "elecMixingConsole",
+    "elecMonitor",
+    "elecMute",
+    "elecPause",
+    "elecPlay",
+    "elecPowerOnOff",
+    "elecProjector",
+    "elecReplay",
+    "elecRewind",
+    "elecShuffle",
+    "elecSkipBackwards",
+    "elecSkipForwards",
+    "elecStop",
+    "elecTape",
+    "elecUnmute",
+    "elecUpload",
+    "elecUSB",
+    "elecVideoCamera",
+    "elecVideoIn",
+    "elecVideoOut",
+    "elecVolumeFader",
+    "elecVolumeFaderThumb",
+    "elecVolumeLevel0",
+    "elecVolumeLevel100",
+    "elecVolumeLevel20",
+    "elecVolumeLevel40",
+    "elecVolumeLevel60",
+    "elecVolumeLevel80",
+    "fClef",
+    "fClef15ma",
+    "fClef15mb",
+    "fClef8va",
+    "fClef8vb",
+    "fClefArrowDown",
+    "fClefArrowUp",
+    "fClefChange",
+    "fClefReversed",
+    "fClefTurned",
+    "fermataAbove",
+    "fermataBelow",
+    "fermataLongAbove",
+    "fermataLongBelow",
+    "fermataLongHenzeAbove",
+    "fermataLongHenzeBelow",
+    "fermataShortAbove",
+    "fermataShortBelow",
+    "fermataShortHenzeAbove",
+    "fermataShortHenzeBelow",
+    "fermataVeryLongAbove",
+    "fermataVeryLongBelow",
+    "fermataVeryShortAbove",
+    "fermataVeryShortBelow",
+    "figbass0",
+    "figbass1",
+    "figbass2",
+    "figbass2Raised",
+    "figbass3",
+    "figbass4",
+    "figbass4Raised",
+    "figbass5",
+    "figbass5Raised1",
+    "figbass5Raised2",
+    "figbass5Raised3",
+    "figbass6",
+    "figbass6Raised",
+    "figbass6Raised2",
+    "figbass7",
+    "figbass7Diminished",
+    "figbass7Raised1",
+    "figbass7Raised2",
+    "figbass8",
+    "figbass9",
+    "figbass9Raised",
+    "figbassBracketLeft",
+    "figbassBracketRight",
+    "figbassCombiningLowering",
+    "figbassCombiningRaising",
+    "figbassDoubleFlat",
+    "figbassDoubleSharp",
+    "figbassFlat",
+    "figbassNatural",
+    "figbassParensLeft",
+    "figbassParensRight",
+    "figbassPlus",
+    "figbassSharp",
+    "figbassTripleFlat",
+    "figbassTripleSharp",
+    "fingering0",
+    "fingering0Italic",
+    "fingering1",
+    "fingering1Italic",
+    "fingering2",
+    "fingering2Italic",
+    "fingering3",
+    "fingering3Italic",
+    "fingering4",
+    "fingering4Italic",
+    "fingering5",
+    "fingering5Italic",
+    "fingering6",
+    "fingering6Italic",
+    "fingering7",
+    "fingering7Italic",
+    "fingering8",
+    "fingering8Italic",
+    "fingering9",
+    "fingering9Italic",
+    "fingeringALower",
+    "fingeringCLower",
+    "fingeringELower",
+    "fingeringILower",
+    "fingeringLeftBracket",
+    "fingeringLeftBracketItalic",
+    "fingeringLeftParenthesis",
+    "fingeringLeftParenthesisItalic",
+    "fingeringMLower",
+    "fingeringMultipleNotes",
+    "fingeringOLower",
+    "fingeringPLower",
+    "fingeringQLower",
+    "fingeringRightBracket",
+    "fingeringRightBracketItalic",
+    "fingeringRightParenthesis",
+    "fingeringRightParenthesisItalic",
+    "fingeringSLower",
+    "fingeringSeparatorMiddleDot",
+    "fingeringSeparatorMiddleDotWhite",
+    "fingeringSeparatorSlash",
+    "fingeringSubstitutionAbove",
+    "fingeringSubstitutionBelow",
+    "fingeringSubstitutionDash",
+    "fingeringTLower",
+    "fingeringTUpper",
+    "fingeringXLower",
+    "flag1024thDown",
+    "flag1024thUp",
+    "flag128thDown",
+    "flag128thUp",
+    "flag16thDown",
+    "flag16thUp",
+    "flag256thDown",
+    "flag256thUp",
+    "flag32ndDown",
+    "flag32ndUp",
+    "flag512thDown",
+    "flag512thUp",
+    "flag64thDown",
+    "flag64thUp",
+    "flag8thDown",
+    "flag8thUp",
+    "flagInternalDown",
+    "flagInternalUp",
+    "fretboard3String",
+    "fretboard3StringNut",
+    "fretboard4String",
+    "fretboard4StringNut",
+    "fretboard5String",
+    "fretboard5StringNut",
+    "fretboard6String",
+    "fretboard6StringNut",
+    "fretboardFilledCircle",
+    "fretboardO",
+    "fretboardX",
+    "functionAngleLeft",
+    "functionAngleRight",
+    "functionBracketLeft",
+    "functionBracketRight",
+    "functionDLower",
+    "functionDUpper",
+    "functionDD",
+    "functionEight",
+    "functionFUpper",
+    "functionFive",
+    "functionFour",
+    "functionGLower",
+    "functionGUpper",
+    "functionGreaterThan",
+    "functionILower",
+    "functionIUpper",
+    "functionKLower",
+    "functionKUpper",
+    "functionLLower",
+    "functionLUpper",
+    "functionLessThan",
+    "functionMLower",
+    "functionMUpper",
+    "functionMinus",
+    "functionNLower",
+    "functionNUpper",
+    "functionNUpperSuperscript",
+    "functionNine",
+    "functionOne",
+    "functionPLower",
+    "functionPUpper",
+    "functionParensLeft",
+    "functionParensRight",
+    "functionPlus",
+    "functionRLower",
+    "functionRepetition1",
+    "functionRepetition2",
+    "functionRing",
+    "functionSLower",
+    "functionSUpper",
+    "functionSeven",
+    "functionSix",
+    "functionSlashedDD",
+    "functionSSLower",
+    "functionSSUpper",
+    "functionTLower",
+    "functionTUpper",
+    "functionThree",
+    "functionTwo",
+    "functionVLower",
+    "functionVUpper",
+    "functionZero",
+    "gClef",
+    "gClef15ma",
+    "gClef15mb",
+    "gClef8va",
+    "gClef8vb",
+    "gClef8vbCClef",
+    "gClef8vbOld",
+    "gClef8vbParens",
+    "gClefArrowDown",
+    "gClefArrowUp",
+    "gClefChange",
+    "gClefLigatedNumberAbove",
+    "gClefLigatedNumberBelow",
+    "gClefReversed",
+    "gClefTurned",
+    "glissandoDown",
+    "glissandoUp",
+    "graceNoteAcciaccaturaStemDown",
+    "graceNoteAcciaccaturaStemUp",
+    "graceNoteAppoggiaturaStemDown",
+    "graceNoteAppoggiaturaStemUp",
+    "graceNoteSlashStemDown",
+    "graceNoteSlashStemUp",
+    "guitarBarreFull",
+    "guitarBarreHalf",
+    "guitarClosePedal",
+    "guitarFadeIn",
+    "guitarFadeOut",
+    "guitarGolpe",
+    "guitarHalfOpenPedal",
+    "guitarLeftHandTapping",
+    "guitarOpenPedal",
+    "guitarRightHandTapping",
+    "guitarShake",
+    "guitarString0",
+    "guitarString1",
+    "guitarString10",
+    "guitarString11",
+    "guitarString12",
+    "guitarString13",
+    "guitarString2",
+    "guitarString3",
+    "guitarString4",
+    "guitarString5",
+    "guitarString6",
+    "guitarString7",
+    "guitarString8",
+    "guitarString9",
+    "guitarStrumDown",
+    "guitarStrumUp",
+    "guitarVibratoBarDip",
+    "guitarVibratoBarScoop",
+    "guitarVibratoStroke",
+    "guitarVolumeSwell",
+    "guitarWideVibratoStroke",
+    "handbellsBelltree",
+    "handbellsDamp3",
+    "handbellsEcho1",
+    "handbellsEcho2",
+    "handbellsGyro",
+    "handbellsHandMartellato",
+    "handbellsMalletBellOnTable",
+    "handbellsMalletBellSuspended",
+    "handbellsMalletLft",
+    "handbellsMartellato",
+    "handbellsMartellatoLift",
+    "handbellsMutedMartellato",
+    "handbellsPluckLift",
+    "handbellsSwing",
+    "handbellsSwingDown",
+    "handbellsSwingUp",
+    "handbellsTablePairBells",
+    "handbellsTableSingleBell",
+    "harpMetalRod",
+    "harpPedalCentered",
+    "harpPedalDivider",
+    "harpPedalLowered",
+    "harpPedalRaised",
+    "harpSalzedoAeolianAscending",
+    "harpSalzedoAeolianDescending",
+    "harpSalzedoDampAbove",
+    "harpSalzedoDampBelow",
+    "harpSalzedoDampBothHands",
+    "harpSalzedoDampLowStrings",
+    "harpSalzedoFluidicSoundsLeft",
+    "harpSalzedoFluidicSoundsRight",
+    "harpSalzedoIsolatedSounds",
+    "harpSalzedoMetallicSounds",
+    "harpSalzedoMetallicSoundsOneString",
+    "harpSalzedoMuffleTotally",
+    "harpSalzedoOboicFlux",
+    "harpSalzedoPlayUpperEnd",
+    "harpSalzedoSlideWithSuppleness",
+    "harpSalzedoSnareDrum",
+    "harpSalzedoTamTamSounds",
+    "harpSalzedoThunderEffect",
+    "harpSalzedoTimpanicSounds",
+    "harpSalzedoWhistlingSounds",
+    "harpStringNoiseStem",
+    "harpTuningKey",
+    "harpTuningKeyGlissando",
+    "harpTuningKeyHandle",
+    "harpTuningKeyShank",
+    "indianDrumClef",
+    "kahnBackChug",
+    "kahnBackFlap",
+    "kahnBackRiff",
+    "kahnBackRip",
+    "kahnBallChange",
+    "kahnBallDig",
+    "kahnBrushBackward",
+    "kahnBrushForward",
+    "kahnChug",
+    "kahnClap",
+    "kahnDoubleSnap",
+    "kahnDoubleWing",
+    "kahnDrawStep",
+    "kahnDrawTap",
+    "kahnFlam",
+    "kahnFlap",
+    "kahnFlapStep",
+    "kahnFlat",
+    "kahnFleaHop",
+    "kahnFleaTap",
+    "kahnGraceTap",
+    "kahnGraceTapChange",
+    "kahnGraceTapHop",
+    "kahnGraceTapStamp",
+    "kahnHeel",
+    "kahnHeelChange",
+    "kahnHeelClick",
+    "kahnHeelDrop",
+    "kahnHeelStep",
+    "kahnHeelTap",
+    "kahnHop",
+    "kahnJumpApart",
+    "kahnJumpTogether",
+    "kahnKneeInward",
+    "kahnKneeOutward",
+    "kahnLeap",
+    "kahnLeapFlatFoot",
+    "kahnLeapHeelClick",
+    "kahnLeftCatch",
+    "kahnLeftCross",
+    "kahnLeftFoot",
+    "kahnLeftToeStrike",
+    "kahnLeftTurn",
+    "kahnOverTheTop",
+    "kahnOverTheTopTap",
+    "kahnPull",
+    "kahnPush",
+    "kahnRiff",
+    "kahnRiffle",
+    "kahnRightCatch",
+    "kahnRightCross",
+    "kahnRightFoot",
+    "kahnRightToeStrike",
+    "kahnRightTurn",
+    "kahnRip",
+    "kahnRipple",
+    "kahnScrape",
+    "kahnScuff",
+    "kahnScuffle",
+    "kahnShuffle",
+    "kahnSlam",
+    "kahnSlap",
+    "kahnSlideStep",
+    "kahnSlideTap",
+    "kahnSnap",
+    "kahnStamp",
+    "kahnStampStamp",
+    "kahnStep",
+    "kahnStepStamp",
+    "kahnStomp",
+    "kahnStompBrush",
+    "kahnTap",
+    "kahnToe",
+    "kahnToeClick",
+    "kahnToeDrop",
+    "kahnToeStep",
+    "kahnToeTap",
+    "kahnTrench",
+    "kahnWing",
+    "kahnWingChange",
+    "kahnZank",
+    "kahnZink",
+    "keyboardBebung2DotsAbove",
+    "keyboardBebung2DotsBelow",
+    "keyboardBebung3DotsAbove",
+    "keyboardBebung3DotsBelow",
+    "keyboardBebung4DotsAbove",
+    "keyboardBebung4DotsBelow",
+    "keyboardLeftPedalPictogram",
+    "keyboardMiddlePedalPictogram",
+    "keyboardPedalD",
+    "keyboardPedalDot",
+    "keyboardPedalE",
+    "keyboardPedalHalf",
+    "keyboardPedalHalf2",
+    "keyboardPedalHalf3",
+    "keyboardPedalHeel1",
+    "keyboardPedalHeel2",
+    "keyboardPedalHeel3",
+    "keyboardPedalHeelToToe",
+    "keyboardPedalHeelToe",
+    "keyboardPedalHookEnd",
+    "keyboardPedalHookStart",
+    "keyboardPedalHyphen",
+    "keyboardPedalP",
+    "keyboardPedalParensLeft",
+    "keyboardPedalParensRight",
+    "keyboardPedalPed",
+    "keyboardPedalS",
+    "keyboardPedalSost",
+    "keyboardPedalToe1",
+    "keyboardPedalToe2",
+    "keyboardPedalToeToHeel",
+    "keyboardPedalUp",
+    "keyboardPedalUpNotch",
+    "keyboardPedalUpSpecial",
+    "keyboardPlayWithLH",
+    "keyboardPlayWithLHEnd",
+    "keyboardPlayWithRH",
+    "keyboardPlayWithRHEnd",
+    "keyboardPluckInside",
+    "keyboardRightPedalPictogram",
+    "kievanAccidentalFlat",
+    "kievanAccidentalSharp",
+    "kievanAugmentationDot",
+    "kievanCClef",
+    "kievanEndingSymbol",
+    "kievanNote8thStemDown",
+    "kievanNote8thStemUp",
+    "kievanNoteBeam",
+    "kievanNoteHalfStaffLine",
+    "kievanNoteHalfStaffSpace",
+    "kievanNoteQuarterStemDown",
+    "kievanNoteQuarterStemUp",
+    "kievanNoteReciting",
+    "kievanNoteWhole",
+    "kievanNoteWholeFinal",
+    "kodalyHandDo",
+    "kodalyHandFa",
+    "kodalyHandLa",
+    "kodalyHandMi",
+    "kodalyHandRe",
+    "kodalyHandSo",
+    "kodalyHandTi",
+    "leftRepeatSmall",
+    "legerLine",
+    "legerLineNarrow",
+    "legerLineWide",
+    "luteBarlineEndRepeat",
+    "luteBarlineFinal",
+    "luteBarlineStartRepeat",
+    "luteDuration16th",
+    "luteDuration32nd",
+    "luteDuration8th",
+    "luteDurationDoubleWhole",
+    "luteDurationHalf",
+    "luteDurationQuarter",
+    "luteDurationWhole",
+    "luteFingeringRHFirst",
+    "luteFingeringRHSecond",
+    "luteFingeringRHThird",
+    "luteFingeringRHThumb",
+    "luteFrench10thCourse",
+    "luteFrench7thCourse",
+    "luteFrench8thCourse",
+    "luteFrench9thCourse",
+    "luteFrenchAppoggiaturaAbove",
+    "luteFrenchAppoggiaturaBelow",
+    "luteFrenchFretA",
+    "luteFrenchFretB",
+    "luteFrenchFretC",
+    "luteFrenchFretD",
+    "luteFrenchFretE",
+    "luteFrenchFretF",
+    "luteFrenchFretG",
+    "luteFrenchFretH",
+    "luteFrenchFretI",
+    "luteFrenchFretK",
+    "luteFrenchFretL",
+    "luteFrenchFretM",
+    "luteFrenchFretN",
+    "luteFrenchMordentInverted",
+    "luteFrenchMordentLower",
+    "luteFrenchMordentUpper",
+    "luteGermanALower",
+    "luteGermanAUpper",
+    "luteGermanBLower",
+    "luteGermanBUpper",
+    "luteGermanCLower",
+    "luteGermanCUpper",
+    "luteGermanDLower",
+    "luteGermanDUpper",
+    "luteGermanELower",
+    "luteGermanEUpper",
+    "luteGermanFLower",
+    "luteGermanFUpper",
+    "luteGermanGLower",
+    "luteGermanGUpper",
+    "luteGermanHLower",
+    "luteGermanHUpper",
+    "luteGermanILower",
+    "luteGermanIUpper",
+    "luteGermanKLower",
+    "luteGermanKUpper",
+    "luteGermanLLower",
+    "luteGermanLUpper",
+    "luteGermanMLower",
+    "luteGermanMUpper",
+    "luteGermanNLower",
+    "luteGermanNUpper",
+    "luteGermanOLower",
+    "luteGermanPLower",
+    "luteGermanQLower",
+    "luteGermanRLower",
+    "luteGermanSLower",
+    "luteGermanTLower",
+    "luteGermanVLower",
+    "luteGermanXLower",
+    "luteGermanYLower",
+    "luteGermanZLower",
+    "luteItalianClefCSolFaUt",
+    "luteItalianClefFFaUt",
+    "luteItalianFret0",
+    "luteItalianFret1",
+    "luteItalianFret2",
+    "luteItalianFret3",
+    "luteItalianFret4",
+    "luteItalianFret5",
+    "luteItalianFret6",
+    "luteItalianFret7",
+    "luteItalianFret8",
+    "luteItalianFret9",
+    "luteItalianHoldFinger",
+    "luteItalianHoldNote",
+    "luteItalianReleaseFinger",
+    "luteItalianTempoFast",
+    "luteItalianTempoNeitherFastNorSlow",
+    "luteItalianTempoSlow",
+    "luteItalianTempoSomewhatFast",
+    "luteItalianTempoVerySlow",
+    "luteItalianTimeTriple",
+    "luteItalianTremolo",
+    "luteItalianVibrato",
+    "luteStaff6Lines",
+    "luteStaff6LinesNarrow",
+    "luteStaff6LinesWide",
+    "lyricsElision",
+    "lyricsElisionNarrow",
+    "lyricsElisionWide",
+    "lyricsHyphenBaseline",
+    "lyricsHyphenBaselineNonBreaking",
+    "lyricsTextRepeat",
+    "medRenFlatHardB",
+    "medRenFlatSoftB",
+    "medRenFlatWithDot",
+    "medRenGClefCMN",
+    "medRenLiquescenceCMN",
+    "medRenLiquescentAscCMN",
+    "medRenLiquescentDescCMN",
+    "medRenNatural",
+    "medRenNaturalWithCross",
+    "medRenOriscusCMN",
+    "medRenPlicaCMN",
+    "medRenPunctumCMN",
+    "medRenQuilismaCMN",
+    "medRenSharpCroix",
+    "medRenStrophicusCMN",
+    "mensuralAlterationSign",
+    "mensuralBlackBrevis",
+    "mensuralBlackBrevisVoid",
+    "mensuralBlackDragma",
+    "mensuralBlackLonga",
+    "mensuralBlackMaxima",
+    "mensuralBlackMinima",
+    "mensuralBlackMinimaVoid",
+    "mensuralBlackSemibrevis",
+    "mensuralBlackSemibrevisCaudata",
+    "mensuralBlackSemibrevisOblique",
+    "mensuralBlackSemibrevisVoid",
+    "mensuralBlackSemiminima",
+    "mensuralCclef",
+    "mensuralCclefPetrucciPosHigh",
+    "mensuralCclefPetrucciPosHighest",
+    "mensuralCclefPetrucciPosLow",
+    "mensuralCclefPetrucciPosLowest",
+    "mensuralCclefPetrucciPosMiddle",
+    "mensuralColorationEndRound",
+    "mensuralColorationEndSquare",
+    "mensuralColorationStartRound",
+    "mensuralColorationStartSquare",
+    "mensuralCombStemDiagonal",
+    "mensuralCombStemDown",
+    "mensuralCombStemDownFlagExtended",
+    "mensuralCombStemDownFlagFlared",
+    "mensuralCombStemDownFlagFusa",
+    "mensuralCombStemDownFlagLeft",
+    "mensuralCombStemDownFlagRight",
+    "mensuralCombStemDownFlagSemiminima",
+    "mensuralCombStemUp",
+    "mensuralCombStemUpFlagExtended",
+    "mensuralCombStemUpFlagFlared",
+    "mensuralCombStemUpFlagFusa",
+    "mensuralCombStemUpFlagLeft",
+    "mensuralCombStemUpFlagRight",
+    "mensuralCombStemUpFlagSemiminima",
+    "mensuralCustosCheckmark",
+    "mensuralCustosDown",
+    "mensuralCustosTurn",
+    "mensuralCustosUp",
+    "mensuralFclef",
+    "mensuralFclefPetrucci",
+    "mensuralGclef",
+    "mensuralGclefPetrucci",
+    "mensuralModusImperfectumVert",
+    "mensuralModusPerfectumVert",
+    "mensuralNoteheadLongaBlack",
+    "mensuralNoteheadLongaBlackVoid",
+    "mensuralNoteheadLongaVoid",
+    "mensuralNoteheadLongaWhite",
+    "mensuralNoteheadMaximaBlack",
+    "mensuralNoteheadMaximaBlackVoid",
+    "mensuralNoteheadMaximaVoid",
+    "mensuralNoteheadMaximaWhite",
+    "mensuralNoteheadMinimaWhite",
+    "mensuralNoteheadSemibrevisBlack",
+    "mensuralNoteheadSemibrevisBlackVoid",
+    "mensuralNoteheadSemibrevisBlackVoidTurned",
+    "mensuralNoteheadSemibrevisVoid",
+    "mensuralNoteheadSemiminimaWhite",
+    "mensuralObliqueAsc2ndBlack",
+    "mensuralObliqueAsc2ndBlackVoid",
+    "mensuralObliqueAsc2ndVoid",
+    "mensuralObliqueAsc2ndWhite",
+    "mensuralObliqueAsc3rdBlack",
+    "mensuralObliqueAsc3rdBlackVoid",
+    "mensuralObliqueAsc3rdVoid",
+    "mensuralObliqueAsc3rdWhite",
+    "mensuralObliqueAsc4thBlack",
+    "mensuralObliqueAsc4thBlackVoid",
+    "mensuralObliqueAsc4thVoid",
+    "mensuralObliqueAsc4thWhite",
+    "mensuralObliqueAsc5thBlack",
+    "mensuralObliqueAsc5thBlackVoid",
+    "mensuralObliqueAsc5thVoid",
+    "mensuralObliqueAsc5thWhite",
+    "mensuralObliqueDesc2ndBlack",
+    "mensuralObliqueDesc2ndBlackVoid",
+    "mensuralObliqueDesc2ndVoid",
+    "mensuralObliqueDesc2ndWhite",
+    "mensuralObliqueDesc3rdBlack",
+    "mensuralObliqueDesc3rdBlackVoid",
+    "mensuralObliqueDesc3rdVoid",
+    "mensuralObliqueDesc3rdWhite",
+    "mensuralObliqueDesc4thBlack",
+    "mensuralObliqueDesc4thBlackVoid",
+    "mensuralObliqueDesc4thVoid",
+    "mensuralObliqueDesc4thWhite",
+    "mensuralObliqueDesc5thBlack",
+    "mensuralObliqueDesc5thBlackVoid",
+    "mensuralObliqueDesc5thVoid",
+    "mensuralObliqueDesc5thWhite",
+    "mensuralProlation1",
+    "mensuralProlation10",
+    "mensuralProlation11",
+    "mensuralProlation2",
+    "mensuralProlation3",
+    "mensuralProlation4",
+    "mensuralProlation5",
+    "mensuralProlation6",
+    "mensuralProlation7",
+    "mensuralProlation8",
+    "mensuralProlation9",
+    "mensuralProlationCombiningDot",
+    "mensuralProlationCombiningDotVoid",
+    "mensuralProlationCombiningStroke",
+    "mensuralProlationCombiningThreeDots",
+    "mensuralProlationCombiningThreeDotsTri",
+    "mensuralProlationCombiningTwoDots",
+    "mensuralProportion1",
+    "mensuralProportion2",
+    "mensuralProportion3",
+    "mensuralProportion4",
+    "mensuralProportion5",
+    "mensuralProportion6",
+    "mensuralProportion7",
+    "mensuralProportion8",
+    "mensuralProportion9",
+    "mensuralProportionMajor",
+    "mensuralProportionMinor",
+    "mensuralProportionProportioDupla1",
+    "mensuralProportionProportioDupla2",
+    "mensuralProportionProportioQuadrupla",
+    "mensuralProportionProportioTripla",
+    "mensuralProportionTempusPerfectum",
+    "mensuralRestBrevis",
+    "mensuralRestFusa",
+    "mensuralRestLongaImperfecta",
+    "mensuralRestLongaPerfecta",
+    "mensuralRestMaxima",
+    "mensuralRestMinima",
+    "mensuralRestSemibrevis",
+    "mensuralRestSemifusa",
+    "mensuralRestSemiminima",
+    "mensuralSignumDown",
+    "mensuralSignumUp",
+    "mensuralTempusImperfectumHoriz",
+    "mensuralTempusPerfectumHoriz",
+    "mensuralWhiteBrevis",
+    "mensuralWhiteFusa",
+    "mensuralWhiteLonga",
+    "mensuralWhiteMaxima",
+    "mensuralWhiteMinima",
+    "mensuralWhiteSemibrevis",
+    "mensuralWhiteSemiminima",
+    "metAugmentationDot",
+    "metNote1024thDown",
+    "metNote1024thUp",
+    "metNote128thDown",
+    "metNote128thUp",
+    "metNote16thDown",
+    "metNote16thUp",
+    "metNote256thDown",
+    "metNote256thUp",
+    "metNote32ndDown",
+    "metNote32ndUp",
+    "metNote512thDown",
+    "metNote512thUp",
+    "metNote64thDown",
+    "metNote64thUp",
+    "metNote8thDown",
+    "metNote8thUp",
+    "metNoteDoubleWhole",
+    "metNoteDoubleWholeSquare",
+    "metNoteHalfDown",
+    "metNoteHalfUp",
+    "metNoteQuarterDown",
+    "metNoteQuarterUp",
+    "metNoteWhole",
+    "metricModulationArrowLeft",
+    "metricModulationArrowRight",
+    "miscDoNotCopy",
+    "miscDoNotPhotocopy",
+    "miscEyeglasses",
+    "note1024thDown",
+    "note1024thUp",
+    "note128thDown",
+    "note128thUp",
+    "note16thDown",
+    "note16thUp",
+    "note256thDown",
+    "note256thUp",
+    "note32ndDown",
+    "note32ndUp",
+    "note512thDown",
+    "note512thUp",
+    "note64thDown",
+    "note64thUp",
+    "note8thDown",
+    "note8thUp",
+    "noteABlack",
+    "noteAFlatBlack",
+    "noteAFlatHalf",
+    "noteAFlatWhole",
+    "noteAHalf",
+    "noteASharpBlack",
+    "noteASharpHalf",
+    "noteASharpWhole",
+    "noteAWhole",
+    "noteBBlack",
+    "noteBFlatBlack",
+    "noteBFlatHalf",
+    "noteBFlatWhole",
+    "noteBHalf",
+    "noteBSharpBlack",
+    "noteBSharpHalf",
+    "noteBSharpWhole",
+    "noteBWhole",
+    "noteCBlack",
+    "noteCFlatBlack",
+    "noteCFlatHalf",
+    "noteCFlatWhole",
+    "noteCHalf",
+    "noteCSharpBlack",
+    "noteCSharpHalf",
+    "noteCSharpWhole",
+    "noteCWhole",
+    "noteDBlack",
+    "noteDFlatBlack",
+    "noteDFlatHalf",
+    "noteDFlatWhole",
+    "noteDHalf",
+    "noteDSharpBlack",
+    "noteDSharpHalf",
+    "noteDSharpWhole",
+    "noteDWhole",
+    "noteDiBlack",
+    "noteDiHalf",
+    "noteDiWhole",
+    "noteDoBlack",
+    "noteDoHalf",
+    "noteDoWhole",
+    "noteDoubleWhole",
+    "noteDoubleWholeSquare",
+    "noteEBlack",
+    "noteEFlatBlack",
+    "noteEFlatHalf",
+    "noteEFlatWhole",
+    "noteEHalf",
+    "noteESharpBlack",
+    "noteESharpHalf",
+    "noteESharpWhole",
+    "noteEWhole",
+    "noteEmptyBlack",
+    "noteEmptyHalf",
+    "noteEmptyWhole",
+    "noteFBlack",
+    "noteFFlatBlack",
+    "noteFFlatHalf",
+    "noteFFlatWhole",
+    "noteFHalf",
+    "noteFSharpBlack",
+    "noteFSharpHalf",
+    "noteFSharpWhole",
+    "noteFWhole",
+    "noteFaBlack",
+    "noteFaHalf",
+    "noteFaWhole",
+    "noteFiBlack",
+    "noteFiHalf",
+    "noteFiWhole",
+    "noteGBlack",
+    "noteGFlatBlack",
+    "noteGFlatHalf",
+    "noteGFlatWhole",
+    "noteGHalf",
+    "noteGSharpBlack",
+    "noteGSharpHalf",
+    "noteGSharpWhole",
+    "noteGWhole",
+    "noteHBlack",
+    "noteHHalf",
+    "noteHSharpBlack",
+    "noteHSharpHalf",
+    "noteHSharpWhole",
+    "noteHWhole",
+    "noteHalfDown",
+    "noteHalfUp",
+    "noteLaBlack",
+    "noteLaHalf",
+    "noteLaWhole",
+    "noteLeBlack",
+    "noteLeHalf",
+    "noteLeWhole",
+    "noteLiBlack",
+    "noteLiHalf",
+    "noteLiWhole",
+    "noteMeBlack",
+    "noteMeHalf",
+    "noteMeWhole",
+    "noteMiBlack",
+    "noteMiHalf",
+    "noteMiWhole",
+    "noteQuarterDown",
+    "noteQuarterUp",
+    "noteRaBlack",
+    "noteRaHalf",
+    "noteRaWhole",
+    "noteReBlack",
+    "noteReHalf",
+    "noteReWhole",
+    "noteRiBlack",
+    "noteRiHalf",
+    "noteRiWhole",
+    "noteSeBlack",
+    "noteSeHalf",
+    "noteSeWhole",
+    "noteShapeArrowheadLeftBlack",
+    "noteShapeArrowheadLeftDoubleWhole",
+    "noteShapeArrowheadLeftWhite",
+    "noteShapeDiamondBlack",
+    "noteShapeDiamondDoubleWhole",
+    "noteShapeDiamondWhite",
+    "noteShapeIsoscelesTriangleBlack",
+    "noteShapeIsoscelesTriangleDoubleWhole",
+    "noteShapeIsoscelesTriangleWhite",
+    "noteShapeKeystoneBlack",
+    "noteShapeKeystoneDoubleWhole",
+    "noteShapeKeystoneWhite",
+    "noteShapeMoonBlack",
+    "noteShapeMoonDoubleWhole",
+    "noteShapeMoonLeftBlack",
+    "noteShapeMoonLeftDoubleWhole",
+    "noteShapeMoonLeftWhite",
+    "noteShapeMoonWhite",
+    "noteShapeQuarterMoonBlack",
+    "noteShapeQuarterMoonDoubleWhole",
+    "noteShapeQuarterMoonWhite",
+    "noteShapeRoundBlack",
+    "noteShapeRoundDoubleWhole",
+    "noteShapeRoundWhite",
+    "noteShapeSquareBlack",
+    "noteShapeSquareDoubleWhole",
+    "noteShapeSquareWhite",
+    "noteShapeTriangleLeftBlack",
+    "noteShapeTriangleLeftDoubleWhole",
+    "noteShapeTriangleLeftWhite",
+    "noteShapeTriangleRightBlack",
+    "noteShapeTriangleRightDoubleWhole",
+    "noteShapeTriangleRightWhite",
+    "noteShapeTriangleRoundBlack",
+    "noteShapeTriangleRoundDoubleWhole",
+    "noteShapeTriangleRoundLeftBlack",
+    "noteShapeTriangleRoundLeftDoubleWhole",
+    "noteShapeTriangleRoundLeftWhite",
+    "noteShapeTriangleRoundWhite",
+    "noteShapeTriangleUpBlack",
+    "noteShapeTriangleUpDoubleWhole",
+    "noteShapeTriangleUpWhite",
+    "noteSiBlack",
+    "noteSiHalf",
+    "noteSiWhole",
+    "noteSoBlack",
+    "noteSoHalf",
+    "noteSoWhole",
+    "noteTeBlack",
+    "noteTeHalf",
+    "noteTeWhole",
+    "noteTiBlack",
+    "noteTiHalf",
+    "noteTiWhole",
+    "noteWhole",
+    "noteheadBlack",
+    "noteheadCircleSlash",
+    "noteheadCircleX",
+    "noteheadCircleXDoubleWhole",
+    "noteheadCircleXHalf",
+    "noteheadCircleXWhole",
+    "noteheadCircledBlack",
+    "noteheadCircledBlackLarge",
+    "noteheadCircledDoubleWhole",
+    "noteheadCircledDoubleWholeLarge",
+    "noteheadCircledHalf",
+    "noteheadCircledHalfLarge",
+    "noteheadCircledWhole",
+    "noteheadCircledWholeLarge",
+    "noteheadCircledXLarge",
+    "noteheadClusterDoubleWhole2nd",
+    "noteheadClusterDoubleWhole3rd",
+    "noteheadClusterDoubleWholeBottom",
+    "noteheadClusterDoubleWholeMiddle",
+    "noteheadClusterDoubleWholeTop",
+    "noteheadClusterHalf2nd",
+    "noteheadClusterHalf3rd",
+    "noteheadClusterHalfBottom",
+    "noteheadClusterHalfMiddle",
+    "noteheadClusterHalfTop",
+    "noteheadClusterQuarter2nd",
+    "noteheadClusterQuarter3rd",
+    "noteheadClusterQuarterBottom",
+    "noteheadClusterQuarterMiddle",
+    "noteheadClusterQuarterTop",
+    "noteheadClusterRoundBlack",
+    "noteheadClusterRoundWhite",
+    "noteheadClusterSquareBlack",
+    "noteheadClusterSquareWhite",
+    "noteheadClusterWhole2nd",
+    "noteheadClusterWhole3rd",
+    "noteheadClusterWholeBottom",
+    "noteheadClusterWholeMiddle",
+    "noteheadClusterWholeTop",
+    "noteheadCowellEleventhNoteSeriesHalf",
+    "noteheadCowellEleventhNoteSeriesWhole",
+    "noteheadCowellEleventhSeriesBlack",
+    "noteheadCowellFifteenthNoteSeriesBlack",
+    "noteheadCowellFifteenthNoteSeriesHalf",
+    "noteheadCowellFifteenthNoteSeriesWhole",
+    "noteheadCowellFifthNoteSeriesBlack",
+    "noteheadCowellFifthNoteSeriesHalf",
+    "noteheadCowellFifthNoteSeriesWhole",
+    "noteheadCowellNinthNoteSeriesBlack",
+    "noteheadCowellNinthNoteSeriesHalf",
+    "noteheadCowellNinthNoteSeriesWhole",
+    "noteheadCowellSeventhNoteSeriesBlack",
+    "noteheadCowellSeventhNoteSeriesHalf",
+    "noteheadCowellSeventhNoteSeriesWhole",
+    "noteheadCowellThirdNoteSeriesBlack",
+    "noteheadCowellThirdNoteSeriesHalf",
+    "noteheadCowellThirdNoteSeriesWhole",
+    "noteheadCowellThirteenthNoteSeriesBlack",
+    "noteheadCowellThirteenthNoteSeriesHalf",
+    "noteheadCowellThirteenthNoteSeriesWhole",
+    "noteheadDiamondBlack",
+    "noteheadDiamondBlackOld",
+    "noteheadDiamondBlackWide",
+    "noteheadDiamondClusterBlack2nd",
+    "noteheadDiamondClusterBlack3rd",
+    "noteheadDiamondClusterBlackBottom",
+    "noteheadDiamondClusterBlackMiddle",
+    "noteheadDiamondClusterBlackTop",
+    "noteheadDiamondClusterWhite2nd",
+    "noteheadDiamondClusterWhite3rd",
+    "noteheadDiamondClusterWhiteBottom",
+    "noteheadDiamondClusterWhiteMiddle",
+    "noteheadDiamondClusterWhiteTop",
+    "noteheadDiamondDoubleWhole",
+    "noteheadDiamondDoubleWholeOld",
+    "noteheadDiamondHalf",
+    "noteheadDiamondHalfFilled",
+    "noteheadDiamondHalfOld",
+    "noteheadDiamondHalfWide",
+    "noteheadDiamondOpen",
+    "noteheadDiamondWhite",
+    "noteheadDiamondWhiteWide",
+    "noteheadDiamondWhole",
+    "noteheadDiamondWholeOld",
+    "noteheadDoubleWhole",
+    "noteheadDoubleWholeSquare",
+    "noteheadDoubleWholeWithX",
+    "noteheadHalf",
+    "noteheadHalfFilled",
+    "noteheadHalfWithX",
+    "noteheadHeavyX",
+    "noteheadHeavyXHat",
+    "noteheadLargeArrowDownBlack",
+    "noteheadLargeArrowDownDoubleWhole",
+    "noteheadLargeArrowDownHalf",
+    "noteheadLargeArrowDownWhole",
+    "noteheadLargeArrowUpBlack",
+    "noteheadLargeArrowUpDoubleWhole",
+    "noteheadLargeArrowUpHalf",
+    "noteheadLargeArrowUpWhole",
+    "noteheadMoonBlack",
+    "noteheadMoonWhite",
+    "noteheadNancarrowSine",
+    "noteheadNull",
+    "noteheadParenthesis",
+    "noteheadParenthesisLeft",
+    "noteheadParenthesisRight",
+    "noteheadPlusBlack",
+    "noteheadPlusDoubleWhole",
+    "noteheadPlusHalf",
+    "noteheadPlusWhole",
+    "noteheadRectangularClusterBlackBottom",
+    "noteheadRectangularClusterBlackMiddle",
+    "noteheadRectangularClusterBlackTop",
+    "noteheadRectangularClusterWhiteBottom",
+    "noteheadRectangularClusterWhiteMiddle",
+    "noteheadRectangularClusterWhiteTop",
+    "noteheadRoundBlack",
+    "noteheadRoundBlackDoubleSlashed",
+    "noteheadRoundBlackLarge",
+    "noteheadRoundBlackSlashed",
+    "noteheadRoundBlackSlashedLarge",
+    "noteheadRoundWhite",
+    "noteheadRoundWhiteDoubleSlashed",
+    "noteheadRoundWhiteLarge",
+    "noteheadRoundWhiteSlashed",
+    "noteheadRoundWhiteSlashedLarge",
+    "noteheadRoundWhiteWithDot",
+    "noteheadRoundWhiteWithDotLarge",
+    "noteheadSlashDiamondWhite",
+    "noteheadSlashHorizontalEnds",
+    "noteheadSlashHorizontalEndsMuted",
+    "noteheadSlashVerticalEnds",
+    "noteheadSlashVerticalEndsMuted",
+    "noteheadSlashVerticalEndsSmall",
+    "noteheadSlashWhiteDoubleWhole",
+    "noteheadSlashWhiteHalf",
+    "noteheadSlashWhiteMuted",
+    "noteheadSlashWhiteWhole",
+    "noteheadSlashX",
+    "noteheadSlashedBlack1",
+    "noteheadSlashedBlack2",
+    "noteheadSlashedDoubleWhole1",
+    "noteheadSlashedDoubleWhole2",
+    "noteheadSlashedHalf1",
+    "noteheadSlashedHalf2",
+    "noteheadSlashedWhole1",
+    "noteheadSlashedWhole2",
+    "noteheadSquareBlack",
+    "noteheadSquareBlackLarge",
+    "noteheadSquareBlackWhite",
+    "noteheadSquareWhite",
+    "noteheadTriangleDownBlack",
+    "noteheadTriangleDownDoubleWhole",
+    "noteheadTriangleDownHalf",
+    "noteheadTriangleDownWhite",
+    "noteheadTriangleDownWhole",
+    "noteheadTriangleLeftBlack",
+    "noteheadTriangleLeftWhite",
+    "noteheadTriangleRightBlack",
+    "noteheadTriangleRightWhite",
+    "noteheadTriangleRoundDownBlack",
+    "noteheadTriangleRoundDownWhite",
+    "noteheadTriangleUpBlack",
+    "noteheadTriangleUpDoubleWhole",
+    "noteheadTriangleUpHalf",
+    "noteheadTriangleUpRightBlack",
+    "noteheadTriangleUpRightWhite",
+    "noteheadTriangleUpWhite",
+    "noteheadTriangleUpWhole",
+    "noteheadVoidWithX",
+    "noteheadWhole",
+    "noteheadWholeFilled",
+    "noteheadWholeWithX",
+    "noteheadXBlack",
+    "noteheadXDoubleWhole",
+    "noteheadXHalf",
+    "noteheadXOrnate",
+    "noteheadXOrnateEllipse",
+    "noteheadXWhole",
+    "octaveBaselineA",
+    "octaveBaselineB",
+    "octaveBaselineM",
+    "octaveBaselineV",
+    "octaveBassa",
+    "octaveLoco",
+    "octaveParensLeft",
+    "octaveParensRight",
+    "octaveSuperscriptA",
+    "octaveSuperscriptB",
+    "octaveSuperscriptM",
+    "octaveSuperscriptV",
+    "oneHandedRollStevens",
+    "organGerman2Fusae",
+    "organGerman2Minimae",
+    "organGerman2OctaveUp",
+    "organGerman2Semifusae",
+    "organGerman2Semiminimae",
+    "organGerman3Fusae",
+    "organGerman3Minimae",
+    "organGerman3Semifusae",
+    "organGerman3Semiminimae",
+    "organGerman4Fusae",
+    "organGerman4Minimae",
+    "organGerman4Semifusae",
+    "organGerman4Semiminimae",
+    "organGerman5Fusae",
+    "organGerman5Minimae",
+    "organGerman5Semifusae",
+    "organGerman5Semiminimae",
+    "organGerman6Fusae",
+    "organGerman6Minimae",
+    "organGerman6Semifusae",
+    "organGerman6Semiminimae",
+    "organGermanALower",
+    "organGermanAUpper",
+    "organGermanAugmentationDot",
+    "organGermanBLower",
+    "organGermanBUpper",
+    "organGermanBuxheimerBrevis2",
+    "organGermanBuxheimerBrevis3",
+    "organGermanBuxheimerMinimaRest",
+    "organGermanBuxheimerSemibrevis",
+    "organGermanBuxheimerSemibrevisRest",
+    "organGermanCLower",
+    "organGermanCUpper",
+    "organGermanCisLower",
+    "organGermanCisUpper",
+    "organGermanDLower",
+    "organGermanDUpper",
+    "organGermanDisLower",
+    "organGermanDisUpper",
+    "organGermanELower",
+    "organGermanEUpper",
+    "organGermanFLower",
+    "organGermanFUpper",
+    "organGermanFisLower",
+    "organGermanFisUpper",
+    "organGermanFusa",
+    "organGermanFusaRest",
+    "organGermanGLower",
+    "organGermanGUpper",
+    "organGermanGisLower",
+    "organGermanGisUpper",
+    "organGermanHLower",
+    "organGermanHUpper",
+    "organGermanMinima",
+    "organGermanMinimaRest",
+    "organGermanOctaveDown",
+    "organGermanOctaveUp",
+    "organGermanSemibrevis",
+    "organGermanSemibrevisRest",
+    "organGermanSemifusa",
+    "organGermanSemifusaRest",
+    "organGermanSemiminima",
+    "organGermanSemiminimaRest",
+    "organGermanTie",
+    "ornamentBottomLeftConcaveStroke",
+    "ornamentBottomLeftConcaveStrokeLarge",
+    "ornamentBottomLeftConvexStroke",
+    "ornamentBottomRightConcaveStroke",
+    "ornamentBottomRightConvexStroke",
+    "ornamentComma",
+    "ornamentDoubleObliqueLinesAfterNote",
+    "ornamentDoubleObliqueLinesBeforeNote",
+    "ornamentDownCurve",
+    "ornamentHaydn",
+    "ornamentHighLeftConcaveStroke",
+    "ornamentHighLeftConvexStroke",
+    "ornamentHighRightConcaveStroke",
+    "ornamentHighRightConvexStroke",
+    "ornamentHookAfterNote",
+    "ornamentHookBeforeNote",
+    "ornamentLeftFacingHalfCircle",
+    "ornamentLeftFacingHook",
+    "ornamentLeftPlus",
+    "ornamentLeftShakeT",
+    "ornamentLeftVerticalStroke",
+    "ornamentLeftVerticalStrokeWithCross",
+    "ornamentLowLeftConcaveStroke",
+    "ornamentLowLeftConvexStroke",
+    "ornamentLowRightConcaveStroke",
+    "ornamentLowRightConvexStroke",
+    "ornamentMiddleVerticalStroke",
+    "ornamentMordent",
+    "ornamentObliqueLineAfterNote",
+    "ornamentObliqueLineBeforeNote",
+    "ornamentObliqueLineHorizAfterNote",
+    "ornamentObliqueLineHorizBeforeNote",
+    "ornamentOriscus",
+    "ornamentPinceCouperin",
+    "ornamentPortDeVoixV",
+    "ornamentPrecompAppoggTrill",
+    "ornamentPrecompAppoggTrillSuffix",
+    "ornamentPrecompCadence",
+    "ornamentPrecompCadenceUpperPrefix",
+    "ornamentPrecompCadenceUpperPrefixTurn",
+    "ornamentPrecompCadenceWithTurn",
+    "ornamentPrecompDescendingSlide",
+    "ornamentPrecompDoubleCadenceLowerPrefix",
+    "ornamentPrecompDoubleCadenceUpperPrefix",
+    "ornamentPrecompDoubleCadenceUpperPrefixTurn",
+    "ornamentPrecompInvertedMordentUpperPrefix",
+    "ornamentPrecompMordentRelease",
+    "ornamentPrecompMordentUpperPrefix",
+    "ornamentPrecompPortDeVoixMordent",
+    "ornamentPrecompSlide",
+    "ornamentPrecompSlideTrillBach",
+    "ornamentPrecompSlideTrillDAnglebert",
+    "ornamentPrecompSlideTrillMarpurg",
+    "ornamentPrecompSlideTrillMuffat",
+    "ornamentPrecompSlideTrillSuffixMuffat",
+    "ornamentPrecompTrillLowerSuffix",
+    "ornamentPrecompTrillSuffixDandrieu",
+    "ornamentPrecompTrillWithMordent",
+    "ornamentPrecompTurnTrillBach",
+    "ornamentPrecompTurnTrillDAnglebert",
+    "ornamentQuilisma",
+    "ornamentRightFacingHalfCircle",
+    "ornamentRightFacingHook",
+    "ornamentRightVerticalStroke",
+    "ornamentSchleifer",
+    "ornamentShake3",
+    "ornamentShakeMuffat1",
+    "ornamentShortObliqueLineAfterNote",
+    "ornamentShortObliqueLineBeforeNote",
+    "ornamentShortTrill",
+    "ornamentTopLeftConcaveStroke",
+    "ornamentTopLeftConvexStroke",
+    "ornamentTopRightConcaveStroke",
+    "ornamentTopRightConvexStroke",
+    "ornamentTremblement",
+    "ornamentTremblementCouperin",
+    "ornamentTrill",
+    "ornamentTurn",
+    "ornamentTurnInverted",
+    "ornamentTurnSlash",
+    "ornamentTurnUp",
+    "ornamentTurnUpS",
+    "ornamentUpCurve",
+    "ornamentVerticalLine",
+    "ornamentZigZagLineNoRightEnd",
+    "ornamentZigZagLineWithRightEnd",
+    "ottava",
+    "ottavaAlta",
+    "ottavaBassa",
+    "ottavaBassaBa",
+    "ottavaBassaVb",
+    "pendereckiTremolo",
+    "pictAgogo",
+    "pictAlmglocken",
+    "pictAnvil",
+    "pictBambooChimes",
+    "pictBambooScraper",
+    "pictBassDrum",
+    "pictBassDrumOnSide",
+    "pictBeaterBow",
+    "pictBeaterBox",
+    "pictBeaterBrassMalletsDown",
+    "pictBeaterBrassMalletsLeft",
+    "pictBeaterBrassMalletsRight",
+    "pictBeaterBrassMalletsUp",
+    "pictBeaterCombiningDashedCircle",
+    "pictBeaterCombiningParentheses",
+    "pictBeaterDoubleBassDrumDown",
+    "pictBeaterDoubleBassDrumUp",
+    "pictBeaterFinger",
+    "pictBeaterFingernails",
+    "pictBeaterFist",
+    "pictBeaterGuiroScraper",
+    "pictBeaterHammer",
+    "pictBeaterHammerMetalDown",
+    "pictBeaterHammerMetalUp",
+    "pictBeaterHammerPlasticDown",
+    "pictBeaterHammerPlasticUp",
+    "pictBeaterHammerWoodDown",
+    "pictBeaterHammerWoodUp",
+    "pictBeaterHand",
+    "pictBeaterHardBassDrumDown",
+    "pictBeaterHardBassDrumUp",
+    "pictBeaterHardGlockenspielDown",
+    "pictBeaterHardGlockenspielLeft",
+    "pictBeaterHardGlockenspielRight",
+    "pictBeaterHardGlockenspielUp",
+    "pictBeaterHardTimpaniDown",
+    "pictBeaterHardTimpaniLeft",
+    "pictBeaterHardTimpaniRight",
+    "pictBeaterHardTimpaniUp",
+    "pictBeaterHardXylophoneDown",
+    "pictBeaterHardXylophoneLeft",
+    "pictBeaterHardXylophoneRight",
+    "pictBeaterHardXylophoneUp",
+    "pictBeaterHardYarnDown",
+    "pictBeaterHardYarnLeft",
+    "pictBeaterHardYarnRight",
+    "pictBeaterHardYarnUp",
+    "pictBeaterJazzSticksDown",
+    "pictBeaterJazzSticksUp",
+    "pictBeaterKnittingNeedle",
+    "pictBeaterMallet",
+    "pictBeaterMalletDown",
+    "pictBeaterMediumBassDrumDown",
+    "pictBeaterMediumBassDrumUp",
+    "pictBeaterMediumTimpaniDown",
+    "pictBeaterMediumTimpaniLeft",
+    "pictBeaterMediumTimpaniRight",
+    "pictBeaterMediumTimpaniUp",
+    "pictBeaterMediumXylophoneDown",
+    "pictBeaterMediumXylophoneLeft",
+    "pictBeaterMediumXylophoneRight",
+    "pictBeaterMediumXylophoneUp",
+    "pictBeaterMediumYarnDown",
+    "pictBeaterMediumYarnLeft",
+    "pictBeaterMediumYarnRight",
+    "pictBeaterMediumYarnUp",
+    "pictBeaterMetalBassDrumDown",
+    "pictBeaterMetalBassDrumUp",
+    "pictBeaterMetalDown",
+    "pictBeaterMetalHammer",
+    "pictBeaterMetalLeft",
+    "pictBeaterMetalRight",
+    "pictBeaterMetalUp",
+    "pictBeaterSnareSticksDown",
+    "pictBeaterSnareSticksUp",
+    "pictBeaterSoftBassDrumDown",
+    "pictBeaterSoftBassDrumUp",
+    "pictBeaterSoftGlockenspielDown",
+    "pictBeaterSoftGlockenspielLeft",
+    "pictBeaterSoftGlockenspielRight",
+    "pictBeaterSoftGlockenspielUp",
+    "pictBeaterSoftTimpaniDown",
+    "pictBeaterSoftTimpaniLeft",
+    "pictBeaterSoftTimpaniRight",
+    "pictBeaterSoftTimpaniUp",
+    "pictBeaterSoftXylophone",
+    "pictBeaterSoftXylophoneDown",
+    "pictBeaterSoftXylophoneLeft",
+    "pictBeaterSoftXylophoneRight",
+    "pictBeaterSoftXylophoneUp",
+    "pictBeaterSoftYarnDown",
+    "pictBeaterSoftYarnLeft",
+    "pictBeaterSoftYarnRight",
+    "pictBeaterSoftYarnUp",
+    "pictBeaterSpoonWoodenMallet",
+    "pictBeaterSuperballDown",
+    "pictBeaterSuperballLeft",
+    "pictBeaterSuperballRight",
+    "pictBeaterSuperballUp",
+    "pictBeaterTriangleDown",
+    "pictBeaterTrianglePlain",
+    "pictBeaterTriangleUp",
+    "pictBeaterWireBrushesDown",
+    "pictBeaterWireBrushesUp",
+    "pictBeaterWoodTimpaniDown",
+    "pictBeaterWoodTimpaniLeft",
+    "pictBeaterWoodTimpaniRight",
+    "pictBeaterWoodTimpaniUp",
+    "pictBeaterWoodXylophoneDown",
+    "pictBeaterWoodXylophoneLeft",
+    "pictBeaterWoodXylophoneRight",
+    "pictBeaterWoodXylophoneUp",
+    "pictBell",
+    "pictBellOfCymbal",
+    "pictBellPlate",
+    "pictBellTree",
+    "pictBirdWhistle",
+    "pictBoardClapper",
+    "pictBongos",
+    "pictBrakeDrum",
+    "pictCabasa",
+    "pictCannon",
+    "pictCarHorn",
+    "pictCastanets",
+    "pictCastanetsWithHandle",
+    "pictCelesta",
+    "pictCencerro",
+    "pictCenter1",
+    "pictCenter2",
+    "pictCenter3",
+    "pictChainRattle",
+    "pictChimes",
+    "pictChineseCymbal",
+    "pictChokeCymbal",
+    "pictClaves",
+    "pictCoins",
+    "pictConga",
+    "pictCowBell",
+    "pictCrashCymbals",
+    "pictCrotales",
+    "pictCrushStem",
+    "pictCuica",
+    "pictCymbalTongs",
+    "pictDamp1",
+    "pictDamp2",
+    "pictDamp3",
+    "pictDamp4",
+    "pictDeadNoteStem",
+    "pictDrumStick",
+    "pictDuckCall",
+    "pictEdgeOfCymbal",
+    "pictEmptyTrap",
+    "pictFingerCymbals",
+    "pictFlexatone",
+    "pictFootballRatchet",
+    "pictGlassHarmonica",
+    "pictGlassHarp",
+    "pictGlassPlateChimes",
+    "pictGlassTubeChimes",
+    "pictGlsp",
+    "pictGlspSmithBrindle",
+    "pictGobletDrum",
+    "pictGong",
+    "pictGongWithButton",
+    "pictGuiro",
+    "pictGumHardDown",
+    "pictGumHardLeft",
+    "pictGumHardRight",
+    "pictGumHardUp",
+    "pictGumMediumDown",
+    "pictGumMediumLeft",
+    "pictGumMediumRight",
+    "pictGumMediumUp",
+    "pictGumSoftDown",
+    "pictGumSoftLeft",
+    "pictGumSoftRight",
+    "pictGumSoftUp",
+    "pictHalfOpen1",
+    "pictHalfOpen2",
+    "pictHandbell",
+    "pictHiHat",
+    "pictHiHatOnStand",
+    "pictJawHarp",
+    "pictJingleBells",
+    "pictKlaxonHorn",
+    "pictLeftHandCircle",
+    "pictLionsRoar",
+    "pictLithophone",
+    "pictLogDrum",
+    "pictLotusFlute",
+    "pictMar",
+    "pictMarSmithBrindle",
+    "pictMaraca",
+    "pictMaracas",
+    "pictMegaphone",
+    "pictMetalPlateChimes",
+    "pictMetalTubeChimes",
+    "pictMusicalSaw",
+    "pictNormalPosition",
+    "pictOnRim",
+    "pictOpen",
+    "pictOpenRimShot",
+    "pictPistolShot",
+    "pictPoliceWhistle",
+    "pictQuijada",
+    "pictRainstick",
+    "pictRatchet",
+    "pictRecoReco",
+    "pictRightHandSquare",
+    "pictRim1",
+    "pictRim2",
+    "pictRim3",
+    "pictRimShotOnStem",
+    "pictSandpaperBlocks",
+    "pictScrapeAroundRim",
+    "pictScrapeAroundRimClockwise",
+    "pictScrapeCenterToEdge",
+    "pictScrapeEdgeToCenter",
+    "pictShellBells",
+    "pictShellChimes",
+    "pictSiren",
+    "pictSistrum",
+    "pictSizzleCymbal",
+    "pictSleighBell",
+    "pictSlideBrushOnGong",
+    "pictSlideWhistle",
+    "pictSlitDrum",
+    "pictSnareDrum",
+    "pictSnareDrumMilitary",
+    "pictSnareDrumSnaresOff",
+    "pictSteelDrums",
+    "pictStickShot",
+    "pictSuperball",
+    "pictSuspendedCymbal",
+    "pictSwishStem",
+    "pictTabla",
+    "pictTamTam",
+    "pictTamTamWithBeater",
+    "pictTambourine",
+    "pictTempleBlocks",
+    "pictTenorDrum",
+    "pictThundersheet",
+    "pictTimbales",
+    "pictTimpani",
+    "pictTomTom",
+    "pictTomTomChinese",
+    "pictTomTomIndoAmerican",
+    "pictTomTomJapanese",
+    "pictTriangle",
+    "pictTubaphone",
+    "pictTubularBells",
+    "pictTurnLeftStem",
+    "pictTurnRightLeftStem",
+    "pictTurnRightStem",
+    "pictVib",
+    "pictVibMotorOff",
+    "pictVibSmithBrindle",
+    "pictVibraslap",
+    "pictVietnameseHat",
+    "pictWhip",
+    "pictWindChimesGlass",
+    "pictWindMachine",
+    "pictWindWhistle",
+    "pictWoodBlock",
+    "pictWoundHardDown",
+    "pictWoundHardLeft",
+    "pictWoundHardRight",
+    "pictWoundHardUp",
+    "pictWoundSoftDown",
+    "pictWoundSoftLeft",
+    "pictWoundSoftRight",
+    "pictWoundSoftUp",
+    "pictXyl",
+    "pictXylBass",
+    "pictXylSmithBrindle",
+    "pictXylTenor",
+    "pictXylTenorTrough",
+    "pictXylTrough",
+    "pluckedBuzzPizzicato",
+    "pluckedDamp",
+    "pluckedDampAll",
+    "pluckedDampOnStem",
+    "pluckedFingernailFlick",
+    "pluckedLeftHandPizzicato",
+    "pluckedPlectrum",
+    "pluckedSnapPizzicatoAbove",
+    "pluckedSnapPizzicatoBelow",
+    "pluckedWithFingernails",
+    "quindicesima",
+    "quindicesimaAlta",
+    "quindicesimaBassa",
+    "quindicesimaBassaMb",
+    "repeat1Bar",
+    "repeat2Bars",
+    "repeat4Bars",
+    "repeatBarLowerDot",
+    "repeatBarSlash",
+    "repeatBarUpperDot",
+    "repeatDot",
+    "repeatDots",
+    "repeatLeft",
+    "repeatRight",
+    "repeatRightLeft",
+    "rest1024th",
+    "rest128th",
+    "rest16th",
+    "rest256th",
+    "rest32nd",
+    "rest512th",
+    "rest64th",
+    "rest8th",
+    "restDoubleWhole",
+    "restDoubleWholeLegerLine",
+    "restHBar",
+    "restHBarLeft",
+    "restHBarMiddle",
+    "restHBarRight",
+    "restHalf",
+    "restHalfLegerLine",
+    "restLonga",
+    "restMaxima",
+    "restQuarter",
+    "restQuarterOld",
+    "restQuarterZ",
+    "restWhole",
+    "restWholeLegerLine",
+    "reversedBrace",
+    "reversedBracketBottom",
+    "reversedBracketTop",
+    "rightRepeatSmall",
+    "scaleDegree1",
+    "scaleDegree2",
+    "scaleDegree3",
+    "scaleDegree4",
+    "scaleDegree5",
+    "scaleDegree6",
+    "scaleDegree7",
+    "scaleDegree8",
+    "scaleDegree9",
+    "schaefferClef",
+    "schaefferFClefToGClef",
+    "schaefferGClefToFClef",
+    "schaefferPreviousClef",
+    "segno",
+    "segnoSerpent1",
+    "segnoSerpent2",
+    "semipitchedPercussionClef1",
+    "semipitchedPercussionClef2",
+    "smnFlat",
+    "smnFlatWhite",
+    "smnHistoryDoubleFlat",
+    "smnHistoryDoubleSharp",
+    "smnHistoryFlat",
+    "smnHistorySharp",
+    "smnNatural",
+    "smnSharp",
+    "smnSharpDown",
+    "smnSharpWhite",
+    "smnSharpWhiteDown",
+    "splitBarDivider",
+    "staff1Line",
+    "staff1LineNarrow",
+    "staff1LineWide",
+    "staff2Lines",
+    "staff2LinesNarrow",
+    "staff2LinesWide",
+    "staff3Lines",
+    "staff3LinesNarrow",
+    "staff3LinesWide",
+    "staff4Lines",
+    "staff4LinesNarrow",
+    "staff4LinesWide",
+    "staff5Lines",
+    "staff5LinesNarrow",
+    "staff5LinesWide",
+    "staff6Lines",
+    "staff6LinesNarrow",
+    "staff6LinesWide",
+    "staffDivideArrowDown",
+    "staffDivideArrowUp",
+    "staffDivideArrowUpDown",
+    "staffPosLower1",
+    "staffPosLower2",
+    "staffPosLower3",
+    "staffPosLower4",
+    "staffPosLower5",
+    "staffPosLower6",
+    "staffPosLower7",
+    "staffPosLower8",
+    "staffPosRaise1",
+    "staffPosRaise2",
+    "staffPosRaise3",
+    "staffPosRaise4",
+    "staffPosRaise5",
+    "staffPosRaise6",
+    "staffPosRaise7",
+    "staffPosRaise8",
+    "stem",
+    "stemBowOnBridge",
+    "stemBowOnTailpiece",
+    "stemBuzzRoll",
+    "stemDamp",
+    "stemHarpStringNoise",
+    "stemMultiphonicsBlack",
+    "stemMultiphonicsBlackWhite",
+    "stemMultiphonicsWhite",
+    "stemPendereckiTremolo",
+    "stemRimShot",
+    "stemSprechgesang",
+    "stemSulPonticello",
+    "stemSussurando",
+    "stemSwished",
+    "stemVibratoPulse",
+    "stockhausenTremolo",
+    "stringsBowBehindBridge",
+    "stringsBowBehindBridgeFourStrings",
+    "stringsBowBehindBridgeOneString",
+    "stringsBowBehindBridgeThreeStrings",
+    "stringsBowBehindBridgeTwoStrings",
+    "stringsBowOnBridge",
+    "stringsBowOnTailpiece",
+    "stringsChangeBowDirection",
+    "stringsDownBow",
+    "stringsDownBowAwayFromBody",
+    "stringsDownBowBeyondBridge",
+    "stringsDownBowTowardsBody",
+    "stringsDownBowTurned",
+    "stringsFouette",
+    "stringsHalfHarmonic",
+    "stringsHarmonic",
+    "stringsJeteAbove",
+    "stringsJeteBelow",
+    "stringsMuteOff",
+    "stringsMuteOn",
+    "stringsOverpressureDownBow",
+    "stringsOverpressureNoDirection",
+    "stringsOverpressurePossibileDownBow",
+    "stringsOverpressurePossibileUpBow",
+    "stringsOverpressureUpBow",
+    "stringsScrapeCircularClockwise",
+    "stringsScrapeCircularCounterclockwise",
+    "stringsScrapeParallelInward",
+    "stringsScrapeParallelOutward",
+    "stringsThumbPosition",
+    "stringsThumbPositionTurned",
+    "stringsTripleChopInward",
+    "stringsTripleChopOutward",
+    "stringsUpBow",
+    "stringsUpBowAwayFromBody",
+    "stringsUpBowBeyondBridge",
+    "stringsUpBowTowardsBody",
+    "stringsUpBowTurned",
+    "stringsVibratoPulse",
+    "swissRudimentsNoteheadBlackDouble",
+    "swissRudimentsNoteheadBlackFlam",
+    "swissRudimentsNoteheadHalfDouble",
+    "swissRudimentsNoteheadHalfFlam",
+    "systemDivider",
+    "systemDividerExtraLong",
+    "systemDividerLong",
+    "textAugmentationDot",
+    "textBlackNoteFrac16thLongStem",
+    "textBlackNoteFrac16thShortStem",
+    "textBlackNoteFrac32ndLongStem",
+    "textBlackNoteFrac8thLongStem",
+    "textBlackNoteFrac8thShortStem",
+    "textBlackNoteLongStem",
+    "textBlackNoteShortStem",
+    "textCont16thBeamLongStem",
+    "textCont16thBeamShortStem",
+    "textCont32ndBeamLongStem",
+    "textCont8thBeamLongStem",
+    "textCont8thBeamShortStem",
+    "textHeadlessBlackNoteFrac16thLongStem",
+    "textHeadlessBlackNoteFrac16thShortStem",
+    "textHeadlessBlackNoteFrac32ndLongStem",
+    "textHeadlessBlackNoteFrac8thLongStem",
+    "textHeadlessBlackNoteFrac8thShortStem",
+    "textHeadlessBlackNoteLongStem",
+    "textHeadlessBlackNoteShortStem",
+    "textTie",
+    "textTuplet3LongStem",
+    "textTuplet3ShortStem",
+    "textTupletBracketEndLongStem",
+    "textTupletBracketEndShortStem",
+    "textTupletBracketStartLongStem",
+    "textTupletBracketStartShortStem",
+    "timeSig0",
+    "timeSig0Reversed",
+    "timeSig0Turned",
+    "timeSig1",
+    "timeSig1Reversed",
+    "timeSig1Turned",
+    "timeSig2",
+    "timeSig2Reversed",
+    "timeSig2Turned",
+    "timeSig3",
+    "timeSig3Reversed",
+    "timeSig3Turned",
+    "timeSig4",
+    "timeSig4Reversed",
+    "timeSig4Turned",
+    "timeSig5",
+    "timeSig5Reversed",
+    "timeSig5Turned",
+    "timeSig6",
+    "timeSig6Reversed",
+    "timeSig6Turned",
+    "timeSig7",
+    "timeSig7Reversed",
+    "timeSig7Turned",
+    "timeSig8",
+    "timeSig8Reversed",
+    "timeSig8Turned",
+    "timeSig9",
+    "timeSig9Reversed",
+    "timeSig9Turned",
+    "timeSigBracketLeft",
+    "timeSigBracketLeftSmall",
+    "timeSigBracketRight",
+    "timeSigBracketRightSmall",
+    "timeSigCombDenominator",
+    "timeSigCombNumerator",
+    "timeSigComma",
+    "timeSigCommon",
+    "timeSigCommonReversed",
+    "timeSigCommonTurned",
+    "timeSigCut2",
+    "timeSigCut3",
+    "timeSigCutCommon",
+    "timeSigCutCommonReversed",
+    "timeSigCutCommonTurned",
+    "timeSigEquals",
+    "timeSigFractionHalf",
+    "timeSigFractionOneThird",
+    "timeSigFractionQuarter",
+    "timeSigFractionThreeQuarters",
+    "timeSigFractionTwoThirds",
+    "timeSigFractionalSlash",
+    "timeSigMinus",
+    "timeSigMultiply",
+    "timeSigOpenPenderecki",
+    "timeSigParensLeft",
+    "timeSigParensLeftSmall",
+    "timeSigParensRight",
+    "timeSigParensRightSmall",
+    "timeSigPlus",
+    "timeSigPlusSmall",
+    "timeSigSlash",
+    "timeSigX",
+    "tremolo1",
+    "tremolo2",
+    "tremolo3",
+    "tremolo4",
+    "tremolo5",
+    "tremoloDivisiDots2",
+    "tremoloDivisiDots3",
+    "tremoloDivisiDots4",
+    "tremoloDivisiDots6",
+    "tremoloFingered1",
+    "tremoloFingered2",
+    "tremoloFingered3",
+    "tremoloFingered4",
+    "tremoloFingered5",
+    "tripleTongueAbove",
+    "tripleTongueBelow",
+    "tuplet0",
+    "tuplet1",
+    "tuplet2",
+    "tuplet3",
+    "tuplet4",
+    "tuplet5",
+    "tuplet6",
+    "tuplet7",
+    "tuplet8",
+    "tuplet9",
+    "tupletColon",
+    "unmeasuredTremolo",
+    "unmeasuredTremoloSimple",
+    "unpitchedPercussionClef1",
+    "unpitchedPercussionClef2",
+    "ventiduesima",
+    "ventiduesimaAlta",
+    "ventiduesimaBassa",
+    "ventiduesimaBassaMb",
+    "vocalFingerClickStockhausen",
+    "vocalHalbGesungen",
+    "vocalMouthClosed",
+    "vocalMouthOpen",
+    "vocalMouthPursed",
+    "vocalMouthSlightlyOpen",
+    "vocalMouthWideOpen",
+    "vocalNasalVoice",
+    "vocalSprechgesang",
+    "vocalTongueClickStockhausen",
+    "vocalTongueFingerClickStockhausen",
+    "vocalsSussurando",
+    "wiggleArpeggiatoDown",
+    "wiggleArpeggiatoDownArrow",
+    "wiggleArpeggiatoDownSwash",
+    "wiggleArpeggiatoUp",
+    "wiggleArpeggiatoUpArrow",
+    "wiggleArpeggiatoUpSwash",
+    "wiggleCircular",
+    "wiggleCircularConstant",
+    "wiggleCircularConstantFlipped",
+    "wiggleCircularConstantFlippedLarge",
+    "wiggleCircularConstantLarge",
+    "wiggleCircularEnd",
+    "wiggleCircularLarge",
+    "wiggleCircularLarger",
+    "wiggleCircularLargerStill",
+    "wiggleCircularLargest",
+    "wiggleCircularSmall",
+    "wiggleCircularStart",
+    "wiggleGlissando",
+    "wiggleGlissandoGroup1",
+    "wiggleGlissandoGroup2",
+    "wiggleGlissandoGroup3",
+    "wiggleRandom1",
+    "wiggleRandom2",
+    "wiggleRandom3",
+    "wiggleRandom4",
+    "wiggleSawtooth",
+    "wiggleSawtoothNarrow",
+    "wiggleSawtoothWide",
+    "wiggleSquareWave",
+    "wiggleSquareWaveNarrow",
+    "wiggleSquareWaveWide",
+    "wiggleTrill",
+    "wiggleTrillFast",
+    "wiggleTrillFaster",
+    "wiggleTrillFasterStill",
+    "wiggleTrillFastest",
+    "wiggleTrillSlow",
+    "wiggleTrillSlower",
+    "wiggleTrillSlowerStill",
+    "wiggleTrillSlowest",
+    "wiggleVIbratoLargestSlower",
+    "wiggleVIbratoMediumSlower",
+    "wiggleVibrato",
+    "wiggleVibratoLargeFast",
+    "wiggleVibratoLargeFaster",
+    "wiggleVibratoLargeFasterStill",
+    "wiggleVibratoLargeFastest",
+    "wiggleVibratoLargeSlow",
+    "wiggleVibratoLargeSlower",
+    "wiggleVibratoLargeSlowest",
+    "wiggleVibratoLargestFast",
+    "wiggleVibratoLargestFaster",
+    "wiggleVibratoLargestFasterStill",
+    "wiggleVibratoLargestFastest",
+    "wiggleVibratoLargestSlow",
+    "wiggleVibratoLargestSlowest",
+    "wiggleVibratoMediumFast",
+    "wiggleVibratoMediumFaster",
+    "wiggleVibratoMediumFasterStill",
+    "wiggleVibratoMediumFastest",
+    "wiggleVibratoMediumSlow",
+    "wiggleVibratoMediumSlowest",
+    "wiggleVibratoSmallFast",
+    "wiggleVibratoSmallFaster",
+    "wiggleVibratoSmallFasterStill",
+    "wiggleVibratoSmallFastest",
+    "wiggleVibratoSmallSlow",
+    "wiggleVibratoSmallSlower",
+    "wiggleVibratoSmallSlowest",
+    "wiggleVibratoSmallestFast",
+    "wiggleVibratoSmallestFaster",
+    "wiggleVibratoSmallestFasterStill",
+    "wiggleVibratoSmallestFastest",
+    "wiggleVibratoSmallestSlow",
+    "wiggleVibratoSmallestSlower",
+    "wiggleVibratoSmallestSlowest",
+    "wiggleVibratoStart",
+    "wiggleVibratoWide",
+    "wiggleWavy",
+    "wiggleWavyNarrow",
+    "wiggleWavyWide",
+    "windClosedHole",
+    "windFlatEmbouchure",
+    "windHalfClosedHole1",
+    "windHalfClosedHole2",
+    "windHalfClosedHole3",
+    "windLessRelaxedEmbouchure",
+    "windLessTightEmbouchure",
+    "windMouthpiecePop",
+    "windMultiphonicsBlackStem",
+    "windMultiphonicsBlackWhiteStem",
+    "windMultiphonicsWhiteStem",
+    "windOpenHole",
+    "windReedPositionIn",
+    "windReedPositionNormal",
+    "windReedPositionOut",
+    "windRelaxedEmbouchure",
+    "windRimOnly",
+    "windSharpEmbouchure",
+    "windStrongAirPressure",
+    "windThreeQuartersClosedHole",
+    "windTightEmbouchure",
+    "windTrillKey",
+    "windVeryTightEmbouchure",
+    "windWeakAirPressure",
+    "4stringTabClef",
+    "6stringTabClef",
+];
+
+/// Each [`Glyph`]'s description in `glyphnames.json`, indexed by
+/// discriminant.
+#[rustfmt::skip]
+pub static GLYPH_DESCRIPTIONS: &[&str] = &[
+    "11 large diesis down, 3° down [46 EDO]",
+    "11 large diesis up, (11L), (sharp less 11M), 3° up [46 EDO]",
+    "11 medium diesis down, 1°[17 31] 2°46 down, 1/4-tone down",
+    "11 medium diesis up, (11M), 1°[17 31] 2°46 up, 1/4-tone up",
+    "11:19 large diesis down",
+    "11:19 large diesis up, (11:19L, apotome less 11:19M)",
+    "11:19 medium diesis down",
+    "11:19 medium diesis up, (11:19M, 11M plus 19s)",
+    "11:49 comma down",
+    "11:49 comma up, (11:49C, 11M less 49C)",
+    "143 comma down",
+    "143 comma up, (143C, 13L less 11M)",
+    "17 comma down",
+    "17 comma up, (17C)",
+    "17 kleisma down",
+    "17 kleisma up, (17k)",
+    "19 comma down",
+    "19 comma up, (19C)",
+    "19 schisma down",
+    "19 schisma up, (19s)",
+    "1 mina down, 1/(5⋅7⋅13)-schismina down, 0.42 cents down",
+    "1 mina up, 1/(5⋅7⋅13)-schismina up, 0.42 cents up",
+    "1 tina down, 7²⋅11⋅19/5-schismina down, 0.17 cents down",
+    "1 tina up, 7²⋅11⋅19/5-schismina up, 0.17 cents up",
+    "23 comma down, 2° down [96 EDO], 1/8-tone down",
+    "23 comma up, (23C), 2° up [96 EDO], 1/8-tone up",
+    "23 small diesis down",
+    "23 small diesis up, (23S)",
+    "25 small diesis down, 2° down [53 EDO]",
+    "25 small diesis up, (25S, ~5:13S, ~37S, 5C\u{a0}plus\u{a0}5C), 2° up [53 EDO]",
+    "2 minas down, 65/77-schismina down, 0.83 cents down",
+    "2 minas up, 65/77-schismina up, 0.83 cents up",
+    "2 tinas down, 1/(7³⋅17)-schismina down, 0.30 cents down",
+    "2 tinas up, 1/(7³⋅17)-schismina up, 0.30 cents up",
+    "35 large diesis down, 2° down [50 EDO], 5/18-tone down",
+    "35 large diesis up, (35L, ~13L, ~125L, sharp less 35M), 2°50 up",
+    "35 medium diesis down, 1°[50] 2°[27] down, 2/9-tone down",
+    "35 medium diesis up, (35M, ~13M, ~125M, 5C\u{a0}plus\u{a0}7C), 2/9-tone up",
+    "3 tinas down, 1 mina down, 1/(5⋅7⋅13)-schismina down, 0.42 cents down",
+    "3 tinas up, 1 mina up, 1/(5⋅7⋅13)-schismina up, 0.42 cents up",
+    "49 large diesis down",
+    "49 large diesis up, (49L, ~31L, apotome less 49M)",
+    "49 medium diesis down",
+    "49 medium diesis up, (49M, ~31M, 7C plus 7C)",
+    "49 small diesis down",
+    "49 small diesis up, (49S, ~31S)",
+    "4 tinas down, 5²⋅11²/7-schismina down, 0.57 cents down",
+    "4 tinas up, 5²⋅11²/7-schismina up, 0.57 cents up",
+    "55 comma down, 3° down [96 EDO], 3/16-tone down",
+    "55 comma up, (55C, 11M less 5C), 3°up [96 EDO], 3/16-tone up",
+    "5 comma down, 1° down [22 27 29 34 41 46 53 96 EDOs], 1/12-tone down",
+    "5 comma up, (5C), 1° up [22 27 29 34 41 46 53 96 EDOs], 1/12-tone up",
+    "5:11 small diesis down",
+    "5:11 small diesis up, (5:11S, ~7:13S, ~11:17S, 5:7k plus 7:11C)",
+    "5:13 large diesis down",
+    "5:13 large diesis up, (5:13L, ~37L, apotome less 5:13M)",
+    "5:13 medium diesis down",
+    "5:13 medium diesis up, (5:13M, ~37M, 5C plus 13C)",
+    "5:19 comma down, 1/20-tone down",
+    "5:19 comma up, (5:19C, 5C plus 19s), 1/20-tone up",
+    "5:23 small diesis down, 2° down [60 EDO], 1/5-tone down",
+    "5:23 small diesis up, (5:23S, 5C plus 23C), 2° up [60 EDO], 1/5-tone up",
+    "5:49 medium diesis down",
+    "5:49 medium diesis up, (5:49M, half apotome)",
+    "5:7 kleisma down",
+    "5:7 kleisma up, (5:7k, ~11:13k, 7C\u{a0}less\u{a0}5C)",
+    "5 tinas down, 7⁴/25-schismina down, 0.72 cents down",
+    "5 tinas up, 7⁴/25-schismina up, 0.72 cents up",
+    "6 tinas down, 2 minas down, 65/77-schismina down, 0.83 cents down",
+    "6 tinas up, 2 minas up, 65/77-schismina up, 0.83 cents up",
+    "7 comma down, 1° down [43 EDO], 2° down [72 EDO], 1/6-tone down",
+    "7 comma up, (7C), 1° up [43 EDO], 2° up [72 EDO], 1/6-tone up",
+    "7:11 comma down, 1° down [60 EDO], 1/10-tone down",
+    "7:11 comma up, (7:11C, ~13:17S, ~29S, 11L less 7C), 1° up [60 EDO]",
+    "7:11 kleisma down",
+    "7:11 kleisma up, (7:11k, ~29k)",
+    "7:19 comma down",
+    "7:19 comma up, (7:19C, 7C less 19s)",
+    "7 tinas down, 7/(5²⋅17)-schismina down, 1.02 cents down",
+    "7 tinas up, 7/(5²⋅17)-schismina up, 1.02 cents up",
+    "8 tinas down, 11⋅17/(5²⋅7)-schismina down, 1.14 cents down",
+    "8 tinas up, 11⋅17/(5²⋅7)-schismina up, 1.14 cents up",
+    "9 tinas down, 1/(7²⋅11)-schismina down, 1.26 cents down",
+    "9 tinas up, 1/(7²⋅11)-schismina up, 1.26 cents up",
+    "Acute, 5 schisma up (5s), 2 cents up",
+    "Double flat, (2 apotomes down)[almost all EDOs], whole-tone down",
+    "Double flat 11:49C-up",
+    "Double flat 143C-up",
+    "Double flat 17C-up",
+    "Double flat 17k-up",
+    "Double flat 19C-up",
+    "Double flat 19s-up",
+    "Double flat 23C-up, 14° down [96 EDO], 7/8-tone down",
+    "Double flat 23S-up",
+    "Double flat 25S-up, 8°down [53 EDO]",
+    "Double flat 49S-up",
+    "Double flat 55C-up, 13° down [96 EDO], 13/16-tone down",
+    "Double flat 5C-up, 5°[22 29] 7°[34 41] 9°53 down, 11/12 tone down",
+    "Double flat 5:11S-up",
+    "Double flat 5:19C-up, 19/20-tone down",
+    "Double flat 5:23S-up, 8° down [60 EDO], 4/5-tone down",
+    "Double flat 5:7k-up",
+    "Double flat 7C-up, 5° down [43 EDO], 10° down [72 EDO], 5/6-tone down",
+    "Double flat 7:11C-up, 9° down [60 EDO], 9/10-tone down",
+    "Double flat 7:11k-up",
+    "Double flat 7:19C-up",
+    "Double sharp, (2 apotomes up)[almost all EDOs], whole-tone up",
+    "Double sharp 11:49C-down",
+    "Double sharp 143C-down",
+    "Double sharp 17C-down",
+    "Double sharp 17k-down",
+    "Double sharp 19C-down",
+    "Double sharp 19s-down",
+    "Double sharp 23C-down, 14°up [96 EDO], 7/8-tone up",
+    "Double sharp 23S-down",
+    "Double sharp 25S-down, 8°up [53 EDO]",
+    "Double sharp 49S-down",
+    "Double sharp 55C-down, 13° up [96 EDO], 13/16-tone up",
+    "Double sharp 5C-down, 5°[22 29] 7°[34 41] 9°53 up, 11/12 tone up",
+    "Double sharp 5:11S-down",
+    "Double sharp 5:19C-down, 19/20-tone up",
+    "Double sharp 5:23S-down, 8° up [60 EDO], 4/5-tone up",
+    "Double sharp 5:7k-down",
+    "Double sharp 7C-down, 5°[43] 10°[72] up, 5/6-tone up",
+    "Double sharp 7:11C-down, 9° up [60 EDO], 9/10-tone up",
+    "Double sharp 7:11k-down",
+    "Double sharp 7:19C-down",
+    "Flat, (apotome down)[almost all EDOs], 1/2-tone down",
+    "Flat 11L-down, 8° up [46 EDO]",
+    "Flat 11M-down, 3° down [17 31 EDOs], 7° down [46 EDO], 3/4-tone down",
+    "Flat 11:19L-down",
+    "Flat 11:19M-down",
+    "Flat 11:49C-down",
+    "Flat 11:49C-up",
+    "Flat 143C-down",
+    "Flat 143C-up",
+    "Flat 17C-down",
+    "Flat 17C-up",
+    "Flat 17k-down",
+    "Flat 17k-up",
+    "Flat 19C-down",
+    "Flat 19C-up",
+    "Flat 19s-down",
+    "Flat 19s-up",
+    "Flat 23C-down, 10° down [96 EDO], 5/8-tone down",
+    "Flat 23C-up, 6° down [96 EDO], 3/8-tone down",
+    "Flat 23S-down",
+    "Flat 23S-up",
+    "Flat 25S-down, 7° down [53 EDO]",
+    "Flat 25S-up, 3° down [53 EDO]",
+    "Flat 35L-down, 5° down [50 EDO]",
+    "Flat 35M-down, 4° down [50 EDO], 6° down [27 EDO], 13/18-tone down",
+    "Flat 49L-down",
+    "Flat 49M-down",
+    "Flat 49S-down",
+    "Flat 49S-up",
+    "Flat 55C-down, 11° down [96 EDO], 11/16-tone down",
+    "Flat 55C-up, 5° down [96 EDO], 5/16-tone down",
+    "Flat 5C-down, 4°[22 29] 5°[27 34 41] 6°[39 46 53] down, 7/12-tone down",
+    "Flat 5C-up, 2°[22 29] 3°[27 34 41] 4°[39 46 53] 5°72 7°[96] down, 5/12-tone down",
+    "Flat 5:11S-down",
+    "Flat 5:11S-up",
+    "Flat 5:13L-down",
+    "Flat 5:13M-down",
+    "Flat 5:19C-down, 11/20-tone down",
+    "Flat 5:19C-up, 9/20-tone down",
+    "Flat 5:23S-down, 7° down [60 EDO], 7/10-tone down",
+    "Flat 5:23S-up, 3° down [60 EDO], 3/10-tone down",
+    "Flat 5:49M-down",
+    "Flat 5:7k-down",
+    "Flat 5:7k-up",
+    "Flat 7C-down, 4° down [43 EDO], 8° down [72 EDO], 2/3-tone down",
+    "Flat 7C-up, 2° down [43 EDO], 4° down [72 EDO], 1/3-tone down",
+    "Flat 7:11C-down, 6° down [60 EDO], 3/5- tone down",
+    "Flat 7:11C-up, 4° down [60 EDO], 2/5-tone down",
+    "Flat 7:11k-down",
+    "Flat 7:11k-up",
+    "Flat 7:19C-down",
+    "Flat 7:19C-up",
+    "Fractional tina down, 77/(5⋅37)-schismina down, 0.08 cents down",
+    "Fractional tina up, 77/(5⋅37)-schismina up, 0.08 cents up",
+    "Grave, 5 schisma down, 2 cents down",
+    "Shaft down, (natural for use with only diacritics down)",
+    "Shaft up, (natural for use with only diacritics up)",
+    "Sharp, (apotome up)[almost all EDOs], 1/2-tone up",
+    "Sharp 11L-up, 8° up [46 EDO]",
+    "Sharp 11M-up, 3° up [17 31 EDOs], 7° up [46 EDO], 3/4-tone up",
+    "Sharp 11:19L-up",
+    "Sharp 11:19M-up",
+    "Sharp 11:49C-down",
+    "Sharp 11:49C-up",
+    "Sharp 143C-down",
+    "Sharp 143C-up",
+    "Sharp 17C-down",
+    "Sharp 17C-up",
+    "Sharp 17k-down",
+    "Sharp 17k-up",
+    "Sharp 19C-down",
+    "Sharp 19C-up",
+    "Sharp 19s-down",
+    "Sharp 19s-up",
+    "Sharp 23C-down, 6° up [96 EDO], 3/8-tone up",
+    "Sharp 23C-up, 10° up [96 EDO], 5/8-tone up",
+    "Sharp 23S-down",
+    "Sharp 23S-up",
+    "Sharp 25S-down, 3° up [53 EDO]",
+    "Sharp 25S-up, 7° up [53 EDO]",
+    "Sharp 35L-up, 5° up [50 EDO]",
+    "Sharp 35M-up, 4° up [50 EDO], 6° up [27 EDO], 13/18-tone up",
+    "Sharp 49L-up",
+    "Sharp 49M-up",
+    "Sharp 49S-down",
+    "Sharp 49S-up",
+    "Sharp 55C-down, 5° up [96 EDO], 5/16-tone up",
+    "Sharp 55C-up, 11° up [96 EDO], 11/16-tone up",
+    "Sharp 5C-down, 2°[22 29] 3°[27 34 41] 4°[39 46 53] 5°[72] 7°[96] up, 5/12-tone up",
+    "Sharp 5C-up, 4°[22 29] 5°[27 34 41] 6°[39 46 53] up, 7/12-tone up",
+    "Sharp 5:11S-down",
+    "Sharp 5:11S-up",
+    "Sharp 5:13L-up",
+    "Sharp 5:13M-up",
+    "Sharp 5:19C-down, 9/20-tone up",
+    "Sharp 5:19C-up, 11/20-tone up",
+    "Sharp 5:23S-down, 3° up [60 EDO], 3/10-tone up",
+    "Sharp 5:23S-up, 7° up [60 EDO], 7/10-tone up",
+    "Sharp 5:49M-up, (one and a half apotomes)",
+    "Sharp 5:7k-down",
+    "Sharp 5:7k-up",
+    "Sharp 7C-down, 2° up [43 EDO], 4° up [72 EDO], 1/3-tone up",
+    "Sharp 7C-up, 4° up [43 EDO], 8° up [72 EDO], 2/3-tone up",
+    "Sharp 7:11C-down, 4° up [60 EDO], 2/5-tone up",
+    "Sharp 7:11C-up, 6° up [60 EDO], 3/5- tone up",
+    "Sharp 7:11k-down",
+    "Sharp 7:11k-up",
+    "Sharp 7:19C-down",
+    "Sharp 7:19C-up",
+    "Unused",
+    "Unused",
+    "Unused",
+    "Unused",
+    "Combining accordion coupler dot",
+    "Combining left hand, 2 ranks, empty",
+    "Combining left hand, 3 ranks, empty (square)",
+    "Combining right hand, 3 ranks, empty",
+    "Combining right hand, 4 ranks, empty",
+    "Diatonic accordion clef",
+    "Left hand, 2 ranks, 16' stop (round)",
+    "Left hand, 2 ranks, 8' stop + 16' stop (round)",
+    "Left hand, 2 ranks, 8' stop (round)",
+    "Left hand, 2 ranks, full master (round)",
+    "Left hand, 2 ranks, master + 16' stop (round)",
+    "Left hand, 2 ranks, master (round)",
+    "Left hand, 3 ranks, 2' stop + 8' stop (square)",
+    "Left hand, 3 ranks, 2' stop (square)",
+    "Left hand, 3 ranks, 8' stop (square)",
+    "Left hand, 3 ranks, double 8' stop (square)",
+    "Left hand, 3 ranks, 2' stop + double 8' stop (tutti) (square)",
+    "Pull",
+    "Push",
+    "Right hand, 3 ranks, 8' stop + upper tremolo 8' stop + 16' stop (accordion)",
+    "Right hand, 3 ranks, lower tremolo 8' stop + 8' stop + upper tremolo 8' stop (authentic musette)",
+    "Right hand, 3 ranks, 8' stop + 16' stop (bandoneón)",
+    "Right hand, 3 ranks, 16' stop (bassoon)",
+    "Right hand, 3 ranks, 8' stop (clarinet)",
+    "Right hand, 3 ranks, lower tremolo 8' stop + 8' stop + upper tremolo 8' stop + 16' stop",
+    "Right hand, 3 ranks, 4' stop + lower tremolo 8' stop + 8' stop + upper tremolo 8' stop",
+    "Right hand, 3 ranks, 4' stop + lower tremolo 8' stop + 8' stop + upper tremolo 8' stop + 16' stop",
+    "Right hand, 3 ranks, 4' stop + 8' stop + 16' stop (harmonium)",
+    "Right hand, 3 ranks, 4' stop + 8' stop + upper tremolo 8' stop (imitation musette)",
+    "Right hand, 3 ranks, lower tremolo 8' stop",
+    "Right hand, 3 ranks, 4' stop + lower tremolo 8' stop + upper tremolo 8' stop + 16' stop (master)",
+    "Right hand, 3 ranks, 4' stop + 8' stop (oboe)",
+    "Right hand, 3 ranks, 4' stop + 16' stop (organ)",
+    "Right hand, 3 ranks, 4' stop (piccolo)",
+    "Right hand, 3 ranks, lower tremolo 8' stop + upper tremolo 8' stop + 16' stop",
+    "Right hand, 3 ranks, 4' stop + lower tremolo 8' stop + upper tremolo 8' stop",
+    "Right hand, 3 ranks, lower tremolo 8' stop + upper tremolo 8' stop",
+    "Right hand, 3 ranks, upper tremolo 8' stop",
+    "Right hand, 3 ranks, 8' stop + upper tremolo 8' stop (violin)",
+    "Right hand, 4 ranks, alto",
+    "Right hand, 4 ranks, bass/alto",
+    "Right hand, 4 ranks, master",
+    "Right hand, 4 ranks, soft bass",
+    "Right hand, 4 ranks, soft tenor",
+    "Right hand, 4 ranks, soprano",
+    "Right hand, 4 ranks, tenor",
+    "Ricochet (2 tones)",
+    "Ricochet (3 tones)",
+    "Ricochet (4 tones)",
+    "Ricochet (5 tones)",
+    "Ricochet (6 tones)",
+    "Combining ricochet for stem (2 tones)",
+    "Combining ricochet for stem (3 tones)",
+    "Combining ricochet for stem (4 tones)",
+    "Combining ricochet for stem (5 tones)",
+    "Combining ricochet for stem (6 tones)",
+    "1-comma flat",
+    "1-comma sharp",
+    "2-comma flat",
+    "2-comma sharp",
+    "3-comma flat",
+    "3-comma sharp",
+    "4-comma flat",
+    "5-comma sharp",
+    "Arrow down (lower by one quarter-tone)",
+    "Arrow up (raise by one quarter-tone)",
+    "Bakiye (flat)",
+    "Bakiye (sharp)",
+    "Accidental bracket, left",
+    "Accidental bracket, right",
+    "Büyük mücenneb (flat)",
+    "Büyük mücenneb (sharp)",
+    "Combining close curly brace",
+    "Combining lower by one 17-limit schisma",
+    "Combining lower by one 19-limit schisma",
+    "Combining lower by one 23-limit comma",
+    "Combining lower by one 29-limit comma",
+    "Combining lower by one 31-limit schisma",
+    "Combining lower by one 37-limit quartertone",
+    "Combining lower by one 41-limit comma",
+    "Combining lower by one 43-limit comma",
+    "Combining lower by one 47-limit quartertone",
+    "Combining lower by one 53-limit comma",
+    "Combining open curly brace",
+    "Combining raise by one 17-limit schisma",
+    "Combining raise by one 19-limit schisma",
+    "Combining raise by one 23-limit comma",
+    "Combining raise by one 29-limit comma",
+    "Combining raise by one 31-limit schisma",
+    "Combining raise by one 37-limit quartertone",
+    "Combining raise by one 41-limit comma",
+    "Combining raise by one 43-limit comma",
+    "Combining raise by one 47-limit quartertone",
+    "Combining raise by one 53-limit comma",
+    "Syntonic/Didymus comma (80:81) down (Bosanquet)",
+    "Syntonic/Didymus comma (80:81) up (Bosanquet)",
+    "Double flat",
+    "Arabic double flat",
+    "Double flat equal tempered semitone",
+    "Double flat lowered by one syntonic comma",
+    "Double flat raised by one syntonic comma",
+    "Reversed double flat",
+    "Double flat lowered by three syntonic commas",
+    "Double flat raised by three syntonic commas",
+    "Turned double flat",
+    "Double flat lowered by two syntonic commas",
+    "Double flat raised by two syntonic commas",
+    "Double sharp",
+    "Arabic double sharp",
+    "Double sharp equal tempered semitone",
+    "Double sharp lowered by one syntonic comma",
+    "Double sharp raised by one syntonic comma",
+    "Double sharp lowered by three syntonic commas",
+    "Double sharp raised by three syntonic commas",
+    "Double sharp lowered by two syntonic commas",
+    "Double sharp raised by two syntonic commas",
+    "Enharmonically reinterpret accidental almost equal to",
+    "Enharmonically reinterpret accidental equals",
+    "Enharmonically reinterpret accidental tilde",
+    "Filled reversed flat and flat",
+    "Filled reversed flat and flat with arrow down",
+    "Filled reversed flat and flat with arrow up",
+    "Filled reversed flat with arrow down",
+    "Filled reversed flat with arrow up",
+    "Five-quarter-tones flat",
+    "Five-quarter-tones sharp",
+    "Flat",
+    "Arabic half-tone flat",
+    "Flat equal tempered semitone",
+    "Lowered flat (Stockhausen)",
+    "Flat lowered by one syntonic comma",
+    "Flat raised by one syntonic comma",
+    "Raised flat (Stockhausen)",
+    "Repeated flat, note on line (Stockhausen)",
+    "Repeated flat, note in space (Stockhausen)",
+    "Flat lowered by three syntonic commas",
+    "Flat raised by three syntonic commas",
+    "Turned flat",
+    "Flat lowered by two syntonic commas",
+    "Flat raised by two syntonic commas",
+    "Quarter-tone higher (Alois Hába)",
+    "Three quarter-tones lower (Alois Hába)",
+    "Quarter-tone higher (Alois Hába)",
+    "Quarter-tone lower (Alois Hába)",
+    "Quarter-tone lower (Alois Hába)",
+    "Three quarter-tones higher (Alois Hába)",
+    "Half sharp with arrow down",
+    "Half sharp with arrow up",
+    "Thirteen (raise by 65:64)",
+    "Inverted 13 (lower by 65:64)",
+    "Down arrow (lower by 33:32)",
+    "Inverted seven (raise by 36:35)",
+    "Minus (lower by 81:80)",
+    "Plus (raise by 81:80)",
+    "Seven (lower by 36:35)",
+    "Up arrow (raise by 33:32)",
+    "Koma (flat)",
+    "Koma (sharp)",
+    "Koron (quarter tone flat)",
+    "Küçük mücenneb (flat)",
+    "Küçük mücenneb (sharp)",
+    "Large double sharp",
+    "Lower by one septimal comma",
+    "Lower by one tridecimal quartertone",
+    "Lower by one undecimal quartertone",
+    "Lower by two septimal commas",
+    "Lowered (Stockhausen)",
+    "Narrow reversed flat(quarter-tone flat)",
+    "Narrow reversed flat and flat(three-quarter-tones flat)",
+    "Natural",
+    "Arabic natural",
+    "Natural equal tempered semitone",
+    "Natural flat",
+    "Lowered natural (Stockhausen)",
+    "Natural lowered by one syntonic comma",
+    "Natural raised by one syntonic comma",
+    "Raised natural (Stockhausen)",
+    "Reversed natural",
+    "Natural sharp",
+    "Natural lowered by three syntonic commas",
+    "Natural raised by three syntonic commas",
+    "Natural lowered by two syntonic commas",
+    "Natural raised by two syntonic commas",
+    "One and a half sharps with arrow down",
+    "One and a half sharps with arrow up",
+    "One-quarter-tone flat (Ferneyhough)",
+    "One-quarter-tone flat (Stockhausen)",
+    "One-quarter-tone sharp (Ferneyhough)",
+    "One-quarter-tone sharp (Stockhausen)",
+    "One-third-tone flat (Ferneyhough)",
+    "One-third-tone sharp (Ferneyhough)",
+    "Accidental parenthesis, left",
+    "Accidental parenthesis, right",
+    "Lower by one equal tempered quarter-tone",
+    "Raise by one equal tempered quarter tone",
+    "Quarter-tone flat",
+    "Arabic quarter-tone flat",
+    "Quarter-tone flat",
+    "Filled reversed flat (quarter-tone flat)",
+    "Quarter-tone flat",
+    "Quarter tone flat (Penderecki)",
+    "Reversed flat (quarter-tone flat) (Stein)",
+    "Quarter-tone flat (van Blankenburg)",
+    "Quarter-tone sharp",
+    "Arabic quarter-tone sharp",
+    "Quarter-tone sharp",
+    "Quarter tone sharp (Bussotti)",
+    "Quarter-tone sharp",
+    "Half sharp (quarter-tone sharp) (Stein)",
+    "Quarter tone sharp with wiggly tail",
+    "Raise by one septimal comma",
+    "Raise by one tridecimal quartertone",
+    "Raise by one undecimal quartertone",
+    "Raise by two septimal commas",
+    "Raised (Stockhausen)",
+    "Reversed flat and flat with arrow down",
+    "Reversed flat and flat with arrow up",
+    "Reversed flat with arrow down",
+    "Reversed flat with arrow up",
+    "Sharp",
+    "Arabic half-tone sharp",
+    "Sharp equal tempered semitone",
+    "Lowered sharp (Stockhausen)",
+    "Sharp lowered by one syntonic comma",
+    "Sharp raised by one syntonic comma",
+    "One or three quarter tones sharp",
+    "Raised sharp (Stockhausen)",
+    "Repeated sharp, note on line (Stockhausen)",
+    "Repeated sharp, note in space (Stockhausen)",
+    "Reversed sharp",
+    "Sharp sharp",
+    "Sharp lowered by three syntonic commas",
+    "Sharp raised by three syntonic commas",
+    "Sharp lowered by two syntonic commas",
+    "Sharp raised by two syntonic commas",
+    "1/12 tone low",
+    "1/12 tone high",
+    "1/4 tone low",
+    "1/4 tone high",
+    "1/6 tone low",
+    "1/6 tone high",
+    "Sori (quarter tone sharp)",
+    "Byzantine-style Bakiye flat (Tavener)",
+    "Byzantine-style Bu\u{308}yu\u{308}k mu\u{308}cenneb sharp (Tavener)",
+    "Arabic three-quarter-tones flat",
+    "Three-quarter-tones flat",
+    "Three-quarter-tones flat",
+    "Three-quarter-tones flat (Couper)",
+    "Three-quarter-tones flat (Grisey)",
+    "Three-quarter-tones flat (Tartini)",
+    "Reversed flat and flat (three-quarter-tones flat) (Zimmermann)",
+    "Arabic three-quarter-tones sharp",
+    "Three-quarter-tones sharp",
+    "Three-quarter-tones sharp",
+    "Three quarter tones sharp (Bussotti)",
+    "One and a half sharps (three-quarter-tones sharp) (Stein)",
+    "Three-quarter-tones sharp (Stockhausen)",
+    "Triple flat",
+    "Triple sharp",
+    "Two-third-tones flat (Ferneyhough)",
+    "Two-third-tones sharp (Ferneyhough)",
+    "Accidental down",
+    "Accidental less",
+    "Accidental more",
+    "Accidental up",
+    "Wilson minus (5 comma down)",
+    "Wilson plus (5 comma up)",
+    "5/6 tone flat",
+    "5/6 tone sharp",
+    "11/12 tone flat",
+    "11/12 tone sharp",
+    "1/12 tone flat",
+    "1/12 tone sharp",
+    "1/6 tone flat",
+    "1/6 tone sharp",
+    "1/4 tone flat",
+    "1/4 tone sharp",
+    "1/3 tone flat",
+    "1/3 tone sharp",
+    "5/12 tone flat",
+    "5/12 tone sharp",
+    "1/2 tone flat",
+    "1/2 tone sharp",
+    "7/12 tone flat",
+    "7/12 tone sharp",
+    "2/3 tone flat",
+    "2/3 tone sharp",
+    "3/4 tone flat",
+    "3/4 tone sharp",
+    "One-third-tone sharp (Xenakis)",
+    "Two-third-tones sharp (Xenakis)",
+    "Choralmelodie (Berg)",
+    "End of stimme",
+    "Hauptrhythmus (Berg)",
+    "Hauptstimme",
+    "Inversion 1",
+    "Nebenstimme",
+    "Start of stimme",
+    "Theme",
+    "Theme 1",
+    "Inversion of theme",
+    "Retrograde of theme",
+    "Retrograde inversion of theme",
+    "Arpeggiato",
+    "Arpeggiato down",
+    "Arpeggiato up",
+    "Black arrow down (S)",
+    "Black arrow down-left (SW)",
+    "Black arrow down-right (SE)",
+    "Black arrow left (W)",
+    "Black arrow right (E)",
+    "Black arrow up (N)",
+    "Black arrow up-left (NW)",
+    "Black arrow up-right (NE)",
+    "Open arrow down (S)",
+    "Open arrow down-left (SW)",
+    "Open arrow down-right (SE)",
+    "Open arrow left (W)",
+    "Open arrow right (E)",
+    "Open arrow up (N)",
+    "Open arrow up-left (NW)",
+    "Open arrow up-right (NE)",
+    "White arrow down (S)",
+    "White arrow down-left (SW)",
+    "White arrow down-right (SE)",
+    "White arrow left (W)",
+    "White arrow right (E)",
+    "White arrow up (N)",
+    "White arrow up-left (NW)",
+    "White arrow up-right (NE)",
+    "Black arrowhead down (S)",
+    "Black arrowhead down-left (SW)",
+    "Black arrowhead down-right (SE)",
+    "Black arrowhead left (W)",
+    "Black arrowhead right (E)",
+    "Black arrowhead up (N)",
+    "Black arrowhead up-left (NW)",
+    "Black arrowhead up-right (NE)",
+    "Open arrowhead down (S)",
+    "Open arrowhead down-left (SW)",
+    "Open arrowhead down-right (SE)",
+    "Open arrowhead left (W)",
+    "Open arrowhead right (E)",
+    "Open arrowhead up (N)",
+    "Open arrowhead up-left (NW)",
+    "Open arrowhead up-right (NE)",
+    "White arrowhead down (S)",
+    "White arrowhead down-left (SW)",
+    "White arrowhead down-right (SE)",
+    "White arrowhead left (W)",
+    "White arrowhead right (E)",
+    "White arrowhead up (N)",
+    "White arrowhead up-left (NW)",
+    "White arrowhead up-right (NE)",
+    "Accent above",
+    "Accent below",
+    "Accent-staccato above",
+    "Accent-staccato below",
+    "Laissez vibrer (l.v.) above",
+    "Laissez vibrer (l.v.) below",
+    "Marcato above",
+    "Marcato below",
+    "Marcato-staccato above",
+    "Marcato-staccato below",
+    "Marcato-tenuto above",
+    "Marcato-tenuto below",
+    "Soft accent above",
+    "Soft accent below",
+    "Soft accent-staccato above",
+    "Soft accent-staccato below",
+    "Soft accent-tenuto above",
+    "Soft accent-tenuto below",
+    "Soft accent-tenuto-staccato above",
+    "Soft accent-tenuto-staccato below",
+    "Staccatissimo above",
+    "Staccatissimo below",
+    "Staccatissimo stroke above",
+    "Staccatissimo stroke below",
+    "Staccatissimo wedge above",
+    "Staccatissimo wedge below",
+    "Staccato above",
+    "Staccato below",
+    "Stress above",
+    "Stress below",
+    "Tenuto above",
+    "Tenuto-accent above",
+    "Tenuto-accent below",
+    "Tenuto below",
+    "Louré (tenuto-staccato) above",
+    "Louré (tenuto-staccato) below",
+    "Unstress above",
+    "Unstress below",
+    "Augmentation dot",
+    "Dashed barline",
+    "Dotted barline",
+    "Double barline",
+    "Final barline",
+    "Heavy barline",
+    "Heavy double barline",
+    "Reverse final barline",
+    "Short barline",
+    "Single barline",
+    "Tick barline",
+    "Accel./rit. beam 1 (widest)",
+    "Accel./rit. beam 10",
+    "Accel./rit. beam 11",
+    "Accel./rit. beam 12",
+    "Accel./rit. beam 13",
+    "Accel./rit. beam 14",
+    "Accel./rit. beam 15 (narrowest)",
+    "Accel./rit. beam 2",
+    "Accel./rit. beam 3",
+    "Accel./rit. beam 4",
+    "Accel./rit. beam 5",
+    "Accel./rit. beam 6",
+    "Accel./rit. beam 7",
+    "Accel./rit. beam 8",
+    "Accel./rit. beam 9",
+    "Accel./rit. beam terminating line",
+    "Brace",
+    "Bracket",
+    "Bracket bottom",
+    "Bracket top",
+    "Bend",
+    "Doit, long",
+    "Doit, medium",
+    "Doit, short",
+    "Lip fall, long",
+    "Lip fall, medium",
+    "Lip fall, short",
+    "Rough fall, long",
+    "Rough fall, medium",
+    "Rough fall, short",
+    "Smooth fall, long",
+    "Smooth fall, medium",
+    "Smooth fall, short",
+    "Flip",
+    "Harmon mute, stem in",
+    "Harmon mute, stem extended, left",
+    "Harmon mute, stem extended, right",
+    "Harmon mute, stem out",
+    "Jazz turn",
+    "Lift, long",
+    "Lift, medium",
+    "Lift, short",
+    "Smooth lift, long",
+    "Smooth lift, medium",
+    "Smooth lift, short",
+    "Muted (closed)",
+    "Half-muted (half-closed)",
+    "Open",
+    "Plop",
+    "Scoop",
+    "Smear",
+    "Valve trill",
+    "Breath mark (comma)",
+    "Breath mark (Salzedo)",
+    "Breath mark (tick-like)",
+    "Breath mark (upbow-like)",
+    "Bridge clef",
+    "Buzz roll",
+    "C clef",
+    "C clef ottava bassa",
+    "C clef, arrow down",
+    "C clef, arrow up",
+    "C clef change",
+    "Combining C clef",
+    "Reversed C clef",
+    "C clef (19th century)",
+    "Caesura",
+    "Curved caesura",
+    "Short caesura",
+    "Single stroke caesura",
+    "Thick caesura",
+    "Accentus above",
+    "Accentus below",
+    "Punctum auctum, ascending",
+    "Punctum auctum, descending",
+    "Augmentum (mora)",
+    "Caesura",
+    "Plainchant C clef",
+    "Circulus above",
+    "Circulus below",
+    "Connecting line, ascending 2nd",
+    "Connecting line, ascending 3rd",
+    "Connecting line, ascending 4th",
+    "Connecting line, ascending 5th",
+    "Connecting line, ascending 6th",
+    "Plainchant custos, stem down, high position",
+    "Plainchant custos, stem down, highest position",
+    "Plainchant custos, stem down, middle position",
+    "Plainchant custos, stem up, low position",
+    "Plainchant custos, stem up, lowest position",
+    "Plainchant custos, stem up, middle position",
+    "Punctum deminutum, lower",
+    "Punctum deminutum, upper",
+    "Divisio finalis",
+    "Divisio maior",
+    "Divisio maxima",
+    "Divisio minima",
+    "Entry line, ascending 2nd",
+    "Entry line, ascending 3rd",
+    "Entry line, ascending 4th",
+    "Entry line, ascending 5th",
+    "Entry line, ascending 6th",
+    "Episema",
+    "Plainchant F clef",
+    "Ictus above",
+    "Ictus below",
+    "Ligated stroke, descending 2nd",
+    "Ligated stroke, descending 3rd",
+    "Ligated stroke, descending 4th",
+    "Ligated stroke, descending 5th",
+    "Oriscus ascending",
+    "Oriscus descending",
+    "Oriscus liquescens",
+    "Podatus, lower",
+    "Podatus, upper",
+    "Punctum",
+    "Punctum cavum",
+    "Punctum deminutum",
+    "Punctum inclinatum",
+    "Punctum inclinatum auctum",
+    "Punctum inclinatum deminutum",
+    "Punctum linea",
+    "Punctum linea cavum",
+    "Punctum virga",
+    "Punctum virga, reversed",
+    "Quilisma",
+    "Semicirculus above",
+    "Semicirculus below",
+    "Plainchant staff",
+    "Plainchant staff (narrow)",
+    "Plainchant staff (wide)",
+    "Strophicus",
+    "Strophicus auctus",
+    "Strophicus liquescens, 2nd",
+    "Strophicus liquescens, 3rd",
+    "Strophicus liquescens, 4th",
+    "Strophicus liquescens, 5th",
+    "Virgula",
+    "15 for clefs",
+    "8 for clefs",
+    "Combining clef change",
+    "Coda",
+    "Square coda",
+    "Beat 2, compound time",
+    "Beat 2, simple time",
+    "Beat 3, compound time",
+    "Beat 3, simple time",
+    "Beat 4, compound time",
+    "Beat 4, simple time",
+    "Left-hand beat or cue",
+    "Right-hand beat or cue",
+    "Strong beat or cue",
+    "Unconducted/free passages",
+    "Weak beat or cue",
+    "Begin beam",
+    "Begin phrase",
+    "Begin slur",
+    "Begin tie",
+    "End beam",
+    "End phrase",
+    "End slur",
+    "End tie",
+    "Double flat",
+    "Double sharp",
+    "Flat",
+    "Natural",
+    "Sharp",
+    "Triple flat",
+    "Triple sharp",
+    "Slash for altered bass note",
+    "Augmented",
+    "Double-height left bracket",
+    "Double-height right bracket",
+    "Slash for chord symbols arranged diagonally",
+    "Diminished",
+    "Half-diminished",
+    "Major seventh",
+    "Minor",
+    "Double-height left parenthesis",
+    "Triple-height left parenthesis",
+    "Double-height right parenthesis",
+    "Triple-height right parenthesis",
+    "Curlew (Britten)",
+    "Da capo",
+    "Dal segno",
+    "Daseian excellentes 1",
+    "Daseian excellentes 2",
+    "Daseian excellentes 3",
+    "Daseian excellentes 4",
+    "Daseian finales 1",
+    "Daseian finales 2",
+    "Daseian finales 3",
+    "Daseian finales 4",
+    "Daseian graves 1",
+    "Daseian graves 2",
+    "Daseian graves 3",
+    "Daseian graves 4",
+    "Daseian residua 1",
+    "Daseian residua 2",
+    "Daseian superiores 1",
+    "Daseian superiores 2",
+    "Daseian superiores 3",
+    "Daseian superiores 4",
+    "Double lateral roll (Stevens)",
+    "Double-tongue above",
+    "Double-tongue below",
+    "Colon separator for combined dynamics",
+    "Hyphen separator for combined dynamics",
+    "Slash separator for combined dynamics",
+    "Space separator for combined dynamics",
+    "Crescendo",
+    "Diminuendo",
+    "ff",
+    "fff",
+    "ffff",
+    "fffff",
+    "ffffff",
+    "Forte",
+    "Forte-piano",
+    "Forzando",
+    "Left bracket (for hairpins)",
+    "Right bracket (for hairpins)",
+    "Left parenthesis (for hairpins)",
+    "Right parenthesis (for hairpins)",
+    "Messa di voce",
+    "Mezzo",
+    "mf",
+    "mp",
+    "Niente",
+    "Niente (for hairpins)",
+    "pf",
+    "Piano",
+    "pp",
+    "ppp",
+    "pppp",
+    "ppppp",
+    "pppppp",
+    "Rinforzando",
+    "Rinforzando 1",
+    "Rinforzando 2",
+    "Sforzando",
+    "Sforzando 1",
+    "Sforzando-pianissimo",
+    "Sforzando-piano",
+    "Sforzato",
+    "Sforzatissimo",
+    "Sforzato-piano",
+    "Z",
+    "Eight channels (7.1 surround)",
+    "Five channels",
+    "Four channels",
+    "One channel (mono)",
+    "Seven channels",
+    "Six channels (5.1 surround)",
+    "Three channels (frontal)",
+    "Three channels (surround)",
+    "Two channels (stereo)",
+    "Audio in",
+    "Mono audio setup",
+    "Audio out",
+    "Stereo audio setup",
+    "Camera",
+    "Data in",
+    "Data out",
+    "Disc",
+    "Download",
+    "Eject",
+    "Fast-forward",
+    "Headphones",
+    "Headset",
+    "Line in",
+    "Line out",
+    "Loop",
+    "Loudspeaker",
+    "Microphone",
+    "Mute microphone",
+    "Unmute microphone",
+    "MIDI controller 0%",
+    "MIDI controller 100%",
+    "MIDI controller 20%",
+    "MIDI controller 40%",
+    "MIDI controller 60%",
+    "MIDI controller 80%",
+    "MIDI in",
+    "MIDI out",
+    "Mixing console",
+    "Monitor",
+    "Mute",
+    "Pause",
+    "Play",
+    "Power on/off",
+    "Projector",
+    "Replay",
+    "Rewind",
+    "Shuffle",
+    "Skip backwards",
+    "Skip forwards",
+    "Stop",
+    "Tape",
+    "Unmute",
+    "Upload",
+    "USB connection",
+    "Video camera",
+    "Video in",
+    "Video out",
+    "Combining volume fader",
+    "Combining volume fader thumb",
+    "Volume level 0%",
+    "Volume level 100%",
+    "Volume level 20%",
+    "Volume level 40%",
+    "Volume level 60%",
+    "Volume level 80%",
+    "F clef",
+    "F clef quindicesima alta",
+    "F clef quindicesima bassa",
+    "F clef ottava alta",
+    "F clef ottava bassa",
+    "F clef, arrow down",
+    "F clef, arrow up",
+    "F clef change",
+    "Reversed F clef",
+    "Turned F clef",
+    "Fermata above",
+    "Fermata below",
+    "Long fermata above",
+    "Long fermata below",
+    "Long fermata (Henze) above",
+    "Long fermata (Henze) below",
+    "Short fermata above",
+    "Short fermata below",
+    "Short fermata (Henze) above",
+    "Short fermata (Henze) below",
+    "Very long fermata above",
+    "Very long fermata below",
+    "Very short fermata above",
+    "Very short fermata below",
+    "Figured bass 0",
+    "Figured bass 1",
+    "Figured bass 2",
+    "Figured bass 2 raised by half-step",
+    "Figured bass 3",
+    "Figured bass 4",
+    "Figured bass 4 raised by half-step",
+    "Figured bass 5",
+    "Figured bass 5 raised by half-step",
+    "Figured bass 5 raised by half-step 2",
+    "Figured bass diminished 5",
+    "Figured bass 6",
+    "Figured bass 6 raised by half-step",
+    "Figured bass 6 raised by half-step 2",
+    "Figured bass 7",
+    "Figured bass 7 diminished",
+    "Figured bass 7 raised by half-step",
+    "Figured bass 7 lowered by a half-step",
+    "Figured bass 8",
+    "Figured bass 9",
+    "Figured bass 9 raised by half-step",
+    "Figured bass [",
+    "Figured bass ]",
+    "Combining lower",
+    "Combining raise",
+    "Figured bass double flat",
+    "Figured bass double sharp",
+    "Figured bass flat",
+    "Figured bass natural",
+    "Figured bass (",
+    "Figured bass )",
+    "Figured bass +",
+    "Figured bass sharp",
+    "Figured bass triple flat",
+    "Figured bass triple sharp",
+    "Fingering 0 (open string)",
+    "Fingering 0 italic (open string)",
+    "Fingering 1 (thumb)",
+    "Fingering 1 italic (thumb)",
+    "Fingering 2 (index finger)",
+    "Fingering 2 italic (index finger)",
+    "Fingering 3 (middle finger)",
+    "Fingering 3 italic (middle finger)",
+    "Fingering 4 (ring finger)",
+    "Fingering 4 italic (ring finger)",
+    "Fingering 5 (little finger)",
+    "Fingering 5 italic (little finger)",
+    "Fingering 6",
+    "Fingering 6 italic",
+    "Fingering 7",
+    "Fingering 7 italic",
+    "Fingering 8",
+    "Fingering 8 italic",
+    "Fingering 9",
+    "Fingering 9 italic",
+    "Fingering a (anular; right-hand ring finger for guitar)",
+    "Fingering c (right-hand little finger for guitar)",
+    "Fingering e (right-hand little finger for guitar)",
+    "Fingering i (indicio; right-hand index finger for guitar)",
+    "Fingering left bracket",
+    "Fingering left bracket italic",
+    "Fingering left parenthesis",
+    "Fingering left parenthesis italic",
+    "Fingering m (medio; right-hand middle finger for guitar)",
+    "Multiple notes played by thumb or single finger",
+    "Fingering o (right-hand little finger for guitar)",
+    "Fingering p (pulgar; right-hand thumb for guitar)",
+    "Fingering q (right-hand little finger for guitar)",
+    "Fingering right bracket",
+    "Fingering right bracket italic",
+    "Fingering right parenthesis",
+    "Fingering right parenthesis italic",
+    "Fingering s (right-hand little finger for guitar)",
+    "Fingering middle dot separator",
+    "Fingering white middle dot separator",
+    "Fingering forward slash separator",
+    "Finger substitution above",
+    "Finger substitution below",
+    "Finger substitution dash",
+    "Fingering t (right-hand thumb for guitar)",
+    "Fingering T (left-hand thumb for guitar)",
+    "Fingering x (right-hand little finger for guitar)",
+    "Combining flag 8 (1024th) below",
+    "Combining flag 8 (1024th) above",
+    "Combining flag 5 (128th) below",
+    "Combining flag 5 (128th) above",
+    "Combining flag 2 (16th) below",
+    "Combining flag 2 (16th) above",
+    "Combining flag 6 (256th) below",
+    "Combining flag 6 (256th) above",
+    "Combining flag 3 (32nd) below",
+    "Combining flag 3 (32nd) above",
+    "Combining flag 7 (512th) below",
+    "Combining flag 7 (512th) above",
+    "Combining flag 4 (64th) below",
+    "Combining flag 4 (64th) above",
+    "Combining flag 1 (8th) below",
+    "Combining flag 1 (8th) above",
+    "Internal combining flag below",
+    "Internal combining flag above",
+    "3-string fretboard",
+    "3-string fretboard at nut",
+    "4-string fretboard",
+    "4-string fretboard at nut",
+    "5-string fretboard",
+    "5-string fretboard at nut",
+    "6-string fretboard",
+    "6-string fretboard at nut",
+    "Fingered fret (filled circle)",
+    "Open string (O)",
+    "String not played (X)",
+    "Function theory angle bracket left",
+    "Function theory angle bracket right",
+    "Function theory bracket left",
+    "Function theory bracket right",
+    "Function theory minor dominant",
+    "Function theory major dominant",
+    "Function theory dominant of dominant",
+    "Function theory 8",
+    "Function theory F",
+    "Function theory 5",
+    "Function theory 4",
+    "Function theory g",
+    "Function theory G",
+    "Function theory greater than",
+    "Function theory i",
+    "Function theory I",
+    "Function theory k",
+    "Function theory K",
+    "Function theory l",
+    "Function theory L",
+    "Function theory less than",
+    "Function theory m",
+    "Function theory M",
+    "Function theory minus",
+    "Function theory n",
+    "Function theory N",
+    "Function theory superscript N",
+    "Function theory 9",
+    "Function theory 1",
+    "Function theory p",
+    "Function theory P",
+    "Function theory parenthesis left",
+    "Function theory parenthesis right",
+    "Function theory prefix plus",
+    "Function theory r",
+    "Function theory repetition 1",
+    "Function theory repetition 2",
+    "Function theory prefix ring",
+    "Function theory minor subdominant",
+    "Function theory major subdominant",
+    "Function theory 7",
+    "Function theory 6",
+    "Function theory double dominant seventh",
+    "Function theory minor subdominant of subdominant",
+    "Function theory major subdominant of subdominant",
+    "Function theory minor tonic",
+    "Function theory tonic",
+    "Function theory 3",
+    "Function theory 2",
+    "Function theory v",
+    "Function theory V",
+    "Function theory 0",
+    "G clef",
+    "G clef quindicesima alta",
+    "G clef quindicesima bassa",
+    "G clef ottava alta",
+    "G clef ottava bassa",
+    "G clef ottava bassa with C clef",
+    "G clef ottava bassa (old style)",
+    "G clef, optionally ottava bassa",
+    "G clef, arrow down",
+    "G clef, arrow up",
+    "G clef change",
+    "Combining G clef, number above",
+    "Combining G clef, number below",
+    "Reversed G clef",
+    "Turned G clef",
+    "Glissando down",
+    "Glissando up",
+    "Slashed grace note stem down",
+    "Slashed grace note stem up",
+    "Grace note stem down",
+    "Grace note stem up",
+    "Slash for stem down grace note",
+    "Slash for stem up grace note",
+    "Full barré",
+    "Half barré",
+    "Closed wah/volume pedal",
+    "Fade in",
+    "Fade out",
+    "Golpe (tapping the pick guard)",
+    "Half-open wah/volume pedal",
+    "Left-hand tapping",
+    "Open wah/volume pedal",
+    "Right-hand tapping",
+    "Guitar shake",
+    "String number 0",
+    "String number 1",
+    "String number 10",
+    "String number 11",
+    "String number 12",
+    "String number 13",
+    "String number 2",
+    "String number 3",
+    "String number 4",
+    "String number 5",
+    "String number 6",
+    "String number 7",
+    "String number 8",
+    "String number 9",
+    "Strum direction down",
+    "Strum direction up",
+    "Guitar vibrato bar dip",
+    "Guitar vibrato bar scoop",
+    "Vibrato wiggle segment",
+    "Volume swell",
+    "Wide vibrato wiggle segment",
+    "Belltree",
+    "Damp 3",
+    "Echo",
+    "Echo 2",
+    "Gyro",
+    "Hand martellato",
+    "Mallet, bell on table",
+    "Mallet, bell suspended",
+    "Mallet lift",
+    "Martellato",
+    "Martellato lift",
+    "Muted martellato",
+    "Pluck lift",
+    "Swing",
+    "Swing down",
+    "Swing up",
+    "Table pair of handbells",
+    "Table single handbell",
+    "Metal rod pictogram",
+    "Harp pedal centered (natural)",
+    "Harp pedal divider",
+    "Harp pedal lowered (sharp)",
+    "Harp pedal raised (flat)",
+    "Ascending aeolian chords (Salzedo)",
+    "Descending aeolian chords (Salzedo)",
+    "Damp above (Salzedo)",
+    "Damp below (Salzedo)",
+    "Damp with both hands (Salzedo)",
+    "Damp only low strings (Salzedo)",
+    "Fluidic sounds, left hand (Salzedo)",
+    "Fluidic sounds, right hand (Salzedo)",
+    "Isolated sounds (Salzedo)",
+    "Metallic sounds (Salzedo)",
+    "Metallic sounds, one string (Salzedo)",
+    "Muffle totally (Salzedo)",
+    "Oboic flux (Salzedo)",
+    "Play at upper end of strings (Salzedo)",
+    "Slide with suppleness (Salzedo)",
+    "Snare drum effect (Salzedo)",
+    "Tam-tam sounds (Salzedo)",
+    "Thunder effect (Salzedo)",
+    "Timpanic sounds (Salzedo)",
+    "Whistling sounds (Salzedo)",
+    "Combining string noise for stem",
+    "Tuning key pictogram",
+    "Retune strings for glissando",
+    "Use handle of tuning key pictogram",
+    "Use shank of tuning key pictogram",
+    "Indian drum clef",
+    "Back-chug",
+    "Back-flap",
+    "Back-riff",
+    "Back-rip",
+    "Ball-change",
+    "Ball-dig",
+    "Brush-backward",
+    "Brush-forward",
+    "Chug",
+    "Clap",
+    "Double-snap",
+    "Double-wing",
+    "Draw-step",
+    "Draw-tap",
+    "Flam",
+    "Flap",
+    "Flap-step",
+    "Flat",
+    "Flea-hop",
+    "Flea-tap",
+    "Grace-tap",
+    "Grace-tap-change",
+    "Grace-tap-hop",
+    "Grace-tap-stamp",
+    "Heel",
+    "Heel-change",
+    "Heel-click",
+    "Heel-drop",
+    "Heel-step",
+    "Heel-tap",
+    "Hop",
+    "Jump-apart",
+    "Jump-together",
+    "Knee-inward",
+    "Knee-outward",
+    "Leap",
+    "Leap-flat-foot",
+    "Leap-heel-click",
+    "Left-catch",
+    "Left-cross",
+    "Left-foot",
+    "Left-toe-strike",
+    "Left-turn",
+    "Over-the-top",
+    "Over-the-top-tap",
+    "Pull",
+    "Push",
+    "Riff",
+    "Riffle",
+    "Right-catch",
+    "Right-cross",
+    "Right-foot",
+    "Right-toe-strike",
+    "Right-turn",
+    "Rip",
+    "Ripple",
+    "Scrape",
+    "Scuff",
+    "Scuffle",
+    "Shuffle",
+    "Slam",
+    "Slap",
+    "Slide-step",
+    "Slide-tap",
+    "Snap",
+    "Stamp",
+    "Stamp-stamp",
+    "Step",
+    "Step-stamp",
+    "Stomp",
+    "Stomp-brush",
+    "Tap",
+    "Toe",
+    "Toe-click",
+    "Toe-drop",
+    "Toe-step",
+    "Toe-tap",
+    "Trench",
+    "Wing",
+    "Wing-change",
+    "Zank",
+    "Zink",
+    "Clavichord bebung, 2 finger movements (above)",
+    "Clavichord bebung, 2 finger movements (below)",
+    "Clavichord bebung, 3 finger movements (above)",
+    "Clavichord bebung, 3 finger movements (below)",
+    "Clavichord bebung, 4 finger movements (above)",
+    "Clavichord bebung, 4 finger movements (below)",
+    "Left pedal pictogram",
+    "Middle pedal pictogram",
+    "Pedal d",
+    "Pedal dot",
+    "Pedal e",
+    "Half-pedal mark",
+    "Half pedal mark 1",
+    "Half pedal mark 2",
+    "Pedal heel 1",
+    "Pedal heel 2",
+    "Pedal heel 3 (Davis)",
+    "Pedal heel to toe",
+    "Pedal heel or toe",
+    "Pedal hook end",
+    "Pedal hook start",
+    "Pedal hyphen",
+    "Pedal P",
+    "Left parenthesis for pedal marking",
+    "Right parenthesis for pedal marking",
+    "Pedal mark",
+    "Pedal S",
+    "Sostenuto pedal mark",
+    "Pedal toe 1",
+    "Pedal toe 2",
+    "Pedal toe to heel",
+    "Pedal up mark",
+    "Pedal up notch",
+    "Pedal up special",
+    "Play with left hand",
+    "Play with left hand (end)",
+    "Play with right hand",
+    "Play with right hand (end)",
+    "Pluck strings inside piano (Maderna)",
+    "Right pedal pictogram",
+    "Kievan flat",
+    "Kievan sharp",
+    "Kievan augmentation dot",
+    "Kievan C clef (tse-fa-ut)",
+    "Kievan ending symbol",
+    "Kievan eighth note, stem down",
+    "Kievan eighth note, stem up",
+    "Kievan beam",
+    "Kievan half note (on staff line)",
+    "Kievan half note (in staff space)",
+    "Kievan quarter note, stem down",
+    "Kievan quarter note, stem up",
+    "Kievan reciting note",
+    "Kievan whole note",
+    "Kievan final whole note",
+    "Do hand sign",
+    "Fa hand sign",
+    "La hand sign",
+    "Mi hand sign",
+    "Re hand sign",
+    "So hand sign",
+    "Ti hand sign",
+    "Left repeat sign within bar",
+    "Leger line",
+    "Leger line (narrow)",
+    "Leger line (wide)",
+    "Lute tablature end repeat barline",
+    "Lute tablature final barline",
+    "Lute tablature start repeat barline",
+    "16th note (semiquaver) duration sign",
+    "32nd note (demisemiquaver) duration sign",
+    "Eighth note (quaver) duration sign",
+    "Double whole note (breve) duration sign",
+    "Half note (minim) duration sign",
+    "Quarter note (crotchet) duration sign",
+    "Whole note (semibreve) duration sign",
+    "Right-hand fingering, first finger",
+    "Right-hand fingering, second finger",
+    "Right-hand fingering, third finger",
+    "Right-hand fingering, thumb",
+    "10th course (diapason)",
+    "Seventh course (diapason)",
+    "Eighth course (diapason)",
+    "Ninth course (diapason)",
+    "Appoggiatura from above",
+    "Appoggiatura from below",
+    "Open string (a)",
+    "First fret (b)",
+    "Second fret (c)",
+    "Third fret (d)",
+    "Fourth fret (e)",
+    "Fifth fret (f)",
+    "Sixth fret (g)",
+    "Seventh fret (h)",
+    "Eighth fret (i)",
+    "Ninth fret (k)",
+    "10th fret (l)",
+    "11th fret (m)",
+    "12th fret (n)",
+    "Inverted mordent",
+    "Mordent with lower auxiliary",
+    "Mordent with upper auxiliary",
+    "5th course, 1st fret (a)",
+    "6th course, 1st fret (A)",
+    "4th course, 1st fret (b)",
+    "6th course, 2nd fret (B)",
+    "3rd course, 1st fret (c)",
+    "6th course, 3rd fret (C)",
+    "2nd course, 1st fret (d)",
+    "6th course, 4th fret (D)",
+    "1st course, 1st fret (e)",
+    "6th course, 5th fret (E)",
+    "5th course, 2nd fret (f)",
+    "6th course, 6th fret (F)",
+    "4th course, 2nd fret (g)",
+    "6th course, 7th fret (G)",
+    "3rd course, 2nd fret (h)",
+    "6th course, 8th fret (H)",
+    "2nd course, 2nd fret (i)",
+    "6th course, 9th fret (I)",
+    "1st course, 2nd fret (k)",
+    "6th course, 10th fret (K)",
+    "5th course, 3rd fret (l)",
+    "6th course, 11th fret (L)",
+    "4th course, 3rd fret (m)",
+    "6th course, 12th fret (M)",
+    "3rd course, 3rd fret (n)",
+    "6th course, 13th fret (N)",
+    "2nd course, 3rd fret (o)",
+    "1st course, 3rd fret (p)",
+    "5th course, 4th fret (q)",
+    "4th course, 4th fret (r)",
+    "3rd course, 4th fret (s)",
+    "2nd course, 4th fret (t)",
+    "1st course, 4th fret (v)",
+    "5th course, 5th fret (x)",
+    "4th course, 5th fret (y)",
+    "3rd course, 5th fret (z)",
+    "C sol fa ut clef",
+    "F fa ut clef",
+    "Open string (0)",
+    "First fret (1)",
+    "Second fret (2)",
+    "Third fret (3)",
+    "Fourth fret (4)",
+    "Fifth fret (5)",
+    "Sixth fret (6)",
+    "Seventh fret (7)",
+    "Eighth fret (8)",
+    "Ninth fret (9)",
+    "Hold finger in place",
+    "Hold note",
+    "Release finger",
+    "Fast tempo indication (de Mudarra)",
+    "Neither fast nor slow tempo indication (de Mudarra)",
+    "Slow tempo indication (de Mudarra)",
+    "Somewhat fast tempo indication (de Narvaez)",
+    "Very slow indication (de Narvaez)",
+    "Triple time indication",
+    "Single-finger tremolo or mordent",
+    "Vibrato (verre cassé)",
+    "Lute tablature staff, 6 courses",
+    "Lute tablature staff, 6 courses (narrow)",
+    "Lute tablature staff, 6 courses (wide)",
+    "Elision",
+    "Narrow elision",
+    "Wide elision",
+    "Baseline hyphen",
+    "Non-breaking baseline hyphen",
+    "Text repeats",
+    "Flat, hard b (mi)",
+    "Flat, soft b (fa)",
+    "Flat with dot",
+    "G clef (Corpus Monodicum)",
+    "Liquescence",
+    "Liquescent ascending (Corpus Monodicum)",
+    "Liquescent descending (Corpus Monodicum)",
+    "Natural",
+    "Natural with interrupted cross",
+    "Oriscus (Corpus Monodicum)",
+    "Plica",
+    "Punctum (Corpus Monodicum)",
+    "Quilisma (Corpus Monodicum)",
+    "Croix",
+    "Strophicus (Corpus Monodicum)",
+    "Alteration sign",
+    "Black mensural brevis",
+    "Black mensural void brevis",
+    "Black mensural dragma",
+    "Black mensural longa",
+    "Black mensural maxima",
+    "Black mensural minima",
+    "Black mensural void minima",
+    "Black mensural semibrevis",
+    "Black mensural semibrevis caudata",
+    "Black mensural oblique semibrevis",
+    "Black mensural void semibrevis",
+    "Black mensural semiminima",
+    "Mensural C clef",
+    "Petrucci C clef, high position",
+    "Petrucci C clef, highest position",
+    "Petrucci C clef, low position",
+    "Petrucci C clef, lowest position",
+    "Petrucci C clef, middle position",
+    "Coloration end, round",
+    "Coloration end, square",
+    "Coloration start, round",
+    "Coloration start, square",
+    "Combining stem diagonal",
+    "Combining stem down",
+    "Combining stem with extended flag down",
+    "Combining stem with flared flag down",
+    "Combining stem with fusa flag down",
+    "Combining stem with flag left down",
+    "Combining stem with flag right down",
+    "Combining stem with semiminima flag down",
+    "Combining stem up",
+    "Combining stem with extended flag up",
+    "Combining stem with flared flag up",
+    "Combining stem with fusa flag up",
+    "Combining stem with flag left up",
+    "Combining stem with flag right up",
+    "Combining stem with semiminima flag up",
+    "Checkmark custos",
+    "Mensural custos down",
+    "Turn-like custos",
+    "Mensural custos up",
+    "Mensural F clef",
+    "Petrucci F clef",
+    "Mensural G clef",
+    "Petrucci G clef",
+    "Modus imperfectum, vertical",
+    "Modus perfectum, vertical",
+    "Longa/brevis notehead, black",
+    "Longa/brevis notehead, black and void",
+    "Longa/brevis notehead, void",
+    "Longa/brevis notehead, white",
+    "Maxima notehead, black",
+    "Maxima notehead, black and void",
+    "Maxima notehead, void",
+    "Maxima notehead, white",
+    "Minima notehead, white",
+    "Semibrevis notehead, black",
+    "Semibrevis notehead, black and void",
+    "Semibrevis notehead, black and void (turned)",
+    "Semibrevis notehead, void",
+    "Semiminima/fusa notehead, white",
+    "Oblique form, ascending 2nd, black",
+    "Oblique form, ascending 2nd, black and void",
+    "Oblique form, ascending 2nd, void",
+    "Oblique form, ascending 2nd, white",
+    "Oblique form, ascending 3rd, black",
+    "Oblique form, ascending 3rd, black and void",
+    "Oblique form, ascending 3rd, void",
+    "Oblique form, ascending 3rd, white",
+    "Oblique form, ascending 4th, black",
+    "Oblique form, ascending 4th, black and void",
+    "Oblique form, ascending 4th, void",
+    "Oblique form, ascending 4th, white",
+    "Oblique form, ascending 5th, black",
+    "Oblique form, ascending 5th, black and void",
+    "Oblique form, ascending 5th, void",
+    "Oblique form, ascending 5th, white",
+    "Oblique form, descending 2nd, black",
+    "Oblique form, descending 2nd, black and void",
+    "Oblique form, descending 2nd, void",
+    "Oblique form, descending 2nd, white",
+    "Oblique form, descending 3rd, black",
+    "Oblique form, descending 3rd, black and void",
+    "Oblique form, descending 3rd, void",
+    "Oblique form, descending 3rd, white",
+    "Oblique form, descending 4th, black",
+    "Oblique form, descending 4th, black and void",
+    "Oblique form, descending 4th, void",
+    "Oblique form, descending 4th, white",
+    "Oblique form, descending 5th, black",
+    "Oblique form, descending 5th, black and void",
+    "Oblique form, descending 5th, void",
+    "Oblique form, descending 5th, white",
+    "Tempus perfectum cum prolatione perfecta (9/8)",
+    "Tempus imperfectum cum prolatione imperfecta diminution 4",
+    "Tempus imperfectum cum prolatione imperfecta diminution 5",
+    "Tempus perfectum cum prolatione imperfecta (3/4)",
+    "Tempus perfectum cum prolatione imperfecta diminution 1 (3/8)",
+    "Tempus perfectum cum prolatione perfecta diminution 2 (9/16)",
+    "Tempus imperfectum cum prolatione perfecta (6/8)",
+    "Tempus imperfectum cum prolatione imperfecta (2/4)",
+    "Tempus imperfectum cum prolatione imperfecta diminution 1 (2/2)",
+    "Tempus imperfectum cum prolatione imperfecta diminution 2 (6/16)",
+    "Tempus imperfectum cum prolatione imperfecta diminution 3 (2/2)",
+    "Combining dot",
+    "Combining void dot",
+    "Combining vertical stroke",
+    "Combining three dots horizontal",
+    "Combining three dots triangular",
+    "Combining two dots",
+    "Mensural proportion 1",
+    "Mensural proportion 2",
+    "Mensural proportion 3",
+    "Mensural proportion 4",
+    "Mensural proportion 5",
+    "Mensural proportion 6",
+    "Mensural proportion 7",
+    "Mensural proportion 8",
+    "Mensural proportion 9",
+    "Mensural proportion major",
+    "Mensural proportion minor",
+    "Proportio dupla 1",
+    "Proportio dupla 2",
+    "Proportio quadrupla",
+    "Proportio tripla",
+    "Tempus perfectum",
+    "Brevis rest",
+    "Fusa rest",
+    "Longa imperfecta rest",
+    "Longa perfecta rest",
+    "Maxima rest",
+    "Minima rest",
+    "Semibrevis rest",
+    "Semifusa rest",
+    "Semiminima rest",
+    "Signum congruentiae down",
+    "Signum congruentiae up",
+    "Tempus imperfectum, horizontal",
+    "Tempus perfectum, horizontal",
+    "White mensural brevis",
+    "White mensural fusa",
+    "White mensural longa",
+    "White mensural maxima",
+    "White mensural minima",
+    "White mensural semibrevis",
+    "White mensural semiminima",
+    "Augmentation dot",
+    "1024th note (semihemidemisemihemidemisemiquaver) stem down",
+    "1024th note (semihemidemisemihemidemisemiquaver) stem up",
+    "128th note (semihemidemisemiquaver) stem down",
+    "128th note (semihemidemisemiquaver) stem up",
+    "16th note (semiquaver) stem down",
+    "16th note (semiquaver) stem up",
+    "256th note (demisemihemidemisemiquaver) stem down",
+    "256th note (demisemihemidemisemiquaver) stem up",
+    "32nd note (demisemiquaver) stem down",
+    "32nd note (demisemiquaver) stem up",
+    "512th note (hemidemisemihemidemisemiquaver) stem down",
+    "512th note (hemidemisemihemidemisemiquaver) stem up",
+    "64th note (hemidemisemiquaver) stem down",
+    "64th note (hemidemisemiquaver) stem up",
+    "Eighth note (quaver) stem down",
+    "Eighth note (quaver) stem up",
+    "Double whole note (breve)",
+    "Double whole note (square)",
+    "Half note (minim) stem down",
+    "Half note (minim) stem up",
+    "Quarter note (crotchet) stem down",
+    "Quarter note (crotchet) stem up",
+    "Whole note (semibreve)",
+    "Left-pointing arrow for metric modulation",
+    "Right-pointing arrow for metric modulation",
+    "Do not copy",
+    "Do not photocopy",
+    "Eyeglasses",
+    "1024th note (semihemidemisemihemidemisemiquaver) stem down",
+    "1024th note (semihemidemisemihemidemisemiquaver) stem up",
+    "128th note (semihemidemisemiquaver) stem down",
+    "128th note (semihemidemisemiquaver) stem up",
+    "16th note (semiquaver) stem down",
+    "16th note (semiquaver) stem up",
+    "256th note (demisemihemidemisemiquaver) stem down",
+    "256th note (demisemihemidemisemiquaver) stem up",
+    "32nd note (demisemiquaver) stem down",
+    "32nd note (demisemiquaver) stem up",
+    "512th note (hemidemisemihemidemisemiquaver) stem down",
+    "512th note (hemidemisemihemidemisemiquaver) stem up",
+    "64th note (hemidemisemiquaver) stem down",
+    "64th note (hemidemisemiquaver) stem up",
+    "Eighth note (quaver) stem down",
+    "Eighth note (quaver) stem up",
+    "A (black note)",
+    "A flat (black note)",
+    "A flat (half note)",
+    "A flat (whole note)",
+    "A (half note)",
+    "A sharp (black note)",
+    "A sharp (half note)",
+    "A sharp (whole note)",
+    "A (whole note)",
+    "B (black note)",
+    "B flat (black note)",
+    "B flat (half note)",
+    "B flat (whole note)",
+    "B (half note)",
+    "B sharp (black note)",
+    "B sharp (half note)",
+    "B sharp (whole note)",
+    "B (whole note)",
+    "C (black note)",
+    "C flat (black note)",
+    "C flat (half note)",
+    "C flat (whole note)",
+    "C (half note)",
+    "C sharp (black note)",
+    "C sharp (half note)",
+    "C sharp (whole note)",
+    "C (whole note)",
+    "D (black note)",
+    "D flat (black note)",
+    "D flat (half note)",
+    "D flat (whole note)",
+    "D (half note)",
+    "D sharp (black note)",
+    "D sharp (half note)",
+    "D sharp (whole note)",
+    "D (whole note)",
+    "Di (black note)",
+    "Di (half note)",
+    "Di (whole note)",
+    "Do (black note)",
+    "Do (half note)",
+    "Do (whole note)",
+    "Double whole note (breve)",
+    "Double whole note (square)",
+    "E (black note)",
+    "E flat (black note)",
+    "E flat (half note)",
+    "E flat (whole note)",
+    "E (half note)",
+    "E sharp (black note)",
+    "E sharp (half note)",
+    "E sharp (whole note)",
+    "E (whole note)",
+    "Empty black note",
+    "Empty half note",
+    "Empty whole note",
+    "F (black note)",
+    "F flat (black note)",
+    "F flat (half note)",
+    "F flat (whole note)",
+    "F (half note)",
+    "F sharp (black note)",
+    "F sharp (half note)",
+    "F sharp (whole note)",
+    "F (whole note)",
+    "Fa (black note)",
+    "Fa (half note)",
+    "Fa (whole note)",
+    "Fi (black note)",
+    "Fi (half note)",
+    "Fi (whole note)",
+    "G (black note)",
+    "G flat (black note)",
+    "G flat (half note)",
+    "G flat (whole note)",
+    "G (half note)",
+    "G sharp (black note)",
+    "G sharp (half note)",
+    "G sharp (whole note)",
+    "G (whole note)",
+    "H (black note)",
+    "H (half note)",
+    "H sharp (black note)",
+    "H sharp (half note)",
+    "H sharp (whole note)",
+    "H (whole note)",
+    "Half note (minim) stem down",
+    "Half note (minim) stem up",
+    "La (black note)",
+    "La (half note)",
+    "La (whole note)",
+    "Le (black note)",
+    "Le (half note)",
+    "Le (whole note)",
+    "Li (black note)",
+    "Li (half note)",
+    "Li (whole note)",
+    "Me (black note)",
+    "Me (half note)",
+    "Me (whole note)",
+    "Mi (black note)",
+    "Mi (half note)",
+    "Mi (whole note)",
+    "Quarter note (crotchet) stem down",
+    "Quarter note (crotchet) stem up",
+    "Ra (black note)",
+    "Ra (half note)",
+    "Ra (whole note)",
+    "Re (black note)",
+    "Re (half note)",
+    "Re (whole note)",
+    "Ri (black note)",
+    "Ri (half note)",
+    "Ri (whole note)",
+    "Se (black note)",
+    "Se (half note)",
+    "Se (whole note)",
+    "Arrowhead left black (Funk 7-shape re)",
+    "Arrowhead left double whole (Funk 7-shape re)",
+    "Arrowhead left white (Funk 7-shape re)",
+    "Diamond black (4-shape mi; 7-shape mi)",
+    "Diamond double whole (4-shape mi; 7-shape mi)",
+    "Diamond white (4-shape mi; 7-shape mi)",
+    "Isosceles triangle black (Walker 7-shape ti)",
+    "Isosceles triangle double whole (Walker 7-shape ti)",
+    "Isosceles triangle white (Walker 7-shape ti)",
+    "Inverted keystone black (Walker 7-shape do)",
+    "Inverted keystone double whole (Walker 7-shape do)",
+    "Inverted keystone white (Walker 7-shape do)",
+    "Moon black (Aikin 7-shape re)",
+    "Moon double whole (Aikin 7-shape re)",
+    "Moon left black (Funk 7-shape do)",
+    "Moon left double whole (Funk 7-shape do)",
+    "Moon left white (Funk 7-shape do)",
+    "Moon white (Aikin 7-shape re)",
+    "Quarter moon black (Walker 7-shape re)",
+    "Quarter moon double whole (Walker 7-shape re)",
+    "Quarter moon white (Walker 7-shape re)",
+    "Round black (4-shape sol; 7-shape so)",
+    "Round double whole (4-shape sol; 7-shape so)",
+    "Round white (4-shape sol; 7-shape so)",
+    "Square black (4-shape la; Aikin 7-shape la)",
+    "Square double whole (4-shape la; Aikin 7-shape la)",
+    "Square white (4-shape la; Aikin 7-shape la)",
+    "Triangle left black (stem up; 4-shape fa; 7-shape fa)",
+    "Triangle left double whole (stem up; 4-shape fa; 7-shape fa)",
+    "Triangle left white (stem up; 4-shape fa; 7-shape fa)",
+    "Triangle right black (stem down; 4-shape fa; 7-shape fa)",
+    "Triangle right double whole (stem down; 4-shape fa; 7-shape fa)",
+    "Triangle right white (stem down; 4-shape fa; 7-shape fa)",
+    "Triangle-round black (Aikin 7-shape ti)",
+    "Triangle-round white (Aikin 7-shape ti)",
+    "Triangle-round left black (Funk 7-shape ti)",
+    "Triangle-round left double whole (Funk 7-shape ti)",
+    "Triangle-round left white (Funk 7-shape ti)",
+    "Triangle-round white (Aikin 7-shape ti)",
+    "Triangle up black (Aikin 7-shape do)",
+    "Triangle up double whole (Aikin 7-shape do)",
+    "Triangle up white (Aikin 7-shape do)",
+    "Si (black note)",
+    "Si (half note)",
+    "Si (whole note)",
+    "So (black note)",
+    "So (half note)",
+    "So (whole note)",
+    "Te (black note)",
+    "Te (half note)",
+    "Te (whole note)",
+    "Ti (black note)",
+    "Ti (half note)",
+    "Ti (whole note)",
+    "Whole note (semibreve)",
+    "Black notehead",
+    "Circle slash notehead",
+    "Circle X notehead",
+    "Circle X double whole",
+    "Circle X half",
+    "Circle X whole",
+    "Circled black notehead",
+    "Black notehead in large circle",
+    "Circled double whole notehead",
+    "Double whole notehead in large circle",
+    "Circled half notehead",
+    "Half notehead in large circle",
+    "Circled whole notehead",
+    "Whole notehead in large circle",
+    "Cross notehead in large circle",
+    "Double whole note cluster, 2nd",
+    "Double whole note cluster, 3rd",
+    "Combining double whole note cluster, bottom",
+    "Combining double whole note cluster, middle",
+    "Combining double whole note cluster, top",
+    "Half note cluster, 2nd",
+    "Half note cluster, 3rd",
+    "Combining half note cluster, bottom",
+    "Combining half note cluster, middle",
+    "Combining half note cluster, top",
+    "Quarter note cluster, 2nd",
+    "Quarter note cluster, 3rd",
+    "Combining quarter note cluster, bottom",
+    "Combining quarter note cluster, middle",
+    "Combining quarter note cluster, top",
+    "Cluster notehead black (round)",
+    "Cluster notehead white (round)",
+    "Cluster notehead black (square)",
+    "Cluster notehead white (square)",
+    "Whole note cluster, 2nd",
+    "Whole note cluster, 3rd",
+    "Combining whole note cluster, bottom",
+    "Combining whole note cluster, middle",
+    "Combining whole note cluster, top",
+    "4/11 note (eleventh note series, Cowell)",
+    "8/11 note (eleventh note series, Cowell)",
+    "2/11 note (eleventh note series, Cowell)",
+    "2/15 note (fifteenth note series, Cowell)",
+    "4/15 note (fifteenth note series, Cowell)",
+    "8/15 note (fifteenth note series, Cowell)",
+    "1/5 note (fifth note series, Cowell)",
+    "2/5 note (fifth note series, Cowell)",
+    "4/5 note (fifth note series, Cowell)",
+    "2/9 note (ninth note series, Cowell)",
+    "4/9 note (ninth note series, Cowell)",
+    "8/9 note (ninth note series, Cowell)",
+    "1/7 note (seventh note series, Cowell)",
+    "2/7 note (seventh note series, Cowell)",
+    "4/7 note (seventh note series, Cowell)",
+    "1/6 note (third note series, Cowell)",
+    "1/3 note (third note series, Cowell)",
+    "2/3 note (third note series, Cowell)",
+    "2/13 note (thirteenth note series, Cowell)",
+    "4/13 note (thirteenth note series, Cowell)",
+    "8/13 note (thirteenth note series, Cowell)",
+    "Diamond black notehead",
+    "Diamond black notehead (old)",
+    "Diamond black notehead (wide)",
+    "Black diamond cluster, 2nd",
+    "Black diamond cluster, 3rd",
+    "Combining black diamond cluster, bottom",
+    "Combining black diamond cluster, middle",
+    "Combining black diamond cluster, top",
+    "White diamond cluster, 2nd",
+    "White diamond cluster, 3rd",
+    "Combining white diamond cluster, bottom",
+    "Combining white diamond cluster, middle",
+    "Combining white diamond cluster, top",
+    "Diamond double whole notehead",
+    "Diamond double whole notehead (old)",
+    "Diamond half notehead",
+    "Half-filled diamond notehead",
+    "Diamond half notehead (old)",
+    "Diamond half notehead (wide)",
+    "Open diamond notehead",
+    "Diamond white notehead",
+    "Diamond white notehead (wide)",
+    "Diamond whole notehead",
+    "Diamond whole notehead (old)",
+    "Double whole (breve) notehead",
+    "Double whole (breve) notehead (square)",
+    "Double whole notehead with X",
+    "Half (minim) notehead",
+    "Filled half (minim) notehead",
+    "Half notehead with X",
+    "Heavy X notehead",
+    "Heavy X with hat notehead",
+    "Large arrow down (lowest pitch) black notehead",
+    "Large arrow down (lowest pitch) double whole notehead",
+    "Large arrow down (lowest pitch) half notehead",
+    "Large arrow down (lowest pitch) whole notehead",
+    "Large arrow up (highest pitch) black notehead",
+    "Large arrow up (highest pitch) double whole notehead",
+    "Large arrow up (highest pitch) half notehead",
+    "Large arrow up (highest pitch) whole notehead",
+    "Moon notehead black",
+    "Moon notehead white",
+    "Sine notehead (Nancarrow)",
+    "Null notehead",
+    "Parenthesis notehead",
+    "Opening parenthesis",
+    "Closing parenthesis",
+    "Plus notehead black",
+    "Plus notehead double whole",
+    "Plus notehead half",
+    "Plus notehead whole",
+    "Combining black rectangular cluster, bottom",
+    "Combining black rectangular cluster, middle",
+    "Combining black rectangular cluster, top",
+    "Combining white rectangular cluster, bottom",
+    "Combining white rectangular cluster, middle",
+    "Combining white rectangular cluster, top",
+    "Round black notehead",
+    "Round black notehead, double slashed",
+    "Large round black notehead",
+    "Round black notehead, slashed",
+    "Large round black notehead, slashed",
+    "Round white notehead",
+    "Round white notehead, double slashed",
+    "Large round white notehead",
+    "Round white notehead, slashed",
+    "Large round white notehead, slashed",
+    "Round white notehead with dot",
+    "Large round white notehead with dot",
+    "Large white diamond",
+    "Slash with horizontal ends",
+    "Muted slash with horizontal ends",
+    "Slash with vertical ends",
+    "Muted slash with vertical ends",
+    "Small slash with vertical ends",
+    "White slash double whole",
+    "White slash half",
+    "Muted white slash",
+    "White slash whole",
+    "Large X notehead",
+    "Slashed black notehead (bottom left to top right)",
+    "Slashed black notehead (top left to bottom right)",
+    "Slashed double whole notehead (bottom left to top right)",
+    "Slashed double whole notehead (top left to bottom right)",
+    "Slashed half notehead (bottom left to top right)",
+    "Slashed half notehead (top left to bottom right)",
+    "Slashed whole notehead (bottom left to top right)",
+    "Slashed whole notehead (top left to bottom right)",
+    "Square notehead black",
+    "Large square black notehead",
+    "Large square white notehead",
+    "Square notehead white",
+    "Triangle notehead down black",
+    "Triangle notehead down double whole",
+    "Triangle notehead down half",
+    "Triangle notehead down white",
+    "Triangle notehead down whole",
+    "Triangle notehead left black",
+    "Triangle notehead left white",
+    "Triangle notehead right black",
+    "Triangle notehead right white",
+    "Triangle-round notehead down black",
+    "Triangle-round notehead down white",
+    "Triangle notehead up black",
+    "Triangle notehead up double whole",
+    "Triangle notehead up half",
+    "Triangle notehead up right black",
+    "Triangle notehead up right white",
+    "Triangle notehead up white",
+    "Triangle notehead up whole",
+    "Void notehead with X",
+    "Whole (semibreve) notehead",
+    "Filled whole (semibreve) notehead",
+    "Whole notehead with X",
+    "X notehead black",
+    "X notehead double whole",
+    "X notehead half",
+    "Ornate X notehead",
+    "Ornate X notehead in ellipse",
+    "X notehead whole",
+    "a (baseline)",
+    "b (baseline)",
+    "m (baseline)",
+    "v (baseline)",
+    "Bassa",
+    "Loco",
+    "Left parenthesis for octave signs",
+    "Right parenthesis for octave signs",
+    "a (superscript)",
+    "b (superscript)",
+    "m (superscript)",
+    "v (superscript)",
+    "One-handed roll (Stevens)",
+    "Two Fusae",
+    "Two Minimae",
+    "Combining double octave line above",
+    "Two Semifusae",
+    "Two Semiminimae",
+    "Three Fusae",
+    "Three Minimae",
+    "Three Semifusae",
+    "Three Semiminimae",
+    "Four Fusae",
+    "Four Minimae",
+    "Four Semifusae",
+    "Four Semiminimae",
+    "Five Fusae",
+    "Five Minimae",
+    "Five Semifusae",
+    "Five Semiminimae",
+    "Six Fusae",
+    "Six Minimae",
+    "Six Semifusae",
+    "Six Semiminimae",
+    "German organ tablature small A",
+    "German organ tablature great A",
+    "Rhythm Dot",
+    "German organ tablature small B",
+    "German organ tablature great B",
+    "Brevis (Binary) Buxheimer Orgelbuch",
+    "Brevis (Ternary) Buxheimer Orgelbuch",
+    "Minima Rest Buxheimer Orgelbuch",
+    "Semibrevis Buxheimer Orgelbuch",
+    "Semibrevis Rest Buxheimer Orgelbuch",
+    "German organ tablature small C",
+    "German organ tablature great C",
+    "German organ tablature small Cis",
+    "German organ tablature great Cis",
+    "German organ tablature small D",
+    "German organ tablature great D",
+    "German organ tablature small Dis",
+    "German organ tablature great Dis",
+    "German organ tablature small E",
+    "German organ tablature great E",
+    "German organ tablature small F",
+    "German organ tablature great F",
+    "German organ tablature small Fis",
+    "German organ tablature great Fis",
+    "Fusa",
+    "Fusa Rest",
+    "German organ tablature small G",
+    "German organ tablature great G",
+    "German organ tablature small Gis",
+    "German organ tablature great Gis",
+    "German organ tablature small H",
+    "German organ tablature great H",
+    "Minima",
+    "Minima Rest",
+    "Combining single octave line below",
+    "Combining single octave line above",
+    "Semibrevis",
+    "Semibrevis Rest",
+    "Semifusa",
+    "Semifusa Rest",
+    "Semiminima",
+    "Semiminima Rest",
+    "Tie",
+    "Ornament bottom left concave stroke",
+    "Ornament bottom left concave stroke, large",
+    "Ornament bottom left convex stroke",
+    "Ornament bottom right concave stroke",
+    "Ornament bottom right convex stroke",
+    "Comma",
+    "Double oblique straight lines NW-SE",
+    "Double oblique straight lines SW-NE",
+    "Curve below",
+    "Haydn ornament",
+    "Ornament high left concave stroke",
+    "Ornament high left convex stroke",
+    "Ornament high right concave stroke",
+    "Ornament high right convex stroke",
+    "Hook after note",
+    "Hook before note",
+    "Left-facing half circle",
+    "Left-facing hook",
+    "Ornament left +",
+    "Ornament left shake t",
+    "Ornament left vertical stroke",
+    "Ornament left vertical stroke with cross (+)",
+    "Ornament low left concave stroke",
+    "Ornament low left convex stroke",
+    "Ornament low right concave stroke",
+    "Ornament low right convex stroke",
+    "Ornament middle vertical stroke",
+    "Mordent",
+    "Oblique straight line NW-SE",
+    "Oblique straight line SW-NE",
+    "Oblique straight line tilted NW-SE",
+    "Oblique straight line tilted SW-NE",
+    "Oriscus",
+    "Pincé (Couperin)",
+    "Port de voix",
+    "Supported appoggiatura trill",
+    "Supported appoggiatura trill with two-note suffix",
+    "Cadence",
+    "Cadence with upper prefix",
+    "Cadence with upper prefix and turn",
+    "Cadence with turn",
+    "Descending slide",
+    "Double cadence with lower prefix",
+    "Double cadence with upper prefix",
+    "Double cadence with upper prefix and turn",
+    "Inverted mordent with upper prefix",
+    "Mordent with release",
+    "Mordent with upper prefix",
+    "Pre-beat port de voix followed by multiple mordent (Dandrieu)",
+    "Slide",
+    "Slide-trill with two-note suffix (J.S. Bach)",
+    "Slide-trill (D'Anglebert)",
+    "Slide-trill with one-note suffix (Marpurg)",
+    "Slide-trill (Muffat)",
+    "Slide-trill with two-note suffix (Muffat)",
+    "Trill with lower suffix",
+    "Trill with two-note suffix (Dandrieu)",
+    "Trill with mordent",
+    "Turn-trill with two-note suffix (J.S. Bach)",
+    "Turn-trill (D'Anglebert)",
+    "Quilisma",
+    "Right-facing half circle",
+    "Right-facing hook",
+    "Ornament right vertical stroke",
+    "Schleifer (long mordent)",
+    "Shake",
+    "Shake (Muffat)",
+    "Short oblique straight line NW-SE",
+    "Short oblique straight line SW-NE",
+    "Short trill",
+    "Ornament top left concave stroke",
+    "Ornament top left convex stroke",
+    "Ornament top right concave stroke",
+    "Ornament top right convex stroke",
+    "Tremblement",
+    "Tremblement appuyé (Couperin)",
+    "Trill",
+    "Turn",
+    "Inverted turn",
+    "Turn with slash",
+    "Turn up",
+    "Inverted turn up",
+    "Curve above",
+    "Vertical line",
+    "Ornament zig-zag line without right-hand end",
+    "Ornament zig-zag line with right-hand end",
+    "Ottava",
+    "Ottava alta",
+    "Ottava bassa",
+    "Ottava bassa (ba)",
+    "Ottava bassa (8vb)",
+    "Penderecki unmeasured tremolo",
+    "Agogo",
+    "Almglocken",
+    "Anvil",
+    "Bamboo tube chimes",
+    "Bamboo scraper",
+    "Bass drum",
+    "Bass drum on side",
+    "Bow",
+    "Box for percussion beater",
+    "Brass mallets down",
+    "Brass mallets left",
+    "Brass mallets right",
+    "Brass mallets up",
+    "Combining dashed circle for round beaters (plated)",
+    "Combining parentheses for round beaters (padded)",
+    "Double bass drum stick down",
+    "Double bass drum stick up",
+    "Finger",
+    "Fingernails",
+    "Fist",
+    "Guiro scraper",
+    "Hammer",
+    "Metal hammer, down",
+    "Metal hammer, up",
+    "Plastic hammer, down",
+    "Plastic hammer, up",
+    "Wooden hammer, down",
+    "Wooden hammer, up",
+    "Hand",
+    "Hard bass drum stick down",
+    "Hard bass drum stick up",
+    "Hard glockenspiel stick down",
+    "Hard glockenspiel stick left",
+    "Hard glockenspiel stick right",
+    "Hard glockenspiel stick up",
+    "Hard timpani stick down",
+    "Hard timpani stick left",
+    "Hard timpani stick right",
+    "Hard timpani stick up",
+    "Hard xylophone stick down",
+    "Hard xylophone stick left",
+    "Hard xylophone stick right",
+    "Hard xylophone stick up",
+    "Hard yarn beater down",
+    "Hard yarn beater left",
+    "Hard yarn beater right",
+    "Hard yarn beater up",
+    "Jazz sticks down",
+    "Jazz sticks up",
+    "Knitting needle",
+    "Chime hammer up",
+    "Chime hammer down",
+    "Medium bass drum stick down",
+    "Medium bass drum stick up",
+    "Medium timpani stick down",
+    "Medium timpani stick left",
+    "Medium timpani stick right",
+    "Medium timpani stick up",
+    "Medium xylophone stick down",
+    "Medium xylophone stick left",
+    "Medium xylophone stick right",
+    "Medium xylophone stick up",
+    "Medium yarn beater down",
+    "Medium yarn beater left",
+    "Medium yarn beater right",
+    "Medium yarn beater up",
+    "Metal bass drum stick down",
+    "Metal bass drum stick up",
+    "Metal beater down",
+    "Metal hammer",
+    "Metal beater, left",
+    "Metal beater, right",
+    "Metal beater, up",
+    "Snare sticks down",
+    "Snare sticks up",
+    "Soft bass drum stick down",
+    "Soft bass drum stick up",
+    "Soft glockenspiel stick down",
+    "Soft glockenspiel stick left",
+    "Soft glockenspiel stick right",
+    "Soft glockenspiel stick up",
+    "Soft timpani stick down",
+    "Soft timpani stick left",
+    "Soft timpani stick right",
+    "Soft timpani stick up",
+    "Soft xylophone beaters",
+    "Soft xylophone stick down",
+    "Soft xylophone stick left",
+    "Soft xylophone stick right",
+    "Soft xylophone stick up",
+    "Soft yarn beater down",
+    "Soft yarn beater left",
+    "Soft yarn beater right",
+    "Soft yarn beater up",
+    "Spoon-shaped wooden mallet",
+    "Superball beater down",
+    "Superball beater left",
+    "Superball beater right",
+    "Superball beater up",
+    "Triangle beater down",
+    "Triangle beater plain",
+    "Triangle beater up",
+    "Wire brushes down",
+    "Wire brushes up",
+    "Wood timpani stick down",
+    "Wood timpani stick left",
+    "Wood timpani stick right",
+    "Wood timpani stick up",
+    "Wood xylophone stick down",
+    "Wood xylophone stick left",
+    "Wood xylophone stick right",
+    "Wood xylophone stick up",
+    "Bell",
+    "Bell of cymbal",
+    "Bell plate",
+    "Bell tree",
+    "Bird whistle",
+    "Board clapper",
+    "Bongos",
+    "Brake drum",
+    "Cabasa",
+    "Cannon",
+    "Car horn",
+    "Castanets",
+    "Castanets with handle",
+    "Celesta",
+    "Cencerro",
+    "Center (Weinberg)",
+    "Center (Ghent)",
+    "Center (Caltabiano)",
+    "Chain rattle",
+    "Chimes",
+    "Chinese cymbal",
+    "Choke (Weinberg)",
+    "Claves",
+    "Coins",
+    "Conga",
+    "Cow bell",
+    "Crash cymbals",
+    "Crotales",
+    "Combining crush for stem",
+    "Cuica",
+    "Cymbal tongs",
+    "Damp",
+    "Damp 2",
+    "Damp 3",
+    "Damp 4",
+    "Combining X for stem (dead note)",
+    "Drum stick",
+    "Duck call",
+    "Edge of cymbal",
+    "Empty trapezoid",
+    "Finger cymbals",
+    "Flexatone",
+    "Football rattle",
+    "Glass harmonica",
+    "Glass harp",
+    "Glass plate chimes",
+    "Glass tube chimes",
+    "Glockenspiel",
+    "Glockenspiel (Smith Brindle)",
+    "Goblet drum (djembe, dumbek)",
+    "Gong",
+    "Gong with button (nipple)",
+    "Guiro",
+    "Hard gum beater, down",
+    "Hard gum beater, left",
+    "Hard gum beater, right",
+    "Hard gum beater, up",
+    "Medium gum beater, down",
+    "Medium gum beater, left",
+    "Medium gum beater, right",
+    "Medium gum beater, up",
+    "Soft gum beater, down",
+    "Soft gum beater, left",
+    "Soft gum beater, right",
+    "Soft gum beater, up",
+    "Half-open",
+    "Half-open 2 (Weinberg)",
+    "Handbell",
+    "Hi-hat",
+    "Hi-hat cymbals on stand",
+    "Jaw harp",
+    "Jingle bells",
+    "Klaxon horn",
+    "Right hand (Agostini)",
+    "Lion's roar",
+    "Lithophone",
+    "Log drum",
+    "Lotus flute",
+    "Marimba",
+    "Marimba (Smith Brindle)",
+    "Maraca",
+    "Maracas",
+    "Megaphone",
+    "Metal plate chimes",
+    "Metal tube chimes",
+    "Musical saw",
+    "Normal position (Caltabiano)",
+    "On rim",
+    "Open",
+    "Closed / rim shot",
+    "Pistol shot",
+    "Police whistle",
+    "Quijada (jawbone)",
+    "Rainstick",
+    "Ratchet",
+    "Reco-reco",
+    "Left hand (Agostini)",
+    "Rim or edge (Weinberg)",
+    "Rim (Ghent)",
+    "Rim (Caltabiano)",
+    "Rim shot for stem",
+    "Sandpaper blocks",
+    "Scrape around rim (counter-clockwise)",
+    "Scrape around rim (clockwise)",
+    "Scrape from center to edge",
+    "Scrape from edge to center",
+    "Shell bells",
+    "Shell chimes",
+    "Siren",
+    "Sistrum",
+    "Sizzle cymbal",
+    "Sleigh bell",
+    "Slide brush on gong",
+    "Slide whistle",
+    "Slit drum",
+    "Snare drum",
+    "Military snare drum",
+    "Snare drum, snares off",
+    "Steel drums",
+    "Stick shot",
+    "Superball",
+    "Suspended cymbal",
+    "Combining swish for stem",
+    "Indian tabla",
+    "Tam-tam",
+    "Tam-tam with beater (Smith Brindle)",
+    "Tambourine",
+    "Temple blocks",
+    "Tenor drum",
+    "Thundersheet",
+    "Timbales",
+    "Timpani",
+    "Tom-tom",
+    "Chinese tom-tom",
+    "Indo-American tom tom",
+    "Japanese tom-tom",
+    "Triangle",
+    "Tubaphone",
+    "Tubular bells",
+    "Combining turn left for stem",
+    "Combining turn left or right for stem",
+    "Combining turn right for stem",
+    "Vibraphone",
+    "Metallophone (vibraphone motor off)",
+    "Vibraphone (Smith Brindle)",
+    "Vibraslap",
+    "Vietnamese hat cymbal",
+    "Whip",
+    "Wind chimes (glass)",
+    "Wind machine",
+    "Wind whistle (or mouth siren)",
+    "Wood block",
+    "Wound beater, hard core down",
+    "Wound beater, hard core left",
+    "Wound beater, hard core right",
+    "Wound beater, hard core up",
+    "Wound beater, soft core down",
+    "Wound beater, soft core left",
+    "Wound beater, soft core right",
+    "Wound beater, soft core up",
+    "Xylophone",
+    "Bass xylophone",
+    "Xylophone (Smith Brindle)",
+    "Tenor xylophone",
+    "Trough tenor xylophone",
+    "Trough xylophone",
+    "Buzz pizzicato",
+    "Damp",
+    "Damp all",
+    "Damp for stem",
+    "Fingernail flick",
+    "Left-hand pizzicato",
+    "Plectrum",
+    "Snap pizzicato above",
+    "Snap pizzicato below",
+    "With fingernails",
+    "Quindicesima",
+    "Quindicesima alta",
+    "Quindicesima bassa",
+    "Quindicesima bassa (mb)",
+    "Repeat last bar",
+    "Repeat last two bars",
+    "Repeat last four bars",
+    "Repeat bar lower dot",
+    "Repeat bar slash",
+    "Repeat bar upper dot",
+    "Single repeat dot",
+    "Repeat dots",
+    "Left (start) repeat sign",
+    "Right (end) repeat sign",
+    "Right and left repeat sign",
+    "1024th rest",
+    "128th (semihemidemisemiquaver) rest",
+    "16th (semiquaver) rest",
+    "256th rest",
+    "32nd (demisemiquaver) rest",
+    "512th rest",
+    "64th (hemidemisemiquaver) rest",
+    "Eighth (quaver) rest",
+    "Double whole (breve) rest",
+    "Double whole rest on leger lines",
+    "Multiple measure rest",
+    "H-bar, left half",
+    "H-bar, middle",
+    "H-bar, right half",
+    "Half (minim) rest",
+    "Half rest on leger line",
+    "Longa rest",
+    "Maxima rest",
+    "Quarter (crotchet) rest",
+    "Old-style quarter (crotchet) rest",
+    "Z-style quarter (crotchet) rest",
+    "Whole (semibreve) rest",
+    "Whole rest on leger line",
+    "Reversed brace",
+    "Reversed bracket bottom",
+    "Reversed bracket top",
+    "Right repeat sign within bar",
+    "Scale degree 1",
+    "Scale degree 2",
+    "Scale degree 3",
+    "Scale degree 4",
+    "Scale degree 5",
+    "Scale degree 6",
+    "Scale degree 7",
+    "Scale degree 8",
+    "Scale degree 9",
+    "Schäffer clef",
+    "Schäffer F clef to G clef change",
+    "Schäffer G clef to F clef change",
+    "Schäffer previous clef",
+    "Segno",
+    "Segno (serpent)",
+    "Segno (serpent with vertical lines)",
+    "Semi-pitched percussion clef 1",
+    "Semi-pitched percussion clef 2",
+    "Flat",
+    "Flat (white)",
+    "Double flat history sign",
+    "Double sharp history sign",
+    "Flat history sign",
+    "Sharp history sign",
+    "Natural (N)",
+    "Sharp stem up",
+    "Sharp stem down",
+    "Sharp (white) stem up",
+    "Sharp (white) stem down",
+    "Split bar divider (bar spans a system break)",
+    "1-line staff",
+    "1-line staff (narrow)",
+    "1-line staff (wide)",
+    "2-line staff",
+    "2-line staff (narrow)",
+    "2-line staff (wide)",
+    "3-line staff",
+    "3-line staff (narrow)",
+    "3-line staff (wide)",
+    "4-line staff",
+    "4-line staff (narrow)",
+    "4-line staff (wide)",
+    "5-line staff",
+    "5-line staff (narrow)",
+    "5-line staff (wide)",
+    "6-line staff",
+    "6-line staff (narrow)",
+    "6-line staff (wide)",
+    "Staff divide arrow down",
+    "Staff divide arrow up",
+    "Staff divide arrows",
+    "Lower 1 staff position",
+    "Lower 2 staff positions",
+    "Lower 3 staff positions",
+    "Lower 4 staff positions",
+    "Lower 5 staff positions",
+    "Lower 6 staff positions",
+    "Lower 7 staff positions",
+    "Lower 8 staff positions",
+    "Raise 1 staff position",
+    "Raise 2 staff positions",
+    "Raise 3 staff positions",
+    "Raise 4 staff positions",
+    "Raise 5 staff positions",
+    "Raise 6 staff positions",
+    "Raise 7 staff positions",
+    "Raise 8 staff positions",
+    "Combining stem",
+    "Combining bow on bridge stem",
+    "Combining bow on tailpiece stem",
+    "Combining buzz roll stem",
+    "Combining damp stem",
+    "Combining harp string noise stem",
+    "Combining multiphonics (black) stem",
+    "Combining multiphonics (black and white) stem",
+    "Combining multiphonics (white) stem",
+    "Combining Penderecki unmeasured tremolo stem",
+    "Combining rim shot stem",
+    "Combining sprechgesang stem",
+    "Combining sul ponticello (bow behind bridge) stem",
+    "Combining sussurando stem",
+    "Combining swished stem",
+    "Combining vibrato pulse accent (Saunders) stem",
+    "Stockhausen irregular tremolo (\"Morsen\", like Morse code)",
+    "Bow behind bridge (sul ponticello)",
+    "Bow behind bridge on four strings",
+    "Bow behind bridge on one string",
+    "Bow behind bridge on three strings",
+    "Bow behind bridge on two strings",
+    "Bow on top of bridge",
+    "Bow on tailpiece",
+    "Change bow direction, indeterminate",
+    "Down bow",
+    "Down bow, away from body",
+    "Down bow, beyond bridge",
+    "Down bow, towards body",
+    "Turned down bow",
+    "Fouetté",
+    "Half-harmonic",
+    "Harmonic",
+    "Jeté (gettato) above",
+    "Jeté (gettato) below",
+    "Mute off",
+    "Mute on",
+    "Overpressure, down bow",
+    "Overpressure, no bow direction",
+    "Overpressure possibile, down bow",
+    "Overpressure possibile, up bow",
+    "Overpressure, up bow",
+    "Scrape, circular clockwise",
+    "Scrape, circular counter-clockwise",
+    "Scrape, parallel inward",
+    "Scrape, parallel outward",
+    "Thumb position",
+    "Turned thumb position",
+    "Triple chop, inward",
+    "Triple chop, outward",
+    "Up bow",
+    "Up bow, away from body",
+    "Up bow, beyond bridge",
+    "Up bow, towards body",
+    "Turned up bow",
+    "Vibrato pulse accent (Saunders) for stem",
+    "Swiss rudiments doublé black notehead",
+    "Swiss rudiments flam black notehead",
+    "Swiss rudiments doublé half (minim) notehead",
+    "Swiss rudiments flam half (minim) notehead",
+    "System divider",
+    "Extra long system divider",
+    "Long system divider",
+    "Augmentation dot",
+    "Black note, fractional 16th beam, long stem",
+    "Black note, fractional 16th beam, short stem",
+    "Black note, fractional 32nd beam, long stem",
+    "Black note, fractional 8th beam, long stem",
+    "Black note, fractional 8th beam, short stem",
+    "Black note, long stem",
+    "Black note, short stem",
+    "Continuing 16th beam for long stem",
+    "Continuing 16th beam for short stem",
+    "Continuing 32nd beam for long stem",
+    "Continuing 8th beam for long stem",
+    "Continuing 8th beam for short stem",
+    "Headless black note, fractional 16th beam, long stem",
+    "Headless black note, fractional 16th beam, short stem",
+    "Headless black note, fractional 32nd beam, long stem",
+    "Headless black note, fractional 8th beam, long stem",
+    "Headless black note, fractional 8th beam, short stem",
+    "Headless black note, long stem",
+    "Headless black note, short stem",
+    "Tie",
+    "Tuplet number 3 for long stem",
+    "Tuplet number 3 for short stem",
+    "Tuplet bracket end for long stem",
+    "Tuplet bracket end for short stem",
+    "Tuplet bracket start for long stem",
+    "Tuplet bracket start for short stem",
+    "Time signature 0",
+    "Reversed time signature 0",
+    "Turned time signature 0",
+    "Time signature 1",
+    "Reversed time signature 1",
+    "Turned time signature 1",
+    "Time signature 2",
+    "Reversed time signature 2",
+    "Turned time signature 2",
+    "Time signature 3",
+    "Reversed time signature 3",
+    "Turned time signature 3",
+    "Time signature 4",
+    "Reversed time signature 4",
+    "Turned time signature 4",
+    "Time signature 5",
+    "Reversed time signature 5",
+    "Turned time signature 5",
+    "Time signature 6",
+    "Reversed time signature 6",
+    "Turned time signature 6",
+    "Time signature 7",
+    "Reversed time signature 7",
+    "Turned time signature 7",
+    "Time signature 8",
+    "Reversed time signature 8",
+    "Turned time signature 8",
+    "Time signature 9",
+    "Reversed time signature 9",
+    "Turned time signature 9",
+    "Left bracket for whole time signature",
+    "Left bracket for numerator only",
+    "Right bracket for whole time signature",
+    "Right bracket for numerator only",
+    "Control character for denominator digit",
+    "Control character for numerator digit",
+    "Time signature comma",
+    "Common time",
+    "Reversed common time",
+    "Turned common time",
+    "Cut time (Bach)",
+    "Cut triple time (9/8)",
+    "Cut time",
+    "Reversed cut time",
+    "Turned cut time",
+    "Time signature equals",
+    "Time signature fraction ½",
+    "Time signature fraction ⅓",
+    "Time signature fraction ¼",
+    "Time signature fraction ¾",
+    "Time signature fraction ⅔",
+    "Time signature fraction slash",
+    "Time signature minus",
+    "Time signature multiply",
+    "Open time signature (Penderecki)",
+    "Left parenthesis for whole time signature",
+    "Left parenthesis for numerator only",
+    "Right parenthesis for whole time signature",
+    "Right parenthesis for numerator only",
+    "Time signature +",
+    "Time signature + (for numerators)",
+    "Time signature slash separator",
+    "Open time signature",
+    "Combining tremolo 1",
+    "Combining tremolo 2",
+    "Combining tremolo 3",
+    "Combining tremolo 4",
+    "Combining tremolo 5",
+    "Divide measured tremolo by 2",
+    "Divide measured tremolo by 3",
+    "Divide measured tremolo by 4",
+    "Divide measured tremolo by 6",
+    "Fingered tremolo 1",
+    "Fingered tremolo 2",
+    "Fingered tremolo 3",
+    "Fingered tremolo 4",
+    "Fingered tremolo 5",
+    "Triple-tongue above",
+    "Triple-tongue below",
+    "Tuplet 0",
+    "Tuplet 1",
+    "Tuplet 2",
+    "Tuplet 3",
+    "Tuplet 4",
+    "Tuplet 5",
+    "Tuplet 6",
+    "Tuplet 7",
+    "Tuplet 8",
+    "Tuplet 9",
+    "Tuplet colon",
+    "Wieniawski unmeasured tremolo",
+    "Wieniawski unmeasured tremolo (simpler)",
+    "Unpitched percussion clef 1",
+    "Unpitched percussion clef 2",
+    "Ventiduesima",
+    "Ventiduesima alta",
+    "Ventiduesima bassa",
+    "Ventiduesima bassa (mb)",
+    "Finger click (Stockhausen)",
+    "Halb gesungen (semi-sprechgesang)",
+    "Mouth closed",
+    "Mouth open",
+    "Mouth pursed",
+    "Mouth slightly open",
+    "Mouth wide open",
+    "Nasal voice",
+    "Sprechgesang",
+    "Tongue click (Stockhausen)",
+    "Tongue and finger click (Stockhausen)",
+    "Combining sussurando for stem",
+    "Arpeggiato wiggle segment, downwards",
+    "Arpeggiato arrowhead down",
+    "Arpeggiato downward swash",
+    "Arpeggiato wiggle segment, upwards",
+    "Arpeggiato arrowhead up",
+    "Arpeggiato upward swash",
+    "Circular motion segment",
+    "Constant circular motion segment",
+    "Constant circular motion segment (flipped)",
+    "Constant circular motion segment (flipped, large)",
+    "Constant circular motion segment (large)",
+    "Circular motion end",
+    "Circular motion segment, large",
+    "Circular motion segment, larger",
+    "Circular motion segment, larger still",
+    "Circular motion segment, largest",
+    "Circular motion segment, small",
+    "Circular motion start",
+    "Glissando wiggle segment",
+    "Group glissando 1",
+    "Group glissando 2",
+    "Group glissando 3",
+    "Quasi-random squiggle 1",
+    "Quasi-random squiggle 2",
+    "Quasi-random squiggle 3",
+    "Quasi-random squiggle 4",
+    "Sawtooth line segment",
+    "Narrow sawtooth line segment",
+    "Wide sawtooth line segment",
+    "Square wave line segment",
+    "Narrow square wave line segment",
+    "Wide square wave line segment",
+    "Trill wiggle segment",
+    "Trill wiggle segment, fast",
+    "Trill wiggle segment, faster",
+    "Trill wiggle segment, faster still",
+    "Trill wiggle segment, fastest",
+    "Trill wiggle segment, slow",
+    "Trill wiggle segment, slower",
+    "Trill wiggle segment, slower still",
+    "Trill wiggle segment, slowest",
+    "Vibrato largest, slower",
+    "Vibrato medium, slower",
+    "Vibrato / shake wiggle segment",
+    "Vibrato large, fast",
+    "Vibrato large, faster",
+    "Vibrato large, faster still",
+    "Vibrato large, fastest",
+    "Vibrato large, slow",
+    "Vibrato large, slower",
+    "Vibrato large, slowest",
+    "Vibrato largest, fast",
+    "Vibrato largest, faster",
+    "Vibrato largest, faster still",
+    "Vibrato largest, fastest",
+    "Vibrato largest, slow",
+    "Vibrato largest, slowest",
+    "Vibrato medium, fast",
+    "Vibrato medium, faster",
+    "Vibrato medium, faster still",
+    "Vibrato medium, fastest",
+    "Vibrato medium, slow",
+    "Vibrato medium, slowest",
+    "Vibrato small, fast",
+    "Vibrato small, faster",
+    "Vibrato small, faster still",
+    "Vibrato small, fastest",
+    "Vibrato small, slow",
+    "Vibrato small, slower",
+    "Vibrato small, slowest",
+    "Vibrato smallest, fast",
+    "Vibrato smallest, faster",
+    "Vibrato smallest, faster still",
+    "Vibrato smallest, fastest",
+    "Vibrato smallest, slow",
+    "Vibrato smallest, slower",
+    "Vibrato smallest, slowest",
+    "Vibrato start",
+    "Wide vibrato / shake wiggle segment",
+    "Wavy line segment",
+    "Narrow wavy line segment",
+    "Wide wavy line segment",
+    "Closed hole",
+    "Flatter embouchure",
+    "Half-closed hole",
+    "Half-closed hole 2",
+    "Half-open hole",
+    "Somewhat relaxed embouchure",
+    "Somewhat tight embouchure",
+    "Mouthpiece or hand pop",
+    "Combining multiphonics (black) for stem",
+    "Combining multiphonics (black and white) for stem",
+    "Combining multiphonics (white) for stem",
+    "Open hole",
+    "Much more reed (push inwards)",
+    "Normal reed position",
+    "Very little reed (pull outwards)",
+    "Relaxed embouchure",
+    "Rim only",
+    "Sharper embouchure",
+    "Very tight embouchure / strong air pressure",
+    "Three-quarters closed hole",
+    "Tight embouchure",
+    "Trill key",
+    "Very tight embouchure",
+    "Very relaxed embouchure / weak air-pressure",
+    "4-string tab clef",
+    "6-string tab clef",
+];
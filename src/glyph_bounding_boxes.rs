@@ -1,4 +1,4 @@
-use crate::{BoundingBox, GlyphData};
+use crate::{BoundingBox, Glyph, GlyphData};
 
 /// Information about the actual bounding box for each glyph.[^note]
 ///
@@ -20,3 +20,75 @@ use crate::{BoundingBox, GlyphData};
 /// be able to consume this metadata directly and automatically produce the
 /// required Font Annotation file.
 pub type GlyphBoundingBoxes = GlyphData<BoundingBox>;
+
+impl GlyphData<BoundingBox> {
+    /// Returns the glyphs whose bounding boxes are not valid (see
+    /// [`BoundingBox::is_valid`]), in arbitrary order.
+    pub fn invalid_glyphs(&self) -> impl Iterator<Item = Glyph> + '_ {
+        self.iter()
+            .filter(|(_, bounding_box)| !bounding_box.is_valid())
+            .map(|(glyph, _)| glyph)
+    }
+
+    /// Replaces every invalid bounding box with its normalized equivalent (see
+    /// [`BoundingBox::normalized`]), and returns the glyphs which were changed.
+    pub fn normalize(&mut self) -> Vec<Glyph> {
+        self.iter_mut()
+            .filter(|(_, bounding_box)| !bounding_box.is_valid())
+            .map(|(glyph, bounding_box)| {
+                *bounding_box = bounding_box.normalized();
+                glyph
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn glyph_bounding_boxes() -> GlyphBoundingBoxes {
+        [
+            (
+                Glyph::NoteheadBlack,
+                BoundingBox {
+                    ne: (1.0, 1.0).into(),
+                    sw: (0.0, 0.0).into(),
+                },
+            ),
+            (
+                Glyph::NoteheadWhole,
+                BoundingBox {
+                    ne: (0.0, 0.0).into(),
+                    sw: (1.0, 1.0).into(),
+                },
+            ),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn invalid_glyphs() {
+        let invalid_glyphs: Vec<_> = glyph_bounding_boxes().invalid_glyphs().collect();
+
+        assert_eq!(invalid_glyphs, [Glyph::NoteheadWhole]);
+    }
+
+    #[test]
+    fn normalize() {
+        let mut glyph_bounding_boxes = glyph_bounding_boxes();
+        let normalized = glyph_bounding_boxes.normalize();
+
+        assert_eq!(normalized, [Glyph::NoteheadWhole]);
+        assert_eq!(glyph_bounding_boxes.invalid_glyphs().count(), 0);
+        assert_eq!(
+            glyph_bounding_boxes.get(Glyph::NoteheadWhole),
+            Some(BoundingBox {
+                ne: (1.0, 1.0).into(),
+                sw: (0.0, 0.0).into(),
+            })
+        );
+    }
+}
@@ -1,15 +1,20 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use itertools::Itertools;
+use rustc_hash::FxBuildHasher;
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::{glyph_or_unknown::GlyphOrUnknown, Glyph};
 
 /// A map of [Glyph] to some data (`T`).
+///
+/// When serialized, entries are ordered by glyph name.
 #[derive(Clone, Debug, Deserialize)]
-#[cfg_attr(test, derive(serde::Serialize))]
 #[serde(transparent)]
 pub struct GlyphData<T> {
-    data: HashMap<GlyphOrUnknown, T>,
+    // Keys are mostly glyphs, which hash as their discriminants, so the Fx
+    // hasher is much faster than SipHash here.
+    data: HashMap<GlyphOrUnknown, T, FxBuildHasher>,
 }
 
 #[cfg(test)]
@@ -33,6 +38,46 @@ impl<T, const NUM: usize> From<[(GlyphOrUnknown, T); NUM]> for GlyphData<T> {
     }
 }
 
+impl<T: Serialize> Serialize for GlyphData<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.data
+                .iter()
+                .map(|(key, value)| (key.name(), value))
+                .sorted_by(|(a, _), (b, _)| a.cmp(b)),
+        )
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for GlyphData<T> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("GlyphData_for_{}", T::schema_name()).into()
+    }
+
+    /// Describes an object whose keys are glyph names and whose values are
+    /// `T`.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "additionalProperties": generator.subschema_for::<T>(),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for GlyphData<T> {
+    /// Generates data for known glyphs only.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            data: u
+                .arbitrary_iter::<(Glyph, T)>()?
+                .map(|entry| entry.map(|(glyph, value)| (GlyphOrUnknown::Glyph(glyph), value)))
+                .collect::<arbitrary::Result<_>>()?,
+        })
+    }
+}
+
 impl<T> Default for GlyphData<T> {
     fn default() -> Self {
         Self {
@@ -49,6 +94,75 @@ impl<T: Copy> GlyphData<T> {
 }
 
 impl<T> GlyphData<T> {
+    /// Returns an iterator over the known glyphs and their data, in arbitrary
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (Glyph, &T)> {
+        self.data.iter().filter_map(|(key, value)| match key {
+            GlyphOrUnknown::Glyph(glyph) => Some((*glyph, value)),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the known glyphs and mutable references to
+    /// their data, in arbitrary order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Glyph, &mut T)> {
+        self.data.iter_mut().filter_map(|(key, value)| match key {
+            GlyphOrUnknown::Glyph(glyph) => Some((*glyph, value)),
+            _ => None,
+        })
+    }
+
+    /// Returns a `GlyphData` containing `entries`, including unknown glyphs.
+    pub(crate) fn from_entries(entries: impl IntoIterator<Item = (GlyphOrUnknown, T)>) -> Self {
+        Self {
+            data: entries.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` if there is data for `glyph`, which may be unknown.
+    pub(crate) fn contains(&self, glyph: &GlyphOrUnknown) -> bool {
+        self.data.contains_key(glyph)
+    }
+
+    /// Inserts data for a glyph, which may be unknown, replacing any existing
+    /// data.
+    pub(crate) fn insert(&mut self, glyph: GlyphOrUnknown, value: T) {
+        self.data.insert(glyph, value);
+    }
+
+    /// Returns an iterator over every glyph, including unknown glyphs, and
+    /// its data, in arbitrary order.
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (&GlyphOrUnknown, &T)> {
+        self.data.iter()
+    }
+
+    /// Returns a copy of the data with `f` applied to each value.
+    pub(crate) fn map_values<U>(&self, f: impl Fn(&T) -> U) -> GlyphData<U> {
+        GlyphData {
+            data: self
+                .data
+                .iter()
+                .map(|(key, value)| (key.clone(), f(value)))
+                .collect(),
+        }
+    }
+
+    /// Shrinks the map's capacity as much as possible, e.g. after merging in
+    /// defaults.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Returns the approximate number of bytes allocated on the heap for the
+    /// map and the names of unknown glyphs.
+    pub(crate) fn heap_size(&self) -> usize {
+        // Each slot in the map holds an entry and a control byte.
+        let slots = self.data.capacity() * (size_of::<(GlyphOrUnknown, T)>() + 1);
+        let names = self.unknown_glyphs().map(String::capacity).sum::<usize>();
+
+        slots + names
+    }
+
     /// Insert data from `defaults` for any keys that are not present.
     pub(crate) fn with_defaults(mut self, defaults: Self) -> Self {
         for (glyph, value) in defaults.data {
@@ -75,6 +189,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn shrink_to_fit() {
+        let mut data = GlyphData {
+            data: HashMap::with_capacity_and_hasher(1000, FxBuildHasher),
+        };
+        data.data.insert(Glyph::NoteheadBlack.into(), 1);
+        data.data.insert("unknown".into(), 2);
+        let before = data.heap_size();
+
+        data.shrink_to_fit();
+
+        assert!(data.heap_size() < before);
+        assert!(data.heap_size() >= 2 * size_of::<(GlyphOrUnknown, u64)>() + "unknown".len());
+        assert_eq!(data.get(Glyph::NoteheadBlack), Some(1));
+    }
+
     #[rstest]
     #[case::empty(
         [],
@@ -101,6 +231,29 @@ mod tests {
         assert_eq!(glyph_data.get(glyph), expected);
     }
 
+    #[test]
+    fn iter() {
+        let glyph_data: GlyphData<u64> = [
+            (GlyphOrUnknown::Glyph(Glyph::NoteheadBlack), 1),
+            (GlyphOrUnknown::Unknown("Unknown".to_owned()), 2),
+        ]
+        .into();
+        let values: Vec<_> = glyph_data.iter().collect();
+
+        assert_eq!(values, [(Glyph::NoteheadBlack, &1)]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut glyph_data: GlyphData<u64> = [(Glyph::NoteheadBlack, 1)].into();
+
+        for (_, value) in glyph_data.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(glyph_data.get(Glyph::NoteheadBlack), Some(2));
+    }
+
     #[rstest]
     #[case::both_empty([], [], Glyph::NoteheadBlack, None)]
     #[case::fallback_empty(
@@ -153,4 +306,43 @@ mod tests {
 
         assert_eq!(unknown_glyphs, expected);
     }
+
+    #[test]
+    fn serialize() {
+        let glyph_data: GlyphData<u64> = [
+            (GlyphOrUnknown::from(Glyph::NoteheadWhole), 2),
+            (GlyphOrUnknown::from("unknown"), 3),
+            (GlyphOrUnknown::from(Glyph::NoteheadBlack), 1),
+        ]
+        .into();
+
+        assert_eq!(
+            serde_json::to_string(&glyph_data).unwrap(),
+            r#"{"noteheadBlack":1,"noteheadWhole":2,"unknown":3}"#
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(GlyphData<u64>);
+
+        assert_eq!(schema.get("type"), Some(&serde_json::json!("object")));
+        assert_eq!(
+            schema.as_value()["additionalProperties"]["type"],
+            serde_json::json!("integer")
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (1..=255).step_by(2).cycle().take(4096).collect::<Vec<u8>>();
+        let glyph_data = GlyphData::<u64>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        assert!(glyph_data.iter().next().is_some());
+        assert_eq!(glyph_data.unknown_glyphs().count(), 0);
+    }
 }
@@ -0,0 +1,18 @@
+use crate::{AnchorKind, BoundingBox, Change, Coord, Glyph, StaffSpaces};
+
+/// The differences in one glyph's metrics between two sets of metadata, as
+/// reported by [`Metadata::diff`](crate::Metadata::diff).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphDiff {
+    /// The glyph whose metrics differ.
+    pub glyph: Glyph,
+
+    /// The change in advance width, if it differs.
+    pub advance_width: Option<Change<StaffSpaces>>,
+
+    /// The change in bounding box, if it differs.
+    pub bounding_box: Option<Change<BoundingBox>>,
+
+    /// The anchors which differ, in [`AnchorKind`] order.
+    pub anchors: Vec<(AnchorKind, Change<Coord>)>,
+}
@@ -1,15 +1,61 @@
-use serde::Deserialize;
+use std::fmt::{self, Formatter};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 use crate::Glyph;
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
-#[cfg_attr(test, derive(serde::Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum GlyphOrUnknown {
     Glyph(Glyph),
     Unknown(String),
 }
 
+/// Deserializes directly from the glyph name, rather than buffering it as
+/// `#[serde(untagged)]` would, so that no allocation is made for known glyphs.
+impl<'de> Deserialize<'de> for GlyphOrUnknown {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(GlyphOrUnknownVisitor)
+    }
+}
+
+struct GlyphOrUnknownVisitor;
+
+impl Visitor<'_> for GlyphOrUnknownVisitor {
+    type Value = GlyphOrUnknown;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a glyph name")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(GlyphOrUnknown::from_name(value))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Glyph::from_name(&value).map_or(GlyphOrUnknown::Unknown(value), GlyphOrUnknown::Glyph))
+    }
+}
+
+impl GlyphOrUnknown {
+    /// Returns the glyph with the given name, as it appears in metadata files,
+    /// or an unknown glyph if there is none.
+    pub(crate) fn from_name(name: &str) -> Self {
+        Glyph::from_name(name).map_or_else(|| Self::Unknown(name.to_owned()), Self::Glyph)
+    }
+
+    /// Returns the name of the glyph, as it appears in metadata files.
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::Glyph(glyph) => glyph.name(),
+            Self::Unknown(name) => name,
+        }
+    }
+}
+
 #[cfg(test)]
 impl From<Glyph> for GlyphOrUnknown {
     fn from(value: Glyph) -> Self {
@@ -30,3 +76,38 @@ impl From<&str> for GlyphOrUnknown {
         Self::Unknown(value.to_owned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Glyph::NoteheadBlack.into(), "noteheadBlack")]
+    #[case("notAGlyph".into(), "notAGlyph")]
+    fn name(#[case] glyph: GlyphOrUnknown, #[case] expected: &str) {
+        assert_eq!(glyph.name(), expected);
+    }
+
+    #[rstest]
+    #[case(r#""noteheadBlack""#, Glyph::NoteheadBlack.into())]
+    #[case(r#""notAGlyph""#, "notAGlyph".into())]
+    #[case(r#""note\u0068eadBlack""#, Glyph::NoteheadBlack.into())]
+    fn deserialize(#[case] json: &str, #[case] expected: GlyphOrUnknown) {
+        assert_eq!(
+            serde_json::from_str::<GlyphOrUnknown>(json).unwrap(),
+            expected
+        );
+        assert_eq!(
+            serde_json::from_reader::<_, GlyphOrUnknown>(json.as_bytes()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn deserialize_not_a_string() {
+        assert!(serde_json::from_str::<GlyphOrUnknown>("1").is_err());
+    }
+}
@@ -0,0 +1,454 @@
+use crate::{Glyph, GLYPHS};
+
+/// A range of related glyphs in SMuFL, e.g. noteheads, along with the glyphs
+/// in it which a font for common music notation is expected to include, for
+/// checking a font's coverage with
+/// [`Metadata::check_coverage`](crate::Metadata::check_coverage).
+///
+/// The names and code points of the ranges are generated from SMuFL's
+/// [ranges.json](https://github.com/w3c/smufl/blob/gh-pages/metadata/ranges.json).
+/// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/tables/).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GlyphRange {
+    /// The name of the range, as in the SMuFL specification, e.g. `Noteheads`.
+    pub name: &'static str,
+
+    /// The first code point in the range.
+    pub start: char,
+
+    /// The last code point in the range.
+    pub end: char,
+
+    /// The glyphs in the range which most applications need.
+    ///
+    /// SMuFL doesn't recommend a subset of each range, so this is this crate's
+    /// own selection of the glyphs needed to engrave common music notation.
+    pub recommended: &'static [Glyph],
+}
+
+// region:sourcegen
+impl GlyphRange {
+    /// The *Staves* range (`staves`).
+    pub const STAVES: Self = Self {
+        name: "Staves",
+        start: '\u{e010}',
+        end: '\u{e02f}',
+        recommended: recommended::STAVES,
+    };
+
+    /// The *Barlines* range (`barlines`).
+    pub const BARLINES: Self = Self {
+        name: "Barlines",
+        start: '\u{e030}',
+        end: '\u{e03f}',
+        recommended: recommended::BARLINES,
+    };
+
+    /// The *Repeats* range (`repeats`).
+    pub const REPEATS: Self = Self {
+        name: "Repeats",
+        start: '\u{e040}',
+        end: '\u{e04f}',
+        recommended: recommended::REPEATS,
+    };
+
+    /// The *Clefs* range (`clefs`).
+    pub const CLEFS: Self = Self {
+        name: "Clefs",
+        start: '\u{e050}',
+        end: '\u{e07f}',
+        recommended: recommended::CLEFS,
+    };
+
+    /// The *Time signatures* range (`timeSignatures`).
+    pub const TIME_SIGNATURES: Self = Self {
+        name: "Time signatures",
+        start: '\u{e080}',
+        end: '\u{e09f}',
+        recommended: recommended::TIME_SIGNATURES,
+    };
+
+    /// The *Noteheads* range (`noteheads`).
+    pub const NOTEHEADS: Self = Self {
+        name: "Noteheads",
+        start: '\u{e0a0}',
+        end: '\u{e0ff}',
+        recommended: recommended::NOTEHEADS,
+    };
+
+    /// The *Flags* range (`flags`).
+    pub const FLAGS: Self = Self {
+        name: "Flags",
+        start: '\u{e240}',
+        end: '\u{e25f}',
+        recommended: recommended::FLAGS,
+    };
+
+    /// The *Standard accidentals (12-EDO)* range (`standardAccidentals12Edo`).
+    pub const STANDARD_ACCIDENTALS: Self = Self {
+        name: "Standard accidentals (12-EDO)",
+        start: '\u{e260}',
+        end: '\u{e26f}',
+        recommended: recommended::STANDARD_ACCIDENTALS,
+    };
+
+    /// The *Articulation* range (`articulation`).
+    pub const ARTICULATION: Self = Self {
+        name: "Articulation",
+        start: '\u{e4a0}',
+        end: '\u{e4bf}',
+        recommended: recommended::ARTICULATION,
+    };
+
+    /// The *Holds and pauses* range (`holdsAndPauses`).
+    pub const HOLDS_AND_PAUSES: Self = Self {
+        name: "Holds and pauses",
+        start: '\u{e4c0}',
+        end: '\u{e4df}',
+        recommended: recommended::HOLDS_AND_PAUSES,
+    };
+
+    /// The *Rests* range (`rests`).
+    pub const RESTS: Self = Self {
+        name: "Rests",
+        start: '\u{e4e0}',
+        end: '\u{e4ff}',
+        recommended: recommended::RESTS,
+    };
+
+    /// The *Octaves* range (`octaves`).
+    pub const OCTAVES: Self = Self {
+        name: "Octaves",
+        start: '\u{e510}',
+        end: '\u{e51f}',
+        recommended: recommended::OCTAVES,
+    };
+
+    /// The *Dynamics* range (`dynamics`).
+    pub const DYNAMICS: Self = Self {
+        name: "Dynamics",
+        start: '\u{e520}',
+        end: '\u{e54f}',
+        recommended: recommended::DYNAMICS,
+    };
+
+    /// The *Tuplets* range (`tuplets`).
+    pub const TUPLETS: Self = Self {
+        name: "Tuplets",
+        start: '\u{e880}',
+        end: '\u{e88f}',
+        recommended: recommended::TUPLETS,
+    };
+} // endregion:sourcegen
+
+impl GlyphRange {
+    /// The unpitched percussion noteheads in the *Noteheads* range.
+    pub const PERCUSSION_NOTEHEADS: Self = Self {
+        name: "Percussion noteheads",
+        recommended: recommended::PERCUSSION_NOTEHEADS,
+        ..Self::NOTEHEADS
+    };
+
+    /// The ranges used in common music notation, in code point order.
+    pub const RECOMMENDED: &'static [Self] = &[
+        Self::STAVES,
+        Self::BARLINES,
+        Self::REPEATS,
+        Self::CLEFS,
+        Self::TIME_SIGNATURES,
+        Self::NOTEHEADS,
+        Self::PERCUSSION_NOTEHEADS,
+        Self::FLAGS,
+        Self::STANDARD_ACCIDENTALS,
+        Self::ARTICULATION,
+        Self::HOLDS_AND_PAUSES,
+        Self::RESTS,
+        Self::OCTAVES,
+        Self::DYNAMICS,
+        Self::TUPLETS,
+    ];
+
+    /// Returns `true` if `glyph`'s code point is in the range.
+    pub fn contains(&self, glyph: Glyph) -> bool {
+        (self.start..=self.end).contains(&glyph.codepoint())
+    }
+
+    /// Returns an iterator over every glyph in the range, in the order of
+    /// [`GLYPHS`].
+    pub fn glyphs(&self) -> impl Iterator<Item = Glyph> + '_ {
+        GLYPHS.iter().copied().filter(|&glyph| self.contains(glyph))
+    }
+}
+
+/// The recommended glyphs in each range. These are this crate's own selection,
+/// since SMuFL doesn't define one.
+mod recommended {
+    use crate::Glyph;
+
+    pub(super) const STAVES: &[Glyph] = &[Glyph::Staff5Lines, Glyph::LegerLine];
+
+    pub(super) const BARLINES: &[Glyph] = &[
+        Glyph::BarlineSingle,
+        Glyph::BarlineDouble,
+        Glyph::BarlineFinal,
+    ];
+
+    pub(super) const REPEATS: &[Glyph] = &[
+        Glyph::RepeatLeft,
+        Glyph::RepeatRight,
+        Glyph::RepeatDots,
+        Glyph::Segno,
+        Glyph::Coda,
+    ];
+
+    pub(super) const CLEFS: &[Glyph] = &[
+        Glyph::GClef,
+        Glyph::CClef,
+        Glyph::FClef,
+        Glyph::UnpitchedPercussionClef1,
+    ];
+
+    pub(super) const TIME_SIGNATURES: &[Glyph] = &[
+        Glyph::TimeSig0,
+        Glyph::TimeSig1,
+        Glyph::TimeSig2,
+        Glyph::TimeSig3,
+        Glyph::TimeSig4,
+        Glyph::TimeSig5,
+        Glyph::TimeSig6,
+        Glyph::TimeSig7,
+        Glyph::TimeSig8,
+        Glyph::TimeSig9,
+        Glyph::TimeSigCommon,
+        Glyph::TimeSigCutCommon,
+    ];
+
+    pub(super) const NOTEHEADS: &[Glyph] = &[
+        Glyph::NoteheadDoubleWhole,
+        Glyph::NoteheadWhole,
+        Glyph::NoteheadHalf,
+        Glyph::NoteheadBlack,
+    ];
+
+    pub(super) const FLAGS: &[Glyph] = &[
+        Glyph::Flag8thUp,
+        Glyph::Flag8thDown,
+        Glyph::Flag16thUp,
+        Glyph::Flag16thDown,
+        Glyph::Flag32ndUp,
+        Glyph::Flag32ndDown,
+        Glyph::Flag64thUp,
+        Glyph::Flag64thDown,
+    ];
+
+    pub(super) const STANDARD_ACCIDENTALS: &[Glyph] = &[
+        Glyph::AccidentalFlat,
+        Glyph::AccidentalNatural,
+        Glyph::AccidentalSharp,
+        Glyph::AccidentalDoubleSharp,
+        Glyph::AccidentalDoubleFlat,
+    ];
+
+    pub(super) const ARTICULATION: &[Glyph] = &[
+        Glyph::ArticAccentAbove,
+        Glyph::ArticAccentBelow,
+        Glyph::ArticStaccatoAbove,
+        Glyph::ArticStaccatoBelow,
+        Glyph::ArticTenutoAbove,
+        Glyph::ArticTenutoBelow,
+        Glyph::ArticMarcatoAbove,
+        Glyph::ArticMarcatoBelow,
+    ];
+
+    pub(super) const HOLDS_AND_PAUSES: &[Glyph] = &[
+        Glyph::FermataAbove,
+        Glyph::FermataBelow,
+        Glyph::BreathMarkComma,
+    ];
+
+    pub(super) const RESTS: &[Glyph] = &[
+        Glyph::RestDoubleWhole,
+        Glyph::RestWhole,
+        Glyph::RestHalf,
+        Glyph::RestQuarter,
+        Glyph::Rest8th,
+        Glyph::Rest16th,
+        Glyph::Rest32nd,
+        Glyph::Rest64th,
+    ];
+
+    pub(super) const OCTAVES: &[Glyph] =
+        &[Glyph::Ottava, Glyph::OttavaBassaVb, Glyph::Quindicesima];
+
+    pub(super) const DYNAMICS: &[Glyph] = &[
+        Glyph::DynamicPiano,
+        Glyph::DynamicMezzo,
+        Glyph::DynamicForte,
+        Glyph::DynamicRinforzando,
+        Glyph::DynamicSforzando,
+        Glyph::DynamicZ,
+        Glyph::DynamicNiente,
+    ];
+
+    pub(super) const TUPLETS: &[Glyph] = &[
+        Glyph::Tuplet0,
+        Glyph::Tuplet1,
+        Glyph::Tuplet2,
+        Glyph::Tuplet3,
+        Glyph::Tuplet4,
+        Glyph::Tuplet5,
+        Glyph::Tuplet6,
+        Glyph::Tuplet7,
+        Glyph::Tuplet8,
+        Glyph::Tuplet9,
+        Glyph::TupletColon,
+    ];
+
+    pub(super) const PERCUSSION_NOTEHEADS: &[Glyph] = &[
+        Glyph::NoteheadXWhole,
+        Glyph::NoteheadXHalf,
+        Glyph::NoteheadXBlack,
+        Glyph::NoteheadPlusBlack,
+        Glyph::NoteheadCircleX,
+        Glyph::NoteheadTriangleUpBlack,
+        Glyph::NoteheadDiamondBlack,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Write, path::Path};
+
+    use anyhow::{anyhow, bail, Result};
+    use similar_asserts::assert_eq;
+    use smufl_gen::{output::rustfmt, RangeDefinition};
+
+    use super::*;
+
+    /// The name of each generated constant and of its range in `ranges.json`.
+    const RANGES: &[(&str, &str)] = &[
+        ("STAVES", "staves"),
+        ("BARLINES", "barlines"),
+        ("REPEATS", "repeats"),
+        ("CLEFS", "clefs"),
+        ("TIME_SIGNATURES", "timeSignatures"),
+        ("NOTEHEADS", "noteheads"),
+        ("FLAGS", "flags"),
+        ("STANDARD_ACCIDENTALS", "standardAccidentals12Edo"),
+        ("ARTICULATION", "articulation"),
+        ("HOLDS_AND_PAUSES", "holdsAndPauses"),
+        ("RESTS", "rests"),
+        ("OCTAVES", "octaves"),
+        ("DYNAMICS", "dynamics"),
+        ("TUPLETS", "tuplets"),
+    ];
+
+    #[test]
+    fn sourcegen() -> Result<()> {
+        let start_marker = "// region:sourcegen\n";
+        let end_marker = " // endregion:sourcegen\n";
+
+        let original_text = std::fs::read_to_string(file!())?;
+        let (prefix, rest) = original_text
+            .split_once(start_marker)
+            .ok_or_else(|| anyhow!("Could not find location of ranges in file"))?;
+        let (_, suffix) = rest
+            .split_once(end_marker)
+            .ok_or_else(|| anyhow!("Could not find end of ranges in file"))?;
+
+        let ranges =
+            RangeDefinition::from_path(Path::new("submodules/smufl/metadata/ranges.json"))?;
+
+        let mut code = "impl GlyphRange {".to_owned();
+        for (constant, key) in RANGES {
+            let range = ranges
+                .get(*key)
+                .ok_or_else(|| anyhow!("{key} is not in ranges.json"))?;
+
+            write!(
+                code,
+                "/// The *{name}* range (`{key}`).
+                pub const {constant}: Self = Self {{
+                    name: {name:?},
+                    start: {},
+                    end: {},
+                    recommended: recommended::{constant},
+                }};
+
+                ",
+                range.range_start,
+                range.range_end,
+                name = range.description,
+            )?;
+        }
+        code.push('}');
+
+        let new_text = format!(
+            "{prefix}{start_marker}{}{end_marker}{suffix}",
+            rustfmt(&code)?.trim_end()
+        );
+
+        if new_text != original_text {
+            std::fs::write(file!(), new_text)?;
+            bail!("Source was not up-to-date")
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn recommended_glyphs_are_in_range() {
+        for range in GlyphRange::RECOMMENDED {
+            for &glyph in range.recommended {
+                assert!(range.contains(glyph), "{glyph:?} is not in {}", range.name);
+            }
+        }
+    }
+
+    #[test]
+    fn recommended_is_sorted() {
+        assert!(GlyphRange::RECOMMENDED
+            .windows(2)
+            .all(|pair| pair[0].start <= pair[1].start));
+    }
+
+    #[test]
+    fn recommended_glyphs_are_disjoint() {
+        let mut glyphs = GlyphRange::RECOMMENDED
+            .iter()
+            .flat_map(|range| range.recommended)
+            .collect::<Vec<_>>();
+        let len = glyphs.len();
+
+        glyphs.sort_by_key(|glyph| glyph.codepoint());
+        glyphs.dedup();
+
+        assert_eq!(glyphs.len(), len);
+    }
+
+    #[test]
+    fn percussion_noteheads() {
+        assert_eq!(
+            GlyphRange::PERCUSSION_NOTEHEADS.start,
+            GlyphRange::NOTEHEADS.start
+        );
+        assert_eq!(
+            GlyphRange::PERCUSSION_NOTEHEADS.end,
+            GlyphRange::NOTEHEADS.end
+        );
+        assert!(GlyphRange::PERCUSSION_NOTEHEADS
+            .recommended
+            .contains(&Glyph::NoteheadXBlack));
+    }
+
+    #[test]
+    fn glyphs() {
+        let glyphs = GlyphRange::STANDARD_ACCIDENTALS
+            .glyphs()
+            .collect::<Vec<_>>();
+
+        assert_eq!(glyphs.len(), 14);
+        assert!(glyphs.contains(&Glyph::AccidentalFlat));
+        assert!(!glyphs.contains(&Glyph::AccidentalQuarterToneFlatStein));
+    }
+}
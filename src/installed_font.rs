@@ -0,0 +1,344 @@
+use std::{
+    collections::HashSet,
+    env,
+    ffi::OsString,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{bundle::FONT_EXTENSIONS, BundleError, Metadata};
+
+/// A SMuFL font installed on the system, along with the location of its
+/// metadata file.
+///
+/// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/font-specific-metadata.html)
+/// for where metadata files are installed on each platform.
+///
+/// ```no_run
+/// use smufl::InstalledFont;
+///
+/// for font in InstalledFont::discover() {
+///     println!("{}: {}", font.name, font.metadata_path.display());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InstalledFont {
+    /// The name of the font, taken from the name of its directory in a SMuFL
+    /// metadata location, or from the name of its metadata file. A metadata
+    /// file named `metadata.json` takes the name of its directory.
+    pub name: String,
+
+    /// The path of the font's metadata file.
+    pub metadata_path: PathBuf,
+}
+
+impl InstalledFont {
+    /// Returns the fonts installed on the system, ordered by name.
+    ///
+    /// This searches the SMuFL metadata locations for the current platform
+    /// (see [`smufl_directories`](Self::smufl_directories)) for
+    /// `<name>/<name>.json` files, then the platform's font directories (see
+    /// [`font_directories`](Self::font_directories)) for metadata files
+    /// distributed alongside fonts, such as `bravura_metadata.json`. If a font
+    /// is found more than once, the first location wins.
+    ///
+    /// In font directories, a metadata file is only found if it is named after
+    /// a font in the same directory (`<font>.json` or `<font>_metadata.json`),
+    /// or is named `metadata.json` in a directory containing fonts. Symbolic
+    /// links are followed, but each directory is searched at most once.
+    pub fn discover() -> Vec<Self> {
+        Self::discover_in(&Self::smufl_directories(), &Self::font_directories())
+    }
+
+    /// Like [`discover`](Self::discover), but searches the given directories
+    /// instead of the platform's. Directories which don't exist are skipped.
+    pub fn discover_in(smufl_directories: &[PathBuf], font_directories: &[PathBuf]) -> Vec<Self> {
+        let mut visited = HashSet::new();
+
+        smufl_directories
+            .iter()
+            .flat_map(|directory| in_smufl_directory(directory))
+            .chain(
+                font_directories
+                    .iter()
+                    .flat_map(|directory| in_font_directory(directory, &mut visited)),
+            )
+            .unique_by(|font| font.name.to_lowercase())
+            .sorted()
+            .collect()
+    }
+
+    /// Returns the directories in which the SMuFL specification recommends
+    /// installing metadata files on the current platform, from the most to the
+    /// least specific to the current user.
+    pub fn smufl_directories() -> Vec<PathBuf> {
+        let directories = if cfg!(target_os = "windows") {
+            [env_path("LOCALAPPDATA"), env_path("COMMONPROGRAMFILES")]
+                .into_iter()
+                .flatten()
+                .collect()
+        } else if cfg!(target_os = "macos") {
+            home()
+                .map(|home| home.join("Library/Application Support"))
+                .into_iter()
+                .chain([PathBuf::from("/Library/Application Support")])
+                .collect()
+        } else {
+            xdg_data_home()
+                .into_iter()
+                .chain(xdg_data_dirs())
+                .collect::<Vec<_>>()
+        };
+
+        directories
+            .into_iter()
+            .map(|directory| directory.join("SMuFL").join("Fonts"))
+            .collect()
+    }
+
+    /// Returns the directories in which fonts are installed on the current
+    /// platform, from the most to the least specific to the current user.
+    pub fn font_directories() -> Vec<PathBuf> {
+        if cfg!(target_os = "windows") {
+            env_path("LOCALAPPDATA")
+                .map(|path| path.join("Microsoft").join("Windows").join("Fonts"))
+                .into_iter()
+                .chain(env_path("WINDIR").map(|path| path.join("Fonts")))
+                .collect()
+        } else if cfg!(target_os = "macos") {
+            home()
+                .map(|home| home.join("Library/Fonts"))
+                .into_iter()
+                .chain([PathBuf::from("/Library/Fonts")])
+                .collect()
+        } else {
+            home()
+                .map(|home| home.join(".fonts"))
+                .into_iter()
+                .chain(
+                    xdg_data_home()
+                        .into_iter()
+                        .chain(xdg_data_dirs())
+                        .map(|directory| directory.join("fonts")),
+                )
+                .collect()
+        }
+    }
+
+    /// Loads the font's metadata.
+    pub fn metadata(&self) -> Result<Metadata, BundleError> {
+        let reader = BufReader::new(File::open(&self.metadata_path)?);
+
+        Ok(Metadata::from_reader(reader)?)
+    }
+}
+
+/// Finds `<name>/<name>.json` files in a SMuFL metadata location.
+fn in_smufl_directory(directory: &Path) -> Vec<InstalledFont> {
+    subdirectories(directory)
+        .into_iter()
+        .filter_map(|font_directory| {
+            let name = font_directory.file_name()?.to_str()?.to_owned();
+            let metadata_path = font_directory.join(format!("{name}.json"));
+
+            metadata_path.is_file().then_some(InstalledFont {
+                name,
+                metadata_path,
+            })
+        })
+        .collect()
+}
+
+/// Finds metadata files distributed alongside fonts in a font directory or
+/// any of its subdirectories, skipping directories in `visited` (by canonical
+/// path) so that symbolic link loops are only followed once.
+fn in_font_directory(directory: &Path, visited: &mut HashSet<PathBuf>) -> Vec<InstalledFont> {
+    if !fs::canonicalize(directory).is_ok_and(|canonical| visited.insert(canonical)) {
+        return Vec::new();
+    }
+
+    let files = files(directory);
+    let fonts = files
+        .iter()
+        .filter(|file| {
+            file.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    FONT_EXTENSIONS
+                        .iter()
+                        .any(|font_extension| extension.eq_ignore_ascii_case(font_extension))
+                })
+        })
+        .filter_map(|file| Some(file.file_stem()?.to_str()?.to_lowercase()))
+        .collect::<HashSet<_>>();
+
+    let found = files
+        .iter()
+        .filter_map(|file| {
+            let stem = file.file_name()?.to_str()?.strip_suffix(".json")?;
+            let name = if stem.eq_ignore_ascii_case("metadata") {
+                directory
+                    .file_name()?
+                    .to_str()
+                    .filter(|_| !fonts.is_empty())?
+            } else {
+                let name = stem.strip_suffix("_metadata").unwrap_or(stem);
+                fonts.contains(&name.to_lowercase()).then_some(name)?
+            };
+
+            Some(InstalledFont {
+                name: name.to_owned(),
+                metadata_path: file.clone(),
+            })
+        })
+        .sorted()
+        .collect::<Vec<_>>();
+
+    found
+        .into_iter()
+        .chain(
+            subdirectories(directory)
+                .iter()
+                .flat_map(|subdirectory| in_font_directory(subdirectory, visited)),
+        )
+        .collect()
+}
+
+fn entries(directory: &Path) -> impl Iterator<Item = PathBuf> {
+    fs::read_dir(directory)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+}
+
+fn subdirectories(directory: &Path) -> Vec<PathBuf> {
+    entries(directory)
+        .filter(|path| path.is_dir())
+        .sorted()
+        .collect()
+}
+
+fn files(directory: &Path) -> Vec<PathBuf> {
+    entries(directory).filter(|path| path.is_file()).collect()
+}
+
+fn env_path(key: &str) -> Option<PathBuf> {
+    env::var_os(key)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn home() -> Option<PathBuf> {
+    env_path("HOME")
+}
+
+fn xdg_data_home() -> Option<PathBuf> {
+    env_path("XDG_DATA_HOME").or_else(|| home().map(|home| home.join(".local/share")))
+}
+
+fn xdg_data_dirs() -> Vec<PathBuf> {
+    let dirs = env::var_os("XDG_DATA_DIRS")
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| OsString::from("/usr/local/share:/usr/share"));
+
+    env::split_paths(&dirs).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn discover_in() -> anyhow::Result<()> {
+        let root = env::temp_dir().join(format!("smufl-discover-{}", std::process::id()));
+        let user = root.join("user/SMuFL/Fonts");
+        let system = root.join("system/SMuFL/Fonts");
+        let fonts = root.join("fonts");
+
+        fs::create_dir_all(user.join("Bravura"))?;
+        fs::write(user.join("Bravura/Bravura.json"), "{}")?;
+        fs::create_dir_all(system.join("Bravura"))?;
+        fs::write(system.join("Bravura/Bravura.json"), "{}")?;
+        fs::create_dir_all(system.join("Empty"))?;
+        fs::create_dir_all(fonts.join("leland"))?;
+        fs::write(fonts.join("leland/Leland.otf"), [])?;
+        fs::write(fonts.join("leland/leland_metadata.json"), "{}")?;
+
+        let installed = InstalledFont::discover_in(
+            &[user.clone(), system, root.join("missing")],
+            std::slice::from_ref(&fonts),
+        );
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(
+            installed,
+            vec![
+                InstalledFont {
+                    name: "Bravura".to_owned(),
+                    metadata_path: user.join("Bravura/Bravura.json"),
+                },
+                InstalledFont {
+                    name: "leland".to_owned(),
+                    metadata_path: fonts.join("leland/leland_metadata.json"),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn discover_in_font_directory() -> anyhow::Result<()> {
+        let root = env::temp_dir().join(format!("smufl-discover-fonts-{}", std::process::id()));
+        let fonts = root.join("fonts");
+
+        fs::create_dir_all(&fonts)?;
+        fs::write(fonts.join("Bravura.otf"), [])?;
+        fs::write(fonts.join("Bravura.json"), "{}")?;
+        fs::write(fonts.join("Leland.otf"), [])?;
+        fs::write(fonts.join("leland_metadata.json"), "{}")?;
+        fs::write(fonts.join("other.json"), "{}")?;
+        fs::create_dir_all(fonts.join("Petaluma"))?;
+        fs::write(fonts.join("Petaluma/Petaluma.otf"), [])?;
+        fs::write(fonts.join("Petaluma/metadata.json"), "{}")?;
+        fs::create_dir_all(fonts.join("empty"))?;
+        fs::write(fonts.join("empty/metadata.json"), "{}")?;
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&fonts, fonts.join("loop"))?;
+
+        let installed = InstalledFont::discover_in(&[], std::slice::from_ref(&fonts));
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(
+            installed,
+            vec![
+                InstalledFont {
+                    name: "Bravura".to_owned(),
+                    metadata_path: fonts.join("Bravura.json"),
+                },
+                InstalledFont {
+                    name: "Petaluma".to_owned(),
+                    metadata_path: fonts.join("Petaluma/metadata.json"),
+                },
+                InstalledFont {
+                    name: "leland".to_owned(),
+                    metadata_path: fonts.join("leland_metadata.json"),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn smufl_directories() {
+        assert!(InstalledFont::smufl_directories()
+            .iter()
+            .all(|directory| directory.ends_with("SMuFL/Fonts")));
+    }
+}
@@ -0,0 +1,107 @@
+//! Support for reporting where in a metadata file parsing failed.
+
+use serde::Deserialize;
+use serde_path_to_error::{Path, Segment};
+
+use crate::Error;
+
+/// Deserializes a `T` from `read`, which must contain a single JSON value.
+///
+/// Errors include the path to the value being parsed when they occurred, e.g.
+/// `glyphBBoxes → noteheadBlack → bBoxNE`, prefixed by `section` when parsing
+/// a single section of a metadata file.
+pub(crate) fn deserialize<'de, R, T>(read: R, section: Option<&str>) -> Result<T, Error>
+where
+    R: serde_json::de::Read<'de>,
+    T: Deserialize<'de>,
+{
+    let mut deserializer = serde_json::Deserializer::new(read);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+        let path = format(section, error.path());
+        let source = error.into_inner();
+
+        match path {
+            Some(path) => Error::json_at(source, path),
+            None => source.into(),
+        }
+    })?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Formats `path` with `section` before it, returning `None` if both are
+/// empty.
+///
+/// Parts of the path which are not known, e.g. inside flattened structs, are
+/// left out.
+fn format(section: Option<&str>, path: &Path) -> Option<String> {
+    let segments = path
+        .iter()
+        .filter(|segment| !matches!(segment, Segment::Unknown))
+        .map(ToString::to_string);
+    let formatted = section
+        .map(str::to_owned)
+        .into_iter()
+        .chain(segments)
+        .collect::<Vec<_>>();
+
+    (!formatted.is_empty()).then(|| formatted.join(" → "))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{GlyphBoundingBoxes, Metadata};
+
+    fn path(error: Error) -> Option<String> {
+        match error {
+            Error::Json { path, .. } => path,
+            error => panic!("unexpected error: {error}"),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"fontName": "Test", "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [1, "x"], "bBoxSW": [0, 0]}}}"#,
+        Some("glyphBBoxes → noteheadBlack → bBoxNE → [1]")
+    )]
+    #[case(
+        r#"{"fontName": "Test", "glyphsWithAnchors": {"noteheadBlack": {"stemUpSE": [1, "x"]}}}"#,
+        Some("glyphsWithAnchors → noteheadBlack → stemUpSE → [1]")
+    )]
+    #[case(
+        r#"{"fontName": "Test", "glyphAdvanceWidths": {"noteheadBlack": true}}"#,
+        Some("glyphAdvanceWidths → noteheadBlack")
+    )]
+    #[case(
+        r#"{"fontName": "Test", "engravingDefaults": {"stemThickness": "thick"}}"#,
+        Some("engravingDefaults → stemThickness")
+    )]
+    #[case(r#"{"fontName": 1}"#, Some("fontName"))]
+    #[case(r#"{"fontName": "Test"} {}"#, None)]
+    fn deserialize(#[case] json: &str, #[case] expected: Option<&str>) {
+        let error = Metadata::from_reader(json.as_bytes()).unwrap_err();
+
+        assert_eq!(path(error).as_deref(), expected);
+    }
+
+    #[test]
+    fn deserialize_section() {
+        let json = r#"{"noteheadBlack": {"bBoxNE": [1, 1], "bBoxSW": null}}"#;
+
+        let error = super::deserialize::<_, GlyphBoundingBoxes>(
+            serde_json::de::StrRead::new(json),
+            Some("glyphBBoxes"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            path(error).as_deref(),
+            Some("glyphBBoxes → noteheadBlack → bBoxSW")
+        );
+    }
+}
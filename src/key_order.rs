@@ -0,0 +1,13 @@
+/// The order in which object keys are written when serializing metadata.
+///
+/// See [`SerializeOptions`](crate::SerializeOptions).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum KeyOrder {
+    /// Fields are written in the order they appear in the SMuFL specification,
+    /// and glyphs are ordered by name.
+    #[default]
+    Spec,
+
+    /// Every key is written in alphabetical order.
+    Alphabetical,
+}
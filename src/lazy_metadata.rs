@@ -0,0 +1,269 @@
+use std::{io::Read, sync::OnceLock};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::value::RawValue;
+
+use crate::{
+    log::warn, EngravingDefaults, Error, GlyphAdvanceWidths, GlyphAnchors, GlyphBoundingBoxes,
+    GlyphData, Metadata,
+};
+
+/// [`Metadata`] which defers parsing the glyph sections until they are first
+/// accessed.
+///
+/// Only the font name and engraving defaults are parsed up front. The advance
+/// widths, anchors and bounding boxes, which make up the bulk of a metadata
+/// file, are kept as unparsed JSON until they are needed, so applications
+/// which only need e.g. engraving defaults don't pay to parse them.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use smufl::{Glyph, LazyMetadata};
+///
+/// let json = r#"{"fontName": "Test", "glyphAdvanceWidths": {"noteheadBlack": 1.18}}"#;
+/// let metadata = LazyMetadata::from_reader(json.as_bytes())?;
+///
+/// assert_eq!(metadata.font_name, "Test");
+///
+/// // Parses the advance widths.
+/// let width = metadata.advance_widths()?.get(Glyph::NoteheadBlack);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LazyMetadata {
+    /// The name of the font to which the metadata applies.
+    pub font_name: String,
+
+    /// Recommended defaults for line widths, etc.
+    pub engraving_defaults: EngravingDefaults,
+
+    advance_widths: Section<GlyphAdvanceWidths>,
+    anchors: Section<GlyphAnchors>,
+    bounding_boxes: Section<GlyphBoundingBoxes>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Raw {
+    font_name: String,
+    #[serde(default)]
+    engraving_defaults: EngravingDefaults,
+    glyph_advance_widths: Option<Box<RawValue>>,
+    glyphs_with_anchors: Option<Box<RawValue>>,
+    #[serde(rename = "glyphBBoxes")]
+    glyph_bboxes: Option<Box<RawValue>>,
+}
+
+/// A section of the metadata file, parsed on first access.
+#[derive(Debug)]
+struct Section<T> {
+    name: &'static str,
+    raw: Option<Box<RawValue>>,
+    parsed: OnceLock<T>,
+}
+
+impl<T> Section<GlyphData<T>>
+where
+    GlyphData<T>: DeserializeOwned,
+{
+    fn new(name: &'static str, raw: Option<Box<RawValue>>) -> Self {
+        Self {
+            name,
+            raw,
+            parsed: OnceLock::new(),
+        }
+    }
+
+    fn get(&self) -> Result<&GlyphData<T>, Error> {
+        if let Some(parsed) = self.parsed.get() {
+            return Ok(parsed);
+        }
+
+        let parsed: GlyphData<T> = match &self.raw {
+            Some(raw) => crate::json_path::deserialize(
+                serde_json::de::StrRead::new(raw.get()),
+                Some(self.name),
+            )?,
+            None => GlyphData::default(),
+        };
+
+        let unknowns = parsed.unknown_glyphs().collect::<Vec<_>>();
+        if !unknowns.is_empty() {
+            warn!(?unknowns, "Unknown glyphs found");
+        }
+
+        Ok(self.parsed.get_or_init(|| parsed))
+    }
+
+    fn into_inner(self) -> Result<GlyphData<T>, Error> {
+        self.get()?;
+
+        Ok(self.parsed.into_inner().unwrap_or_default())
+    }
+}
+
+impl LazyMetadata {
+    /// Deserializes the font name and engraving defaults from JSON data,
+    /// keeping the remaining sections for parsing on first access.
+    ///
+    /// The whole input is still checked to be valid JSON.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_reader(reader: impl Read) -> Result<Self, Error> {
+        let raw: Raw = serde_json::from_reader(reader)?;
+
+        Ok(Self {
+            font_name: raw.font_name,
+            engraving_defaults: raw.engraving_defaults,
+            advance_widths: Section::new("glyphAdvanceWidths", raw.glyph_advance_widths),
+            anchors: Section::new("glyphsWithAnchors", raw.glyphs_with_anchors),
+            bounding_boxes: Section::new("glyphBBoxes", raw.glyph_bboxes),
+        })
+    }
+
+    /// Returns the advance widths for glyphs, parsing them if this is the first
+    /// access.
+    ///
+    /// If parsing fails, the error is returned and parsing is retried on the
+    /// next access.
+    pub fn advance_widths(&self) -> Result<&GlyphAdvanceWidths, Error> {
+        self.advance_widths.get()
+    }
+
+    /// Returns the anchor data for glyphs, parsing it if this is the first
+    /// access.
+    ///
+    /// If parsing fails, the error is returned and parsing is retried on the
+    /// next access.
+    pub fn anchors(&self) -> Result<&GlyphAnchors, Error> {
+        self.anchors.get()
+    }
+
+    /// Returns the bounding boxes for glyphs, parsing them if this is the first
+    /// access.
+    ///
+    /// If parsing fails, the error is returned and parsing is retried on the
+    /// next access.
+    pub fn bounding_boxes(&self) -> Result<&GlyphBoundingBoxes, Error> {
+        self.bounding_boxes.get()
+    }
+
+    /// Parses any remaining sections, returning the complete [`Metadata`].
+    pub fn into_metadata(self) -> Result<Metadata, Error> {
+        Ok(Metadata {
+            font_name: self.font_name,
+            engraving_defaults: self.engraving_defaults,
+            advance_widths: self.advance_widths.into_inner()?,
+            anchors: self.anchors.into_inner()?,
+            bounding_boxes: self.bounding_boxes.into_inner()?,
+        })
+    }
+
+    /// Like [`into_metadata`](Self::into_metadata), but parses the remaining
+    /// sections in parallel on the [`rayon`] thread pool.
+    #[cfg(feature = "parallel")]
+    pub fn into_metadata_parallel(self) -> Result<Metadata, Error> {
+        let (advance_widths, (anchors, bounding_boxes)) = rayon::join(
+            || self.advance_widths.into_inner(),
+            || {
+                rayon::join(
+                    || self.anchors.into_inner(),
+                    || self.bounding_boxes.into_inner(),
+                )
+            },
+        );
+
+        Ok(Metadata {
+            font_name: self.font_name,
+            engraving_defaults: self.engraving_defaults,
+            advance_widths: advance_widths?,
+            anchors: anchors?,
+            bounding_boxes: bounding_boxes?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Glyph, StaffSpaces};
+
+    const JSON: &str = r#"{
+        "fontName": "Test",
+        "engravingDefaults": {"stemThickness": 0.12},
+        "glyphAdvanceWidths": {"noteheadBlack": 1.18},
+        "glyphBBoxes": {"noteheadBlack": "invalid"}
+    }"#;
+
+    #[test]
+    fn from_reader() -> anyhow::Result<()> {
+        let metadata = LazyMetadata::from_reader(JSON.as_bytes())?;
+
+        assert_eq!(metadata.font_name, "Test");
+        assert_eq!(
+            metadata.engraving_defaults.stem_thickness,
+            Some(StaffSpaces(0.12))
+        );
+        assert_eq!(
+            metadata.advance_widths()?.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+        assert_eq!(metadata.anchors()?.get(Glyph::NoteheadBlack), None);
+        assert!(matches!(
+            metadata.bounding_boxes(),
+            Err(Error::Json { path: Some(path), .. }) if path == "glyphBBoxes → noteheadBlack"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_invalid_json() {
+        assert!(
+            LazyMetadata::from_reader(r#"{"fontName": "Test", "glyphBBoxes": {"#.as_bytes())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn into_metadata() -> anyhow::Result<()> {
+        let json = JSON.replace(
+            r#""invalid""#,
+            r#"{"bBoxNE": [1, 0.5], "bBoxSW": [0, -0.5]}"#,
+        );
+        let metadata = LazyMetadata::from_reader(json.as_bytes())?.into_metadata()?;
+
+        assert_eq!(
+            metadata.advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+        assert!(metadata.bounding_boxes.get(Glyph::NoteheadBlack).is_some());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn into_metadata_parallel() -> anyhow::Result<()> {
+        let metadata = LazyMetadata::from_reader(JSON.as_bytes())?;
+
+        assert!(metadata.into_metadata_parallel().is_err());
+
+        let json = JSON.replace(
+            r#""invalid""#,
+            r#"{"bBoxNE": [1, 0.5], "bBoxSW": [0, -0.5]}"#,
+        );
+        let metadata = LazyMetadata::from_reader(json.as_bytes())?.into_metadata_parallel()?;
+
+        assert_eq!(metadata.font_name, "Test");
+        assert_eq!(
+            metadata.advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+        assert!(metadata.bounding_boxes.get(Glyph::NoteheadBlack).is_some());
+
+        Ok(())
+    }
+}
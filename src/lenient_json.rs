@@ -0,0 +1,98 @@
+//! Support for parsing JSON with comments and trailing commas.
+
+/// Replaces comments and trailing commas in `json` with whitespace, so that
+/// it can be parsed as standard JSON.
+///
+/// Newlines are kept, so the line and column numbers of any parse errors are
+/// the same as in the original input.
+pub(crate) fn strip(json: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(json.len());
+    let mut bytes = json.iter().copied().peekable();
+    // The position in `output` of a comma which has only been followed by
+    // whitespace or comments so far.
+    let mut last_comma = None;
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'"' => {
+                last_comma = None;
+                output.push(byte);
+
+                while let Some(byte) = bytes.next() {
+                    output.push(byte);
+
+                    match byte {
+                        b'\\' => output.extend(bytes.next()),
+                        b'"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            b'/' if bytes.peek() == Some(&b'/') => {
+                output.push(b' ');
+
+                while let Some(&byte) = bytes.peek() {
+                    if byte == b'\n' {
+                        break;
+                    }
+
+                    output.push(b' ');
+                    bytes.next();
+                }
+            }
+            b'/' if bytes.peek() == Some(&b'*') => {
+                output.extend(b"  ");
+                bytes.next();
+
+                let mut previous = None;
+
+                for byte in bytes.by_ref() {
+                    output.push(if byte == b'\n' { b'\n' } else { b' ' });
+
+                    if previous == Some(b'*') && byte == b'/' {
+                        break;
+                    }
+
+                    previous = Some(byte);
+                }
+            }
+            b',' => {
+                last_comma = Some(output.len());
+                output.push(byte);
+            }
+            b'}' | b']' => {
+                if let Some(position) = last_comma.take() {
+                    output[position] = b' ';
+                }
+
+                output.push(byte);
+            }
+            byte if byte.is_ascii_whitespace() => output.push(byte),
+            byte => {
+                last_comma = None;
+                output.push(byte);
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    #[rstest]
+    #[case(r#"{"a": [1, 2]}"#, r#"{"a": [1, 2]}"#)]
+    #[case(r#"{"a": [1, 2,],}"#, r#"{"a": [1, 2 ] }"#)]
+    #[case("{\"a\": 1, // comment\n}", "{\"a\": 1            \n}")]
+    #[case("{/* multi\nline */\"a\": 1}", "{        \n       \"a\": 1}")]
+    #[case(r#"{"a": "// not a comment,]"}"#, r#"{"a": "// not a comment,]"}"#)]
+    #[case(r#"{"a\"": "/*", }"#, r#"{"a\"": "/*"  }"#)]
+    fn strip(#[case] json: &str, #[case] expected: &str) {
+        let stripped = String::from_utf8(super::strip(json.as_bytes())).unwrap();
+
+        assert_eq!(stripped, expected);
+    }
+}
@@ -1,5 +1,8 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
+// The crate documentation describes metadata parsing, which needs `serde`.
+#![cfg_attr(not(feature = "serde"), allow(rustdoc::broken_intra_doc_links))]
 
 //! Parse [SMuFL][smufl] (Standard Music Font Layout) metadata.
 //!
@@ -63,31 +66,268 @@
 //! # example().unwrap()
 //! ```
 //!
+//! Each glyph's code points, name and description are also available in static
+//! tables, such as [`GLYPH_CODEPOINTS`] and [`GLYPH_NAMES`], indexed by
+//! `glyph as usize`. Looking glyphs up in them needs no allocation or parsing,
+//! for use in firmware and WebAssembly.
+//!
+//! ## Features
+//!
+//! - `approx`: Implements the [`approx`](https://docs.rs/approx) traits for
+//!   [`StaffSpaces`], [`Coord`], and [`BoundingBox`], for tolerance-based
+//!   comparison of metrics.
+//! - `arbitrary`: Implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary)
+//!   for [`Metadata`] and the types it contains, generating realistic metrics
+//!   (finite values and valid bounding boxes) for property-testing and fuzzing
+//!   code which uses them.
+//! - `decimal`: Adds `DecimalMetadata`, which keeps the exact decimal literals
+//!   of a metadata file so that it can be written back without changing values
+//!   which weren't edited, and conversions between [`StaffSpaces`] and [`rust_decimal::Decimal`](https://docs.rs/rust_decimal).
+//! - `euclid`: Adds conversions between this crate's types and their [`euclid`](https://docs.rs/euclid)
+//!   equivalents, tagged with a `StaffSpaceUnit` unit.
+//! - `fetch`: Adds `Metadata::from_url` and `Metadata::from_reference_font`,
+//!   for fetching metadata over HTTP, e.g. the published metadata for
+//!   [`ReferenceFont::Bravura`].
+//! - `font`: Adds `Font`, which combines a font file parsed with [`ttf-parser`](https://docs.rs/ttf-parser)
+//!   with its [`Metadata`], for looking up glyphs and metrics in the font
+//!   itself. Advance widths and bounding boxes missing from the metadata file
+//!   are filled in from the font, and `Metadata::from_font_path` reads metadata
+//!   embedded in the font file.
+//! - `mint`: Adds conversions between [`Coord`] and [`mint::Point2`](https://docs.rs/mint),
+//!   for interoperability with math and graphics libraries.
+//! - `notify`: Adds `MetadataWatcher`, which reloads a metadata file whenever
+//!   it changes.
+//! - `parallel`: Adds `Metadata::from_reader_parallel`, which parses the glyph
+//!   sections of a metadata file on the [`rayon`](https://docs.rs/rayon) thread
+//!   pool, reducing the time to load large metadata files.
+//! - `schemars`: Implements [`schemars::JsonSchema`](https://docs.rs/schemars)
+//!   for [`Metadata`] and the types it contains, so metadata files can be
+//!   validated against a schema produced from the structures this crate parses.
+//! - `serde`: Enabled by default. Implements serde's traits for [`Glyph`] and
+//!   the geometry types, and adds [`Metadata`] and everything else for parsing
+//!   and working with metadata files. Without it, the crate is a lightweight
+//!   table of glyphs, with their names and codepoints, and the types for
+//!   measuring in staff spaces.
+//! - `std`: Enabled by default, and by `serde` and the features which need it.
+//!   Without it, the crate is `no_std`, and [`Glyph`], [`StaffSpaces`],
+//!   [`Coord`], [`BoundingBox`] and the other geometry and unit types don't
+//!   need an allocator either.
+//! - `toml`: Adds `Metadata::from_toml_str`, for metadata kept in TOML with the
+//!   same structure as a JSON metadata file.
+//! - `tracing`: Enabled by default. Logs unknown glyphs, invalid bounding boxes
+//!   and cache and watcher activity with [`tracing`](https://docs.rs/tracing),
+//!   and adds spans to the functions which load metadata. Without it, nothing
+//!   is logged.
+//! - `yaml`: Adds `Metadata::from_yaml_reader`, for metadata kept in YAML with
+//!   the same structure as a JSON metadata file.
+//! - `zip`: Adds `Metadata::from_zip`, for loading metadata from a zip archive
+//!   containing a font and its metadata file.
+//!
 //! [smufl]: https://www.smufl.org/
 //! [smufl-metadata]: https://w3c.github.io/smufl/latest/specification/font-specific-metadata.html
 //! [smufl-version-history]: https://w3c.github.io/smufl/latest/preamble/version-history.html
 
+#[cfg(feature = "serde")]
+mod anchor_kind;
+#[cfg(feature = "serde")]
 mod anchors;
+#[cfg(feature = "serde")]
+mod binary;
 mod bounding_box;
+#[cfg(feature = "serde")]
+mod bundle;
+#[cfg(feature = "serde")]
+mod bundle_error;
+#[cfg(feature = "serde")]
+mod change;
 mod coord;
+#[cfg(feature = "decimal")]
+mod decimal_metadata;
+mod ems;
+#[cfg(feature = "serde")]
 mod engraving_defaults;
+#[cfg(feature = "serde")]
+mod error;
+#[cfg(feature = "fetch")]
+mod fetch_error;
+mod float;
+#[cfg(feature = "font")]
+mod font;
+#[cfg(feature = "font")]
+mod font_error;
+#[cfg(feature = "serde")]
+mod font_registry;
 mod glyph;
+#[cfg(feature = "serde")]
 mod glyph_advance_widths;
+#[cfg(feature = "serde")]
 mod glyph_anchors;
+#[cfg(feature = "serde")]
 mod glyph_bounding_boxes;
+#[cfg(feature = "serde")]
 mod glyph_data;
+#[cfg(feature = "serde")]
+mod glyph_diff;
+#[cfg(feature = "serde")]
 mod glyph_or_unknown;
+mod glyph_range;
+#[cfg(feature = "serde")]
+mod installed_font;
+#[cfg(feature = "serde")]
+mod json_path;
+#[cfg(feature = "serde")]
+mod key_order;
+#[cfg(feature = "serde")]
+mod lazy_metadata;
+#[cfg(feature = "serde")]
+mod lenient_json;
+#[cfg(feature = "serde")]
+mod log;
+#[cfg(feature = "serde")]
+mod memory_footprint;
+#[cfg(feature = "serde")]
 mod metadata;
+#[cfg(feature = "serde")]
+mod metadata_cache;
+#[cfg(feature = "serde")]
+mod metadata_diff;
+#[cfg(feature = "notify")]
+mod metadata_watcher;
+#[cfg(feature = "font")]
+mod metric_source;
+mod millimeters;
+#[cfg(feature = "serde")]
+mod non_finite_value;
+#[cfg(feature = "serde")]
+mod numeral_extents;
+mod orientation;
+#[cfg(feature = "serde")]
+mod parse_options;
+#[cfg(feature = "serde")]
+mod parse_report;
+mod points;
+#[cfg(feature = "serde")]
+mod reference_font;
+mod render_context;
+#[cfg(feature = "serde")]
+mod serialize_options;
+#[cfg(feature = "serde")]
+mod severity;
+#[cfg(feature = "serde")]
+mod shared_metadata;
+mod side;
+mod space_range;
+mod staff_size;
 mod staff_spaces;
+mod staff_spaces_f32;
+mod stem_direction;
+#[cfg(feature = "serde")]
+mod text_font_family;
+mod transform;
+#[cfg(feature = "serde")]
+mod validation;
+#[cfg(feature = "serde")]
+mod validation_issue;
+#[cfg(feature = "serde")]
+mod warning;
+#[cfg(feature = "serde")]
+mod warning_sink;
+#[cfg(feature = "notify")]
+mod watch_error;
 
+#[cfg(feature = "serde")]
+pub use anchor_kind::AnchorKind;
+#[cfg(feature = "serde")]
 pub use anchors::Anchors;
 pub use bounding_box::BoundingBox;
+#[cfg(feature = "serde")]
+pub use bundle_error::BundleError;
+#[cfg(feature = "serde")]
+pub use change::Change;
 pub use coord::Coord;
+#[cfg(feature = "decimal")]
+pub use decimal_metadata::DecimalMetadata;
+pub use ems::Ems;
+#[cfg(feature = "serde")]
 pub use engraving_defaults::EngravingDefaults;
-pub use glyph::Glyph;
+#[cfg(feature = "serde")]
+pub use error::Error;
+#[cfg(feature = "fetch")]
+pub use fetch_error::FetchError;
+#[cfg(feature = "font")]
+pub use font::Font;
+#[cfg(feature = "font")]
+pub use font_error::FontError;
+#[cfg(feature = "serde")]
+pub use font_registry::FontRegistry;
+pub use glyph::{
+    Glyph, GLYPHS, GLYPH_ALTERNATE_CODEPOINTS, GLYPH_CODEPOINTS, GLYPH_DESCRIPTIONS, GLYPH_NAMES,
+};
+#[cfg(feature = "serde")]
 pub use glyph_advance_widths::GlyphAdvanceWidths;
+#[cfg(feature = "serde")]
 pub use glyph_anchors::GlyphAnchors;
+#[cfg(feature = "serde")]
 pub use glyph_bounding_boxes::GlyphBoundingBoxes;
+#[cfg(feature = "serde")]
 pub use glyph_data::GlyphData;
+#[cfg(feature = "serde")]
+pub use glyph_diff::GlyphDiff;
+pub use glyph_range::GlyphRange;
+#[cfg(feature = "serde")]
+pub use installed_font::InstalledFont;
+#[cfg(feature = "serde")]
+pub use key_order::KeyOrder;
+#[cfg(feature = "serde")]
+pub use lazy_metadata::LazyMetadata;
+#[cfg(feature = "serde")]
+pub use memory_footprint::MemoryFootprint;
+#[cfg(feature = "serde")]
 pub use metadata::Metadata;
+#[cfg(feature = "serde")]
+pub use metadata_cache::MetadataCache;
+#[cfg(feature = "serde")]
+pub use metadata_diff::MetadataDiff;
+#[cfg(feature = "notify")]
+pub use metadata_watcher::MetadataWatcher;
+#[cfg(feature = "font")]
+pub use metric_source::MetricSource;
+pub use millimeters::Millimeters;
+#[cfg(feature = "serde")]
+pub use non_finite_value::NonFiniteValue;
+#[cfg(feature = "serde")]
+pub use numeral_extents::NumeralExtents;
+pub use orientation::Orientation;
+#[cfg(feature = "serde")]
+pub use parse_options::ParseOptions;
+#[cfg(feature = "serde")]
+pub use parse_report::ParseReport;
+pub use points::Points;
+#[cfg(feature = "serde")]
+pub use reference_font::ReferenceFont;
+pub use render_context::RenderContext;
+#[cfg(feature = "serde")]
+pub use serialize_options::SerializeOptions;
+#[cfg(feature = "serde")]
+pub use severity::Severity;
+#[cfg(feature = "serde")]
+pub use shared_metadata::SharedMetadata;
+pub use side::Side;
+pub use space_range::SpaceRange;
+pub use staff_size::StaffSize;
+#[cfg(feature = "euclid")]
+pub use staff_spaces::StaffSpaceUnit;
 pub use staff_spaces::StaffSpaces;
+pub use staff_spaces_f32::StaffSpacesF32;
+pub use stem_direction::StemDirection;
+#[cfg(feature = "serde")]
+pub use text_font_family::TextFontFamily;
+pub use transform::Transform;
+#[cfg(feature = "serde")]
+pub use validation_issue::ValidationIssue;
+#[cfg(feature = "serde")]
+pub use warning::Warning;
+#[cfg(feature = "serde")]
+pub use warning_sink::WarningSink;
+#[cfg(feature = "notify")]
+pub use watch_error::WatchError;
@@ -0,0 +1,40 @@
+//! Logging macros, which are `tracing`'s with the `tracing` feature, and
+//! otherwise do nothing.
+
+#[cfg(feature = "tracing")]
+pub(crate) use tracing::{debug, warn};
+
+/// Accepts the subset of `tracing`'s event syntax used in this crate (`?field`
+/// and `%field` followed by a message), borrowing each field so that variables
+/// which are only logged aren't reported as unused.
+#[cfg(not(feature = "tracing"))]
+macro_rules! event {
+    (? $field:ident, $($rest:tt)*) => {{
+        let _ = &$field;
+        $crate::log::event!($($rest)*)
+    }};
+    (% $field:ident, $($rest:tt)*) => {{
+        let _ = &$field;
+        $crate::log::event!($($rest)*)
+    }};
+    ($message:literal) => {{}};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug_event {
+    ($($event:tt)*) => {
+        $crate::log::event!($($event)*)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! warn_event {
+    ($($event:tt)*) => {
+        $crate::log::event!($($event)*)
+    };
+}
+
+// `warn` can't be re-exported under its own name, since that is ambiguous with
+// the built-in attribute.
+#[cfg(not(feature = "tracing"))]
+pub(crate) use {debug_event as debug, event, warn_event as warn};
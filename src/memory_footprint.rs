@@ -0,0 +1,30 @@
+/// The approximate number of bytes a [`Metadata`](crate::Metadata) has
+/// allocated on the heap, by section, as returned by
+/// [`Metadata::memory_footprint`](crate::Metadata::memory_footprint).
+///
+/// The sizes count the maps' allocations, including spare capacity, and the
+/// strings they own, but not the allocator's own overhead.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryFootprint {
+    /// The font name.
+    pub font_name: usize,
+    /// The engraving defaults, i.e. the text font family names.
+    pub engraving_defaults: usize,
+    /// The glyph advance widths.
+    pub advance_widths: usize,
+    /// The glyph anchors.
+    pub anchors: usize,
+    /// The glyph bounding boxes.
+    pub bounding_boxes: usize,
+}
+
+impl MemoryFootprint {
+    /// Returns the total number of bytes across all sections.
+    pub fn total(&self) -> usize {
+        self.font_name
+            + self.engraving_defaults
+            + self.advance_widths
+            + self.anchors
+            + self.bounding_boxes
+    }
+}
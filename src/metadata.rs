@@ -1,16 +1,31 @@
-use std::io::Read;
+use std::{
+    fmt::{self, Formatter},
+    io::{Read, Write},
+    marker::PhantomData,
+};
 
 use itertools::Itertools;
-use serde::Deserialize;
-use tracing::{debug, instrument, warn};
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::Value;
 
-use crate::{EngravingDefaults, GlyphAdvanceWidths, GlyphAnchors, GlyphBoundingBoxes};
+use crate::{
+    glyph_or_unknown::GlyphOrUnknown,
+    log::{debug, warn},
+    AnchorKind, Anchors, BoundingBox, Coord, EngravingDefaults, Glyph, GlyphAdvanceWidths,
+    GlyphAnchors, GlyphBoundingBoxes, GlyphData, KeyOrder, MemoryFootprint, NonFiniteValue,
+    NumeralExtents, ParseOptions, ParseReport, SerializeOptions, Severity, StaffSpaces,
+    ValidationIssue, Warning, WarningSink,
+};
 
 /// Representation of the metadata file provided with a SMuFL font.
 ///
 /// See the [SMuFL documentation](https://w3c.github.io/smufl/latest/specification/font-specific-metadata.html).
-#[derive(Clone, Debug, Deserialize)]
-#[cfg_attr(test, derive(serde::Serialize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Metadata {
     /// The name of the font to which the metadata applies.
@@ -45,15 +60,302 @@ impl Metadata {
     /// Deserializes `Metadata` from JSON data.
     ///
     /// If any unknown glyphs are encountered, they will be logged at the WARN
-    /// level.
-    #[instrument(skip(reader), err(Debug))]
-    pub fn from_reader(reader: impl Read) -> Result<Self, serde_json::Error> {
-        let metadata: Self = serde_json::from_reader(reader)?;
-        metadata.log_unknowns();
+    /// level. Invalid bounding boxes (see [`BoundingBox::is_valid`]) are kept
+    /// as they are; use [`from_reader_with`](Self::from_reader_with) with
+    /// [`ParseOptions::report_invalid_bounding_boxes`] or
+    /// [`ParseOptions::normalize_bounding_boxes`] to report or fix them.
+    ///
+    /// Errors reading from `reader` are returned as [`Error::Io`], and invalid
+    /// JSON or JSON which doesn't have the structure of a metadata file as
+    /// [`Error::Json`]. Malformed input never causes a panic; this is checked
+    /// by the fuzz targets in the `fuzz` directory.
+    ///
+    /// [`BoundingBox::is_valid`]: crate::BoundingBox::is_valid
+    /// [`Error::Io`]: crate::Error::Io
+    /// [`Error::Json`]: crate::Error::Json
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_reader(reader: impl Read) -> Result<Self, crate::Error> {
+        Self::from_reader_with(reader, &ParseOptions::default())
+    }
+
+    /// Deserializes `Metadata` from JSON data, according to `options`.
+    ///
+    /// Unknown glyphs are logged as in [`from_reader`](Self::from_reader),
+    /// unless `options` turns them into errors. Invalid bounding boxes are also
+    /// logged if
+    /// [`report_invalid_bounding_boxes`](ParseOptions::report_invalid_bounding_boxes)
+    /// is set.
+    ///
+    /// ```
+    /// use smufl::{Error, Metadata, ParseOptions};
+    ///
+    /// let json = r#"{"fontName": "Test", "glyphAdvanceWidths": {"notAGlyph": 1.0}}"#;
+    /// let options = ParseOptions {
+    ///     strict_unknown_glyphs: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(matches!(
+    ///     Metadata::from_reader_with(json.as_bytes(), &options),
+    ///     Err(Error::UnknownGlyphs(_))
+    /// ));
+    /// ```
+    pub fn from_reader_with(
+        reader: impl Read,
+        options: &ParseOptions,
+    ) -> Result<Self, crate::Error> {
+        Self::from_reader_with_report(reader, options).map(|(metadata, _)| metadata)
+    }
+
+    /// Like [`from_reader_with`](Self::from_reader_with), but also returns a
+    /// [`ParseReport`] of the names in the metadata file which were not
+    /// recognized, and the sections which were missing.
+    ///
+    /// Unknown anchors and duplicate glyphs are logged at the WARN level, along
+    /// with unknown glyphs and any reported invalid bounding boxes.
+    ///
+    /// ```
+    /// use smufl::{Metadata, ParseOptions};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let json = r#"{
+    ///     "fontName": "Test",
+    ///     "glyphsWithAnchors": {"noteheadBlack": {"vendorAnchor": [0, 0]}}
+    /// }"#;
+    /// let (_, report) = Metadata::from_reader_with_report(json.as_bytes(), &ParseOptions::default())?;
+    ///
+    /// assert_eq!(
+    ///     report.unknown_anchors,
+    ///     [("noteheadBlack".to_owned(), "vendorAnchor".to_owned())]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_reader_with_report(
+        reader: impl Read,
+        options: &ParseOptions,
+    ) -> Result<(Self, ParseReport), crate::Error> {
+        Self::parse(reader, options, |metadata, report| {
+            metadata.log_unknowns(options.max_reported_unknown_glyphs);
+            if !report.unknown_anchors.is_empty() {
+                let unknown_anchors = &report.unknown_anchors;
+                warn!(?unknown_anchors, "Unknown anchors found");
+            }
+            if !report.duplicate_glyphs.is_empty() {
+                let duplicate_glyphs = &report.duplicate_glyphs;
+                warn!(?duplicate_glyphs, "Duplicate glyphs found");
+            }
+            if !report.invalid_bounding_boxes.is_empty() {
+                let invalid = report
+                    .invalid_bounding_boxes
+                    .iter()
+                    .map(|&(glyph, _)| glyph)
+                    .collect::<Vec<_>>();
+                warn!(?invalid, "Invalid bounding boxes found");
+            }
+        })
+    }
+
+    /// Like [`from_reader_with`](Self::from_reader_with), but passes
+    /// [`Warning`]s to `sink` instead of logging them.
+    ///
+    /// The warnings are unknown glyphs (at most
+    /// [`max_reported_unknown_glyphs`](ParseOptions::max_reported_unknown_glyphs)
+    /// of them), then unknown anchors, then missing sections, then duplicate
+    /// glyphs, then invalid bounding boxes, each sorted by name. No warnings
+    /// are passed if parsing fails, including when the metadata is rejected by
+    /// [`reject_non_finite`](ParseOptions::reject_non_finite) or
+    /// [`validate`](ParseOptions::validate).
+    ///
+    /// [`Warning`]: crate::Warning
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(reader, sink), err(Debug))
+    )]
+    pub fn from_reader_with_sink(
+        reader: impl Read,
+        options: &ParseOptions,
+        mut sink: impl WarningSink,
+    ) -> Result<Self, crate::Error> {
+        let (metadata, _) = Self::parse(reader, options, |_, report| {
+            for warning in warnings(report, options.max_reported_unknown_glyphs) {
+                sink.warn(warning);
+            }
+        })?;
 
         Ok(metadata)
     }
 
+    /// Parses metadata according to `options`, calling `warn` to report any
+    /// warnings once the metadata has been parsed, checked for non-finite
+    /// values and validated.
+    fn parse(
+        mut reader: impl Read,
+        options: &ParseOptions,
+        warn: impl FnOnce(&Self, &ParseReport),
+    ) -> Result<(Self, ParseReport), crate::Error> {
+        let parsed: Parsed = if options.lenient {
+            let mut json = Vec::new();
+            reader.read_to_end(&mut json)?;
+
+            crate::json_path::deserialize(
+                serde_json::de::SliceRead::new(&crate::lenient_json::strip(&json)),
+                None,
+            )?
+        } else {
+            crate::json_path::deserialize(serde_json::de::IoRead::new(reader), None)?
+        };
+        let (mut metadata, mut report) = parsed.into_metadata();
+        if !options.detect_duplicate_glyphs {
+            report.duplicate_glyphs.clear();
+        }
+        if options.report_invalid_bounding_boxes {
+            report.invalid_bounding_boxes = metadata
+                .bounding_boxes
+                .iter()
+                .filter(|(_, bounding_box)| !bounding_box.is_valid())
+                .map(|(glyph, &bounding_box)| (glyph, bounding_box))
+                .sorted_by_key(|(glyph, _)| glyph.name())
+                .collect();
+        }
+        if options.normalize_bounding_boxes {
+            metadata.bounding_boxes.normalize();
+        }
+
+        if options.strict_unknown_glyphs && !report.unknown_glyphs.is_empty() {
+            let mut unknowns = report.unknown_glyphs;
+            if let Some(max) = options.max_reported_unknown_glyphs {
+                unknowns.truncate(max);
+            }
+
+            return Err(crate::Error::UnknownGlyphs(unknowns));
+        }
+
+        if options.reject_non_finite {
+            let issues = metadata
+                .find_non_finite()
+                .into_iter()
+                .map(|value| {
+                    ValidationIssue::new(
+                        Severity::Error,
+                        value.glyph.map(|glyph| glyph.name().to_owned()),
+                        format!("non-finite value at {value}"),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if !issues.is_empty() {
+                return Err(crate::Error::Validation(issues));
+            }
+        }
+
+        if options.validate {
+            let errors = metadata
+                .validate()
+                .into_iter()
+                .filter(|issue| issue.severity == Severity::Error)
+                .collect::<Vec<_>>();
+            if !errors.is_empty() {
+                return Err(crate::Error::Validation(errors));
+            }
+        }
+
+        warn(&metadata, &report);
+
+        Ok((metadata, report))
+    }
+
+    /// Like [`from_reader`](Self::from_reader), but parses the advance widths,
+    /// anchors and bounding boxes in parallel on the [`rayon`] thread pool.
+    ///
+    /// This reduces the time to load large metadata files, such as those of
+    /// fonts with complete SMuFL coverage.
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_reader_parallel(reader: impl Read) -> Result<Self, crate::Error> {
+        // Unknown glyphs are logged as each section is parsed.
+        crate::LazyMetadata::from_reader(reader)?.into_metadata_parallel()
+    }
+
+    /// Fetches and deserializes `Metadata` from the JSON file at `url`.
+    ///
+    /// Unknown glyphs are logged as in [`from_reader`](Self::from_reader).
+    #[cfg(feature = "fetch")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(err(Debug)))]
+    pub fn from_url(url: &str) -> Result<Self, crate::FetchError> {
+        let response = ureq::get(url).call()?;
+
+        Ok(Self::from_reader(response.into_reader())?)
+    }
+
+    /// Fetches and deserializes the published metadata for `font`.
+    ///
+    /// This is useful for build tools and CI jobs which need reference
+    /// metrics without vendoring the font repositories.
+    #[cfg(feature = "fetch")]
+    pub fn from_reference_font(font: crate::ReferenceFont) -> Result<Self, crate::FetchError> {
+        Self::from_url(font.metadata_url())
+    }
+
+    /// Deserializes `Metadata` from YAML data with the same structure as a
+    /// JSON metadata file.
+    ///
+    /// Unknown glyphs are logged as in [`from_reader`](Self::from_reader).
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(reader), err(Debug)))]
+    pub fn from_yaml_reader(reader: impl Read) -> Result<Self, serde_yaml::Error> {
+        let metadata: Self = serde_yaml::from_reader(reader)?;
+        metadata.log_unknowns(None);
+
+        Ok(metadata)
+    }
+
+    /// Deserializes `Metadata` from a TOML document with the same structure as
+    /// a JSON metadata file.
+    ///
+    /// Unknown glyphs are logged as in [`from_reader`](Self::from_reader).
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(text), err(Debug)))]
+    pub fn from_toml_str(text: &str) -> Result<Self, toml::de::Error> {
+        let metadata: Self = toml::from_str(text)?;
+        metadata.log_unknowns(None);
+
+        Ok(metadata)
+    }
+
+    /// Serializes `Metadata` as JSON, according to `options`.
+    ///
+    /// For the default options, this is equivalent to serializing with
+    /// [`serde_json::to_writer`].
+    pub fn to_writer(
+        &self,
+        writer: impl Write,
+        options: &SerializeOptions,
+    ) -> Result<(), serde_json::Error> {
+        let rounded;
+        let metadata = match options.precision {
+            Some(decimals) => {
+                rounded = self.round_decimals(decimals);
+                &rounded
+            }
+            None => self,
+        };
+
+        match (options.key_order, options.pretty) {
+            (KeyOrder::Spec, false) => serde_json::to_writer(writer, metadata),
+            (KeyOrder::Spec, true) => serde_json::to_writer_pretty(writer, metadata),
+            (KeyOrder::Alphabetical, pretty) => {
+                let value = sort_keys(serde_json::to_value(metadata)?);
+
+                if pretty {
+                    serde_json::to_writer_pretty(writer, &value)
+                } else {
+                    serde_json::to_writer(writer, &value)
+                }
+            }
+        }
+    }
+
     /// Returns a new `Metadata` which combines `self` and `defaults`, using
     /// values from `defaults` wherever `self` does not have data.
     pub fn with_defaults(mut self, defaults: Self) -> Self {
@@ -67,24 +369,488 @@ impl Metadata {
         self
     }
 
-    fn log_unknowns(&self) {
-        let unknowns = self
+    /// Returns the approximate number of bytes allocated on the heap for each
+    /// section of the metadata, e.g. for monitoring applications which keep
+    /// many fonts loaded.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            font_name: self.font_name.capacity(),
+            engraving_defaults: self.engraving_defaults.text_font_family.heap_size(),
+            advance_widths: self.advance_widths.heap_size(),
+            anchors: self.anchors.heap_size(),
+            bounding_boxes: self.bounding_boxes.heap_size(),
+        }
+    }
+
+    /// Shrinks every section's allocations to fit its contents.
+    ///
+    /// Maps are over-allocated as they grow while parsing, and when merging in
+    /// defaults with [`with_defaults`](Self::with_defaults), so this reduces
+    /// the memory used by metadata which is kept loaded for a long time.
+    pub fn shrink_to_fit(&mut self) {
+        self.font_name.shrink_to_fit();
+        self.engraving_defaults.text_font_family.shrink_to_fit();
+        self.advance_widths.shrink_to_fit();
+        self.anchors.shrink_to_fit();
+        self.bounding_boxes.shrink_to_fit();
+    }
+
+    /// Returns every value which is NaN or infinite, with its location.
+    ///
+    /// Values are ordered by section, then by glyph name, then by field. JSON
+    /// can't represent non-finite numbers, but they can be read from other
+    /// formats, such as YAML, or produced by transforming the metadata, and
+    /// would silently break layout.
+    ///
+    /// See also [`ParseOptions::reject_non_finite`].
+    pub fn find_non_finite(&self) -> Vec<NonFiniteValue> {
+        let first_non_finite = |coord: Coord| {
+            [coord.x(), coord.y()]
+                .into_iter()
+                .map(|value| value.0)
+                .find(|value| !value.is_finite())
+        };
+
+        let engraving_defaults = self
+            .engraving_defaults
+            .values()
+            .filter(|(_, value)| !value.0.is_finite())
+            .map(|(name, value)| NonFiniteValue {
+                section: "engravingDefaults",
+                glyph: None,
+                field: Some(name),
+                value: value.0,
+            });
+        let advance_widths = self
             .advance_widths
+            .iter()
+            .filter(|(_, width)| !width.0.is_finite())
+            .map(|(glyph, width)| NonFiniteValue {
+                section: "glyphAdvanceWidths",
+                glyph: Some(glyph),
+                field: None,
+                value: width.0,
+            });
+        let anchors = self.anchors.iter().flat_map(|(glyph, anchors)| {
+            anchors.iter().filter_map(move |(kind, coord)| {
+                Some(NonFiniteValue {
+                    section: "glyphsWithAnchors",
+                    glyph: Some(glyph),
+                    field: Some(kind.name()),
+                    value: first_non_finite(coord)?,
+                })
+            })
+        });
+        let bounding_boxes = self
+            .bounding_boxes
+            .iter()
+            .flat_map(|(glyph, bounding_box)| {
+                [("bBoxNE", bounding_box.ne), ("bBoxSW", bounding_box.sw)]
+                    .into_iter()
+                    .filter_map(move |(field, coord)| {
+                        Some(NonFiniteValue {
+                            section: "glyphBBoxes",
+                            glyph: Some(glyph),
+                            field: Some(field),
+                            value: first_non_finite(coord)?,
+                        })
+                    })
+            });
+        let by_glyph_name = |a: &NonFiniteValue, b: &NonFiniteValue| {
+            a.glyph
+                .map(|glyph| glyph.name())
+                .cmp(&b.glyph.map(|glyph| glyph.name()))
+        };
+
+        engraving_defaults
+            .chain(advance_widths.sorted_by(by_glyph_name))
+            .chain(anchors.sorted_by(by_glyph_name))
+            .chain(bounding_boxes.sorted_by(by_glyph_name))
+            .collect()
+    }
+
+    /// Returns the optical center of `glyph`, for aligning it relative to a
+    /// notehead or stem.
+    ///
+    /// This is the glyph's [`optical_center`](crate::Anchors::optical_center)
+    /// anchor if present, and otherwise the center of its bounding box.
+    pub fn optical_center(&self, glyph: Glyph) -> Option<Coord> {
+        self.anchors
+            .get(glyph)
+            .and_then(|anchors| anchors.optical_center)
+            .or_else(|| {
+                self.bounding_boxes
+                    .get(glyph)
+                    .map(|bounding_box| bounding_box.center())
+            })
+    }
+
+    /// Returns the points at the top and bottom of `glyph`, for aligning
+    /// numerals of differing heights, e.g. when stacking the digits of a time
+    /// signature.
+    ///
+    /// Each point is taken from the glyph's
+    /// [`numeral_top`](crate::Anchors::numeral_top) or
+    /// [`numeral_bottom`](crate::Anchors::numeral_bottom) anchor if present,
+    /// and otherwise from the center of the top or bottom edge of its
+    /// bounding box. Returns `None` if either point can't be determined.
+    pub fn numeral_extents(&self, glyph: Glyph) -> Option<NumeralExtents> {
+        let anchors = self.anchors.get(glyph).unwrap_or_default();
+        let bounding_box = self.bounding_boxes.get(glyph);
+
+        let top = anchors.numeral_top.or_else(|| {
+            bounding_box.map(|bounding_box| Coord(bounding_box.center().x(), bounding_box.top()))
+        })?;
+        let bottom = anchors.numeral_bottom.or_else(|| {
+            bounding_box.map(|bounding_box| Coord(bounding_box.center().x(), bounding_box.bottom()))
+        })?;
+
+        Some(NumeralExtents { top, bottom })
+    }
+
+    /// Returns how far `right` may be moved leftwards into the bounding box of
+    /// `left` without the glyphs colliding, based on their cut-out anchors
+    /// ([`cut_out_ne`](crate::Anchors::cut_out_ne),
+    /// [`cut_out_se`](crate::Anchors::cut_out_se),
+    /// [`cut_out_sw`](crate::Anchors::cut_out_sw) and
+    /// [`cut_out_nw`](crate::Anchors::cut_out_nw)), e.g. to tuck an accidental
+    /// against a notehead.
+    ///
+    /// `vertical_offset` is the position of the origin of `right` relative to
+    /// the origin of `left`. If the glyphs were placed so that their bounding
+    /// boxes abut, `right` can be moved left by the returned amount.
+    ///
+    /// Returns `None` if either glyph has no bounding box, or if the glyphs
+    /// don't overlap vertically (in which case they can't collide).
+    pub fn cut_out_overlap(
+        &self,
+        left: Glyph,
+        right: Glyph,
+        vertical_offset: StaffSpaces,
+    ) -> Option<StaffSpaces> {
+        let left_box = self.bounding_boxes.get(left)?;
+        let right_box = self
+            .bounding_boxes
+            .get(right)?
+            .translated(Coord(StaffSpaces::zero(), vertical_offset));
+        let left_anchors = self.anchors.get(left).unwrap_or_default();
+        let right_anchors = self.anchors.get(right).unwrap_or_default();
+
+        let overlap = left_box.y_range().intersection(&right_box.y_range())?;
+
+        // The edges are piecewise constant, changing only at the heights of
+        // the cut-outs, so it is enough to check the middle of each band.
+        let required = left_anchors
+            .cut_out_heights()
+            .chain(right_anchors.cut_out_heights().map(|y| y + vertical_offset))
+            .filter(|&y| overlap.contains(y))
+            .chain([overlap.min, overlap.max])
+            .sorted()
+            .tuple_windows()
+            .filter(|(bottom, top)| bottom < top)
+            .map(|(bottom, top)| {
+                let y = StaffSpaces::lerp(bottom, top, 0.5);
+
+                left_anchors.right_edge_at(&left_box, y)
+                    - right_anchors.left_edge_at(&right_box, y - vertical_offset)
+            })
+            .max()?;
+
+        Some((left_box.right() - right_box.left()) - required)
+    }
+
+    fn round_decimals(&self, decimals: usize) -> Self {
+        Self {
+            font_name: self.font_name.clone(),
+            engraving_defaults: self.engraving_defaults.round_decimals(decimals),
+            advance_widths: self
+                .advance_widths
+                .map_values(|width| width.round_decimals(decimals)),
+            anchors: self
+                .anchors
+                .map_values(|anchors| anchors.round_decimals(decimals)),
+            bounding_boxes: self
+                .bounding_boxes
+                .map_values(|bounding_box| bounding_box.round_decimals(decimals)),
+        }
+    }
+
+    /// Returns the names of any unknown glyphs in any section, sorted and
+    /// without duplicates.
+    fn unknown_glyphs(&self) -> Vec<String> {
+        self.advance_widths
             .unknown_glyphs()
             .chain(self.anchors.unknown_glyphs())
             .chain(self.bounding_boxes.unknown_glyphs())
             .unique()
             .sorted()
-            .collect::<Vec<_>>();
+            .cloned()
+            .collect()
+    }
+
+    /// Logs the names of any unknown glyphs, listing at most `max` of them.
+    fn log_unknowns(&self, max: Option<usize>) {
+        let mut unknowns = self.unknown_glyphs();
+        let total = unknowns.len();
+
+        if let Some(max) = max {
+            unknowns.truncate(max);
+        }
 
         if unknowns.is_empty() {
             debug!("No unknowns glyphs found");
+        } else if unknowns.len() < total {
+            warn!(?unknowns, %total, "Unknown glyphs found");
         } else {
             warn!(?unknowns, "Unknown glyphs found");
         }
     }
 }
 
+/// The structure of a metadata file, as parsed by
+/// [`Metadata::from_reader_with_report`], which also keeps track of missing
+/// sections, duplicate glyphs, and the names of any unknown anchors.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Parsed {
+    font_name: String,
+    engraving_defaults: Option<EngravingDefaults>,
+    glyph_advance_widths: Option<ParsedSection<StaffSpaces>>,
+    glyphs_with_anchors: Option<ParsedSection<ParsedAnchors>>,
+    #[serde(rename = "glyphBBoxes")]
+    glyph_bboxes: Option<ParsedSection<BoundingBox>>,
+}
+
+/// A section of a metadata file which maps glyph names to `T`, along with the
+/// names of glyphs which have more than one entry.
+struct ParsedSection<T> {
+    data: GlyphData<T>,
+    duplicates: Vec<String>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ParsedSection<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ParsedSectionVisitor(PhantomData))
+    }
+}
+
+struct ParsedSectionVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ParsedSectionVisitor<T> {
+    type Value = ParsedSection<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of glyph names to glyph data")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut data = GlyphData::default();
+        let mut duplicates = Vec::new();
+
+        while let Some((glyph, value)) = map.next_entry::<GlyphOrUnknown, T>()? {
+            if data.contains(&glyph) {
+                duplicates.push(glyph.name().to_owned());
+            }
+            data.insert(glyph, value);
+        }
+
+        duplicates.sort();
+        duplicates.dedup();
+
+        Ok(ParsedSection { data, duplicates })
+    }
+}
+
+/// A glyph's anchors, along with the names of any anchors which are not in
+/// SMuFL.
+///
+/// This is deserialized with a map visitor rather than by flattening
+/// [`Anchors`], so that errors in an anchor's value keep its name and index in
+/// their path.
+struct ParsedAnchors {
+    anchors: Anchors,
+    unknown: Vec<String>,
+}
+
+impl<'de> Deserialize<'de> for ParsedAnchors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ParsedAnchorsVisitor)
+    }
+}
+
+struct ParsedAnchorsVisitor;
+
+impl<'de> Visitor<'de> for ParsedAnchorsVisitor {
+    type Value = ParsedAnchors;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of anchor names to coordinates")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut anchors = Anchors::default();
+        let mut unknown = Vec::new();
+
+        while let Some(name) = map.next_key::<String>()? {
+            match AnchorKind::from_name(&name) {
+                Some(kind) => {
+                    let anchor = anchors.get_mut(kind);
+                    if anchor.is_some() {
+                        return Err(de::Error::duplicate_field(kind.name()));
+                    }
+                    *anchor = map.next_value()?;
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                    unknown.push(name);
+                }
+            }
+        }
+
+        Ok(ParsedAnchors { anchors, unknown })
+    }
+}
+
+impl Parsed {
+    fn into_metadata(self) -> (Metadata, ParseReport) {
+        let missing_sections = [
+            ("engravingDefaults", self.engraving_defaults.is_none()),
+            ("glyphAdvanceWidths", self.glyph_advance_widths.is_none()),
+            ("glyphsWithAnchors", self.glyphs_with_anchors.is_none()),
+            ("glyphBBoxes", self.glyph_bboxes.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(section, missing)| missing.then_some(section))
+        .collect();
+
+        let duplicate_glyphs = [
+            (
+                "glyphAdvanceWidths",
+                self.glyph_advance_widths
+                    .as_ref()
+                    .map(|section| &section.duplicates),
+            ),
+            (
+                "glyphsWithAnchors",
+                self.glyphs_with_anchors
+                    .as_ref()
+                    .map(|section| &section.duplicates),
+            ),
+            (
+                "glyphBBoxes",
+                self.glyph_bboxes
+                    .as_ref()
+                    .map(|section| &section.duplicates),
+            ),
+        ]
+        .into_iter()
+        .flat_map(|(section, duplicates)| {
+            duplicates
+                .into_iter()
+                .flatten()
+                .map(move |glyph| (section, glyph.clone()))
+        })
+        .collect();
+
+        let anchors = self
+            .glyphs_with_anchors
+            .map(|section| section.data)
+            .unwrap_or_default();
+        let unknown_anchors = anchors
+            .entries()
+            .flat_map(|(glyph, parsed)| {
+                parsed
+                    .unknown
+                    .iter()
+                    .map(move |anchor| (glyph.name().to_owned(), anchor.clone()))
+            })
+            .sorted()
+            .collect();
+
+        let metadata = Metadata {
+            font_name: self.font_name,
+            engraving_defaults: self.engraving_defaults.unwrap_or_default(),
+            advance_widths: self
+                .glyph_advance_widths
+                .map(|section| section.data)
+                .unwrap_or_default(),
+            anchors: anchors.map_values(|parsed| parsed.anchors),
+            bounding_boxes: self
+                .glyph_bboxes
+                .map(|section| section.data)
+                .unwrap_or_default(),
+        };
+        let report = ParseReport {
+            unknown_glyphs: metadata.unknown_glyphs(),
+            unknown_anchors,
+            missing_sections,
+            duplicate_glyphs,
+            invalid_bounding_boxes: Vec::new(),
+        };
+
+        (metadata, report)
+    }
+}
+
+/// Returns the warnings to pass to a [`WarningSink`] for `report`, including
+/// at most `max_unknown_glyphs` unknown glyphs.
+fn warnings(report: &ParseReport, max_unknown_glyphs: Option<usize>) -> Vec<Warning> {
+    let unknown_glyphs = report
+        .unknown_glyphs
+        .iter()
+        .take(max_unknown_glyphs.unwrap_or(usize::MAX))
+        .map(|name| Warning::UnknownGlyph { name: name.clone() });
+    let unknown_anchors =
+        report
+            .unknown_anchors
+            .iter()
+            .map(|(glyph, anchor)| Warning::UnknownAnchor {
+                glyph: glyph.clone(),
+                anchor: anchor.clone(),
+            });
+    let missing_sections = report
+        .missing_sections
+        .iter()
+        .map(|&section| Warning::MissingSection { section });
+    let duplicate_glyphs =
+        report
+            .duplicate_glyphs
+            .iter()
+            .map(|(section, glyph)| Warning::DuplicateGlyph {
+                section,
+                glyph: glyph.clone(),
+            });
+    let invalid_bounding_boxes =
+        report
+            .invalid_bounding_boxes
+            .iter()
+            .map(|&(glyph, bounding_box)| Warning::InvalidBoundingBox {
+                glyph,
+                bounding_box,
+            });
+
+    unknown_glyphs
+        .chain(unknown_anchors)
+        .chain(missing_sections)
+        .chain(duplicate_glyphs)
+        .chain(invalid_bounding_boxes)
+        .collect()
+}
+
+/// Recursively rebuilds every object in `value` with its keys in alphabetical
+/// order.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (key, sort_keys(value)))
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .collect(),
+        Value::Array(values) => values.into_iter().map(sort_keys).collect(),
+        value => value,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fs::File, io::BufReader, path::Path};
@@ -94,7 +860,7 @@ mod tests {
     use similar_asserts::assert_eq;
 
     use super::*;
-    use crate::{Anchors, BoundingBox, Coord, Glyph, StaffSpaces};
+    use crate::{Anchors, BoundingBox};
 
     macro_rules! set_snapshot_suffix {
         ($($expr:expr),*) => {
@@ -239,4 +1005,772 @@ mod tests {
             non_empty.bounding_boxes.get(Glyph::NoteheadBlack),
         );
     }
+
+    #[rstest]
+    fn memory_footprint(non_empty: Metadata) {
+        let footprint = non_empty.memory_footprint();
+
+        assert_eq!(footprint.font_name, non_empty.font_name.capacity());
+        assert_eq!(footprint.engraving_defaults, 0);
+        assert!(footprint.advance_widths > 0);
+        assert!(footprint.anchors > 0);
+        assert!(footprint.bounding_boxes > 0);
+        assert_eq!(
+            footprint.total(),
+            footprint.font_name
+                + footprint.advance_widths
+                + footprint.anchors
+                + footprint.bounding_boxes
+        );
+    }
+
+    #[rstest]
+    fn shrink_to_fit(empty: Metadata) {
+        let mut metadata = Metadata::from_reader(
+            r#"{"fontName": "Test", "glyphAdvanceWidths": {"noteheadBlack": 1.18}}"#.as_bytes(),
+        )
+        .unwrap()
+        .with_defaults(empty);
+        metadata.font_name.reserve(100);
+        let before = metadata.memory_footprint();
+
+        metadata.shrink_to_fit();
+
+        let after = metadata.memory_footprint();
+        assert_eq!(after.font_name, "Test".len());
+        assert!(after.advance_widths <= before.advance_widths);
+        assert!(after.total() < before.total());
+        assert_eq!(
+            metadata.advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+    }
+
+    #[test]
+    fn optical_center_from_anchor() {
+        let metadata = Metadata {
+            anchors: [(
+                Glyph::DynamicForte,
+                Anchors {
+                    optical_center: Some(Coord(StaffSpaces(0.5), StaffSpaces(0.0))),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            bounding_boxes: [(
+                Glyph::DynamicForte,
+                BoundingBox {
+                    ne: Coord(StaffSpaces(2.0), StaffSpaces(2.0)),
+                    sw: Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+                },
+            )]
+            .into(),
+            ..empty()
+        };
+
+        assert_eq!(
+            metadata.optical_center(Glyph::DynamicForte),
+            Some(Coord(StaffSpaces(0.5), StaffSpaces(0.0)))
+        );
+    }
+
+    #[test]
+    fn optical_center_from_bounding_box() {
+        let metadata = Metadata {
+            anchors: [(Glyph::DynamicForte, Anchors::default())].into(),
+            bounding_boxes: [(
+                Glyph::DynamicForte,
+                BoundingBox {
+                    ne: Coord(StaffSpaces(2.0), StaffSpaces(2.0)),
+                    sw: Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+                },
+            )]
+            .into(),
+            ..empty()
+        };
+
+        assert_eq!(
+            metadata.optical_center(Glyph::DynamicForte),
+            Some(Coord(StaffSpaces(1.0), StaffSpaces(1.0)))
+        );
+    }
+
+    #[rstest]
+    fn optical_center_missing(empty: Metadata) {
+        assert_eq!(empty.optical_center(Glyph::DynamicForte), None);
+    }
+
+    #[test]
+    fn numeral_extents() {
+        let metadata = Metadata {
+            anchors: [(
+                Glyph::TimeSig4,
+                Anchors {
+                    numeral_top: Some(Coord(StaffSpaces(0.5), StaffSpaces(1.5))),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            bounding_boxes: [(
+                Glyph::TimeSig4,
+                BoundingBox {
+                    ne: Coord(StaffSpaces(2.0), StaffSpaces(2.0)),
+                    sw: Coord(StaffSpaces(0.0), StaffSpaces(-1.0)),
+                },
+            )]
+            .into(),
+            ..empty()
+        };
+
+        assert_eq!(
+            metadata.numeral_extents(Glyph::TimeSig4),
+            Some(NumeralExtents {
+                top: Coord(StaffSpaces(0.5), StaffSpaces(1.5)),
+                bottom: Coord(StaffSpaces(1.0), StaffSpaces(-1.0)),
+            })
+        );
+    }
+
+    #[test]
+    fn numeral_extents_incomplete() {
+        let metadata = Metadata {
+            anchors: [(
+                Glyph::TimeSig4,
+                Anchors {
+                    numeral_top: Some(Coord(StaffSpaces(0.5), StaffSpaces(1.5))),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..empty()
+        };
+
+        assert_eq!(metadata.numeral_extents(Glyph::TimeSig4), None);
+    }
+
+    fn cut_out_metadata() -> Metadata {
+        Metadata {
+            anchors: [
+                (
+                    Glyph::NoteheadBlack,
+                    Anchors {
+                        cut_out_nw: Some(Coord(StaffSpaces(0.5), StaffSpaces(0.25))),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    Glyph::AccidentalFlat,
+                    Anchors {
+                        cut_out_se: Some(Coord(StaffSpaces(0.75), StaffSpaces(0.5))),
+                        ..Default::default()
+                    },
+                ),
+            ]
+            .into(),
+            bounding_boxes: [
+                (
+                    Glyph::NoteheadBlack,
+                    BoundingBox {
+                        ne: Coord(StaffSpaces(1.0), StaffSpaces(0.5)),
+                        sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+                    },
+                ),
+                (
+                    Glyph::AccidentalFlat,
+                    BoundingBox {
+                        ne: Coord(StaffSpaces(1.0), StaffSpaces(2.0)),
+                        sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+                    },
+                ),
+            ]
+            .into(),
+            ..empty()
+        }
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.0), Some(StaffSpaces(0.0)))]
+    #[case(StaffSpaces(0.0), Some(StaffSpaces(0.25)))]
+    #[case(StaffSpaces(-0.75), Some(StaffSpaces(0.75)))]
+    #[case(StaffSpaces(-3.0), None)]
+    fn cut_out_overlap(
+        #[case] vertical_offset: StaffSpaces,
+        #[case] expected: Option<StaffSpaces>,
+    ) {
+        assert_eq!(
+            cut_out_metadata().cut_out_overlap(
+                Glyph::AccidentalFlat,
+                Glyph::NoteheadBlack,
+                vertical_offset
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn cut_out_overlap_missing_bounding_box() {
+        assert_eq!(
+            cut_out_metadata().cut_out_overlap(
+                Glyph::AccidentalFlat,
+                Glyph::AccidentalSharp,
+                StaffSpaces(0.0)
+            ),
+            None
+        );
+    }
+
+    fn serialize_metadata() -> Metadata {
+        Metadata {
+            font_name: "Test".to_owned(),
+            engraving_defaults: EngravingDefaults {
+                stem_thickness: Some(StaffSpaces(0.12345)),
+                ..Default::default()
+            },
+            advance_widths: [(Glyph::NoteheadBlack, StaffSpaces(1.18))].into(),
+            bounding_boxes: [(
+                Glyph::NoteheadBlack,
+                BoundingBox {
+                    ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+                    sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.50004)),
+                },
+            )]
+            .into(),
+            ..empty()
+        }
+    }
+
+    #[rstest]
+    #[case::default(
+        SerializeOptions::default(),
+        r#"{"fontName":"Test","engravingDefaults":{"textFontFamily":[],"stemThickness":0.12345},"glyphAdvanceWidths":{"noteheadBlack":1.18},"glyphsWithAnchors":{},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.18,0.5],"bBoxSW":[0.0,-0.50004]}}}"#
+    )]
+    #[case::alphabetical(
+        SerializeOptions {
+            key_order: KeyOrder::Alphabetical,
+            ..Default::default()
+        },
+        r#"{"engravingDefaults":{"stemThickness":0.12345,"textFontFamily":[]},"fontName":"Test","glyphAdvanceWidths":{"noteheadBlack":1.18},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.18,0.5],"bBoxSW":[0.0,-0.50004]}},"glyphsWithAnchors":{}}"#
+    )]
+    #[case::precision(
+        SerializeOptions {
+            precision: Some(2),
+            ..Default::default()
+        },
+        r#"{"fontName":"Test","engravingDefaults":{"textFontFamily":[],"stemThickness":0.12},"glyphAdvanceWidths":{"noteheadBlack":1.18},"glyphsWithAnchors":{},"glyphBBoxes":{"noteheadBlack":{"bBoxNE":[1.18,0.5],"bBoxSW":[0.0,-0.5]}}}"#
+    )]
+    fn to_writer(#[case] options: SerializeOptions, #[case] expected: &str) -> Result<()> {
+        let mut output = Vec::new();
+        serialize_metadata().to_writer(&mut output, &options)?;
+
+        assert_eq!(String::from_utf8(output)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_writer_round_trip() -> Result<()> {
+        let metadata = serialize_metadata();
+        let mut output = Vec::new();
+        metadata.to_writer(&mut output, &SerializeOptions::default())?;
+
+        let parsed = Metadata::from_reader(output.as_slice())?;
+
+        assert_eq!(parsed.engraving_defaults, metadata.engraving_defaults);
+        assert_eq!(
+            parsed.bounding_boxes.get(Glyph::NoteheadBlack),
+            metadata.bounding_boxes.get(Glyph::NoteheadBlack)
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(Metadata);
+        let properties = &schema.as_value()["properties"];
+
+        assert_eq!(
+            schema.as_value()["required"],
+            serde_json::json!(["fontName"])
+        );
+        assert!(properties["glyphBBoxes"].is_object());
+        assert!(properties["glyphsWithAnchors"].is_object());
+    }
+
+    #[rstest]
+    #[case::all(None, &["aaa", "notAGlyph", "zzz"])]
+    #[case::capped(Some(2), &["aaa", "notAGlyph"])]
+    fn from_reader_with_strict_unknown_glyphs(
+        #[case] max_reported_unknown_glyphs: Option<usize>,
+        #[case] expected: &[&str],
+    ) {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphAdvanceWidths": {"zzz": 1.0, "noteheadBlack": 1.18},
+            "glyphBBoxes": {"notAGlyph": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]}, "aaa": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]}}
+        }"#;
+        let options = ParseOptions {
+            strict_unknown_glyphs: true,
+            max_reported_unknown_glyphs,
+            ..Default::default()
+        };
+
+        match Metadata::from_reader_with(json.as_bytes(), &options) {
+            Err(crate::Error::UnknownGlyphs(names)) => assert_eq!(names, expected),
+            result => panic!("unexpected result: {result:?}"),
+        }
+        assert!(Metadata::from_reader(json.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn from_reader_with_report() -> Result<()> {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphAdvanceWidths": {"notAGlyph": 1.0},
+            "glyphsWithAnchors": {
+                "noteheadBlack": {"stemUpSE": [1.18, 0.168], "zAnchor": [0, 0], "aAnchor": [0, 0]},
+                "notAGlyph": {"stemUpSE": [1, 1], "vendorAnchor": {"nested": true}}
+            }
+        }"#;
+
+        let (metadata, report) =
+            Metadata::from_reader_with_report(json.as_bytes(), &ParseOptions::default())?;
+
+        assert_eq!(
+            metadata.anchors.get(Glyph::NoteheadBlack),
+            Some(Anchors {
+                stem_up_se: Some(Coord(StaffSpaces(1.18), StaffSpaces(0.168))),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            report,
+            ParseReport {
+                unknown_glyphs: vec!["notAGlyph".to_owned()],
+                unknown_anchors: vec![
+                    ("notAGlyph".to_owned(), "vendorAnchor".to_owned()),
+                    ("noteheadBlack".to_owned(), "aAnchor".to_owned()),
+                    ("noteheadBlack".to_owned(), "zAnchor".to_owned()),
+                ],
+                missing_sections: vec!["engravingDefaults", "glyphBBoxes"],
+                duplicate_glyphs: vec![],
+                invalid_bounding_boxes: vec![],
+            }
+        );
+
+        let json = r#"{
+            "fontName": "Test",
+            "engravingDefaults": {},
+            "glyphAdvanceWidths": {},
+            "glyphsWithAnchors": {},
+            "glyphBBoxes": {}
+        }"#;
+        let (_, report) =
+            Metadata::from_reader_with_report(json.as_bytes(), &ParseOptions::default())?;
+        assert!(report.is_empty());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(false, vec![])]
+    #[case(
+        true,
+        vec![
+            ("glyphAdvanceWidths", "notAGlyph".to_owned()),
+            ("glyphAdvanceWidths", "noteheadBlack".to_owned()),
+            ("glyphBBoxes", "noteheadWhole".to_owned()),
+        ]
+    )]
+    fn duplicate_glyphs(
+        #[case] detect_duplicate_glyphs: bool,
+        #[case] expected: Vec<(&'static str, String)>,
+    ) -> Result<()> {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphAdvanceWidths": {
+                "noteheadBlack": 1.0,
+                "notAGlyph": 1.0,
+                "noteheadBlack": 2.0,
+                "notAGlyph": 2.0,
+                "noteheadBlack": 3.0
+            },
+            "glyphsWithAnchors": {"noteheadBlack": {}},
+            "glyphBBoxes": {
+                "noteheadWhole": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                "noteheadWhole": {"bBoxNE": [2, 1], "bBoxSW": [0, 0]}
+            }
+        }"#;
+        let options = ParseOptions {
+            detect_duplicate_glyphs,
+            ..Default::default()
+        };
+
+        let (metadata, report) = Metadata::from_reader_with_report(json.as_bytes(), &options)?;
+
+        assert_eq!(report.duplicate_glyphs, expected);
+        assert_eq!(
+            metadata.advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(3.0))
+        );
+        assert_eq!(
+            metadata
+                .bounding_boxes
+                .get(Glyph::NoteheadWhole)
+                .map(|bounding_box| bounding_box.ne),
+            Some(Coord(StaffSpaces(2.0), StaffSpaces(1.0)))
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn from_reader_truncated(#[case] lenient: bool) {
+        let json = r#"{
+            "fontName": "Test", // comment
+            "engravingDefaults": {"stemThickness": 0.12, "textFontFamily": ["serif"]},
+            "glyphAdvanceWidths": {"noteheadBlack": 1.18, "noteheadBlack": 1.0},
+            "glyphsWithAnchors": {"noteheadBlack": {"stemUpSE": [1.18, 0.168], "zAnchor": [0, 0]}},
+            "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]}},
+        }"#;
+        let options = ParseOptions {
+            lenient,
+            detect_duplicate_glyphs: true,
+            validate: true,
+            ..Default::default()
+        };
+
+        for len in 0..json.len() {
+            assert!(Metadata::from_reader_with(&json.as_bytes()[..len], &options).is_err());
+        }
+        assert_eq!(
+            Metadata::from_reader_with(json.as_bytes(), &options).is_ok(),
+            lenient
+        );
+    }
+
+    #[test]
+    fn from_reader_with_sink() -> Result<()> {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphAdvanceWidths": {"zzz": 1.0, "notAGlyph": 1.0},
+            "glyphsWithAnchors": {"noteheadBlack": {"vendorAnchor": [0, 0]}},
+            "glyphBBoxes": {
+                "noteheadWhole": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]},
+                "noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]}
+            }
+        }"#;
+        let options = ParseOptions {
+            max_reported_unknown_glyphs: Some(1),
+            report_invalid_bounding_boxes: true,
+            ..Default::default()
+        };
+        let invalid = BoundingBox {
+            ne: Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+            sw: Coord(StaffSpaces(1.0), StaffSpaces(1.0)),
+        };
+
+        let mut warnings = Vec::new();
+        Metadata::from_reader_with_sink(json.as_bytes(), &options, &mut warnings)?;
+
+        assert_eq!(
+            warnings,
+            [
+                Warning::UnknownGlyph {
+                    name: "notAGlyph".to_owned()
+                },
+                Warning::UnknownAnchor {
+                    glyph: "noteheadBlack".to_owned(),
+                    anchor: "vendorAnchor".to_owned()
+                },
+                Warning::MissingSection {
+                    section: "engravingDefaults"
+                },
+                Warning::InvalidBoundingBox {
+                    glyph: Glyph::NoteheadBlack,
+                    bounding_box: invalid
+                },
+                Warning::InvalidBoundingBox {
+                    glyph: Glyph::NoteheadWhole,
+                    bounding_box: invalid
+                },
+            ]
+        );
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        Metadata::from_reader_with_sink(json.as_bytes(), &options, sender)?;
+        assert_eq!(receiver.iter().count(), warnings.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_with_lenient() -> Result<()> {
+        let json = r#"{
+            // The font name.
+            "fontName": "Test",
+            /* Advance widths. */
+            "glyphAdvanceWidths": {"noteheadBlack": 1.18,},
+        }"#;
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        let metadata = Metadata::from_reader_with(json.as_bytes(), &options)?;
+
+        assert_eq!(
+            metadata.advance_widths.get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+        assert!(matches!(
+            Metadata::from_reader(json.as_bytes()),
+            Err(crate::Error::Json { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_with_validate() {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]}}
+        }"#;
+        let options = ParseOptions {
+            validate: true,
+            ..Default::default()
+        };
+
+        match Metadata::from_reader_with(json.as_bytes(), &options) {
+            Err(crate::Error::Validation(issues)) => {
+                assert_eq!(issues.len(), 1);
+                assert_eq!(issues[0].glyph.as_deref(), Some("noteheadBlack"));
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, false)]
+    #[case(false, true)]
+    #[case(true, true)]
+    fn from_reader_with_invalid_bounding_boxes(
+        #[case] report_invalid_bounding_boxes: bool,
+        #[case] normalize_bounding_boxes: bool,
+    ) -> Result<()> {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]}}
+        }"#;
+        let options = ParseOptions {
+            report_invalid_bounding_boxes,
+            normalize_bounding_boxes,
+            validate: normalize_bounding_boxes,
+            ..Default::default()
+        };
+        let invalid = BoundingBox {
+            ne: Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+            sw: Coord(StaffSpaces(1.0), StaffSpaces(1.0)),
+        };
+
+        let (metadata, report) = Metadata::from_reader_with_report(json.as_bytes(), &options)?;
+
+        assert_eq!(
+            metadata.bounding_boxes.get(Glyph::NoteheadBlack),
+            Some(if normalize_bounding_boxes {
+                invalid.normalized()
+            } else {
+                invalid
+            })
+        );
+        assert_eq!(
+            report.invalid_bounding_boxes,
+            if report_invalid_bounding_boxes {
+                vec![(Glyph::NoteheadBlack, invalid)]
+            } else {
+                vec![]
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_with_sink_validate() {
+        let json = r#"{
+            "fontName": "Test",
+            "glyphBBoxes": {"noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]}}
+        }"#;
+        let options = ParseOptions {
+            validate: true,
+            ..Default::default()
+        };
+
+        let mut warnings = Vec::new();
+        let result = Metadata::from_reader_with_sink(json.as_bytes(), &options, &mut warnings);
+
+        assert!(matches!(result, Err(crate::Error::Validation(_))));
+        assert!(warnings.is_empty());
+    }
+
+    #[rstest]
+    fn find_non_finite(empty: Metadata) {
+        let metadata = Metadata {
+            engraving_defaults: EngravingDefaults {
+                stem_thickness: Some(StaffSpaces(f64::NAN)),
+                beam_thickness: Some(StaffSpaces(0.5)),
+                ..Default::default()
+            },
+            advance_widths: [
+                (Glyph::NoteheadBlack, StaffSpaces(1.18)),
+                (Glyph::NoteheadWhole, StaffSpaces(f64::INFINITY)),
+            ]
+            .into(),
+            anchors: [(
+                Glyph::NoteheadBlack,
+                Anchors {
+                    stem_up_se: Some(Coord(StaffSpaces(1.18), StaffSpaces(f64::NEG_INFINITY))),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            bounding_boxes: [
+                (
+                    Glyph::NoteheadWhole,
+                    BoundingBox {
+                        ne: Coord(StaffSpaces(f64::NAN), StaffSpaces(0.5)),
+                        sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+                    },
+                ),
+                (
+                    Glyph::NoteheadBlack,
+                    BoundingBox {
+                        ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+                        sw: Coord(StaffSpaces(0.0), StaffSpaces(f64::INFINITY)),
+                    },
+                ),
+            ]
+            .into(),
+            ..empty.clone()
+        };
+
+        let values = metadata
+            .find_non_finite()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            values,
+            [
+                "engravingDefaults → stemThickness: NaN",
+                "glyphAdvanceWidths → noteheadWhole: inf",
+                "glyphsWithAnchors → noteheadBlack → stemUpSE: -inf",
+                "glyphBBoxes → noteheadBlack → bBoxSW: inf",
+                "glyphBBoxes → noteheadWhole → bBoxNE: NaN",
+            ]
+        );
+        assert!(empty.find_non_finite().is_empty());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..=255).cycle().take(16384).collect::<Vec<u8>>();
+        let metadata = Metadata::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        assert_eq!(metadata.bounding_boxes.invalid_glyphs().count(), 0);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml_reader() -> Result<()> {
+        let yaml = "
+fontName: Test
+engravingDefaults:
+  stemThickness: 0.12
+glyphBBoxes:
+  noteheadBlack:
+    bBoxNE: [1.18, 0.5]
+    bBoxSW: [0, -0.5]
+  notAGlyph:
+    bBoxNE: [1, 1]
+    bBoxSW: [0, 0]
+";
+        let metadata = Metadata::from_yaml_reader(yaml.as_bytes())?;
+
+        assert_eq!(metadata.font_name, "Test");
+        assert_eq!(
+            metadata.engraving_defaults.stem_thickness,
+            Some(StaffSpaces(0.12))
+        );
+        assert_eq!(
+            metadata.bounding_boxes.get(Glyph::NoteheadBlack),
+            Some(BoundingBox {
+                ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+                sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+            })
+        );
+        assert_eq!(
+            metadata.bounding_boxes.unknown_glyphs().collect::<Vec<_>>(),
+            vec!["notAGlyph"]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml_str() -> Result<()> {
+        let toml = r#"
+fontName = "Test"
+
+[engravingDefaults]
+stemThickness = 0.12
+
+[glyphBBoxes.noteheadBlack]
+bBoxNE = [1.18, 0.5]
+bBoxSW = [0, -0.5]
+
+[glyphBBoxes.notAGlyph]
+bBoxNE = [1, 1]
+bBoxSW = [0, 0]
+"#;
+        let metadata = Metadata::from_toml_str(toml)?;
+
+        assert_eq!(metadata.font_name, "Test");
+        assert_eq!(
+            metadata.engraving_defaults.stem_thickness,
+            Some(StaffSpaces(0.12))
+        );
+        assert_eq!(
+            metadata.bounding_boxes.get(Glyph::NoteheadBlack),
+            Some(BoundingBox {
+                ne: Coord(StaffSpaces(1.18), StaffSpaces(0.5)),
+                sw: Coord(StaffSpaces(0.0), StaffSpaces(-0.5)),
+            })
+        );
+        assert_eq!(
+            metadata.bounding_boxes.unknown_glyphs().collect::<Vec<_>>(),
+            vec!["notAGlyph"]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn from_url_invalid() {
+        assert!(matches!(
+            Metadata::from_url("not a url"),
+            Err(crate::FetchError::Http(_))
+        ));
+    }
 }
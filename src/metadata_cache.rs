@@ -0,0 +1,382 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use crate::{
+    log::{debug, warn},
+    BundleError, Metadata,
+};
+
+/// A cache of parsed [`Metadata`], keyed by file path and a hash of the file's
+/// contents, so that each metadata file is parsed only once, even when it is
+/// requested many times (e.g. by a server rendering many scores with the same
+/// fonts).
+///
+/// Each request reads and hashes the file, which is much faster than parsing
+/// it, and the file is re-parsed if its contents have changed. If several
+/// threads request the same file at once, one parses it while the others wait
+/// for the result.
+///
+/// The cache may optionally persist parsed metadata to a directory (see
+/// [`with_directory`](Self::with_directory)), in the binary format of
+/// [`Metadata::to_binary`], which is much faster to load than JSON. Persisted
+/// metadata is named by a hash of the file's contents, and shared between
+/// processes using the same directory.
+///
+/// ```no_run
+/// use smufl::MetadataCache;
+///
+/// let cache = MetadataCache::new();
+///
+/// // Parses the file.
+/// let metadata = cache.get("bravura_metadata.json")?;
+///
+/// // Returns the already parsed metadata.
+/// let metadata = cache.get("bravura_metadata.json")?;
+/// # Ok::<(), smufl::BundleError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct MetadataCache {
+    entries: Mutex<HashMap<PathBuf, Entry>>,
+    directory: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    /// The [`content_hash`] of the file the metadata was loaded from.
+    hash: u64,
+    metadata: Slot,
+}
+
+/// The metadata for one version of a file, which is `None` until it has been
+/// loaded.
+///
+/// Loading happens with the slot's lock held, so each version is loaded at
+/// most once, and if it fails the next caller can try again. The map's lock is
+/// not held meanwhile, so other files can be loaded in parallel.
+type Slot = Arc<Mutex<Option<Arc<Metadata>>>>;
+
+impl MetadataCache {
+    /// Returns an empty in-memory cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an empty cache which also persists parsed metadata in
+    /// `directory`, creating it if necessary.
+    pub fn with_directory(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: Some(directory.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the metadata in the file at `path`, parsing it only if it
+    /// hasn't been parsed before or it has changed.
+    pub fn get(&self, path: impl AsRef<Path>) -> Result<Arc<Metadata>, BundleError> {
+        let path = path.as_ref();
+        let contents = fs::read(path)?;
+        let hash = content_hash(&contents);
+
+        let shared = {
+            let mut entries = self.lock();
+            let entry = entries.entry(path.to_owned()).or_insert_with(|| Entry {
+                hash,
+                metadata: Slot::default(),
+            });
+            if entry.hash != hash {
+                *entry = Entry {
+                    hash,
+                    metadata: Slot::default(),
+                };
+            }
+
+            Arc::clone(&entry.metadata)
+        };
+
+        let mut slot = lock(&shared);
+        if let Some(metadata) = &*slot {
+            return Ok(Arc::clone(metadata));
+        }
+
+        match self.load(path, &contents, hash) {
+            Ok(metadata) => {
+                let metadata = Arc::new(metadata);
+                *slot = Some(Arc::clone(&metadata));
+
+                Ok(metadata)
+            }
+            Err(error) => {
+                let mut entries = self.lock();
+                if entries
+                    .get(path)
+                    .is_some_and(|entry| Arc::ptr_eq(&entry.metadata, &shared))
+                {
+                    entries.remove(path);
+                }
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Removes the metadata for `path` from memory. Persisted metadata is kept.
+    pub fn remove(&self, path: impl AsRef<Path>) -> Option<Arc<Metadata>> {
+        let entry = self.lock().remove(path.as_ref())?;
+        let metadata = lock(&entry.metadata).clone();
+
+        metadata
+    }
+
+    /// Removes all metadata from memory. Persisted metadata is kept.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the number of files whose metadata is held in memory, including
+    /// any being loaded.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no metadata is held in memory.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<PathBuf, Entry>> {
+        lock(&self.entries)
+    }
+
+    /// Loads the metadata in `contents`, read from the file at `path`, from
+    /// the cache directory if it has been persisted there, and otherwise
+    /// parses and persists it.
+    fn load(&self, path: &Path, contents: &[u8], hash: u64) -> Result<Metadata, BundleError> {
+        let persisted_path = self
+            .directory
+            .as_ref()
+            .map(|directory| directory.join(format!("{hash:016x}.bin")));
+        if let Some(metadata) = persisted_path.as_deref().and_then(load_persisted) {
+            return Ok(metadata);
+        }
+
+        debug!(?path, "Parsing metadata");
+        let metadata = Metadata::from_reader(contents)?;
+        if let Some(persisted_path) = &persisted_path {
+            persist(persisted_path, &metadata);
+        }
+
+        Ok(metadata)
+    }
+}
+
+/// Locks `mutex`. Nothing is left in an inconsistent state while one of the
+/// cache's locks is held, so a poisoned lock can be used as-is.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn load_persisted(path: &Path) -> Option<Metadata> {
+    let file = File::open(path).ok()?;
+
+    match Metadata::from_binary(BufReader::new(file)) {
+        Ok(metadata) => Some(metadata),
+        Err(error) => {
+            warn!(?path, %error, "Ignoring invalid persisted metadata");
+            None
+        }
+    }
+}
+
+/// Writes `metadata` to `path` in the cache directory. Failures are logged
+/// rather than returned, since the metadata itself was loaded successfully.
+///
+/// The metadata is written to a temporary file in the same directory and then
+/// renamed into place, so other processes sharing the directory never see a
+/// partially written file.
+fn persist(path: &Path, metadata: &Metadata) {
+    static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let temp = path.with_extension(format!(
+        "{}.{}.tmp",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| File::create(&temp))
+        .and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            metadata.to_binary(&mut writer)?;
+            writer.flush()
+        })
+        .and_then(|()| fs::rename(&temp, path));
+
+    if let Err(error) = result {
+        let _ = fs::remove_file(&temp);
+        warn!(?path, %error, "Failed to persist metadata");
+    }
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`.
+///
+/// This is used instead of [`std::hash::DefaultHasher`] because persisted
+/// metadata is named by hash, so the hash must be stable across Rust versions.
+fn content_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("smufl-cache-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn content_hash() {
+        assert_eq!(super::content_hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(super::content_hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn get() -> anyhow::Result<()> {
+        let dir = temp_dir("get");
+        let path = dir.join("metadata.json");
+        fs::write(&path, r#"{"fontName": "Before"}"#)?;
+
+        let cache = MetadataCache::new();
+        let first = cache.get(&path)?;
+        let second = cache.get(&path)?;
+
+        fs::write(&path, r#"{"fontName": "After"}"#)?;
+        let third = cache.get(&path)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(third.font_name, "After");
+        assert_eq!(cache.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_same_length() -> anyhow::Result<()> {
+        let dir = temp_dir("same-length");
+        let path = dir.join("metadata.json");
+        fs::write(&path, r#"{"fontName": "One"}"#)?;
+
+        let cache = MetadataCache::new();
+        let first = cache.get(&path)?;
+
+        // An edit which keeps the length, likely within the same second.
+        fs::write(&path, r#"{"fontName": "Two"}"#)?;
+        let second = cache.get(&path)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(first.font_name, "One");
+        assert_eq!(second.font_name, "Two");
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_concurrently() -> anyhow::Result<()> {
+        let dir = temp_dir("concurrent");
+        let path = dir.join("metadata.json");
+        fs::write(&path, r#"{"fontName": "Test"}"#)?;
+
+        let cache = MetadataCache::new();
+        let results = std::thread::scope(|scope| {
+            let handles = (0..8)
+                .map(|_| scope.spawn(|| cache.get(&path)))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Result<Vec<_>, _>>()
+        });
+        fs::remove_dir_all(&dir)?;
+
+        let results = results?;
+        assert!(results
+            .iter()
+            .all(|metadata| Arc::ptr_eq(metadata, &results[0])));
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_invalid() -> anyhow::Result<()> {
+        let dir = temp_dir("invalid");
+        let path = dir.join("metadata.json");
+        fs::write(&path, "{")?;
+
+        let cache = MetadataCache::new();
+        let result = cache.get(&path);
+        fs::remove_dir_all(&dir)?;
+
+        assert!(result.is_err());
+        assert!(cache.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_directory() -> anyhow::Result<()> {
+        let dir = temp_dir("persist");
+        let path = dir.join("metadata.json");
+        let contents = r#"{"fontName": "Test", "glyphAdvanceWidths": {}}"#;
+        fs::write(&path, contents)?;
+
+        let persisted_path = dir.join("cache").join(format!(
+            "{:016x}.bin",
+            super::content_hash(contents.as_bytes())
+        ));
+
+        let metadata = MetadataCache::with_directory(dir.join("cache")).get(&path)?;
+        let persisted = Metadata::from_binary(File::open(&persisted_path)?)?;
+
+        // Show that the persisted metadata is loaded instead of the file being
+        // parsed again.
+        let mut changed = Vec::new();
+        Metadata {
+            font_name: "Persisted".to_owned(),
+            ..persisted.clone()
+        }
+        .to_binary(&mut changed)?;
+        fs::write(&persisted_path, changed)?;
+        let reloaded = MetadataCache::with_directory(dir.join("cache")).get(&path)?;
+        let persisted_files = fs::read_dir(dir.join("cache"))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>();
+        fs::remove_dir_all(&dir)?;
+
+        // No temporary files are left behind.
+        assert_eq!(persisted_files?, [persisted_path]);
+
+        assert_eq!(metadata.font_name, "Test");
+        assert_eq!(persisted.font_name, "Test");
+        assert_eq!(reloaded.font_name, "Persisted");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{AnchorKind, Change, Glyph, GlyphDiff, Metadata, StaffSpaces, TextFontFamily};
+
+/// The differences between two sets of metadata, as returned by
+/// [`Metadata::diff`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetadataDiff {
+    /// The change in font name, if it differs.
+    pub font_name: Option<Change<String>>,
+
+    /// The change in the text font families from the engraving defaults, if
+    /// they differ.
+    pub text_font_family: Option<Change<TextFontFamily>>,
+
+    /// The other engraving defaults which differ, keyed by their names in
+    /// metadata files (e.g. `stemThickness`), in the order the fields of
+    /// [`EngravingDefaults`](crate::EngravingDefaults) are declared.
+    pub engraving_defaults: Vec<(&'static str, Change<StaffSpaces>)>,
+
+    /// The glyphs whose metrics differ, ordered by glyph name.
+    pub glyphs: Vec<GlyphDiff>,
+}
+
+impl MetadataDiff {
+    /// Returns `true` if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.font_name.is_none()
+            && self.text_font_family.is_none()
+            && self.engraving_defaults.is_empty()
+            && self.glyphs.is_empty()
+    }
+}
+
+impl Metadata {
+    /// Compares `self` (the original) with `other` (the new metadata),
+    /// returning the font name, engraving defaults and glyph metrics which
+    /// differ.
+    ///
+    /// Unknown glyphs are not compared.
+    pub fn diff(&self, other: &Self) -> MetadataDiff {
+        MetadataDiff {
+            font_name: Change::between(Some(self.font_name.clone()), Some(other.font_name.clone())),
+            text_font_family: Change::between(
+                Some(self.engraving_defaults.text_font_family.clone()),
+                Some(other.engraving_defaults.text_font_family.clone()),
+            ),
+            engraving_defaults: self
+                .engraving_defaults
+                .fields()
+                .zip(other.engraving_defaults.fields())
+                .filter_map(|((name, old), (_, new))| {
+                    Change::between(old, new).map(|change| (name, change))
+                })
+                .collect(),
+            glyphs: glyphs(self)
+                .chain(glyphs(other))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .filter_map(|glyph| diff_glyph(self, other, glyph))
+                .sorted_by_key(|diff| diff.glyph.name())
+                .collect(),
+        }
+    }
+}
+
+fn glyphs(metadata: &Metadata) -> impl Iterator<Item = Glyph> + '_ {
+    metadata
+        .advance_widths
+        .iter()
+        .map(|(glyph, _)| glyph)
+        .chain(metadata.anchors.iter().map(|(glyph, _)| glyph))
+        .chain(metadata.bounding_boxes.iter().map(|(glyph, _)| glyph))
+}
+
+fn diff_glyph(old: &Metadata, new: &Metadata, glyph: Glyph) -> Option<GlyphDiff> {
+    let old_anchors = old.anchors.get(glyph).unwrap_or_default();
+    let new_anchors = new.anchors.get(glyph).unwrap_or_default();
+
+    let diff = GlyphDiff {
+        glyph,
+        advance_width: Change::between(
+            old.advance_widths.get(glyph),
+            new.advance_widths.get(glyph),
+        ),
+        bounding_box: Change::between(old.bounding_boxes.get(glyph), new.bounding_boxes.get(glyph)),
+        anchors: AnchorKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                Change::between(old_anchors.get(kind), new_anchors.get(kind))
+                    .map(|change| (kind, change))
+            })
+            .collect(),
+    };
+
+    (diff.advance_width.is_some() || diff.bounding_box.is_some() || !diff.anchors.is_empty())
+        .then_some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Coord, StaffSpaces};
+
+    fn metadata(json: &str) -> Metadata {
+        Metadata::from_reader(json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn diff() {
+        let old = metadata(
+            r#"{
+                "fontName": "Old",
+                "engravingDefaults": {
+                    "stemThickness": 0.12,
+                    "beamThickness": 0.5,
+                    "textFontFamily": ["Academico"]
+                },
+                "glyphAdvanceWidths": {"noteheadBlack": 1.18, "accidentalFlat": 0.9},
+                "glyphsWithAnchors": {"noteheadBlack": {"stemUpSE": [1.18, 0.168]}}
+            }"#,
+        );
+        let new = metadata(
+            r#"{
+                "fontName": "New",
+                "engravingDefaults": {
+                    "stemThickness": 0.1,
+                    "beamThickness": 0.5,
+                    "textFontFamily": ["Academico", "serif"]
+                },
+                "glyphAdvanceWidths": {"noteheadBlack": 1.2, "accidentalFlat": 0.9},
+                "glyphsWithAnchors": {"noteheadBlack": {"stemDownNW": [0, -0.168]}}
+            }"#,
+        );
+
+        assert_eq!(
+            old.diff(&new),
+            MetadataDiff {
+                font_name: Some(Change {
+                    old: Some("Old".to_owned()),
+                    new: Some("New".to_owned()),
+                }),
+                text_font_family: Some(Change {
+                    old: Some(["Academico"].into_iter().collect()),
+                    new: Some(["Academico", "serif"].into_iter().collect()),
+                }),
+                engraving_defaults: vec![(
+                    "stemThickness",
+                    Change {
+                        old: Some(StaffSpaces(0.12)),
+                        new: Some(StaffSpaces(0.1)),
+                    },
+                )],
+                glyphs: vec![GlyphDiff {
+                    glyph: Glyph::NoteheadBlack,
+                    advance_width: Some(Change {
+                        old: Some(StaffSpaces(1.18)),
+                        new: Some(StaffSpaces(1.2)),
+                    }),
+                    bounding_box: None,
+                    anchors: vec![
+                        (
+                            AnchorKind::StemUpSE,
+                            Change {
+                                old: Some(Coord(StaffSpaces(1.18), StaffSpaces(0.168))),
+                                new: None,
+                            },
+                        ),
+                        (
+                            AnchorKind::StemDownNW,
+                            Change {
+                                old: None,
+                                new: Some(Coord(StaffSpaces(0.0), StaffSpaces(-0.168))),
+                            },
+                        ),
+                    ],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn diff_equal() {
+        let metadata =
+            metadata(r#"{"fontName": "Test", "glyphAdvanceWidths": {"noteheadBlack": 1}}"#);
+
+        assert!(metadata.diff(&metadata).is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{
+    log::{debug, warn},
+    Metadata, WatchError,
+};
+
+/// Watches a metadata file and reloads it whenever it changes, so that e.g. a
+/// preview app picks up edits to a font's metrics live.
+///
+/// If a changed file can't be parsed (for example, because it was saved
+/// half-way through an edit), the error is logged at the WARN level and the
+/// previously loaded metadata is kept.
+///
+/// ```no_run
+/// use smufl::MetadataWatcher;
+///
+/// let watcher = MetadataWatcher::new("bravura_metadata.json")?;
+///
+/// // Later, e.g. once per frame:
+/// let metadata = watcher.metadata();
+/// # Ok::<(), smufl::WatchError>(())
+/// ```
+#[derive(Debug)]
+pub struct MetadataWatcher {
+    current: Arc<RwLock<Arc<Metadata>>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl MetadataWatcher {
+    /// Loads the metadata file at `path` and starts watching it for changes.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, WatchError> {
+        let path = path.as_ref().to_owned();
+        let current = Arc::new(RwLock::new(Arc::new(load(&path)?)));
+
+        // Watch the parent directory rather than the file itself, since many
+        // editors save by replacing the file.
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_owned(),
+            _ => PathBuf::from("."),
+        };
+        let file_name = path.file_name().map(ToOwned::to_owned);
+
+        let mut watcher = {
+            let current = Arc::clone(&current);
+
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(error) => {
+                        warn!(%error, "Error watching metadata");
+                        return;
+                    }
+                };
+
+                let is_relevant = (event.kind.is_create() || event.kind.is_modify())
+                    && event
+                        .paths
+                        .iter()
+                        .any(|changed| changed.file_name() == file_name.as_deref());
+
+                if !is_relevant {
+                    return;
+                }
+
+                match load(&path) {
+                    Ok(metadata) => {
+                        debug!(?path, "Reloaded metadata");
+                        *current.write().unwrap_or_else(|error| error.into_inner()) =
+                            Arc::new(metadata);
+                    }
+                    Err(error) => warn!(?path, %error, "Failed to reload metadata"),
+                }
+            })?
+        };
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            current,
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the most recently loaded metadata.
+    pub fn metadata(&self) -> Arc<Metadata> {
+        Arc::clone(
+            &self
+                .current
+                .read()
+                .unwrap_or_else(|error| error.into_inner()),
+        )
+    }
+}
+
+fn load(path: &Path) -> Result<Metadata, WatchError> {
+    let reader = BufReader::new(File::open(path)?);
+
+    Ok(Metadata::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs, thread,
+        time::{Duration, Instant},
+    };
+
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn reloads_on_change() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("smufl-watcher-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let path = dir.join("metadata.json");
+        fs::write(&path, r#"{"fontName": "Before"}"#)?;
+
+        let watcher = MetadataWatcher::new(&path)?;
+        assert_eq!(watcher.metadata().font_name, "Before");
+
+        // Invalid data is ignored.
+        fs::write(&path, "{")?;
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(watcher.metadata().font_name, "Before");
+
+        fs::write(&path, r#"{"fontName": "After"}"#)?;
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while watcher.metadata().font_name != "After" && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let font_name = watcher.metadata().font_name.clone();
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(font_name, "After");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_initial_file() {
+        let path = std::env::temp_dir().join("smufl-watcher-missing/metadata.json");
+
+        assert!(matches!(MetadataWatcher::new(path), Err(WatchError::Io(_))));
+    }
+}
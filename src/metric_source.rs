@@ -0,0 +1,10 @@
+/// Where a [`Font`](crate::Font)'s metric for a glyph came from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MetricSource {
+    /// The font's metadata file.
+    Metadata,
+
+    /// The font file itself, because the metadata file doesn't have the
+    /// metric, e.g. a bounding box computed from the glyph's outline.
+    Font,
+}
@@ -0,0 +1,59 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::Points;
+
+/// The number of millimeters in one inch.
+pub(crate) const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+/// A physical length in millimeters.
+///
+/// Use a [`StaffSize`](crate::StaffSize) to convert between
+/// [`StaffSpaces`](crate::StaffSpaces) and millimeters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Millimeters(pub f64);
+
+impl From<Points> for Millimeters {
+    fn from(Points(value): Points) -> Self {
+        Self(value * MILLIMETERS_PER_INCH / Points::PER_INCH)
+    }
+}
+
+impl From<Millimeters> for f64 {
+    fn from(value: Millimeters) -> Self {
+        value.0
+    }
+}
+
+impl Display for Millimeters {
+    /// Formats the value followed by the unit, e.g. `1.75 mm`.
+    ///
+    /// The formatter's precision is applied to the number.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} mm", precision, self.0),
+            None => write!(f, "{} mm", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Points(0.0), Millimeters(0.0))]
+    #[case(Points(72.0), Millimeters(25.4))]
+    #[case(Points(36.0), Millimeters(12.7))]
+    fn from_points(#[case] from: Points, #[case] expected: Millimeters) {
+        assert_eq!(Millimeters::from(from), expected);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Millimeters(1.75).to_string(), "1.75 mm");
+        assert_eq!(format!("{:.1}", Millimeters(1.75)), "1.8 mm");
+    }
+}
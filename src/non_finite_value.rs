@@ -0,0 +1,79 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::Glyph;
+
+/// A NaN or infinite value in [`Metadata`](crate::Metadata), as returned by
+/// [`Metadata::find_non_finite`](crate::Metadata::find_non_finite).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonFiniteValue {
+    /// The section of the metadata file the value is in, e.g. `glyphBBoxes`.
+    pub section: &'static str,
+
+    /// The glyph the value applies to, if it is in one of the glyph sections.
+    pub glyph: Option<Glyph>,
+
+    /// The name of the field within the section or glyph, e.g. `bBoxNE` or
+    /// `stemThickness`, or `None` for advance widths.
+    pub field: Option<&'static str>,
+
+    /// The value, or for coordinates, the first component which isn't finite.
+    pub value: f64,
+}
+
+impl Display for NonFiniteValue {
+    /// Formats the location and value, e.g. `glyphBBoxes → noteheadBlack →
+    /// bBoxNE: NaN`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.section)?;
+
+        if let Some(glyph) = self.glyph {
+            write!(f, " → {}", glyph.name())?;
+        }
+
+        if let Some(field) = self.field {
+            write!(f, " → {field}")?;
+        }
+
+        write!(f, ": {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        NonFiniteValue {
+            section: "glyphBBoxes",
+            glyph: Some(Glyph::NoteheadBlack),
+            field: Some("bBoxNE"),
+            value: f64::NAN,
+        },
+        "glyphBBoxes → noteheadBlack → bBoxNE: NaN"
+    )]
+    #[case(
+        NonFiniteValue {
+            section: "glyphAdvanceWidths",
+            glyph: Some(Glyph::NoteheadBlack),
+            field: None,
+            value: f64::INFINITY,
+        },
+        "glyphAdvanceWidths → noteheadBlack: inf"
+    )]
+    #[case(
+        NonFiniteValue {
+            section: "engravingDefaults",
+            glyph: None,
+            field: Some("stemThickness"),
+            value: f64::NEG_INFINITY,
+        },
+        "engravingDefaults → stemThickness: -inf"
+    )]
+    fn display(#[case] value: NonFiniteValue, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Coord, StaffSpaces};
+
+/// The points used to align a numeral with other numerals, e.g. when stacking
+/// the digits of a time signature or positioning the number of a clef with a
+/// ligated number.
+///
+/// Both points correspond horizontally to the center of the numeral.
+///
+/// See [`Metadata::numeral_extents`](crate::Metadata::numeral_extents).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumeralExtents {
+    /// The point at the top of the numeral.
+    pub top: Coord,
+
+    /// The point at the bottom of the numeral.
+    pub bottom: Coord,
+}
+
+impl NumeralExtents {
+    /// Returns the vertical distance between [`bottom`](Self::bottom) and
+    /// [`top`](Self::top).
+    pub fn height(&self) -> StaffSpaces {
+        self.top.y() - self.bottom.y()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn height() {
+        let extents = NumeralExtents {
+            top: (0.5, 2.0).into(),
+            bottom: (0.5, -1.0).into(),
+        };
+
+        assert_eq!(extents.height(), StaffSpaces(3.0));
+    }
+}
@@ -0,0 +1,12 @@
+/// The direction of the vertical axis of a coordinate system.
+///
+/// SMuFL metrics are y-up, while most raster graphics APIs are y-down.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// The y axis points up, as in SMuFL metrics.
+    #[default]
+    YUp,
+
+    /// The y axis points down, as in most raster graphics APIs.
+    YDown,
+}
@@ -0,0 +1,67 @@
+/// Options controlling how [`Metadata`](crate::Metadata) is parsed by
+/// [`Metadata::from_reader_with`](crate::Metadata::from_reader_with).
+///
+/// The default options parse metadata in the same way as
+/// [`Metadata::from_reader`](crate::Metadata::from_reader).
+///
+/// ```
+/// use smufl::ParseOptions;
+///
+/// let options = ParseOptions {
+///     strict_unknown_glyphs: true,
+///     max_reported_unknown_glyphs: Some(10),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    /// Whether to return [`Error::UnknownGlyphs`](crate::Error::UnknownGlyphs)
+    /// if the metadata refers to glyphs which are not in SMuFL, instead of
+    /// logging them at the WARN level.
+    pub strict_unknown_glyphs: bool,
+
+    /// Whether to return [`Error::Validation`](crate::Error::Validation) if any
+    /// value is NaN or infinite, with an issue giving the location of each
+    /// (see [`Metadata::find_non_finite`](crate::Metadata::find_non_finite)).
+    pub reject_non_finite: bool,
+
+    /// The maximum number of unknown glyph names to log or include in an
+    /// error, or `None` to report all of them.
+    ///
+    /// Fonts built against a different version of SMuFL can have hundreds of
+    /// unknown glyphs, which makes for unwieldy log messages.
+    pub max_reported_unknown_glyphs: Option<usize>,
+
+    /// Whether to report glyphs with more than one entry in the same section,
+    /// in [`ParseReport::duplicate_glyphs`](crate::ParseReport::duplicate_glyphs)
+    /// and as warnings.
+    ///
+    /// Standard JSON parsers, including this one, silently keep the last entry
+    /// for a duplicated key, which can hide mistakes in generated or
+    /// hand-edited metadata files.
+    pub detect_duplicate_glyphs: bool,
+
+    /// Whether to report bounding boxes which are not valid (see
+    /// [`BoundingBox::is_valid`](crate::BoundingBox::is_valid)), in
+    /// [`ParseReport::invalid_bounding_boxes`](crate::ParseReport::invalid_bounding_boxes),
+    /// as warnings, and by logging them at the WARN level.
+    pub report_invalid_bounding_boxes: bool,
+
+    /// Whether to replace bounding boxes which are not valid with their
+    /// normalized equivalents (see
+    /// [`BoundingBox::normalized`](crate::BoundingBox::normalized)), before
+    /// the metadata is validated.
+    ///
+    /// Some fonts have bounding boxes with `ne` below or to the left of `sw`,
+    /// which would otherwise produce nonsensical layouts.
+    pub normalize_bounding_boxes: bool,
+
+    /// Whether to accept `//` and `/* */` comments and trailing commas, which
+    /// are common in hand-edited metadata files.
+    pub lenient: bool,
+
+    /// Whether to run [`Metadata::validate`](crate::Metadata::validate) after
+    /// parsing, returning [`Error::Validation`](crate::Error::Validation) if it
+    /// reports any errors.
+    pub validate: bool,
+}
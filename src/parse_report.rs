@@ -0,0 +1,59 @@
+use crate::{BoundingBox, Glyph};
+
+/// Names in a metadata file which were not recognized or were duplicated,
+/// sections which were missing, and invalid bounding boxes, as returned by
+/// [`Metadata::from_reader_with_report`](crate::Metadata::from_reader_with_report).
+///
+/// Data for unknown glyphs is kept, and can be serialized again, but data for
+/// unknown anchors is discarded.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParseReport {
+    /// The names of glyphs which are not in SMuFL, sorted and without
+    /// duplicates.
+    ///
+    /// Unlike in log messages and errors, these are not limited by
+    /// [`ParseOptions::max_reported_unknown_glyphs`](crate::ParseOptions::max_reported_unknown_glyphs).
+    pub unknown_glyphs: Vec<String>,
+
+    /// The names of anchors which are not in SMuFL, e.g. anchors added in a
+    /// newer version of the specification or vendor extensions, as
+    /// `(glyph, anchor)` pairs sorted by glyph name and then anchor name.
+    pub unknown_anchors: Vec<(String, String)>,
+
+    /// The sections which were not present in the metadata file, e.g.
+    /// `glyphBBoxes`, in the order they are described in the SMuFL
+    /// specification. Missing sections are treated as empty.
+    pub missing_sections: Vec<&'static str>,
+
+    /// Glyphs with more than one entry in the same section, as
+    /// `(section, glyph)` pairs in the order the sections are described in the
+    /// SMuFL specification, and then sorted by glyph name. The last entry for
+    /// each glyph is kept.
+    ///
+    /// Only populated if
+    /// [`ParseOptions::detect_duplicate_glyphs`](crate::ParseOptions::detect_duplicate_glyphs)
+    /// is set.
+    pub duplicate_glyphs: Vec<(&'static str, String)>,
+
+    /// Glyphs whose bounding boxes are not valid (see
+    /// [`BoundingBox::is_valid`]), with the bounding boxes as they were in the
+    /// metadata file, sorted by glyph name.
+    ///
+    /// Only populated if
+    /// [`ParseOptions::report_invalid_bounding_boxes`](crate::ParseOptions::report_invalid_bounding_boxes)
+    /// is set.
+    pub invalid_bounding_boxes: Vec<(Glyph, BoundingBox)>,
+}
+
+impl ParseReport {
+    /// Returns `true` if every name in the metadata file was recognized, no
+    /// sections were missing, no glyphs were duplicated, and no invalid
+    /// bounding boxes were reported.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_glyphs.is_empty()
+            && self.unknown_anchors.is_empty()
+            && self.missing_sections.is_empty()
+            && self.duplicate_glyphs.is_empty()
+            && self.invalid_bounding_boxes.is_empty()
+    }
+}
@@ -0,0 +1,62 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{millimeters::MILLIMETERS_PER_INCH, Millimeters};
+
+/// A physical length in typographic (PostScript) points, where one point is
+/// 1/72 of an inch.
+///
+/// Use a [`StaffSize`](crate::StaffSize) to convert between
+/// [`StaffSpaces`](crate::StaffSpaces) and points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Points(pub f64);
+
+impl Points {
+    /// The number of points in one inch.
+    pub(crate) const PER_INCH: f64 = 72.0;
+}
+
+impl From<Millimeters> for Points {
+    fn from(Millimeters(value): Millimeters) -> Self {
+        Self(value * Self::PER_INCH / MILLIMETERS_PER_INCH)
+    }
+}
+
+impl From<Points> for f64 {
+    fn from(value: Points) -> Self {
+        value.0
+    }
+}
+
+impl Display for Points {
+    /// Formats the value followed by the unit, e.g. `12 pt`.
+    ///
+    /// The formatter's precision is applied to the number.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} pt", precision, self.0),
+            None => write!(f, "{} pt", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Millimeters(0.0), Points(0.0))]
+    #[case(Millimeters(25.4), Points(72.0))]
+    #[case(Millimeters(12.7), Points(36.0))]
+    fn from_millimeters(#[case] from: Millimeters, #[case] expected: Points) {
+        assert_eq!(Points::from(from), expected);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Points(12.0).to_string(), "12 pt");
+        assert_eq!(format!("{:.2}", Points(12.0)), "12.00 pt");
+    }
+}
@@ -0,0 +1,53 @@
+/// A SMuFL font whose metadata is published online, e.g. for use with
+/// `Metadata::from_reference_font` (which requires the `fetch` feature).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ReferenceFont {
+    /// [Bravura](https://github.com/steinbergmedia/bravura), the SMuFL
+    /// reference font, at version 1.392.
+    Bravura,
+
+    /// [Leland](https://github.com/MuseScoreFonts/Leland), the default font in
+    /// MuseScore, at the version released with MuseScore 4.0.0.
+    Leland,
+
+    /// [Petaluma](https://github.com/steinbergmedia/petaluma), a handwritten
+    /// style font, at version 1.065.
+    Petaluma,
+}
+
+impl ReferenceFont {
+    /// Every reference font.
+    pub const ALL: [Self; 3] = [Self::Bravura, Self::Leland, Self::Petaluma];
+
+    /// Returns the URL of the font's metadata file, pinned to a release tag so
+    /// that the metadata doesn't change underneath its users.
+    ///
+    /// Bravura and Petaluma are fetched from their own repositories, and Leland
+    /// from the MuseScore release which ships it.
+    pub const fn metadata_url(&self) -> &'static str {
+        match self {
+            Self::Bravura => "https://raw.githubusercontent.com/steinbergmedia/bravura/bravura-1.392/redist/bravura_metadata.json",
+            Self::Leland => "https://raw.githubusercontent.com/musescore/MuseScore/v4.0.0/fonts/leland/leland_metadata.json",
+            Self::Petaluma => "https://raw.githubusercontent.com/steinbergmedia/petaluma/petaluma-1.065/redist/petaluma_metadata.json",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    #[rstest]
+    #[case(ReferenceFont::Bravura, "bravura_metadata.json")]
+    #[case(ReferenceFont::Leland, "leland_metadata.json")]
+    #[case(ReferenceFont::Petaluma, "petaluma_metadata.json")]
+    fn metadata_url(#[case] font: ReferenceFont, #[case] file_name: &str) {
+        let url = font.metadata_url();
+
+        assert!(url.starts_with("https://"));
+        assert!(url.ends_with(file_name));
+        assert!(!url.contains("/master/") && !url.contains("/main/"));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{millimeters::MILLIMETERS_PER_INCH, Coord, Orientation, StaffSize, StaffSpaces};
+
+/// Converts metrics in [`StaffSpaces`] to device pixels.
+///
+/// ```
+/// use smufl::{Coord, Millimeters, Orientation, RenderContext, StaffSize, StaffSpaces};
+///
+/// let context = RenderContext::new(10.0);
+/// assert_eq!(context.px(StaffSpaces(1.5)), 15.0);
+///
+/// // A 7 mm staff at 96 DPI, zoomed to 200%.
+/// let context = RenderContext::from_dpi(StaffSize::from_height(Millimeters(7.0)), 96.0, 2.0);
+/// assert_eq!(context.px(StaffSpaces(4.0)), 7.0 / 25.4 * 96.0 * 2.0);
+///
+/// // A y-down device, as used by most raster graphics APIs.
+/// let context = RenderContext::new(10.0).with_orientation(Orientation::YDown);
+/// assert_eq!(
+///     context.coord_px(Coord(StaffSpaces(1.0), StaffSpaces(2.0))),
+///     (10.0, -20.0)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderContext {
+    /// The size of one staff space, in pixels.
+    pub staff_space_px: f64,
+
+    /// The orientation of the device's y axis, applied by
+    /// [`coord_px`](Self::coord_px).
+    pub orientation: Orientation,
+}
+
+impl RenderContext {
+    /// Creates a y-up context where one staff space is `staff_space_px`
+    /// pixels.
+    pub fn new(staff_space_px: f64) -> Self {
+        Self {
+            staff_space_px,
+            orientation: Orientation::YUp,
+        }
+    }
+
+    /// Returns a copy of the context with the given `orientation`.
+    #[must_use]
+    pub fn with_orientation(self, orientation: Orientation) -> Self {
+        Self {
+            orientation,
+            ..self
+        }
+    }
+
+    /// Creates a context for rendering a staff of the given physical size on
+    /// a device with the given resolution (in dots per inch) and zoom factor
+    /// (where `1.0` is 100%).
+    pub fn from_dpi(staff_size: StaffSize, dpi: f64, zoom: f64) -> Self {
+        let staff_space_inches = staff_size.staff_space().0 / MILLIMETERS_PER_INCH;
+
+        Self::new(staff_space_inches * dpi * zoom)
+    }
+
+    /// Converts `value` to pixels.
+    pub fn px(&self, StaffSpaces(value): StaffSpaces) -> f64 {
+        value * self.staff_space_px
+    }
+
+    /// Converts both components of `coord` to pixels, returning `(x, y)`.
+    ///
+    /// For a [`YDown`](Orientation::YDown) context, the y component is
+    /// negated.
+    pub fn coord_px(&self, coord: Coord) -> (f64, f64) {
+        let coord = match self.orientation {
+            Orientation::YUp => coord,
+            Orientation::YDown => coord.flip_y(),
+        };
+
+        (self.px(coord.x()), self.px(coord.y()))
+    }
+
+    /// Converts a value in pixels to staff spaces.
+    pub fn to_staff_spaces(&self, px: f64) -> StaffSpaces {
+        StaffSpaces(px / self.staff_space_px)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Millimeters, Points};
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), 0.0)]
+    #[case(StaffSpaces(1.0), 12.0)]
+    #[case(StaffSpaces(-0.5), -6.0)]
+    fn px(#[case] value: StaffSpaces, #[case] expected: f64) {
+        assert_eq!(RenderContext::new(12.0).px(value), expected);
+    }
+
+    #[test]
+    fn coord_px() {
+        assert_eq!(
+            RenderContext::new(12.0).coord_px(Coord(StaffSpaces(1.0), StaffSpaces(-0.5))),
+            (12.0, -6.0)
+        );
+    }
+
+    #[rstest]
+    #[case(Orientation::YUp, (12.0, -6.0))]
+    #[case(Orientation::YDown, (12.0, 6.0))]
+    fn coord_px_orientation(#[case] orientation: Orientation, #[case] expected: (f64, f64)) {
+        assert_eq!(
+            RenderContext::new(12.0)
+                .with_orientation(orientation)
+                .coord_px(Coord(StaffSpaces(1.0), StaffSpaces(-0.5))),
+            expected
+        );
+    }
+
+    #[test]
+    fn to_staff_spaces() {
+        assert_eq!(
+            RenderContext::new(12.0).to_staff_spaces(6.0),
+            StaffSpaces(0.5)
+        );
+    }
+
+    #[rstest]
+    #[case(StaffSize::from_height(Points(72.0)), 96.0, 1.0, 24.0)]
+    #[case(StaffSize::from_height(Points(72.0)), 96.0, 2.0, 48.0)]
+    #[case(StaffSize::from_staff_space(Millimeters(25.4)), 300.0, 1.0, 300.0)]
+    fn from_dpi(
+        #[case] staff_size: StaffSize,
+        #[case] dpi: f64,
+        #[case] zoom: f64,
+        #[case] expected: f64,
+    ) {
+        assert_eq!(
+            RenderContext::from_dpi(staff_size, dpi, zoom).staff_space_px,
+            expected
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::KeyOrder;
+
+/// Options controlling how [`Metadata`](crate::Metadata) is serialized by
+/// [`Metadata::to_writer`](crate::Metadata::to_writer).
+///
+/// The output for a given `Metadata` and set of options is always the same,
+/// so generated metadata files are deterministic and diff-friendly.
+///
+/// ```
+/// use smufl::{KeyOrder, SerializeOptions};
+///
+/// let options = SerializeOptions {
+///     key_order: KeyOrder::Alphabetical,
+///     precision: Some(3),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SerializeOptions {
+    /// The order in which object keys are written.
+    pub key_order: KeyOrder,
+
+    /// The number of decimal places to round values in staff spaces to, or
+    /// `None` to write them at full precision.
+    pub precision: Option<usize>,
+
+    /// Whether to write indented JSON instead of compact JSON.
+    pub pretty: bool,
+}
@@ -0,0 +1,21 @@
+use std::fmt::{self, Display, Formatter};
+
+/// How serious a [`ValidationIssue`](crate::ValidationIssue) is.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The metadata is usable, but may produce poor results.
+    Warning,
+
+    /// The metadata is incorrect, and will produce visibly broken results.
+    Error,
+}
+
+impl Display for Severity {
+    /// Formats the severity in lowercase, e.g. `warning`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
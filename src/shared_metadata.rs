@@ -0,0 +1,145 @@
+use std::{ops::Deref, sync::Arc};
+
+use crate::Metadata;
+
+/// A cheaply cloneable, thread-safe handle to [`Metadata`].
+///
+/// Cloning `Metadata` copies every glyph's metrics, whereas cloning a
+/// `SharedMetadata` only increments a reference count, so it can be handed to
+/// worker threads freely. It dereferences to `Metadata` for lookups.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::thread;
+///
+/// use smufl::{Glyph, Metadata, SharedMetadata};
+///
+/// let metadata = Metadata::from_reader(
+///     r#"{"fontName": "Bravura", "glyphAdvanceWidths": {"noteheadBlack": 1.18}}"#.as_bytes(),
+/// )?;
+/// let shared = SharedMetadata::from(metadata);
+///
+/// let worker = {
+///     let shared = shared.clone();
+///     thread::spawn(move || shared.advance_widths.get(Glyph::NoteheadBlack))
+/// };
+///
+/// assert_eq!(
+///     worker.join().unwrap(),
+///     shared.advance_widths.get(Glyph::NoteheadBlack)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedMetadata(Arc<Metadata>);
+
+impl SharedMetadata {
+    /// Returns a mutable reference to the metadata, first cloning it if any
+    /// other handle shares it, so that they are unaffected.
+    pub fn make_mut(&mut self) -> &mut Metadata {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Returns the metadata, cloning it only if another handle shares it.
+    pub fn into_inner(self) -> Metadata {
+        Arc::unwrap_or_clone(self.0)
+    }
+
+    /// Returns `true` if both handles share the same metadata.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for SharedMetadata {
+    type Target = Metadata;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Metadata> for SharedMetadata {
+    fn as_ref(&self) -> &Metadata {
+        &self.0
+    }
+}
+
+impl From<Metadata> for SharedMetadata {
+    fn from(value: Metadata) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+/// Wraps metadata shared by a [`MetadataCache`](crate::MetadataCache), without
+/// copying it.
+impl From<Arc<Metadata>> for SharedMetadata {
+    fn from(value: Arc<Metadata>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SharedMetadata> for Arc<Metadata> {
+    fn from(value: SharedMetadata) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Glyph, StaffSpaces};
+
+    fn metadata() -> Metadata {
+        Metadata::from_reader(
+            r#"{"fontName": "Test", "glyphAdvanceWidths": {"noteheadBlack": 1.18}}"#.as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<SharedMetadata>();
+    }
+
+    #[test]
+    fn clone_shares() {
+        let shared = SharedMetadata::from(metadata());
+        let clone = shared.clone();
+
+        assert!(shared.ptr_eq(&clone));
+        assert_eq!(clone.font_name, "Test");
+    }
+
+    #[test]
+    fn make_mut_copies_on_write() {
+        let shared = SharedMetadata::from(metadata());
+        let mut clone = shared.clone();
+
+        clone.make_mut().font_name = "Changed".to_owned();
+
+        assert!(!shared.ptr_eq(&clone));
+        assert_eq!(shared.font_name, "Test");
+        assert_eq!(clone.font_name, "Changed");
+    }
+
+    #[test]
+    fn into_inner() {
+        let shared = SharedMetadata::from(metadata());
+
+        assert_eq!(
+            shared
+                .clone()
+                .into_inner()
+                .advance_widths
+                .get(Glyph::NoteheadBlack),
+            Some(StaffSpaces(1.18))
+        );
+        assert_eq!(shared.into_inner().font_name, "Test");
+    }
+}
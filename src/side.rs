@@ -0,0 +1,9 @@
+/// A horizontal side of a glyph.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Side {
+    /// The left-hand (west) side.
+    Left,
+
+    /// The right-hand (east) side.
+    Right,
+}
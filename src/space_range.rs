@@ -0,0 +1,149 @@
+use crate::StaffSpaces;
+
+/// A closed interval of [`StaffSpaces`], e.g. the vertical extent of a staff
+/// or a band used for collision detection.
+///
+/// ```
+/// use smufl::{SpaceRange, StaffSpaces};
+///
+/// let staff = SpaceRange::new(StaffSpaces(0.0), StaffSpaces(4.0));
+/// let notehead = SpaceRange::new(StaffSpaces(3.5), StaffSpaces(4.5));
+///
+/// assert_eq!(staff.length(), StaffSpaces(4.0));
+/// assert_eq!(
+///     staff.intersection(&notehead),
+///     Some(SpaceRange::new(StaffSpaces(3.5), StaffSpaces(4.0)))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SpaceRange {
+    /// The lower bound (inclusive).
+    pub min: StaffSpaces,
+
+    /// The upper bound (inclusive).
+    pub max: StaffSpaces,
+}
+
+impl SpaceRange {
+    /// Creates a range between `a` and `b`, in either order.
+    pub fn new(a: StaffSpaces, b: StaffSpaces) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Returns the distance between the bounds.
+    pub fn length(&self) -> StaffSpaces {
+        self.max - self.min
+    }
+
+    /// Returns the value half way between the bounds.
+    pub fn center(&self) -> StaffSpaces {
+        StaffSpaces::lerp(self.min, self.max, 0.5)
+    }
+
+    /// Returns `true` if `value` lies within the range, including the bounds.
+    pub fn contains(&self, value: StaffSpaces) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Restricts `value` to the range.
+    pub fn clamp(&self, value: StaffSpaces) -> StaffSpaces {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Returns the smallest range which contains both `self` and `other`.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Returns the range covered by both `self` and `other`, or `None` if they
+    /// do not overlap.
+    ///
+    /// Ranges which only touch at a bound intersect in a range of length zero.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+
+        (min <= max).then_some(Self { min, max })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn range(min: f64, max: f64) -> SpaceRange {
+        SpaceRange {
+            min: StaffSpaces(min),
+            max: StaffSpaces(max),
+        }
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.0), StaffSpaces(2.0), range(1.0, 2.0))]
+    #[case(StaffSpaces(2.0), StaffSpaces(1.0), range(1.0, 2.0))]
+    fn new(#[case] a: StaffSpaces, #[case] b: StaffSpaces, #[case] expected: SpaceRange) {
+        assert_eq!(SpaceRange::new(a, b), expected);
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(range(-1.0, 2.5).length(), StaffSpaces(3.5));
+    }
+
+    #[test]
+    fn center() {
+        assert_eq!(range(-1.0, 2.0).center(), StaffSpaces(0.5));
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(-0.5), false)]
+    #[case(StaffSpaces(0.0), true)]
+    #[case(StaffSpaces(0.5), true)]
+    #[case(StaffSpaces(1.0), true)]
+    #[case(StaffSpaces(1.5), false)]
+    fn contains(#[case] value: StaffSpaces, #[case] expected: bool) {
+        assert_eq!(range(0.0, 1.0).contains(value), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(-0.5), StaffSpaces(0.0))]
+    #[case(StaffSpaces(0.5), StaffSpaces(0.5))]
+    #[case(StaffSpaces(1.5), StaffSpaces(1.0))]
+    fn clamp(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(range(0.0, 1.0).clamp(value), expected);
+    }
+
+    #[rstest]
+    #[case(range(0.0, 1.0), range(2.0, 3.0), range(0.0, 3.0))]
+    #[case(range(0.0, 2.0), range(1.0, 3.0), range(0.0, 3.0))]
+    #[case(range(0.0, 3.0), range(1.0, 2.0), range(0.0, 3.0))]
+    fn union(#[case] a: SpaceRange, #[case] b: SpaceRange, #[case] expected: SpaceRange) {
+        assert_eq!(a.union(&b), expected);
+        assert_eq!(b.union(&a), expected);
+    }
+
+    #[rstest]
+    #[case(range(0.0, 1.0), range(2.0, 3.0), None)]
+    #[case(range(0.0, 1.0), range(1.0, 2.0), Some(range(1.0, 1.0)))]
+    #[case(range(0.0, 2.0), range(1.0, 3.0), Some(range(1.0, 2.0)))]
+    #[case(range(0.0, 3.0), range(1.0, 2.0), Some(range(1.0, 2.0)))]
+    fn intersection(
+        #[case] a: SpaceRange,
+        #[case] b: SpaceRange,
+        #[case] expected: Option<SpaceRange>,
+    ) {
+        assert_eq!(a.intersection(&b), expected);
+        assert_eq!(b.intersection(&a), expected);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Millimeters, Points, StaffSpaces};
+
+/// The physical size of a staff, used to convert between [`StaffSpaces`] and
+/// absolute units.
+///
+/// SMuFL fonts are scaled so that the em square equals the height of a
+/// five-line staff, i.e. four staff spaces. A staff size is conventionally
+/// given as that height (e.g. a "rastral size" of 7 mm), so a staff space is a
+/// quarter of it.
+///
+/// ```
+/// use smufl::{Millimeters, StaffSize, StaffSpaces};
+///
+/// let staff_size = StaffSize::from_height(Millimeters(7.0));
+///
+/// assert_eq!(staff_size.staff_space(), Millimeters(1.75));
+/// assert_eq!(
+///     StaffSpaces(2.0).to_millimeters(staff_size),
+///     Millimeters(3.5)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StaffSize {
+    staff_space: Millimeters,
+}
+
+impl StaffSize {
+    /// Creates a staff size from the height of a five-line staff, measured
+    /// from the bottom line to the top line.
+    pub fn from_height(height: impl Into<Millimeters>) -> Self {
+        let Millimeters(height) = height.into();
+
+        Self {
+            staff_space: Millimeters(height / 4.0),
+        }
+    }
+
+    /// Creates a staff size from the distance between two adjacent staff
+    /// lines.
+    pub fn from_staff_space(staff_space: impl Into<Millimeters>) -> Self {
+        Self {
+            staff_space: staff_space.into(),
+        }
+    }
+
+    /// Returns the height of a five-line staff.
+    pub fn height(&self) -> Millimeters {
+        Millimeters(self.staff_space.0 * 4.0)
+    }
+
+    /// Returns the distance between two adjacent staff lines.
+    pub fn staff_space(&self) -> Millimeters {
+        self.staff_space
+    }
+
+    /// Converts `value` to millimeters.
+    pub fn to_millimeters(&self, StaffSpaces(value): StaffSpaces) -> Millimeters {
+        Millimeters(value * self.staff_space.0)
+    }
+
+    /// Converts `value` to points.
+    pub fn to_points(&self, value: StaffSpaces) -> Points {
+        self.to_millimeters(value).into()
+    }
+
+    /// Converts a length in millimeters (or points) to staff spaces.
+    pub fn to_staff_spaces(&self, value: impl Into<Millimeters>) -> StaffSpaces {
+        let Millimeters(value) = value.into();
+
+        StaffSpaces(value / self.staff_space.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_height() {
+        let staff_size = StaffSize::from_height(Millimeters(7.0));
+
+        assert_eq!(staff_size.height(), Millimeters(7.0));
+        assert_eq!(staff_size.staff_space(), Millimeters(1.75));
+    }
+
+    #[test]
+    fn from_height_points() {
+        let staff_size = StaffSize::from_height(Points(72.0));
+
+        assert_eq!(staff_size.height(), Millimeters(25.4));
+    }
+
+    #[test]
+    fn from_staff_space() {
+        let staff_size = StaffSize::from_staff_space(Millimeters(1.75));
+
+        assert_eq!(staff_size.height(), Millimeters(7.0));
+        assert_eq!(staff_size.staff_space(), Millimeters(1.75));
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), Millimeters(0.0))]
+    #[case(StaffSpaces(1.0), Millimeters(2.0))]
+    #[case(StaffSpaces(-0.5), Millimeters(-1.0))]
+    fn to_millimeters(#[case] value: StaffSpaces, #[case] expected: Millimeters) {
+        let staff_size = StaffSize::from_height(Millimeters(8.0));
+
+        assert_eq!(staff_size.to_millimeters(value), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), Points(0.0))]
+    #[case(StaffSpaces(1.0), Points(18.0))]
+    fn to_points(#[case] value: StaffSpaces, #[case] expected: Points) {
+        let staff_size = StaffSize::from_height(Points(72.0));
+
+        assert_eq!(staff_size.to_points(value), expected);
+    }
+
+    #[rstest]
+    #[case(Millimeters(0.0), StaffSpaces(0.0))]
+    #[case(Millimeters(2.0), StaffSpaces(1.0))]
+    #[case(Millimeters(-1.0), StaffSpaces(-0.5))]
+    fn to_staff_spaces(#[case] value: Millimeters, #[case] expected: StaffSpaces) {
+        let staff_size = StaffSize::from_height(Millimeters(8.0));
+
+        assert_eq!(staff_size.to_staff_spaces(value), expected);
+    }
+}
@@ -1,16 +1,40 @@
-use std::{
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
     iter::Sum,
-    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
 };
 
-use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{float, Ems, Millimeters, Points, StaffSize};
 
 /// The primary unit of measurement for SMuFL fonts.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd)]
-#[cfg_attr(test, derive(serde::Serialize))]
-#[serde(transparent)]
+///
+/// Comparison, ordering and hashing use the IEEE 754 total order (see
+/// [f64::total_cmp]), so `StaffSpaces` can be used as a key in a
+/// [`BTreeMap`](std::collections::BTreeMap) or
+/// [`HashMap`](std::collections::HashMap) and sorted without unwrapping. Under
+/// this ordering `-0.0` is equal to `0.0`, and `NaN` is equal to itself.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct StaffSpaces(pub f64);
 
+/// Unit tag for [`euclid`](https://docs.rs/euclid) types measured in staff
+/// spaces.
+///
+/// [`StaffSpaces`], [`Coord`](crate::Coord), and
+/// [`BoundingBox`](crate::BoundingBox) convert to and from `euclid`'s
+/// [`Length`](euclid::Length), [`Point2D`](euclid::Point2D), and
+/// [`Rect`](euclid::Rect) with this unit.
+#[cfg(feature = "euclid")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct StaffSpaceUnit;
+
 macro_rules! impl_from {
     ($T:ty) => {
         impl From<$T> for StaffSpaces {
@@ -31,12 +55,136 @@ impl_from!(i32);
 impl_from!(f32);
 impl_from!(f64);
 
+impl From<Ems> for StaffSpaces {
+    fn from(Ems(value): Ems) -> Self {
+        Self(value / Ems::PER_STAFF_SPACE)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<euclid::Length<f64, StaffSpaceUnit>> for StaffSpaces {
+    fn from(value: euclid::Length<f64, StaffSpaceUnit>) -> Self {
+        Self(value.get())
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl From<StaffSpaces> for euclid::Length<f64, StaffSpaceUnit> {
+    fn from(StaffSpaces(value): StaffSpaces) -> Self {
+        Self::new(value)
+    }
+}
+
 impl From<StaffSpaces> for f64 {
     fn from(value: StaffSpaces) -> Self {
         value.0
     }
 }
 
+impl PartialEq for StaffSpaces {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for StaffSpaces {}
+
+impl PartialOrd for StaffSpaces {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StaffSpaces {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.normalized().total_cmp(&other.normalized())
+    }
+}
+
+impl Hash for StaffSpaces {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().to_bits().hash(state);
+    }
+}
+
+impl Display for StaffSpaces {
+    /// Formats the value followed by the unit, e.g. `1.25 sp`.
+    ///
+    /// The formatter's precision is applied to the number, so `{:.2}` can be
+    /// used to limit the number of decimal places.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} sp", precision, self.0),
+            None => write!(f, "{} sp", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for StaffSpaces {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for StaffSpaces {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for StaffSpaces {
+    /// Generates finite values between -100 and 100 staff spaces, with up to
+    /// three decimal places, like those found in metadata files.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let thousandths = u.int_in_range(-100_000..=100_000)?;
+
+        Ok(Self(f64::from(thousandths) / 1000.0))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <i32 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<rust_decimal::Decimal> for StaffSpaces {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        use rust_decimal::prelude::ToPrimitive;
+
+        Self(value.to_f64().unwrap_or(f64::NAN))
+    }
+}
+
+impl StaffSpaces {
+    /// Returns the inner value with `-0.0` replaced by `0.0`, so that the two
+    /// zeroes compare and hash the same.
+    fn normalized(self) -> f64 {
+        if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        }
+    }
+}
+
 impl Add for StaffSpaces {
     type Output = Self;
 
@@ -82,10 +230,11 @@ impl Mul<f64> for StaffSpaces {
 }
 
 impl Div<Self> for StaffSpaces {
-    type Output = Self;
+    /// Dividing one length by another produces a dimensionless ratio.
+    type Output = f64;
 
-    fn div(self, rhs: Self) -> Self {
-        Self(self.0 / rhs.0)
+    fn div(self, rhs: Self) -> f64 {
+        self.0 / rhs.0
     }
 }
 
@@ -97,6 +246,14 @@ impl Div<f64> for StaffSpaces {
     }
 }
 
+impl Neg for StaffSpaces {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
 impl Sum for StaffSpaces {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         Self(iter.map(|value| value.0).sum())
@@ -104,11 +261,69 @@ impl Sum for StaffSpaces {
 }
 
 impl StaffSpaces {
+    /// Creates a new `StaffSpaces`.
+    ///
+    /// This is equivalent to `StaffSpaces(value)`, and is provided for use in
+    /// const contexts alongside the other const methods:
+    ///
+    /// ```
+    /// use smufl::StaffSpaces;
+    ///
+    /// const STEM_LENGTH: StaffSpaces = StaffSpaces::new(3.5);
+    /// const SHORTENED_STEM_LENGTH: StaffSpaces = STEM_LENGTH.minus(StaffSpaces::new(0.5));
+    ///
+    /// assert_eq!(SHORTENED_STEM_LENGTH, StaffSpaces(3.0));
+    /// ```
+    pub const fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the inner value.
+    pub const fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Adds `other` to `self`.
+    ///
+    /// This is equivalent to `self + other`, but can be used in const
+    /// contexts.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub const fn plus(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtracts `other` from `self`.
+    ///
+    /// This is equivalent to `self - other`, but can be used in const
+    /// contexts.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub const fn minus(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    /// Multiplies `self` by `factor`.
+    ///
+    /// This is equivalent to `self * factor`, but can be used in const
+    /// contexts.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub const fn times(self, factor: f64) -> Self {
+        Self(self.0 * factor)
+    }
+
+    /// Divides `self` by `divisor`.
+    ///
+    /// This is equivalent to `self / divisor`, but can be used in const
+    /// contexts.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub const fn divided_by(self, divisor: f64) -> Self {
+        Self(self.0 / divisor)
+    }
+
     /// Computes the absolute value of `self`.
     ///
     /// See [f64::abs].
     #[must_use = "method returns a new number and does not mutate the original value"]
-    pub fn abs(&self) -> Self {
+    pub const fn abs(self) -> Self {
         Self(self.0.abs())
     }
 
@@ -116,7 +331,7 @@ impl StaffSpaces {
     ///
     /// See [f64::max].
     #[must_use = "this returns the result of the comparison, without modifying either input"]
-    pub fn max(&self, other: Self) -> Self {
+    pub const fn max(self, other: Self) -> Self {
         Self(self.0.max(other.0))
     }
 
@@ -124,18 +339,155 @@ impl StaffSpaces {
     ///
     /// See [f64::min].
     #[must_use = "this returns the result of the comparison, without modifying either input"]
-    pub fn min(&self, other: Self) -> Self {
+    pub const fn min(self, other: Self) -> Self {
         Self(self.0.min(other.0))
     }
 
+    /// Restricts the value to the interval `[min, max]`.
+    ///
+    /// See [f64::clamp].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        Self(self.0.clamp(min.0, max.0))
+    }
+
+    /// Returns the nearest integer to `self`, rounding half-way cases away from
+    /// `0.0`.
+    ///
+    /// See [f64::round].
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn round(self) -> Self {
+        Self(float::round(self.0))
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    ///
+    /// See [f64::floor].
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn floor(self) -> Self {
+        Self(float::floor(self.0))
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    ///
+    /// See [f64::ceil].
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn ceil(self) -> Self {
+        Self(float::ceil(self.0))
+    }
+
+    /// Returns the nearest multiple of `step`, rounding half-way cases away
+    /// from `0.0`.
+    ///
+    /// This is useful for snapping values to a grid, e.g. the size of a device
+    /// pixel.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn round_to(self, step: Self) -> Self {
+        Self(float::round(self.0 / step.0) * step.0)
+    }
+
+    /// Rounds to `decimals` decimal places, such that the result is serialized
+    /// with at most that many digits after the decimal point.
+    #[cfg(feature = "serde")]
+    pub(crate) fn round_decimals(self, decimals: usize) -> Self {
+        let rounded = format!("{:.*}", decimals, self.0)
+            .parse::<f64>()
+            .unwrap_or(self.0);
+
+        // Avoid serializing values such as `-0.0001` as `-0.0`.
+        Self(rounded + 0.0)
+    }
+
+    /// Linearly interpolates between `a` and `b`, where a `t` of `0.0` returns
+    /// `a` and a `t` of `1.0` returns `b`.
+    ///
+    /// `t` is not clamped, so values outside of `[0.0, 1.0]` extrapolate.
+    #[must_use]
+    pub const fn lerp(a: Self, b: Self, t: f64) -> Self {
+        Self(a.0 + (b.0 - a.0) * t)
+    }
+
     /// Returns `StaffSpaces(0.0)`.
     pub const fn zero() -> Self {
         Self(0.0)
     }
+
+    /// Converts `self` to ems. One staff space is 0.25 em.
+    pub fn to_ems(&self) -> Ems {
+        (*self).into()
+    }
+
+    /// Converts `self` to font design units, given the font's units per em
+    /// (e.g. from the `head` table).
+    pub fn to_font_units(&self, units_per_em: u16) -> f64 {
+        self.to_ems().to_font_units(units_per_em)
+    }
+
+    /// Converts a value in font design units to staff spaces, given the font's
+    /// units per em (e.g. from the `head` table).
+    pub fn from_font_units(value: f64, units_per_em: u16) -> Self {
+        Ems::from_font_units(value, units_per_em).into()
+    }
+
+    /// Returns the shortest decimal which converts back to the same `f64`.
+    ///
+    /// Values parsed from metadata are stored as the nearest `f64`, which is
+    /// usually not exactly the literal written in the file (e.g. `1.18`), so
+    /// this is useful for doing exact decimal arithmetic on metrics. The
+    /// original literal is not kept, so the result only matches it if it has
+    /// no trailing zeros and at most 15 significant digits: `1.180` gives
+    /// `1.18`, for example. Use [`DecimalMetadata`](crate::DecimalMetadata)
+    /// to keep the literals of a metadata file exactly.
+    ///
+    /// Returns `None` if the value is not finite or is out of the range of
+    /// [`Decimal`](rust_decimal::Decimal).
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use rust_decimal::Decimal;
+    /// use smufl::StaffSpaces;
+    ///
+    /// let value: StaffSpaces = serde_json::from_str("1.18").unwrap();
+    ///
+    /// assert_eq!(value.to_decimal(), Some(Decimal::from_str("1.18").unwrap()));
+    /// ```
+    #[cfg(feature = "decimal")]
+    pub fn to_decimal(&self) -> Option<rust_decimal::Decimal> {
+        use std::str::FromStr;
+
+        if !self.0.is_finite() {
+            return None;
+        }
+
+        // `f64`'s `Display` implementation writes the shortest representation
+        // that round-trips, without an exponent.
+        rust_decimal::Decimal::from_str(&self.0.to_string()).ok()
+    }
+
+    /// Converts `self` to millimeters for a staff of the given size.
+    ///
+    /// See [StaffSize::to_millimeters].
+    pub fn to_millimeters(&self, staff_size: StaffSize) -> Millimeters {
+        staff_size.to_millimeters(*self)
+    }
+
+    /// Converts `self` to points for a staff of the given size.
+    ///
+    /// See [StaffSize::to_points].
+    pub fn to_points(&self, staff_size: StaffSize) -> Points {
+        staff_size.to_points(*self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use rstest::*;
     use similar_asserts::assert_eq;
 
@@ -205,6 +557,14 @@ mod tests {
         assert_eq!(StaffSpaces::from(from), expected);
     }
 
+    #[rstest]
+    #[case(Ems(0.0), StaffSpaces(0.0))]
+    #[case(Ems(0.25), StaffSpaces(1.0))]
+    #[case(Ems(1.0), StaffSpaces(4.0))]
+    fn from_ems(#[case] from: Ems, #[case] expected: StaffSpaces) {
+        assert_eq!(StaffSpaces::from(from), expected);
+    }
+
     #[rstest]
     #[case(StaffSpaces(0.0), 0.0)]
     #[case(StaffSpaces(1.5), 1.5)]
@@ -213,6 +573,95 @@ mod tests {
         assert_eq!(f64::from(from), expected);
     }
 
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn euclid_length() {
+        let length: euclid::Length<f64, StaffSpaceUnit> = StaffSpaces(1.5).into();
+
+        assert_eq!(length.get(), 1.5);
+        assert_eq!(StaffSpaces::from(length), StaffSpaces(1.5));
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.0), StaffSpaces(2.0), Ordering::Less)]
+    #[case(StaffSpaces(2.0), StaffSpaces(1.0), Ordering::Greater)]
+    #[case(StaffSpaces(1.0), StaffSpaces(1.0), Ordering::Equal)]
+    #[case(StaffSpaces(-0.0), StaffSpaces(0.0), Ordering::Equal)]
+    #[case(StaffSpaces(-1.0), StaffSpaces(0.0), Ordering::Less)]
+    #[case(StaffSpaces(f64::NAN), StaffSpaces(f64::NAN), Ordering::Equal)]
+    #[case(StaffSpaces(f64::INFINITY), StaffSpaces(f64::NAN), Ordering::Less)]
+    fn cmp(#[case] left: StaffSpaces, #[case] right: StaffSpaces, #[case] expected: Ordering) {
+        assert_eq!(left.cmp(&right), expected);
+        assert_eq!(left.partial_cmp(&right), Some(expected));
+        assert_eq!(left == right, expected == Ordering::Equal);
+    }
+
+    #[test]
+    fn sort_and_dedup() {
+        let mut values = vec![
+            StaffSpaces(2.0),
+            StaffSpaces(1.0),
+            StaffSpaces(2.0),
+            StaffSpaces(0.5),
+        ];
+        values.sort();
+        values.dedup();
+
+        assert_eq!(
+            values,
+            [StaffSpaces(0.5), StaffSpaces(1.0), StaffSpaces(2.0)]
+        );
+    }
+
+    #[test]
+    fn hash() {
+        let values: HashSet<StaffSpaces> = [
+            StaffSpaces(1.0),
+            StaffSpaces(1.0),
+            StaffSpaces(2.0),
+            StaffSpaces(0.0),
+            StaffSpaces(-0.0),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(values.len(), 3);
+        assert!(values.contains(&StaffSpaces(1.0)));
+        assert!(values.contains(&StaffSpaces(2.0)));
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), "0 sp")]
+    #[case(StaffSpaces(1.25), "1.25 sp")]
+    #[case(StaffSpaces(-0.5), "-0.5 sp")]
+    fn display(#[case] value: StaffSpaces, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.0), 2, "1.00 sp")]
+    #[case(StaffSpaces(1.256), 2, "1.26 sp")]
+    #[case(StaffSpaces(1.256), 0, "1 sp")]
+    fn display_with_precision(
+        #[case] value: StaffSpaces,
+        #[case] precision: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(format!("{value:.precision$}"), expected);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx() {
+        approx::assert_abs_diff_eq!(StaffSpaces(0.1 + 0.2), StaffSpaces(0.3));
+        approx::assert_abs_diff_ne!(StaffSpaces(1.0), StaffSpaces(1.1), epsilon = 0.01);
+        approx::assert_relative_eq!(
+            StaffSpaces(1000.0),
+            StaffSpaces(1000.1),
+            max_relative = 0.001
+        );
+    }
+
     #[rstest]
     #[case(StaffSpaces(1.0), StaffSpaces(2.0), StaffSpaces(3.0))]
     #[case(StaffSpaces(2.0), StaffSpaces(1.0), StaffSpaces(3.0))]
@@ -270,13 +719,9 @@ mod tests {
     }
 
     #[rstest]
-    #[case(StaffSpaces(6.0), StaffSpaces(3.0), StaffSpaces(2.0))]
-    #[case(StaffSpaces(3.0), StaffSpaces(6.0), StaffSpaces(0.5))]
-    fn div_self(
-        #[case] left: StaffSpaces,
-        #[case] right: StaffSpaces,
-        #[case] expected: StaffSpaces,
-    ) {
+    #[case(StaffSpaces(6.0), StaffSpaces(3.0), 2.0)]
+    #[case(StaffSpaces(3.0), StaffSpaces(6.0), 0.5)]
+    fn div_self(#[case] left: StaffSpaces, #[case] right: StaffSpaces, #[case] expected: f64) {
         assert_eq!(left / right, expected);
     }
 
@@ -287,6 +732,13 @@ mod tests {
         assert_eq!(left / right, expected);
     }
 
+    #[rstest]
+    #[case(StaffSpaces(1.5), StaffSpaces(-1.5))]
+    #[case(StaffSpaces(-1.5), StaffSpaces(1.5))]
+    fn neg(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(-value, expected);
+    }
+
     #[rstest]
     #[case(&[], StaffSpaces(0.0))]
     #[case(&[StaffSpaces(1.0)], StaffSpaces(1.0))]
@@ -307,6 +759,7 @@ mod tests {
     #[case(StaffSpaces(0.0), StaffSpaces(1.0), StaffSpaces(1.0))]
     #[case(StaffSpaces(1.0), StaffSpaces(0.0), StaffSpaces(1.0))]
     #[case(StaffSpaces(1.0), StaffSpaces(1.0), StaffSpaces(1.0))]
+    #[case(StaffSpaces(f64::NAN), StaffSpaces(1.0), StaffSpaces(1.0))]
     fn max(#[case] value: StaffSpaces, #[case] other: StaffSpaces, #[case] expected: StaffSpaces) {
         assert_eq!(value.max(other), expected);
     }
@@ -315,12 +768,210 @@ mod tests {
     #[case(StaffSpaces(0.0), StaffSpaces(1.0), StaffSpaces(0.0))]
     #[case(StaffSpaces(1.0), StaffSpaces(0.0), StaffSpaces(0.0))]
     #[case(StaffSpaces(1.0), StaffSpaces(1.0), StaffSpaces(1.0))]
+    #[case(StaffSpaces(f64::NAN), StaffSpaces(1.0), StaffSpaces(1.0))]
     fn min(#[case] value: StaffSpaces, #[case] other: StaffSpaces, #[case] expected: StaffSpaces) {
         assert_eq!(value.min(other), expected);
     }
 
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(StaffSpaces(0.0), "0.0")]
+    #[case(StaffSpaces(1.18), "1.18")]
+    fn serialize(#[case] value: StaffSpaces, #[case] expected: &str) {
+        assert_eq!(serde_json::to_string(&value).unwrap(), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case("0", StaffSpaces(0.0))]
+    #[case("1.18", StaffSpaces(1.18))]
+    fn deserialize(#[case] json: &str, #[case] expected: StaffSpaces) {
+        assert_eq!(serde_json::from_str::<StaffSpaces>(json).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(-1.0), StaffSpaces(0.0))]
+    #[case(StaffSpaces(0.5), StaffSpaces(0.5))]
+    #[case(StaffSpaces(2.0), StaffSpaces(1.0))]
+    fn clamp(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(value.clamp(StaffSpaces(0.0), StaffSpaces(1.0)), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.4), StaffSpaces(1.0))]
+    #[case(StaffSpaces(1.5), StaffSpaces(2.0))]
+    #[case(StaffSpaces(-1.5), StaffSpaces(-2.0))]
+    fn round(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(value.round(), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.6), StaffSpaces(1.0))]
+    #[case(StaffSpaces(-1.2), StaffSpaces(-2.0))]
+    fn floor(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(value.floor(), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.2), StaffSpaces(2.0))]
+    #[case(StaffSpaces(-1.6), StaffSpaces(-1.0))]
+    fn ceil(#[case] value: StaffSpaces, #[case] expected: StaffSpaces) {
+        assert_eq!(value.ceil(), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.1), StaffSpaces(0.25), StaffSpaces(1.0))]
+    #[case(StaffSpaces(1.2), StaffSpaces(0.25), StaffSpaces(1.25))]
+    #[case(StaffSpaces(-0.4), StaffSpaces(0.5), StaffSpaces(-0.5))]
+    fn round_to(
+        #[case] value: StaffSpaces,
+        #[case] step: StaffSpaces,
+        #[case] expected: StaffSpaces,
+    ) {
+        assert_eq!(value.round_to(step), expected);
+    }
+
+    #[rstest]
+    #[case(0.0, StaffSpaces(1.0))]
+    #[case(0.5, StaffSpaces(2.0))]
+    #[case(1.0, StaffSpaces(3.0))]
+    #[case(2.0, StaffSpaces(5.0))]
+    fn lerp(#[case] t: f64, #[case] expected: StaffSpaces) {
+        assert_eq!(
+            StaffSpaces::lerp(StaffSpaces(1.0), StaffSpaces(3.0), t),
+            expected
+        );
+    }
+
+    #[test]
+    fn new() {
+        const VALUE: StaffSpaces = StaffSpaces::new(1.5);
+
+        assert_eq!(VALUE, StaffSpaces(1.5));
+        assert_eq!(VALUE.value(), 1.5);
+    }
+
+    #[test]
+    fn const_math() {
+        const A: StaffSpaces = StaffSpaces::new(3.0);
+        const B: StaffSpaces = StaffSpaces::new(-1.0);
+
+        const PLUS: StaffSpaces = A.plus(B);
+        const MINUS: StaffSpaces = A.minus(B);
+        const TIMES: StaffSpaces = A.times(2.0);
+        const DIVIDED_BY: StaffSpaces = A.divided_by(2.0);
+        const ABS: StaffSpaces = B.abs();
+        const MAX: StaffSpaces = A.max(B);
+        const MIN: StaffSpaces = A.min(B);
+        const CLAMP: StaffSpaces = A.clamp(B, StaffSpaces::new(2.0));
+        const LERP: StaffSpaces = StaffSpaces::lerp(A, B, 0.5);
+
+        assert_eq!(PLUS, StaffSpaces(2.0));
+        assert_eq!(MINUS, StaffSpaces(4.0));
+        assert_eq!(TIMES, StaffSpaces(6.0));
+        assert_eq!(DIVIDED_BY, StaffSpaces(1.5));
+        assert_eq!(ABS, StaffSpaces(1.0));
+        assert_eq!(MAX, StaffSpaces(3.0));
+        assert_eq!(MIN, StaffSpaces(-1.0));
+        assert_eq!(CLAMP, StaffSpaces(2.0));
+        assert_eq!(LERP, StaffSpaces(1.0));
+    }
+
     #[test]
     fn zero() {
         assert_eq!(StaffSpaces::zero(), StaffSpaces(0.0));
     }
+
+    #[test]
+    fn to_ems() {
+        assert_eq!(StaffSpaces(2.0).to_ems(), Ems(0.5));
+    }
+
+    #[rstest]
+    #[case(StaffSpaces(1.0), 1000, 250.0)]
+    #[case(StaffSpaces(1.18), 1000, 295.0)]
+    #[case(StaffSpaces(4.0), 2048, 2048.0)]
+    fn to_font_units(#[case] value: StaffSpaces, #[case] units_per_em: u16, #[case] expected: f64) {
+        assert_eq!(value.to_font_units(units_per_em), expected);
+    }
+
+    #[rstest]
+    #[case(250.0, 1000, StaffSpaces(1.0))]
+    #[case(2048.0, 2048, StaffSpaces(4.0))]
+    fn from_font_units(
+        #[case] value: f64,
+        #[case] units_per_em: u16,
+        #[case] expected: StaffSpaces,
+    ) {
+        assert_eq!(StaffSpaces::from_font_units(value, units_per_em), expected);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[rstest]
+    #[case(StaffSpaces(0.0), Some("0"))]
+    #[case(StaffSpaces(1.18), Some("1.18"))]
+    #[case(StaffSpaces(-0.004), Some("-0.004"))]
+    #[case(StaffSpaces(0.1 + 0.2), Some("0.30000000000000004"))]
+    #[case(StaffSpaces(f64::NAN), None)]
+    #[case(StaffSpaces(f64::INFINITY), None)]
+    fn to_decimal(#[case] value: StaffSpaces, #[case] expected: Option<&str>) {
+        assert_eq!(
+            value.to_decimal().map(|decimal| decimal.to_string()),
+            expected.map(str::to_owned)
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[rstest]
+    #[case("1.18", "1.18")]
+    #[case("1.180", "1.18")]
+    #[case("0.12345678901234567890", "0.12345678901234568")]
+    fn to_decimal_parsed(#[case] literal: &str, #[case] expected: &str) {
+        let value: StaffSpaces = serde_json::from_str(literal).unwrap();
+
+        assert_eq!(value.to_decimal().unwrap().to_string(), expected);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn from_decimal() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            StaffSpaces::from(rust_decimal::Decimal::from_str("1.18").unwrap()),
+            StaffSpaces(1.18)
+        );
+    }
+
+    #[test]
+    fn to_millimeters() {
+        let staff_size = StaffSize::from_height(Millimeters(7.0));
+
+        assert_eq!(
+            StaffSpaces(2.0).to_millimeters(staff_size),
+            Millimeters(3.5)
+        );
+    }
+
+    #[test]
+    fn to_points() {
+        let staff_size = StaffSize::from_height(Points(72.0));
+
+        assert_eq!(StaffSpaces(2.0).to_points(staff_size), Points(36.0));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..=255).cycle().take(1024).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let StaffSpaces(value) = StaffSpaces::arbitrary(&mut u).unwrap();
+
+            assert!((-100.0..=100.0).contains(&value));
+        }
+    }
 }
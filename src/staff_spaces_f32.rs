@@ -0,0 +1,192 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::StaffSpaces;
+
+/// Single-precision variant of [`StaffSpaces`].
+///
+/// Metadata is parsed into [`StaffSpaces`], which stores an `f64`. Renderers
+/// that upload metrics to the GPU, or that keep large tables of metrics on
+/// memory-constrained targets, can convert to `StaffSpacesF32` to halve the
+/// storage size. SMuFL metadata rarely specifies more than three decimal
+/// places, so the loss of precision is not significant in practice.
+///
+/// ```
+/// use smufl::{StaffSpaces, StaffSpacesF32};
+///
+/// let value = StaffSpacesF32::from(StaffSpaces(1.18));
+///
+/// assert_eq!(value, StaffSpacesF32(1.18));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct StaffSpacesF32(pub f32);
+
+impl From<f32> for StaffSpacesF32 {
+    #[inline(always)]
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StaffSpacesF32> for f32 {
+    fn from(value: StaffSpacesF32) -> Self {
+        value.0
+    }
+}
+
+impl From<StaffSpaces> for StaffSpacesF32 {
+    /// Converts to single precision, rounding to the nearest representable
+    /// value.
+    fn from(StaffSpaces(value): StaffSpaces) -> Self {
+        Self(value as f32)
+    }
+}
+
+impl From<StaffSpacesF32> for StaffSpaces {
+    fn from(StaffSpacesF32(value): StaffSpacesF32) -> Self {
+        Self(f64::from(value))
+    }
+}
+
+impl Add for StaffSpacesF32 {
+    type Output = Self;
+
+    fn add(self, StaffSpacesF32(rhs): Self) -> Self {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign for StaffSpacesF32 {
+    fn add_assign(&mut self, StaffSpacesF32(rhs): Self) {
+        self.0 += rhs
+    }
+}
+
+impl Sub for StaffSpacesF32 {
+    type Output = Self;
+
+    fn sub(self, StaffSpacesF32(rhs): Self) -> Self {
+        Self(self.0 - rhs)
+    }
+}
+
+impl SubAssign for StaffSpacesF32 {
+    fn sub_assign(&mut self, StaffSpacesF32(rhs): Self) {
+        self.0 -= rhs;
+    }
+}
+
+impl Mul<f32> for StaffSpacesF32 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<f32> for StaffSpacesF32 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Neg for StaffSpacesF32 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Sum for StaffSpacesF32 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|value| value.0).sum())
+    }
+}
+
+impl Display for StaffSpacesF32 {
+    /// Formats the value followed by the unit, e.g. `1.25 sp`.
+    ///
+    /// The formatter's precision is applied to the number.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} sp", precision, self.0),
+            None => write!(f, "{} sp", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(StaffSpaces(0.0), StaffSpacesF32(0.0))]
+    #[case(StaffSpaces(1.5), StaffSpacesF32(1.5))]
+    #[case(StaffSpaces(1.18), StaffSpacesF32(1.18))]
+    fn from_staff_spaces(#[case] from: StaffSpaces, #[case] expected: StaffSpacesF32) {
+        assert_eq!(StaffSpacesF32::from(from), expected);
+    }
+
+    #[rstest]
+    #[case(StaffSpacesF32(0.0), StaffSpaces(0.0))]
+    #[case(StaffSpacesF32(1.5), StaffSpaces(1.5))]
+    fn into_staff_spaces(#[case] from: StaffSpacesF32, #[case] expected: StaffSpaces) {
+        assert_eq!(StaffSpaces::from(from), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let value: StaffSpacesF32 = serde_json::from_str("1.25").unwrap();
+
+        assert_eq!(value, StaffSpacesF32(1.25));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&StaffSpacesF32(1.25)).unwrap(),
+            "1.25"
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut value = StaffSpacesF32(1.0) + StaffSpacesF32(2.0) - StaffSpacesF32(0.5);
+        value += StaffSpacesF32(1.0);
+        value -= StaffSpacesF32(0.5);
+
+        assert_eq!(value, StaffSpacesF32(3.0));
+        assert_eq!(value * 2.0, StaffSpacesF32(6.0));
+        assert_eq!(value / 2.0, StaffSpacesF32(1.5));
+        assert_eq!(-value, StaffSpacesF32(-3.0));
+    }
+
+    #[rstest]
+    #[case(&[], StaffSpacesF32(0.0))]
+    #[case(&[StaffSpacesF32(1.0), StaffSpacesF32(2.0)], StaffSpacesF32(3.0))]
+    fn sum(#[case] values: &[StaffSpacesF32], #[case] expected: StaffSpacesF32) {
+        assert_eq!(values.iter().copied().sum::<StaffSpacesF32>(), expected);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(StaffSpacesF32(1.25).to_string(), "1.25 sp");
+        assert_eq!(format!("{:.3}", StaffSpacesF32(1.25)), "1.250 sp");
+    }
+}
@@ -0,0 +1,9 @@
+/// The direction of a note's stem.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StemDirection {
+    /// The stem points up from the notehead.
+    Up,
+
+    /// The stem points down from the notehead.
+    Down,
+}
@@ -0,0 +1,161 @@
+use std::fmt::{self, Display, Formatter};
+
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+/// The text font families that are ideally paired with a music font, in
+/// descending order of preference.
+///
+/// Fonts list a handful of families, with names short enough to be stored
+/// inline, so loading a font's metadata usually makes no heap allocations for
+/// them.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct TextFontFamily(SmallVec<[CompactString; 4]>);
+
+impl TextFontFamily {
+    /// Returns an empty list of families.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of families.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no families.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the family at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.0.get(index).map(CompactString::as_str)
+    }
+
+    /// Returns an iterator over the families, most preferred first.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(CompactString::as_str)
+    }
+
+    /// Shrinks the list, and any names that don't fit inline, to fit.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+        for family in &mut self.0 {
+            family.shrink_to_fit();
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap, which is zero unless
+    /// there are more families, or longer names, than fit inline.
+    pub(crate) fn heap_size(&self) -> usize {
+        let list = if self.0.spilled() {
+            self.0.capacity() * size_of::<CompactString>()
+        } else {
+            0
+        };
+        let names = self
+            .0
+            .iter()
+            .filter(|family| family.is_heap_allocated())
+            .map(CompactString::capacity)
+            .sum::<usize>();
+
+        list + names
+    }
+
+    /// Adds a family, less preferred than those already listed.
+    pub fn push(&mut self, family: &str) {
+        self.0.push(family.into());
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for TextFontFamily {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|family| family.as_ref().into())
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<String>> for TextFontFamily {
+    fn from(value: Vec<String>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<TextFontFamily> for Vec<String> {
+    fn from(value: TextFontFamily) -> Self {
+        value.iter().map(str::to_owned).collect()
+    }
+}
+
+/// Formats the families as a CSS `font-family` value, e.g. `Academico, serif`.
+impl Display for TextFontFamily {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, family) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(family)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TextFontFamily {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "TextFontFamily".into()
+    }
+
+    /// Describes an array of family names.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<String>::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let family: TextFontFamily = serde_json::from_str(r#"["Academico", "serif"]"#).unwrap();
+
+        assert_eq!(family.iter().collect::<Vec<_>>(), ["Academico", "serif"]);
+        assert_eq!(
+            serde_json::to_string(&family).unwrap(),
+            r#"["Academico","serif"]"#
+        );
+    }
+
+    #[test]
+    fn display() {
+        let family = ["Academico", "serif"]
+            .into_iter()
+            .collect::<TextFontFamily>();
+
+        assert_eq!(family.to_string(), "Academico, serif");
+    }
+
+    #[test]
+    fn heap_size() {
+        let inline = ["Academico", "serif"]
+            .into_iter()
+            .collect::<TextFontFamily>();
+        let name = "A family name which is too long to fit inline";
+        let long = [name].into_iter().collect::<TextFontFamily>();
+
+        assert_eq!(inline.heap_size(), 0);
+        assert!(long.heap_size() >= name.len());
+    }
+}
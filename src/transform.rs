@@ -0,0 +1,107 @@
+use crate::{Coord, StaffSpaces};
+
+/// A uniform scale followed by a translation, for placing glyph metrics, e.g.
+/// scaling a grace note and positioning it relative to a staff.
+///
+/// ```
+/// use smufl::{Coord, StaffSpaces, Transform};
+///
+/// let grace_note = Transform::scale(0.6).then(&Transform::translate(Coord(
+///     StaffSpaces(2.0),
+///     StaffSpaces(1.0),
+/// )));
+///
+/// assert_eq!(
+///     grace_note.apply(Coord(StaffSpaces(1.0), StaffSpaces(0.5))),
+///     Coord(StaffSpaces(2.6), StaffSpaces(1.3))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// The factor by which coordinates are scaled, relative to the origin.
+    pub scale: f64,
+
+    /// The offset added to coordinates after scaling.
+    pub translate: Coord,
+}
+
+impl Transform {
+    /// Creates a transform which scales by `scale` and then translates by
+    /// `translate`.
+    pub const fn new(scale: f64, translate: Coord) -> Self {
+        Self { scale, translate }
+    }
+
+    /// Returns a transform which leaves coordinates unchanged.
+    pub const fn identity() -> Self {
+        Self::scale(1.0)
+    }
+
+    /// Returns a transform which only scales by `scale`.
+    pub const fn scale(scale: f64) -> Self {
+        Self::new(scale, Coord::new(StaffSpaces::zero(), StaffSpaces::zero()))
+    }
+
+    /// Returns a transform which only translates by `translate`.
+    pub const fn translate(translate: Coord) -> Self {
+        Self::new(1.0, translate)
+    }
+
+    /// Returns a transform equivalent to applying `self` and then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        Self {
+            scale: self.scale * next.scale,
+            translate: next.apply(self.translate),
+        }
+    }
+
+    /// Applies the transform to `coord`.
+    pub fn apply(&self, coord: Coord) -> Coord {
+        coord * self.scale + self.translate
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    fn transform() -> Transform {
+        Transform::new(2.0, (1.0, -1.0).into())
+    }
+
+    #[rstest]
+    #[case(Transform::identity(), (1.0, 2.0))]
+    #[case(Transform::scale(0.5), (0.5, 1.0))]
+    #[case(Transform::translate((1.0, -1.0).into()), (2.0, 1.0))]
+    #[case(transform(), (3.0, 3.0))]
+    fn apply(#[case] transform: Transform, #[case] expected: (f64, f64)) {
+        assert_eq!(transform.apply((1.0, 2.0).into()), expected.into());
+    }
+
+    #[test]
+    fn then() {
+        let first = transform();
+        let second = Transform::new(0.5, (0.0, 3.0).into());
+        let coord = Coord::from((1.0, 2.0));
+
+        assert_eq!(
+            first.then(&second).apply(coord),
+            second.apply(first.apply(coord))
+        );
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Transform::default(), Transform::identity());
+    }
+}
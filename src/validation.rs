@@ -0,0 +1,418 @@
+//! Checks for problems in [`Metadata`] which parse successfully but will
+//! produce poor or broken results.
+
+use itertools::Itertools;
+
+use crate::{GlyphRange, Metadata, Severity, StaffSpaces, ValidationIssue};
+
+/// How far an anchor may be outside its glyph's bounding box before it is
+/// reported, since anchors and bounding boxes are often rounded separately.
+const ANCHOR_TOLERANCE: StaffSpaces = StaffSpaces(0.01);
+
+/// The smallest advance width, as a fraction of the glyph's bounding box
+/// width, which isn't reported. Smaller advance widths are usually a sign of
+/// values exported in the wrong units, e.g. ems rather than staff spaces.
+const MIN_ADVANCE_WIDTH_RATIO: f64 = 0.5;
+
+impl Metadata {
+    /// Checks the metadata for problems, returning the issues found, ordered by
+    /// check and then by glyph name.
+    ///
+    /// The following are reported:
+    ///
+    /// - Glyphs which are not in SMuFL, as warnings.
+    /// - Invalid bounding boxes (see
+    ///   [`BoundingBox::is_valid`](crate::BoundingBox::is_valid)), as errors.
+    /// - [`stem_up_se`](crate::Anchors::stem_up_se) anchors left of the center
+    ///   of the glyph's bounding box, and
+    ///   [`stem_down_nw`](crate::Anchors::stem_down_nw) anchors right of it,
+    ///   which attach stems to the wrong side of noteheads, as errors.
+    /// - Cut-out, [`notehead_origin`](crate::Anchors::notehead_origin) and
+    ///   [`optical_center`](crate::Anchors::optical_center) anchors outside the
+    ///   glyph's bounding box, as warnings.
+    /// - Negative advance widths, as errors, and advance widths which are zero
+    ///   or less than half the width of the glyph's bounding box, as warnings.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        [
+            unknown_glyphs,
+            invalid_bounding_boxes,
+            misplaced_stem_anchors,
+            anchors_outside_bounding_boxes,
+            suspicious_advance_widths,
+        ]
+        .iter()
+        .flat_map(|check| check(self))
+        .collect()
+    }
+
+    /// Checks that the font has the [recommended
+    /// glyphs](GlyphRange::recommended) in each of `ranges`, returning a
+    /// warning for each range with missing glyphs, e.g. so that applications
+    /// can tell users that a font lacks percussion noteheads before rendering
+    /// with it.
+    ///
+    /// A glyph is considered present if it has a bounding box, since
+    /// `glyphBBoxes` lists every glyph in the font.
+    ///
+    /// This isn't part of [`validate`](Self::validate), since many fonts
+    /// intentionally cover only some ranges.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use smufl::{GlyphRange, Metadata};
+    ///
+    /// let json = r#"{"fontName": "Test"}"#;
+    /// let metadata = Metadata::from_reader(json.as_bytes())?;
+    ///
+    /// let issues = metadata.check_coverage(GlyphRange::RECOMMENDED);
+    /// assert_eq!(issues.len(), GlyphRange::RECOMMENDED.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_coverage(&self, ranges: &[GlyphRange]) -> Vec<ValidationIssue> {
+        ranges
+            .iter()
+            .filter_map(|range| {
+                let missing = range
+                    .recommended
+                    .iter()
+                    .filter(|&&glyph| self.bounding_boxes.get(glyph).is_none())
+                    .map(|glyph| glyph.name())
+                    .collect::<Vec<_>>();
+
+                (!missing.is_empty()).then(|| {
+                    ValidationIssue::new(
+                        Severity::Warning,
+                        None,
+                        format!(
+                            "missing {} of {} recommended glyphs in {}: {}",
+                            missing.len(),
+                            range.recommended.len(),
+                            range.name,
+                            missing.join(", ")
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+fn unknown_glyphs(metadata: &Metadata) -> Vec<ValidationIssue> {
+    [
+        (
+            "glyphAdvanceWidths",
+            metadata.advance_widths.unknown_glyphs().collect::<Vec<_>>(),
+        ),
+        (
+            "glyphsWithAnchors",
+            metadata.anchors.unknown_glyphs().collect(),
+        ),
+        (
+            "glyphBBoxes",
+            metadata.bounding_boxes.unknown_glyphs().collect(),
+        ),
+    ]
+    .into_iter()
+    .flat_map(|(section, names)| {
+        names.into_iter().sorted().map(move |name| {
+            ValidationIssue::new(
+                Severity::Warning,
+                Some(name.clone()),
+                format!("unknown glyph in {section}"),
+            )
+        })
+    })
+    .collect()
+}
+
+fn invalid_bounding_boxes(metadata: &Metadata) -> Vec<ValidationIssue> {
+    metadata
+        .bounding_boxes
+        .iter()
+        .filter(|(_, bounding_box)| !bounding_box.is_valid())
+        .map(|(glyph, bounding_box)| {
+            ValidationIssue::new(
+                Severity::Error,
+                Some(glyph.name().to_owned()),
+                format!("invalid bounding box ({bounding_box})"),
+            )
+        })
+        .sorted_by(|a, b| a.glyph.cmp(&b.glyph))
+        .collect()
+}
+
+fn misplaced_stem_anchors(metadata: &Metadata) -> Vec<ValidationIssue> {
+    metadata
+        .anchors
+        .iter()
+        .filter_map(|(glyph, anchors)| Some((glyph, anchors, metadata.bounding_boxes.get(glyph)?)))
+        .flat_map(|(glyph, anchors, bounding_box)| {
+            let center = bounding_box.center().x();
+            let stem_up = anchors
+                .stem_up_se
+                .filter(|anchor| anchor.x() < center)
+                .map(|anchor| {
+                    format!(
+                        "stemUpSE anchor ({anchor}) is left of the center of the bounding box \
+                         ({bounding_box})"
+                    )
+                });
+            let stem_down = anchors
+                .stem_down_nw
+                .filter(|anchor| anchor.x() > center)
+                .map(|anchor| {
+                    format!(
+                        "stemDownNW anchor ({anchor}) is right of the center of the bounding \
+                         box ({bounding_box})"
+                    )
+                });
+
+            stem_up.into_iter().chain(stem_down).map(move |message| {
+                ValidationIssue::new(Severity::Error, Some(glyph.name().to_owned()), message)
+            })
+        })
+        .sorted_by(|a, b| a.glyph.cmp(&b.glyph))
+        .collect()
+}
+
+fn anchors_outside_bounding_boxes(metadata: &Metadata) -> Vec<ValidationIssue> {
+    metadata
+        .anchors
+        .iter()
+        .filter_map(|(glyph, anchors)| Some((glyph, anchors, metadata.bounding_boxes.get(glyph)?)))
+        .flat_map(|(glyph, anchors, bounding_box)| {
+            let bounds = bounding_box.expanded(ANCHOR_TOLERANCE);
+
+            [
+                ("cutOutNE", anchors.cut_out_ne),
+                ("cutOutSE", anchors.cut_out_se),
+                ("cutOutSW", anchors.cut_out_sw),
+                ("cutOutNW", anchors.cut_out_nw),
+                ("noteheadOrigin", anchors.notehead_origin),
+                ("opticalCenter", anchors.optical_center),
+            ]
+            .into_iter()
+            .filter_map(move |(name, anchor)| {
+                let anchor = anchor.filter(|&anchor| !bounds.contains(anchor))?;
+
+                Some(ValidationIssue::new(
+                    Severity::Warning,
+                    Some(glyph.name().to_owned()),
+                    format!(
+                        "{name} anchor ({anchor}) is outside the bounding box ({bounding_box})"
+                    ),
+                ))
+            })
+        })
+        .sorted_by(|a, b| a.glyph.cmp(&b.glyph))
+        .collect()
+}
+
+fn suspicious_advance_widths(metadata: &Metadata) -> Vec<ValidationIssue> {
+    metadata
+        .advance_widths
+        .iter()
+        .filter_map(|(glyph, &advance_width)| {
+            let width = metadata.bounding_boxes.get(glyph)?.width();
+
+            let (severity, message) = if advance_width < StaffSpaces::zero() {
+                (
+                    Severity::Error,
+                    format!("negative advance width ({advance_width})"),
+                )
+            } else if advance_width == StaffSpaces::zero() && width > StaffSpaces::zero() {
+                (
+                    Severity::Warning,
+                    format!("zero advance width for a glyph {width} wide"),
+                )
+            } else if advance_width < width * MIN_ADVANCE_WIDTH_RATIO {
+                (
+                    Severity::Warning,
+                    format!(
+                        "advance width ({advance_width}) is less than half the bounding box \
+                         width ({width})"
+                    ),
+                )
+            } else {
+                return None;
+            };
+
+            Some(ValidationIssue::new(
+                severity,
+                Some(glyph.name().to_owned()),
+                message,
+            ))
+        })
+        .sorted_by(|a, b| a.glyph.cmp(&b.glyph))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn validate() -> anyhow::Result<()> {
+        let metadata = Metadata::from_reader(
+            r#"{
+                "fontName": "Test",
+                "glyphAdvanceWidths": {"notAGlyph": 1},
+                "glyphBBoxes": {
+                    "noteheadBlack": {"bBoxNE": [0, 0], "bBoxSW": [1, 1]},
+                    "noteheadWhole": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]}
+                }
+            }"#
+            .as_bytes(),
+        )?;
+
+        let issues = metadata
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "warning: notAGlyph: unknown glyph in glyphAdvanceWidths",
+                "error: noteheadBlack: invalid bounding box (sw: (1 sp, 1 sp), ne: (0 sp, 0 sp))",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_anchors() -> anyhow::Result<()> {
+        let metadata = Metadata::from_reader(
+            r#"{
+                "fontName": "Test",
+                "glyphsWithAnchors": {
+                    "noteheadBlack": {
+                        "stemUpSE": [0.2, 0.168],
+                        "stemDownNW": [0.0, -0.168],
+                        "cutOutNE": [1.18, 0.5],
+                        "cutOutSW": [0.005, -0.5]
+                    },
+                    "noteheadWhole": {
+                        "stemUpSE": [1.68, 0.168],
+                        "stemDownNW": [1.5, -0.168]
+                    },
+                    "dynamicForte": {
+                        "opticalCenter": [0.5, 1.5]
+                    },
+                    "noteheadParenthesis": {
+                        "noteheadOrigin": [-0.5, 0]
+                    }
+                },
+                "glyphBBoxes": {
+                    "noteheadBlack": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]},
+                    "noteheadWhole": {"bBoxNE": [1.68, 0.5], "bBoxSW": [0, -0.5]},
+                    "dynamicForte": {"bBoxNE": [1, 1], "bBoxSW": [0, -0.5]},
+                    "noteheadParenthesis": {"bBoxNE": [2, 0.5], "bBoxSW": [0, -0.5]}
+                }
+            }"#
+            .as_bytes(),
+        )?;
+
+        let issues = metadata
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "error: noteheadBlack: stemUpSE anchor ((0.2 sp, 0.168 sp)) is left of the center of the bounding box (sw: (0 sp, -0.5 sp), ne: (1.18 sp, 0.5 sp))",
+                "error: noteheadWhole: stemDownNW anchor ((1.5 sp, -0.168 sp)) is right of the center of the bounding box (sw: (0 sp, -0.5 sp), ne: (1.68 sp, 0.5 sp))",
+                "warning: dynamicForte: opticalCenter anchor ((0.5 sp, 1.5 sp)) is outside the bounding box (sw: (0 sp, -0.5 sp), ne: (1 sp, 1 sp))",
+                "warning: noteheadParenthesis: noteheadOrigin anchor ((-0.5 sp, 0 sp)) is outside the bounding box (sw: (0 sp, -0.5 sp), ne: (2 sp, 0.5 sp))",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_advance_widths() -> anyhow::Result<()> {
+        let metadata = Metadata::from_reader(
+            r#"{
+                "fontName": "Test",
+                "glyphAdvanceWidths": {
+                    "noteheadBlack": 0.295,
+                    "noteheadWhole": -1.68,
+                    "noteheadHalf": 0,
+                    "noteheadDoubleWhole": 2.5,
+                    "stem": 0
+                },
+                "glyphBBoxes": {
+                    "noteheadBlack": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]},
+                    "noteheadWhole": {"bBoxNE": [1.68, 0.5], "bBoxSW": [0, -0.5]},
+                    "noteheadHalf": {"bBoxNE": [1.18, 0.5], "bBoxSW": [0, -0.5]},
+                    "noteheadDoubleWhole": {"bBoxNE": [2.5, 0.5], "bBoxSW": [0, -0.5]},
+                    "stem": {"bBoxNE": [0, 3.5], "bBoxSW": [0, 0]}
+                }
+            }"#
+            .as_bytes(),
+        )?;
+
+        let issues = metadata
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "warning: noteheadBlack: advance width (0.295 sp) is less than half the bounding box width (1.18 sp)",
+                "warning: noteheadHalf: zero advance width for a glyph 1.18 sp wide",
+                "error: noteheadWhole: negative advance width (-1.68 sp)",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_coverage() -> anyhow::Result<()> {
+        let metadata = Metadata::from_reader(
+            r#"{
+                "fontName": "Test",
+                "glyphBBoxes": {
+                    "accidentalFlat": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                    "accidentalNatural": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                    "accidentalSharp": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                    "fermataAbove": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                    "fermataBelow": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]},
+                    "breathMarkComma": {"bBoxNE": [1, 1], "bBoxSW": [0, 0]}
+                }
+            }"#
+            .as_bytes(),
+        )?;
+
+        let issues = metadata
+            .check_coverage(&[
+                GlyphRange::STANDARD_ACCIDENTALS,
+                GlyphRange::HOLDS_AND_PAUSES,
+                GlyphRange::BARLINES,
+            ])
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "warning: missing 2 of 5 recommended glyphs in Standard accidentals (12-EDO): accidentalDoubleSharp, accidentalDoubleFlat",
+                "warning: missing 3 of 3 recommended glyphs in Barlines: barlineSingle, barlineDouble, barlineFinal",
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::Severity;
+
+/// A problem found by [`Metadata::validate`](crate::Metadata::validate).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ValidationIssue {
+    /// How serious the problem is.
+    pub severity: Severity,
+
+    /// The name of the glyph the problem applies to, as it appears in the
+    /// metadata file, if the problem is specific to one glyph.
+    pub glyph: Option<String>,
+
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl ValidationIssue {
+    pub(crate) fn new(
+        severity: Severity,
+        glyph: Option<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            glyph,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ValidationIssue {
+    /// Formats the issue as `severity: glyph: message`, e.g.
+    /// `error: noteheadBlack: invalid bounding box`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.glyph {
+            Some(glyph) => write!(f, "{}: {glyph}: {}", self.severity, self.message),
+            None => write!(f, "{}: {}", self.severity, self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        ValidationIssue::new(Severity::Error, Some("noteheadBlack".to_owned()), "invalid bounding box"),
+        "error: noteheadBlack: invalid bounding box"
+    )]
+    #[case(
+        ValidationIssue::new(Severity::Warning, None, "no glyphs"),
+        "warning: no glyphs"
+    )]
+    fn display(#[case] issue: ValidationIssue, #[case] expected: &str) {
+        assert_eq!(issue.to_string(), expected);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{BoundingBox, Glyph};
+
+/// A problem found while parsing [`Metadata`](crate::Metadata) which doesn't
+/// prevent it from being used, as passed to a
+/// [`WarningSink`](crate::WarningSink).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// The metadata has data for a glyph which is not in SMuFL.
+    UnknownGlyph {
+        /// The name of the glyph, as it appears in the metadata file.
+        name: String,
+    },
+
+    /// A glyph has an anchor which is not in SMuFL. The anchor's data is
+    /// discarded.
+    UnknownAnchor {
+        /// The name of the glyph, as it appears in the metadata file.
+        glyph: String,
+
+        /// The name of the anchor, as it appears in the metadata file.
+        anchor: String,
+    },
+
+    /// The metadata file has no section with this name, e.g. `glyphBBoxes`, so
+    /// it is treated as empty.
+    MissingSection {
+        /// The name of the section, as it would appear in the metadata file.
+        section: &'static str,
+    },
+
+    /// A glyph has more than one entry in a section. The last entry is kept.
+    ///
+    /// Only reported if
+    /// [`ParseOptions::detect_duplicate_glyphs`](crate::ParseOptions::detect_duplicate_glyphs)
+    /// is set.
+    DuplicateGlyph {
+        /// The name of the section, e.g. `glyphBBoxes`.
+        section: &'static str,
+
+        /// The name of the glyph, as it appears in the metadata file.
+        glyph: String,
+    },
+
+    /// A glyph's bounding box is not valid (see
+    /// [`BoundingBox::is_valid`](crate::BoundingBox::is_valid)).
+    InvalidBoundingBox {
+        /// The glyph.
+        glyph: Glyph,
+
+        /// The invalid bounding box.
+        bounding_box: BoundingBox,
+    },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownGlyph { name } => write!(f, "unknown glyph {name}"),
+            Self::UnknownAnchor { glyph, anchor } => {
+                write!(f, "unknown anchor {anchor} on {glyph}")
+            }
+            Self::MissingSection { section } => write!(f, "missing section {section}"),
+            Self::DuplicateGlyph { section, glyph } => {
+                write!(f, "duplicate entries for {glyph} in {section}")
+            }
+            Self::InvalidBoundingBox {
+                glyph,
+                bounding_box,
+            } => write!(
+                f,
+                "invalid bounding box for {} ({bounding_box})",
+                glyph.name()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use similar_asserts::assert_eq;
+
+    use super::*;
+    use crate::{Coord, StaffSpaces};
+
+    #[rstest]
+    #[case(
+        Warning::UnknownGlyph { name: "notAGlyph".to_owned() },
+        "unknown glyph notAGlyph"
+    )]
+    #[case(
+        Warning::UnknownAnchor { glyph: "noteheadBlack".to_owned(), anchor: "vendorAnchor".to_owned() },
+        "unknown anchor vendorAnchor on noteheadBlack"
+    )]
+    #[case(
+        Warning::MissingSection { section: "glyphBBoxes" },
+        "missing section glyphBBoxes"
+    )]
+    #[case(
+        Warning::DuplicateGlyph { section: "glyphBBoxes", glyph: "noteheadBlack".to_owned() },
+        "duplicate entries for noteheadBlack in glyphBBoxes"
+    )]
+    #[case(
+        Warning::InvalidBoundingBox {
+            glyph: Glyph::NoteheadBlack,
+            bounding_box: BoundingBox {
+                ne: Coord(StaffSpaces(0.0), StaffSpaces(0.0)),
+                sw: Coord(StaffSpaces(1.0), StaffSpaces(1.0)),
+            },
+        },
+        "invalid bounding box for noteheadBlack (sw: (1 sp, 1 sp), ne: (0 sp, 0 sp))"
+    )]
+    fn display(#[case] warning: Warning, #[case] expected: &str) {
+        assert_eq!(warning.to_string(), expected);
+    }
+}
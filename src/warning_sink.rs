@@ -0,0 +1,55 @@
+use std::sync::mpsc::{Sender, SyncSender};
+
+use crate::Warning;
+
+/// Receives the [`Warning`]s found while parsing metadata with
+/// [`Metadata::from_reader_with_sink`](crate::Metadata::from_reader_with_sink),
+/// e.g. to show them to users, or to check them in tests.
+///
+/// This is implemented for closures, channel senders, and `&mut Vec<Warning>`.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use smufl::{Metadata, ParseOptions, Warning};
+///
+/// let json = r#"{"fontName": "Test", "glyphAdvanceWidths": {"notAGlyph": 1.0}}"#;
+/// let mut warnings = Vec::new();
+/// Metadata::from_reader_with_sink(json.as_bytes(), &ParseOptions::default(), &mut warnings)?;
+///
+/// assert!(warnings.contains(&Warning::UnknownGlyph {
+///     name: "notAGlyph".to_owned()
+/// }));
+/// # Ok(())
+/// # }
+/// ```
+pub trait WarningSink {
+    /// Handles a single warning.
+    fn warn(&mut self, warning: Warning);
+}
+
+impl<F: FnMut(Warning)> WarningSink for F {
+    fn warn(&mut self, warning: Warning) {
+        self(warning);
+    }
+}
+
+impl WarningSink for &mut Vec<Warning> {
+    fn warn(&mut self, warning: Warning) {
+        self.push(warning);
+    }
+}
+
+impl WarningSink for Sender<Warning> {
+    /// Sends the warning, ignoring it if the receiver has been dropped.
+    fn warn(&mut self, warning: Warning) {
+        let _ = self.send(warning);
+    }
+}
+
+impl WarningSink for SyncSender<Warning> {
+    /// Sends the warning, blocking if the channel is full, and ignoring it if
+    /// the receiver has been dropped.
+    fn warn(&mut self, warning: Warning) {
+        let _ = self.send(warning);
+    }
+}
@@ -0,0 +1,57 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+/// An error encountered while creating a
+/// [`MetadataWatcher`](crate::MetadataWatcher).
+#[derive(Debug)]
+pub enum WatchError {
+    /// The metadata file could not be read.
+    Io(io::Error),
+
+    /// The metadata file could not be parsed.
+    Metadata(crate::Error),
+
+    /// The metadata file could not be watched.
+    Notify(notify::Error),
+}
+
+impl Display for WatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read metadata: {error}"),
+            Self::Metadata(error) => write!(f, "{error}"),
+            Self::Notify(error) => write!(f, "failed to watch metadata: {error}"),
+        }
+    }
+}
+
+impl Error for WatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Metadata(error) => Some(error),
+            Self::Notify(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for WatchError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<crate::Error> for WatchError {
+    fn from(error: crate::Error) -> Self {
+        Self::Metadata(error)
+    }
+}
+
+impl From<notify::Error> for WatchError {
+    fn from(error: notify::Error) -> Self {
+        Self::Notify(error)
+    }
+}